@@ -0,0 +1,131 @@
+"""
+In-memory registry of admin web sessions.
+
+POST /api/admin/login trades the admin password for a session: a public id (shown to owners, used in the websocket
+path) and a secret bearer token the admin page sends instead of the password from then on. Tokens are only valid
+while their session hasn't been revoked (by the owner or on logout), gone idle for longer than
+ADMIN_SESSION_IDLE_HOURS, or outlived ADMIN_SESSION_MAX_HOURS. Sessions don't survive a restart, so admins log in
+again after one.
+"""
+
+from dataclasses import dataclass, field
+from datetime import datetime, timedelta, timezone
+from typing import Dict, Optional
+import secrets
+import uuid
+
+from backend.custom_logging import api_logger
+from backend.in_memory_state import on_reset
+from backend.settings import settings
+
+
+@dataclass
+class AdminSession:
+    session_id: str
+    ip: Optional[str]
+    is_owner: bool = False
+    organization_id: Optional[int] = None  # Set for organization admins, whose session only sees that organization
+    token: str = field(default_factory=lambda: secrets.token_urlsafe(32))
+    created_at: datetime = field(default_factory=lambda: datetime.now(tz=timezone.utc))
+    last_activity_at: datetime = field(default_factory=lambda: datetime.now(tz=timezone.utc))
+
+    @property
+    def expires_at(self) -> datetime:
+        idle_expiry = self.last_activity_at + timedelta(hours=settings.ADMIN_SESSION_IDLE_HOURS)
+        return min(idle_expiry, self.created_at + timedelta(hours=settings.ADMIN_SESSION_MAX_HOURS))
+
+
+class AdminSessionStore:
+    def __init__(self):
+        self.sessions: Dict[str, AdminSession] = {}
+        # Tokens of revoked sessions, kept until they'd have expired anyway so they're refused as revoked
+        self.revoked_tokens: Dict[str, datetime] = {}
+
+    def create(
+        self,
+        ip: Optional[str],
+        is_owner: bool = False,
+        organization_id: Optional[int] = None,
+        token: Optional[str] = None,
+    ) -> AdminSession:
+        """Issue a session; its token is random unless given, which only AppBuilder.admin_session does."""
+        self.prune_expired()
+        session = AdminSession(session_id=str(uuid.uuid4()), ip=ip, is_owner=is_owner, organization_id=organization_id)
+        if token is not None:
+            session.token = token
+        self.sessions[session.session_id] = session
+        api_logger.info(
+            f"Admin session created: session_id={session.session_id} ip={ip} is_owner={is_owner} "
+            f"organization_id={organization_id}"
+        )
+        return session
+
+    def authenticate(
+        self, token: str, ip: Optional[str] = None, now: Optional[datetime] = None
+    ) -> Optional[AdminSession]:
+        """Find the live session a bearer token belongs to, recording the activity. None if there isn't one."""
+        for session in list(self.sessions.values()):
+            if secrets.compare_digest(session.token, token):
+                return self.touch(session.session_id, ip=ip, now=now)
+        return None
+
+    def touch(
+        self, session_id: str, ip: Optional[str] = None, now: Optional[datetime] = None
+    ) -> Optional[AdminSession]:
+        """
+        Record activity on a session, extending its expiry.
+
+        Returns:
+            The session, or None if it was never issued, has been revoked, or has expired
+        """
+        now = now or datetime.now(tz=timezone.utc)
+        session = self.sessions.get(session_id)
+        if not session:
+            api_logger.warning(f"Unknown or revoked admin session: session_id={session_id}")
+            return None
+        if session.expires_at <= now:
+            del self.sessions[session_id]
+            api_logger.info(f"Admin session expired: session_id={session_id}")
+            return None
+
+        session.last_activity_at = now
+        if ip:
+            session.ip = ip
+        return session
+
+    def prune_expired(self, now: Optional[datetime] = None):
+        now = now or datetime.now(tz=timezone.utc)
+        for session_id in [session_id for session_id, session in self.sessions.items() if session.expires_at <= now]:
+            del self.sessions[session_id]
+            api_logger.info(f"Admin session expired: session_id={session_id}")
+        for token in [token for token, expires_at in self.revoked_tokens.items() if expires_at <= now]:
+            del self.revoked_tokens[token]
+
+    def is_revoked(self, token: str) -> bool:
+        """Whether token belonged to a session that was revoked (rather than never issued or expired)."""
+        self.prune_expired()
+        return any(secrets.compare_digest(revoked, token) for revoked in self.revoked_tokens)
+
+    def list(self) -> list[AdminSession]:
+        self.prune_expired()
+        return sorted(self.sessions.values(), key=lambda session: session.created_at)
+
+    def revoke(self, session_id: str) -> bool:
+        session = self.sessions.pop(session_id, None)
+        if session:
+            self.revoked_tokens[session.token] = session.expires_at
+        api_logger.info(f"Admin session revoked: session_id={session_id} existed={session is not None}")
+        return session is not None
+
+    def revoke_organization(self, organization_id: int) -> list[str]:
+        """Revoke every session of an organization's admins, returning their ids."""
+        session_ids = [
+            session_id for session_id, session in self.sessions.items() if session.organization_id == organization_id
+        ]
+        for session_id in session_ids:
+            self.revoke(session_id)
+        return session_ids
+
+
+admin_session_store = AdminSessionStore()
+on_reset(admin_session_store.__init__)
@@ -1,15 +1,91 @@
-import uuid
-
-from fastapi import APIRouter, Depends
+from fastapi import APIRouter, Depends, Request, Response
+from sqlmodel import Session, select
 
+from backend.admin_sessions import AdminSession, admin_session_store
+from backend.csrf import clear_session_cookies, set_session_cookies
 from backend.custom_logging import api_logger
-from backend.dependencies import check_admin_token
-from backend.schemas import AdminAuthenticatedResponse
+from backend.database import get_session
+from backend.database.models import Organization
+from backend.dependencies import check_admin_token, current_admin_session, is_admin_password, is_owner_password
+from backend.errors import UnauthorizedError
+from backend.organizations import verify_password
+from backend.schemas import (
+    AdminAuthenticatedResponse,
+    AdminLoginRequest,
+    AdminWebSocketTokenResponse,
+    MessageResponse,
+)
+from backend.settings import settings
+from backend.websocket.connect_tokens import issue_admin_connect_token
+from backend.websocket.managers import admin_web_socket_manager
+
+router = APIRouter()
+
+
+def session_response(session: AdminSession) -> AdminAuthenticatedResponse:
+    return AdminAuthenticatedResponse(
+        session_id=session.session_id,
+        token=session.token,
+        is_owner=session.is_owner,
+        organization_id=session.organization_id,
+        expires_at=session.expires_at.isoformat(),
+    )
+
+
+@router.post("/login", response_model=AdminAuthenticatedResponse)
+async def login_admin(
+    login: AdminLoginRequest, request: Request, response: Response, db: Session = Depends(get_session)
+):
+    """
+    Trade the admin (or owner) password for a session token to send as the bearer from then on.
 
-router = APIRouter(dependencies=[Depends(check_admin_token)])
+    With an organization slug, the password is that organization's and the session only sees its data. With
+    ADMIN_COOKIE_AUTH the token is also set as a cookie, along with the CSRF token to echo (see backend/csrf.py).
+    """
+    ip = request.client.host if request.client else None
+    if login.organization:
+        organization = db.exec(select(Organization).where(Organization.slug == login.organization.lower())).first()
+        if not organization or not verify_password(login.password, organization.password_hash):
+            api_logger.warning(f"Organization admin login failed: organization={login.organization}")
+            raise UnauthorizedError("Invalid admin credentials")
+        session = admin_session_store.create(ip=ip, organization_id=organization.id)
+    elif is_admin_password(login.password):
+        session = admin_session_store.create(ip=ip, is_owner=is_owner_password(login.password))
+    else:
+        api_logger.warning("Admin login with an invalid password")
+        raise UnauthorizedError("Invalid admin credentials")
 
+    api_logger.info(f"Admin logged in: session_id={session.session_id} organization_id={session.organization_id}")
+    if settings.ADMIN_COOKIE_AUTH:
+        set_session_cookies(response, session)
+    return session_response(session)
 
-@router.get("/check", response_model=AdminAuthenticatedResponse)
-async def check_admin_credentials():
+
+@router.get("/check", response_model=AdminAuthenticatedResponse, dependencies=[Depends(check_admin_token)])
+async def check_admin_credentials(session: AdminSession = Depends(current_admin_session)):
+    """The caller's session."""
     api_logger.info("Admin credentials check endpoint called")
-    return AdminAuthenticatedResponse(session_id=str(uuid.uuid4()))
+    return session_response(session)
+
+
+@router.post("/ws-token", response_model=AdminWebSocketTokenResponse, dependencies=[Depends(check_admin_token)])
+async def issue_admin_websocket_token(session: AdminSession = Depends(current_admin_session)):
+    """A short-lived token for /ws/admin/{session_id}?token=..., which keeps the session token out of the URL."""
+    token = issue_admin_connect_token(session)
+    api_logger.info(f"Issued admin websocket connect token: session_id={session.session_id}")
+    return AdminWebSocketTokenResponse(
+        token=token, session_id=session.session_id, expires_in_seconds=settings.WS_TOKEN_TTL_SECONDS
+    )
+
+
+@router.post("/logout", response_model=MessageResponse, dependencies=[Depends(check_admin_token)])
+async def logout_admin(response: Response, session: AdminSession = Depends(current_admin_session)):
+    """
+    Revoke the caller's own web session and close its websocket. Other admins' sessions are only revoked by the
+    owner, through DELETE /sessions/{session_id}.
+    """
+    clear_session_cookies(response)
+    api_logger.info(f"Admin logout: session_id={session.session_id}")
+    admin_session_store.revoke(session.session_id)
+    await admin_web_socket_manager.disconnect(session.session_id, code=1000, reason="Logged out")
+    return MessageResponse(status=True, message="Logged out")
@@ -0,0 +1,129 @@
+from fastapi import APIRouter, Depends
+
+from backend.custom_logging import api_logger
+from backend.dependencies import check_deployment_admin
+from backend.schemas import (
+    AckStatsResponse,
+    BufferStatsResponse,
+    ConnectionSendStats,
+    DeliveryStatsResponse,
+    DiffStatsResponse,
+    EventDeliveryStats,
+    RecentDeliveryFailure,
+)
+from backend.settings import settings
+from backend.websocket.delivery import delivery_stats
+from backend.websocket.managers import admin_web_socket_manager, lobby_websocket_manager
+from backend.websocket.send_stats import SendStats, admin_key, player_key, send_stats, spectator_key
+
+router = APIRouter(dependencies=[Depends(check_deployment_admin)])
+
+
+def to_connection_stats(
+    kind: str,
+    connection_id: str,
+    lobby_ids: list[int],
+    stats: SendStats | None,
+    unacked_events: int = 0,
+    queued_events: int = 0,
+):
+    stats = stats or SendStats()
+    return ConnectionSendStats(
+        kind=kind,
+        connection_id=connection_id,
+        lobby_ids=lobby_ids,
+        messages_sent=stats.messages_sent,
+        avg_send_ms=round(stats.avg_ms, 2),
+        p95_send_ms=round(stats.p95_ms, 2),
+        max_send_ms=round(stats.max_seconds * 1000, 2),
+        is_slow=stats.is_slow(settings.WS_SLOW_CLIENT_P95_MS),
+        unacked_events=unacked_events,
+        queued_events=queued_events,
+    )
+
+
+@router.get("/connections", response_model=list[ConnectionSendStats])
+async def list_connections(slow_only: bool = False):
+    """Open websocket connections with their send latency, slowest p95 first."""
+    api_logger.info(f"Admin requested connection inspector: slow_only={slow_only}")
+    connections = []
+    for lobby_id, members in lobby_websocket_manager.lobby_websockets.items():
+        for player_session_id in members:
+            stats = send_stats.get(player_key(player_session_id))
+            unacked = lobby_websocket_manager.acks.unacked_count(player_session_id)
+            queued = lobby_websocket_manager.backlog(lobby_id, player_key(player_session_id))
+            connections.append(to_connection_stats("player", player_session_id, [lobby_id], stats, unacked, queued))
+    for lobby_id, viewers in lobby_websocket_manager.spectators.items():
+        for spectator_session_id in viewers:
+            key = spectator_key(spectator_session_id)
+            queued = lobby_websocket_manager.backlog(lobby_id, key)
+            connections.append(
+                to_connection_stats("spectator", spectator_session_id, [lobby_id], send_stats.get(key), 0, queued)
+            )
+    for web_session_id, connection in admin_web_socket_manager.admin_websockets.items():
+        stats = send_stats.get(admin_key(web_session_id))
+        connections.append(to_connection_stats("admin", web_session_id, list(connection["subscribed_lobbies"]), stats))
+
+    if slow_only:
+        connections = [connection for connection in connections if connection.is_slow]
+    connections.sort(key=lambda connection: connection.p95_send_ms, reverse=True)
+
+    slow_count = sum(1 for connection in connections if connection.is_slow)
+    api_logger.info(f"Returning {len(connections)} connections ({slow_count} slow)")
+    return connections
+
+
+@router.get("/connections/acks", response_model=AckStatsResponse)
+async def get_ack_stats():
+    """How players' clients are keeping up with acknowledging critical events (game start, kick)."""
+    acks = lobby_websocket_manager.acks
+    return AckStatsResponse(
+        opted_in_players=len(acks.opted_in),
+        unacked_events=acks.unacked_count(),
+        resends=acks.resends,
+        expired=acks.expired,
+    )
+
+
+@router.get("/connections/diffs", response_model=DiffStatsResponse)
+async def get_diff_stats():
+    """How much of the snapshot-style traffic to players in diff mode goes out as patches."""
+    diffs = lobby_websocket_manager.diffs
+    return DiffStatsResponse(opted_in_players=len(diffs.sent), full_copies=diffs.full, patches=diffs.patched)
+
+
+@router.get("/connections/buffers", response_model=BufferStatsResponse)
+async def get_buffer_stats():
+    """Memory held for replaying and resending events, and how often the replay budget has had to evict."""
+    replay = lobby_websocket_manager.replay
+    return BufferStatsResponse(
+        replay_lobbies=len(replay.events),
+        replay_events=replay.event_count(),
+        replay_bytes=replay.total_bytes,
+        replay_max_bytes=replay.max_bytes,
+        replay_evicted_lobbies=replay.evicted_lobbies,
+        replay_trimmed_events=replay.trimmed_events,
+        unacked_events=lobby_websocket_manager.acks.unacked_count(),
+    )
+
+
+@router.get("/connections/deliveries", response_model=DeliveryStatsResponse)
+async def get_delivery_stats():
+    """Websocket sends per event type since startup and the latest failures, to tell one bad client from an outage."""
+    event_types = [
+        EventDeliveryStats(
+            event_type=event_type, attempted=totals.attempted, succeeded=totals.succeeded, failed=totals.failed
+        )
+        for event_type, totals in delivery_stats.by_event_type.items()
+    ]
+    event_types.sort(key=lambda stats: (-stats.failed, stats.event_type))
+    recent_failures = [
+        RecentDeliveryFailure(
+            at=recent.at.isoformat(),
+            event_type=recent.event_type,
+            connection=recent.failure.connection,
+            reason=recent.failure.reason,
+        )
+        for recent in reversed(delivery_stats.recent_failures)
+    ]
+    return DeliveryStatsResponse(event_types=event_types, recent_failures=recent_failures)
@@ -0,0 +1,31 @@
+from fastapi import APIRouter, Depends
+
+from backend.custom_logging import api_logger
+from backend.dependencies import check_deployment_admin
+from backend.deprecation import DeprecatedRouteUsage, deprecation_tracker
+from backend.schemas import DeprecatedRouteUsageResponse
+from backend.settings import settings
+
+router = APIRouter(dependencies=[Depends(check_deployment_admin)])
+
+
+@router.get("/deprecations", response_model=list[DeprecatedRouteUsageResponse])
+async def list_deprecated_route_usage():
+    """Configured deprecated routes with how often, and by whom, they are still being called."""
+    api_logger.info("Admin requested deprecated route usage")
+    results = []
+    for route in settings.DEPRECATED_ROUTES:
+        usage = deprecation_tracker.usage.get(route.key) or DeprecatedRouteUsage()
+        results.append(
+            DeprecatedRouteUsageResponse(
+                route=route.key,
+                sunset=route.sunset.isoformat() if route.sunset else None,
+                replacement=route.replacement,
+                calls=usage.calls,
+                last_called_at=usage.last_called_at.isoformat() if usage.last_called_at else None,
+                last_path=usage.last_path,
+                last_user_agent=usage.last_user_agent,
+                last_referer=usage.last_referer,
+            )
+        )
+    return sorted(results, key=lambda result: result.calls, reverse=True)
@@ -0,0 +1,25 @@
+import time
+
+from fastapi import APIRouter, Depends
+
+from backend.custom_logging import api_logger
+from backend.dependencies import check_deployment_admin
+from backend.features import feature_flags
+from backend.health import health_monitor
+from backend.schemas import HealthResponse
+
+router = APIRouter(dependencies=[Depends(check_deployment_admin)])
+
+
+@router.get("/health", response_model=HealthResponse)
+async def get_health():
+    """This node's current health score and the features switched off because of it (or by config)."""
+    api_logger.info("Admin requested node health")
+    score = health_monitor.score(time.time())
+    return HealthResponse(
+        degraded=feature_flags.degraded,
+        db_latency_ms=score.db_latency_ms,
+        error_rate=score.error_rate,
+        request_count=score.request_count,
+        disabled_features=[feature.value for feature in feature_flags.disabled()],
+    )
@@ -1,22 +1,85 @@
+from collections import Counter
 from uuid import uuid4
 import json
-import math
-from datetime import datetime, timezone
+from datetime import datetime, timedelta, timezone
+from typing import Literal
 
-from fastapi import APIRouter, Depends, HTTPException
+from fastapi import APIRouter, Depends, Query, Request, Response
+from fastapi.responses import StreamingResponse
+from sqlalchemy.exc import SQLAlchemyError
 from sqlalchemy.orm import selectinload
 from sqlmodel import Session, func, select
 from pydantic import BaseModel
 
+from backend.api.shared import SHARE_REPLAY_SCOPE, SHARE_RESULTS_SCOPE
 from backend.custom_logging import api_logger
-from backend.database import Lobby, Player, Team, Game, get_session
-from backend.database.models import RoundResult
-from backend.dependencies import check_admin_token
-from backend.schemas import GeneratedNameResponse, LobbyCreate, LobbyInfo, MessageResponse
+from backend.database import ChatMessage, Guess, Lobby, Player, Team, Game, get_session
+from backend.database.models import PaceHint, RoundResult
+from backend.dependencies import admin_organization_id, check_admin_token
+from backend.api.admin.lobby.team import MAX_TEAMS_PER_LOBBY
+from backend.api.lobby_phase import (
+    announce_phase_change,
+    ensure_no_game_in_progress,
+    get_lobby_phase,
+    get_lobby_phases,
+    smallest_team,
+)
+from backend.bots import bot_names
+from backend.commentary import commentary_feed, commentate
+from backend.game import commentary
+from backend.intermission import begin_intermission
+from backend.lobby_host import hand_off_host, host_changed_event
+from backend.reveal import reveal_store
+from backend.roster import bump_roster_version, in_join_order, next_join_order
+from backend.errors import ConflictError, DatabaseError, ErrorCode, ForbiddenError, InvalidRequestError, NotFoundError
+from backend.schemas import (
+    AdminLobbyListEntry,
+    AdminLobbyListResponse,
+    BroadcastMessageResponse,
+    DeliverySummary,
+    GeneratedNameResponse,
+    ImportedPlayer,
+    ImportPlayersResponse,
+    LateJoinUpdate,
+    LobbyCreate,
+    LobbyInfo,
+    LobbyScheduleUpdate,
+    LobbySeedUpdate,
+    LobbySettingsUpdate,
+    MergeLobbiesResponse,
+    MessageResponse,
+    PaceKeeperUpdate,
+    ProgressVisibilityUpdate,
+    ShareReplayRequest,
+    ShareResultsResponse,
+    SoftPlayerCapUpdate,
+)
+from backend.settings import settings
+from backend.spectators import spectator_store
+from backend.utils.name_filter import allowed_name
 from backend.utils.name_generator import generate_lobby_name
-from backend.websocket.events import LobbyDeletedEvent, NewRoundStartedEvent, RoundEndedEvent
+from backend.utils.lobby_codes import unique_lobby_code
+from backend.utils.lobby_merge import dedupe_name, merged_settings
+from backend.utils.occupancy import effective_soft_cap, is_over_soft_cap
+from backend.utils.results_export import PlayerStatsRow, ResultsExport, TeamStatsRow, iter_results_csv
+from backend.utils.roster import RosterRow, build_roster_csv
+from backend.utils.scheduling import LobbySchedule, build_lobby_schedule
+from backend.utils.signing import sign_token
+from backend.utils.team_colors import pick_team_colors
+from backend.websocket.events import (
+    JoinedLobbyEvent,
+    LobbyDeletedEvent,
+    LobbyMergedEvent,
+    NewRoundStartedEvent,
+    RoundEndedEvent,
+)
 from backend.websocket.managers import lobby_websocket_manager
+from backend.game.prizes import MAX_TOP_TEAMS, PrizeTiers, contest_prizes
 from backend.game.puzzles import get_puzzle_manager
+from backend.game.lobby_state import LobbyPhase
+from backend.game.results import game_finished_event, record_round_results
+from backend.game.rng import SEED_LIMIT, new_seed
+from backend.game.standings import PROGRESS_VISIBILITY_LEVELS
 
 router = APIRouter(dependencies=[Depends(check_admin_token)])
 
@@ -33,11 +96,16 @@ async def get_puzzle_dates():
 async def create_lobby(
     lobby_data: LobbyCreate,
     db: Session = Depends(get_session),
+    organization_id: int | None = Depends(admin_organization_id),
 ):
     # Auto-generate lobby name if not provided
-    lobby_name = lobby_data.name if lobby_data.name else generate_lobby_name()
-    api_logger.info(f"Admin requested lobby creation: name={lobby_name}")
-    lobby = Lobby(name=lobby_name, code=uuid4().hex[:6].upper())
+    lobby_name = allowed_name(lobby_data.name) if lobby_data.name else generate_lobby_name()
+    api_logger.info(f"Admin requested lobby creation: name={lobby_name} rng_seed={lobby_data.rng_seed}")
+    if lobby_data.rng_seed is not None and not 0 <= lobby_data.rng_seed < SEED_LIMIT:
+        raise InvalidRequestError(f"Seed must be between 0 and {SEED_LIMIT - 1}")
+    lobby = Lobby(name=lobby_name, code=unique_lobby_code(db), organization_id=organization_id)
+    if lobby_data.rng_seed is not None:
+        lobby.rng_seed = lobby_data.rng_seed
     db.add(lobby)
     db.commit()
     db.refresh(lobby)
@@ -53,27 +121,116 @@ async def get_random_lobby_name():
     return GeneratedNameResponse(name=name)
 
 
-@router.get("/lobby", response_model=list[Lobby])
-async def get_all_lobbies(db: Session = Depends(get_session)):
-    api_logger.info("Admin requested list of all lobbies")
-    lobbies = db.exec(select(Lobby)).all()
-    api_logger.info(f"Returning {len(lobbies)} lobbies")
-    return lobbies
+def last_activity_by_lobby(db: Session, lobbies: list[Lobby]) -> dict[int, datetime]:
+    """The latest player join, round start or guess in each lobby (else its creation), one grouped query apiece."""
+    lobby_ids = [lobby.id for lobby in lobbies]
+    queries = [
+        select(Player.lobby_id, func.max(Player.created_at)).where(Player.lobby_id.in_(lobby_ids)),
+        select(Game.lobby_id, func.max(Game.started_at)).where(Game.lobby_id.in_(lobby_ids)),
+        select(Game.lobby_id, func.max(Guess.created_at))
+        .join(Guess, Guess.game_id == Game.id)
+        .where(Game.lobby_id.in_(lobby_ids)),
+    ]
+    # SQLite hands datetimes back naive; they're stored in UTC
+    latest = {lobby.id: lobby.created_at.replace(tzinfo=lobby.created_at.tzinfo or timezone.utc) for lobby in lobbies}
+    for query in queries:
+        for lobby_id, at in db.exec(query.group_by(query.selected_columns[0])).all():
+            if at is not None:
+                latest[lobby_id] = max(latest[lobby_id], at.replace(tzinfo=at.tzinfo or timezone.utc))
+    return latest
+
+
+LOBBY_LIST_SORTS = {
+    "newest": (Lobby.created_at.desc(), Lobby.id.desc()),
+    "oldest": (Lobby.created_at, Lobby.id),
+    "name": (func.lower(Lobby.name), Lobby.id),
+}
+
+
+@router.get("/lobby", response_model=AdminLobbyListResponse)
+async def get_all_lobbies(
+    page: int = Query(default=1, ge=1),
+    per_page: int = Query(default=50, ge=1, le=200),
+    sort: Literal["newest", "oldest", "name", "players"] = "newest",
+    active_only: bool = False,
+    db: Session = Depends(get_session),
+    organization_id: int | None = Depends(admin_organization_id),
+):
+    """
+    One page of lobbies with their occupancy, only the admin's organization's when they're scoped to one.
+
+    Each entry carries what the dashboard overview shows (player, team and connected counts, phase, last activity),
+    gathered with a few grouped queries over the page rather than per lobby. active_only keeps lobbies with a round in
+    progress; "players" sorts by player count, largest first.
+    """
+    api_logger.info(
+        f"Admin requested list of lobbies: page={page} per_page={per_page} sort={sort} active_only={active_only}"
+    )
+    player_counts = (
+        select(Player.lobby_id, func.count(Player.id).label("player_count")).group_by(Player.lobby_id).subquery()
+    )
+    player_count = func.coalesce(player_counts.c.player_count, 0)
+
+    conditions = []
+    if organization_id is not None:
+        conditions.append(Lobby.organization_id == organization_id)
+    if active_only:
+        active_lobby_ids = (
+            select(Team.lobby_id).join(Game, Team.game_id == Game.id).where(Game.completed_at.is_(None))
+        )
+        conditions.append(Lobby.id.in_(active_lobby_ids))
+
+    total = db.exec(select(func.count(Lobby.id)).where(*conditions)).one()
+    order_by = (player_count.desc(), Lobby.id) if sort == "players" else LOBBY_LIST_SORTS[sort]
+    rows = db.exec(
+        select(Lobby, player_count)
+        .outerjoin(player_counts, player_counts.c.lobby_id == Lobby.id)
+        .where(*conditions)
+        .order_by(*order_by)
+        .offset((page - 1) * per_page)
+        .limit(per_page)
+    ).all()
 
+    lobby_ids = [lobby.id for lobby, _ in rows]
+    team_counts = dict(
+        db.exec(
+            select(Team.lobby_id, func.count(Team.id)).where(Team.lobby_id.in_(lobby_ids)).group_by(Team.lobby_id)
+        ).all()
+    )
+    phases = get_lobby_phases(db, lobby_ids)
+    last_activity = last_activity_by_lobby(db, [lobby for lobby, _ in rows])
+
+    entries = []
+    for lobby, count in rows:
+        cap = effective_soft_cap(lobby.soft_player_cap, settings.LOBBY_SOFT_PLAYER_CAP)
+        entries.append(
+            AdminLobbyListEntry(
+                lobby=lobby,
+                player_count=count,
+                soft_player_cap=cap,
+                over_soft_cap=is_over_soft_cap(count, cap),
+                team_count=team_counts.get(lobby.id, 0),
+                connected_count=len(lobby_websocket_manager.lobby_websockets.get(lobby.id, {})),
+                phase=phases[lobby.id],
+                last_activity_at=last_activity[lobby.id],
+            )
+        )
+    api_logger.info(f"Returning {len(entries)} of {total} lobbies")
+    return AdminLobbyListResponse(
+        items=entries, total=total, page=page, per_page=per_page, has_more=page * per_page < total
+    )
 
-@router.get("/lobby/{lobby_id}", response_model=LobbyInfo)
-async def get_lobby_info(lobby_id: int, db: Session = Depends(get_session)):
-    api_logger.info(f"Admin requested lobby info: lobby_id={lobby_id}")
+
+def build_lobby_info(db: Session, lobby_id: int) -> LobbyInfo | None:
+    """The admin view of a lobby, shared by the HTTP endpoint and the admin websocket's request_lobby_state."""
     lobby = db.exec(
         select(Lobby).options(selectinload(Lobby.players), selectinload(Lobby.teams)).where(Lobby.id == lobby_id)
     ).first()
     if not lobby:
-        api_logger.warning(f"Lobby not found lobby_id={lobby_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        return None
 
-    players = lobby.players
+    players = in_join_order(lobby.players)
     teams = lobby.teams
-    api_logger.info(f"Found {len(players)} players in lobby_id={lobby.id}")
 
     players_by_team = {}
     for player in players:
@@ -82,12 +239,153 @@ async def get_lobby_info(lobby_id: int, db: Session = Depends(get_session)):
         if player.team_id not in players_by_team:
             players_by_team[player.team_id] = []
         players_by_team[player.team_id].append(player)
-    api_logger.info(f"Admin returning lobby info for {lobby_id}: {len(teams)} teams, {len(players)} players")
 
-    return LobbyInfo(lobby=lobby, players=players, players_by_team=players_by_team, teams=teams)
+    return LobbyInfo(
+        lobby=lobby,
+        players=players,
+        players_by_team=players_by_team,
+        teams=teams,
+        schedule=build_lobby_schedule(lobby),
+        phase=get_lobby_phase(db, lobby_id),
+    )
+
+
+@router.get("/lobby/{lobby_id}", response_model=LobbyInfo)
+async def get_lobby_info(lobby_id: int, db: Session = Depends(get_session)):
+    api_logger.info(f"Admin requested lobby info: lobby_id={lobby_id}")
+    lobby_info = build_lobby_info(db, lobby_id)
+    if not lobby_info:
+        api_logger.warning(f"Lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    api_logger.info(
+        f"Admin returning lobby info for {lobby_id}: {len(lobby_info.teams)} teams, {len(lobby_info.players)} players"
+    )
+    return lobby_info
+
+
+@router.get("/lobby/{lobby_id}/players.csv")
+async def download_roster_csv(lobby_id: int, db: Session = Depends(get_session)):
+    """Roster as a CSV attachment: name, team, joined_at, connection status and guesses made across all rounds."""
+    api_logger.info(f"Admin requested roster CSV: lobby_id={lobby_id}")
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Roster CSV failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    players = db.exec(select(Player).where(Player.lobby_id == lobby_id).order_by(Player.join_order, Player.id)).all()
+    team_names = {team.id: team.name for team in db.exec(select(Team).where(Team.lobby_id == lobby_id)).all()}
+    guess_counts = dict(
+        db.exec(
+            select(Guess.player_id, func.count(Guess.id))
+            .join(Player, Player.id == Guess.player_id)
+            .where(Player.lobby_id == lobby_id)
+            .group_by(Guess.player_id)
+        ).all()
+    )
+    connected = lobby_websocket_manager.lobby_websockets.get(lobby_id, {})
+
+    rows = [
+        RosterRow(
+            name=player.name,
+            team=team_names.get(player.team_id),
+            joined_at=player.created_at,
+            is_connected=player.session_id in connected,
+            guesses_made=guess_counts.get(player.id, 0),
+        )
+        for player in players
+    ]
+    api_logger.info(f"Returning roster CSV for lobby_id={lobby_id}: {len(rows)} players")
+    return Response(
+        content=build_roster_csv(rows),
+        media_type="text/csv",
+        headers={"Content-Disposition": f'attachment; filename="{lobby.code}-players.csv"'},
+    )
 
 
-@router.delete("/lobby/player/{player_id}", response_model=MessageResponse)
+@router.get("/lobby/{lobby_id}/results/export")
+async def export_results(
+    lobby_id: int,
+    format: Literal["csv", "json"] = "csv",
+    db: Session = Depends(get_session),
+):
+    """
+    Per-team and per-player stats across every round, as a CSV or JSON attachment: words solved, guesses, pace
+    keeper hints, time on finished rounds and points (including manual adjustments), plus contest-mode prizes.
+    """
+    api_logger.info(f"Admin requested results export: lobby_id={lobby_id} format={format}")
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Results export failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    teams = db.exec(select(Team).where(Team.lobby_id == lobby_id).order_by(Team.total_points.desc(), Team.id)).all()
+    players = db.exec(select(Player).where(Player.lobby_id == lobby_id).order_by(Player.join_order, Player.id)).all()
+    team_names = {team.id: team.name for team in teams}
+    guesses = db.exec(select(Guess).where(Guess.team_id.in_(list(team_names)))).all()
+    hints = db.exec(select(PaceHint.team_id).where(PaceHint.team_id.in_(list(team_names)))).all()
+    solve_times = dict(
+        db.exec(
+            select(RoundResult.team_id, func.sum(RoundResult.time_to_complete))
+            .where(RoundResult.lobby_id == lobby_id)
+            .group_by(RoundResult.team_id)
+        ).all()
+    )
+
+    # A rung counts once per team and puzzle, however many teammates typed the answer
+    solved_rungs = {(guess.team_id, guess.game_id, guess.word_index) for guess in guesses if guess.is_correct}
+    team_solves = Counter(team_id for team_id, _, _ in solved_rungs)
+    team_guesses = Counter(guess.team_id for guess in guesses)
+    player_solves = Counter(guess.player_id for guess in guesses if guess.is_correct)
+    player_guesses = Counter(guess.player_id for guess in guesses)
+    hint_counts = Counter(hints)
+
+    team_rows = [
+        TeamStatsRow(
+            name=team.name,
+            words_solved=team_solves[team.id],
+            guesses=team_guesses[team.id],
+            hints=hint_counts[team.id],
+            time_seconds=solve_times.get(team.id) or 0,
+            total_points=team.total_points,
+            rounds_played=team.rounds_played,
+            rounds_won=team.rounds_won,
+        )
+        for team in teams
+    ]
+    player_rows = [
+        PlayerStatsRow(
+            name=player.name,
+            team=team_names.get(player.team_id),
+            words_solved=player_solves[player.id],
+            guesses=player_guesses[player.id],
+        )
+        for player in players
+    ]
+
+    prizes = contest_prizes(db, lobby_id)
+
+    api_logger.info(
+        f"Exporting results for lobby_id={lobby_id}: {len(team_rows)} teams, {len(player_rows)} players as {format}"
+    )
+    disposition = {"Content-Disposition": f'attachment; filename="{lobby.code}-results.{format}"'}
+    if format == "json":
+        export = ResultsExport(
+            lobby_id=lobby.id,
+            lobby_code=lobby.code,
+            lobby_name=lobby.name,
+            exported_at=datetime.now(timezone.utc),
+            teams=team_rows,
+            players=player_rows,
+            prizes=prizes,
+        )
+        return Response(content=export.model_dump_json(), media_type="application/json", headers=disposition)
+    return StreamingResponse(
+        iter_results_csv(team_rows, player_rows, prizes), media_type="text/csv", headers=disposition
+    )
+
+
+@router.delete("/lobby/player/{player_id}", response_model=BroadcastMessageResponse)
 async def kick_player(
     player_id: int,
     db: Session = Depends(get_session),
@@ -97,7 +395,7 @@ async def kick_player(
     player = db.get(Player, player_id)
     if not player:
         api_logger.warning(f"Player kick failed: player not found player_id={player_id}")
-        raise HTTPException(status_code=404, detail="Player not found")
+        raise NotFoundError("Player not found")
 
     player_name = player.name
     lobby_id = player.lobby_id
@@ -112,14 +410,591 @@ async def kick_player(
             db.add(team_player)
         db.commit()
 
-    await lobby_websocket_manager.kick_player(lobby_id, player_session_id)
+    roster_version = bump_roster_version(db, lobby_id)
+    delivery = await lobby_websocket_manager.kick_player(lobby_id, player_session_id, roster_version=roster_version)
 
     # Delete player (this will cascade delete related guesses)
+    new_host = hand_off_host(db, db.get(Lobby, lobby_id), player_id)
     db.delete(player)
     db.commit()
+    if new_host:
+        await lobby_websocket_manager.broadcast_to_lobby(lobby_id, host_changed_event(lobby_id, new_host))
 
     api_logger.info(f"Successfully kicked player {player_name} (id={player_id}) from lobby_id={lobby_id}")
-    return MessageResponse(status=True, message=f"Player '{player_name}' has been kicked from the lobby")
+    return BroadcastMessageResponse(
+        status=True,
+        message=f"Player '{player_name}' has been kicked from the lobby",
+        delivery=DeliverySummary.of(delivery),
+    )
+
+
+@router.put("/lobby/{lobby_id}/host/{player_id}", response_model=Lobby)
+async def set_lobby_host(
+    lobby_id: int,
+    player_id: int,
+    db: Session = Depends(get_session),
+):
+    """Make a player the lobby's host, letting them shuffle teams and start games (see backend/lobby_host.py)."""
+    api_logger.info(f"Admin set lobby host: lobby_id={lobby_id} player_id={player_id}")
+
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        raise NotFoundError("Lobby not found")
+    player = db.get(Player, player_id)
+    if not player or player.lobby_id != lobby_id:
+        raise NotFoundError("Player not found in this lobby")
+    if player.is_bot:
+        raise InvalidRequestError("Bots can't host a lobby")
+
+    lobby.host_player_id = player.id
+    db.add(lobby)
+    db.commit()
+    db.refresh(lobby)
+
+    await lobby_websocket_manager.broadcast_to_lobby(lobby_id, host_changed_event(lobby_id, player))
+    return lobby
+
+
+@router.put("/lobby/{lobby_id}/progress-visibility", response_model=MessageResponse)
+async def update_progress_visibility(
+    lobby_id: int,
+    update: ProgressVisibilityUpdate,
+    db: Session = Depends(get_session),
+):
+    """Set how much of other teams' progress players can see: none, coarse or exact."""
+    api_logger.info(f"Admin requested progress visibility change: lobby_id={lobby_id} visibility={update.visibility}")
+    if update.visibility not in PROGRESS_VISIBILITY_LEVELS:
+        raise InvalidRequestError(f"Visibility must be one of: {', '.join(PROGRESS_VISIBILITY_LEVELS)}")
+
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Progress visibility change failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    lobby.progress_visibility = update.visibility
+    db.add(lobby)
+    db.commit()
+
+    api_logger.info(f"Progress visibility for lobby_id={lobby_id} set to {update.visibility}")
+    return MessageResponse(status=True, message=f"Progress visibility set to {update.visibility}")
+
+
+@router.patch("/lobby/{lobby_id}", response_model=Lobby)
+async def update_lobby_settings(
+    lobby_id: int,
+    update: LobbySettingsUpdate,
+    db: Session = Depends(get_session),
+):
+    """
+    Change a lobby's player cap, join lock, private flag or round time limit; fields left out of the body keep their
+    value. With a round time limit set, every game started in the lobby arms the round timer for that many seconds.
+    """
+    changes = update.model_dump(include=update.model_fields_set)
+    api_logger.info(f"Admin requested lobby settings change: lobby_id={lobby_id} changes={changes}")
+    if "max_players" in changes and changes["max_players"] is not None and changes["max_players"] < 1:
+        raise InvalidRequestError("Max players must be at least 1")
+    time_limit = changes.get("round_time_limit_seconds")
+    if time_limit is not None and not 1 <= time_limit <= 3600:
+        raise InvalidRequestError("Round time limit must be between 1 second and 60 minutes")
+    for field in ("locked", "is_private"):
+        if field in changes and changes[field] is None:
+            raise InvalidRequestError(f"{field} can't be null")
+
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Lobby settings change failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    for field, value in changes.items():
+        setattr(lobby, field, value)
+    db.add(lobby)
+    db.commit()
+    db.refresh(lobby)
+
+    api_logger.info(
+        f"Lobby settings for lobby_id={lobby_id}: max_players={lobby.max_players} locked={lobby.locked} "
+        f"is_private={lobby.is_private} round_time_limit_seconds={lobby.round_time_limit_seconds}"
+    )
+    return lobby
+
+
+@router.put("/lobby/{lobby_id}/late-join", response_model=MessageResponse)
+async def update_late_join(
+    lobby_id: int,
+    update: LateJoinUpdate,
+    db: Session = Depends(get_session),
+):
+    """Allow or refuse players joining while a game is in progress."""
+    api_logger.info(f"Admin requested late join change: lobby_id={lobby_id} enabled={update.enabled}")
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Late join change failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    lobby.late_join_enabled = update.enabled
+    db.add(lobby)
+    db.commit()
+
+    api_logger.info(f"Late join for lobby_id={lobby_id} {'enabled' if update.enabled else 'disabled'}")
+    return MessageResponse(status=True, message=f"Late join {'enabled' if update.enabled else 'disabled'}")
+
+
+@router.put("/lobby/{lobby_id}/pace-keeper", response_model=MessageResponse)
+async def update_pace_keeper(
+    lobby_id: int,
+    update: PaceKeeperUpdate,
+    db: Session = Depends(get_session),
+):
+    """Turn automatic hints for the slowest stalled team on or off."""
+    api_logger.info(f"Admin requested pace keeper change: lobby_id={lobby_id} enabled={update.enabled}")
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Pace keeper change failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    lobby.pace_keeper_enabled = update.enabled
+    db.add(lobby)
+    db.commit()
+
+    api_logger.info(f"Pace keeper for lobby_id={lobby_id} {'enabled' if update.enabled else 'disabled'}")
+    return MessageResponse(status=True, message=f"Pace keeper {'enabled' if update.enabled else 'disabled'}")
+
+
+@router.put("/lobby/{lobby_id}/prizes", response_model=MessageResponse)
+async def update_prize_tiers(
+    lobby_id: int,
+    tiers: PrizeTiers,
+    db: Session = Depends(get_session),
+):
+    """Turn contest mode on with the given prize tiers, or off by sending none; see backend/game/prizes.py."""
+    api_logger.info(f"Admin requested prize tiers change: lobby_id={lobby_id} tiers={tiers.model_dump()}")
+    if not 0 <= tiers.top_teams <= MAX_TOP_TEAMS:
+        raise InvalidRequestError(f"Top teams must be between 0 and {MAX_TOP_TEAMS}")
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Prize tiers change failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    lobby.prize_tiers = tiers.model_dump_json() if tiers.enabled else None
+    db.add(lobby)
+    db.commit()
+
+    api_logger.info(f"Prize tiers for lobby_id={lobby_id}: {lobby.prize_tiers or 'contest mode off'}")
+    return MessageResponse(status=True, message="Contest mode enabled" if tiers.enabled else "Contest mode disabled")
+
+
+@router.put("/lobby/{lobby_id}/soft-cap", response_model=MessageResponse)
+async def update_soft_player_cap(
+    lobby_id: int,
+    update: SoftPlayerCapUpdate,
+    db: Session = Depends(get_session),
+):
+    """Set the player count past which joins warn admins, or clear it to use the server default."""
+    api_logger.info(f"Admin requested soft cap change: lobby_id={lobby_id} soft_player_cap={update.soft_player_cap}")
+    if update.soft_player_cap is not None and update.soft_player_cap < 0:
+        raise InvalidRequestError("Soft player cap can't be negative")
+
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Soft cap change failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    lobby.soft_player_cap = update.soft_player_cap
+    db.add(lobby)
+    db.commit()
+
+    cap = effective_soft_cap(lobby.soft_player_cap, settings.LOBBY_SOFT_PLAYER_CAP)
+    api_logger.info(f"Soft cap for lobby_id={lobby_id} set to {update.soft_player_cap} (effective {cap})")
+    return MessageResponse(status=True, message=f"Soft player cap set to {cap}" if cap else "Soft player cap disabled")
+
+
+@router.put("/lobby/{lobby_id}/seed", response_model=Lobby)
+async def update_lobby_seed(
+    lobby_id: int,
+    update: LobbySeedUpdate,
+    db: Session = Depends(get_session),
+):
+    """
+    Set the lobby's random seed, or reroll it when no seed is given.
+
+    Only affects shuffles and puzzle draws made afterwards; existing teams and games are left as they are.
+    """
+    api_logger.info(f"Admin requested lobby seed change: lobby_id={lobby_id} seed={update.seed}")
+    if update.seed is not None and not 0 <= update.seed < SEED_LIMIT:
+        raise InvalidRequestError(f"Seed must be between 0 and {SEED_LIMIT - 1}")
+
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Lobby seed change failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    lobby.rng_seed = update.seed if update.seed is not None else new_seed()
+    db.add(lobby)
+    db.commit()
+    db.refresh(lobby)
+
+    api_logger.info(f"Lobby seed for lobby_id={lobby_id} set to {lobby.rng_seed}")
+    return lobby
+
+
+@router.put("/lobby/{lobby_id}/schedule", response_model=LobbySchedule)
+async def update_lobby_schedule(
+    lobby_id: int,
+    update: LobbyScheduleUpdate,
+    db: Session = Depends(get_session),
+):
+    """Set the lobby's timezone and its open/close/start times, returning them formatted for display."""
+    api_logger.info(
+        f"Admin requested schedule change: lobby_id={lobby_id} timezone={update.timezone} "
+        f"opens_at={update.opens_at} closes_at={update.closes_at} starts_at={update.starts_at}"
+    )
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Schedule change failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    lobby.timezone = update.timezone
+    lobby.opens_at = update.opens_at
+    lobby.closes_at = update.closes_at
+    lobby.starts_at = update.starts_at
+    db.add(lobby)
+    db.commit()
+    db.refresh(lobby)
+
+    api_logger.info(f"Schedule for lobby_id={lobby_id} updated")
+    return build_lobby_schedule(lobby)
+
+
+@router.post("/lobby/{lobby_id}/import-players", response_model=ImportPlayersResponse)
+async def import_players(
+    lobby_id: int,
+    from_lobby: int,
+    request: Request,
+    preserve_teams: bool = False,
+    db: Session = Depends(get_session),
+):
+    """
+    Copy players from an earlier lobby into this one, issuing each a fresh session and join link.
+
+    With preserve_teams, players keep their groupings: teams are matched by name and created when missing. Like
+    joining, it's refused while the lobby is locked or when the players wouldn't fit under its max_players.
+    """
+    api_logger.info(
+        f"Admin requested player import: lobby_id={lobby_id} from_lobby={from_lobby} preserve_teams={preserve_teams}"
+    )
+    if from_lobby == lobby_id:
+        raise InvalidRequestError("Cannot import players from the same lobby")
+
+    lobby = db.get(Lobby, lobby_id)
+    source_lobby = db.get(Lobby, from_lobby)
+    if not lobby or not source_lobby:
+        api_logger.warning(f"Player import failed: lobby not found lobby_id={lobby_id} from_lobby={from_lobby}")
+        raise NotFoundError("Lobby not found")
+    if lobby.locked:
+        api_logger.warning(f"Player import failed: lobby is locked lobby_id={lobby_id}")
+        raise ConflictError("This lobby isn't accepting new players", code=ErrorCode.LOBBY_LOCKED)
+
+    if preserve_teams:
+        ensure_no_game_in_progress(db, lobby, "change teams")
+
+    source_players = db.exec(select(Player).where(Player.lobby_id == from_lobby).order_by(Player.created_at)).all()
+    source_team_names = {team.id: team.name for team in db.exec(select(Team).where(Team.lobby_id == from_lobby)).all()}
+    existing_names = set(db.exec(select(Player.name).where(Player.lobby_id == lobby_id)).all())
+    teams_by_name = {team.name: team for team in db.exec(select(Team).where(Team.lobby_id == lobby_id)).all()}
+
+    to_import = []
+    skipped_names = []
+    for source_player in source_players:
+        if source_player.name in existing_names:
+            skipped_names.append(source_player.name)
+            continue
+        existing_names.add(source_player.name)
+        to_import.append(source_player)
+
+    if lobby.max_players is not None:
+        player_count = db.exec(select(func.count(Player.id)).where(Player.lobby_id == lobby_id)).one()
+        if player_count + len(to_import) > lobby.max_players:
+            api_logger.warning(
+                f"Player import failed: {len(to_import)} players won't fit lobby_id={lobby_id} "
+                f"players={player_count} max_players={lobby.max_players}"
+            )
+            raise ConflictError(
+                f"Only {max(lobby.max_players - player_count, 0)} more players fit in this lobby",
+                code=ErrorCode.LOBBY_FULL,
+                max_players=lobby.max_players,
+            )
+
+    missing_team_names = set()
+    if preserve_teams:
+        missing_team_names = {
+            source_team_names[player.team_id]
+            for player in to_import
+            if player.team_id in source_team_names and source_team_names[player.team_id] not in teams_by_name
+        }
+        if len(teams_by_name) + len(missing_team_names) > MAX_TEAMS_PER_LOBBY:
+            raise InvalidRequestError(f"Maximum of {MAX_TEAMS_PER_LOBBY} teams reached")
+
+    new_team_colors = pick_team_colors(
+        [team.color for team in teams_by_name.values()], len(missing_team_names), settings.TEAM_COLOR_PALETTE
+    )
+    base_url = str(request.base_url).rstrip("/")
+    imported: list[tuple[Player, str | None]] = []
+    roster_version = None
+    # Teams and players are created in one commit, so a failure doesn't leave empty teams behind
+    try:
+        for team_name, color in zip(sorted(missing_team_names), new_team_colors):
+            team = Team(name=team_name, color=color, lobby_id=lobby_id)
+            db.add(team)
+            teams_by_name[team_name] = team
+        # Gives the new teams their ids
+        db.flush()
+
+        join_order = next_join_order(db, lobby_id)
+        for source_player in to_import:
+            team_name = source_team_names.get(source_player.team_id) if preserve_teams else None
+            player = Player(
+                name=source_player.name,
+                session_id=str(uuid4()),
+                lobby_id=lobby_id,
+                team_id=teams_by_name[team_name].id if team_name else None,
+                join_order=join_order + len(imported),
+            )
+            db.add(player)
+            imported.append((player, team_name))
+        if imported:
+            roster_version = bump_roster_version(db, lobby_id)
+        db.commit()
+    except SQLAlchemyError as e:
+        db.rollback()
+        api_logger.exception(f"Player import failed, rolled back: lobby_id={lobby_id} from_lobby={from_lobby}: {e}")
+        raise DatabaseError("Couldn't import the players, nothing was changed")
+
+    for player, _ in imported:
+        db.refresh(player)
+        if player.team_id:
+            lobby_websocket_manager.register_player_team(player.session_id, player.team_id)
+
+    # One per player, as if each had joined; clients refetch the roster once, on the first new roster_version
+    for player, _ in imported:
+        await lobby_websocket_manager.broadcast_to_lobby(
+            lobby_id,
+            JoinedLobbyEvent(lobby_id=lobby_id, player_session_id=player.session_id, roster_version=roster_version),
+        )
+
+    api_logger.info(
+        f"Imported {len(imported)} players into lobby_id={lobby_id} from lobby_id={from_lobby}, "
+        f"skipped {len(skipped_names)} duplicate names"
+    )
+    return ImportPlayersResponse(
+        imported=[
+            ImportedPlayer(
+                name=player.name,
+                session_id=player.session_id,
+                join_url=f"{base_url}/?session={player.session_id}",
+                team_name=team_name,
+            )
+            for player, team_name in imported
+        ],
+        skipped_names=skipped_names,
+    )
+
+
+@router.post("/lobby/{source_id}/merge-into/{target_id}", response_model=MergeLobbiesResponse)
+async def merge_lobbies(source_id: int, target_id: int, db: Session = Depends(get_session)):
+    """
+    Fold a lobby created by mistake into the one its host meant to use, then delete it.
+
+    Players keep their sessions and teams; names the target already has get a " (2)" style suffix. Team chat moves
+    with the teams, and settings the target hasn't set (theme, caps, schedule) are taken from the source. Merges
+    that would put the target past its max_players (or the source's, when it's taken over) are refused, as is a
+    source that has played a round.
+    """
+    api_logger.info(f"Admin requested lobby merge: source_id={source_id} target_id={target_id}")
+    if source_id == target_id:
+        raise InvalidRequestError("Cannot merge a lobby into itself")
+
+    source = db.get(Lobby, source_id)
+    target = db.get(Lobby, target_id)
+    if not source or not target:
+        api_logger.warning(f"Lobby merge failed: lobby not found source_id={source_id} target_id={target_id}")
+        raise NotFoundError("Lobby not found")
+    if source.organization_id != target.organization_id:
+        raise InvalidRequestError("Lobbies belong to different organizations")
+    if source.is_quick_play or target.is_quick_play:
+        raise InvalidRequestError("The quick play lobby can't be merged")
+    ensure_no_game_in_progress(db, source, "merge lobbies")
+    ensure_no_game_in_progress(db, target, "merge lobbies")
+    if db.exec(select(Game.id).where(Game.lobby_id == source_id)).first():
+        raise InvalidRequestError("The lobby being merged has already played a round")
+
+    source_teams = db.exec(select(Team).where(Team.lobby_id == source_id).order_by(Team.id)).all()
+    target_team_names = set(db.exec(select(Team.name).where(Team.lobby_id == target_id)).all())
+    if len(target_team_names) + len(source_teams) > MAX_TEAMS_PER_LOBBY:
+        raise InvalidRequestError(f"Merged lobby would have more than {MAX_TEAMS_PER_LOBBY} teams")
+    source_players = db.exec(
+        select(Player).where(Player.lobby_id == source_id).order_by(Player.join_order, Player.id)
+    ).all()
+    player_names = set(db.exec(select(Player.name).where(Player.lobby_id == target_id)).all())
+    max_players = merged_settings(source, target).get("max_players", target.max_players)
+    if max_players is not None and len(player_names) + len(source_players) > max_players:
+        api_logger.warning(
+            f"Lobby merge failed: {len(player_names) + len(source_players)} players won't fit "
+            f"target_id={target_id} max_players={max_players}"
+        )
+        raise ConflictError(
+            f"The merged lobby would have more than {max_players} players",
+            code=ErrorCode.LOBBY_FULL,
+            max_players=max_players,
+        )
+
+    source_code = source.code
+    player_session_ids = [player.session_id for player in source_players]
+    renamed_players: dict[str, str] = {}
+    renamed_teams: dict[str, str] = {}
+    # Everything is moved and the source deleted in one commit, so a failure leaves both lobbies as they were
+    try:
+        for team in source_teams:
+            name = dedupe_name(team.name, target_team_names)
+            if name != team.name:
+                renamed_teams[team.name] = name
+            team.name = name
+            team.lobby_id = target_id
+            target_team_names.add(name)
+            db.add(team)
+
+        join_order = next_join_order(db, target_id)
+        for offset, player in enumerate(source_players):
+            name = dedupe_name(player.name, player_names)
+            if name != player.name:
+                renamed_players[player.name] = name
+            player.name = name
+            player.lobby_id = target_id
+            player.join_order = join_order + offset
+            player.is_ready = False
+            player_names.add(name)
+            db.add(player)
+
+        for chat_message in db.exec(select(ChatMessage).where(ChatMessage.lobby_id == source_id)).all():
+            chat_message.lobby_id = target_id
+            db.add(chat_message)
+
+        for setting, value in merged_settings(source, target).items():
+            setattr(target, setting, value)
+        db.add(target)
+        roster_version = bump_roster_version(db, target_id)
+        # The moves have to reach the database before the delete cascades over what's still in the source
+        db.flush()
+        db.delete(source)
+        db.commit()
+    except SQLAlchemyError as e:
+        db.rollback()
+        api_logger.exception(f"Lobby merge failed, rolled back: source_id={source_id} target_id={target_id}: {e}")
+        raise DatabaseError("Couldn't merge the lobbies, nothing was changed")
+
+    await lobby_websocket_manager.broadcast_to_lobby(
+        source_id,
+        LobbyMergedEvent(
+            lobby_id=source_id,
+            player_session_id="",
+            target_lobby_id=target_id,
+            target_lobby_code=target.code,
+            target_lobby_name=target.name,
+        ),
+    )
+    await lobby_websocket_manager.release_players(source_id, player_session_ids, "Lobby merged")
+    lobby_websocket_manager.replay.forget(source_id)
+    lobby_websocket_manager.acks.forget_lobby(source_id)
+    reveal_store.forget(source_id)
+    commentary_feed.forget(source_id)
+    await lobby_websocket_manager.close_spectators(source_id, "Lobby merged")
+    spectator_store.forget_lobby(source_id)
+
+    if player_session_ids:
+        await lobby_websocket_manager.broadcast_to_lobby(
+            target_id,
+            JoinedLobbyEvent(
+                lobby_id=target_id, player_session_id=player_session_ids[-1], roster_version=roster_version
+            ),
+        )
+
+    api_logger.info(
+        f"Merged lobby {source_code} into lobby_id={target_id}: {len(source_players)} players, "
+        f"{len(source_teams)} teams, renamed {len(renamed_players)} players and {len(renamed_teams)} teams"
+    )
+    return MergeLobbiesResponse(
+        target_lobby_id=target_id,
+        moved_players=len(source_players),
+        moved_teams=len(source_teams),
+        renamed_players=renamed_players,
+        renamed_teams=renamed_teams,
+    )
+
+
+@router.post("/lobby/{lobby_id}/bots", response_model=list[Player])
+async def add_bots(
+    lobby_id: int,
+    count: int = Query(default=1, ge=1),
+    skill: float | None = Query(default=None, ge=0.0, le=1.0),
+    db: Session = Depends(get_session),
+):
+    """
+    Add bot players to fill out uneven teams; each lands on the smallest team, or waits unassigned before teams exist.
+
+    With a skill, bots also solve their team's next rung with that chance every BOT_TICK_SECONDS. Bots count towards
+    max_players, and a request that wouldn't fit under it is refused.
+    """
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Add bots failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    bot_count = db.exec(select(func.count(Player.id)).where(Player.lobby_id == lobby_id, Player.is_bot.is_(True))).one()
+    if bot_count + count > settings.MAX_BOTS_PER_LOBBY:
+        raise InvalidRequestError(f"Maximum of {settings.MAX_BOTS_PER_LOBBY} bots per lobby")
+    if lobby.max_players is not None:
+        # Bots hold roster slots like anyone else
+        player_count = db.exec(select(func.count(Player.id)).where(Player.lobby_id == lobby_id)).one()
+        if player_count + count > lobby.max_players:
+            api_logger.warning(f"Add bots failed: lobby_id={lobby_id} is full max_players={lobby.max_players}")
+            raise ConflictError(
+                f"Only {max(lobby.max_players - player_count, 0)} more players fit in this lobby",
+                code=ErrorCode.LOBBY_FULL,
+                max_players=lobby.max_players,
+            )
+
+    existing_names = db.exec(select(Player.name).where(Player.lobby_id == lobby_id)).all()
+    bots = []
+    for name in bot_names(existing_names, count):
+        team = smallest_team(db, lobby_id)
+        bot = Player(
+            name=name,
+            session_id=f"bot-{uuid4()}",
+            lobby_id=lobby_id,
+            team_id=team.id if team else None,
+            is_ready=True,
+            is_bot=True,
+            bot_skill=skill,
+            join_order=next_join_order(db, lobby_id),
+        )
+        db.add(bot)
+        # Flushed one at a time so smallest_team sees the bots already placed
+        db.flush()
+        bots.append(bot)
+    roster_version = bump_roster_version(db, lobby_id)
+    db.commit()
+
+    for bot in bots:
+        db.refresh(bot)
+        if bot.team_id:
+            lobby_websocket_manager.register_player_team(bot.session_id, bot.team_id)
+
+    await lobby_websocket_manager.broadcast_to_lobby(
+        lobby_id,
+        JoinedLobbyEvent(lobby_id=lobby_id, player_session_id=bots[-1].session_id, roster_version=roster_version),
+    )
+
+    api_logger.info(f"Added {len(bots)} bots to lobby_id={lobby_id} skill={skill}")
+    return bots
 
 
 @router.delete("/lobby/{lobby_id}", response_model=MessageResponse)
@@ -128,22 +1003,82 @@ async def delete_lobby(lobby_id: int, db: Session = Depends(get_session)):
     lobby = db.get(Lobby, lobby_id)
     if not lobby:
         api_logger.warning(f"Delete failed: lobby not found lobby_id={lobby_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
+
+    lobby_name = lobby.name
+    player_session_ids = db.exec(select(Player.session_id).where(Player.lobby_id == lobby_id)).all()
+
+    # Delete first and only kick once that's committed, so a failed delete doesn't leave a lobby nobody is in
+    try:
+        # this cascades delete all related players and teams
+        db.delete(lobby)
+        db.commit()
+    except SQLAlchemyError as e:
+        db.rollback()
+        api_logger.exception(f"Lobby deletion failed, rolled back: lobby_id={lobby_id} error={e}")
+        raise DatabaseError("Couldn't delete the lobby, nothing was changed")
 
-    players = db.exec(select(Player).where(Player.lobby_id == lobby_id)).all()
     await lobby_websocket_manager.broadcast_to_lobby(
         lobby_id,
         LobbyDeletedEvent(lobby_id=lobby_id, player_session_id=""),
     )
-    for player in players:
-        await lobby_websocket_manager.kick_player(lobby_id, player.session_id)
+    for player_session_id in player_session_ids:
+        await lobby_websocket_manager.kick_player(lobby_id, player_session_id)
+    lobby_websocket_manager.replay.forget(lobby_id)
+    lobby_websocket_manager.acks.forget_lobby(lobby_id)
+    reveal_store.forget(lobby_id)
+    commentary_feed.forget(lobby_id)
+    await lobby_websocket_manager.close_spectators(lobby_id, "Lobby deleted")
+    spectator_store.forget_lobby(lobby_id)
+    api_logger.info(f"Successfully deleted lobby_id={lobby_id} name={lobby_name}")
+
+    return MessageResponse(status=True, message=f"Lobby '{lobby_name}' deleted successfully")
+
+
+@router.post("/lobby/{lobby_id}/share-results", response_model=ShareResultsResponse)
+async def share_results(lobby_id: int, request: Request, db: Session = Depends(get_session)):
+    """Issue a signed, expiring public link to the lobby's standings."""
+    api_logger.info(f"Admin requested shareable results link: lobby_id={lobby_id}")
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Share results failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+    if lobby.is_private:
+        raise ForbiddenError("Private lobbies can't be shared publicly")
 
-    # this cascades delete all related players and teams
-    db.delete(lobby)
-    db.commit()
-    api_logger.info(f"Successfully deleted lobby_id={lobby_id} name={lobby.name}")
+    ttl_seconds = settings.SHARE_LINK_TTL_HOURS * 3600
+    token = sign_token({"scope": SHARE_RESULTS_SCOPE, "lobby_id": lobby_id}, settings.share_secret, ttl_seconds)
+    expires_at = datetime.now(timezone.utc) + timedelta(seconds=ttl_seconds)
+    url = f"{str(request.base_url).rstrip('/')}/api/shared/results/{token}"
+
+    api_logger.info(f"Issued results link for lobby_id={lobby_id} expires_at={expires_at.isoformat()}")
+    return ShareResultsResponse(token=token, url=url, expires_at=expires_at.isoformat())
+
+
+@router.post("/lobby/{lobby_id}/share-replay", response_model=ShareResultsResponse)
+async def share_replay(
+    lobby_id: int, share_request: ShareReplayRequest, request: Request, db: Session = Depends(get_session)
+):
+    """Issue a signed, expiring public link to a recap of the lobby's latest round, optionally anonymized."""
+    api_logger.info(f"Admin requested shareable replay link: lobby_id={lobby_id} anonymize={share_request.anonymize}")
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Share replay failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+    if lobby.is_private:
+        raise ForbiddenError("Private lobbies can't be shared publicly")
+
+    ttl_seconds = settings.SHARE_LINK_TTL_HOURS * 3600
+    token = sign_token(
+        {"scope": SHARE_REPLAY_SCOPE, "lobby_id": lobby_id, "anonymize": share_request.anonymize},
+        settings.share_secret,
+        ttl_seconds,
+    )
+    expires_at = datetime.now(timezone.utc) + timedelta(seconds=ttl_seconds)
+    url = f"{str(request.base_url).rstrip('/')}/api/shared/replay/{token}"
 
-    return MessageResponse(status=True, message=f"Lobby '{lobby.name}' deleted successfully")
+    api_logger.info(f"Issued replay link for lobby_id={lobby_id} expires_at={expires_at.isoformat()}")
+    return ShareResultsResponse(token=token, url=url, expires_at=expires_at.isoformat())
 
 
 # Response models for game state endpoint
@@ -173,7 +1108,7 @@ async def get_game_state(lobby_id: int, db: Session = Depends(get_session)):
     lobby = db.get(Lobby, lobby_id)
     if not lobby:
         api_logger.warning(f"Lobby not found lobby_id={lobby_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
     # Get all teams in the lobby
     teams = db.exec(select(Team).where(Team.lobby_id == lobby_id)).all()
@@ -238,28 +1173,8 @@ async def get_game_state(lobby_id: int, db: Session = Depends(get_session)):
     return GameStateResponse(is_game_active=has_active_game, teams=team_progress_list)
 
 
-def calculate_points(
-    placement: int,
-    total_teams: int,
-    completion_percentage: float,
-    completed: bool,
-    worst_finished_points: int,
-) -> int:
-    """
-    Calculate points for a team based on placement and completion.
-
-    Reverse placement for finishers: 1st gets n points, 2nd gets n-1, etc.
-    DNFs get up to 75% of worst finished points, scaled by completion %, ceil, min 1.
-    """
-    if completed:
-        return total_teams - placement + 1
-
-    base = worst_finished_points
-    cap = base * 0.75
-    return max(1, math.ceil(min(cap, base * completion_percentage)))
-
-
-@router.post("/lobby/{lobby_id}/end", response_model=MessageResponse)
+@router.post("/lobby/{lobby_id}/game/end", response_model=BroadcastMessageResponse)
+@router.post("/lobby/{lobby_id}/end", response_model=BroadcastMessageResponse)
 async def end_game(
     lobby_id: int,
     db: Session = Depends(get_session),
@@ -274,7 +1189,9 @@ async def end_game(
     4. Marks all games as completed
     5. Creates a new Game for the next round
     6. Resets teams for the next round
-    7. Broadcasts round ended and new round started events
+    7. Broadcasts round ended, game finished (with the ranking) and new round started events
+    8. Goes into intermission when the lobby holds one, see backend/intermission.py
+    9. Broadcasts LOBBY_PHASE_CHANGED (in_progress -> finished or intermission)
     """
     api_logger.info(f"Admin requested to end game: lobby_id={lobby_id}")
 
@@ -282,14 +1199,14 @@ async def end_game(
     lobby = db.get(Lobby, lobby_id)
     if not lobby:
         api_logger.warning(f"End game failed: lobby not found lobby_id={lobby_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
     # Get all teams in the lobby
     teams = db.exec(select(Team).where(Team.lobby_id == lobby_id)).all()
 
     if not teams:
         api_logger.warning(f"End game failed: no teams in lobby_id={lobby_id}")
-        raise HTTPException(status_code=400, detail="No teams in this lobby")
+        raise InvalidRequestError("No teams in this lobby")
 
     # Get active games to determine if there's a game to end
     # Filter out games with empty puzzle_path (placeholder games for next round)
@@ -299,7 +1216,7 @@ async def end_game(
 
     if not active_games:
         api_logger.warning(f"End game failed: no active game lobby_id={lobby_id}")
-        raise HTTPException(status_code=400, detail="No active game to end")
+        raise InvalidRequestError("No active game to end")
 
     # Get the first active game (they should all be from the same round)
     game = active_games[0]
@@ -307,97 +1224,7 @@ async def end_game(
     puzzle = puzzle_manager.load_puzzle_by_path(game.puzzle_path)
     puzzle_length = len(puzzle.ladder)
 
-    # Separate completed teams from DNF teams
-    completed_teams = []
-    dnf_teams = []
-
-    for team in teams:
-        if team.game_id is None:
-            continue
-
-        team_game = db.get(Game, team.game_id)
-        if not team_game:
-            continue
-
-        # Check if team completed (game marked as completed for this team)
-        if team_game.completed_at is not None:
-            completed_teams.append(team)
-        else:
-            dnf_teams.append(team)
-
-    # Sort completed teams by completed_at
-    completed_teams.sort(key=lambda t: db.get(Game, t.game_id).completed_at)
-
-    # Calculate completion percentage for DNF teams - store in dict
-    team_completion_pct = {}
-    for team in dnf_teams:
-        team_game = db.get(Game, team.game_id)
-        revealed = json.loads(team_game.revealed_steps) if team_game.revealed_steps else []
-        team_completion_pct[team.id] = len(revealed) / puzzle_length if puzzle_length > 0 else 0.0
-
-    # Sort DNF teams by completion percentage (descending)
-    dnf_teams.sort(key=lambda t: team_completion_pct[t.id], reverse=True)
-
-    # Combine: completed teams first, then DNF teams
-    all_teams_ranked = completed_teams + dnf_teams
-
-    # Determine round number
-    round_number = (
-        db.exec(select(func.max(RoundResult.round_number)).where(RoundResult.lobby_id == lobby_id)).first() or 0
-    )
-    round_number += 1
-
-    # Calculate worst_finished_points: points awarded to the lowest finished placement
-    if completed_teams:
-        worst_finished_points = len(teams) - len(completed_teams) + 1
-    else:
-        worst_finished_points = len(teams)
-
-    # Calculate points and create round results
-    for placement, team in enumerate(all_teams_ranked, start=1):
-        completed = team in completed_teams
-        completion_pct = 1.0 if completed else team_completion_pct.get(team.id, 0.0)
-
-        points = calculate_points(
-            placement,
-            len(teams),
-            completion_pct,
-            completed,
-            worst_finished_points,
-        )
-
-        # Update team statistics
-        team.total_points += points
-        team.rounds_played += 1
-        if placement == 1:
-            team.rounds_won += 1
-
-        # Calculate time to complete
-        time_to_complete = None
-        completed_at = None
-        if completed:
-            team_game = db.get(Game, team.game_id)
-            time_to_complete = int((team_game.completed_at - team_game.started_at).total_seconds())
-            completed_at = team_game.completed_at
-
-        # Create round result
-        round_result = RoundResult(
-            lobby_id=lobby_id,
-            game_id=team.game_id,
-            team_id=team.id,
-            round_number=round_number,
-            placement=placement,
-            points_earned=points,
-            completion_percentage=completion_pct,
-            time_to_complete=time_to_complete,
-            completed_at=completed_at,
-        )
-        db.add(round_result)
-
-        api_logger.info(
-            f"Round {round_number} result: team={team.name} placement={placement} "
-            f"points={points} completed={completed} completion_pct={completion_pct:.2%}"
-        )
+    round_number = record_round_results(db, lobby_id, teams, puzzle_length)
 
     # Clear timer fields from all active games (the poller will skip these)
     # When manually ending, we clear the timer fields so the poller knows not to process them
@@ -417,6 +1244,7 @@ async def end_game(
     for player in all_players:
         player.is_ready = False
         db.add(player)
+    bump_roster_version(db, lobby_id)
 
     db.commit()
 
@@ -458,21 +1286,30 @@ async def end_game(
     db.commit()
 
     # Broadcast round ended event
-    await lobby_websocket_manager.broadcast_to_lobby(
+    delivery = await lobby_websocket_manager.broadcast_to_lobby(
         lobby_id,
         RoundEndedEvent(lobby_id=lobby_id, round_number=round_number),
     )
+    finished = game_finished_event(db, lobby_id, round_number)
+    await lobby_websocket_manager.broadcast_to_lobby(lobby_id, finished)
+    await commentate(lobby_id, commentary.round_won(round_number, finished.winning_team_name))
 
     # Broadcast new round started event
     await lobby_websocket_manager.broadcast_to_lobby(
         lobby_id,
         NewRoundStartedEvent(lobby_id=lobby_id, game_id=new_game.id, round_number=round_number + 1),
     )
+    phase = await begin_intermission(db, lobby_id, round_number)
+    await announce_phase_change(lobby_id, LobbyPhase.IN_PROGRESS, phase)
 
     api_logger.info(
         f"Successfully ended round {round_number} for lobby_id={lobby_id}. New Game created: game_id={new_game.id}"
     )
-    return MessageResponse(status=True, message=f"Round {round_number} ended for lobby {lobby.name}")
+    return BroadcastMessageResponse(
+        status=True,
+        message=f"Round {round_number} ended for lobby {lobby.name}",
+        delivery=DeliverySummary.of(delivery),
+    )
 
 
 @router.get("/lobby/{lobby_id}/round-results/{round_number}", response_model=list[RoundResult])
@@ -488,7 +1325,7 @@ async def get_round_results(
     lobby = db.get(Lobby, lobby_id)
     if not lobby:
         api_logger.warning(f"Lobby not found lobby_id={lobby_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
     results = db.exec(
         select(RoundResult)
@@ -520,7 +1357,7 @@ async def get_all_rounds(
     lobby = db.get(Lobby, lobby_id)
     if not lobby:
         api_logger.warning(f"Lobby not found lobby_id={lobby_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
     # Get all unique round numbers and their game_ids
     # Group by round_number to get one game_id per round (teams share rounds but may have different games)
@@ -635,21 +1472,21 @@ async def start_timer(
     # Validate timer duration
     if request.duration_minutes < 0 or request.duration_seconds < 0:
         api_logger.warning(f"Invalid timer duration: {request.duration_minutes}min {request.duration_seconds}sec")
-        raise HTTPException(status_code=400, detail="Timer duration must be non-negative")
+        raise InvalidRequestError("Timer duration must be non-negative")
 
     if request.duration_minutes == 0 and request.duration_seconds == 0:
         api_logger.warning("Invalid timer duration: 0 minutes 0 seconds")
-        raise HTTPException(status_code=400, detail="Timer duration must be greater than 0")
+        raise InvalidRequestError("Timer duration must be greater than 0")
 
     if request.duration_minutes > 60:
         api_logger.warning(f"Timer duration too long: {request.duration_minutes} minutes")
-        raise HTTPException(status_code=400, detail="Timer duration must be 60 minutes or less")
+        raise InvalidRequestError("Timer duration must be 60 minutes or less")
 
     # Check if lobby exists
     lobby = db.get(Lobby, lobby_id)
     if not lobby:
         api_logger.warning(f"Start timer failed: lobby not found lobby_id={lobby_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
     # Get active games (games not yet completed)
     active_games = db.exec(
@@ -658,12 +1495,12 @@ async def start_timer(
 
     if not active_games:
         api_logger.warning(f"Start timer failed: no active game lobby_id={lobby_id}")
-        raise HTTPException(status_code=400, detail="No active game to start timer for")
+        raise InvalidRequestError("No active game to start timer for")
 
     # Check if timer is already running
     if any(game.timer_started_at is not None for game in active_games):
         api_logger.warning(f"Start timer failed: timer already running lobby_id={lobby_id}")
-        raise HTTPException(status_code=400, detail="Timer is already running")
+        raise InvalidRequestError("Timer is already running")
 
     # Check that at least one team has completed
     teams = db.exec(select(Team).where(Team.lobby_id == lobby_id)).all()
@@ -679,7 +1516,7 @@ async def start_timer(
 
     if len(completed_teams) == 0:
         api_logger.warning(f"Start timer failed: no teams have completed yet lobby_id={lobby_id}")
-        raise HTTPException(status_code=400, detail="Cannot start timer: no teams have completed yet")
+        raise InvalidRequestError("Cannot start timer: no teams have completed yet")
 
     # Set timer on all active games
     timer_started_at = datetime.now(timezone.utc)
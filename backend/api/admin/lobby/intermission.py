@@ -0,0 +1,55 @@
+from fastapi import APIRouter, Depends
+from sqlmodel import Session
+
+from backend.api.game import StartGameResponse
+from backend.custom_logging import api_logger
+from backend.database import Lobby, get_session
+from backend.dependencies import check_admin_token
+from backend.errors import InvalidRequestError, NotFoundError
+from backend.intermission import advance, end_intermission
+from backend.schemas import IntermissionUpdate, MessageResponse, NextRoundRequest
+
+router = APIRouter(dependencies=[Depends(check_admin_token)])
+
+
+@router.put("/lobby/{lobby_id}/intermission", response_model=MessageResponse)
+async def update_intermission(lobby_id: int, update: IntermissionUpdate, db: Session = Depends(get_session)):
+    """
+    Hold the lobby in intermission after each round, optionally advancing on its own after `seconds`.
+
+    Turning it off during an intermission ends it, leaving the lobby finished.
+    """
+    api_logger.info(f"Admin requested intermission change: lobby_id={lobby_id} {update.model_dump()}")
+    if update.seconds is not None and not 1 <= update.seconds <= 3600:
+        raise InvalidRequestError("Intermission must be between 1 second and 60 minutes")
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Intermission change failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    lobby.intermission_enabled = update.enabled
+    lobby.intermission_seconds = update.seconds
+    db.add(lobby)
+    db.commit()
+
+    if not update.enabled:
+        await end_intermission(db, lobby)
+        message = "Intermission turned off"
+    elif update.seconds:
+        message = f"Intermission on, advancing after {update.seconds} seconds"
+    else:
+        message = "Intermission on, waiting for the admin to advance"
+    api_logger.info(f"Intermission for lobby_id={lobby_id}: {message}")
+    return MessageResponse(status=True, message=message)
+
+
+@router.post("/lobby/{lobby_id}/next-round", response_model=StartGameResponse)
+async def next_round(
+    lobby_id: int,
+    request: NextRoundRequest | None = None,
+    db: Session = Depends(get_session),
+):
+    """Leave intermission by starting the next round, like the last one unless the body says otherwise."""
+    request = request or NextRoundRequest()
+    api_logger.info(f"Admin requested next round: lobby_id={lobby_id} {request.model_dump()}")
+    return await advance(db, lobby_id, request)
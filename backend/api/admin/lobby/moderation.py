@@ -0,0 +1,278 @@
+from fastapi import APIRouter, Depends, Query
+from pydantic import BaseModel
+from sqlmodel import Session, func, select
+
+from backend.api.game import apply_correct_guess, broadcast_team_state, get_team_state_machine, save_game_state
+from backend.api.lobby import guess_result
+from backend.api.lobby_phase import announce_phase_change, get_lobby_phase
+from backend.custom_logging import api_logger
+from backend.database import Game, Lobby, Player, Team, get_session
+from backend.database.models import Guess, RoundResult
+from backend.dependencies import check_admin_token
+from backend.errors import ConflictError, InvalidRequestError, NotFoundError
+from backend.game.puzzles import get_puzzle_manager
+from backend.schemas import LobbyGuessEntry, LobbyGuessHistoryResponse, MessageResponse
+from backend.websocket.events import GuessReviewedEvent, WordRolledBackEvent
+from backend.websocket.managers import lobby_websocket_manager
+
+router = APIRouter(dependencies=[Depends(check_admin_token)])
+
+
+class PendingGuessInfo(BaseModel):
+    guess_id: int
+    team_id: int
+    team_name: str
+    player_name: str
+    word_index: int
+    clue: str | None
+    example_answer: str
+    guess: str
+    created_at: str
+
+
+def get_pending_guess(guess_id: int, db: Session) -> tuple[Guess, Team, Game]:
+    guess = db.get(Guess, guess_id)
+    if not guess or guess.review_status != "pending":
+        api_logger.warning(f"Guess review failed: no pending guess guess_id={guess_id}")
+        raise NotFoundError("Pending guess not found")
+
+    team = db.get(Team, guess.team_id)
+    game = db.get(Game, guess.game_id)
+    if not team or not game or team.game_id != game.id or game.completed_at is not None:
+        api_logger.warning(f"Guess review failed: round is no longer active guess_id={guess_id}")
+        raise InvalidRequestError("The round this guess belongs to is no longer active")
+    return guess, team, game
+
+
+async def reject_guess(guess: Guess, team: Team, db: Session):
+    guess.review_status = "rejected"
+    db.add(guess)
+    db.commit()
+    await lobby_websocket_manager.broadcast_to_team(
+        team.lobby_id,
+        team.id,
+        GuessReviewedEvent(
+            team_id=team.id, guess_id=guess.id, word_index=guess.word_index, guess=guess.guess, approved=False
+        ),
+    )
+
+
+@router.get("/lobby/{lobby_id}/pending-guesses", response_model=list[PendingGuessInfo])
+async def list_pending_guesses(lobby_id: int, db: Session = Depends(get_session)):
+    """Answers to open-ended rungs waiting for the host, oldest first."""
+    api_logger.info(f"Admin requested pending guesses: lobby_id={lobby_id}")
+    rows = db.exec(
+        select(Guess, Team, Player, Game)
+        .join(Team, Guess.team_id == Team.id)
+        .join(Player, Guess.player_id == Player.id)
+        .join(Game, Guess.game_id == Game.id)
+        .where(Team.lobby_id == lobby_id)
+        .where(Guess.review_status == "pending")
+        .where(Game.completed_at.is_(None))
+        .order_by(Guess.created_at)
+    ).all()
+
+    puzzle_manager = get_puzzle_manager()
+    pending = []
+    for guess, team, player, game in rows:
+        step = puzzle_manager.load_puzzle_by_path(game.puzzle_path).ladder[guess.word_index]
+        pending.append(
+            PendingGuessInfo(
+                guess_id=guess.id,
+                team_id=team.id,
+                team_name=team.name,
+                player_name=player.name,
+                word_index=guess.word_index,
+                clue=step.clue,
+                example_answer=step.word,
+                guess=guess.guess,
+                created_at=guess.created_at.isoformat(),
+            )
+        )
+
+    api_logger.info(f"Returning {len(pending)} pending guesses for lobby_id={lobby_id}")
+    return pending
+
+
+@router.get("/lobby/{lobby_id}/guesses", response_model=LobbyGuessHistoryResponse)
+async def list_lobby_guesses(
+    lobby_id: int,
+    team_id: int | None = None,
+    player_id: int | None = None,
+    game_id: int | None = None,
+    page: int = Query(default=1, ge=1),
+    page_size: int = Query(default=100, ge=1, le=500),
+    db: Session = Depends(get_session),
+):
+    """
+    Every guess made in the lobby across all rounds, newest first, with who made it.
+
+    For auditing who solved what after a game; filter by team, player or a team's puzzle (game_id).
+    """
+    api_logger.info(
+        f"Admin requested lobby guesses: lobby_id={lobby_id} team_id={team_id} player_id={player_id} "
+        f"game_id={game_id} page={page} page_size={page_size}"
+    )
+    if not db.get(Lobby, lobby_id):
+        api_logger.warning(f"Lobby guesses failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    conditions = [Team.lobby_id == lobby_id]
+    if team_id is not None:
+        conditions.append(Guess.team_id == team_id)
+    if player_id is not None:
+        conditions.append(Guess.player_id == player_id)
+    if game_id is not None:
+        conditions.append(Guess.game_id == game_id)
+
+    total = db.exec(select(func.count(Guess.id)).join(Team, Guess.team_id == Team.id).where(*conditions)).one()
+    rows = db.exec(
+        select(Guess, Team.name, Player.name)
+        .join(Team, Guess.team_id == Team.id)
+        .join(Player, Guess.player_id == Player.id)
+        .where(*conditions)
+        .order_by(Guess.created_at.desc(), Guess.id.desc())
+        .offset((page - 1) * page_size)
+        .limit(page_size)
+    ).all()
+
+    guesses = [
+        LobbyGuessEntry(
+            id=guess.id,
+            word_index=guess.word_index,
+            direction=guess.direction,
+            guess=guess.guess,
+            result=guess_result(guess),
+            player_id=guess.player_id,
+            player_name=player_name,
+            created_at=guess.created_at,
+            team_id=guess.team_id,
+            team_name=team_name,
+            game_id=guess.game_id,
+        )
+        for guess, team_name, player_name in rows
+    ]
+    api_logger.info(f"Returning {len(guesses)} of {total} guesses for lobby_id={lobby_id}")
+    return LobbyGuessHistoryResponse(
+        page=page, page_size=page_size, total=total, has_more=page * page_size < total, guesses=guesses
+    )
+
+
+@router.post("/lobby/guess/{guess_id}/approve", response_model=MessageResponse)
+async def approve_guess(guess_id: int, db: Session = Depends(get_session)):
+    api_logger.info(f"Admin requested guess approval: guess_id={guess_id}")
+    guess, team, game = get_pending_guess(guess_id, db)
+    player = db.get(Player, guess.player_id)
+
+    machine = get_team_state_machine(team, game)
+    result = machine.approve_answer(guess.guess, guess.word_index)
+
+    if result.already_solved:
+        # A teammate's answer for this rung was approved first
+        await reject_guess(guess, team, db)
+        api_logger.info(f"Guess approval skipped, rung already solved: guess_id={guess_id}")
+        return MessageResponse(status=False, message="This rung was already solved; the answer was rejected")
+
+    guess.review_status = "approved"
+    guess.is_correct = True
+    db.add(guess)
+
+    await lobby_websocket_manager.broadcast_to_team(
+        team.lobby_id,
+        team.id,
+        GuessReviewedEvent(
+            team_id=team.id, guess_id=guess.id, word_index=guess.word_index, guess=guess.guess, approved=True
+        ),
+    )
+    await apply_correct_guess(team.lobby_id, team, player, game, result, db, lobby_websocket_manager)
+
+    # Other answers queued for the same rung no longer matter
+    superseded = db.exec(
+        select(Guess)
+        .where(Guess.game_id == game.id)
+        .where(Guess.team_id == team.id)
+        .where(Guess.word_index == guess.word_index)
+        .where(Guess.review_status == "pending")
+    ).all()
+    for other in superseded:
+        await reject_guess(other, team, db)
+
+    db.commit()
+    api_logger.info(f"Approved guess_id={guess_id} for team_id={team.id} word_index={guess.word_index}")
+    return MessageResponse(status=True, message=f"Approved '{guess.guess}' for {team.name}")
+
+
+@router.post("/lobby/guess/{guess_id}/reject", response_model=MessageResponse)
+async def reject_pending_guess(guess_id: int, db: Session = Depends(get_session)):
+    api_logger.info(f"Admin requested guess rejection: guess_id={guess_id}")
+    guess, team, _ = get_pending_guess(guess_id, db)
+    await reject_guess(guess, team, db)
+    api_logger.info(f"Rejected guess_id={guess_id} for team_id={team.id}")
+    return MessageResponse(status=True, message=f"Rejected '{guess.guess}' for {team.name}")
+
+
+@router.post("/lobby/team/{team_id}/rollback-word", response_model=MessageResponse)
+async def rollback_last_word(team_id: int, db: Session = Depends(get_session)):
+    """
+    Un-solve the team's most recently solved rung, for when a rung was accepted against a typo in the answer key.
+
+    The solving guess stops counting, the team's progress (and so its standing) drops by one rung, and a finished
+    team is put back in play. Only possible until the round is scored.
+    """
+    api_logger.info(f"Admin requested word rollback: team_id={team_id}")
+    team = db.get(Team, team_id)
+    if not team:
+        api_logger.warning(f"Word rollback failed: team not found team_id={team_id}")
+        raise NotFoundError("Team not found")
+
+    game = db.get(Game, team.game_id) if team.game_id else None
+    if not game or not game.puzzle_path:
+        api_logger.warning(f"Word rollback failed: team_id={team_id} has no puzzle this round")
+        raise InvalidRequestError("This team has no puzzle this round")
+    if db.exec(select(RoundResult.id).where(RoundResult.game_id == game.id)).first():
+        api_logger.warning(f"Word rollback failed: round already scored game_id={game.id}")
+        raise ConflictError("This round has already been scored")
+
+    machine = get_team_state_machine(team, game)
+    solves = db.exec(
+        select(Guess)
+        .where(Guess.game_id == game.id)
+        .where(Guess.team_id == team.id)
+        .where(Guess.is_correct.is_(True))
+        .order_by(Guess.created_at.desc(), Guess.id.desc())
+    ).all()
+    solve = next((guess for guess in solves if guess.word_index in machine.state.revealed_steps), None)
+    previous_state = machine.get_current_state()
+    new_state = machine.rollback(solve.word_index) if solve else None
+    if not new_state:
+        api_logger.warning(f"Word rollback failed: no solved rung for team_id={team_id}")
+        raise InvalidRequestError("This team hasn't solved any rungs yet")
+
+    previous_phase = get_lobby_phase(db, team.lobby_id)
+    solve.is_correct = False
+    if solve.review_status == "approved":
+        solve.review_status = "rejected"
+    db.add(solve)
+    game.completed_at = None
+    save_game_state(game, new_state, db)
+
+    lobby = db.get(Lobby, team.lobby_id)
+    word = get_puzzle_manager().load_puzzle_by_path(game.puzzle_path).ladder[solve.word_index].word
+    rolled_back_event = WordRolledBackEvent(
+        team_id=team.id, word_index=solve.word_index, word=word, revealed_count=len(new_state.revealed_steps)
+    )
+    await lobby_websocket_manager.broadcast_to_team(team.lobby_id, team.id, rolled_back_event)
+    await lobby_websocket_manager.admin_web_socket_manager.broadcast_to_lobby(team.lobby_id, rolled_back_event)
+    await broadcast_team_state(
+        team.lobby_id,
+        team,
+        game,
+        new_state,
+        lobby.progress_visibility,
+        lobby_websocket_manager,
+        previous_state=previous_state,
+    )
+    await announce_phase_change(team.lobby_id, previous_phase, get_lobby_phase(db, team.lobby_id))
+
+    api_logger.info(f"Rolled back word_index={solve.word_index} for team_id={team_id} (guess_id={solve.id})")
+    return MessageResponse(status=True, message=f"Rolled back '{word}' for {team.name}")
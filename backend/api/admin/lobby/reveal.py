@@ -0,0 +1,102 @@
+from datetime import datetime, timezone
+
+from fastapi import APIRouter, Depends
+from pydantic import BaseModel, Field
+from sqlmodel import Session, select
+
+from backend.api.lobby_phase import game_in_progress_error, get_lobby_phase
+from backend.custom_logging import api_logger
+from backend.database import Game, Lobby, Team, get_session
+from backend.dependencies import check_admin_token
+from backend.errors import ConflictError, ErrorCode, NotFoundError
+from backend.game.lobby_state import LobbyPhase
+from backend.game.puzzles import Puzzle, get_puzzle_manager
+from backend.game.reveal import RevealSession, build_reveal_steps
+from backend.reveal import advance_reveal, end_reveal, reveal_store
+from backend.schemas import MessageResponse
+
+router = APIRouter(dependencies=[Depends(check_admin_token)])
+
+
+class StartRevealRequest(BaseModel):
+    # Seconds each rung stays up before the next; leave unset to step through by hand
+    interval_seconds: float | None = Field(default=None, ge=1, le=120)
+
+
+class RevealStatusResponse(BaseModel):
+    lobby_id: int
+    step_number: int
+    total_steps: int
+    finished: bool  # The last step is on screen; advancing again ends the reveal
+    active: bool  # False once the reveal has ended
+    interval_seconds: float | None
+
+
+def reveal_status(lobby_id: int, session: RevealSession) -> RevealStatusResponse:
+    return RevealStatusResponse(
+        lobby_id=lobby_id,
+        step_number=session.position + 1,
+        total_steps=len(session.steps),
+        finished=session.finished,
+        active=reveal_store.get(lobby_id) is session,
+        interval_seconds=session.interval_seconds,
+    )
+
+
+def load_round_puzzles(db: Session, lobby_id: int) -> list[Puzzle]:
+    """The puzzles the lobby's teams played last round, in a stable order; ones that no longer load are skipped."""
+    puzzle_paths = db.exec(
+        select(Game.puzzle_path).join(Team, Team.game_id == Game.id).where(Team.lobby_id == lobby_id).distinct()
+    ).all()
+    puzzle_manager = get_puzzle_manager()
+    puzzles = []
+    for puzzle_path in sorted(path for path in puzzle_paths if path):
+        try:
+            puzzles.append(puzzle_manager.load_puzzle_by_path(puzzle_path))
+        except ValueError:
+            api_logger.warning(f"Leaving a puzzle that no longer loads out of the reveal: puzzle_path={puzzle_path}")
+    return puzzles
+
+
+@router.post("/lobby/{lobby_id}/reveal", response_model=RevealStatusResponse)
+async def start_reveal(lobby_id: int, request: StartRevealRequest, db: Session = Depends(get_session)):
+    """
+    Walk the whole lobby through last round's answers, one rung at a time.
+
+    Sends the first step straight away; starting again restarts the reveal from the top.
+    """
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        raise NotFoundError("Lobby not found")
+    phase = get_lobby_phase(db, lobby_id)
+    if phase == LobbyPhase.IN_PROGRESS:
+        raise game_in_progress_error(db, lobby, "reveal the answers")
+    puzzles = load_round_puzzles(db, lobby_id) if phase in (LobbyPhase.FINISHED, LobbyPhase.INTERMISSION) else []
+    if not puzzles:
+        raise ConflictError("There's no finished round to reveal", code=ErrorCode.NO_REVEAL)
+
+    session = RevealSession(steps=build_reveal_steps(puzzles), interval_seconds=request.interval_seconds)
+    reveal_store.start(lobby_id, session)
+    api_logger.info(
+        f"Answer reveal started: lobby_id={lobby_id} steps={len(session.steps)} "
+        f"interval_seconds={request.interval_seconds}"
+    )
+    await advance_reveal(lobby_id, datetime.now(timezone.utc))
+    return reveal_status(lobby_id, session)
+
+
+@router.post("/lobby/{lobby_id}/reveal/next", response_model=RevealStatusResponse)
+async def next_reveal_step(lobby_id: int):
+    """Show the next rung now, even on a timed reveal; after the last one this ends the reveal."""
+    session = reveal_store.get(lobby_id)
+    if session is None:
+        raise ConflictError("No answer reveal is running", code=ErrorCode.NO_REVEAL)
+    await advance_reveal(lobby_id, datetime.now(timezone.utc))
+    return reveal_status(lobby_id, session)
+
+
+@router.delete("/lobby/{lobby_id}/reveal", response_model=MessageResponse)
+async def stop_reveal(lobby_id: int):
+    if not await end_reveal(lobby_id, completed=False):
+        raise ConflictError("No answer reveal is running", code=ErrorCode.NO_REVEAL)
+    return MessageResponse(status=True, message="Answer reveal stopped")
@@ -1,24 +1,57 @@
-import random
+from collections import Counter
 
-from fastapi import APIRouter, Depends, HTTPException
+from fastapi import APIRouter, Depends, Query
+from sqlalchemy.exc import SQLAlchemyError
 from sqlalchemy.orm import selectinload
 from sqlmodel import Session, select
 
+from backend.api.lobby_phase import ensure_no_game_in_progress
 from backend.custom_logging import api_logger
-from backend.database import Game, Lobby, Player, Team, get_session
-from backend.dependencies import check_admin_token
-from backend.schemas import MessageResponse, TeamCreate, TeamUpdate
-from backend.utils.name_generator import generate_multiple_team_names
-from backend.websocket.events import TeamAssignedEvent, TeamChangedEvent
+from backend.admin_sessions import AdminSession
+from backend.database import Lobby, Player, Team, get_session
+from backend.database.models import ScoreAdjustment
+from backend.dependencies import check_admin_token, current_admin_session
+from backend.errors import ConflictError, DatabaseError, InvalidRequestError, NotFoundError
+from backend.game.rng import seeded_rng
+from backend.roster import bump_roster_version, in_join_order
+from backend.schemas import (
+    MessageResponse,
+    ScoreAdjustRequest,
+    TeamAssignmentsUpdate,
+    TeamColorUpdate,
+    TeamCreate,
+    TeamPatch,
+    TeamUpdate,
+)
+from backend.settings import settings
+from backend.utils.name_generator import TEAM_NAME_THEMES, generate_multiple_team_names, generate_themed_team_names
+from backend.utils.team_colors import is_hex_color, pick_team_colors
+from backend.websocket.events import (
+    PlayerTeamMove,
+    ScoreAdjustedEvent,
+    TeamAssignedEvent,
+    TeamChangedEvent,
+    TeamsReassignedEvent,
+    TeamUpdatedEvent,
+)
 from backend.websocket.managers import lobby_websocket_manager
 
 router = APIRouter(dependencies=[Depends(check_admin_token)])
 
 MAX_TEAMS_PER_LOBBY = 10
+MAX_TEAM_NAME_LENGTH = 40
+MAX_SCORE_ADJUSTMENT = 1000
+MAX_ADJUSTMENT_REASON_LENGTH = 200
 
 
-def generate_unique_team_name(existing_names: set[str]) -> str:
+def generate_unique_team_name(existing_names: set[str], theme: str | None = None) -> str:
     """Generate a team name that is unlikely to collide with existing names."""
+    if theme:
+        try:
+            return generate_themed_team_names(theme, 1, existing_names=existing_names)[0]
+        except ValueError:
+            api_logger.warning(f"Team name theme '{theme}' exhausted; falling back to random names")
+
     # Try a few times to avoid duplicates
     for _ in range(5):
         candidate = generate_multiple_team_names(1)[0]
@@ -28,6 +61,69 @@ def generate_unique_team_name(existing_names: set[str]) -> str:
     return generate_multiple_team_names(1)[0]
 
 
+async def apply_team_update(db: Session, team_id: int, name: str | None, color: str | None) -> Team:
+    """Rename and/or recolor a team and tell the lobby with team_updated; fields left as None are kept."""
+    if name is not None:
+        name = name.strip()
+        if not name:
+            raise InvalidRequestError("Team name must not be empty")
+        if len(name) > MAX_TEAM_NAME_LENGTH:
+            raise InvalidRequestError(f"Team name must be at most {MAX_TEAM_NAME_LENGTH} characters")
+    if color is not None and not is_hex_color(color):
+        raise InvalidRequestError("Color must be a hex color like #1A2B3C")
+
+    team = db.get(Team, team_id)
+    if not team:
+        api_logger.warning(f"Team update failed: team not found team_id={team_id}")
+        raise NotFoundError("Team not found")
+    if name is not None and name != team.name:
+        clash = db.exec(select(Team.id).where(Team.lobby_id == team.lobby_id, Team.name == name)).first()
+        if clash is not None:
+            raise ConflictError(f"Another team in this lobby is already called '{name}'")
+
+    old_name, old_color = team.name, team.color
+    if name is not None:
+        team.name = name
+    if color is not None:
+        team.color = color.upper()
+    try:
+        db.add(team)
+        roster_version = bump_roster_version(db, team.lobby_id)
+        db.commit()
+        db.refresh(team)
+    except SQLAlchemyError:
+        db.rollback()
+        api_logger.exception(f"Failed to update team_id={team_id}")
+        raise DatabaseError("Failed to update team")
+
+    await lobby_websocket_manager.broadcast_to_lobby(
+        lobby_id=team.lobby_id,
+        event=TeamUpdatedEvent(
+            lobby_id=team.lobby_id,
+            player_session_id="",
+            team_id=team.id,
+            team_name=team.name,
+            team_color=team.color,
+            roster_version=roster_version,
+        ),
+    )
+    api_logger.info(
+        f"Updated team_id={team_id}: name '{old_name}' -> '{team.name}', color {old_color} -> {team.color}"
+    )
+    return team
+
+
+@router.patch("/lobby/team/{team_id}", response_model=Team)
+async def patch_team(
+    team_id: int,
+    team_patch: TeamPatch,
+    db: Session = Depends(get_session),
+):
+    """Personalize a team's auto-generated name and/or its palette color; only the fields sent are changed."""
+    api_logger.info(f"Admin requested team update: team_id={team_id} name={team_patch.name} color={team_patch.color}")
+    return await apply_team_update(db, team_id, team_patch.name, team_patch.color)
+
+
 @router.put("/lobby/team/{team_id}/name", response_model=MessageResponse)
 async def update_team_name(
     team_id: int,
@@ -35,26 +131,20 @@ async def update_team_name(
     db: Session = Depends(get_session),
 ):
     api_logger.info(f"Admin requested team name update: team_id={team_id} new_name={team_update.name}")
+    team = await apply_team_update(db, team_id, team_update.name, None)
+    return MessageResponse(status=True, message=f"Team name updated to '{team.name}'")
 
-    team = db.get(Team, team_id)
-    if not team:
-        api_logger.warning(f"Team name update failed: team not found team_id={team_id}")
-        raise HTTPException(status_code=404, detail="Team not found")
-
-    old_name = team.name
-    lobby_id = team.lobby_id
-    team.name = team_update.name
-    db.add(team)
-    db.commit()
-
-    # Broadcast team name change to all players in the lobby
-    await lobby_websocket_manager.broadcast_to_lobby(
-        lobby_id=lobby_id,
-        event=TeamAssignedEvent(lobby_id=lobby_id, player_session_id=""),
-    )
 
-    api_logger.info(f"Successfully updated team_id={team_id} name from '{old_name}' to '{team_update.name}'")
-    return MessageResponse(status=True, message=f"Team name updated to '{team_update.name}'")
+@router.put("/lobby/team/{team_id}/color", response_model=MessageResponse)
+async def update_team_color(
+    team_id: int,
+    color_update: TeamColorUpdate,
+    db: Session = Depends(get_session),
+):
+    """Override the color the team was given from the palette; any "#RRGGBB" is allowed."""
+    api_logger.info(f"Admin requested team color update: team_id={team_id} color={color_update.color}")
+    team = await apply_team_update(db, team_id, None, color_update.color)
+    return MessageResponse(status=True, message=f"Team color updated to {team.color}")
 
 
 @router.put("/lobby/team/{team_id}/player/{player_id}", response_model=MessageResponse)
@@ -68,16 +158,19 @@ async def move_player_to_team(
     player = db.get(Player, player_id)
     if not player:
         api_logger.warning(f"Player move failed: player not found player_id={player_id}")
-        raise HTTPException(status_code=404, detail="Player not found")
+        raise NotFoundError("Player not found")
+
+    ensure_no_game_in_progress(db, db.get(Lobby, player.lobby_id), "move players between teams")
 
+    team = None
     if team_id == 0:
         team_id = None
     else:
         team = db.get(Team, team_id)
         if not team:
-            raise HTTPException(status_code=404, detail="Team not found")
+            raise NotFoundError("Team not found")
         if team.lobby_id != player.lobby_id:
-            raise HTTPException(status_code=400, detail="Team is not in the same lobby as player")
+            raise InvalidRequestError("Team is not in the same lobby as player")
 
     old_team_id = player.team_id or 0
     player.team_id = team_id
@@ -96,13 +189,9 @@ async def move_player_to_team(
             p.is_ready = False
             db.add(p)
 
+    roster_version = bump_roster_version(db, player.lobby_id)
     db.commit()
 
-    if team_id:
-        lobby_websocket_manager.register_player_team(player.session_id, team_id)
-    else:
-        lobby_websocket_manager.unregister_player_team(player.session_id)
-
     await lobby_websocket_manager.broadcast_to_lobby(
         lobby_id=player.lobby_id,
         event=TeamChangedEvent(
@@ -110,6 +199,8 @@ async def move_player_to_team(
             player_session_id=player.session_id,
             old_team_id=old_team_id,
             new_team_id=team_id or 0,
+            new_team_color=team.color if team else None,
+            roster_version=roster_version,
         ),
     )
 
@@ -117,6 +208,90 @@ async def move_player_to_team(
     return MessageResponse(status=True, message="Player moved successfully")
 
 
+@router.put("/lobby/{lobby_id}/assignments", response_model=MessageResponse)
+async def update_team_assignments(
+    lobby_id: int,
+    update: TeamAssignmentsUpdate,
+    db: Session = Depends(get_session),
+):
+    """
+    Move many players at once, e.g. after drag-and-drop in the admin panel: every move is applied in one transaction
+    and the lobby gets a single teams_reassigned event instead of a team_changed per player.
+    """
+    api_logger.info(f"Admin requested bulk team assignment: lobby_id={lobby_id} count={len(update.assignments)}")
+    if not update.assignments:
+        raise InvalidRequestError("No assignments given")
+
+    lobby = db.exec(
+        select(Lobby).options(selectinload(Lobby.players), selectinload(Lobby.teams)).where(Lobby.id == lobby_id)
+    ).first()
+    if not lobby:
+        api_logger.warning(f"Bulk team assignment failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    ensure_no_game_in_progress(db, lobby, "move players between teams")
+
+    players = {player.id: player for player in lobby.players}
+    teams = {team.id: team for team in lobby.teams}
+    unknown_players = sorted(set(update.assignments) - set(players))
+    if unknown_players:
+        raise InvalidRequestError(f"Players not in this lobby: {', '.join(map(str, unknown_players))}")
+    unknown_teams = sorted({team_id for team_id in update.assignments.values() if team_id} - set(teams))
+    if unknown_teams:
+        raise InvalidRequestError(f"Teams not in this lobby: {', '.join(map(str, unknown_teams))}")
+
+    moves: list[PlayerTeamMove] = []
+    moved_ids: set[int] = set()
+    affected_teams: set[int] = set()
+    try:
+        for player_id, team_id in update.assignments.items():
+            player = players[player_id]
+            old_team_id = player.team_id or 0
+            if old_team_id == team_id:
+                continue
+            player.team_id = team_id or None
+            db.add(player)
+            moved_ids.add(player_id)
+            affected_teams.update({old_team_id, team_id} - {0})
+            moves.append(
+                PlayerTeamMove(
+                    player_session_id=player.session_id,
+                    old_team_id=old_team_id,
+                    new_team_id=team_id,
+                    new_team_color=teams[team_id].color if team_id else None,
+                )
+            )
+
+        # Like single moves, everyone on a team that changed has to ready up again
+        for player in players.values():
+            if player.team_id in affected_teams or player.id in moved_ids:
+                player.is_ready = False
+                db.add(player)
+
+        roster_version = bump_roster_version(db, lobby_id) if moves else lobby.roster_version
+        db.commit()
+    except SQLAlchemyError as e:
+        db.rollback()
+        api_logger.exception(f"Bulk team assignment failed, rolled back: lobby_id={lobby_id} error={e}")
+        raise DatabaseError("Couldn't apply the team assignments, nothing was changed")
+
+    if moves:
+        for move in moves:
+            if move.new_team_id:
+                lobby_websocket_manager.register_player_team(move.player_session_id, move.new_team_id)
+            else:
+                lobby_websocket_manager.unregister_player_team(move.player_session_id)
+        await lobby_websocket_manager.broadcast_to_lobby(
+            lobby_id=lobby_id,
+            event=TeamsReassignedEvent(
+                lobby_id=lobby_id, player_session_id="", moves=moves, roster_version=roster_version
+            ),
+        )
+
+    api_logger.info(f"Bulk team assignment in lobby_id={lobby_id}: moved {len(moves)} players")
+    return MessageResponse(status=True, message=f"Moved {len(moves)} player(s)")
+
+
 @router.post("/lobby/{lobby_id}/team", response_model=MessageResponse)
 async def create_teams(
     lobby_id: int,
@@ -130,42 +305,59 @@ async def create_teams(
     ).first()
     if not lobby:
         api_logger.warning(f"Team creation failed: lobby not found lobby_id={lobby_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
     if lobby.teams:
         api_logger.warning(f"Team creation failed: teams already exist lobby_id={lobby_id}")
-        raise HTTPException(status_code=400, detail="Teams already exist for this lobby")
+        raise InvalidRequestError("Teams already exist for this lobby")
 
     if team_data.num_teams < 2 or team_data.num_teams > MAX_TEAMS_PER_LOBBY:
-        raise HTTPException(status_code=400, detail=f"Number of teams must be between 2 and {MAX_TEAMS_PER_LOBBY}")
+        raise InvalidRequestError(f"Number of teams must be between 2 and {MAX_TEAMS_PER_LOBBY}")
+
+    if team_data.theme is not None and team_data.theme not in TEAM_NAME_THEMES:
+        raise InvalidRequestError(f"Unknown team name theme. Choose one of: {', '.join(sorted(TEAM_NAME_THEMES))}")
 
     players = lobby.players
     if len(players) == 0:
-        raise HTTPException(status_code=400, detail="Cannot create teams with no players")
-
-    # Generate funny names for teams
-    team_names = generate_multiple_team_names(team_data.num_teams)
-
-    teams = []
-    for i in range(team_data.num_teams):
-        team = Team(name=team_names[i], lobby_id=lobby_id)
-        db.add(team)
-        teams.append(team)
+        raise InvalidRequestError("Cannot create teams with no players")
 
-    db.commit()
-
-    teams = db.exec(select(Team).where(Team.lobby_id == lobby_id)).all()
-
-    # Convert to regular list before shuffling (SQLAlchemy collections can't be shuffled directly)
-    players_list = list(players)
-    random.shuffle(players_list)
-    for i, player in enumerate(players_list):
-        team_index = i % team_data.num_teams
-        player.team_id = teams[team_index].id
-        player.is_ready = False  # All start unready
-        db.add(player)
+    # Remember the theme so teams added later match
+    if team_data.theme is not None:
+        lobby.team_name_theme = team_data.theme
+        db.add(lobby)
 
-    db.commit()
+    # Generate themed or funny names for teams
+    if lobby.team_name_theme:
+        team_names = generate_themed_team_names(lobby.team_name_theme, team_data.num_teams)
+    else:
+        team_names = generate_multiple_team_names(team_data.num_teams)
+
+    # Teams and assignments are committed together, so a failure part-way never leaves half-assigned teams
+    try:
+        team_colors = pick_team_colors([], team_data.num_teams, settings.TEAM_COLOR_PALETTE)
+        teams = []
+        for i in range(team_data.num_teams):
+            team = Team(name=team_names[i], color=team_colors[i], lobby_id=lobby_id)
+            db.add(team)
+            teams.append(team)
+        db.flush()
+
+        # Sort before shuffling so the lobby seed alone decides the assignment
+        # (also converts the SQLAlchemy collection, which can't be shuffled directly)
+        players_list = sorted(players, key=lambda p: (p.name, p.id))
+        seeded_rng(lobby.rng_seed, "teams").shuffle(players_list)
+        for i, player in enumerate(players_list):
+            team_index = i % team_data.num_teams
+            player.team_id = teams[team_index].id
+            player.is_ready = False  # All start unready
+            db.add(player)
+
+        roster_version = bump_roster_version(db, lobby_id)
+        db.commit()
+    except SQLAlchemyError as e:
+        db.rollback()
+        api_logger.exception(f"Team creation failed, rolled back: lobby_id={lobby_id} error={e}")
+        raise DatabaseError("Couldn't create teams, nothing was changed")
 
     # Ensure websocket manager knows each player's team for targeted broadcasts
     for player in players_list:
@@ -174,7 +366,7 @@ async def create_teams(
 
     await lobby_websocket_manager.broadcast_to_lobby(
         lobby_id=lobby_id,
-        event=TeamAssignedEvent(lobby_id=lobby_id, player_session_id=player.session_id),
+        event=TeamAssignedEvent(lobby_id=lobby_id, player_session_id=player.session_id, roster_version=roster_version),
     )
 
     api_logger.info(
@@ -183,15 +375,13 @@ async def create_teams(
     return MessageResponse(status=True, message=f"Created {team_data.num_teams} teams with players randomly assigned")
 
 
-def lobby_has_active_game(db: Session, lobby_id: int) -> bool:
-    return db.exec(select(Game).where(Game.lobby_id == lobby_id).where(Game.completed_at.is_(None))).first() is not None
-
-
+@router.post("/lobby/{lobby_id}/team/single", response_model=MessageResponse)
 @router.post("/lobby/{lobby_id}/team/add-one", response_model=MessageResponse)
 async def add_single_team(
     lobby_id: int,
     db: Session = Depends(get_session),
 ):
+    """Add one more (empty) team next to the existing ones, e.g. for late arrivals; add-one is the older path."""
     api_logger.info(f"Admin requested to add a single team: lobby_id={lobby_id}")
 
     lobby = db.exec(
@@ -199,83 +389,342 @@ async def add_single_team(
     ).first()
     if not lobby:
         api_logger.warning(f"Add team failed: lobby not found lobby_id={lobby_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
-    if lobby_has_active_game(db, lobby_id):
-        raise HTTPException(status_code=400, detail="Cannot modify teams while a game is active")
+    ensure_no_game_in_progress(db, lobby, "modify teams")
 
     if len(lobby.teams) >= MAX_TEAMS_PER_LOBBY:
-        raise HTTPException(status_code=400, detail=f"Maximum of {MAX_TEAMS_PER_LOBBY} teams reached")
+        raise InvalidRequestError(f"Maximum of {MAX_TEAMS_PER_LOBBY} teams reached")
 
     existing_names = {team.name for team in lobby.teams}
-    new_team_name = generate_unique_team_name(existing_names)
-    new_team = Team(name=new_team_name, lobby_id=lobby_id)
+    new_team_name = generate_unique_team_name(existing_names, theme=lobby.team_name_theme)
+    [new_team_color] = pick_team_colors([team.color for team in lobby.teams], 1, settings.TEAM_COLOR_PALETTE)
+    new_team = Team(name=new_team_name, color=new_team_color, lobby_id=lobby_id)
     db.add(new_team)
+    roster_version = bump_roster_version(db, lobby_id)
     db.commit()
     db.refresh(new_team)
 
     await lobby_websocket_manager.broadcast_to_lobby(
         lobby_id=lobby_id,
-        event=TeamAssignedEvent(lobby_id=lobby_id, player_session_id=""),
+        event=TeamAssignedEvent(lobby_id=lobby_id, player_session_id="", roster_version=roster_version),
     )
 
     api_logger.info(f"Successfully added team_id={new_team.id} name='{new_team.name}' to lobby_id={lobby_id}")
     return MessageResponse(status=True, message=f"Added new team '{new_team.name}'")
 
 
+@router.post("/lobby/{lobby_id}/team/shuffle", response_model=MessageResponse)
+async def shuffle_teams(
+    lobby_id: int,
+    db: Session = Depends(get_session),
+):
+    """Deal every player out across the lobby's existing teams again, as evenly as create_teams does."""
+    api_logger.info(f"Team shuffle requested: lobby_id={lobby_id}")
+
+    lobby = db.exec(
+        select(Lobby).options(selectinload(Lobby.players), selectinload(Lobby.teams)).where(Lobby.id == lobby_id)
+    ).first()
+    if not lobby:
+        api_logger.warning(f"Team shuffle failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    ensure_no_game_in_progress(db, lobby, "shuffle teams")
+
+    teams = sorted(lobby.teams, key=lambda team: team.id)
+    if len(teams) < 2:
+        raise InvalidRequestError("Create at least two teams before shuffling")
+
+    try:
+        players_list = sorted(lobby.players, key=lambda p: (p.name, p.id))
+        # Each shuffle draws from its own stream, still reproducible from the lobby seed
+        seeded_rng(lobby.rng_seed, f"teams:shuffle:{lobby.roster_version}").shuffle(players_list)
+        for i, player in enumerate(players_list):
+            player.team_id = teams[i % len(teams)].id
+            player.is_ready = False
+            db.add(player)
+
+        roster_version = bump_roster_version(db, lobby_id)
+        db.commit()
+    except SQLAlchemyError as e:
+        db.rollback()
+        api_logger.exception(f"Team shuffle failed, rolled back: lobby_id={lobby_id} error={e}")
+        raise DatabaseError("Couldn't shuffle teams, nothing was changed")
+
+    for player in players_list:
+        lobby_websocket_manager.register_player_team(player.session_id, player.team_id)
+
+    await lobby_websocket_manager.broadcast_to_lobby(
+        lobby_id=lobby_id,
+        event=TeamAssignedEvent(lobby_id=lobby_id, player_session_id="", roster_version=roster_version),
+    )
+
+    api_logger.info(f"Shuffled {len(players_list)} players across {len(teams)} teams in lobby_id={lobby_id}")
+    return MessageResponse(status=True, message=f"Shuffled players across {len(teams)} teams")
+
+
+def balanced_assignment(players: list[Player], team_ids: list[int]) -> dict[int, int]:
+    """
+    Team for each player (by id) so team sizes differ by at most one, moving as few players as possible.
+
+    The biggest teams keep the spare places; players past their team's share move, latest joiners first, and then
+    unassigned players fill the smallest teams in join order. Teams missing from team_ids count as unassigned.
+    """
+    members: dict[int, list[Player]] = {team_id: [] for team_id in team_ids}
+    pool: list[Player] = []
+    for player in in_join_order(players):
+        if player.team_id in members:
+            members[player.team_id].append(player)
+        else:
+            pool.append(player)
+
+    base, extra = divmod(len(players), len(team_ids))
+    by_size = sorted(team_ids, key=lambda team_id: (-len(members[team_id]), team_id))
+    share = {team_id: base + (1 if i < extra else 0) for i, team_id in enumerate(by_size)}
+
+    assignment: dict[int, int] = {}
+    overflow: list[Player] = []
+    for team_id in team_ids:
+        kept = members[team_id][: share[team_id]]
+        overflow.extend(members[team_id][share[team_id] :])
+        assignment.update({player.id: team_id for player in kept})
+        members[team_id] = kept
+
+    for player in in_join_order(overflow) + pool:
+        team_id = min(
+            (team_id for team_id in team_ids if len(members[team_id]) < share[team_id]),
+            key=lambda team_id: (len(members[team_id]), team_id),
+        )
+        members[team_id].append(player)
+        assignment[player.id] = team_id
+    return assignment
+
+
+@router.post("/lobby/{lobby_id}/team/rebalance", response_model=MessageResponse)
+async def rebalance_teams(
+    lobby_id: int,
+    shuffle: bool = Query(default=False, description="Deal everyone out again instead of moving as few as possible"),
+    db: Session = Depends(get_session),
+):
+    """
+    Even out the existing teams and place unassigned players, e.g. when half the lobby arrived after teams were
+    made. Only the players who move get a team_changed event.
+    """
+    api_logger.info(f"Team rebalance requested: lobby_id={lobby_id} shuffle={shuffle}")
+
+    lobby = db.exec(
+        select(Lobby).options(selectinload(Lobby.players), selectinload(Lobby.teams)).where(Lobby.id == lobby_id)
+    ).first()
+    if not lobby:
+        api_logger.warning(f"Team rebalance failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    ensure_no_game_in_progress(db, lobby, "rebalance teams")
+
+    teams = {team.id: team for team in sorted(lobby.teams, key=lambda team: team.id)}
+    if len(teams) < 2:
+        raise InvalidRequestError("Create at least two teams before rebalancing")
+
+    if shuffle:
+        players_list = sorted(lobby.players, key=lambda p: (p.name, p.id))
+        seeded_rng(lobby.rng_seed, f"teams:rebalance:{lobby.roster_version}").shuffle(players_list)
+        team_ids = list(teams)
+        assignment = {player.id: team_ids[i % len(team_ids)] for i, player in enumerate(players_list)}
+    else:
+        assignment = balanced_assignment(list(lobby.players), list(teams))
+
+    moved = [
+        (player, player.team_id) for player in in_join_order(lobby.players) if player.team_id != assignment[player.id]
+    ]
+    try:
+        for player, _ in moved:
+            player.team_id = assignment[player.id]
+            player.is_ready = False
+            db.add(player)
+        roster_version = bump_roster_version(db, lobby_id)
+        db.commit()
+    except SQLAlchemyError as e:
+        db.rollback()
+        api_logger.exception(f"Team rebalance failed, rolled back: lobby_id={lobby_id} error={e}")
+        raise DatabaseError("Couldn't rebalance teams, nothing was changed")
+
+    for player, old_team_id in moved:
+        lobby_websocket_manager.register_player_team(player.session_id, player.team_id)
+        await lobby_websocket_manager.broadcast_to_lobby(
+            lobby_id=lobby_id,
+            event=TeamChangedEvent(
+                lobby_id=lobby_id,
+                player_session_id=player.session_id,
+                old_team_id=old_team_id or 0,
+                new_team_id=player.team_id,
+                new_team_color=teams[player.team_id].color,
+                roster_version=roster_version,
+            ),
+        )
+    await lobby_websocket_manager.broadcast_to_lobby(
+        lobby_id=lobby_id,
+        event=TeamAssignedEvent(lobby_id=lobby_id, player_session_id="", roster_version=roster_version),
+    )
+
+    api_logger.info(f"Rebalanced lobby_id={lobby_id}: moved {len(moved)} players across {len(teams)} teams")
+    return MessageResponse(status=True, message=f"Moved {len(moved)} player(s) to even out {len(teams)} teams")
+
+
 @router.delete("/lobby/team/{team_id}", response_model=MessageResponse)
 async def remove_team(
     team_id: int,
+    reassign: bool = Query(default=False, description="Deal the team's players out to the remaining teams"),
     db: Session = Depends(get_session),
 ):
-    api_logger.info(f"Admin requested to remove team: team_id={team_id}")
+    """Remove a team; its players go back to the lobby unassigned, or with reassign=true onto the smallest teams."""
+    api_logger.info(f"Admin requested to remove team: team_id={team_id} reassign={reassign}")
 
     team = db.get(Team, team_id)
     if not team:
         api_logger.warning(f"Remove team failed: team not found team_id={team_id}")
-        raise HTTPException(status_code=404, detail="Team not found")
+        raise NotFoundError("Team not found")
 
     lobby = db.exec(
         select(Lobby).options(selectinload(Lobby.players), selectinload(Lobby.teams)).where(Lobby.id == team.lobby_id)
     ).first()
     if not lobby:
         api_logger.warning(f"Remove team failed: lobby not found for team_id={team_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
-    if lobby_has_active_game(db, lobby.id):
-        raise HTTPException(status_code=400, detail="Cannot modify teams while a game is active")
+    ensure_no_game_in_progress(db, lobby, "modify teams")
 
     if len(lobby.teams) <= 2:
-        raise HTTPException(status_code=400, detail="At least two teams are required")
+        raise InvalidRequestError("At least two teams are required")
 
-    players_on_team = db.exec(select(Player).where(Player.team_id == team_id)).all()
+    remaining = sorted((other for other in lobby.teams if other.id != team_id), key=lambda other: other.id)
+    team_sizes = Counter(player.team_id for player in lobby.players)
+    players_on_team = in_join_order([player for player in lobby.players if player.team_id == team_id])
+    new_teams: dict[int, Team | None] = {}
     for player in players_on_team:
-        player.team_id = None
+        # Smallest team first, oldest on ties, like late joiners get
+        new_team = min(remaining, key=lambda other: (team_sizes[other.id], other.id)) if reassign else None
+        if new_team:
+            team_sizes[new_team.id] += 1
+        new_teams[player.id] = new_team
+        player.team_id = new_team.id if new_team else None
         player.is_ready = False  # Reset ready status
         db.add(player)
     db.delete(team)
+    roster_version = bump_roster_version(db, lobby.id)
     db.commit()
 
     for player in players_on_team:
-        lobby_websocket_manager.unregister_player_team(player.session_id)
+        new_team = new_teams[player.id]
+        if new_team:
+            lobby_websocket_manager.register_player_team(player.session_id, new_team.id)
+        else:
+            lobby_websocket_manager.unregister_player_team(player.session_id)
         await lobby_websocket_manager.broadcast_to_lobby(
             lobby_id=team.lobby_id,
             event=TeamChangedEvent(
                 lobby_id=team.lobby_id,
                 player_session_id=player.session_id,
                 old_team_id=team_id,
-                new_team_id=0,
+                new_team_id=new_team.id if new_team else 0,
+                new_team_color=new_team.color if new_team else None,
+                roster_version=roster_version,
             ),
         )
 
     await lobby_websocket_manager.broadcast_to_lobby(
         lobby_id=team.lobby_id,
-        event=TeamAssignedEvent(lobby_id=team.lobby_id, player_session_id=""),
+        event=TeamAssignedEvent(lobby_id=team.lobby_id, player_session_id="", roster_version=roster_version),
     )
 
-    api_logger.info(
-        f"Successfully removed team_id={team_id} from lobby_id={team.lobby_id}; unassigned {len(players_on_team)} players"
-    )
+    outcome = f"{'reassigned' if reassign else 'unassigned'} {len(players_on_team)} players"
+    api_logger.info(f"Successfully removed team_id={team_id} from lobby_id={team.lobby_id}; {outcome}")
+    if reassign:
+        return MessageResponse(
+            status=True, message=f"Removed team and moved {len(players_on_team)} player(s) to the other teams"
+        )
     return MessageResponse(
         status=True, message=f"Removed team and unassigned {len(players_on_team)} player(s) back to the lobby"
     )
+
+
+@router.post("/lobby/team/{team_id}/score-adjust", response_model=ScoreAdjustment)
+async def adjust_team_score(
+    team_id: int,
+    request: ScoreAdjustRequest,
+    db: Session = Depends(get_session),
+    admin_session: AdminSession | None = Depends(current_admin_session),
+):
+    """
+    Add (or, with a negative delta, take away) points from a team's tournament total, e.g. for a bonus challenge
+    the host ran away from the game. Each adjustment is kept with its reason for
+    GET /api/admin/lobby/{lobby_id}/score-adjustments.
+    """
+    reason = request.reason.strip()
+    api_logger.info(f"Admin requested score adjustment: team_id={team_id} delta={request.delta} reason={reason!r}")
+    if request.delta == 0:
+        raise InvalidRequestError("Score adjustment must not be 0")
+    if abs(request.delta) > MAX_SCORE_ADJUSTMENT:
+        raise InvalidRequestError(f"Score adjustments are limited to {MAX_SCORE_ADJUSTMENT} points either way")
+    if not reason:
+        raise InvalidRequestError("A reason is required for score adjustments")
+    if len(reason) > MAX_ADJUSTMENT_REASON_LENGTH:
+        raise InvalidRequestError(f"Reason must be at most {MAX_ADJUSTMENT_REASON_LENGTH} characters")
+
+    team = db.get(Team, team_id)
+    if not team:
+        api_logger.warning(f"Score adjustment failed: team not found team_id={team_id}")
+        raise NotFoundError("Team not found")
+
+    team.total_points += request.delta
+    adjustment = ScoreAdjustment(
+        lobby_id=team.lobby_id,
+        team_id=team.id,
+        team_name=team.name,
+        delta=request.delta,
+        reason=reason,
+        total_points=team.total_points,
+        admin_session_id=admin_session.session_id if admin_session else None,
+    )
+    db.add(team)
+    db.add(adjustment)
+    try:
+        db.commit()
+    except SQLAlchemyError as e:
+        db.rollback()
+        api_logger.exception(f"Score adjustment failed for team_id={team_id}: {e}")
+        raise DatabaseError("Failed to adjust score")
+    db.refresh(adjustment)
+
+    await lobby_websocket_manager.broadcast_to_lobby(
+        team.lobby_id,
+        ScoreAdjustedEvent(
+            lobby_id=team.lobby_id,
+            team_id=team.id,
+            team_name=team.name,
+            delta=adjustment.delta,
+            reason=adjustment.reason,
+            total_points=adjustment.total_points,
+        ),
+    )
+
+    api_logger.info(
+        f"Adjusted score for team_id={team_id} by {request.delta} to {team.total_points} "
+        f"(adjustment_id={adjustment.id} admin_session_id={adjustment.admin_session_id})"
+    )
+    return adjustment
+
+
+@router.get("/lobby/{lobby_id}/score-adjustments", response_model=list[ScoreAdjustment])
+async def list_score_adjustments(
+    lobby_id: int,
+    db: Session = Depends(get_session),
+):
+    """Every manual score adjustment in the lobby, oldest first."""
+    api_logger.info(f"Admin requested score adjustments: lobby_id={lobby_id}")
+    if not db.get(Lobby, lobby_id):
+        raise NotFoundError("Lobby not found")
+    return db.exec(
+        select(ScoreAdjustment)
+        .where(ScoreAdjustment.lobby_id == lobby_id)
+        .order_by(ScoreAdjustment.created_at, ScoreAdjustment.id)
+    ).all()
@@ -1,15 +1,18 @@
 """Periodic poller for checking and handling expired timers."""
 
 import asyncio
+import math
 from datetime import datetime, timezone, timedelta
-from typing import Optional
+from typing import Dict, Iterable, Optional, Tuple
 
-from sqlmodel import select
+from sqlmodel import Session, select
 
 from backend.custom_logging import api_logger
 from backend.database import get_session_context
 from backend.database.models import Game
-from backend.websocket.events import TimerExpiredEvent
+from backend.in_memory_state import on_reset
+from backend.settings import settings
+from backend.websocket.events import TimerExpiredEvent, TimerStartedEvent, TimerTickEvent
 from backend.websocket.managers import lobby_websocket_manager
 
 # Global poller task reference
@@ -17,13 +20,64 @@ _poller_task: Optional[asyncio.Task] = None
 _poller_running = False
 
 
+class TimerTicks:
+    """When each lobby's running timer last sent a timer_tick, so ticks go out once every interval."""
+
+    def __init__(self):
+        # lobby_id -> (timer_started_at of the timer ticking, time of its last tick)
+        self.sent: Dict[int, Tuple[datetime, datetime]] = {}
+
+    def due(self, lobby_id: int, started_at: datetime, now: datetime, interval: float) -> bool:
+        """Whether a tick is due, counting from the timer's start until its first tick; records it when it is."""
+        if interval <= 0:
+            return False
+        last = self.sent.get(lobby_id)
+        last_tick = last[1] if last and last[0] == started_at else started_at
+        if (now - last_tick).total_seconds() < interval:
+            return False
+        self.sent[lobby_id] = (started_at, now)
+        return True
+
+    def retain(self, lobby_ids: Iterable[int]):
+        """Drop lobbies whose timer is no longer running."""
+        keep = set(lobby_ids)
+        self.sent = {lobby_id: sent for lobby_id, sent in self.sent.items() if lobby_id in keep}
+
+
+timer_ticks = TimerTicks()
+on_reset(timer_ticks.__init__)
+
+
+async def arm_round_timer(db: Session, lobby_id: int, seconds: int):
+    """Set the round timer on the lobby's active games so the timer poller ends and scores the round."""
+    started_at = datetime.now(timezone.utc)
+    games = db.exec(
+        select(Game).where(Game.lobby_id == lobby_id).where(Game.completed_at.is_(None)).where(Game.puzzle_path != "")
+    ).all()
+    for game in games:
+        game.timer_started_at = started_at
+        game.timer_duration_seconds = seconds
+        db.add(game)
+    db.commit()
+
+    await lobby_websocket_manager.broadcast_to_lobby(
+        lobby_id,
+        TimerStartedEvent(
+            lobby_id=lobby_id,
+            duration_seconds=seconds,
+            started_at=started_at.isoformat(),
+            expires_at=(started_at + timedelta(seconds=seconds)).isoformat(),
+        ),
+    )
+
+
 async def check_expired_timers():
     """
     Check all active games for expired timers and handle them.
 
     This function:
     1. Finds all active games with timers set
-    2. Checks if any have expired
+    2. Sends TIMER_TICK with the time left for running timers every TIMER_TICK_SECONDS
     3. For each expired timer:
        - Broadcasts TIMER_EXPIRED event
        - Calls end_game to finish the round
@@ -43,10 +97,12 @@ async def check_expired_timers():
             ).all()
 
             if not active_games_with_timer:
+                timer_ticks.retain([])
                 return
 
             # Group games by lobby_id to process one per lobby
             lobbies_with_expired_timers = set()
+            running_timers: Dict[int, Tuple[datetime, datetime]] = {}
 
             for game in active_games_with_timer:
                 # Calculate expiry time
@@ -66,6 +122,21 @@ async def check_expired_timers():
                             f"[TIMER_POLLER] Detected expired timer for lobby_id={lobby_id} "
                             f"(expired at {expires_at.isoformat()})"
                         )
+                else:
+                    running_timers[game.lobby_id] = (timer_started, expires_at)
+
+            timer_ticks.retain(running_timers)
+            for lobby_id, (timer_started, expires_at) in running_timers.items():
+                if lobby_id in lobbies_with_expired_timers:
+                    continue
+                if timer_ticks.due(lobby_id, timer_started, now, settings.TIMER_TICK_SECONDS):
+                    remaining_seconds = math.ceil((expires_at - now).total_seconds())
+                    await lobby_websocket_manager.broadcast_to_lobby(
+                        lobby_id,
+                        TimerTickEvent(
+                            lobby_id=lobby_id, remaining_seconds=remaining_seconds, expires_at=expires_at.isoformat()
+                        ),
+                    )
 
             # Process each lobby with expired timer
             for lobby_id in lobbies_with_expired_timers:
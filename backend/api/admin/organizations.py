@@ -0,0 +1,87 @@
+import re
+
+from fastapi import APIRouter, Depends
+from sqlmodel import Session, func, select
+
+from backend.admin_sessions import admin_session_store
+from backend.custom_logging import api_logger
+from backend.database import get_session
+from backend.database.models import Lobby, Organization, Puzzle
+from backend.dependencies import check_owner_token
+from backend.errors import ConflictError, InvalidRequestError, NotFoundError
+from backend.organizations import hash_password
+from backend.schemas import MessageResponse, OrganizationCreate, OrganizationPasswordUpdate, OrganizationResponse
+from backend.websocket.managers import admin_web_socket_manager
+
+router = APIRouter(dependencies=[Depends(check_owner_token)])
+
+SLUG = re.compile(r"^[a-z0-9][a-z0-9-]{1,39}$")
+MIN_PASSWORD_LENGTH = 8
+
+
+def check_password(password: str):
+    if len(password) < MIN_PASSWORD_LENGTH:
+        raise InvalidRequestError(f"Organization passwords must be at least {MIN_PASSWORD_LENGTH} characters")
+
+
+def to_organization_response(db: Session, organization: Organization) -> OrganizationResponse:
+    lobby_count = db.exec(select(func.count(Lobby.id)).where(Lobby.organization_id == organization.id)).one()
+    puzzle_count = db.exec(select(func.count(Puzzle.id)).where(Puzzle.organization_id == organization.id)).one()
+    return OrganizationResponse(
+        id=organization.id,
+        name=organization.name,
+        slug=organization.slug,
+        lobby_count=lobby_count,
+        puzzle_count=puzzle_count,
+        created_at=organization.created_at,
+    )
+
+
+@router.post("/organizations", response_model=OrganizationResponse)
+async def create_organization(organization_data: OrganizationCreate, db: Session = Depends(get_session)):
+    """Add an organization; its admins log in with its slug and password and only see its lobbies and puzzles."""
+    slug = organization_data.slug.strip().lower()
+    api_logger.info(f"Owner creating organization: slug={slug}")
+    if not SLUG.match(slug):
+        raise InvalidRequestError("Slugs are 2-40 lowercase letters, digits or dashes, starting with a letter or digit")
+    if not organization_data.name.strip():
+        raise InvalidRequestError("Organization name must not be empty")
+    check_password(organization_data.password)
+    if db.exec(select(Organization).where(Organization.slug == slug)).first():
+        raise ConflictError(f"An organization with slug {slug} already exists")
+
+    organization = Organization(
+        name=organization_data.name.strip(), slug=slug, password_hash=hash_password(organization_data.password)
+    )
+    db.add(organization)
+    db.commit()
+    db.refresh(organization)
+    api_logger.info(f"Created organization id={organization.id} slug={slug}")
+    return to_organization_response(db, organization)
+
+
+@router.get("/organizations", response_model=list[OrganizationResponse])
+async def list_organizations(db: Session = Depends(get_session)):
+    api_logger.info("Owner requested list of organizations")
+    organizations = db.exec(select(Organization).order_by(Organization.name, Organization.id)).all()
+    return [to_organization_response(db, organization) for organization in organizations]
+
+
+@router.put("/organizations/{organization_id}/password", response_model=MessageResponse)
+async def update_organization_password(
+    organization_id: int, password_data: OrganizationPasswordUpdate, db: Session = Depends(get_session)
+):
+    """Set a new admin password for an organization, logging out every admin still using the old one."""
+    api_logger.info(f"Owner changing organization password: organization_id={organization_id}")
+    organization = db.get(Organization, organization_id)
+    if not organization:
+        raise NotFoundError("Organization not found")
+    check_password(password_data.password)
+
+    organization.password_hash = hash_password(password_data.password)
+    db.add(organization)
+    db.commit()
+    for session_id in admin_session_store.revoke_organization(organization_id):
+        await admin_web_socket_manager.disconnect(session_id, code=1008, reason="Organization password changed")
+    api_logger.info(f"Changed password for organization id={organization_id}")
+    return MessageResponse(status=True, message=f"Password changed for {organization.name}")
@@ -0,0 +1,537 @@
+import asyncio
+import json
+from datetime import datetime, timezone
+from typing import Optional
+
+from fastapi import APIRouter, Depends, Query
+from pydantic import BaseModel
+from sqlalchemy.exc import SQLAlchemyError
+from sqlmodel import Session, func, select
+
+from backend.custom_logging import api_logger
+from backend.database import get_session
+from backend.database.models import Game, Guess, Lobby, Puzzle, RoundResult, Team
+from backend.dependencies import admin_organization_id, check_admin_token
+from backend.errors import (
+    ConflictError,
+    DatabaseError,
+    ErrorCode,
+    InvalidRequestError,
+    NotFoundError,
+    ServiceUnavailableError,
+)
+from backend.features import Feature, feature_flags
+from backend.game.analytics import GuessRecord, aggregate_rung_stats, difficulty_curve
+from backend.game.puzzles import Puzzle as GamePuzzle
+from backend.game.puzzles import PuzzleFile, get_puzzle_manager, stored_puzzle_path
+from backend.game.rng import new_seed, seeded_rng
+from backend.game.simulation import (
+    MAX_TEAM_PLAYS,
+    MAX_TEAMS,
+    SimulationParams,
+    rung_difficulty_from_curve,
+    simulate_game,
+)
+from backend.game.stored_puzzles import record_ladder, to_game_puzzle
+from backend.schemas import MessageResponse, PuzzleCreate, PuzzleDetail, PuzzleSummary
+from backend.websocket.events import GameStartedEvent, PuzzleReplacedEvent
+
+router = APIRouter(dependencies=[Depends(check_admin_token)])
+
+
+class WrongAnswerCount(BaseModel):
+    guess: str  # Upper-cased, as guesses are compared
+    count: int
+
+
+class RungAnalytics(BaseModel):
+    word_index: int
+    word: str
+    clue: str | None
+    teams_attempted: int
+    teams_solved: int
+    solve_rate: float  # 0.0 to 1.0
+    wrong_guesses: int
+    avg_solve_seconds: float | None
+    median_solve_seconds: float | None
+    common_wrong_answers: list[WrongAnswerCount]  # Most guessed first; candidates for clarifying the clue
+
+
+class TeamRungTimes(BaseModel):
+    game_id: int
+    team_id: int
+    team_name: str
+    solve_seconds: dict[int, float]  # word_index -> seconds spent on that rung
+
+
+class PuzzleAnalyticsResponse(BaseModel):
+    puzzle_id: str
+    title: str
+    games_played: int
+    teams_played: int
+    rungs: list[RungAnalytics]
+    difficulty_curve: list[float]  # relative to the puzzle-wide median, ordered like rungs
+    teams: list[TeamRungTimes]
+
+
+class SimulationRequest(BaseModel):
+    num_teams: int = 4
+    runs: int = 50  # Times the whole game is played; more runs give a steadier projection
+    accuracy: float = 0.5  # Mean chance that a guess is right, 0.0 to 1.0
+    accuracy_spread: float = 0.15  # Standard deviation of each team's accuracy around the mean
+    guess_seconds: float = 20.0  # Median seconds a team takes per guess
+    guess_seconds_spread: float = 0.5  # Log-normal sigma of the seconds per guess
+    time_limit_seconds: float = 3600.0
+    use_history: bool = True  # Make rungs harder or easier the way past games of this puzzle found them
+    seed: int | None = None  # Pass the seed from an earlier response to repeat it
+
+
+class SimulatedRung(BaseModel):
+    word_index: int
+    word: str
+    median_solve_seconds: float | None
+    avg_wrong_guesses: float
+    teams_stuck: int
+    stall_point: bool
+
+
+class SimulationResponse(BaseModel):
+    puzzle_id: str
+    title: str
+    seed: int
+    history_plays: int  # Past team plays the rung difficulties came from; 0 when every rung counted as average
+    projected_duration_seconds: float  # Median over runs of when the last team finished
+    p90_duration_seconds: float
+    median_team_finish_seconds: float | None
+    unfinished_rate: float  # Share of simulated teams still playing at the time limit
+    rungs: list[SimulatedRung]
+    stall_points: list[int]  # word_index of each stall point
+
+
+def to_puzzle_summary(puzzle: Puzzle) -> PuzzleSummary:
+    return PuzzleSummary(
+        id=puzzle.id,
+        title=puzzle.title,
+        difficulty=puzzle.difficulty,
+        word_count=len(record_ladder(puzzle)),
+        author_name=puzzle.author_name,
+        author_handle=puzzle.author_handle,
+        author_notes=puzzle.author_notes,
+        created_at=puzzle.created_at,
+        updated_at=puzzle.updated_at,
+    )
+
+
+def to_puzzle_detail(puzzle: Puzzle) -> PuzzleDetail:
+    return PuzzleDetail(**to_puzzle_summary(puzzle).model_dump(), ladder=record_ladder(puzzle))
+
+
+def get_stored_puzzle_or_404(db: Session, puzzle_id: int) -> Puzzle:
+    puzzle = db.get(Puzzle, puzzle_id)
+    if not puzzle:
+        api_logger.warning(f"Stored puzzle not found: puzzle_id={puzzle_id}")
+        raise NotFoundError("Puzzle not found")
+    return puzzle
+
+
+def puzzle_in_use_error(db: Session, puzzle_id: int, action: str, active_only: bool) -> Optional[ConflictError]:
+    """409 listing the lobbies whose games reference the puzzle, or None when nothing does."""
+    query = select(Lobby).join(Game, Game.lobby_id == Lobby.id).where(Game.puzzle_path == stored_puzzle_path(puzzle_id))
+    if active_only:
+        query = query.where(Game.completed_at.is_(None))
+    lobbies = db.exec(query.distinct().order_by(Lobby.id)).all()
+    if not lobbies:
+        return None
+    return ConflictError(
+        f"Cannot {action} a puzzle used by {len(lobbies)} lobbies",
+        code=ErrorCode.PUZZLE_IN_USE,
+        lobbies=[{"lobby_id": lobby.id, "code": lobby.code, "name": lobby.name} for lobby in lobbies],
+    )
+
+
+def load_puzzle_history(
+    db: Session, puzzle_path: str, organization_id: int | None
+) -> tuple[list[Game], dict[tuple[int, int], datetime], list[GuessRecord]]:
+    """Games that used a puzzle, when each team play started and its logged guesses, for the analytics."""
+    games_query = select(Game).where(Game.puzzle_path == puzzle_path)
+    if organization_id is not None:
+        games_query = games_query.join(Lobby, Lobby.id == Game.lobby_id).where(Lobby.organization_id == organization_id)
+    games = db.exec(games_query).all()
+    games_by_id = {game.id: game for game in games}
+    game_ids = list(games_by_id.keys())
+
+    guesses = []
+    round_results = []
+    if game_ids:
+        guesses = db.exec(select(Guess).where(Guess.game_id.in_(game_ids)).order_by(Guess.created_at)).all()
+        round_results = db.exec(select(RoundResult).where(RoundResult.game_id.in_(game_ids))).all()
+
+    # A play is one team's attempt at one game; teams that never guessed still count via round results
+    play_keys = {(guess.game_id, guess.team_id) for guess in guesses}
+    play_keys.update((result.game_id, result.team_id) for result in round_results)
+    play_starts = {key: games_by_id[key[0]].started_at for key in play_keys}
+
+    records = [
+        GuessRecord(
+            play_key=(guess.game_id, guess.team_id),
+            word_index=guess.word_index,
+            is_correct=guess.is_correct,
+            created_at=guess.created_at,
+            guess=guess.guess,
+        )
+        for guess in guesses
+    ]
+    return games, play_starts, records
+
+
+@router.post("/puzzle", response_model=PuzzleDetail)
+async def create_puzzle(
+    puzzle_data: PuzzleCreate,
+    db: Session = Depends(get_session),
+    organization_id: int | None = Depends(admin_organization_id),
+):
+    api_logger.info(f"Admin creating puzzle: title={puzzle_data.title} difficulty={puzzle_data.difficulty}")
+    puzzle = Puzzle(
+        title=puzzle_data.title,
+        difficulty=puzzle_data.difficulty,
+        ladder=json.dumps([step.model_dump() for step in puzzle_data.ladder]),
+        author_name=puzzle_data.author_name,
+        author_handle=puzzle_data.author_handle,
+        author_notes=puzzle_data.author_notes,
+        organization_id=organization_id,
+    )
+    db.add(puzzle)
+    db.commit()
+    db.refresh(puzzle)
+    api_logger.info(f"Created puzzle id={puzzle.id} with {len(puzzle_data.ladder)} words")
+    return to_puzzle_detail(puzzle)
+
+
+@router.get("/puzzle", response_model=list[PuzzleSummary])
+async def list_puzzles(
+    difficulty: Optional[str] = None,
+    author: Optional[str] = None,
+    db: Session = Depends(get_session),
+    organization_id: int | None = Depends(admin_organization_id),
+):
+    """`author` matches a writer's name or handle, ignoring case and a leading @."""
+    api_logger.info(f"Admin requested stored puzzles: difficulty={difficulty} author={author}")
+    query = select(Puzzle).order_by(Puzzle.created_at.desc(), Puzzle.id.desc())
+    if organization_id is not None:
+        query = query.where(Puzzle.organization_id == organization_id)
+    if difficulty:
+        query = query.where(Puzzle.difficulty == difficulty.lower())
+    if author:
+        author = author.strip().lower()
+        handle = author.lstrip("@")
+        query = query.where(
+            (func.lower(Puzzle.author_name) == author)
+            | (func.lower(Puzzle.author_handle) == handle)
+            | (func.lower(Puzzle.author_handle) == f"@{handle}")
+        )
+    puzzles = db.exec(query).all()
+    api_logger.info(f"Returning {len(puzzles)} stored puzzles")
+    return [to_puzzle_summary(puzzle) for puzzle in puzzles]
+
+
+@router.get("/puzzle/{puzzle_id}", response_model=PuzzleDetail)
+async def get_puzzle(puzzle_id: int, db: Session = Depends(get_session)):
+    api_logger.info(f"Admin requested puzzle: puzzle_id={puzzle_id}")
+    return to_puzzle_detail(get_stored_puzzle_or_404(db, puzzle_id))
+
+
+@router.put("/puzzle/{puzzle_id}", response_model=PuzzleDetail)
+async def update_puzzle(puzzle_id: int, puzzle_data: PuzzleCreate, db: Session = Depends(get_session)):
+    """Replace a stored puzzle. Rejected while a game is playing it, since teams' progress is tied to the words."""
+    api_logger.info(f"Admin updating puzzle: puzzle_id={puzzle_id}")
+    puzzle = get_stored_puzzle_or_404(db, puzzle_id)
+    in_use = puzzle_in_use_error(db, puzzle_id, "edit", active_only=True)
+    if in_use:
+        api_logger.warning(f"Update puzzle rejected: puzzle_id={puzzle_id} is in an active game")
+        raise in_use
+
+    puzzle.title = puzzle_data.title
+    puzzle.difficulty = puzzle_data.difficulty
+    puzzle.ladder = json.dumps([step.model_dump() for step in puzzle_data.ladder])
+    puzzle.author_name = puzzle_data.author_name
+    puzzle.author_handle = puzzle_data.author_handle
+    puzzle.author_notes = puzzle_data.author_notes
+    puzzle.updated_at = datetime.now(timezone.utc)
+    db.add(puzzle)
+    db.commit()
+    db.refresh(puzzle)
+    get_puzzle_manager().evict_puzzle(stored_puzzle_path(puzzle_id))
+    api_logger.info(f"Updated puzzle id={puzzle_id}")
+    return to_puzzle_detail(puzzle)
+
+
+def pick_fallback_puzzle(lobby: Lobby, difficulty: str, used_paths: set[str]) -> PuzzleFile:
+    """A bundled puzzle to stand in for a deleted one, at its difficulty when there is one, unplayed when possible."""
+    puzzle_manager = get_puzzle_manager()
+    rng = seeded_rng(lobby.rng_seed, "fallback")
+    for level in [difficulty] + [level for level in ("easy", "medium", "hard") if level != difficulty]:
+        fallback = puzzle_manager.get_random_puzzle(level, exclude_paths=used_paths, rng=rng)
+        fallback = fallback or puzzle_manager.get_random_puzzle(level, rng=rng)
+        if fallback:
+            return fallback
+    raise ConflictError("There is no bundled puzzle to swap in", code=ErrorCode.PUZZLE_IN_USE)
+
+
+def replace_deleted_puzzle(db: Session, puzzle: Puzzle) -> dict[int, tuple[str, list[int]]]:
+    """
+    Point every game using the stored puzzle at a bundled one, the same one across each lobby, without committing.
+
+    Games still being played restart on it with their guesses dropped. Finished games keep their results and are only
+    repointed so those still load. Returns each lobby's replacement path and the teams that restarted.
+    """
+    puzzle_manager = get_puzzle_manager()
+    games = db.exec(select(Game).where(Game.puzzle_path == stored_puzzle_path(puzzle.id)).order_by(Game.id)).all()
+    replaced: dict[int, tuple[str, list[int]]] = {}
+    for game in games:
+        lobby = db.get(Lobby, game.lobby_id)
+        if lobby.id not in replaced:
+            used_paths = set(db.exec(select(Game.puzzle_path).where(Game.lobby_id == lobby.id)).all())
+            fallback = pick_fallback_puzzle(lobby, puzzle.difficulty, used_paths)
+            fallback_path = puzzle_manager.normalize_puzzle_path(fallback.path)
+            puzzle_manager.cache_puzzle(fallback_path, fallback.puzzle)
+            replaced[lobby.id] = (fallback_path, [])
+        fallback_path, restarted_teams = replaced[lobby.id]
+        game.puzzle_path = fallback_path
+        if game.completed_at is None:
+            for guess in db.exec(select(Guess).where(Guess.game_id == game.id)).all():
+                db.delete(guess)
+            game.revealed_steps = "[]"
+            game.last_updated_at = None
+            restarted_teams.extend(team.id for team in db.exec(select(Team).where(Team.game_id == game.id)).all())
+        db.add(game)
+    return replaced
+
+
+async def announce_replaced_puzzle(puzzle_id: int, deleted_title: str, replaced: dict[int, tuple[str, list[int]]]):
+    """Send restarted teams game_started so they load the new puzzle, and admins watching each lobby puzzle_replaced."""
+    from backend.websocket.managers import admin_web_socket_manager, lobby_websocket_manager
+
+    puzzle_manager = get_puzzle_manager()
+    for lobby_id, (fallback_path, restarted_teams) in replaced.items():
+        fallback = puzzle_manager.load_puzzle_by_path(fallback_path)
+        for team_id in restarted_teams:
+            await lobby_websocket_manager.broadcast_to_team(
+                lobby_id,
+                team_id,
+                GameStartedEvent(team_id=team_id, puzzle_title=fallback.meta.title, puzzle_length=len(fallback.ladder)),
+            )
+        await admin_web_socket_manager.broadcast_to_lobby(
+            lobby_id,
+            PuzzleReplacedEvent(
+                lobby_id=lobby_id,
+                deleted_title=deleted_title,
+                replacement_title=fallback.meta.title,
+                restarted_team_ids=restarted_teams,
+            ),
+        )
+        api_logger.info(
+            f"Swapped deleted puzzle id={puzzle_id} for {fallback_path} in lobby_id={lobby_id} "
+            f"restarted_teams={restarted_teams}"
+        )
+
+
+@router.delete("/puzzle/{puzzle_id}", response_model=MessageResponse)
+async def delete_puzzle(
+    puzzle_id: int,
+    force: bool = Query(default=False, description="Swap the lobbies using it to a bundled puzzle instead of refusing"),
+    db: Session = Depends(get_session),
+):
+    """
+    Delete a stored puzzle. Rejected while a game is playing it; with force=true those lobbies are moved to a bundled
+    puzzle instead. Finished games that used it are repointed at a bundled puzzle so their results still load.
+
+    The swap and the delete are one transaction, so a failure leaves every game on the stored puzzle.
+    """
+    api_logger.info(f"Admin deleting puzzle: puzzle_id={puzzle_id} force={force}")
+    puzzle = get_stored_puzzle_or_404(db, puzzle_id)
+    in_use = puzzle_in_use_error(db, puzzle_id, "delete", active_only=True)
+    if in_use and not force:
+        api_logger.warning(f"Delete puzzle rejected: puzzle_id={puzzle_id} is in an active game")
+        raise in_use
+
+    title = puzzle.title
+    try:
+        replaced = replace_deleted_puzzle(db, puzzle)
+        db.delete(puzzle)
+        db.commit()
+    except SQLAlchemyError as e:
+        db.rollback()
+        api_logger.exception(f"Delete puzzle failed, rolled back: puzzle_id={puzzle_id} error={e}")
+        raise DatabaseError("Couldn't delete the puzzle, nothing was changed")
+    get_puzzle_manager().evict_puzzle(stored_puzzle_path(puzzle_id))
+    api_logger.info(f"Deleted puzzle id={puzzle_id}")
+
+    await announce_replaced_puzzle(puzzle_id, title, replaced)
+    if replaced:
+        return MessageResponse(status=True, message=f"Puzzle {title} deleted, {len(replaced)} lobbies moved off it")
+    return MessageResponse(status=True, message=f"Puzzle {title} deleted")
+
+
+@router.get("/puzzle/{puzzle_id}/analytics", response_model=PuzzleAnalyticsResponse)
+async def get_puzzle_analytics(
+    puzzle_id: str,
+    top_wrong: int = Query(default=5, ge=0, le=50, description="Most common wrong answers to list per rung"),
+    db: Session = Depends(get_session),
+    organization_id: int | None = Depends(admin_organization_id),
+):
+    """
+    Per-rung solve time statistics and the most common wrong answers for a puzzle, aggregated across every game
+    that used it (every game in the admin's organization, when they're scoped to one).
+
+    The puzzle id is its date (YYYY-MM-DD), matching the ids returned by /puzzles/dates.
+    """
+    api_logger.info(f"Admin requested puzzle analytics: puzzle_id={puzzle_id}")
+    if not feature_flags.is_enabled(Feature.PUZZLE_ANALYTICS):
+        raise ServiceUnavailableError("Puzzle analytics are switched off on this server right now")
+    puzzle_manager = get_puzzle_manager()
+    try:
+        puzzle_path = puzzle_manager.puzzle_path_for_date(puzzle_id)
+        puzzle = puzzle_manager.load_puzzle_by_path(puzzle_path)
+    except ValueError:
+        api_logger.warning(f"Puzzle analytics failed: puzzle not found puzzle_id={puzzle_id}")
+        raise NotFoundError("Puzzle not found")
+
+    games, play_starts, records = load_puzzle_history(db, puzzle_path, organization_id)
+    play_keys = set(play_starts)
+    rung_stats, play_solve_times = aggregate_rung_stats(len(puzzle.ladder), play_starts, records)
+
+    team_ids = {team_id for _, team_id in play_keys}
+    teams_by_id = {}
+    if team_ids:
+        teams_by_id = {team.id: team for team in db.exec(select(Team).where(Team.id.in_(team_ids))).all()}
+
+    rungs = [
+        RungAnalytics(
+            word_index=stats.word_index,
+            word=puzzle.ladder[stats.word_index].word,
+            clue=puzzle.ladder[stats.word_index].clue,
+            teams_attempted=stats.teams_attempted,
+            teams_solved=stats.teams_solved,
+            solve_rate=stats.solve_rate,
+            wrong_guesses=stats.wrong_guesses,
+            avg_solve_seconds=stats.avg_solve_seconds,
+            median_solve_seconds=stats.median_solve_seconds,
+            common_wrong_answers=[
+                WrongAnswerCount(guess=guess, count=count) for guess, count in stats.common_wrong_answers(top_wrong)
+            ],
+        )
+        for stats in rung_stats
+    ]
+    teams = [
+        TeamRungTimes(
+            game_id=game_id,
+            team_id=team_id,
+            team_name=teams_by_id[team_id].name if team_id in teams_by_id else "Unknown team",
+            solve_seconds=play_solve_times.get((game_id, team_id), {}),
+        )
+        for game_id, team_id in sorted(play_keys)
+    ]
+
+    api_logger.info(
+        f"Returning puzzle analytics for puzzle_id={puzzle_id}: {len(games)} games, {len(play_keys)} team plays"
+    )
+    return PuzzleAnalyticsResponse(
+        puzzle_id=puzzle_id,
+        title=puzzle.meta.title,
+        games_played=len({game_id for game_id, _ in play_keys}),
+        teams_played=len(play_keys),
+        rungs=rungs,
+        difficulty_curve=difficulty_curve(rung_stats),
+        teams=teams,
+    )
+
+
+def load_simulated_puzzle(db: Session, puzzle_id: str) -> tuple[GamePuzzle, str]:
+    """A stored puzzle (numeric id) or a puzzle file (YYYY-MM-DD), and the puzzle_path games record for it."""
+    if puzzle_id.isdigit():
+        return to_game_puzzle(get_stored_puzzle_or_404(db, int(puzzle_id))), stored_puzzle_path(int(puzzle_id))
+
+    puzzle_manager = get_puzzle_manager()
+    try:
+        puzzle_path = puzzle_manager.puzzle_path_for_date(puzzle_id)
+        return puzzle_manager.load_puzzle_by_path(puzzle_path), puzzle_path
+    except ValueError:
+        api_logger.warning(f"Simulation failed: puzzle not found puzzle_id={puzzle_id}")
+        raise NotFoundError("Puzzle not found")
+
+
+@router.post("/puzzle/{puzzle_id}/simulate", response_model=SimulationResponse)
+async def simulate_puzzle(
+    puzzle_id: str,
+    request: SimulationRequest,
+    db: Session = Depends(get_session),
+    organization_id: int | None = Depends(admin_organization_id),
+):
+    """
+    Dry-run a game of a puzzle with virtual teams to project how long it will take and where teams will stall.
+
+    The puzzle id is a stored puzzle's id or a puzzle file's date. See backend/game/simulation.py for the model.
+    """
+    api_logger.info(f"Admin requested puzzle simulation: puzzle_id={puzzle_id} {request.model_dump()}")
+    if not 1 <= request.num_teams <= MAX_TEAMS:
+        raise InvalidRequestError(f"num_teams must be between 1 and {MAX_TEAMS}")
+    if request.runs < 1 or request.num_teams * request.runs > MAX_TEAM_PLAYS:
+        raise InvalidRequestError(f"runs must be at least 1, and num_teams x runs at most {MAX_TEAM_PLAYS}")
+    if not 0 < request.accuracy <= 1:
+        raise InvalidRequestError("accuracy must be above 0 and at most 1")
+    if request.guess_seconds <= 0 or request.time_limit_seconds <= 0:
+        raise InvalidRequestError("guess_seconds and time_limit_seconds must be positive")
+    if request.accuracy_spread < 0 or request.guess_seconds_spread < 0:
+        raise InvalidRequestError("Spreads can't be negative")
+
+    puzzle, puzzle_path = load_simulated_puzzle(db, puzzle_id)
+
+    rung_difficulty = {}
+    history_plays = 0
+    if request.use_history:
+        _, play_starts, records = load_puzzle_history(db, puzzle_path, organization_id)
+        if play_starts:
+            rung_stats, _ = aggregate_rung_stats(len(puzzle.ladder), play_starts, records)
+            rung_difficulty = rung_difficulty_from_curve(difficulty_curve(rung_stats))
+            history_plays = len(play_starts)
+
+    params = SimulationParams(
+        num_teams=request.num_teams,
+        runs=request.runs,
+        accuracy=request.accuracy,
+        accuracy_spread=request.accuracy_spread,
+        guess_seconds=request.guess_seconds,
+        guess_seconds_spread=request.guess_seconds_spread,
+        time_limit_seconds=request.time_limit_seconds,
+        rung_difficulty=rung_difficulty,
+    )
+    seed = request.seed if request.seed is not None else new_seed()
+    result = await asyncio.to_thread(simulate_game, puzzle, params, seeded_rng(seed, "simulation"))
+
+    rungs = [
+        SimulatedRung(
+            word_index=rung.word_index,
+            word=puzzle.ladder[rung.word_index].word,
+            median_solve_seconds=rung.median_solve_seconds,
+            avg_wrong_guesses=rung.avg_wrong_guesses,
+            teams_stuck=rung.teams_stuck,
+            stall_point=rung.stall_point,
+        )
+        for rung in result.rungs
+    ]
+    api_logger.info(
+        f"Simulated puzzle_id={puzzle_id}: projected {result.projected_duration_seconds:.0f}s, "
+        f"stall points {[rung.word_index for rung in rungs if rung.stall_point]}"
+    )
+    return SimulationResponse(
+        puzzle_id=puzzle_id,
+        title=puzzle.meta.title,
+        seed=seed,
+        history_plays=history_plays,
+        projected_duration_seconds=result.projected_duration_seconds,
+        p90_duration_seconds=result.p90_duration_seconds,
+        median_team_finish_seconds=result.median_team_finish_seconds,
+        unfinished_rate=result.unfinished_rate,
+        rungs=rungs,
+        stall_points=[rung.word_index for rung in rungs if rung.stall_point],
+    )
@@ -0,0 +1,27 @@
+from fastapi import APIRouter, Depends
+
+from backend.custom_logging import api_logger
+from backend.dependencies import check_deployment_admin
+from backend.retention import retention_metrics
+from backend.schemas import RetentionMetricsResponse
+from backend.settings import settings
+
+router = APIRouter(dependencies=[Depends(check_deployment_admin)])
+
+
+@router.get("/retention", response_model=RetentionMetricsResponse)
+async def get_retention_metrics():
+    """Current guess and chat retention settings and how many rows the pruning task has removed."""
+    api_logger.info("Admin requested retention metrics")
+    return RetentionMetricsResponse(
+        guess_retention_days=settings.GUESS_RETENTION_DAYS,
+        guess_max_rows_per_lobby=settings.GUESS_MAX_ROWS_PER_LOBBY,
+        chat_retention_days=settings.CHAT_RETENTION_DAYS,
+        chat_max_rows_per_lobby=settings.CHAT_MAX_ROWS_PER_LOBBY,
+        runs=retention_metrics.runs,
+        guesses_pruned_total=retention_metrics.guesses_pruned_total,
+        guesses_pruned_last_run=retention_metrics.guesses_pruned_last_run,
+        chat_messages_pruned_total=retention_metrics.chat_messages_pruned_total,
+        chat_messages_pruned_last_run=retention_metrics.chat_messages_pruned_last_run,
+        last_run_at=retention_metrics.last_run_at.isoformat() if retention_metrics.last_run_at else None,
+    )
@@ -0,0 +1,40 @@
+from fastapi import APIRouter, Depends
+
+from backend.admin_sessions import admin_session_store
+from backend.custom_logging import api_logger
+from backend.dependencies import check_owner_token
+from backend.errors import NotFoundError
+from backend.schemas import AdminSessionInfo, MessageResponse
+from backend.websocket.managers import admin_web_socket_manager
+
+router = APIRouter(dependencies=[Depends(check_owner_token)])
+
+
+@router.get("/sessions", response_model=list[AdminSessionInfo])
+async def list_admin_sessions():
+    api_logger.info("Owner requested list of admin sessions")
+    sessions = admin_session_store.list()
+    return [
+        AdminSessionInfo(
+            session_id=session.session_id,
+            ip=session.ip,
+            is_owner=session.is_owner,
+            organization_id=session.organization_id,
+            created_at=session.created_at.isoformat(),
+            last_activity_at=session.last_activity_at.isoformat(),
+            websocket_connected=admin_web_socket_manager.is_connected(session.session_id),
+        )
+        for session in sessions
+    ]
+
+
+@router.delete("/sessions/{session_id}", response_model=MessageResponse)
+async def revoke_admin_session(session_id: str):
+    api_logger.info(f"Owner requested admin session revocation: session_id={session_id}")
+    if not admin_session_store.revoke(session_id):
+        api_logger.warning(f"Revoke failed: admin session not found session_id={session_id}")
+        raise NotFoundError("Admin session not found")
+
+    await admin_web_socket_manager.disconnect(session_id, code=1008, reason="Admin session revoked")
+    api_logger.info(f"Admin session revoked and websocket closed: session_id={session_id}")
+    return MessageResponse(status=True, message="Admin session revoked")
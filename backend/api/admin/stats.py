@@ -0,0 +1,164 @@
+from datetime import date as Date
+from datetime import datetime, time, timedelta
+from typing import Optional
+
+from fastapi import APIRouter, Depends, Query
+from sqlmodel import Session, select
+
+from backend.custom_logging import api_logger
+from backend.database import get_session
+from backend.database.models import Guess, Lobby, Player, RoundResult, Team
+from backend.dependencies import admin_organization_id, check_admin_token
+from backend.errors import InvalidRequestError, NotFoundError
+from backend.game.player_stats import PlayerGuess, PlayerResult, aggregate_player_stats
+from backend.game.rivalries import TeamRound, head_to_head, lineup_key
+from backend.schemas import PlayerAggregateStats, PlayerStatsResponse, RivalriesResponse, RivalryStats
+
+router = APIRouter(dependencies=[Depends(check_admin_token)])
+
+
+def day_start(day: Date) -> datetime:
+    # SQLite stores UTC timestamps without an offset, so compare against naive bounds
+    return datetime.combine(day, time.min)
+
+
+@router.get("/stats/players", response_model=PlayerStatsResponse)
+async def get_player_stats(
+    date_from: Optional[Date] = None,
+    date_to: Optional[Date] = None,
+    limit: int = Query(100, ge=1, le=1000),
+    db: Session = Depends(get_session),
+    organization_id: int | None = Depends(admin_organization_id),
+):
+    """
+    Games played, guesses and average solve time per player across every lobby the admin can see.
+
+    date_from and date_to are inclusive UTC days; guesses and round results outside them don't count.
+    """
+    api_logger.info(f"Admin requested player stats: date_from={date_from} date_to={date_to} limit={limit}")
+    if date_from and date_to and date_from > date_to:
+        raise InvalidRequestError("date_from must not be after date_to")
+
+    guess_query = (
+        select(Player.name, Guess.game_id, Guess.is_correct, Guess.created_at)
+        .join(Player, Guess.player_id == Player.id)
+        .join(Lobby, Player.lobby_id == Lobby.id)
+        .where(Player.is_bot.is_(False))
+    )
+    result_query = (
+        select(Player.name, RoundResult.game_id, RoundResult.time_to_complete, RoundResult.created_at)
+        .join(Player, Player.team_id == RoundResult.team_id)
+        .join(Lobby, RoundResult.lobby_id == Lobby.id)
+        .where(Player.is_bot.is_(False))
+    )
+    if organization_id is not None:
+        guess_query = guess_query.where(Lobby.organization_id == organization_id)
+        result_query = result_query.where(Lobby.organization_id == organization_id)
+    if date_from:
+        guess_query = guess_query.where(Guess.created_at >= day_start(date_from))
+        result_query = result_query.where(RoundResult.created_at >= day_start(date_from))
+    if date_to:
+        guess_query = guess_query.where(Guess.created_at < day_start(date_to + timedelta(days=1)))
+        result_query = result_query.where(RoundResult.created_at < day_start(date_to + timedelta(days=1)))
+
+    stats = aggregate_player_stats(
+        (PlayerGuess(*row) for row in db.exec(guess_query).all()),
+        (PlayerResult(*row) for row in db.exec(result_query).all()),
+    )
+    return PlayerStatsResponse(
+        date_from=date_from.isoformat() if date_from else None,
+        date_to=date_to.isoformat() if date_to else None,
+        players=[
+            PlayerAggregateStats(
+                name=entry.name,
+                games_played=entry.games_played,
+                guesses=entry.guesses,
+                correct_guesses=entry.correct_guesses,
+                avg_solve_seconds=round(entry.avg_solve_seconds, 1) if entry.avg_solve_seconds is not None else None,
+            )
+            for entry in stats[:limit]
+        ],
+    )
+
+
+def human_members(session: Session, team_ids: list[int]) -> dict[int, list[str]]:
+    """Current non-bot members' names by team id."""
+    players = session.exec(select(Player).where(Player.team_id.in_(team_ids), Player.is_bot.is_(False))).all()
+    members: dict[int, list[str]] = {}
+    for player in players:
+        members.setdefault(player.team_id, []).append(player.name)
+    return members
+
+
+def load_team_rounds(session: Session, organization_id: int | None) -> list[TeamRound]:
+    """Every round result from public lobbies the admin can see, with each team's current members."""
+    query = (
+        select(RoundResult, Team)
+        .join(Team)
+        .join(Lobby, RoundResult.lobby_id == Lobby.id)
+        .where(Lobby.is_private.is_(False))
+    )
+    if organization_id is not None:
+        query = query.where(Lobby.organization_id == organization_id)
+    rows = session.exec(query).all()
+    members = human_members(session, list({team.id for _, team in rows}))
+    return [
+        TeamRound(
+            lobby_id=result.lobby_id,
+            round_number=result.round_number,
+            team_name=team.name,
+            members=tuple(members.get(team.id, [])),
+            placement=result.placement,
+            points=result.points_earned,
+            played_at=result.created_at,
+        )
+        for result, team in rows
+    ]
+
+
+@router.get("/stats/rivalries", response_model=RivalriesResponse)
+async def get_rivalries(
+    lobby_id: Optional[int] = None,
+    min_meetings: int = Query(2, ge=1),
+    limit: int = Query(20, ge=1, le=100),
+    db: Session = Depends(get_session),
+    organization_id: int | None = Depends(admin_organization_id),
+):
+    """
+    Head-to-head records between team line-ups that keep meeting (see backend/game/rivalries.py), across every
+    lobby the admin can see.
+
+    With a lobby_id, only rivalries between that lobby's current teams, for a bit of pre-game trash talk.
+    """
+    api_logger.info(f"Admin requested rivalries: lobby_id={lobby_id} min_meetings={min_meetings} limit={limit}")
+    rivalries = head_to_head(load_team_rounds(db, organization_id), min_meetings=min_meetings)
+
+    if lobby_id is not None:
+        lobby = db.get(Lobby, lobby_id)
+        if not lobby or (organization_id is not None and lobby.organization_id != organization_id):
+            raise NotFoundError("Lobby not found")
+        team_ids = db.exec(select(Team.id).where(Team.lobby_id == lobby_id)).all()
+        present = {lineup_key(tuple(names)) for names in human_members(db, list(team_ids)).values()}
+        rivalries = [
+            rivalry
+            for rivalry in rivalries
+            if lineup_key(tuple(rivalry.members_a)) in present and lineup_key(tuple(rivalry.members_b)) in present
+        ]
+
+    return RivalriesResponse(
+        rivalries=[
+            RivalryStats(
+                members_a=rivalry.members_a,
+                members_b=rivalry.members_b,
+                team_names_a=rivalry.team_names_a,
+                team_names_b=rivalry.team_names_b,
+                meetings=rivalry.meetings,
+                wins_a=rivalry.wins_a,
+                wins_b=rivalry.wins_b,
+                ties=rivalry.ties,
+                avg_point_margin=round(rivalry.avg_point_margin, 2),
+                last_met_at=rivalry.last_met_at.isoformat() if rivalry.last_met_at else None,
+            )
+            for rivalry in rivalries[:limit]
+        ]
+    )
@@ -0,0 +1,42 @@
+from datetime import date as Date
+from typing import Optional
+
+from fastapi import APIRouter, Depends
+from sqlmodel import Session, select
+
+from backend.custom_logging import api_logger
+from backend.daily_summary import build_summary
+from backend.database import get_session
+from backend.database.models import DailySummary
+from backend.dependencies import check_deployment_admin
+from backend.errors import NotFoundError
+from backend.metrics import metrics_collector, today
+from backend.schemas import DailySummaryResponse
+
+router = APIRouter(dependencies=[Depends(check_deployment_admin)])
+
+
+@router.get("/summary", response_model=DailySummaryResponse)
+async def get_daily_summary(date: Optional[Date] = None, session: Session = Depends(get_session)):
+    """Stored summary for a past UTC day, or today's running totals when no date (or today) is given."""
+    day = date or today()
+    api_logger.info(f"Admin requested daily summary: date={day}")
+
+    summary = session.exec(select(DailySummary).where(DailySummary.date == day.isoformat())).first()
+    is_final = summary is not None
+    if not summary:
+        if day != today():
+            api_logger.warning(f"No daily summary recorded: date={day}")
+            raise NotFoundError(f"No summary recorded for {day.isoformat()}")
+        summary = build_summary(session, day, metrics_collector.day(day))
+
+    return DailySummaryResponse(
+        date=summary.date,
+        is_final=is_final,
+        games_played=summary.games_played,
+        peak_concurrent_players=summary.peak_concurrent_players,
+        request_count=summary.request_count,
+        error_log_count=summary.error_log_count,
+        server_error_count=summary.server_error_count,
+        p99_latency_ms=round(summary.p99_latency_ms, 2) if summary.p99_latency_ms is not None else None,
+    )
@@ -0,0 +1,21 @@
+import time
+from dataclasses import asdict
+
+from fastapi import APIRouter, Depends
+
+from backend.custom_logging import api_logger
+from backend.dependencies import check_deployment_admin
+from backend.schemas import RouteTrafficResponse, TrafficResponse
+from backend.traffic import TRAFFIC_WINDOWS_MINUTES, traffic_tracker
+
+router = APIRouter(dependencies=[Depends(check_deployment_admin)])
+
+
+@router.get("/traffic", response_model=TrafficResponse)
+async def get_traffic():
+    """Requests per route over the last 5, 15 and 60 minutes, busiest first."""
+    api_logger.info("Admin requested route traffic")
+    return TrafficResponse(
+        windows_minutes=list(TRAFFIC_WINDOWS_MINUTES),
+        routes=[RouteTrafficResponse(**asdict(route)) for route in traffic_tracker.summary(time.time())],
+    )
@@ -3,24 +3,50 @@
 import json
 from datetime import datetime, timezone
 
-from fastapi import APIRouter, Depends, HTTPException
+from fastapi import APIRouter, Depends
 from pydantic import BaseModel
+from sqlalchemy import delete
 from sqlmodel import Session, func, select
 
+from backend.api.lobby_phase import announce_phase_change, get_lobby_phase
+from backend.commentary import commentate
 from backend.custom_logging import websocket_logger
 from backend.database import get_session
-from backend.database.models import Game, Guess, Lobby, Player, RoundResult, Team
-from backend.dependencies import check_admin_token
-from backend.game.puzzles import get_puzzle_manager
+from backend.database.models import ChatMessage, Game, Guess, Lobby, Player, RoundResult, Team
+from backend.database.models import Puzzle as StoredPuzzle
+from backend.dependencies import check_admin_token, require_player_session
+from backend.errors import InvalidRequestError, NotFoundError, UnauthorizedError
+from backend.features import Feature, feature_flags
+from backend.game import commentary
+from backend.game.lobby_state import InvalidPhaseTransition, LobbyPhase, check_transition
+from backend.game.puzzles import PuzzleFile, get_puzzle_manager, stored_puzzle_path
+from backend.game.results import game_finished_event, record_round_results, round_already_recorded
+from backend.game.rng import seeded_rng
+from backend.game.standings import Standing, TeamProgress, compute_standings, visible_progress
 from backend.game.state_machine import GuessResult, TeamState, TeamStateMachine
-from backend.schemas import AdminStartGameRequest
+from backend.intermission import begin_intermission
+from backend.offline_guesses import find_replayed_guess, overtaken_solve
+from backend.rate_limit import guess_retry_after
+from backend.reveal import end_reveal
+from backend.roster import bump_roster_version
+from backend.schemas import AdminStartGameRequest, DeliverySummary
+from backend.settings import settings
+from backend.websocket.delivery import DeliveryReport
+from backend.websocket.player_messages import SubmitGuessMessage, TeamChatMessage
 from backend.websocket.events import (
     AlreadySolvedEvent,
+    CreditsEvent,
+    GameEndedEvent,
     GameStartedEvent,
+    GameWebSocketEvents,
+    GuessPendingReviewEvent,
     GuessSubmittedEvent,
+    PuzzleCredit,
     StateUpdateEvent,
+    TeamChatEvent,
     TeamCompletedEvent,
     TeamPlacedEvent,
+    TeamProgressEvent,
     WordSolvedEvent,
 )
 
@@ -36,6 +62,23 @@ class StartGameResponse(BaseModel):
     success: bool
     game_id: int
     message: str
+    delivery: DeliverySummary  # game_started sends to the teams' players
+
+
+class TeamProgressSnapshot(BaseModel):
+    team_id: int
+    team_name: str
+    team_color: str | None
+    is_own_team: bool
+    progress: float  # 0.0 to 1.0 over the hidden rungs
+    solved_count: int | None  # Only for the player's own team or with exact visibility
+    total_count: int | None
+    is_completed: bool
+
+
+class TeamProgressResponse(BaseModel):
+    visibility: str
+    teams: list[TeamProgressSnapshot]
 
 
 ####################################################################
@@ -78,7 +121,7 @@ def get_team_state_machine(team: Team, game: Game) -> TeamStateMachine:
     return machine
 
 
-def save_game_state(game: Game, state: TeamState, session: Session):
+def save_game_state(game: Game, state: TeamState, session: Session, completed_at: datetime | None = None):
     """
     Save team state to database.
 
@@ -86,23 +129,295 @@ def save_game_state(game: Game, state: TeamState, session: Session):
         team: Team model
         state: Team state
         session: Database session
+        completed_at: When the ladder was finished, if it was and not just now (a guess queued while offline)
     """
     game.revealed_steps = json.dumps(sorted(list(state.revealed_steps)))
     game.last_updated_at = state.last_updated_at
 
     if state.is_completed and not game.completed_at:
-        game.completed_at = datetime.now(tz=timezone.utc)
+        game.completed_at = completed_at or datetime.now(tz=timezone.utc)
 
     session.add(game)
     session.commit()
     session.refresh(game)
 
 
+def get_lobby_standings(lobby_id: int, session: Session) -> dict[int, Standing]:
+    """
+    Compute live standings for every team in a lobby that is playing a game.
+
+    Args:
+        lobby_id: Lobby ID
+        session: Database session
+
+    Returns:
+        Standings keyed by team ID
+    """
+    puzzle_manager = get_puzzle_manager()
+    rows = session.exec(select(Team, Game).join(Game, Team.game_id == Game.id).where(Team.lobby_id == lobby_id)).all()
+
+    progress = []
+    for team, game in rows:
+        ladder_length = len(puzzle_manager.load_puzzle_by_path(game.puzzle_path).ladder)
+        revealed_steps = (
+            json.loads(game.revealed_steps) if isinstance(game.revealed_steps, str) else game.revealed_steps
+        )
+        progress.append(
+            TeamProgress(
+                team_id=team.id,
+                revealed_count=len(revealed_steps) if revealed_steps else 2,
+                ladder_length=ladder_length,
+                completed_at=game.completed_at,
+                last_updated_at=game.last_updated_at,
+            )
+        )
+    return compute_standings(progress)
+
+
+async def broadcast_team_state(
+    lobby_id: int,
+    team: Team,
+    game: Game,
+    state: TeamState,
+    visibility: str,
+    websocket_manager,
+    previous_state: TeamState | None = None,
+):
+    """
+    Send a team's new state to the team and admins, and its masked progress to the rest of the lobby.
+
+    The progress only goes out when what the lobby may see of it differs from previous_state's, so a solve that
+    leaves a team in the same coarse quarter doesn't tell opponents that a rung fell.
+    """
+    state_event = StateUpdateEvent(
+        team_id=team.id,
+        revealed_steps=sorted(list(state.revealed_steps)),
+        is_completed=state.is_completed,
+        last_updated_at=state.last_updated_at.isoformat(),
+    )
+    await websocket_manager.broadcast_to_team(lobby_id, team.id, state_event)
+    # Also broadcast to admins so they can see team progress
+    await websocket_manager.admin_web_socket_manager.broadcast_to_lobby(lobby_id, state_event)
+
+    # Let the other teams see this team's progress, masked to the lobby's visibility setting
+    ladder_length = len(get_puzzle_manager().load_puzzle_by_path(game.puzzle_path).ladder)
+    visible = visible_progress(visibility, len(state.revealed_steps), ladder_length, state.is_completed)
+    previously_visible = (
+        visible_progress(visibility, len(previous_state.revealed_steps), ladder_length, previous_state.is_completed)
+        if previous_state
+        else None
+    )
+    if visible and visible != previously_visible and feature_flags.is_enabled(Feature.PROGRESS_BROADCASTS):
+        progress_event = TeamProgressEvent(
+            team_id=team.id,
+            team_name=team.name,
+            team_color=team.color,
+            progress=visible.progress,
+            solved_count=visible.solved_count,
+            total_count=visible.total_count,
+            is_completed=visible.is_completed,
+        )
+        await websocket_manager.broadcast_to_lobby(lobby_id, progress_event)
+
+
+async def broadcast_puzzle_credits(lobby_id: int, session: Session, websocket_manager):
+    """Credit the authors of the puzzles the lobby's teams just played; puzzles without an author are left out."""
+    if not settings.SHOW_PUZZLE_CREDITS:
+        return
+    puzzle_paths = session.exec(
+        select(Game.puzzle_path).join(Team, Team.game_id == Game.id).where(Team.lobby_id == lobby_id).distinct()
+    ).all()
+    puzzle_manager = get_puzzle_manager()
+    credits = []
+    for puzzle_path in sorted(puzzle_paths):
+        try:
+            meta = puzzle_manager.load_puzzle_by_path(puzzle_path).meta
+        except ValueError:
+            websocket_logger.warning(f"Skipping credits for a puzzle that no longer loads: puzzle_path={puzzle_path}")
+            continue
+        if meta.author:
+            credits.append(PuzzleCredit(title=meta.title, author=meta.author, author_handle=meta.author_handle))
+    if credits:
+        await websocket_manager.broadcast_to_lobby(lobby_id, CreditsEvent(lobby_id=lobby_id, puzzles=credits))
+
+
+async def record_finished_round(lobby_id: int, game: Game, session: Session, websocket_manager) -> int | None:
+    """Score a round every team finished, once, and announce the final standings; the round's number, or None."""
+    teams = session.exec(select(Team).where(Team.lobby_id == lobby_id)).all()
+    if round_already_recorded(session, teams):
+        return None
+    puzzle_length = len(get_puzzle_manager().load_puzzle_by_path(game.puzzle_path).ladder)
+    round_number = record_round_results(session, lobby_id, teams, puzzle_length)
+    session.commit()
+    finished = game_finished_event(session, lobby_id, round_number)
+    await websocket_manager.broadcast_to_lobby(lobby_id, finished)
+    await commentate(lobby_id, commentary.round_won(round_number, finished.winning_team_name))
+    return round_number
+
+
+async def apply_correct_guess(
+    lobby_id: int,
+    team: Team,
+    player: Player,
+    game: Game,
+    result: GuessResult,
+    session: Session,
+    websocket_manager,
+    solved_at: datetime | None = None,
+):
+    """
+    Persist a correct guess and broadcast the resulting progress, completion and placement events.
+
+    Shared by live guess handling and host approval of open-ended answers.
+
+    Args:
+        lobby_id: Lobby ID
+        team: Team that solved the word
+        player: Player credited with the solve
+        game: The team's game
+        result: Correct guess result carrying the new state
+        session: Database session
+        websocket_manager: WebSocket manager instance
+        solved_at: When the guess was made, if not just now; a ladder it completes is timed from it
+    """
+    word_index = result.word_index
+    lobby = session.get(Lobby, lobby_id)
+    visibility = lobby.progress_visibility if lobby else "none"
+
+    # Snapshot standings around the save so clients get this solve's rank and points delta
+    previous_standing = get_lobby_standings(lobby_id, session).get(team.id)
+    previous_state = get_team_state_machine(team, game).get_current_state()
+    save_game_state(game, result.new_state, session, completed_at=solved_at)
+    standing = get_lobby_standings(lobby_id, session).get(team.id)
+    if visibility == "none":
+        # Rank and projected points would let a team work out where its opponents are
+        previous_standing = standing = None
+
+    # Broadcast word solved event
+    word_solved_event = WordSolvedEvent(
+        team_id=team.id,
+        player_id=player.id,
+        player_name=player.name,
+        word_index=word_index,
+        word=result.expected_word or "",
+        direction="",  # Not relevant
+        revealed_count=len(result.new_state.revealed_steps),
+        rank=standing.rank if standing else None,
+        rank_delta=previous_standing.rank - standing.rank if previous_standing and standing else 0,
+        projected_points=standing.projected_points if standing else 0,
+        points_earned=standing.projected_points - previous_standing.projected_points
+        if previous_standing and standing
+        else 0,
+    )
+    await websocket_manager.broadcast_to_team(lobby_id, team.id, word_solved_event)
+    if visibility != "none":
+        # Spectator sessions are open to anyone, players included, so solves are only narrated when visible anyway
+        guesses = session.exec(
+            select(func.count(Guess.id)).where(
+                Guess.team_id == team.id, Guess.game_id == game.id, Guess.word_index == word_index
+            )
+        ).one()
+        await commentate(lobby_id, commentary.rung_solved(team.name, word_index, guesses))
+
+    await broadcast_team_state(
+        lobby_id, team, game, result.new_state, visibility, websocket_manager, previous_state=previous_state
+    )
+
+    # If completed, broadcast team completion
+    if result.new_state.is_completed:
+        team_completed_event = TeamCompletedEvent(
+            team_id=team.id,
+            team_name=team.name,
+            team_color=team.color,
+            completed_at=game.completed_at.isoformat()
+            if game.completed_at
+            else datetime.now(tz=timezone.utc).isoformat(),
+        )
+        await websocket_manager.broadcast_to_team(lobby_id, team.id, team_completed_event)
+        # Also broadcast to admins
+        await websocket_manager.admin_web_socket_manager.broadcast_to_lobby(lobby_id, team_completed_event)
+
+        # Calculate placement by counting prior completions (ordered by completed_at, then id for tiebreaker)
+        prior_completions = (
+            session.exec(
+                select(func.count(Team.id))
+                .join(Game, Team.game_id == Game.id)
+                .where(Team.lobby_id == lobby_id)
+                .where(Game.completed_at.isnot(None))
+                .where(
+                    (Game.completed_at < game.completed_at)
+                    | ((Game.completed_at == game.completed_at) & (Team.id < team.id))
+                )
+            ).first()
+            or 0
+        )
+        placement = prior_completions + 1
+
+        # Get the current first place team
+        first_place_team = session.exec(
+            select(Team)
+            .join(Game, Team.game_id == Game.id)
+            .where(Team.lobby_id == lobby_id)
+            .where(Game.completed_at.isnot(None))
+            .order_by(Game.completed_at, Team.id)
+            .limit(1)
+        ).first()
+        first_place_team_name = first_place_team.name if first_place_team else team.name
+
+        # Broadcast TEAM_PLACED to entire lobby so all teams see placements
+        team_placed_event = TeamPlacedEvent(
+            team_id=team.id,
+            team_name=team.name,
+            team_color=team.color,
+            placement=placement,
+            points_earned=0,  # Will be calculated in Phase 5
+            completed_at=game.completed_at.isoformat()
+            if game.completed_at
+            else datetime.now(tz=timezone.utc).isoformat(),
+            first_place_team_name=first_place_team_name,
+        )
+        await websocket_manager.broadcast_to_lobby(lobby_id, team_placed_event)
+        await commentate(lobby_id, commentary.ladder_completed(team.name, placement))
+
+        incomplete_games = (
+            session.exec(
+                select(func.count(Game.id))
+                .join(Team, Team.game_id == Game.id)
+                .where(Team.lobby_id == lobby_id)
+                .where(Game.completed_at.is_(None))
+            ).first()
+            or 0
+        )
+        if incomplete_games == 0:
+            ready_players = session.exec(
+                select(Player).where(Player.lobby_id == lobby_id, Player.is_ready.is_(True))
+            ).all()
+            if ready_players:
+                for ready_player in ready_players:
+                    ready_player.is_ready = False
+                    session.add(ready_player)
+                bump_roster_version(session, lobby_id)
+                session.commit()
+
+                await websocket_manager.broadcast_to_lobby(
+                    lobby_id,
+                    GameEndedEvent(lobby_id=lobby_id),
+                )
+                await broadcast_puzzle_credits(lobby_id, session, websocket_manager)
+            round_number = await record_finished_round(lobby_id, game, session, websocket_manager)
+            if round_number:
+                phase = await begin_intermission(session, lobby_id, round_number)
+            else:
+                phase = get_lobby_phase(session, lobby_id)  # Finished again after an admin rollback
+            await announce_phase_change(lobby_id, LobbyPhase.IN_PROGRESS, phase)
+
+
 ####################################################################
 # ? API ENDPOINTS
 ####################################################################
 
 
+@router.post("/admin/lobby/{lobby_id}/game/start", response_model=StartGameResponse)
 @router.post("/admin/lobby/{lobby_id}/start", response_model=StartGameResponse)
 async def start_game(
     lobby_id: int,
@@ -118,70 +433,75 @@ async def start_game(
     2. Assigns puzzles to each team based on configuration (same or different)
     3. Initializes team state machines
     4. Broadcasts GAME_STARTED event to all players
+    5. Broadcasts LOBBY_PHASE_CHANGED (waiting/finished/intermission -> in_progress)
+    6. Arms the round timer when the lobby has a round time limit
     """
     # Validate puzzle_mode and word_count_mode
     if request.puzzle_mode not in ["same", "different"]:
-        raise HTTPException(status_code=400, detail="puzzle_mode must be 'same' or 'different'")
+        raise InvalidRequestError("puzzle_mode must be 'same' or 'different'")
     if request.word_count_mode not in ["exact", "balanced"]:
-        raise HTTPException(status_code=400, detail="word_count_mode must be 'exact' or 'balanced'")
+        raise InvalidRequestError("word_count_mode must be 'exact' or 'balanced'")
 
     # Check if lobby exists
     lobby = session.get(Lobby, lobby_id)
     if not lobby:
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
-    # Check if there's an active (not completed) game assigned to any team
-    active_game = session.exec(
-        select(Game)
-        .join(Team, Team.game_id == Game.id)
-        .where(Team.lobby_id == lobby_id)
-        .where(Game.completed_at.is_(None))
-    ).first()
-    if active_game:
-        raise HTTPException(
-            status_code=400,
-            detail="A game is currently in progress. Wait for it to complete before starting a new one.",
+    # Starting is only allowed from waiting, finished or intermission; a round already in progress has to end first
+    previous_phase = get_lobby_phase(session, lobby_id)
+    try:
+        check_transition(previous_phase, LobbyPhase.IN_PROGRESS)
+    except InvalidPhaseTransition:
+        raise InvalidRequestError(
+            "A game is currently in progress. Wait for it to complete before starting a new one."
         )
 
     # Get all teams in the lobby
-    teams = session.exec(select(Team).where(Team.lobby_id == lobby_id)).all()
+    teams = session.exec(select(Team).where(Team.lobby_id == lobby_id).order_by(Team.id)).all()
     if not teams:
-        raise HTTPException(status_code=400, detail="No teams in lobby")
+        raise InvalidRequestError("No teams in lobby")
 
     # Validate all players with teams are ready (unless force_start is True)
     all_players = session.exec(select(Player).where(Player.lobby_id == lobby_id)).all()
     players_with_teams = [p for p in all_players if p.team_id is not None]
 
     if not players_with_teams:
-        raise HTTPException(status_code=400, detail="No players assigned to teams")
+        raise InvalidRequestError("No players assigned to teams")
 
     unready_players = [p for p in players_with_teams if not p.is_ready]
     if unready_players and not request.force_start:
         unready_names = ", ".join([p.name for p in unready_players])
-        raise HTTPException(status_code=400, detail=f"Not all players are ready. Waiting for: {unready_names}")
+        raise InvalidRequestError(f"Not all players are ready. Waiting for: {unready_names}")
 
     used_puzzle_paths = session.exec(select(Game.puzzle_path).where(Game.lobby_id == lobby_id)).all()
     used_puzzle_paths = {path for path in used_puzzle_paths if path}
 
+    # Each round draws from its own stream of the lobby seed, so a cloned lobby gets the same puzzles round by round
+    round_number = (
+        session.exec(select(func.max(RoundResult.round_number)).where(RoundResult.lobby_id == lobby_id)).first() or 0
+    ) + 1
+    rng = seeded_rng(lobby.rng_seed, f"puzzles:{round_number}")
+
     # Get puzzles for each team based on configuration
     puzzle_manager = get_puzzle_manager()
     try:
-        if request.puzzle_date:
-            # If a specific date is requested, all teams get the same puzzle for that date
-            date_parts = request.puzzle_date.split("-")
-            if len(date_parts) == 3:
-                puzzle_path_str = f"{date_parts[0]}/{date_parts[1]}/{date_parts[2]}.json"
-                puzzle = puzzle_manager.load_puzzle_by_path(puzzle_path_str)
-                puzzle_file = __import__("backend.game.puzzles").game.puzzles.PuzzleFile(
-                    puzzle=puzzle, path=puzzle_manager.resolve_puzzle_path(puzzle_path_str)
-                )
-                puzzles = [puzzle_file] * len(teams)
+        if request.puzzle_id is not None or request.puzzle_date:
+            # A specific stored puzzle or date means all teams get that same puzzle
+            if request.puzzle_id is not None:
+                # Stored puzzles stay inside their organization, like the lobbies playing them
+                stored_puzzle = session.get(StoredPuzzle, request.puzzle_id)
+                if stored_puzzle and stored_puzzle.organization_id != lobby.organization_id:
+                    raise NotFoundError("Puzzle not found")
+                puzzle_path_str = stored_puzzle_path(request.puzzle_id)
             else:
-                raise ValueError("Invalid date format. Expected YYYY-MM-DD")
+                puzzle_path_str = puzzle_manager.puzzle_path_for_date(request.puzzle_date)
+            puzzle = puzzle_manager.load_puzzle_by_path(puzzle_path_str)
+            puzzle_file = PuzzleFile(puzzle=puzzle, path=puzzle_manager.resolve_puzzle_path(puzzle_path_str))
+            puzzles = [puzzle_file] * len(teams)
         elif request.puzzle_mode == "same":
             # All teams get the same puzzle
             puzzles = puzzle_manager.get_same_puzzle_for_teams(
-                len(teams), request.difficulty, exclude_paths=used_puzzle_paths
+                len(teams), request.difficulty, exclude_paths=used_puzzle_paths, rng=rng
             )
         else:
             # Each team gets a different puzzle
@@ -190,13 +510,15 @@ async def start_game(
                 request.difficulty,
                 request.word_count_mode,
                 exclude_paths=used_puzzle_paths,
+                rng=rng,
             )
     except ValueError as e:
-        raise HTTPException(status_code=400, detail=str(e))
+        raise InvalidRequestError(str(e))
 
     # Create a Game (puzzle assignment) for each team
     from backend.websocket.managers import lobby_websocket_manager
 
+    delivery = DeliveryReport(GameWebSocketEvents.GAME_STARTED.value)
     for i, team in enumerate(teams):
         puzzle_file = puzzles[i]
         puzzle = puzzle_file.puzzle
@@ -233,7 +555,7 @@ async def start_game(
             puzzle_title=puzzle.meta.title,
             puzzle_length=len(puzzle.ladder),
         )
-        await lobby_websocket_manager.broadcast_to_team(lobby_id, team.id, event)
+        delivery.merge(await lobby_websocket_manager.broadcast_to_team(lobby_id, team.id, event, requires_ack=True))
 
     # Also broadcast GAME_STARTED to lobby (for admins) using the first team's event
     first_team_event = GameStartedEvent(
@@ -242,9 +564,20 @@ async def start_game(
         puzzle_length=len(puzzles[0].puzzle.ladder),
     )
     await lobby_websocket_manager.broadcast_to_lobby(lobby_id, first_team_event)
+    await commentate(lobby_id, commentary.round_started(round_number, len(teams)))
 
+    lobby.intermission_started_at = None  # Starting a round is how intermission ends
+    session.add(lobby)
     session.commit()
 
+    await end_reveal(lobby_id, completed=False)
+    await announce_phase_change(lobby_id, previous_phase, LobbyPhase.IN_PROGRESS)
+
+    if lobby.round_time_limit_seconds:
+        from backend.api.admin.lobby.timer_poller import arm_round_timer
+
+        await arm_round_timer(session, lobby_id, lobby.round_time_limit_seconds)
+
     # Return the first game ID (doesn't matter which one for response)
     first_game = session.exec(select(Game).where(Game.lobby_id == lobby_id)).first()
 
@@ -252,6 +585,7 @@ async def start_game(
         success=True,
         game_id=first_game.id if first_game else 0,
         message=f"Game started with {len(teams)} teams",
+        delivery=DeliverySummary.of(delivery),
     )
 
 
@@ -266,20 +600,20 @@ async def get_team_puzzle(
     Returns the full puzzle data and current game state so the frontend can initialize.
     """
     if not player_session_id:
-        raise HTTPException(status_code=401, detail="Player session ID required")
+        raise UnauthorizedError("Player session ID required")
 
     # Get player
     player = session.exec(select(Player).where(Player.session_id == player_session_id)).first()
     if not player:
-        raise HTTPException(status_code=404, detail="Player not found")
+        raise NotFoundError("Player not found")
 
     if not player.team_id:
-        raise HTTPException(status_code=400, detail="Player not assigned to a team")
+        raise InvalidRequestError("Player not assigned to a team")
 
     # Get team
     team = session.get(Team, player.team_id)
     if not team:
-        raise HTTPException(status_code=404, detail="Team not found")
+        raise NotFoundError("Team not found")
 
     game_id = team.game_id
     if not game_id:
@@ -290,13 +624,13 @@ async def get_team_puzzle(
             .limit(1)
         ).first()
         if not last_round:
-            raise HTTPException(status_code=400, detail="Game not started yet")
+            raise InvalidRequestError("Game not started yet")
         game_id = last_round.game_id
 
     # Get game (puzzle)
     game = session.get(Game, game_id)
     if not game:
-        raise HTTPException(status_code=404, detail="Game not found")
+        raise NotFoundError("Game not found")
 
     # Build current team state
     machine = get_team_state_machine(team, game)
@@ -329,12 +663,12 @@ async def get_timer_state(
     Returns whether a timer is active and when it expires.
     """
     if not player_session_id:
-        raise HTTPException(status_code=401, detail="Player session ID required")
+        raise UnauthorizedError("Player session ID required")
 
     # Get player
     player = session.exec(select(Player).where(Player.session_id == player_session_id)).first()
     if not player:
-        raise HTTPException(status_code=404, detail="Player not found")
+        raise NotFoundError("Player not found")
 
     lobby_id = player.lobby_id
 
@@ -374,15 +708,165 @@ async def get_timer_state(
     }
 
 
+@router.get("/game/progress", response_model=TeamProgressResponse)
+async def get_team_progress(
+    player: Player = Depends(require_player_session),
+    session: Session = Depends(get_session),
+):
+    """
+    Progress bars for every team in the player's lobby.
+
+    Other teams are masked to the lobby's progress visibility setting; with "none" only the player's own team is listed.
+    """
+    lobby = session.get(Lobby, player.lobby_id)
+    if not lobby:
+        raise NotFoundError("Lobby not found")
+
+    puzzle_manager = get_puzzle_manager()
+    rows = session.exec(
+        select(Team, Game).join(Game, Team.game_id == Game.id).where(Team.lobby_id == lobby.id).order_by(Team.id)
+    ).all()
+
+    teams = []
+    for team, game in rows:
+        is_own_team = team.id == player.team_id
+        ladder_length = len(puzzle_manager.load_puzzle_by_path(game.puzzle_path).ladder)
+        revealed_steps = (
+            json.loads(game.revealed_steps) if isinstance(game.revealed_steps, str) else game.revealed_steps
+        )
+        revealed_count = len(revealed_steps) if revealed_steps else 2
+        visible = visible_progress(
+            "exact" if is_own_team else lobby.progress_visibility,
+            revealed_count,
+            ladder_length,
+            game.completed_at is not None,
+        )
+        if not visible:
+            continue
+        teams.append(
+            TeamProgressSnapshot(
+                team_id=team.id,
+                team_name=team.name,
+                team_color=team.color,
+                is_own_team=is_own_team,
+                progress=visible.progress,
+                solved_count=visible.solved_count,
+                total_count=visible.total_count,
+                is_completed=visible.is_completed,
+            )
+        )
+
+    return TeamProgressResponse(visibility=lobby.progress_visibility, teams=teams)
+
+
 ####################################################################
 # ? WEBSOCKET HANDLERS
 ####################################################################
 
 
+async def submit_team_guess(
+    lobby_id: int,
+    player: Player,
+    team: Team,
+    game: Game,
+    guess_text: str,
+    word_index: int,
+    session: Session,
+    websocket_manager,
+    client_guess_id: str | None = None,
+    guessed_at: datetime | None = None,
+) -> GuessResult:
+    """
+    Judge a guess against the team's puzzle, record it, and broadcast the outcome.
+
+    Shared by the WebSocket handler and POST /lobby/guess so both paths keep every client on the team in sync.
+    guessed_at dates a guess the client queued while offline (see backend/offline_guesses.py); it's recorded at
+    that time, and so is the team's finish if it completes the ladder, and it may take a rung solved after it.
+
+    Returns:
+        The state machine's result; new_state is set when the guess revealed a word
+    """
+    machine = get_team_state_machine(team, game)
+    result: GuessResult = machine.submit_guess(guess_text, word_index)
+
+    # Save guess to database (only if we want to track it)
+    guess = Guess(
+        team_id=team.id,
+        player_id=player.id,
+        game_id=team.game_id,
+        word_index=word_index,
+        direction="",  # Direction is client-side only now
+        guess=guess_text,
+        is_correct=result.is_correct,
+        client_guess_id=client_guess_id,
+    )
+    if guessed_at:
+        guess.created_at = guessed_at
+    session.add(guess)
+
+    # Handle already solved (race condition)
+    overtaken = overtaken_solve(session, machine.puzzle, guess) if result.already_solved and guessed_at else None
+    if overtaken:
+        # Typed before the teammate's solve, so it's the one that counts; the team's progress is unchanged
+        overtaken.is_correct = False
+        guess.is_correct = True
+        session.commit()
+        return GuessResult(
+            is_correct=True, word_index=word_index, expected_word=machine.puzzle.ladder[word_index].word.upper()
+        )
+    if result.already_solved:
+        event = AlreadySolvedEvent(
+            team_id=team.id,
+            word_index=word_index,
+        )
+        # Send only to the player who submitted
+        await websocket_manager.send_to_player(lobby_id, player.session_id, event)
+        session.commit()
+        return result
+
+    # Open-ended rung: queue the answer for the host instead of judging it
+    if result.pending_review:
+        guess.review_status = "pending"
+        session.commit()
+        session.refresh(guess)
+        pending_event = GuessPendingReviewEvent(
+            team_id=team.id,
+            guess_id=guess.id,
+            player_id=player.id,
+            player_name=player.name,
+            word_index=word_index,
+            guess=guess_text,
+        )
+        await websocket_manager.broadcast_to_team(lobby_id, team.id, pending_event)
+        await websocket_manager.admin_web_socket_manager.broadcast_to_lobby(lobby_id, pending_event)
+        return result
+
+    # Broadcast guess to team
+    guess_event = GuessSubmittedEvent(
+        team_id=team.id,
+        player_id=player.id,
+        player_name=player.name,
+        word_index=word_index,
+        guess=guess_text,
+        is_correct=result.is_correct,
+        direction="",  # Not relevant anymore
+    )
+    await websocket_manager.broadcast_to_team(lobby_id, team.id, guess_event)
+
+    # If correct, update state and broadcast
+    if result.is_correct and result.new_state:
+        await apply_correct_guess(
+            lobby_id, team, player, game, result, session, websocket_manager, solved_at=guessed_at
+        )
+
+    session.commit()
+    return result
+
+
 async def handle_guess_submission(
     lobby_id: int,
     player_session_id: str,
-    message: dict,
+    message: SubmitGuessMessage,
     websocket_manager,
 ):
     """
@@ -394,7 +878,7 @@ async def handle_guess_submission(
     Args:
         lobby_id: Lobby ID
         player_session_id: Player's session ID
-        message: Message data containing the guess and word_index
+        message: The parsed guess and its word_index
         websocket_manager: WebSocket manager instance
     """
     from backend.database import get_session_context
@@ -423,159 +907,97 @@ async def handle_guess_submission(
                 websocket_logger.warning(f"No active game for team {team.id}")
                 return
 
-            # Get state machine
-            machine = get_team_state_machine(team, game)
-
-            # Submit guess
-            guess_text = message.get("guess", "").strip()
-            word_index = message.get("word_index", -1)
-
-            result: GuessResult = machine.submit_guess(guess_text, word_index)
-
-            # Save guess to database (only if we want to track it)
-            guess = Guess(
-                team_id=team.id,
-                player_id=player.id,
-                game_id=team.game_id,
-                word_index=word_index,
-                direction="",  # Direction is client-side only now
-                guess=guess_text,
-                is_correct=result.is_correct,
-            )
-            session.add(guess)
+            if find_replayed_guess(session, player.id, message.client_guess_id):
+                websocket_logger.info(f"Dropping resent guess: client_guess_id={message.client_guess_id}")
+                return
 
-            # Handle already solved (race condition)
-            if result.already_solved:
-                event = AlreadySolvedEvent(
-                    team_id=team.id,
-                    word_index=word_index,
-                )
-                # Send only to the player who submitted
-                await websocket_manager.send_to_player(lobby_id, player_session_id, event)
-                session.commit()
+            wait = guess_retry_after(player_session_id)
+            if wait is not None:
+                websocket_logger.warning(f"Guess rate limited: session_id={player_session_id} retry_after={wait}")
                 return
 
-            # Broadcast guess to team
-            guess_event = GuessSubmittedEvent(
-                team_id=team.id,
-                player_id=player.id,
-                player_name=player.name,
-                word_index=word_index,
-                guess=guess_text,
-                is_correct=result.is_correct,
-                direction="",  # Not relevant anymore
+            guess_text = message.guess.strip()
+            await submit_team_guess(
+                lobby_id,
+                player,
+                team,
+                game,
+                guess_text,
+                message.word_index,
+                session,
+                websocket_manager,
+                client_guess_id=message.client_guess_id,
             )
-            await websocket_manager.broadcast_to_team(lobby_id, team.id, guess_event)
-
-            # If correct, update state and broadcast
-            if result.is_correct and result.new_state:
-                # Save new state
-                save_game_state(game, result.new_state, session)
-
-                # Broadcast word solved event
-                word_solved_event = WordSolvedEvent(
-                    team_id=team.id,
-                    player_id=player.id,
-                    player_name=player.name,
-                    word_index=word_index,
-                    word=result.expected_word or "",
-                    direction="",  # Not relevant
-                )
-                await websocket_manager.broadcast_to_team(lobby_id, team.id, word_solved_event)
-
-                # Broadcast state update
-                state_event = StateUpdateEvent(
-                    team_id=team.id,
-                    revealed_steps=sorted(list(result.new_state.revealed_steps)),
-                    is_completed=result.new_state.is_completed,
-                    last_updated_at=result.new_state.last_updated_at.isoformat(),
-                )
-                await websocket_manager.broadcast_to_team(lobby_id, team.id, state_event)
-                # Also broadcast to admins so they can see team progress
-                await websocket_manager.admin_web_socket_manager.broadcast_to_lobby(lobby_id, state_event)
-
-                # If completed, broadcast team completion
-                if result.new_state.is_completed:
-                    team_completed_event = TeamCompletedEvent(
-                        team_id=team.id,
-                        team_name=team.name,
-                        completed_at=game.completed_at.isoformat()
-                        if game.completed_at
-                        else datetime.now(tz=timezone.utc).isoformat(),
-                    )
-                    await websocket_manager.broadcast_to_team(lobby_id, team.id, team_completed_event)
-                    # Also broadcast to admins
-                    await websocket_manager.admin_web_socket_manager.broadcast_to_lobby(lobby_id, team_completed_event)
-
-                    # Calculate placement by counting prior completions (ordered by completed_at, then id for tiebreaker)
-                    prior_completions = (
-                        session.exec(
-                            select(func.count(Team.id))
-                            .join(Game, Team.game_id == Game.id)
-                            .where(Team.lobby_id == lobby_id)
-                            .where(Game.completed_at.isnot(None))
-                            .where(
-                                (Game.completed_at < game.completed_at)
-                                | ((Game.completed_at == game.completed_at) & (Team.id < team.id))
-                            )
-                        ).first()
-                        or 0
-                    )
-                    placement = prior_completions + 1
-
-                    # Get the current first place team
-                    first_place_team = session.exec(
-                        select(Team)
-                        .join(Game, Team.game_id == Game.id)
-                        .where(Team.lobby_id == lobby_id)
-                        .where(Game.completed_at.isnot(None))
-                        .order_by(Game.completed_at, Team.id)
-                        .limit(1)
-                    ).first()
-                    first_place_team_name = first_place_team.name if first_place_team else team.name
-
-                    # Broadcast TEAM_PLACED to entire lobby so all teams see placements
-                    team_placed_event = TeamPlacedEvent(
-                        team_id=team.id,
-                        team_name=team.name,
-                        placement=placement,
-                        points_earned=0,  # Will be calculated in Phase 5
-                        completed_at=game.completed_at.isoformat()
-                        if game.completed_at
-                        else datetime.now(tz=timezone.utc).isoformat(),
-                        first_place_team_name=first_place_team_name,
-                    )
-                    await websocket_manager.broadcast_to_lobby(lobby_id, team_placed_event)
-
-                    incomplete_games = (
-                        session.exec(
-                            select(func.count(Game.id))
-                            .join(Team, Team.game_id == Game.id)
-                            .where(Team.lobby_id == lobby_id)
-                            .where(Game.completed_at.is_(None))
-                        ).first()
-                        or 0
-                    )
-                    if incomplete_games == 0:
-                        ready_players = session.exec(
-                            select(Player).where(Player.lobby_id == lobby_id, Player.is_ready.is_(True))
-                        ).all()
-                        if ready_players:
-                            for ready_player in ready_players:
-                                ready_player.is_ready = False
-                                session.add(ready_player)
-                            session.commit()
-
-                            await websocket_manager.broadcast_to_lobby(
-                                lobby_id,
-                                {"type": "game_ended", "lobby_id": lobby_id},
-                            )
-
-            session.commit()
 
         except Exception as e:
             websocket_logger.exception(f"Error handling guess submission: {e}")
             session.rollback()
 
 
+def save_chat_message(session: Session, player: Player, text: str, sent_at: datetime):
+    """Store a chat line and drop the team's lines past the newest TEAM_CHAT_HISTORY_SIZE."""
+    session.add(
+        ChatMessage(
+            lobby_id=player.lobby_id,
+            team_id=player.team_id,
+            player_id=player.id,
+            player_name=player.name,
+            text=text,
+            created_at=sent_at,
+        )
+    )
+    session.flush()
+    newest = (
+        select(ChatMessage.id)
+        .where(ChatMessage.team_id == player.team_id)
+        .order_by(ChatMessage.id.desc())
+        .limit(settings.TEAM_CHAT_HISTORY_SIZE)
+    )
+    session.execute(
+        delete(ChatMessage).where(ChatMessage.team_id == player.team_id, ChatMessage.id.not_in(newest))
+    )
+    session.commit()
+
+
+async def handle_team_chat(
+    lobby_id: int,
+    player_session_id: str,
+    message: TeamChatMessage,
+    websocket_manager,
+):
+    """
+    Relay a chat line to the sender's team (and the lobby's admins) via WebSocket.
+
+    Args:
+        lobby_id: Lobby ID
+        player_session_id: Player's session ID
+        message: The parsed chat message, already trimmed and length-checked
+        websocket_manager: WebSocket manager instance
+    """
+    from backend.database import get_session_context
+
+    if not feature_flags.is_enabled(Feature.TEAM_CHAT):
+        websocket_logger.info(f"Chat from {player_session_id} dropped: team chat is switched off")
+        return
+
+    async with get_session_context() as session:
+        player = session.exec(select(Player).where(Player.session_id == player_session_id)).first()
+        if not player or not player.team_id or player.lobby_id != lobby_id:
+            websocket_logger.warning(f"Chat from {player_session_id} dropped: player not found or not on a team")
+            return
+
+        sent_at = datetime.now(timezone.utc)
+        if settings.TEAM_CHAT_HISTORY_SIZE > 0:
+            save_chat_message(session, player, message.text, sent_at)
+        event = TeamChatEvent(
+            team_id=player.team_id,
+            player_id=player.id,
+            player_name=player.name,
+            text=message.text,
+            sent_at=sent_at.isoformat(),
+        )
+    await websocket_manager.broadcast_to_team(lobby_id, event.team_id, event)
+    await websocket_manager.admin_web_socket_manager.broadcast_to_lobby(lobby_id, event)
+
+
 # Direction switching handler removed - direction is now client-side only
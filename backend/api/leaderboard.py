@@ -1,11 +1,15 @@
 from typing import Optional
 
-from fastapi import APIRouter, Depends
+from fastapi import APIRouter, Depends, Query
 from pydantic import BaseModel
 from sqlmodel import Session, func, select
 
 from backend.database import get_session
-from backend.database.models import RoundResult, Team
+from backend.database.models import Lobby, RoundResult, Team
+from backend.errors import NotFoundError
+from backend.game.prizes import contest_prizes
+from backend.game.results import final_standings
+from backend.websocket.events import FinalStanding, PrizeAward
 
 router = APIRouter()
 
@@ -34,9 +38,16 @@ class LeaderboardResponse(BaseModel):
     last_round_game_id: Optional[int]  # to link to last results modal in lobby
 
 
-@router.get("/lobby/{lobby_id}/leaderboard", response_model=LeaderboardResponse)
-async def get_leaderboard(lobby_id: int, session: Session = Depends(get_session)):
-    """Get tournament leaderboard for a lobby."""
+class GameResultsResponse(BaseModel):
+    round_number: int  # 0 until a round has been scored
+    winning_team_id: Optional[int]
+    winning_team_name: Optional[str]
+    standings: list[FinalStanding]
+    prizes: list[PrizeAward]  # Contest-mode awards so far; [] outside contest mode
+
+
+def build_leaderboard(lobby_id: int, session: Session) -> LeaderboardResponse:
+    """Compute the tournament leaderboard for a lobby."""
     # Get all teams sorted by total points
     teams = session.exec(select(Team).where(Team.lobby_id == lobby_id).order_by(Team.total_points.desc())).all()
 
@@ -95,3 +106,29 @@ async def get_leaderboard(lobby_id: int, session: Session = Depends(get_session)
         total_rounds=current_round,
         last_round_game_id=last_round_game_id,
     )
+
+
+@router.get("/lobby/{lobby_id}/leaderboard", response_model=LeaderboardResponse)
+async def get_leaderboard(lobby_id: int, session: Session = Depends(get_session)):
+    """Get tournament leaderboard for a lobby."""
+    return build_leaderboard(lobby_id, session)
+
+
+@router.get("/lobby/{lobby_id}/results", response_model=GameResultsResponse)
+async def get_results(
+    lobby_id: int,
+    round_number: Optional[int] = Query(default=None, ge=1),
+    session: Session = Depends(get_session),
+):
+    """Final standings of a round for the results screen; the latest scored round unless round_number is given."""
+    if not session.get(Lobby, lobby_id):
+        raise NotFoundError("Lobby not found")
+    round_number, standings = final_standings(session, lobby_id, round_number)
+    winner = standings[0] if standings else None
+    return GameResultsResponse(
+        round_number=round_number,
+        winning_team_id=winner.team_id if winner else None,
+        winning_team_name=winner.team_name if winner else None,
+        standings=standings,
+        prizes=contest_prizes(session, lobby_id) if standings else [],
+    )
@@ -1,56 +1,387 @@
+import json
 import uuid
+from datetime import datetime, timezone
 
-from fastapi import APIRouter, Depends, HTTPException
+from fastapi import APIRouter, Depends, Query
 from sqlalchemy.orm import selectinload
-from sqlmodel import Session, select
-
+from sqlmodel import Session, func, select
+
+from backend.api.admin.lobby.team import create_teams, shuffle_teams
+from backend.api.game import StartGameResponse, get_team_state_machine, start_game, submit_team_guess
+from backend.api.lobby_phase import (
+    ensure_no_game_in_progress,
+    game_in_progress_error,
+    get_lobby_phase,
+    lobby_has_active_game,
+    smallest_team,
+)
+from backend.commentary import commentary_feed
 from backend.custom_logging import api_logger
-from backend.database import Lobby, Player, get_session
-from backend.dependencies import require_player_session
-from backend.schemas import LobbyInfo, MessageResponse, PlayerCreate
-from backend.websocket.events import DisconnectedLobbyEvent, JoinedLobbyEvent, ReadyStatusChangedEvent
-from backend.websocket.managers import lobby_websocket_manager
+from backend.database import ChatMessage, Game, Guess, Lobby, Player, Team, get_session
+from backend.dependencies import (
+    identify_player_session,
+    require_lobby_host,
+    require_lobby_member,
+    require_player_session,
+)
+from backend.errors import (
+    AppError,
+    ConflictError,
+    DatabaseError,
+    ErrorCode,
+    ForbiddenError,
+    InvalidRequestError,
+    NotFoundError,
+    TooManyRequestsError,
+)
+from backend.game.puzzles import get_puzzle_manager
+from backend.lobby_host import claim_host_if_free, hand_off_host, host_changed_event
+from backend.offline_guesses import find_replayed_guess, guessed_at, player_sightings, replayed_outcome
+from backend.quick_play import quick_play_state
+from backend.rate_limit import guess_retry_after, limit_guesses, limit_joins
+from backend.roster import bump_roster_version, in_join_order, next_join_order
+from backend.settings import settings
+from backend.spectators import spectator_store
+from backend.schemas import (
+    AdminStartGameRequest,
+    CommentaryFeedResponse,
+    HostStartGameRequest,
+    LobbyInfo,
+    MessageResponse,
+    PlayerCreate,
+    PlayerPreferences,
+    PollResponse,
+    GuessBatchRequest,
+    GuessBatchResponse,
+    GuessSubmitRequest,
+    GuessSubmitResponse,
+    QuickPlayInfoResponse,
+    RosterResponse,
+    SpectatorSessionResponse,
+    TeamChatEntry,
+    TeamCreate,
+    TeamChatHistoryResponse,
+    TeamGuessEntry,
+    TeamGuessHistoryResponse,
+    WebSocketTokenResponse,
+)
+from backend.utils.lobby_codes import normalize_lobby_code
+from backend.utils.name_filter import allowed_name
+from backend.utils.occupancy import effective_soft_cap, is_over_soft_cap
+from backend.utils.scheduling import build_lobby_schedule
+from backend.websocket.events import (
+    DisconnectedLobbyEvent,
+    JoinedLobbyEvent,
+    LateJoinEvent,
+    OccupancyWarningEvent,
+    PreferencesUpdatedEvent,
+    ReadyStatusChangedEvent,
+)
+from backend.websocket.connect_tokens import issue_connect_token
+from backend.websocket.managers import admin_web_socket_manager, lobby_websocket_manager
+from backend.websocket.prepared import PLAYER_AUDIENCE
 
 router = APIRouter()
 
 
-@router.post("/lobby/{lobby_code}", response_model=Player)
+@router.get("/quick-play", response_model=QuickPlayInfoResponse)
+async def get_quick_play_info(db: Session = Depends(get_session)):
+    """Where to join the server-run quick play lobby, for a "Quick Play" button on the landing page."""
+    if not settings.QUICK_PLAY_ENABLED:
+        return QuickPlayInfoResponse(enabled=False, code=None, player_count=0, next_round_at=None)
+
+    lobby = db.exec(select(Lobby).where(Lobby.is_quick_play.is_(True))).first()
+    player_count = db.exec(select(func.count(Player.id)).where(Player.lobby_id == lobby.id)).one() if lobby else 0
+    return QuickPlayInfoResponse(
+        enabled=True,
+        code=lobby.code if lobby else None,
+        player_count=player_count,
+        next_round_at=quick_play_state.next_round_at,
+    )
+
+
+async def judge_guess(
+    guess_request: GuessSubmitRequest, player: Player, db: Session, offline_since: datetime | None = None
+) -> GuessSubmitResponse:
+    """
+    Judge a guess sent over HTTP, or answer a resent one from the first time; raises AppError when it can't be.
+
+    Its submitted_at is only honoured for a queued guess, one with offline_since (see backend/offline_guesses.py).
+    """
+    replayed = find_replayed_guess(db, player.id, guess_request.client_guess_id)
+    if replayed:
+        state = get_team_state_machine(db.get(Team, replayed.team_id), replayed.game).get_current_state()
+        outcome = replayed_outcome(replayed, state.revealed_steps)
+        api_logger.info(
+            f"Guess resent: session_id={player.session_id} client_guess_id={replayed.client_guess_id} {outcome}"
+        )
+        return GuessSubmitResponse(
+            result=outcome,
+            word_index=replayed.word_index,
+            revealed_steps=sorted(state.revealed_steps),
+            is_completed=state.is_completed,
+            client_guess_id=replayed.client_guess_id,
+            duplicate=True,
+        )
+
+    guess_text = guess_request.guess.strip()
+    if not guess_text:
+        raise InvalidRequestError("Guess must not be empty")
+    if not player.team_id:
+        raise InvalidRequestError("You are not on a team")
+
+    team = db.get(Team, player.team_id)
+    game = db.get(Game, team.game_id) if team and team.game_id else None
+    if not game or game.completed_at is not None:
+        raise ConflictError("Your team has no puzzle in progress")
+
+    ladder_length = len(get_puzzle_manager().load_puzzle_by_path(game.puzzle_path).ladder)
+    if not 0 <= guess_request.word_index < ladder_length:
+        raise InvalidRequestError(f"word_index must be between 0 and {ladder_length - 1}")
+
+    result = await submit_team_guess(
+        player.lobby_id,
+        player,
+        team,
+        game,
+        guess_text,
+        guess_request.word_index,
+        db,
+        lobby_websocket_manager,
+        client_guess_id=guess_request.client_guess_id,
+        guessed_at=guessed_at(guess_request.submitted_at, game, offline_since) if offline_since else None,
+    )
+
+    if result.already_solved:
+        outcome = "already_solved"
+    elif result.pending_review:
+        outcome = "pending"
+    else:
+        outcome = "correct" if result.is_correct else "wrong"
+    db.refresh(game)
+    api_logger.info(f"Guess result for session_id={player.session_id}: {outcome}")
+    state = get_team_state_machine(team, game).get_current_state()
+    return GuessSubmitResponse(
+        result=outcome,
+        word_index=guess_request.word_index,
+        revealed_steps=sorted(state.revealed_steps),
+        is_completed=state.is_completed,
+        client_guess_id=guess_request.client_guess_id,
+    )
+
+
+# Registered before join_lobby so "guess" isn't taken for a lobby code
+@router.post("/lobby/guess", response_model=GuessSubmitResponse)
+async def submit_guess(
+    guess_request: GuessSubmitRequest,
+    player: Player = Depends(limit_guesses),
+    db: Session = Depends(get_session),
+):
+    """
+    Submit a guess for one of the team's rungs over HTTP, for clients that can't hold a WebSocket open.
+
+    The guess is judged exactly like one sent over the WebSocket, and the team gets the same events.
+    """
+    api_logger.info(
+        f"Guess submitted over HTTP: session_id={player.session_id} word_index={guess_request.word_index}"
+    )
+    return await judge_guess(guess_request, player, db)
+
+
+@router.post("/lobby/guess/batch", response_model=GuessBatchResponse)
+async def submit_queued_guesses(
+    batch: GuessBatchRequest,
+    player: Player = Depends(identify_player_session),
+    db: Session = Depends(get_session),
+):
+    """
+    Submit the guesses a client queued while it was offline, getting an outcome for each.
+
+    They're judged in the order they were typed (those without submitted_at last), each as POST /lobby/guess would,
+    except that one may be dated when it was typed, back to when the server last heard from the player, and with
+    OFFLINE_GUESS_JUDGE_AS_TYPED take a rung a teammate solved after that.
+    One that can't be judged, e.g. because the round ended or the player is over the guess rate limit, comes back
+    "rejected" with the reason rather than failing the rest; resent ones don't count against the limit.
+    """
+    api_logger.info(f"Queued guesses submitted: session_id={player.session_id} count={len(batch.guesses)}")
+    arrived_at = datetime.now(timezone.utc)
+    connected = lobby_websocket_manager.is_player_connected(player.session_id)
+    offline_since = player_sightings.offline_since(player.session_id, connected, now=arrived_at)
+    player_sightings.flushed(player.session_id, at=arrived_at)
+    order = sorted(range(len(batch.guesses)), key=lambda i: (batch.guesses[i].submitted_at or arrived_at, i))
+
+    results: dict[int, GuessSubmitResponse] = {}
+    for i in order:
+        guess_request = batch.guesses[i]
+        try:
+            if not find_replayed_guess(db, player.id, guess_request.client_guess_id):
+                wait = guess_retry_after(player.session_id)
+                if wait is not None:
+                    raise TooManyRequestsError("Too many guesses, slow down", retry_after=wait)
+            results[i] = await judge_guess(guess_request, player, db, offline_since=offline_since)
+        except AppError as e:
+            db.rollback()
+            api_logger.warning(f"Queued guess rejected: session_id={player.session_id} {e.message}")
+            results[i] = GuessSubmitResponse(
+                result="rejected",
+                word_index=guess_request.word_index,
+                revealed_steps=[],
+                is_completed=False,
+                client_guess_id=guess_request.client_guess_id,
+                detail=e.message,
+            )
+    return GuessBatchResponse(results=[results[i] for i in range(len(batch.guesses))])
+
+
+@router.post("/lobby/spectate/{lobby_code}", response_model=SpectatorSessionResponse)
+async def spectate_lobby(lobby_code: str, db: Session = Depends(get_session)):
+    """Issue a read-only spectator session for a public lobby; it doesn't count as a player or take a name."""
+    lobby_code = normalize_lobby_code(lobby_code)
+    lobby = db.exec(select(Lobby).where(Lobby.code == lobby_code)).first()
+    if not lobby:
+        api_logger.warning(f"Spectate failed: lobby not found for code={lobby_code}")
+        raise NotFoundError("Lobby not found")
+    if lobby.is_private:
+        api_logger.warning(f"Spectate refused: lobby is private code={lobby_code}")
+        raise ForbiddenError("This lobby is private")
+
+    session = spectator_store.create(lobby.id)
+    return SpectatorSessionResponse(
+        spectator_session_id=session.session_id, lobby_id=lobby.id, lobby_code=lobby.code, lobby_name=lobby.name
+    )
+
+
+@router.get("/lobby/{lobby_id}/commentary", response_model=CommentaryFeedResponse)
+async def get_commentary(lobby_id: int, db: Session = Depends(get_session)):
+    """A public lobby's recent commentary, for spectators and overlays catching up; new lines arrive as events."""
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        raise NotFoundError("Lobby not found")
+    if lobby.is_private:
+        raise ForbiddenError("This lobby is private")
+    return CommentaryFeedResponse(lines=commentary_feed.recent(lobby_id))
+
+
+@router.post("/lobby/ws-token", response_model=WebSocketTokenResponse)
+async def issue_websocket_token(player: Player = Depends(require_player_session)):
+    """A short-lived token for /ws/lobby/{lobby_id}/player?token=..., which keeps the session id out of the URL."""
+    token = issue_connect_token(player)
+    api_logger.info(f"Issued websocket connect token: player_id={player.id} lobby_id={player.lobby_id}")
+    return WebSocketTokenResponse(
+        token=token, lobby_id=player.lobby_id, expires_in_seconds=settings.WS_TOKEN_TTL_SECONDS
+    )
+
+
+@router.post("/lobby/{lobby_code}", response_model=Player, dependencies=[Depends(limit_joins)])
 async def join_lobby(
     lobby_code: str,
     player_data: PlayerCreate,
     db: Session = Depends(get_session),
 ):
+    lobby_code = normalize_lobby_code(lobby_code)
     lobby = db.exec(select(Lobby).where(Lobby.code == lobby_code)).first()
     if not lobby:
         api_logger.warning(f"Join failed: lobby not found for code={lobby_code}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
-
-    existing_player = db.exec(
-        select(Player).where(Player.lobby_id == lobby.id, Player.name == player_data.name)
-    ).first()
+        raise NotFoundError("Lobby not found")
+
+    if lobby.locked:
+        api_logger.warning(f"Join failed: lobby is locked code={lobby_code}")
+        raise ConflictError("This lobby isn't accepting new players", code=ErrorCode.LOBBY_LOCKED)
+    if lobby.max_players is not None:
+        player_count = db.exec(select(func.count(Player.id)).where(Player.lobby_id == lobby.id)).one()
+        if player_count >= lobby.max_players:
+            api_logger.warning(f"Join failed: lobby is full code={lobby_code} max_players={lobby.max_players}")
+            raise ConflictError("This lobby is full", code=ErrorCode.LOBBY_FULL, max_players=lobby.max_players)
+
+    name = allowed_name(player_data.name, reserve=True)
+    existing_player = db.exec(select(Player).where(Player.lobby_id == lobby.id, Player.name == name)).first()
     if existing_player:
-        api_logger.warning(f"Join failed: player name already taken in lobby code={lobby_code} name={player_data.name}")
-        raise HTTPException(status_code=400, detail="Player name already taken in this lobby")
+        api_logger.warning(f"Join failed: player name already taken in lobby code={lobby_code} name={name}")
+        raise InvalidRequestError("Player name already taken in this lobby", code=ErrorCode.NAME_TAKEN)
+
+    late_team = None
+    if lobby_has_active_game(db, lobby.id):
+        late_team = smallest_team(db, lobby.id) if lobby.late_join_enabled else None
+        if not late_team:
+            raise game_in_progress_error(db, lobby, "join")
 
     session_id = str(uuid.uuid4())
 
-    player = Player(**player_data.model_dump(), session_id=session_id, lobby_id=lobby.id)
+    preferences = player_data.preferences or PlayerPreferences()
+    player = Player(
+        name=name,
+        **preferences.model_dump(),
+        session_id=session_id,
+        lobby_id=lobby.id,
+        team_id=late_team.id if late_team else None,
+        join_order=next_join_order(db, lobby.id),
+    )
     db.add(player)
+    db.flush()
+    is_host = claim_host_if_free(lobby, player)
+    db.add(lobby)
+    roster_version = bump_roster_version(db, lobby.id)
     db.commit()
     db.refresh(player)
     api_logger.info(f"New player created session_id={player.session_id} lobby_id={lobby.id} name={player.name}")
 
+    if late_team:
+        lobby_websocket_manager.register_player_team(player.session_id, late_team.id)
+        api_logger.info(f"Late join: session_id={player.session_id} placed on team_id={late_team.id}")
+        try:
+            await lobby_websocket_manager.broadcast_to_lobby(
+                lobby.id,
+                LateJoinEvent(
+                    lobby_id=lobby.id,
+                    player_session_id=player.session_id,
+                    player_id=player.id,
+                    player_name=player.name,
+                    team_id=late_team.id,
+                    team_name=late_team.name,
+                    team_color=late_team.color,
+                    roster_version=roster_version,
+                ),
+            )
+        except Exception as e:
+            api_logger.exception(f"Failed to broadcast late join for session {player.session_id}: {e}")
+
     try:
         await lobby_websocket_manager.broadcast_to_lobby(
             lobby.id,
-            JoinedLobbyEvent(lobby_id=lobby.id, player_session_id=player.session_id),
+            JoinedLobbyEvent(lobby_id=lobby.id, player_session_id=player.session_id, roster_version=roster_version),
         )
     except Exception as e:
         api_logger.exception(f"Failed to broadcast lobby join for session {player.session_id}: {e}")
 
+    if is_host:
+        await lobby_websocket_manager.broadcast_to_lobby(lobby.id, host_changed_event(lobby.id, player))
+
+    await warn_if_over_soft_cap(db, lobby, player)
+
     return player
 
 
+async def warn_if_over_soft_cap(db: Session, lobby: Lobby, player: Player):
+    """Joins past the soft cap are admitted; this just gives the lobby's admins a heads-up."""
+    cap = effective_soft_cap(lobby.soft_player_cap, settings.LOBBY_SOFT_PLAYER_CAP)
+    if cap is None:
+        return
+    player_count = db.exec(select(func.count(Player.id)).where(Player.lobby_id == lobby.id)).one()
+    if not is_over_soft_cap(player_count, cap):
+        return
+
+    api_logger.warning(f"Lobby over soft cap: lobby_id={lobby.id} players={player_count} soft_player_cap={cap}")
+    try:
+        await admin_web_socket_manager.broadcast_to_lobby(
+            lobby.id,
+            OccupancyWarningEvent(
+                lobby_id=lobby.id, player_name=player.name, player_count=player_count, soft_player_cap=cap
+            ),
+        )
+    except Exception as e:
+        api_logger.exception(f"Failed to send occupancy warning for lobby {lobby.id}: {e}")
+
+
 @router.get("/lobby/active", response_model=Player)
 async def get_active_user(
     player: Player = Depends(require_player_session),
@@ -75,7 +406,7 @@ async def get_current_lobby(
         api_logger.warning(
             f"Current lobby fetch failed: lobby not found for player session_id={player.session_id} lobby_id={player.lobby_id}"
         )
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
     api_logger.info(f"Returning current lobby id={lobby.id} for player session_id={player.session_id}")
     return lobby
@@ -89,6 +420,8 @@ async def leave_current_lobby(
     """Remove the authenticated player from their current lobby and notify others."""
     api_logger.info(f"Player leave request: session_id={player.session_id}")
 
+    ensure_no_game_in_progress(db, db.get(Lobby, player.lobby_id), "leave the lobby")
+
     lobby_id = player.lobby_id
     team_id = player.team_id
     player_session_id = player.session_id
@@ -102,28 +435,65 @@ async def leave_current_lobby(
         db.commit()
 
     try:
+        new_host = hand_off_host(db, db.get(Lobby, lobby_id), player.id)
         db.delete(player)
+        roster_version = bump_roster_version(db, lobby_id)
         db.commit()
         api_logger.info(f"Player deleted session_id={player_session_id} lobby_id={lobby_id}")
     except Exception as e:
         api_logger.exception(f"Failed to delete player {player_session_id}: {e}")
-        raise HTTPException(status_code=500, detail="Failed to remove player")
+        raise DatabaseError("Failed to remove player")
 
     try:
         await lobby_websocket_manager.broadcast_to_lobby(
             lobby_id,
-            DisconnectedLobbyEvent(lobby_id=lobby_id, player_session_id=player_session_id),
+            DisconnectedLobbyEvent(
+                lobby_id=lobby_id, player_session_id=player_session_id, roster_version=roster_version
+            ),
         )
     except Exception as e:
         api_logger.exception(f"Failed to broadcast player left for session {player_session_id}: {e}")
+    if new_host:
+        await lobby_websocket_manager.broadcast_to_lobby(lobby_id, host_changed_event(lobby_id, new_host))
 
     return MessageResponse(status=True, message="Player left lobby successfully")
 
 
+@router.post("/lobby/host/teams", response_model=MessageResponse)
+async def host_create_teams(
+    team_data: TeamCreate,
+    host: Player = Depends(require_lobby_host),
+    db: Session = Depends(get_session),
+):
+    """The host splits their lobby into teams, like POST /api/admin/lobby/{lobby_id}/team."""
+    api_logger.info(f"Host requested team creation: player_id={host.id} lobby_id={host.lobby_id}")
+    return await create_teams(host.lobby_id, team_data, db)
+
+
+@router.post("/lobby/host/teams/shuffle", response_model=MessageResponse)
+async def host_shuffle_teams(
+    host: Player = Depends(require_lobby_host),
+    db: Session = Depends(get_session),
+):
+    api_logger.info(f"Host requested team shuffle: player_id={host.id} lobby_id={host.lobby_id}")
+    return await shuffle_teams(host.lobby_id, db)
+
+
+@router.post("/lobby/host/game/start", response_model=StartGameResponse)
+async def host_start_game(
+    request: HostStartGameRequest,
+    host: Player = Depends(require_lobby_host),
+    db: Session = Depends(get_session),
+):
+    """The host starts a round with random puzzles; picking stored puzzles or dates stays with admins."""
+    api_logger.info(f"Host requested game start: player_id={host.id} lobby_id={host.lobby_id}")
+    return await start_game(host.lobby_id, AdminStartGameRequest(**request.model_dump()), session=db, is_admin=True)
+
+
 @router.get("/lobby/{lobby_id}", response_model=LobbyInfo)
 async def get_lobby_info(
     lobby_id: int,
-    player: Player = Depends(require_player_session),
+    player: Player = Depends(require_lobby_member),
     db: Session = Depends(get_session),
 ):
     api_logger.info(f"Player requesting lobby info: lobby_id={lobby_id}, session_id={player.session_id}")
@@ -133,9 +503,9 @@ async def get_lobby_info(
     ).first()
     if not lobby:
         api_logger.warning(f"Lobby not found lobby_id={lobby_id}")
-        raise HTTPException(status_code=404, detail="Lobby not found")
+        raise NotFoundError("Lobby not found")
 
-    players = lobby.players
+    players = in_join_order(lobby.players)
     teams = lobby.teams
     api_logger.info(f"Found {len(players)} players in lobby_id={lobby.id}")
 
@@ -148,7 +518,68 @@ async def get_lobby_info(
         players_by_team[p.team_id].append(p)
     api_logger.info(f"Player returning lobby info for {lobby_id}: {len(teams)} teams, {len(players)} players")
 
-    return LobbyInfo(lobby=lobby, players=players, players_by_team=players_by_team, teams=teams)
+    return LobbyInfo(
+        lobby=lobby,
+        players=players,
+        players_by_team=players_by_team,
+        teams=teams,
+        schedule=build_lobby_schedule(lobby),
+        phase=get_lobby_phase(db, lobby_id),
+    )
+
+
+@router.get("/lobby/{lobby_id}/roster", response_model=RosterResponse)
+async def get_lobby_roster(
+    lobby_id: int,
+    since_version: int | None = Query(default=None, description="roster_version already rendered; omit for all"),
+    player: Player = Depends(require_lobby_member),
+    db: Session = Depends(get_session),
+):
+    """The lobby's players in join order, or just changed=false when since_version is still current."""
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        api_logger.warning(f"Roster fetch failed: lobby not found lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    if since_version == lobby.roster_version:
+        return RosterResponse(roster_version=lobby.roster_version, changed=False, players=[])
+    players = db.exec(select(Player).where(Player.lobby_id == lobby_id).order_by(Player.join_order, Player.id)).all()
+    return RosterResponse(roster_version=lobby.roster_version, changed=True, players=players)
+
+
+@router.get("/lobby/{lobby_id}/poll", response_model=PollResponse)
+async def poll_lobby_events(
+    lobby_id: int,
+    after_seq: int | None = Query(default=None, description='Highest "seq" already seen; omit to wait for new ones'),
+    timeout: float = Query(default=25.0, ge=0, description="Seconds to wait when nothing new has happened yet"),
+    player: Player = Depends(require_lobby_member),
+):
+    """
+    Long-poll fallback for clients that can't hold a WebSocket open.
+
+    Returns the events the player's WebSocket would have received after after_seq, waiting up to timeout seconds
+    (capped at LONG_POLL_MAX_SECONDS) for at least one. Events come from the same numbered buffer WebSocket
+    reconnects replay from, so a client can switch between the two without missing anything.
+    """
+    replay = lobby_websocket_manager.replay
+    if after_seq is None:
+        after_seq = replay.last_seq.get(lobby_id, 0)
+    wait = min(timeout, settings.LONG_POLL_MAX_SECONDS)
+    events = await replay.wait_for_events(lobby_id, after_seq, player.session_id, player.team_id, wait)
+
+    current_seq = replay.last_seq.get(lobby_id, 0)
+    if events is None:
+        api_logger.info(
+            f"Poll needs resync: session_id={player.session_id} lobby_id={lobby_id} "
+            f"after_seq={after_seq} current_seq={current_seq}"
+        )
+        return PollResponse(events=[], last_seq=current_seq, resync_required=True)
+
+    return PollResponse(
+        events=[json.loads(prepared.for_audience(PLAYER_AUDIENCE)) for prepared in events],
+        last_seq=current_seq,  # Events in between were for other players, so there's nothing to fetch again
+        resync_required=False,
+    )
 
 
 @router.put("/lobby/ready", response_model=MessageResponse)
@@ -162,11 +593,12 @@ async def toggle_ready_status(
     # Check if player is assigned to a team
     if not player.team_id:
         api_logger.warning(f"Ready toggle failed: no team session_id={player.session_id}")
-        raise HTTPException(status_code=400, detail="You must be assigned to a team before readying up")
+        raise InvalidRequestError("You must be assigned to a team before readying up")
 
     # Toggle ready status
     player.is_ready = not player.is_ready
     db.add(player)
+    roster_version = bump_roster_version(db, player.lobby_id)
     db.commit()
     db.refresh(player)
 
@@ -179,8 +611,131 @@ async def toggle_ready_status(
             player_id=player.id,
             player_name=player.name,
             is_ready=player.is_ready,
+            roster_version=roster_version,
         ),
     )
 
     api_logger.info(f"Ready toggled: session_id={player.session_id} new={player.is_ready}")
     return MessageResponse(status=True, message=f"Ready status set to {'ready' if player.is_ready else 'not ready'}")
+
+
+@router.put("/lobby/player/preferences", response_model=Player)
+async def update_player_preferences(
+    preferences: PlayerPreferences,
+    player: Player = Depends(require_player_session),
+    db: Session = Depends(get_session),
+):
+    """Save the authenticated player's accessibility preferences and push them to their open connection."""
+    api_logger.info(f"Player preferences update: session_id={player.session_id} preferences={preferences.model_dump()}")
+
+    player.reduced_motion = preferences.reduced_motion
+    player.high_contrast = preferences.high_contrast
+    player.larger_text = preferences.larger_text
+    db.add(player)
+    db.commit()
+    db.refresh(player)
+
+    await lobby_websocket_manager.send_to_player(
+        player.lobby_id,
+        player.session_id,
+        PreferencesUpdatedEvent(
+            lobby_id=player.lobby_id,
+            player_session_id=player.session_id,
+            **preferences.model_dump(),
+        ),
+    )
+
+    return player
+
+
+def guess_result(guess: Guess) -> str:
+    if guess.review_status == "pending":
+        return "pending"
+    return "correct" if guess.is_correct else "wrong"
+
+
+@router.get("/lobby/team/guesses", response_model=TeamGuessHistoryResponse)
+async def get_team_guess_history(
+    page: int = Query(default=1, ge=1),
+    page_size: int = Query(default=50, ge=1, le=100),
+    player: Player = Depends(require_player_session),
+    db: Session = Depends(get_session),
+):
+    """
+    The player's team's guesses on its current puzzle, newest first.
+
+    Lets teammates check what's already been tried before re-submitting a wrong answer on a long rung.
+    """
+    api_logger.info(f"Team guess history requested: session_id={player.session_id} page={page} page_size={page_size}")
+    if not player.team_id:
+        raise InvalidRequestError("You must be assigned to a team to see its guesses")
+
+    db.refresh(player, ["team"])
+    game_id = player.team.game_id
+    if game_id is None:
+        return TeamGuessHistoryResponse(
+            game_id=None, page=page, page_size=page_size, total=0, has_more=False, guesses=[]
+        )
+
+    team_guesses = (Guess.team_id == player.team_id) & (Guess.game_id == game_id)
+    total = db.exec(select(func.count(Guess.id)).where(team_guesses)).one()
+    rows = db.exec(
+        select(Guess, Player.name)
+        .join(Player, Guess.player_id == Player.id)
+        .where(team_guesses)
+        .order_by(Guess.created_at.desc(), Guess.id.desc())
+        .offset((page - 1) * page_size)
+        .limit(page_size)
+    ).all()
+
+    guesses = [
+        TeamGuessEntry(
+            id=guess.id,
+            word_index=guess.word_index,
+            direction=guess.direction,
+            guess=guess.guess,
+            result=guess_result(guess),
+            player_id=guess.player_id,
+            player_name=player_name,
+            created_at=guess.created_at,
+        )
+        for guess, player_name in rows
+    ]
+    return TeamGuessHistoryResponse(
+        game_id=game_id,
+        page=page,
+        page_size=page_size,
+        total=total,
+        has_more=page * page_size < total,
+        guesses=guesses,
+    )
+
+
+@router.get("/lobby/team/chat", response_model=TeamChatHistoryResponse)
+async def get_team_chat_history(
+    player: Player = Depends(require_player_session),
+    db: Session = Depends(get_session),
+):
+    """The player's team's recent chat, oldest first, for filling in the chat panel after a reload or reconnect."""
+    api_logger.info(f"Team chat history requested: session_id={player.session_id}")
+    if not player.team_id:
+        raise InvalidRequestError("You must be assigned to a team to see its chat")
+
+    rows = db.exec(
+        select(ChatMessage)
+        .where(ChatMessage.team_id == player.team_id)
+        .order_by(ChatMessage.id.desc())
+        .limit(settings.TEAM_CHAT_HISTORY_SIZE)
+    ).all()
+    messages = [
+        TeamChatEntry(
+            id=row.id,
+            team_id=row.team_id,
+            player_id=row.player_id,
+            player_name=row.player_name,
+            text=row.text,
+            sent_at=row.created_at,
+        )
+        for row in reversed(rows)
+    ]
+    return TeamChatHistoryResponse(messages=messages)
@@ -0,0 +1,118 @@
+"""Phase checks for lobby routes that are only safe while no game is running."""
+
+from typing import Iterable
+
+from sqlmodel import Session, func, select
+
+from backend.custom_logging import api_logger
+from backend.database.models import Game, Lobby, Player, RoundResult, Team
+from backend.errors import ConflictError, ErrorCode
+from backend.game.lobby_state import LobbyPhase, derive_phase
+from backend.websocket.events import LobbyPhaseChangedEvent
+
+
+def lobby_has_active_game(db: Session, lobby_id: int) -> bool:
+    """A game is running while any team is assigned a puzzle that hasn't been finished."""
+    return (
+        db.exec(
+            select(Game.id)
+            .join(Team, Team.game_id == Game.id)
+            .where(Team.lobby_id == lobby_id)
+            .where(Game.completed_at.is_(None))
+        ).first()
+        is not None
+    )
+
+
+def get_lobby_phase(db: Session, lobby_id: int) -> LobbyPhase:
+    has_finished_game = (
+        db.exec(
+            select(Game.id)
+            .where(Game.lobby_id == lobby_id)
+            .where(Game.completed_at.isnot(None))
+            .where(Game.puzzle_path != "")
+        ).first()
+        is not None
+    )
+    lobby = db.get(Lobby, lobby_id)
+    in_intermission = lobby is not None and lobby.intermission_started_at is not None
+    return derive_phase(lobby_has_active_game(db, lobby_id), has_finished_game, in_intermission)
+
+
+def get_lobby_phases(db: Session, lobby_ids: Iterable[int]) -> dict[int, LobbyPhase]:
+    """get_lobby_phase for many lobbies in three queries, for lists."""
+    lobby_ids = list(lobby_ids)
+    active = set(
+        db.exec(
+            select(Team.lobby_id)
+            .join(Game, Team.game_id == Game.id)
+            .where(Team.lobby_id.in_(lobby_ids))
+            .where(Game.completed_at.is_(None))
+            .distinct()
+        ).all()
+    )
+    finished = set(
+        db.exec(
+            select(Game.lobby_id)
+            .where(Game.lobby_id.in_(lobby_ids))
+            .where(Game.completed_at.isnot(None))
+            .where(Game.puzzle_path != "")
+            .distinct()
+        ).all()
+    )
+    intermission = set(
+        db.exec(select(Lobby.id).where(Lobby.id.in_(lobby_ids)).where(Lobby.intermission_started_at.isnot(None))).all()
+    )
+    return {
+        lobby_id: derive_phase(lobby_id in active, lobby_id in finished, lobby_id in intermission)
+        for lobby_id in lobby_ids
+    }
+
+
+async def announce_phase_change(lobby_id: int, previous: LobbyPhase, phase: LobbyPhase):
+    from backend.websocket.managers import lobby_websocket_manager
+
+    if previous == phase:
+        return
+    api_logger.info(f"Lobby phase changed: lobby_id={lobby_id} {previous.value} -> {phase.value}")
+    await lobby_websocket_manager.broadcast_to_lobby(
+        lobby_id, LobbyPhaseChangedEvent(lobby_id=lobby_id, phase=phase, previous_phase=previous)
+    )
+
+
+def game_in_progress_error(db: Session, lobby: Lobby, action: str) -> ConflictError:
+    """409 with a machine-readable code and the lobby's current state, so clients can resync instead of guessing."""
+    round_number = (
+        db.exec(select(func.max(RoundResult.round_number)).where(RoundResult.lobby_id == lobby.id)).first() or 0
+    ) + 1
+    api_logger.warning(f"Rejected '{action}' during game: lobby_id={lobby.id} round={round_number}")
+    return ConflictError(
+        f"Cannot {action} while a game is in progress",
+        code=ErrorCode.GAME_IN_PROGRESS,
+        lobby_state={
+            "lobby_id": lobby.id,
+            "code": lobby.code,
+            "name": lobby.name,
+            "phase": LobbyPhase.IN_PROGRESS.value,
+            "round_number": round_number,
+            "late_join_enabled": lobby.late_join_enabled,
+        },
+    )
+
+
+def ensure_no_game_in_progress(db: Session, lobby: Lobby, action: str):
+    if lobby_has_active_game(db, lobby.id):
+        raise game_in_progress_error(db, lobby, action)
+
+
+def smallest_team(db: Session, lobby_id: int) -> Team | None:
+    """The team with the fewest players, oldest first on ties; late joiners are placed here."""
+    teams = db.exec(select(Team).where(Team.lobby_id == lobby_id).order_by(Team.id)).all()
+    if not teams:
+        return None
+    counts = dict(
+        db.exec(
+            select(Player.team_id, func.count(Player.id)).where(Player.lobby_id == lobby_id).group_by(Player.team_id)
+        ).all()
+    )
+    return min(teams, key=lambda team: (counts.get(team.id, 0), team.id))
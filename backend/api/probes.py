@@ -0,0 +1,53 @@
+"""
+Unauthenticated probes for container orchestrators and load balancers.
+
+GET /api/health only says the process is up and serving requests (liveness: restart it if this fails).
+GET /api/ready also runs a trivial DB query and answers 503 when that fails or the server is draining for
+shutdown (readiness: stop routing players here). Neither is counted in request metrics, traffic or the health
+score, so frequent probing doesn't drown them out and a failing readiness check can't mark the node degraded by
+itself.
+"""
+
+from datetime import datetime, timezone
+
+from fastapi import APIRouter
+from fastapi.responses import JSONResponse
+
+from backend.custom_logging import api_logger
+from backend.features import feature_flags
+from backend.health import probe_db_latency
+from backend.schemas import LivenessResponse, ReadinessResponse
+from backend.websocket.managers import admin_web_socket_manager, lobby_websocket_manager
+
+router = APIRouter()
+
+PROBE_PATHS = {"/api/health", "/api/ready"}
+
+
+@router.get("/health", response_model=LivenessResponse)
+async def liveness():
+    return LivenessResponse(status="ok", timestamp=datetime.now(timezone.utc).isoformat())
+
+
+@router.get("/ready", response_model=ReadinessResponse, responses={503: {"model": ReadinessResponse}})
+async def readiness():
+    db_latency_ms = None
+    try:
+        db_latency_ms = await probe_db_latency()
+    except Exception as e:
+        api_logger.error(f"Readiness check failed, database unreachable: {e}")
+
+    from backend.shutdown import shutdown_state
+
+    readiness = ReadinessResponse(
+        ready=db_latency_ms is not None and not shutdown_state.draining,
+        database=db_latency_ms is not None,
+        draining=shutdown_state.draining,
+        db_latency_ms=db_latency_ms,
+        degraded=feature_flags.degraded,
+        player_connections=sum(len(sockets) for sockets in lobby_websocket_manager.lobby_websockets.values()),
+        admin_connections=len(admin_web_socket_manager.admin_websockets),
+    )
+    if not readiness.ready:
+        return JSONResponse(status_code=503, content=readiness.model_dump())
+    return readiness
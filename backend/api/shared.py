@@ -0,0 +1,186 @@
+from datetime import datetime, timezone
+
+from fastapi import APIRouter, Depends
+from pydantic import BaseModel
+from sqlmodel import Session, select
+
+from backend.api.leaderboard import LeaderboardResponse, build_leaderboard
+from backend.custom_logging import api_logger
+from backend.database import Lobby, get_session
+from backend.database.models import Game, Guess, Player, RoundResult, Team
+from backend.errors import ConflictError, NotFoundError
+from backend.game.puzzles import get_puzzle_manager
+from backend.game.recap import RecapGuess, build_timeline, player_aliases
+from backend.settings import settings
+from backend.utils.signing import InvalidTokenError, verify_token
+
+router = APIRouter()
+
+SHARE_RESULTS_SCOPE = "results"
+SHARE_REPLAY_SCOPE = "replay"
+
+
+class SharedResultsResponse(BaseModel):
+    lobby_name: str
+    standings: LeaderboardResponse
+    expires_at: str
+
+
+class ReplayRung(BaseModel):
+    word_index: int
+    clue: str | None
+
+
+class ReplayTeam(BaseModel):
+    team: int  # Position in SharedReplayResponse.teams, referenced by timeline entries
+    name: str
+    start_word: str  # The two ends of the ladder are shown from the start of a round
+    end_word: str
+    rungs: list[ReplayRung]
+    completed_at_seconds: float | None
+
+
+class ReplayEntry(BaseModel):
+    at_seconds: float
+    team: int
+    player: str
+    word_index: int
+    solved: bool
+    guess: str | None  # The answer for solves; None for a masked wrong guess
+
+
+class SharedReplayResponse(BaseModel):
+    lobby_name: str
+    anonymized: bool
+    teams: list[ReplayTeam]
+    timeline: list[ReplayEntry]
+    expires_at: str
+
+
+def verify_share_token(token: str, scope: str) -> dict:
+    try:
+        payload = verify_token(token, settings.share_secret)
+    except InvalidTokenError as e:
+        api_logger.warning(f"Rejected shared {scope} token: {e}")
+        raise NotFoundError("Shared link is invalid or has expired")
+
+    if payload.get("scope") != scope:
+        api_logger.warning(f"Rejected shared {scope} token with scope={payload.get('scope')}")
+        raise NotFoundError("Shared link is invalid or has expired")
+    return payload
+
+
+@router.get("/shared/results/{token}", response_model=SharedResultsResponse)
+async def get_shared_results(token: str, db: Session = Depends(get_session)):
+    """
+    Public, read-only final standings for a lobby behind a signed link.
+
+    Only served between rounds, once at least one has been scored, so the link never shows a round half played.
+    """
+    payload = verify_share_token(token, SHARE_RESULTS_SCOPE)
+
+    lobby_id = payload.get("lobby_id")
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby or lobby.is_private:
+        api_logger.warning(f"Shared results requested for missing or private lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+    if not db.exec(select(RoundResult.id).where(RoundResult.lobby_id == lobby.id).limit(1)).first():
+        raise NotFoundError("No finished games to share yet")
+    in_progress = db.exec(
+        select(Game.id).where(Game.lobby_id == lobby.id, Game.completed_at.is_(None), Game.puzzle_path != "").limit(1)
+    ).first()
+    if in_progress:
+        api_logger.info(f"Shared results held back while a round is in progress: lobby_id={lobby_id}")
+        raise ConflictError("Results are shared once the round in progress has finished")
+
+    api_logger.info(f"Serving shared results for lobby_id={lobby_id}")
+    return SharedResultsResponse(
+        lobby_name=lobby.name,
+        standings=build_leaderboard(lobby.id, db),
+        expires_at=datetime.fromtimestamp(payload["exp"], tz=timezone.utc).isoformat(),
+    )
+
+
+@router.get("/shared/replay/{token}", response_model=SharedReplayResponse)
+async def get_shared_replay(token: str, db: Session = Depends(get_session)):
+    """
+    Public recap of a lobby's latest round behind a signed link, built from the guess log.
+
+    No session ids or internal ids are included; with anonymize set on the link, player names are replaced too.
+    """
+    payload = verify_share_token(token, SHARE_REPLAY_SCOPE)
+
+    lobby_id = payload.get("lobby_id")
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby or lobby.is_private:
+        api_logger.warning(f"Shared replay requested for missing or private lobby_id={lobby_id}")
+        raise NotFoundError("Lobby not found")
+
+    rows = db.exec(
+        select(Team, Game)
+        .join(Game, Team.game_id == Game.id)
+        .where(Team.lobby_id == lobby.id, Game.puzzle_path != "")
+        .order_by(Team.id)
+    ).all()
+    if not rows:
+        raise NotFoundError("No round to replay yet")
+
+    game_ids = [game.id for _, game in rows]
+    guesses = [
+        RecapGuess(
+            team_id=guess.team_id,
+            player_id=guess.player_id,
+            word_index=guess.word_index,
+            guess=guess.guess,
+            is_correct=guess.is_correct,
+            created_at=guess.created_at,
+        )
+        for guess in db.exec(select(Guess).where(Guess.game_id.in_(game_ids)).order_by(Guess.created_at)).all()
+    ]
+
+    anonymize = bool(payload.get("anonymize"))
+    if anonymize:
+        player_names = player_aliases(guess.player_id for guess in guesses)
+    else:
+        player_ids = {guess.player_id for guess in guesses}
+        player_names = {
+            player.id: player.name for player in db.exec(select(Player).where(Player.id.in_(player_ids))).all()
+        }
+
+    started_at = min(game.started_at for _, game in rows)
+    puzzle_manager = get_puzzle_manager()
+    teams = []
+    for position, (team, game) in enumerate(rows):
+        ladder = puzzle_manager.load_puzzle_by_path(game.puzzle_path).ladder
+        teams.append(
+            ReplayTeam(
+                team=position,
+                name=team.name,
+                start_word=ladder[0].word,
+                end_word=ladder[-1].word,
+                rungs=[ReplayRung(word_index=index, clue=step.clue) for index, step in enumerate(ladder)],
+                completed_at_seconds=(game.completed_at - started_at).total_seconds() if game.completed_at else None,
+            )
+        )
+
+    positions = {team.id: position for position, (team, _) in enumerate(rows)}
+    timeline = build_timeline(started_at, guesses, player_names, settings.SHARED_REPLAY_WRONG_GUESSES)
+
+    api_logger.info(f"Serving shared replay for lobby_id={lobby_id} anonymized={anonymize} entries={len(timeline)}")
+    return SharedReplayResponse(
+        lobby_name=lobby.name,
+        anonymized=anonymize,
+        teams=teams,
+        timeline=[
+            ReplayEntry(
+                at_seconds=entry.at_seconds,
+                team=positions[entry.team_id],
+                player=entry.player,
+                word_index=entry.word_index,
+                solved=entry.solved,
+                guess=entry.guess,
+            )
+            for entry in timeline
+        ],
+        expires_at=datetime.fromtimestamp(payload["exp"], tz=timezone.utc).isoformat(),
+    )
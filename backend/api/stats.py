@@ -1,12 +1,13 @@
 import json
 from typing import Optional
 
-from fastapi import APIRouter, Depends, HTTPException
+from fastapi import APIRouter, Depends
 from pydantic import BaseModel
 from sqlmodel import Session, select
 
 from backend.database import get_session
 from backend.database.models import Game, Guess, Player, RoundResult, Team
+from backend.errors import NotFoundError
 from backend.game.puzzles import get_puzzle_manager
 from backend.utils.awards import PlayerAward, assign_awards
 
@@ -70,7 +71,7 @@ async def get_game_stats(game_id: int, session: Session = Depends(get_session)):
     # Get the game
     game = session.get(Game, game_id)
     if not game:
-        raise HTTPException(status_code=404, detail="Game not found")
+        raise NotFoundError("Game not found")
 
     # Load the puzzle to get ladder length
     puzzle_manager = get_puzzle_manager()
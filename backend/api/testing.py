@@ -0,0 +1,76 @@
+"""
+Testing-only routes, mounted when settings.TESTING is set.
+
+POST /api/test/lobby/{lobby_id}/emit pushes an arbitrary event through the websocket managers, so rare events
+(kicks, timeouts, lobby deletion) can be exercised in the frontend without playing through the flow behind them.
+
+/api/test/chaos turns on fault injection (slow database statements, dropped sends, forced disconnects; see
+chaos.py) so the reconnect, outbox and retry paths can be tested against a misbehaving server.
+"""
+
+from fastapi import APIRouter, Depends
+from sqlmodel import Session
+
+from backend.chaos import ChaosConfig, chaos
+from backend.custom_logging import api_logger
+from backend.database import get_session
+from backend.database.models import Lobby
+from backend.errors import InvalidRequestError, NotFoundError
+from backend.schemas import ChaosStatusResponse, MessageResponse, TestEmitRequest
+from backend.websocket.managers import admin_web_socket_manager, lobby_websocket_manager
+from backend.websocket.prepared import PreparedEvent
+
+router = APIRouter()
+
+
+@router.post("/test/lobby/{lobby_id}/emit", response_model=MessageResponse)
+async def emit_event(lobby_id: int, request: TestEmitRequest, db: Session = Depends(get_session)):
+    if not db.get(Lobby, lobby_id):
+        raise NotFoundError("Lobby not found")
+    if not isinstance(request.event.get("type"), str):
+        raise InvalidRequestError("Event needs a string 'type'")
+
+    event = PreparedEvent({"lobby_id": lobby_id, **request.event})
+    event_type = request.event["type"]
+    if request.target == "lobby":
+        await lobby_websocket_manager.broadcast_to_lobby(lobby_id, event)
+    elif request.target == "admins":
+        await admin_web_socket_manager.broadcast_to_lobby(lobby_id, event)
+    elif request.target == "team":
+        if request.team_id is None:
+            raise InvalidRequestError("team_id is required for target 'team'")
+        await lobby_websocket_manager.broadcast_to_team(lobby_id, request.team_id, event)
+    elif request.target == "player":
+        if not request.player_session_id:
+            raise InvalidRequestError("player_session_id is required for target 'player'")
+        await lobby_websocket_manager.send_to_player(lobby_id, request.player_session_id, event)
+    else:
+        raise InvalidRequestError(f"Unknown target: {request.target}")
+    # Player events are queued; wait for them so a test can check what arrived as soon as this returns
+    await lobby_websocket_manager.flush(lobby_id)
+
+    api_logger.info(f"Test emit: lobby_id={lobby_id} type={event_type} target={request.target}")
+    return MessageResponse(status=True, message=f"Emitted {event_type} to {request.target}")
+
+
+def chaos_status() -> ChaosStatusResponse:
+    return ChaosStatusResponse(enabled=chaos.enabled, config=chaos.config, injected=dict(chaos.injected))
+
+
+@router.get("/test/chaos", response_model=ChaosStatusResponse)
+async def get_chaos():
+    return chaos_status()
+
+
+@router.put("/test/chaos", response_model=ChaosStatusResponse)
+async def configure_chaos(config: ChaosConfig):
+    chaos.configure(config)
+    api_logger.warning(f"Chaos configured: {config.model_dump()}")
+    return chaos_status()
+
+
+@router.delete("/test/chaos", response_model=ChaosStatusResponse)
+async def disable_chaos():
+    chaos.configure(ChaosConfig())
+    api_logger.info("Chaos disabled")
+    return chaos_status()
@@ -0,0 +1,280 @@
+"""FastAPI application factory shared by the server entry point (backend.main) and AppBuilder."""
+
+import time
+from datetime import datetime
+from pathlib import Path
+from contextlib import asynccontextmanager
+
+from fastapi import FastAPI, Request
+from fastapi.responses import FileResponse
+from fastapi.staticfiles import StaticFiles
+from sqlalchemy.exc import SQLAlchemyError
+
+from backend.api.admin.auth import router as admin_auth_router
+from backend.api.admin.connections import router as admin_connections_router
+from backend.api.admin.deprecations import router as admin_deprecations_router
+from backend.api.admin.health import router as admin_health_router
+from backend.api.admin.organizations import router as admin_organizations_router
+from backend.api.admin.lobby.index import router as admin_lobby_router
+from backend.api.admin.lobby.intermission import router as admin_lobby_intermission_router
+from backend.api.admin.lobby.moderation import router as admin_lobby_moderation_router
+from backend.api.admin.lobby.reveal import router as admin_lobby_reveal_router
+from backend.api.admin.lobby.team import router as admin_lobby_team_router
+from backend.api.admin.puzzle import router as admin_puzzle_router
+from backend.api.admin.retention import router as admin_retention_router
+from backend.api.admin.sessions import router as admin_sessions_router
+from backend.api.admin.stats import router as admin_stats_router
+from backend.api.admin.summary import router as admin_summary_router
+from backend.api.admin.traffic import router as admin_traffic_router
+from backend.api.game import router as game_router
+from backend.api.leaderboard import router as leaderboard_router
+from backend.api.lobby import router as lobby_router
+from backend.api.probes import PROBE_PATHS
+from backend.api.probes import router as probes_router
+from backend.api.shared import router as shared_router
+from backend.api.stats import router as stats_router
+from backend.api.testing import router as testing_router
+from backend.csrf import check_csrf
+from backend.custom_logging import access_logger, api_logger, server_logger
+from backend.database import create_db_and_tables, drop_all_tables
+from backend.deprecation import deprecation_headers, deprecation_tracker, find_deprecated_route
+from backend.errors import AppError, NotFoundError, app_error_handler, database_error_handler
+from backend.health import health_monitor
+from backend.in_memory_state import reset_in_memory_state
+from backend.metrics import metrics_collector
+from backend.schemas import ApiRootResponse, MessageResponse
+from backend.settings import settings
+from backend.shutdown import SERVICE_RESTART, reject_while_draining
+from backend.traffic import UNMATCHED_ROUTE, access_log_line, session_fingerprint, traffic_tracker
+from backend.websocket.api import router as websocket_router
+from backend.websocket.events import ServerShutdownEvent
+
+STATIC_PATH = Path(__file__).parent.parent / "static"
+
+
+@asynccontextmanager
+async def lifespan(app: FastAPI):
+    """Handle startup and shutdown events."""
+    # Startup
+    server_logger.info("Starting up application...")
+    from backend.api.admin.lobby.timer_poller import start_timer_poller
+    from backend.bots import start_bots
+    from backend.daily_summary import start_daily_summary_task
+    from backend.health import start_health_monitor
+    from backend.intermission import start_intermission_task
+    from backend.pace_keeper import start_pace_keeper
+    from backend.puzzles_sync import start_puzzle_sync
+    from backend.quick_play import start_quick_play
+    from backend.reveal import start_reveal_task
+    from backend.retention import start_retention_task
+    from backend.websocket.acks import start_ack_resender
+
+    start_timer_poller()
+    server_logger.info("Timer poller started")
+
+    start_puzzle_sync()
+    server_logger.info("Puzzle sync task started")
+
+    start_retention_task()
+    start_daily_summary_task()
+    start_quick_play()
+    start_pace_keeper()
+    start_reveal_task()
+    start_intermission_task()
+    start_bots()
+    start_health_monitor()
+    start_ack_resender()
+
+    yield
+
+    # Shutdown
+    server_logger.info("Shutting down application...")
+    from backend.api.admin.lobby.timer_poller import stop_timer_poller
+    from backend.bots import stop_bots
+    from backend.daily_summary import stop_daily_summary_task
+    from backend.health import stop_health_monitor
+    from backend.intermission import stop_intermission_task
+    from backend.pace_keeper import stop_pace_keeper
+    from backend.puzzles_sync import stop_puzzle_sync
+    from backend.quick_play import stop_quick_play
+    from backend.reveal import stop_reveal_task
+    from backend.retention import stop_retention_task
+    from backend.websocket.acks import stop_ack_resender
+
+    stop_timer_poller()
+    stop_puzzle_sync()
+    stop_retention_task()
+    stop_daily_summary_task()
+    stop_quick_play()
+    stop_pace_keeper()
+    stop_reveal_task()
+    stop_intermission_task()
+    stop_bots()
+    stop_health_monitor()
+    stop_ack_resender()
+    server_logger.info("Background tasks stopped")
+
+
+async def record_request_metrics(request: Request, call_next):
+    if not request.url.path.startswith("/api") or request.url.path in PROBE_PATHS:
+        return await call_next(request)
+
+    started = time.perf_counter()
+    status_code = 500
+    try:
+        response = await call_next(request)
+        status_code = response.status_code
+        return response
+    finally:
+        duration_ms = (time.perf_counter() - started) * 1000
+        metrics_collector.record_request(duration_ms, status_code)
+        health_monitor.record_request(status_code, time.time())
+        # Routing fills in scope["route"], so the template is known once the request has been handled
+        matched = request.scope.get("route")
+        route = getattr(matched, "path", None) or UNMATCHED_ROUTE
+        traffic_tracker.record(request.method, route, status_code, duration_ms, time.time())
+        session = session_fingerprint(request.headers.get("authorization"))
+        access_logger.info(access_log_line(request.method, route, status_code, duration_ms, session))
+
+
+async def mark_deprecated_routes(request: Request, call_next):
+    route = find_deprecated_route(settings.DEPRECATED_ROUTES, request.method, request.url.path)
+    if not route:
+        return await call_next(request)
+
+    user_agent = request.headers.get("user-agent")
+    deprecation_tracker.record(route, request.url.path, user_agent, request.headers.get("referer"))
+    api_logger.warning(
+        f"Deprecated route called: {request.method} {request.url.path} (matches {route.key}) user_agent={user_agent}"
+    )
+    response = await call_next(request)
+    response.headers.update(deprecation_headers(route))
+    return response
+
+
+async def reset_db():
+    """
+    Drop and recreate every table, then clear what's held in memory about the old rows.
+
+    Open sockets are sent server_shutdown and closed first, since the sessions they belong to are gone; sessions,
+    replay buffers and the rest of the in-memory state are cleared afterwards, the caller's admin session included.
+    """
+    from backend.websocket.managers import admin_web_socket_manager, lobby_websocket_manager
+
+    api_logger.info("Resetting database (TESTING mode)")
+    reason = "Database was reset"
+    event = ServerShutdownEvent(reason=reason, reconnect_after_seconds=settings.SHUTDOWN_RECONNECT_AFTER_SECONDS)
+    players = await lobby_websocket_manager.close_all(event, SERVICE_RESTART, reason)
+    admins = await admin_web_socket_manager.close_all(event, SERVICE_RESTART, reason)
+    drop_all_tables()
+    create_db_and_tables()
+    reset_in_memory_state()
+    api_logger.info(f"Database reset successful, closed {players} player/spectator and {admins} admin sockets")
+    return MessageResponse(status=True, message="Database reset successful")
+
+
+async def api_root():
+    api_logger.info("API root accessed")
+    return ApiRootResponse(
+        message="Welcome to the Raddle Teams API",
+        timestamp=datetime.now().isoformat(),
+        documentation_endpoints={"OpenAPI": "/docs", "ReDoc": "/redoc"},
+    )
+
+
+async def serve_frontend(full_path: str):
+    api_logger.debug("Catch-all route accessed with path: %s", full_path)
+
+    # If it's an API or WebSocket route, return 404
+    if full_path.startswith(("api/", "ws/", "docs", "redoc", "openapi.json")):
+        api_logger.warning("API endpoint not found (catch-all): %s", full_path)
+        raise NotFoundError("Endpoint not found")
+
+    # For all other routes, serve the frontend index.html to support SPA routing
+    index_file = STATIC_PATH / "index.html"
+    if index_file.exists():
+        api_logger.debug("Serving index.html for SPA route: %s", full_path)
+        return FileResponse(str(index_file))
+    api_logger.error("index.html not found at: %s", index_file)
+    raise AppError("Frontend not built. Run 'npm run build' first.")
+
+
+def create_app(serve_static: bool = True, run_background_tasks: bool = True) -> FastAPI:
+    """
+    Assemble the API, websocket routes and (optionally) the built frontend.
+
+    Args:
+        serve_static: Mount the built frontend and the SPA catch-all route; requires `npm run build`
+        run_background_tasks: Start the timer poller, puzzle sync, retention, daily summary, quick play and pace keeper
+    """
+    app = FastAPI(
+        title="Raddle Teams",
+        description="A team-based word chain puzzle game",
+        version="1.0.0",
+        lifespan=lifespan if run_background_tasks else None,
+    )
+
+    app.middleware("http")(record_request_metrics)
+    app.middleware("http")(mark_deprecated_routes)
+    app.middleware("http")(check_csrf)
+    app.middleware("http")(reject_while_draining)
+
+    app.add_exception_handler(AppError, app_error_handler)
+    app.add_exception_handler(SQLAlchemyError, database_error_handler)
+
+    if settings.TESTING:
+        app.delete("/api/reset-db", response_model=MessageResponse)(reset_db)
+        app.include_router(testing_router, prefix="/api", tags=["Testing"])
+
+    # Define specific API routes BEFORE the catch-all route
+    app.get("/api", tags=["Root"], response_model=ApiRootResponse)(api_root)
+    app.include_router(probes_router, prefix="/api", tags=["Probes"])
+
+    server_logger.info("Included user api routes")
+    app.include_router(lobby_router, prefix="/api", tags=["Lobby"])
+
+    server_logger.info("Included admin api routes")
+    app.include_router(admin_lobby_router, prefix="/api/admin", tags=["AdminLobby"])
+    app.include_router(admin_auth_router, prefix="/api/admin", tags=["AdminAuth"])
+    app.include_router(admin_lobby_team_router, prefix="/api/admin", tags=["AdminLobbyTeam"])
+    app.include_router(admin_lobby_moderation_router, prefix="/api/admin", tags=["AdminLobbyModeration"])
+    app.include_router(admin_lobby_reveal_router, prefix="/api/admin", tags=["AdminLobbyReveal"])
+    app.include_router(admin_lobby_intermission_router, prefix="/api/admin", tags=["AdminLobbyIntermission"])
+    app.include_router(admin_puzzle_router, prefix="/api/admin", tags=["AdminPuzzle"])
+    app.include_router(admin_sessions_router, prefix="/api/admin", tags=["AdminSessions"])
+    app.include_router(admin_organizations_router, prefix="/api/admin", tags=["AdminOrganizations"])
+    app.include_router(admin_retention_router, prefix="/api/admin", tags=["AdminRetention"])
+    app.include_router(admin_connections_router, prefix="/api/admin", tags=["AdminConnections"])
+    app.include_router(admin_deprecations_router, prefix="/api/admin", tags=["AdminDeprecations"])
+    app.include_router(admin_summary_router, prefix="/api/admin", tags=["AdminSummary"])
+    app.include_router(admin_stats_router, prefix="/api/admin", tags=["AdminStats"])
+    app.include_router(admin_traffic_router, prefix="/api/admin", tags=["AdminTraffic"])
+    app.include_router(admin_health_router, prefix="/api/admin", tags=["AdminHealth"])
+
+    server_logger.info("Included game api routes")
+    app.include_router(game_router, prefix="/api", tags=["Game"])
+
+    server_logger.info("Included stats api routes")
+    app.include_router(stats_router, prefix="/api", tags=["Stats"])
+
+    server_logger.info("Included leaderboard api routes")
+    app.include_router(leaderboard_router, prefix="/api", tags=["Leaderboard"])
+
+    server_logger.info("Included shared results routes")
+    app.include_router(shared_router, prefix="/api", tags=["Shared"])
+
+    server_logger.info("Included websocket routes")
+    app.include_router(websocket_router, prefix="/ws", tags=["WebSocket"])
+
+    if serve_static:
+        if not STATIC_PATH.exists():
+            server_logger.error("Static directory not found. Looked for: %s", STATIC_PATH)
+            server_logger.error("Frontend not built. Run 'npm run build' first.")
+            exit(1)
+
+        app.mount("/assets", StaticFiles(directory=str(STATIC_PATH / "assets")), name="assets")
+        app.mount("/img", StaticFiles(directory=str(STATIC_PATH / "img")), name="img")
+        server_logger.info("Static files mounted from: %s", STATIC_PATH)
+        app.get("/{full_path:path}")(serve_frontend)
+
+    return app
@@ -0,0 +1,94 @@
+"""
+Build a fully working app in a couple of lines for integration tests and client/bot tooling:
+
+    app = AppBuilder().build()
+    client = TestClient(app)
+
+Defaults to an in-memory SQLite database, TESTING mode, fresh in-memory managers, no background tasks
+and no built frontend, so nothing on disk is touched. Used as a context manager, the settings it overrode are
+put back on the way out:
+
+    with AppBuilder().setting("ADMIN_PASSWORD", "secret") as app, TestClient(app) as client:
+        ...
+
+Admin routes only take a session token from POST /api/admin/login; admin_session("token") issues one up front, so
+callers can send {"Authorization": "Bearer token"} without logging in first.
+"""
+
+from typing import Any, Dict, Optional
+
+from fastapi import FastAPI
+
+from backend.admin_sessions import admin_session_store
+from backend.app import create_app
+from backend.database import build_engine, create_db_and_tables, use_engine
+from backend.in_memory_state import reset_in_memory_state
+from backend.settings import settings
+
+IN_MEMORY_DATABASE_URL = "sqlite://"
+
+
+class AppBuilder:
+    def __init__(self):
+        self._database_url = IN_MEMORY_DATABASE_URL
+        self._serve_static = False
+        self._run_background_tasks = False
+        self._settings: Dict[str, Any] = {"TESTING": True}
+        self._admin_token: Optional[str] = None
+        self._overridden: Dict[str, Any] = {}  # The values build() replaced, for restore_settings()
+
+    def database_url(self, database_url: str) -> "AppBuilder":
+        self._database_url = database_url
+        return self
+
+    def serve_static(self, enabled: bool = True) -> "AppBuilder":
+        self._serve_static = enabled
+        return self
+
+    def background_tasks(self, enabled: bool = True) -> "AppBuilder":
+        self._run_background_tasks = enabled
+        return self
+
+    def setting(self, name: str, value: Any) -> "AppBuilder":
+        """Override a setting, e.g. .setting("ADMIN_PASSWORD", "secret")."""
+        if not hasattr(settings, name):
+            raise ValueError(f"Unknown setting: {name}")
+        self._settings[name] = value
+        return self
+
+    def admin_session(self, token: str) -> "AppBuilder":
+        """Issue a deployment admin session with this token once built, as logging in with ADMIN_PASSWORD would."""
+        self._admin_token = token
+        return self
+
+    def build(self) -> FastAPI:
+        """
+        Apply the configuration and return a new app.
+
+        Settings, the database engine and the managers are process-wide, so building again
+        replaces the state of any app built before it. The settings stay overridden until
+        restore_settings().
+        """
+        for name, value in self._settings.items():
+            self._overridden.setdefault(name, getattr(settings, name))
+            setattr(settings, name, value)
+
+        use_engine(build_engine(self._database_url))
+        create_db_and_tables()
+        reset_in_memory_state()
+        if self._admin_token is not None:
+            admin_session_store.create(ip=None, is_owner=not settings.ADMIN_OWNER_PASSWORD, token=self._admin_token)
+
+        return create_app(serve_static=self._serve_static, run_background_tasks=self._run_background_tasks)
+
+    def restore_settings(self):
+        """Put back the settings build() overrode."""
+        for name, value in self._overridden.items():
+            setattr(settings, name, value)
+        self._overridden.clear()
+
+    def __enter__(self) -> FastAPI:
+        return self.build()
+
+    def __exit__(self, *exc_info):
+        self.restore_settings()
@@ -0,0 +1,113 @@
+"""
+Drop-in bot players, so a small group can still play team-vs-team.
+
+Admins add bots to a lobby (POST /api/admin/lobby/{id}/bots); they take roster slots like anyone else, landing on
+the smallest team when teams exist. A bot given a skill also plays: every BOT_TICK_SECONDS it solves its team's next
+rung with that chance, through the same guess path players use, so teammates and admins see its solves live.
+"""
+
+import asyncio
+import random
+from typing import Iterable, List, Optional, Sequence
+
+from sqlmodel import Session, select
+
+from backend.custom_logging import server_logger
+from backend.database import get_session_context
+from backend.database.models import Game, Player, Team
+from backend.game.puzzles import LadderStep
+from backend.settings import settings
+
+BOT_NAME_PREFIX = "Bot"
+
+_bot_task: Optional[asyncio.Task] = None
+
+
+def bot_names(existing_names: Iterable[str], count: int) -> List[str]:
+    """The lowest free "Bot N" names, so removing a bot frees its number for the next one."""
+    taken = set(existing_names)
+    names: List[str] = []
+    number = 1
+    while len(names) < count:
+        name = f"{BOT_NAME_PREFIX} {number}"
+        if name not in taken:
+            names.append(name)
+        number += 1
+    return names
+
+
+def bot_target_rung(ladder: Sequence[LadderStep], revealed_steps: Iterable[int]) -> Optional[int]:
+    """
+    The rung a bot solves next: the first unrevealed one from the top.
+
+    Open-ended rungs are skipped; they need the host to judge an answer, and a bot has nothing to offer there.
+    """
+    revealed = set(revealed_steps)
+    return next(
+        (index for index, step in enumerate(ladder) if index not in revealed and not step.open_ended),
+        None,
+    )
+
+
+async def play_bot_turn(db: Session, bot: Player, team: Team, game: Game, rng: random.Random) -> Optional[int]:
+    """Roll against the bot's skill and, on success, solve its team's next rung; returns the rung solved, if any."""
+    from backend.api.game import get_team_state_machine, submit_team_guess
+    from backend.websocket.managers import lobby_websocket_manager
+
+    if rng.random() >= (bot.bot_skill or 0.0):
+        return None
+
+    machine = get_team_state_machine(team, game)
+    word_index = bot_target_rung(machine.puzzle.ladder, machine.state.revealed_steps)
+    if word_index is None:
+        return None
+
+    answer = machine.puzzle.ladder[word_index].word.upper()
+    await submit_team_guess(bot.lobby_id, bot, team, game, answer, word_index, db, lobby_websocket_manager)
+    server_logger.info(f"Bot solved a rung: lobby_id={bot.lobby_id} team_id={team.id} word_index={word_index}")
+    return word_index
+
+
+async def bot_tick(rng: random.Random):
+    async with get_session_context() as db:
+        rows = db.exec(
+            select(Player, Team, Game)
+            .join(Team, Player.team_id == Team.id)
+            .join(Game, Team.game_id == Game.id)
+            .where(
+                Player.is_bot.is_(True),
+                Player.bot_skill.is_not(None),
+                Game.puzzle_path != "",
+                Game.completed_at.is_(None),
+            )
+        ).all()
+        for bot, team, game in rows:
+            try:
+                await play_bot_turn(db, bot, team, game, rng)
+            except Exception as e:
+                server_logger.exception(f"Bot turn failed for player_id={bot.id}: {e}")
+                db.rollback()
+
+
+async def bot_task():
+    rng = random.Random()
+    while True:
+        try:
+            await bot_tick(rng)
+        except Exception as e:
+            server_logger.exception(f"Error running bot players: {e}")
+        await asyncio.sleep(settings.BOT_TICK_SECONDS)
+
+
+def start_bots():
+    global _bot_task
+    _bot_task = asyncio.create_task(bot_task())
+    server_logger.info("Started bot player background task")
+
+
+def stop_bots():
+    global _bot_task
+    if _bot_task:
+        _bot_task.cancel()
+        _bot_task = None
+        server_logger.info("Stopped bot player background task")
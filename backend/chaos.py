@@ -0,0 +1,85 @@
+"""
+Fault injection for resilience tests, configured through /api/test/chaos (only mounted in TESTING mode).
+
+Each fault fires independently with its own probability: database statements are held up before they run,
+websocket sends are dropped (reported as sent, the way a lost packet would be, so only acks and replay notice)
+and sockets are closed with 1012 just before a send so the client's reconnect and resume path kicks in.
+Everything is off until configured; until then each hook is a single attribute check.
+"""
+
+import random
+import time
+from typing import Any, Dict, Optional
+
+from pydantic import BaseModel, Field
+
+from backend.custom_logging import websocket_logger
+from backend.in_memory_state import on_reset
+
+SERVICE_RESTART = 1012
+
+
+class ChaosConfig(BaseModel):
+    db_delay_probability: float = Field(default=0.0, ge=0.0, le=1.0)
+    db_delay_ms: int = Field(default=0, ge=0, le=10_000)
+    drop_send_probability: float = Field(default=0.0, ge=0.0, le=1.0)
+    disconnect_probability: float = Field(default=0.0, ge=0.0, le=1.0)
+    seed: Optional[int] = None  # Makes a run's faults repeatable
+
+    @property
+    def enabled(self) -> bool:
+        delays = self.db_delay_probability > 0 and self.db_delay_ms > 0
+        return delays or self.drop_send_probability > 0 or self.disconnect_probability > 0
+
+
+class ForcedDisconnect(Exception):
+    """Raised in place of a send after chaos closed the socket."""
+
+
+class Chaos:
+    def __init__(self):
+        self.config = ChaosConfig()
+        self.random = random.Random()
+        self.injected: Dict[str, int] = {"db_delays": 0, "dropped_sends": 0, "disconnects": 0}
+
+    @property
+    def enabled(self) -> bool:
+        return self.config.enabled
+
+    def configure(self, config: ChaosConfig):
+        self.__init__()
+        self.config = config
+        self.random.seed(config.seed)
+
+    def roll(self, probability: float) -> bool:
+        return probability > 0 and self.random.random() < probability
+
+    def delay_statement(self):
+        if self.roll(self.config.db_delay_probability):
+            self.injected["db_delays"] += 1
+            time.sleep(self.config.db_delay_ms / 1000)
+
+    async def before_send(self, key: str, websocket: Any) -> bool:
+        """Whether the send should actually go out; raises ForcedDisconnect once the socket has been closed."""
+        if self.roll(self.config.disconnect_probability):
+            self.injected["disconnects"] += 1
+            websocket_logger.info(f"Chaos: forcing {key} to disconnect")
+            try:
+                await websocket.close(code=SERVICE_RESTART, reason="Chaos: forced disconnect")
+            except Exception:
+                pass
+            raise ForcedDisconnect("chaos: forced disconnect")
+        if self.roll(self.config.drop_send_probability):
+            self.injected["dropped_sends"] += 1
+            return False
+        return True
+
+
+chaos = Chaos()
+on_reset(chaos.__init__)
+
+
+def delay_statement(conn, cursor, statement, parameters, context, executemany):
+    """before_cursor_execute listener; see build_engine."""
+    if chaos.enabled:
+        chaos.delay_statement()
@@ -0,0 +1,50 @@
+"""
+The lobby's commentary feed: recent plain-text narration lines (see backend/game/commentary.py).
+
+Each line goes out live as a commentary event to spectators and admins, and the last COMMENTARY_HISTORY_SIZE per
+lobby are kept in memory for GET /api/lobby/{lobby_id}/commentary, so a screen reader or stream overlay that joins
+late can catch up. Like reveals, the feed doesn't survive a restart.
+"""
+
+from collections import deque
+from datetime import datetime, timezone
+from typing import Deque, Dict, List
+
+from backend.custom_logging import websocket_logger
+from backend.in_memory_state import on_reset
+from backend.settings import settings
+from backend.websocket.events import CommentaryEvent
+
+
+class CommentaryFeed:
+    def __init__(self):
+        self.lines: Dict[int, Deque[CommentaryEvent]] = {}
+
+    def record(self, event: CommentaryEvent):
+        if settings.COMMENTARY_HISTORY_SIZE == 0:
+            return
+        lines = self.lines.get(event.lobby_id)
+        if lines is None or lines.maxlen != settings.COMMENTARY_HISTORY_SIZE:
+            lines = deque(lines or [], maxlen=settings.COMMENTARY_HISTORY_SIZE)
+            self.lines[event.lobby_id] = lines
+        lines.append(event)
+
+    def recent(self, lobby_id: int) -> List[CommentaryEvent]:
+        return list(self.lines.get(lobby_id, []))
+
+    def forget(self, lobby_id: int):
+        self.lines.pop(lobby_id, None)
+
+
+commentary_feed = CommentaryFeed()
+on_reset(commentary_feed.__init__)
+
+
+async def commentate(lobby_id: int, text: str):
+    """Add a line to the lobby's feed and send it to its spectators and admins."""
+    from backend.websocket.managers import lobby_websocket_manager
+
+    event = CommentaryEvent(lobby_id=lobby_id, text=text, created_at=datetime.now(tz=timezone.utc).isoformat())
+    commentary_feed.record(event)
+    websocket_logger.debug(f"Commentary lobby_id={lobby_id}: {text}")
+    await lobby_websocket_manager.broadcast_to_watchers(lobby_id, event)
@@ -0,0 +1,66 @@
+"""
+Cookie sessions for the admin API, and the CSRF check that has to come with them.
+
+With ADMIN_COOKIE_AUTH on, POST /api/admin/login also sets the session token as an HttpOnly cookie, so a browser
+can authenticate without the page keeping the token around. Browsers send that cookie on cross-site requests
+too, so every state-changing request authenticated by it must repeat the readable raddle_csrf cookie in an
+X-CSRF-Token header (double submit). The CSRF token is derived from the session token, so a raddle_csrf cookie
+planted by another site or subdomain never matches. Requests with an Authorization header don't use the cookie
+and aren't checked.
+"""
+
+import hashlib
+import hmac
+import secrets
+
+from fastapi import Request, Response
+
+from backend.admin_sessions import AdminSession
+from backend.custom_logging import api_logger
+from backend.errors import ErrorCode, ForbiddenError, app_error_handler
+from backend.settings import settings
+
+SESSION_COOKIE = "raddle_admin_session"
+CSRF_COOKIE = "raddle_csrf"
+CSRF_HEADER = "X-CSRF-Token"
+SAFE_METHODS = {"GET", "HEAD", "OPTIONS"}
+# Logging in needs the password anyway, and a stale cookie mustn't stop anyone from getting a fresh session
+EXEMPT_PATHS = {"/api/admin/login"}
+
+
+def csrf_token_for(session_token: str) -> str:
+    return hmac.new(session_token.encode(), b"csrf", hashlib.sha256).hexdigest()
+
+
+def set_session_cookies(response: Response, session: AdminSession):
+    max_age = int(settings.ADMIN_SESSION_MAX_HOURS * 3600)
+    secure = settings.ADMIN_COOKIE_SECURE
+    response.set_cookie(SESSION_COOKIE, session.token, max_age=max_age, httponly=True, secure=secure, samesite="strict")
+    response.set_cookie(
+        CSRF_COOKIE, csrf_token_for(session.token), max_age=max_age, httponly=False, secure=secure, samesite="strict"
+    )
+
+
+def clear_session_cookies(response: Response):
+    response.delete_cookie(SESSION_COOKIE)
+    response.delete_cookie(CSRF_COOKIE)
+
+
+def needs_csrf_check(request: Request) -> bool:
+    return (
+        settings.ADMIN_COOKIE_AUTH
+        and request.method not in SAFE_METHODS
+        and request.url.path not in EXEMPT_PATHS
+        and "authorization" not in request.headers
+        and SESSION_COOKIE in request.cookies
+    )
+
+
+async def check_csrf(request: Request, call_next):
+    if needs_csrf_check(request):
+        expected = csrf_token_for(request.cookies[SESSION_COOKIE])
+        if not secrets.compare_digest(request.headers.get(CSRF_HEADER, ""), expected):
+            api_logger.warning(f"Rejected cookie-authenticated {request.method} {request.url.path}: bad CSRF token")
+            error = ForbiddenError("Missing or invalid CSRF token", code=ErrorCode.CSRF_FAILED)
+            return await app_error_handler(request, error)
+    return await call_next(request)
@@ -38,3 +38,4 @@ server_logger = create_logger("server", logging.DEBUG, include_console=True)
 api_logger = create_logger("api", logging.DEBUG)
 database_logger = create_logger("database", logging.DEBUG)
 websocket_logger = create_logger("websocket", logging.DEBUG)
+access_logger = create_logger("access", logging.INFO)
@@ -0,0 +1,91 @@
+"""Scheduled daily summary of games played, peak players, errors and request latency."""
+
+import asyncio
+from datetime import date, datetime, time, timedelta, timezone
+from typing import Optional
+
+from sqlmodel import Session, func, select
+
+from backend.custom_logging import api_logger, database_logger, server_logger, websocket_logger
+from backend.database import get_session_context
+from backend.database.models import DailySummary, Game
+from backend.metrics import DayMetrics, ErrorCountingHandler, metrics_collector
+
+_summary_task: Optional[asyncio.Task] = None
+_error_handler = ErrorCountingHandler(metrics_collector)
+
+
+def count_games_played(session: Session, day: date) -> int:
+    # SQLite stores UTC timestamps without an offset, so compare against naive bounds
+    start = datetime.combine(day, time.min)
+    return session.exec(
+        select(func.count(Game.id)).where(Game.started_at >= start, Game.started_at < start + timedelta(days=1))
+    ).one()
+
+
+def build_summary(session: Session, day: date, metrics: DayMetrics) -> DailySummary:
+    return DailySummary(
+        date=day.isoformat(),
+        games_played=count_games_played(session, day),
+        peak_concurrent_players=metrics.peak_concurrent_players,
+        request_count=metrics.request_count,
+        error_log_count=metrics.error_log_count,
+        server_error_count=metrics.server_error_count,
+        p99_latency_ms=metrics.p99_latency_ms,
+    )
+
+
+async def write_daily_summary(day: date) -> DailySummary:
+    """Persist the summary for a finished day, replacing any earlier row for it."""
+    metrics = metrics_collector.pop(day)
+    async with get_session_context() as session:
+        summary = build_summary(session, day, metrics)
+        existing = session.exec(select(DailySummary).where(DailySummary.date == summary.date)).first()
+        if existing:
+            session.delete(existing)
+            session.flush()
+        session.add(summary)
+        session.commit()
+        session.refresh(summary)
+    return summary
+
+
+def seconds_until_next_day(now: datetime) -> float:
+    next_midnight = datetime.combine(now.date() + timedelta(days=1), time.min, tzinfo=timezone.utc)
+    return (next_midnight - now).total_seconds()
+
+
+async def daily_summary_task():
+    while True:
+        now = datetime.now(timezone.utc)
+        # Wake just after midnight so late requests from the previous day are already recorded
+        await asyncio.sleep(seconds_until_next_day(now) + 5)
+        day = now.date()
+        try:
+            summary = await write_daily_summary(day)
+            server_logger.info(
+                f"Daily summary for {summary.date}: games={summary.games_played} "
+                f"peak_players={summary.peak_concurrent_players} errors={summary.error_log_count} "
+                f"p99_ms={summary.p99_latency_ms}"
+            )
+        except Exception as e:
+            server_logger.exception(f"Error writing daily summary for {day}: {e}")
+
+
+def start_daily_summary_task():
+    global _summary_task
+    for logger in (server_logger, api_logger, database_logger, websocket_logger):
+        if _error_handler not in logger.handlers:
+            logger.addHandler(_error_handler)
+    _summary_task = asyncio.create_task(daily_summary_task())
+    server_logger.info("Started daily summary background task")
+
+
+def stop_daily_summary_task():
+    global _summary_task
+    for logger in (server_logger, api_logger, database_logger, websocket_logger):
+        logger.removeHandler(_error_handler)
+    if _summary_task:
+        _summary_task.cancel()
+        _summary_task = None
+        server_logger.info("Stopped daily summary background task")
@@ -2,26 +2,37 @@ import logging
 import os
 from contextlib import asynccontextmanager
 
-from sqlalchemy import event
+from sqlalchemy import Engine, event
+from sqlalchemy.pool import StaticPool
 from sqlmodel import Session, SQLModel, create_engine
 
+from backend.chaos import delay_statement
 from backend.custom_logging import database_logger
-from backend.database.models import Game, Guess, Lobby, Player, Team  # noqa: F401
+from backend.database.models import ChatMessage, DailySummary, Game, Guess, Lobby, Player, Team  # noqa: F401
 from backend.settings import settings
 
 DATABASE_URL = settings.DATABASE_URL
 
 os.makedirs("databases", exist_ok=True)
 
-engine = create_engine(DATABASE_URL, connect_args={"check_same_thread": False})
 
-if "sqlite" in DATABASE_URL:
+def enable_sqlite_fks(dbapi_connection, connection_record):
+    cursor = dbapi_connection.cursor()
+    cursor.execute("PRAGMA foreign_keys=ON")
+    cursor.close()
 
-    @event.listens_for(engine, "connect")
-    def enable_sqlite_fks(dbapi_connection, connection_record):
-        cursor = dbapi_connection.cursor()
-        cursor.execute("PRAGMA foreign_keys=ON")
-        cursor.close()
+
+def build_engine(database_url: str) -> Engine:
+    if database_url in ("sqlite://", "sqlite:///:memory:"):
+        # One shared connection, otherwise every session would see its own empty in-memory database
+        new_engine = create_engine(database_url, connect_args={"check_same_thread": False}, poolclass=StaticPool)
+    else:
+        new_engine = create_engine(database_url, connect_args={"check_same_thread": False})
+    if "sqlite" in database_url:
+        event.listen(new_engine, "connect", enable_sqlite_fks)
+    event.listen(new_engine, "handle_error", _sqlalchemy_handle_error)
+    event.listen(new_engine, "before_cursor_execute", delay_statement)
+    return new_engine
 
 
 def _sqlalchemy_handle_error(exception_context):
@@ -52,11 +63,17 @@ def register_database_logger():
     log.setLevel(database_logger.level)
     log.propagate = False
 
-    event.listen(engine, "handle_error", _sqlalchemy_handle_error)
-
 
 register_database_logger()
 
+engine = build_engine(DATABASE_URL)
+
+
+def use_engine(new_engine: Engine):
+    """Point every session factory at a different engine (used by AppBuilder for in-memory test databases)."""
+    global engine
+    engine = new_engine
+
 
 def drop_all_tables():
     SQLModel.metadata.drop_all(engine)
@@ -0,0 +1,583 @@
+"""
+Versioned schema migrations, so schema changes no longer mean dropping the database.
+
+The applied version is kept in a schema_version table. A database without any of the app's tables is created
+straight from the models and stamped with the latest version. One that has the tables but no schema_version
+predates migrations and is taken to be at BASELINE_VERSION, the first release's schema, which migration 1 brings
+up to the models as they were when migrations were added. Anything in MIGRATIONS above the stored version is then
+applied in order, each in its own transaction.
+
+To change the schema, edit the model in models.py and append a migration that brings an existing database
+to the same shape, e.g.
+
+    def add_lobby_theme(connection: Connection):
+        connection.execute(text("ALTER TABLE lobby ADD COLUMN theme VARCHAR"))
+
+    MIGRATIONS = [Migration(2, "add lobby theme", add_lobby_theme)]
+
+Run them with `./rt migrate` (the server also runs them on startup):
+
+    uv run python -m backend.database.migrations
+    uv run python -m backend.database.migrations --status
+    uv run python -m backend.database.migrations --status --json
+
+Instances starting against the same database take turns: the first inserts a row into startup_lock and the
+others wait (and log who they're waiting on) until it's deleted again. A row older than STARTUP_LOCK_TIMEOUT_SECONDS
+was left by an instance that died mid-startup and is taken over.
+
+Before applying anything to an SQLite database file, a copy is written to a backups/ folder next to it, keeping the
+newest MIGRATION_BACKUPS_KEEP, and its path is stored with the versions it preceded in schema_version. If a
+migration goes wrong, stop the server, go back to the previous release and restore the copy with
+`./rt db-restore <backup>`:
+
+    uv run python -m backend.database.migrations --restore databases/backups/main-20260301T120000000000Z-v4.db
+"""
+
+import argparse
+import json
+import os
+import socket
+import sqlite3
+import sys
+import time
+from contextlib import closing, contextmanager
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Callable, List, Optional
+
+from sqlalchemy import (
+    Column,
+    DateTime,
+    Engine,
+    Float,
+    ForeignKey,
+    Index,
+    Integer,
+    JSON,
+    MetaData,
+    String,
+    Table,
+    delete,
+    func,
+    inspect,
+    select,
+    text,
+)
+from sqlalchemy.engine import Connection
+from sqlalchemy.exc import IntegrityError, OperationalError
+from sqlmodel import SQLModel
+
+from backend.custom_logging import database_logger
+from backend.database import models  # noqa: F401  (registers the tables on SQLModel.metadata)
+from backend.game.rng import new_seed
+from backend.settings import settings
+
+# The schema before any migrations existed: lobby, player, team, game, guess and roundresult as at the first release
+BASELINE_VERSION = 0
+
+# Kept out of SQLModel.metadata so drop_all_tables() leaves it alone
+version_metadata = MetaData()
+schema_version = Table(
+    "schema_version",
+    version_metadata,
+    Column("version", Integer, primary_key=True),
+    Column("name", String, nullable=False),
+    Column("applied_at", DateTime(timezone=True), nullable=False),
+    Column("backup_path", String, nullable=True),  # Copy of the database taken before this version was applied
+)
+# One row while an instance is creating or migrating the database, so instances starting together take turns
+startup_lock_table = Table(
+    "startup_lock",
+    version_metadata,
+    Column("id", Integer, primary_key=True),
+    Column("holder", String, nullable=False),  # host:pid of the instance holding it
+    Column("acquired_at", DateTime(timezone=True), nullable=False),
+)
+
+
+class MigrationError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class Migration:
+    version: int
+    name: str
+    upgrade: Callable[[Connection], None]
+
+
+def add_team_chat_history(connection: Connection):
+    # The table as it was added, not ChatMessage.__table__, so later model changes stay with their own migrations
+    chat_message = Table(
+        "chat_message",
+        MetaData(),
+        Column("id", Integer, primary_key=True),
+        Column("lobby_id", Integer, ForeignKey("lobby.id", ondelete="CASCADE"), nullable=False),
+        Column("team_id", Integer, ForeignKey("team.id", ondelete="CASCADE"), nullable=False),
+        Column("player_id", Integer, ForeignKey("player.id", ondelete="CASCADE"), nullable=False),
+        Column("player_name", String, nullable=False),
+        Column("text", String, nullable=False),
+        Column("created_at", DateTime, nullable=False),
+        Index("ix_chat_message_team_id", "team_id"),
+    )
+    chat_message.create(connection)
+
+
+def add_roster_ordering(connection: Connection):
+    connection.execute(text("ALTER TABLE player ADD COLUMN join_order INTEGER NOT NULL DEFAULT 0"))
+    connection.execute(text("ALTER TABLE lobby ADD COLUMN roster_version INTEGER NOT NULL DEFAULT 0"))
+    # Existing players are numbered in the order they joined
+    connection.execute(
+        text(
+            "UPDATE player SET join_order = ("
+            "SELECT COUNT(*) FROM player AS earlier "
+            "WHERE earlier.lobby_id = player.lobby_id "
+            "AND (earlier.created_at < player.created_at "
+            "OR (earlier.created_at = player.created_at AND earlier.id <= player.id)))"
+        )
+    )
+
+
+def add_round_time_limit(connection: Connection):
+    connection.execute(text("ALTER TABLE lobby ADD COLUMN round_time_limit_seconds INTEGER"))
+
+
+def add_score_adjustments(connection: Connection):
+    # As added, like add_team_chat_history
+    score_adjustment = Table(
+        "score_adjustment",
+        MetaData(),
+        Column("id", Integer, primary_key=True),
+        Column("lobby_id", Integer, ForeignKey("lobby.id", ondelete="CASCADE"), nullable=False),
+        Column("team_id", Integer, ForeignKey("team.id", ondelete="CASCADE"), nullable=False),
+        Column("team_name", String, nullable=False),
+        Column("delta", Integer, nullable=False),
+        Column("reason", String, nullable=False),
+        Column("total_points", Integer, nullable=False),
+        Column("admin_session_id", String, nullable=True),
+        Column("created_at", DateTime, nullable=False),
+        Index("ix_score_adjustment_lobby_id", "lobby_id"),
+    )
+    score_adjustment.create(connection)
+
+
+def add_lobby_host(connection: Connection):
+    connection.execute(text("ALTER TABLE lobby ADD COLUMN host_player_id INTEGER"))
+    # Existing lobbies get the same host they would have had: their longest-standing human player
+    connection.execute(
+        text(
+            "UPDATE lobby SET host_player_id = ("
+            "SELECT player.id FROM player "
+            "WHERE player.lobby_id = lobby.id AND player.is_bot = 0 "
+            "ORDER BY player.join_order, player.id LIMIT 1) "
+            "WHERE is_quick_play = 0"
+        )
+    )
+
+
+def add_prize_tiers(connection: Connection):
+    connection.execute(text("ALTER TABLE lobby ADD COLUMN prize_tiers VARCHAR"))
+
+
+def add_intermission(connection: Connection):
+    connection.execute(text("ALTER TABLE lobby ADD COLUMN intermission_enabled BOOLEAN NOT NULL DEFAULT 0"))
+    connection.execute(text("ALTER TABLE lobby ADD COLUMN intermission_seconds INTEGER"))
+    connection.execute(text("ALTER TABLE lobby ADD COLUMN intermission_started_at DATETIME"))
+
+
+def add_client_guess_ids(connection: Connection):
+    connection.execute(text("ALTER TABLE guess ADD COLUMN client_guess_id VARCHAR"))
+    connection.execute(
+        text("CREATE UNIQUE INDEX ix_guess_player_client_guess ON guess (player_id, client_guess_id)")
+    )
+
+
+def _column_names(connection: Connection, table: str) -> set[str]:
+    return {column["name"] for column in inspect(connection).get_columns(table)}
+
+
+# Columns the models gained between the baseline and the first migration, with their DDL
+PRE_MIGRATION_COLUMNS = {
+    "lobby": {
+        "organization_id": "INTEGER REFERENCES organization (id)",
+        "team_name_theme": "VARCHAR",
+        "progress_visibility": "VARCHAR NOT NULL DEFAULT 'none'",
+        "late_join_enabled": "BOOLEAN NOT NULL DEFAULT 0",
+        "soft_player_cap": "INTEGER",
+        "max_players": "INTEGER",
+        "locked": "BOOLEAN NOT NULL DEFAULT 0",
+        "is_private": "BOOLEAN NOT NULL DEFAULT 0",
+        "pace_keeper_enabled": "BOOLEAN NOT NULL DEFAULT 0",
+        "is_quick_play": "BOOLEAN NOT NULL DEFAULT 0",
+        "rng_seed": "INTEGER NOT NULL DEFAULT 0",
+        "timezone": "VARCHAR NOT NULL DEFAULT 'UTC'",
+        "opens_at": "DATETIME",
+        "closes_at": "DATETIME",
+        "starts_at": "DATETIME",
+    },
+    "player": {
+        "reduced_motion": "BOOLEAN NOT NULL DEFAULT 0",
+        "high_contrast": "BOOLEAN NOT NULL DEFAULT 0",
+        "larger_text": "BOOLEAN NOT NULL DEFAULT 0",
+        "is_bot": "BOOLEAN NOT NULL DEFAULT 0",
+        "bot_skill": "FLOAT",
+    },
+    "team": {"color": "VARCHAR"},
+    "guess": {"review_status": "VARCHAR"},
+}
+
+
+def add_pre_migration_schema(connection: Connection):
+    """
+    Bring in the tables, columns and indexes added before migrations existed, which baseline databases lack.
+
+    Databases created partway through that stretch already have some of them, so only missing ones are added.
+    """
+    # The tables as they were added, like add_team_chat_history
+    metadata = MetaData()
+    Table(
+        "organization",
+        metadata,
+        Column("id", Integer, primary_key=True),
+        Column("name", String, nullable=False),
+        Column("slug", String, nullable=False),
+        Column("password_hash", String, nullable=False),
+        Column("created_at", DateTime, nullable=False),
+        Index("ix_organization_slug", "slug", unique=True),
+    )
+    Table(
+        "puzzle",
+        metadata,
+        Column("id", Integer, primary_key=True),
+        Column("title", String, nullable=False),
+        Column("difficulty", String, nullable=False),
+        Column("ladder", JSON),
+        Column("author_name", String),
+        Column("author_handle", String),
+        Column("author_notes", String),
+        Column("organization_id", Integer, ForeignKey("organization.id")),
+        Column("created_at", DateTime, nullable=False),
+        Column("updated_at", DateTime),
+        Index("ix_puzzle_organization_id", "organization_id"),
+    )
+    Table(
+        "pacehint",
+        metadata,
+        Column("id", Integer, primary_key=True),
+        Column("game_id", Integer, ForeignKey("game.id", ondelete="CASCADE"), nullable=False),
+        Column("team_id", Integer, ForeignKey("team.id", ondelete="CASCADE"), nullable=False),
+        Column("word_index", Integer, nullable=False),
+        Column("level", Integer, nullable=False),
+        Column("hint", String, nullable=False),
+        Column("created_at", DateTime, nullable=False),
+        Index("ix_pacehint_game_id", "game_id"),
+    )
+    Table(
+        "dailysummary",
+        metadata,
+        Column("id", Integer, primary_key=True),
+        Column("date", String, nullable=False),
+        Column("games_played", Integer, nullable=False),
+        Column("peak_concurrent_players", Integer, nullable=False),
+        Column("request_count", Integer, nullable=False),
+        Column("error_log_count", Integer, nullable=False),
+        Column("server_error_count", Integer, nullable=False),
+        Column("p99_latency_ms", Float),
+        Column("created_at", DateTime, nullable=False),
+        Index("ix_dailysummary_date", "date", unique=True),
+    )
+    Table("lobby", metadata, Column("id", Integer, primary_key=True))  # Only for the foreign keys above
+    Table("game", metadata, Column("id", Integer, primary_key=True))
+    Table("team", metadata, Column("id", Integer, primary_key=True))
+    new_tables = ("organization", "puzzle", "pacehint", "dailysummary")
+    metadata.create_all(connection, tables=[metadata.tables[name] for name in new_tables])
+
+    added = set()
+    for table, columns in PRE_MIGRATION_COLUMNS.items():
+        existing = _column_names(connection, table)
+        for column, ddl in columns.items():
+            if column not in existing:
+                connection.execute(text(f"ALTER TABLE {table} ADD COLUMN {column} {ddl}"))
+                added.add(column)
+    if "rng_seed" in added:
+        # Each lobby gets its own seed, as new lobbies do
+        for (lobby_id,) in connection.execute(text("SELECT id FROM lobby")).all():
+            connection.execute(
+                text("UPDATE lobby SET rng_seed = :seed WHERE id = :id"), {"seed": new_seed(), "id": lobby_id}
+            )
+    connection.execute(text("CREATE INDEX IF NOT EXISTS ix_lobby_organization_id ON lobby (organization_id)"))
+    connection.execute(
+        text("CREATE INDEX IF NOT EXISTS ix_guess_team_game_created ON guess (team_id, game_id, created_at)")
+    )
+
+
+MIGRATIONS: List[Migration] = [
+    Migration(1, "add tables and columns from before migrations", add_pre_migration_schema),
+    Migration(2, "add team chat history", add_team_chat_history),
+    Migration(3, "add player join order and lobby roster version", add_roster_ordering),
+    Migration(4, "add lobby round time limit", add_round_time_limit),
+    Migration(5, "add score adjustments", add_score_adjustments),
+    Migration(6, "add lobby host", add_lobby_host),
+    Migration(7, "add lobby prize tiers", add_prize_tiers),
+    Migration(8, "add lobby intermission", add_intermission),
+    Migration(9, "add client guess ids", add_client_guess_ids),
+]
+
+
+def head_version() -> int:
+    return max([BASELINE_VERSION] + [migration.version for migration in MIGRATIONS])
+
+
+def check_migrations():
+    versions = [migration.version for migration in MIGRATIONS]
+    if versions != sorted(set(versions)) or (versions and versions[0] <= BASELINE_VERSION):
+        raise MigrationError(f"Migration versions must be unique, increasing and above {BASELINE_VERSION}")
+
+
+def current_version(connection: Connection) -> Optional[int]:
+    """The applied version, or None when the database has no schema_version yet."""
+    if not inspect(connection).has_table(schema_version.name):
+        return None
+    return connection.execute(select(func.max(schema_version.c.version))).scalar()
+
+
+def _has_app_tables(connection: Connection) -> bool:
+    existing = set(inspect(connection).get_table_names())
+    return any(table in existing for table in SQLModel.metadata.tables)
+
+
+def _stamp(connection: Connection, version: int, name: str, backup_path: Optional[Path] = None):
+    connection.execute(
+        schema_version.insert().values(
+            version=version,
+            name=name,
+            applied_at=datetime.now(timezone.utc),
+            backup_path=str(backup_path) if backup_path else None,
+        )
+    )
+
+
+def _add_backup_path_column(connection: Connection):
+    # schema_version tables from before backups were taken lack the column
+    columns = [column["name"] for column in inspect(connection).get_columns(schema_version.name)]
+    if "backup_path" not in columns:
+        connection.execute(text("ALTER TABLE schema_version ADD COLUMN backup_path VARCHAR"))
+
+
+def sqlite_database_file(target_engine: Engine) -> Optional[Path]:
+    """The file behind an SQLite engine; None for other databases and in-memory ones."""
+    url = target_engine.url
+    if url.get_backend_name() != "sqlite" or url.database in (None, "", ":memory:"):
+        return None
+    return Path(url.database)
+
+
+def _copy_sqlite(source: Path, destination: Path):
+    # SQLite's backup API rather than a file copy, so pages still in a WAL journal come along
+    with closing(sqlite3.connect(source)) as source_db, closing(sqlite3.connect(destination)) as destination_db:
+        source_db.backup(destination_db)
+
+
+def backup_database(database_file: Path, version: int, keep: int) -> Path:
+    """
+    Copy the database to backups/<name>-<UTC timestamp>-v<version> next to it and return the copy's path.
+
+    Only the newest `keep` copies of this database are kept.
+    """
+    backups = database_file.parent / "backups"
+    backups.mkdir(exist_ok=True)
+    timestamp = datetime.now(timezone.utc).strftime("%Y%m%dT%H%M%S%fZ")
+    backup = backups / f"{database_file.stem}-{timestamp}-v{version}{database_file.suffix}"
+    _copy_sqlite(database_file, backup)
+
+    existing = sorted(backups.glob(f"{database_file.stem}-*T*Z-v*{database_file.suffix}"))
+    for old_backup in existing[:-keep]:
+        old_backup.unlink()
+    return backup
+
+
+def restore_backup(backup: Path, target_engine: Optional[Engine] = None) -> Path:
+    """Overwrite the SQLite database with a backup taken before a migration; returns the restored file's path."""
+    if target_engine is None:
+        from backend.database import engine as target_engine
+
+    database_file = sqlite_database_file(target_engine)
+    if database_file is None:
+        raise MigrationError("Only SQLite database files can be restored from a backup")
+    if not backup.is_file():
+        raise MigrationError(f"No backup at {backup}")
+    with closing(sqlite3.connect(backup)) as connection:
+        if not connection.execute("SELECT name FROM sqlite_master WHERE name = 'schema_version'").fetchone():
+            raise MigrationError(f"{backup} isn't a Raddle Teams database backup")
+
+    target_engine.dispose()
+    _copy_sqlite(backup, database_file)
+    database_logger.warning(f"Restored the database at {database_file} from {backup}")
+    return database_file
+
+
+def _as_utc(value: datetime) -> datetime:
+    # SQLite hands back naive datetimes
+    return value if value.tzinfo else value.replace(tzinfo=timezone.utc)
+
+
+@contextmanager
+def startup_lock(target_engine: Engine, timeout: Optional[int] = None, poll_seconds: float = 0.5):
+    """
+    Hold the database's startup lock, waiting while another instance holds it.
+
+    A lock held longer than timeout seconds (by default STARTUP_LOCK_TIMEOUT_SECONDS) is taken to be abandoned.
+    """
+    if timeout is None:
+        timeout = settings.STARTUP_LOCK_TIMEOUT_SECONDS
+    holder = f"{socket.gethostname()}:{os.getpid()}"
+    try:
+        startup_lock_table.create(target_engine, checkfirst=True)
+    except OperationalError:
+        # Another instance created it between the check and the CREATE
+        if not inspect(target_engine).has_table(startup_lock_table.name):
+            raise
+
+    waiting_on = None
+    while True:
+        try:
+            with target_engine.begin() as connection:
+                connection.execute(
+                    startup_lock_table.insert().values(id=1, holder=holder, acquired_at=datetime.now(timezone.utc))
+                )
+            break
+        except IntegrityError:
+            pass
+        with target_engine.begin() as connection:
+            row = connection.execute(select(startup_lock_table)).first()
+            if row is None:
+                continue
+            held_for = (datetime.now(timezone.utc) - _as_utc(row.acquired_at)).total_seconds()
+            if held_for > timeout:
+                database_logger.warning(
+                    f"Taking over the startup lock from {row.holder}, held for {held_for:.0f}s (likely crashed)"
+                )
+                connection.execute(
+                    delete(startup_lock_table).where(startup_lock_table.c.acquired_at == row.acquired_at)
+                )
+                continue
+        if waiting_on != row.holder:
+            waiting_on = row.holder
+            database_logger.info(f"Waiting for {row.holder} to finish setting up the database before starting")
+        time.sleep(poll_seconds)
+
+    if waiting_on:
+        database_logger.info(f"{waiting_on} finished setting up the database, continuing startup")
+    try:
+        yield
+    finally:
+        with target_engine.begin() as connection:
+            connection.execute(delete(startup_lock_table).where(startup_lock_table.c.holder == holder))
+
+
+def run_migrations(target_engine: Optional[Engine] = None, backups_keep: Optional[int] = None) -> List[int]:
+    """
+    Bring the database up to head_version(); returns the versions that were applied.
+
+    When there is anything to apply to an SQLite file, it is backed up first unless backups_keep (by default
+    MIGRATION_BACKUPS_KEEP) is 0.
+    """
+    if target_engine is None:
+        from backend.database import engine as target_engine
+    if backups_keep is None:
+        backups_keep = settings.MIGRATION_BACKUPS_KEEP
+
+    check_migrations()
+    with startup_lock(target_engine):
+        return _migrate(target_engine, backups_keep)
+
+
+def _migrate(target_engine: Engine, backups_keep: int) -> List[int]:
+    with target_engine.begin() as connection:
+        version_metadata.create_all(connection)
+        _add_backup_path_column(connection)
+        version = current_version(connection)
+        if not _has_app_tables(connection):
+            SQLModel.metadata.create_all(connection)
+            connection.execute(schema_version.delete())
+            _stamp(connection, head_version(), "created from models")
+            database_logger.info(f"Created a new database at schema version {head_version()}")
+            return []
+        if version is None:
+            _stamp(connection, BASELINE_VERSION, "baseline")
+            database_logger.warning(f"Database predates migrations, assuming schema version {BASELINE_VERSION}")
+            version = BASELINE_VERSION
+    if version > head_version():
+        raise MigrationError(f"Database is at schema version {version}, newer than this code ({head_version()})")
+
+    pending = [migration for migration in MIGRATIONS if migration.version > version]
+    backup_path = None
+    database_file = sqlite_database_file(target_engine)
+    if pending and database_file is not None and backups_keep > 0:
+        backup_path = backup_database(database_file, version, backups_keep)
+        database_logger.info(f"Backed up the database at schema version {version} to {backup_path}")
+
+    applied = []
+    for migration in pending:
+        database_logger.info(f"Applying migration {migration.version}: {migration.name}")
+        try:
+            with target_engine.begin() as connection:
+                migration.upgrade(connection)
+                _stamp(connection, migration.version, migration.name, backup_path)
+        except Exception as e:
+            raise MigrationError(f"Migration {migration.version} ({migration.name}) failed: {e}") from e
+        applied.append(migration.version)
+    return applied
+
+
+def main(argv: Optional[list[str]] = None) -> int:
+    parser = argparse.ArgumentParser(description="Apply pending Raddle Teams database migrations")
+    parser.add_argument("--status", action="store_true", help="Only show the current and latest schema version")
+    parser.add_argument("--json", action="store_true", help="Print the result as a JSON object, for scripts")
+    parser.add_argument("--restore", metavar="BACKUP", help="Overwrite the database with a pre-migration backup")
+    args = parser.parse_args(argv)
+
+    def report(text: str, **fields):
+        print(json.dumps(fields) if args.json else text)
+
+    from backend.database import engine
+
+    try:
+        if args.restore:
+            database_file = restore_backup(Path(args.restore), engine)
+            with engine.connect() as connection:
+                version = current_version(connection)
+            report(
+                f"Restored {database_file} from {args.restore}, now at schema version {version}",
+                restored_from=args.restore,
+                current_version=version,
+            )
+            return 0
+        if args.status:
+            with engine.connect() as connection:
+                version = current_version(connection)
+            report(
+                f"Schema version {version if version is not None else 'unknown'}, latest is {head_version()}",
+                current_version=version,
+                latest_version=head_version(),
+                up_to_date=version == head_version(),
+            )
+            return 0
+        applied = run_migrations(engine)
+    except MigrationError as e:
+        if args.json:
+            report("", error=str(e))
+        else:
+            print(f"Error: {e}", file=sys.stderr)
+        return 1
+    report(
+        f"Applied migrations {applied}" if applied else f"Already at schema version {head_version()}",
+        applied=applied,
+        current_version=head_version(),
+    )
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -4,6 +4,10 @@ from typing import Optional
 from sqlalchemy import Column, Index, JSON, UniqueConstraint
 from sqlmodel import Field, Relationship, SQLModel
 
+from backend.game.rng import new_seed
+
+DEFAULT_TIMEZONE = "UTC"
+
 
 class Player(SQLModel, table=True):
     __table_args__ = (
@@ -19,6 +23,14 @@ class Player(SQLModel, table=True):
     lobby_id: int = Field(foreign_key="lobby.id", ondelete="CASCADE")
     team_id: Optional[int] = Field(default=None, foreign_key="team.id", ondelete="CASCADE")
     is_ready: bool = Field(default=False)
+    # Accessibility preferences, stored server-side so every device the player uses renders the same way
+    reduced_motion: bool = Field(default=False)
+    high_contrast: bool = Field(default=False)
+    larger_text: bool = Field(default=False)
+    # Admin-added stand-ins for missing players; with a bot_skill they also solve rungs (see backend/bots.py)
+    is_bot: bool = Field(default=False)
+    bot_skill: Optional[float] = Field(default=None)
+    join_order: int = Field(default=0)  # Position in the lobby's join sequence; rosters are listed by it
     created_at: datetime = Field(default_factory=lambda: datetime.now(tz=timezone.utc))
 
     # Relationships
@@ -31,6 +43,7 @@ class Team(SQLModel, table=True):
 
     id: Optional[int] = Field(default=None, primary_key=True)
     name: str
+    color: Optional[str] = Field(default=None)  # "#RRGGBB" from TEAM_COLOR_PALETTE, or an admin override
     lobby_id: int = Field(foreign_key="lobby.id", ondelete="CASCADE")
     game_id: Optional[int] = Field(default=None, foreign_key="game.id", ondelete="SET NULL")  # Link to team's puzzle
     current_word_index: int = Field(default=0)  # Deprecated, not used
@@ -48,10 +61,47 @@ class Team(SQLModel, table=True):
     guesses: list["Guess"] = Relationship(back_populates="team", cascade_delete=True, passive_deletes=True)
 
 
+class Organization(SQLModel, table=True):
+    """An independent group on a shared deployment; its admins only see its own lobbies and puzzles."""
+
+    id: Optional[int] = Field(default=None, primary_key=True)
+    name: str
+    slug: str = Field(unique=True, index=True)  # What its admins type alongside their password to log in
+    password_hash: str  # See backend/organizations.py
+    created_at: datetime = Field(default_factory=lambda: datetime.now(tz=timezone.utc))
+
+
 class Lobby(SQLModel, table=True):
     id: Optional[int] = Field(default=None, primary_key=True)
     code: str = Field(unique=True, index=True)
     name: str
+    # None for lobbies created by deployment admins (and quick play), which organizations can't see
+    organization_id: Optional[int] = Field(default=None, foreign_key="organization.id", index=True)
+    team_name_theme: Optional[str] = Field(default=None)  # None uses the random "Adjective Noun" generator
+    progress_visibility: str = Field(default="none")  # What players see of other teams: none, coarse or exact
+    late_join_enabled: bool = Field(default=False)  # Let players join mid-game onto the smallest team
+    soft_player_cap: Optional[int] = Field(default=None)  # None uses LOBBY_SOFT_PLAYER_CAP, 0 disables the cap
+    max_players: Optional[int] = Field(default=None)  # Hard cap: joins past it are refused. None for no cap
+    locked: bool = Field(default=False)  # Refuse every new join, e.g. once the game has started
+    is_private: bool = Field(default=False)  # No public share links, and existing ones stop resolving
+    pace_keeper_enabled: bool = Field(default=False)  # Auto-hint the slowest team when it stalls
+    is_quick_play: bool = Field(default=False)  # The server-run quick play lobby (see backend/quick_play.py)
+    rng_seed: int = Field(default_factory=new_seed)  # Drives team shuffles and puzzle draws; reuse it to clone a lobby
+    timezone: str = Field(default=DEFAULT_TIMEZONE)  # IANA name; schedule times are displayed in this zone
+    opens_at: Optional[datetime] = Field(default=None)  # Scheduled times are stored in UTC
+    closes_at: Optional[datetime] = Field(default=None)
+    starts_at: Optional[datetime] = Field(default=None)
+    roster_version: int = Field(default=0)  # Bumped on every roster change, see backend/roster.py
+    round_time_limit_seconds: Optional[int] = Field(default=None)  # Round timer armed on every start; None: untimed
+    # Player allowed to shuffle teams and start games from the lobby screen (see backend/lobby_host.py). Not a
+    # foreign key, since player already references lobby; cleared or handed off when the host leaves
+    host_player_id: Optional[int] = Field(default=None)
+    prize_tiers: Optional[str] = Field(default=None)  # JSON PrizeTiers for contest mode; None when it's off
+    # Hold the lobby in intermission after each round until the admin starts the next (see backend/intermission.py),
+    # or until intermission_seconds have passed when that's set
+    intermission_enabled: bool = Field(default=False)
+    intermission_seconds: Optional[int] = Field(default=None)
+    intermission_started_at: Optional[datetime] = Field(default=None)  # Set while the lobby is in intermission
     created_at: datetime = Field(default_factory=lambda: datetime.now(tz=timezone.utc))
 
     # Relationships
@@ -83,12 +133,30 @@ class Game(SQLModel, table=True):
     teams: list["Team"] = Relationship(back_populates="game")  # Teams solving this puzzle
 
 
+class Puzzle(SQLModel, table=True):
+    """A puzzle written by a host and stored in the database; games reference it as "db:<id>" in puzzle_path."""
+
+    id: Optional[int] = Field(default=None, primary_key=True)
+    title: str
+    difficulty: str  # "easy", "medium", "hard"
+    ladder: str = Field(default="[]", sa_column=Column(JSON))  # JSON array of ladder steps (word, clue, transform)
+    author_name: Optional[str] = Field(default=None)
+    author_handle: Optional[str] = Field(default=None)  # e.g. "@wordsmith", credited to players alongside the name
+    author_notes: Optional[str] = Field(default=None)  # For other hosts only, never sent to players
+    # Only playable in lobbies of the same organization (None: deployment admins' lobbies)
+    organization_id: Optional[int] = Field(default=None, foreign_key="organization.id", index=True)
+    created_at: datetime = Field(default_factory=lambda: datetime.now(tz=timezone.utc))
+    updated_at: Optional[datetime] = Field(default=None)
+
+
 class Guess(SQLModel, table=True):
     __table_args__ = (
         Index("ix_guess_team_id", "team_id"),
         Index("ix_guess_player_id", "player_id"),
         Index("ix_guess_game_id", "game_id"),
         Index("ix_guess_team_word", "team_id", "word_index"),
+        Index("ix_guess_team_game_created", "team_id", "game_id", "created_at"),  # Team guess history pages
+        Index("ix_guess_player_client_guess", "player_id", "client_guess_id", unique=True),  # Replayed offline guesses
     )
 
     id: Optional[int] = Field(default=None, primary_key=True)
@@ -99,6 +167,8 @@ class Guess(SQLModel, table=True):
     direction: str  # "down" or "up"
     guess: str  # The guessed word
     is_correct: bool
+    review_status: Optional[str] = Field(default=None)  # "pending", "approved" or "rejected" on open-ended rungs
+    client_guess_id: Optional[str] = Field(default=None)  # The client's id for it, to spot a queued guess sent twice
     created_at: datetime = Field(default_factory=lambda: datetime.now(tz=timezone.utc))
 
     # Relationships
@@ -107,6 +177,52 @@ class Guess(SQLModel, table=True):
     game: "Game" = Relationship()
 
 
+class PaceHint(SQLModel, table=True):
+    """A hint the pace keeper granted a stalled team; hints since the team's last solve set the next level."""
+
+    __table_args__ = (Index("ix_pacehint_game_id", "game_id"),)
+
+    id: Optional[int] = Field(default=None, primary_key=True)
+    game_id: int = Field(foreign_key="game.id", ondelete="CASCADE")
+    team_id: int = Field(foreign_key="team.id", ondelete="CASCADE")
+    word_index: int
+    level: int  # 1 for the first hint on a stall, escalating while the team stays stuck
+    hint: str  # e.g. "FL___"
+    created_at: datetime = Field(default_factory=lambda: datetime.now(tz=timezone.utc))
+
+
+class ChatMessage(SQLModel, table=True):
+    """A team chat line, kept (the last TEAM_CHAT_HISTORY_SIZE per team) for players catching up on reconnect."""
+
+    __tablename__ = "chat_message"
+    __table_args__ = (Index("ix_chat_message_team_id", "team_id"),)
+
+    id: Optional[int] = Field(default=None, primary_key=True)
+    lobby_id: int = Field(foreign_key="lobby.id", ondelete="CASCADE")
+    team_id: int = Field(foreign_key="team.id", ondelete="CASCADE")
+    player_id: int = Field(foreign_key="player.id", ondelete="CASCADE")
+    player_name: str  # As it was when sent
+    text: str
+    created_at: datetime = Field(default_factory=lambda: datetime.now(tz=timezone.utc))
+
+
+class ScoreAdjustment(SQLModel, table=True):
+    """A host's manual change to a team's points, e.g. for an off-platform bonus challenge; kept as an audit trail."""
+
+    __tablename__ = "score_adjustment"
+    __table_args__ = (Index("ix_score_adjustment_lobby_id", "lobby_id"),)
+
+    id: Optional[int] = Field(default=None, primary_key=True)
+    lobby_id: int = Field(foreign_key="lobby.id", ondelete="CASCADE")
+    team_id: int = Field(foreign_key="team.id", ondelete="CASCADE")
+    team_name: str  # As it was when adjusted
+    delta: int  # Points added; negative to take points away
+    reason: str
+    total_points: int  # The team's total after the adjustment
+    admin_session_id: Optional[str] = Field(default=None)  # None if made with the raw password, before sessions
+    created_at: datetime = Field(default_factory=lambda: datetime.now(tz=timezone.utc))
+
+
 class RoundResult(SQLModel, table=True):
     __table_args__ = (
         Index("ix_round_lobby_id", "lobby_id"),
@@ -129,3 +245,17 @@ class RoundResult(SQLModel, table=True):
     lobby: "Lobby" = Relationship()
     game: "Game" = Relationship()
     team: "Team" = Relationship()
+
+
+class DailySummary(SQLModel, table=True):
+    """Per-day operational record written by the daily summary task."""
+
+    id: Optional[int] = Field(default=None, primary_key=True)
+    date: str = Field(unique=True, index=True)  # UTC day, YYYY-MM-DD
+    games_played: int
+    peak_concurrent_players: int
+    request_count: int
+    error_log_count: int
+    server_error_count: int
+    p99_latency_ms: Optional[float]
+    created_at: datetime = Field(default_factory=lambda: datetime.now(tz=timezone.utc))
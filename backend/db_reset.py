@@ -0,0 +1,72 @@
+"""Drop and recreate every table, either directly in the configured database or through a running server.
+
+    uv run python -m backend.db_reset --yes
+    uv run python -m backend.db_reset --yes --server http://localhost:8000
+
+Resetting the database file directly leaves a running server's in-memory state (sockets, replay buffers, timers)
+pointing at rows that no longer exist, so stop the server first or pass --server. The server only exposes
+DELETE /api/reset-db in TESTING mode. Tables recreated directly are stamped with the latest schema version.
+"""
+
+import argparse
+import sys
+from typing import Optional
+
+RESET_PATH = "/api/reset-db"
+REQUEST_TIMEOUT_SECONDS = 10
+
+
+class ResetError(Exception):
+    pass
+
+
+def reset_local() -> str:
+    # Imported here so --help works without a configured .env
+    from backend.database import drop_all_tables
+    from backend.database.migrations import run_migrations
+    from backend.settings import settings
+
+    try:
+        drop_all_tables()
+        run_migrations()
+    except Exception as e:
+        raise ResetError(f"Couldn't reset {settings.DATABASE_URL}: {e}") from e
+    return settings.DATABASE_URL
+
+
+def reset_server(server_url: str) -> str:
+    import requests
+
+    url = server_url.rstrip("/") + RESET_PATH
+    try:
+        response = requests.delete(url, timeout=REQUEST_TIMEOUT_SECONDS)
+    except requests.RequestException as e:
+        raise ResetError(f"Couldn't reach {url}: {e}") from e
+    if response.status_code in (404, 405):
+        raise ResetError(f"{url} isn't available, the server has to be running with TESTING=true")
+    if not response.ok:
+        raise ResetError(f"{url} failed with {response.status_code}: {response.text[:200]}")
+    return url
+
+
+def main(argv: Optional[list[str]] = None) -> int:
+    parser = argparse.ArgumentParser(description="Drop and recreate every Raddle Teams database table")
+    parser.add_argument("--yes", action="store_true", help="Confirm that all lobbies, teams and games get deleted")
+    parser.add_argument("--server", help="Reset through a running TESTING server, e.g. http://localhost:8000")
+    args = parser.parse_args(argv)
+
+    if not args.yes:
+        print("Refusing to reset without --yes, this deletes every lobby, team and game", file=sys.stderr)
+        return 2
+
+    try:
+        target = reset_server(args.server) if args.server else reset_local()
+    except ResetError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        return 1
+    print(f"Reset database via {target}")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -1,80 +1,160 @@
-from fastapi import Depends, HTTPException, Query, status
+import hmac
+
+from fastapi import Depends, Request
 from fastapi.security import HTTPAuthorizationCredentials, HTTPBearer
 from sqlmodel import select
 
+from backend.admin_sessions import AdminSession, admin_session_store
+from backend.csrf import SESSION_COOKIE
 from backend.custom_logging import api_logger
 from backend.database import Session, get_session
-from backend.database.models import Player
+from backend.database.models import Lobby, Player
+from backend.errors import ForbiddenError, UnauthorizedError
+from backend.offline_guesses import player_sightings
+from backend.organizations import check_organization_scope
 from backend.settings import settings
 
 security = HTTPBearer()
 
 
+async def admin_credentials(request: Request) -> HTTPAuthorizationCredentials | None:
+    """The bearer token or, with ADMIN_COOKIE_AUTH, the session cookie set at login (see backend/csrf.py)."""
+    if settings.ADMIN_COOKIE_AUTH and "authorization" not in request.headers:
+        token = request.cookies.get(SESSION_COOKIE)
+        if token:
+            return HTTPAuthorizationCredentials(scheme="Cookie", credentials=token)
+    return await security(request)
+
+
+def matches(password: str, expected: str) -> bool:
+    return hmac.compare_digest(password.encode("utf-8"), expected.encode("utf-8"))
+
+
+def is_admin_password(password: str) -> bool:
+    """Owners can log in with their password anywhere a regular admin password is accepted."""
+    return matches(password, settings.ADMIN_PASSWORD) or (
+        settings.ADMIN_OWNER_PASSWORD is not None and matches(password, settings.ADMIN_OWNER_PASSWORD)
+    )
+
+
+def is_owner_password(password: str) -> bool:
+    return matches(password, settings.ADMIN_OWNER_PASSWORD or settings.ADMIN_PASSWORD)
+
+
 def check_admin_token(
-    credentials: HTTPAuthorizationCredentials = Depends(security),
+    request: Request,
+    credentials: HTTPAuthorizationCredentials = Depends(admin_credentials),
+    db: Session = Depends(get_session),
 ) -> bool:
+    """
+    Accepts a session token from POST /login, the only route that takes the password itself. A revoked session's
+    token is refused with 401 until it would have expired anyway.
+
+    Organization admins' requests are also checked against the lobby, team, player, guess or puzzle in the path.
+    """
     if not credentials or not credentials.credentials:
         api_logger.warning("Missing admin auth token in Authorization header")
-        raise HTTPException(
-            status_code=status.HTTP_401_UNAUTHORIZED,
-            detail="Missing authentication token",
-            headers={"WWW-Authenticate": "Bearer"},
-        )
-
-    if credentials.credentials != settings.ADMIN_PASSWORD:
-        api_logger.warning("Invalid admin credentials provided via Authorization header")
-        raise HTTPException(
-            status_code=status.HTTP_401_UNAUTHORIZED,
-            detail="Invalid admin credentials",
-            headers={"WWW-Authenticate": "Bearer"},
-        )
-
-    api_logger.info("Admin authenticated via Authorization header")
-    return True
-
-
-def check_admin_token_query(
-    token: str = Query(..., description="Admin authentication token"),
+        raise UnauthorizedError("Missing authentication token")
+
+    session = admin_session_store.authenticate(credentials.credentials)
+    if session:
+        if session.organization_id is not None:
+            check_organization_scope(db, session.organization_id, request.path_params, request.query_params)
+        api_logger.info(f"Admin authenticated via session token: organization_id={session.organization_id}")
+        return True
+    if admin_session_store.is_revoked(credentials.credentials):
+        api_logger.warning("Revoked admin session token used via Authorization header")
+        raise UnauthorizedError("Admin session revoked")
+
+    api_logger.warning("Invalid or expired admin credentials provided via Authorization header")
+    raise UnauthorizedError("Invalid admin credentials")
+
+
+def check_owner_token(
+    credentials: HTTPAuthorizationCredentials = Depends(admin_credentials),
 ) -> bool:
-    if not token:
-        api_logger.warning("Missing admin token in query parameter")
-        raise HTTPException(
-            status_code=status.HTTP_401_UNAUTHORIZED,
-            detail="Missing authentication token",
-        )
+    if not credentials or not credentials.credentials:
+        api_logger.warning("Missing owner auth token in Authorization header")
+        raise UnauthorizedError("Missing authentication token")
+
+    session = admin_session_store.authenticate(credentials.credentials)
+    if session:
+        if not session.is_owner:
+            api_logger.warning(f"Non-owner session used for an owner-only endpoint: session_id={session.session_id}")
+            raise ForbiddenError("Owner credentials required")
+        api_logger.info(f"Admin owner authenticated via session token: session_id={session.session_id}")
+        return True
+    if admin_session_store.is_revoked(credentials.credentials):
+        api_logger.warning("Revoked admin session token used for an owner-only endpoint")
+        raise UnauthorizedError("Admin session revoked")
 
-    if token != settings.ADMIN_PASSWORD:
-        api_logger.warning("Invalid admin credentials provided via query parameter")
-        raise HTTPException(
-            status_code=status.HTTP_401_UNAUTHORIZED,
-            detail="Invalid admin credentials",
-        )
+    api_logger.warning("Invalid or expired admin credentials used for an owner-only endpoint")
+    raise UnauthorizedError("Invalid admin credentials")
 
-    api_logger.info("Admin authenticated via query parameter")
-    return True
 
+def current_admin_session(
+    credentials: HTTPAuthorizationCredentials = Depends(admin_credentials),
+) -> AdminSession | None:
+    """The session behind the bearer token, or None without one; always set behind check_admin_token."""
+    return admin_session_store.authenticate(credentials.credentials) if credentials else None
 
-def require_player_session(
+
+def admin_organization_id(session: AdminSession | None = Depends(current_admin_session)) -> int | None:
+    """The organization an admin request is scoped to; None for deployment admins, who see everything."""
+    return session.organization_id if session else None
+
+
+def check_deployment_admin(
+    is_admin: bool = Depends(check_admin_token),
+    organization_id: int | None = Depends(admin_organization_id),
+) -> bool:
+    """For deployment-wide pages (traffic, health, retention...) that organization admins don't get to see."""
+    if organization_id is not None:
+        api_logger.warning(f"Organization admin denied a deployment-wide endpoint: organization_id={organization_id}")
+        raise ForbiddenError("Only deployment admins can use this endpoint")
+    return is_admin
+
+
+def identify_player_session(
     credentials: HTTPAuthorizationCredentials = Depends(security),
     db: Session = Depends(get_session),
 ) -> Player:
+    """The authenticated player, without counting the request as activity from them."""
     if not credentials or not credentials.credentials:
         api_logger.warning("Missing player session token in Authorization header")
-        raise HTTPException(
-            status_code=status.HTTP_401_UNAUTHORIZED,
-            detail="Missing authentication token",
-            headers={"WWW-Authenticate": "Bearer"},
-        )
+        raise UnauthorizedError("Missing authentication token")
 
     player = db.exec(select(Player).where(Player.session_id == credentials.credentials)).first()
 
     if not player:
         api_logger.warning("Invalid player session token provided in Authorization header")
-        raise HTTPException(
-            status_code=status.HTTP_401_UNAUTHORIZED,
-            detail="Invalid authentication token",
-            headers={"WWW-Authenticate": "Bearer"},
-        )
+        raise UnauthorizedError("Invalid authentication token")
 
     api_logger.debug(f"Player session authenticated: player_id={player.id}")
     return player
+
+
+def require_player_session(player: Player = Depends(identify_player_session)) -> Player:
+    """The authenticated player; the request marks them as online (see backend/offline_guesses.py)."""
+    player_sightings.seen(player.session_id)
+    return player
+
+
+def require_lobby_member(lobby_id: int, player: Player = Depends(require_player_session)) -> Player:
+    """The authenticated player, if they're in the lobby named by the route's lobby_id."""
+    if player.lobby_id != lobby_id:
+        api_logger.warning(f"Player asked for another lobby: player_id={player.id} lobby_id={lobby_id}")
+        raise ForbiddenError("You are not in this lobby")
+    return player
+
+
+def require_lobby_host(
+    player: Player = Depends(require_player_session),
+    db: Session = Depends(get_session),
+) -> Player:
+    """The authenticated player, if they host their lobby (see backend/lobby_host.py)."""
+    lobby = db.get(Lobby, player.lobby_id)
+    if not lobby or lobby.host_player_id != player.id:
+        api_logger.warning(f"Non-host player tried a host action: player_id={player.id} lobby_id={player.lobby_id}")
+        raise ForbiddenError("Only the lobby host can do that")
+    return player
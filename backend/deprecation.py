@@ -0,0 +1,73 @@
+"""Deprecation headers and usage counts for API routes that are scheduled for removal."""
+
+from dataclasses import dataclass
+from datetime import date, datetime, time, timezone
+from email.utils import format_datetime
+from fnmatch import fnmatchcase
+from typing import Dict, List, Optional
+
+from pydantic import BaseModel
+
+from backend.in_memory_state import on_reset
+
+
+class DeprecatedRoute(BaseModel):
+    """A configured deprecated route, matched by fnmatch-style path pattern and optional method."""
+
+    pattern: str  # e.g. "/api/lobby/*/team"
+    method: Optional[str] = None  # None matches every method
+    sunset: Optional[date] = None  # Day the route is expected to be removed
+    replacement: Optional[str] = None  # Successor route, advertised in a Link header
+
+    @property
+    def key(self) -> str:
+        return f"{self.method.upper()} {self.pattern}" if self.method else self.pattern
+
+    def matches(self, method: str, path: str) -> bool:
+        if self.method and self.method.upper() != method.upper():
+            return False
+        return fnmatchcase(path, self.pattern)
+
+
+@dataclass
+class DeprecatedRouteUsage:
+    calls: int = 0
+    last_called_at: Optional[datetime] = None
+    last_path: Optional[str] = None
+    last_user_agent: Optional[str] = None
+    last_referer: Optional[str] = None
+
+
+def find_deprecated_route(routes: List[DeprecatedRoute], method: str, path: str) -> Optional[DeprecatedRoute]:
+    return next((route for route in routes if route.matches(method, path)), None)
+
+
+def deprecation_headers(route: DeprecatedRoute) -> Dict[str, str]:
+    """
+    Build response headers for a deprecated route.
+
+    Sunset is an HTTP-date (RFC 8594); the successor route goes in a Link header.
+    """
+    headers = {"Deprecation": "true"}
+    if route.sunset:
+        headers["Sunset"] = format_datetime(datetime.combine(route.sunset, time.min, tzinfo=timezone.utc), usegmt=True)
+    if route.replacement:
+        headers["Link"] = f'<{route.replacement}>; rel="successor-version"'
+    return headers
+
+
+class DeprecationTracker:
+    def __init__(self):
+        self.usage: Dict[str, DeprecatedRouteUsage] = {}
+
+    def record(self, route: DeprecatedRoute, path: str, user_agent: Optional[str], referer: Optional[str]):
+        usage = self.usage.setdefault(route.key, DeprecatedRouteUsage())
+        usage.calls += 1
+        usage.last_called_at = datetime.now(timezone.utc)
+        usage.last_path = path
+        usage.last_user_agent = user_agent
+        usage.last_referer = referer
+
+
+deprecation_tracker = DeprecationTracker()
+on_reset(deprecation_tracker.__init__)
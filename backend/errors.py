@@ -0,0 +1,127 @@
+"""
+Errors API routes raise instead of building HTTPExceptions by hand.
+
+app_error_handler renders every AppError the same way, keeping the {"detail": ...} body clients already read: the
+plain message, or for errors with a machine-readable code, {"code", "message", "hint"?, ...extra} (e.g.
+GAME_IN_PROGRESS carries the lobby's state so clients can resync). Codes are listed in ErrorCode, and the ones players
+run into have a hint in ERROR_HINTS saying what to try next, so clients can show it without their own copy of the
+text. Database failures that escape a route are answered as a DatabaseError rather than a bare 500 page.
+"""
+
+from enum import Enum
+from typing import Any, Dict, Optional
+
+from fastapi import Request
+from fastapi.responses import JSONResponse
+from sqlalchemy.exc import SQLAlchemyError
+
+from backend.custom_logging import api_logger
+
+
+class ErrorCode(str, Enum):
+    GAME_IN_PROGRESS = "GAME_IN_PROGRESS"
+    LOBBY_LOCKED = "LOBBY_LOCKED"
+    LOBBY_FULL = "LOBBY_FULL"
+    NAME_TAKEN = "NAME_TAKEN"
+    NAME_NOT_ALLOWED = "NAME_NOT_ALLOWED"
+    PUZZLE_IN_USE = "PUZZLE_IN_USE"
+    NO_REVEAL = "NO_REVEAL"
+    SHUTTING_DOWN = "SHUTTING_DOWN"
+    CSRF_FAILED = "CSRF_FAILED"
+    RATE_LIMITED = "RATE_LIMITED"
+
+
+# Guidance for the failures players hit, sent as "hint" next to the message
+ERROR_HINTS: Dict[ErrorCode, str] = {
+    ErrorCode.NAME_TAKEN: 'Try adding your last initial, e.g. "Sam K".',
+    ErrorCode.NAME_NOT_ALLOWED: "Pick another name; this one will be shown on the big screen.",
+    ErrorCode.LOBBY_FULL: "Ask the host to raise the player cap.",
+    ErrorCode.LOBBY_LOCKED: "Ask the host to unlock the lobby.",
+    ErrorCode.GAME_IN_PROGRESS: "Wait for the next round, or ask the host to turn on late joining.",
+    ErrorCode.SHUTTING_DOWN: "The server is restarting. Try again in a minute.",
+    ErrorCode.RATE_LIMITED: "Slow down a little and try again in a few seconds.",
+}
+
+
+class AppError(Exception):
+    status_code = 500
+
+    def __init__(
+        self,
+        message: str,
+        code: Optional[ErrorCode] = None,
+        headers: Optional[Dict[str, str]] = None,
+        **extra: Any,
+    ):
+        super().__init__(message)
+        self.message = message
+        self.code = ErrorCode(code) if code is not None else None
+        self.headers = headers
+        self.extra = extra
+
+    @property
+    def detail(self) -> str | Dict[str, Any]:
+        if self.code is None:
+            return self.message
+        detail: Dict[str, Any] = {"code": self.code.value, "message": self.message}
+        if self.code in ERROR_HINTS:
+            detail["hint"] = ERROR_HINTS[self.code]
+        return {**detail, **self.extra}
+
+
+class NotFoundError(AppError):
+    status_code = 404
+
+
+class InvalidRequestError(AppError):
+    """The request was understood but its values can't be accepted."""
+
+    status_code = 400
+
+
+class UnauthorizedError(AppError):
+    status_code = 401
+
+    def __init__(self, message: str, **kwargs: Any):
+        kwargs.setdefault("headers", {"WWW-Authenticate": "Bearer"})
+        super().__init__(message, **kwargs)
+
+
+class ForbiddenError(AppError):
+    status_code = 403
+
+
+class ConflictError(AppError):
+    """The request clashes with the lobby's or puzzle's current state, e.g. a game in progress."""
+
+    status_code = 409
+
+
+class DatabaseError(AppError):
+    status_code = 500
+
+
+class ServiceUnavailableError(AppError):
+    """A non-critical feature switched off, e.g. while the node is degraded."""
+
+    status_code = 503
+
+
+class TooManyRequestsError(AppError):
+    """The client went past a rate limit; Retry-After says how many seconds until it may try again."""
+
+    status_code = 429
+
+    def __init__(self, message: str, retry_after: int, **kwargs: Any):
+        kwargs.setdefault("code", ErrorCode.RATE_LIMITED)
+        kwargs.setdefault("headers", {"Retry-After": str(retry_after)})
+        super().__init__(message, retry_after=retry_after, **kwargs)
+
+
+async def app_error_handler(request: Request, exc: AppError) -> JSONResponse:
+    return JSONResponse(status_code=exc.status_code, content={"detail": exc.detail}, headers=exc.headers)
+
+
+async def database_error_handler(request: Request, exc: SQLAlchemyError) -> JSONResponse:
+    api_logger.exception(f"Database error handling {request.method} {request.url.path}: {exc}")
+    return await app_error_handler(request, DatabaseError("Database error"))
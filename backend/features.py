@@ -0,0 +1,42 @@
+"""
+Feature flags for the non-critical features a node can shed.
+
+A feature is off when it's listed in DISABLED_FEATURES, or while the node is degraded (see backend/health.py) and
+it's listed in DEGRADABLE_FEATURES. What's needed to play - joining, guessing, scoring - isn't a feature here and
+is never switched off.
+"""
+
+from enum import Enum
+from typing import List
+
+from backend.in_memory_state import on_reset
+from backend.settings import settings
+
+
+class Feature(str, Enum):
+    TEAM_CHAT = "team_chat"
+    PROGRESS_BROADCASTS = "progress_broadcasts"  # Other teams' progress pushed to the lobby after each solve
+    PUZZLE_ANALYTICS = "puzzle_analytics"  # Admin per-rung analytics, which scan the guess log
+
+
+class FeatureFlags:
+    def __init__(self):
+        self.degraded = False
+
+    def set_degraded(self, degraded: bool) -> bool:
+        """Returns whether this changed the degraded state."""
+        changed = degraded != self.degraded
+        self.degraded = degraded
+        return changed
+
+    def is_enabled(self, feature: Feature) -> bool:
+        if feature.value in settings.DISABLED_FEATURES:
+            return False
+        return not (self.degraded and feature.value in settings.DEGRADABLE_FEATURES)
+
+    def disabled(self) -> List[Feature]:
+        return [feature for feature in Feature if not self.is_enabled(feature)]
+
+
+feature_flags = FeatureFlags()
+on_reset(feature_flags.__init__)
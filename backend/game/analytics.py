@@ -0,0 +1,132 @@
+"""Aggregation of guess logs into per-rung puzzle analytics."""
+
+from collections import Counter, defaultdict
+from dataclasses import dataclass, field
+from datetime import datetime
+from statistics import mean, median
+from typing import Dict, Iterable, List, Optional, Tuple
+
+
+@dataclass(frozen=True)
+class GuessRecord:
+    """The parts of a logged guess the analytics need."""
+
+    play_key: Tuple[int, int]  # (game_id, team_id) identifies one team's attempt at the puzzle
+    word_index: int
+    is_correct: bool
+    created_at: datetime
+    guess: str = ""  # The guessed word, as typed
+
+
+@dataclass
+class RungStats:
+    word_index: int
+    teams_attempted: int = 0
+    teams_solved: int = 0
+    wrong_guesses: int = 0
+    solve_seconds: List[float] = field(default_factory=list)
+    wrong_answers: Counter = field(default_factory=Counter)  # Normalized wrong guess -> times guessed
+
+    @property
+    def solve_rate(self) -> float:
+        return self.teams_solved / self.teams_attempted if self.teams_attempted else 0.0
+
+    @property
+    def avg_solve_seconds(self) -> Optional[float]:
+        return mean(self.solve_seconds) if self.solve_seconds else None
+
+    @property
+    def median_solve_seconds(self) -> Optional[float]:
+        return median(self.solve_seconds) if self.solve_seconds else None
+
+    def common_wrong_answers(self, limit: int) -> List[Tuple[str, int]]:
+        """
+        The most guessed wrong answers, most frequent first (ties alphabetically).
+
+        A wrong answer many teams reach for is often a sign the clue allows it too.
+        """
+        ranked = sorted(self.wrong_answers.items(), key=lambda item: (-item[1], item[0]))
+        return ranked[:limit]
+
+
+def normalize_answer(guess: str) -> str:
+    """Guesses are checked case-insensitively, so "Bread" and "bread " count as the same answer."""
+    return guess.strip().upper()
+
+
+def solve_times_for_play(started_at: datetime, guesses: Iterable[GuessRecord]) -> Dict[int, float]:
+    """
+    Time spent on each rung by one team, in solve order.
+
+    A rung's solve time is measured from the team's previous solve (or the game start)
+    to the first correct guess for that rung.
+    """
+    solve_times: Dict[int, float] = {}
+    last_solve_at = started_at
+    for guess in sorted(guesses, key=lambda g: g.created_at):
+        if not guess.is_correct or guess.word_index in solve_times:
+            continue
+        solve_times[guess.word_index] = max(0.0, (guess.created_at - last_solve_at).total_seconds())
+        last_solve_at = guess.created_at
+    return solve_times
+
+
+def aggregate_rung_stats(
+    ladder_length: int,
+    play_starts: Dict[Tuple[int, int], datetime],
+    guesses: Iterable[GuessRecord],
+) -> Tuple[List[RungStats], Dict[Tuple[int, int], Dict[int, float]]]:
+    """
+    Aggregate guesses from every team that played a puzzle into per-rung statistics.
+
+    The first and last rungs are revealed from the start, so only the hidden rungs are reported.
+
+    Args:
+        ladder_length: Number of steps in the puzzle ladder
+        play_starts: Start time for each (game_id, team_id) that played the puzzle
+        guesses: Logged guesses for those plays
+
+    Returns:
+        Tuple of (per-rung stats ordered by word index, per-play solve times keyed by play)
+    """
+    guesses_by_play: Dict[Tuple[int, int], List[GuessRecord]] = defaultdict(list)
+    for guess in guesses:
+        guesses_by_play[guess.play_key].append(guess)
+
+    hidden_rungs = range(1, ladder_length - 1)
+    stats = {index: RungStats(word_index=index) for index in hidden_rungs}
+    play_solve_times: Dict[Tuple[int, int], Dict[int, float]] = {}
+
+    for play_key, started_at in play_starts.items():
+        play_guesses = guesses_by_play.get(play_key, [])
+        solve_times = solve_times_for_play(started_at, play_guesses)
+        play_solve_times[play_key] = solve_times
+
+        for index in hidden_rungs:
+            rung = stats[index]
+            rung.teams_attempted += 1
+            if index in solve_times:
+                rung.teams_solved += 1
+                rung.solve_seconds.append(solve_times[index])
+
+        for guess in play_guesses:
+            if not guess.is_correct and guess.word_index in stats:
+                stats[guess.word_index].wrong_guesses += 1
+                if guess.guess.strip():
+                    stats[guess.word_index].wrong_answers[normalize_answer(guess.guess)] += 1
+
+    return [stats[index] for index in hidden_rungs], play_solve_times
+
+
+def difficulty_curve(rungs: List[RungStats]) -> List[float]:
+    """
+    Relative difficulty per rung: median solve time divided by the puzzle-wide median.
+
+    1.0 is an average rung; rungs nobody solved are reported as 0.0.
+    """
+    medians = [rung.median_solve_seconds for rung in rungs]
+    known = [value for value in medians if value is not None]
+    if not known:
+        return [0.0 for _ in rungs]
+    baseline = median(known) or 1.0
+    return [round(value / baseline, 2) if value is not None else 0.0 for value in medians]
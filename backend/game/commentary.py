@@ -0,0 +1,37 @@
+"""
+Plain-text commentary lines narrating a game, for spectators, screen readers and stream chat overlays.
+
+Each function turns one game moment into a sentence; backend/commentary.py keeps the recent lines and sends them.
+"""
+
+
+def ordinal(number: int) -> str:
+    if 10 <= number % 100 <= 20:
+        suffix = "th"
+    else:
+        suffix = {1: "st", 2: "nd", 3: "rd"}.get(number % 10, "th")
+    return f"{number}{suffix}"
+
+
+def plural(count: int, noun: str, nouns: str | None = None) -> str:
+    return f"{count} {noun}" if count == 1 else f"{count} {nouns or noun + 's'}"
+
+
+def round_started(round_number: int, team_count: int) -> str:
+    return f"Round {round_number} is under way with {plural(team_count, 'team')} racing!"
+
+
+def rung_solved(team_name: str, rung: int, guesses: int) -> str:
+    return f"{team_name} solved rung {rung} after {plural(guesses, 'guess', 'guesses')}!"
+
+
+def ladder_completed(team_name: str, placement: int) -> str:
+    if placement == 1:
+        return f"{team_name} finished the ladder first!"
+    return f"{team_name} finished the ladder in {ordinal(placement)} place."
+
+
+def round_won(round_number: int, team_name: str | None) -> str:
+    if team_name is None:
+        return f"Round {round_number} is over."
+    return f"{team_name} wins round {round_number}!"
@@ -0,0 +1,50 @@
+"""Lobby-level game phase: waiting -> in_progress -> finished, and back to in_progress for the next round.
+
+The phase isn't stored; it's derived from the lobby's games so it can't drift from the puzzles teams are
+actually playing. Starting and ending a round check the transition here and broadcast LOBBY_PHASE_CHANGED
+so every player leaves the lobby screen (or returns to it) together.
+
+Lobbies with intermission turned on go to intermission instead of finished when a round ends, and stay there,
+showing the standings so far, until the admin advances (or the auto-advance timer does); see backend/intermission.py.
+The lobby's intermission_started_at marks it, since nothing in the games tells the two apart.
+"""
+
+from enum import Enum
+
+
+class LobbyPhase(str, Enum):
+    WAITING = "waiting"  # No round played yet
+    IN_PROGRESS = "in_progress"  # At least one team is still solving its puzzle
+    FINISHED = "finished"  # The last round is over; the host can start another
+    INTERMISSION = "intermission"  # Between rounds, waiting for the admin (or the timer) to start the next one
+
+
+ALLOWED_TRANSITIONS = {
+    LobbyPhase.WAITING: {LobbyPhase.IN_PROGRESS},
+    LobbyPhase.IN_PROGRESS: {LobbyPhase.FINISHED, LobbyPhase.INTERMISSION},
+    LobbyPhase.INTERMISSION: {LobbyPhase.IN_PROGRESS, LobbyPhase.FINISHED},
+    LobbyPhase.FINISHED: {LobbyPhase.IN_PROGRESS},
+}
+
+
+class InvalidPhaseTransition(ValueError):
+    def __init__(self, current: LobbyPhase, target: LobbyPhase):
+        self.current = current
+        self.target = target
+        super().__init__(f"Cannot move lobby from {current.value} to {target.value}")
+
+
+def derive_phase(has_active_game: bool, has_finished_game: bool, in_intermission: bool = False) -> LobbyPhase:
+    if has_active_game:
+        return LobbyPhase.IN_PROGRESS
+    if in_intermission and has_finished_game:
+        return LobbyPhase.INTERMISSION
+    if has_finished_game:
+        return LobbyPhase.FINISHED
+    return LobbyPhase.WAITING
+
+
+def check_transition(current: LobbyPhase, target: LobbyPhase):
+    """Raise InvalidPhaseTransition unless `current` may move to `target`."""
+    if target not in ALLOWED_TRANSITIONS[current]:
+        raise InvalidPhaseTransition(current, target)
@@ -0,0 +1,64 @@
+"""
+"Pace keeper" stall detection: spot the slowest team stuck on a rung and decide when it's owed a hint.
+
+A team is stalled once it has gone `multiplier` x the lobby's median solve time without solving anything.
+Hints escalate while the stall lasts: hint N is due after N x that threshold, up to a maximum level, and
+solving the rung resets the clock. Only the slowest team (fewest revealed steps, then longest stalled)
+is considered, so faster teams are never helped past the ones behind them.
+"""
+
+from dataclasses import dataclass
+from datetime import datetime
+from statistics import median
+from typing import Iterable, List, Optional, Sequence
+
+
+@dataclass
+class TeamPace:
+    team_id: int
+    revealed_count: int
+    stalled_since: datetime  # Last correct guess, or when the round started
+    hints_since_solve: int  # Pace hints granted since stalled_since
+
+
+def solve_durations(started_at: datetime, solved_at: Sequence[datetime]) -> List[float]:
+    """Seconds each solve took for one team, from the round start and then from the previous solve."""
+    times = [started_at, *sorted(solved_at)]
+    return [(later - earlier).total_seconds() for earlier, later in zip(times, times[1:])]
+
+
+def median_solve_seconds(durations: Iterable[float], min_solves: int) -> Optional[float]:
+    """The lobby-wide median solve time, or None until there are enough solves to trust it."""
+    durations = list(durations)
+    if len(durations) < max(min_solves, 1):
+        return None
+    return median(durations)
+
+
+def slowest_team(teams: Iterable[TeamPace]) -> Optional[TeamPace]:
+    return min(teams, key=lambda team: (team.revealed_count, team.stalled_since), default=None)
+
+
+def due_hint_level(
+    team: TeamPace, median_seconds: float, now: datetime, multiplier: float, max_level: int
+) -> Optional[int]:
+    """The hint level the team should get now, or None if it isn't stalled long enough for the next one."""
+    level = team.hints_since_solve + 1
+    if level > max_level:
+        return None
+    stalled_for = (now - team.stalled_since).total_seconds()
+    if stalled_for < multiplier * median_seconds * level:
+        return None
+    return level
+
+
+def next_rung(revealed_steps: Iterable[int], ladder_length: int) -> Optional[int]:
+    """The rung a stalled team gets help with: the first unrevealed one from the top."""
+    revealed = set(revealed_steps)
+    return next((index for index in range(ladder_length) if index not in revealed), None)
+
+
+def hint_text(word: str, level: int) -> str:
+    """Reveal the first `level` letters, never the whole word: hint_text("FLAKE", 2) == "FL___"."""
+    shown = min(level, len(word) - 1)
+    return word[:shown] + "_" * (len(word) - shown)
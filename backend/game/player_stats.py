@@ -0,0 +1,83 @@
+"""
+Player statistics across games, for the admin dashboard.
+
+Players don't have accounts, so like rivalries (backend/game/rivalries.py) a player is identified by their name,
+case-insensitive: the same name joining next week's lobby adds to the same row. A player has played a game when
+they guessed in it or their team got a result for it, and their solve time for a game is their team's.
+"""
+
+from dataclasses import dataclass, field
+from datetime import datetime
+from typing import Dict, Iterable, List, Optional, Set
+
+
+@dataclass(frozen=True)
+class PlayerGuess:
+    name: str
+    game_id: int
+    is_correct: bool
+    created_at: datetime
+
+
+@dataclass(frozen=True)
+class PlayerResult:
+    """A result of the player's team, for one game."""
+
+    name: str
+    game_id: int
+    time_to_complete: Optional[int]  # Seconds, None when the team didn't finish
+    created_at: datetime
+
+
+@dataclass
+class PlayerStats:
+    name: str  # The most recently used spelling
+    games: Set[int] = field(default_factory=set)
+    guesses: int = 0
+    correct_guesses: int = 0
+    solve_times: Dict[int, int] = field(default_factory=dict)  # game_id -> seconds, for games the team finished
+    last_seen_at: Optional[datetime] = None
+
+    @property
+    def games_played(self) -> int:
+        return len(self.games)
+
+    @property
+    def avg_solve_seconds(self) -> Optional[float]:
+        if not self.solve_times:
+            return None
+        return sum(self.solve_times.values()) / len(self.solve_times)
+
+    def seen(self, name: str, at: datetime):
+        if self.last_seen_at is None or at >= self.last_seen_at:
+            self.name = name
+            self.last_seen_at = at
+
+
+def player_key(name: str) -> str:
+    return name.strip().casefold()
+
+
+def aggregate_player_stats(guesses: Iterable[PlayerGuess], results: Iterable[PlayerResult]) -> List[PlayerStats]:
+    """Stats per player, most correct guesses first, then by name."""
+    stats: Dict[str, PlayerStats] = {}
+
+    def player(name: str, at: datetime) -> PlayerStats:
+        entry = stats.setdefault(player_key(name), PlayerStats(name=name.strip()))
+        entry.seen(name.strip(), at)
+        return entry
+
+    for guess in guesses:
+        entry = player(guess.name, guess.created_at)
+        entry.games.add(guess.game_id)
+        entry.guesses += 1
+        if guess.is_correct:
+            entry.correct_guesses += 1
+
+    for result in results:
+        entry = player(result.name, result.created_at)
+        entry.games.add(result.game_id)
+        if result.time_to_complete is not None:
+            entry.solve_times[result.game_id] = result.time_to_complete
+
+    return sorted(stats.values(), key=lambda entry: (-entry.correct_guesses, entry.name.casefold()))
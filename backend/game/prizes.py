@@ -0,0 +1,146 @@
+"""
+Contest mode: side awards worked out when a round is scored, so hosts don't tally them by hand from the export.
+
+A lobby opts in by setting prize tiers (PUT /api/admin/lobby/{lobby_id}/prizes). Awards cover every round played in
+the lobby so far: the top N teams on tournament points, the fastest single rung (time since the team's previous
+solve, or since the round started) and the longest run of correct guesses without a wrong one.
+"""
+
+import json
+from dataclasses import dataclass
+from datetime import datetime
+from typing import Dict, List, Optional
+
+from pydantic import BaseModel
+from sqlmodel import Session, select
+
+from backend.database.models import Game, Guess, Lobby, Player, Team
+from backend.websocket.events import PrizeAward
+
+MAX_TOP_TEAMS = 10
+
+
+class PrizeTiers(BaseModel):
+    top_teams: int = 0  # How many places get a prize; 0 for none
+    fastest_rung: bool = False
+    best_streak: bool = False
+
+    @property
+    def enabled(self) -> bool:
+        return self.top_teams > 0 or self.fastest_rung or self.best_streak
+
+
+@dataclass
+class PrizeGuess:
+    team_id: int
+    player_id: int
+    game_id: int
+    word_index: int
+    is_correct: bool
+    created_at: datetime
+
+
+@dataclass(frozen=True)
+class RungSolve:
+    team_id: int
+    player_id: int
+    seconds: float
+    solved_at: datetime
+
+
+def load_prize_tiers(lobby: Lobby) -> Optional[PrizeTiers]:
+    """The lobby's prize tiers, or None when contest mode is off."""
+    if not lobby.prize_tiers:
+        return None
+    return PrizeTiers.model_validate(json.loads(lobby.prize_tiers))
+
+
+def fastest_rung(guesses: List[PrizeGuess], started_at: Dict[int, datetime]) -> Optional[RungSolve]:
+    """
+    The quickest rung solved, timed from the same team's previous solve in that game (or the game's start).
+
+    Only a rung's first correct guess counts; ties go to whoever got there first.
+    """
+    fastest: Optional[RungSolve] = None
+    previous: Dict[tuple[int, int], datetime] = {}
+    solved: set[tuple[int, int, int]] = set()
+    for guess in sorted(guesses, key=lambda g: g.created_at):
+        rung = (guess.team_id, guess.game_id, guess.word_index)
+        if not guess.is_correct or rung in solved or guess.game_id not in started_at:
+            continue
+        solved.add(rung)
+        since = previous.get((guess.team_id, guess.game_id), started_at[guess.game_id])
+        previous[(guess.team_id, guess.game_id)] = guess.created_at
+        seconds = max((guess.created_at - since).total_seconds(), 0.0)
+        if fastest is None or seconds < fastest.seconds:
+            fastest = RungSolve(guess.team_id, guess.player_id, seconds, guess.created_at)
+    return fastest
+
+
+def best_streaks(guesses: List[PrizeGuess]) -> Dict[int, tuple[int, datetime]]:
+    """Each team's longest run of correct guesses in a row, and when it first reached that length."""
+    current: Dict[int, int] = {}
+    best: Dict[int, tuple[int, datetime]] = {}
+    for guess in sorted(guesses, key=lambda g: g.created_at):
+        current[guess.team_id] = current.get(guess.team_id, 0) + 1 if guess.is_correct else 0
+        length = current[guess.team_id]
+        if length and length > best.get(guess.team_id, (0, guess.created_at))[0]:
+            best[guess.team_id] = (length, guess.created_at)
+    return best
+
+
+def contest_prizes(db: Session, lobby_id: int) -> List[PrizeAward]:
+    """The awards for a lobby in contest mode, in tier order; [] when its prize tiers aren't set."""
+    lobby = db.get(Lobby, lobby_id)
+    tiers = load_prize_tiers(lobby) if lobby else None
+    if not tiers or not tiers.enabled:
+        return []
+
+    teams = {team.id: team for team in db.exec(select(Team).where(Team.lobby_id == lobby_id)).all()}
+    awards: List[PrizeAward] = []
+
+    ranked = sorted(
+        (team for team in teams.values() if team.rounds_played > 0),
+        key=lambda team: (-team.total_points, -team.rounds_won, team.id),
+    )
+    for place, team in enumerate(ranked[: tiers.top_teams], start=1):
+        awards.append(
+            PrizeAward(prize="top_team", place=place, team_id=team.id, team_name=team.name, value=team.total_points)
+        )
+
+    if not (tiers.fastest_rung or tiers.best_streak) or not teams:
+        return awards
+
+    guesses = [
+        PrizeGuess(g.team_id, g.player_id, g.game_id, g.word_index, g.is_correct, g.created_at)
+        for g in db.exec(select(Guess).where(Guess.team_id.in_(list(teams)))).all()
+        if g.review_status != "pending"  # Open-ended answers still waiting on a host don't count either way
+    ]
+
+    if tiers.fastest_rung:
+        game_ids = {guess.game_id for guess in guesses}
+        started_at = {
+            game.id: game.started_at for game in db.exec(select(Game).where(Game.id.in_(list(game_ids)))).all()
+        }
+        solve = fastest_rung(guesses, started_at)
+        if solve:
+            player = db.get(Player, solve.player_id)
+            awards.append(
+                PrizeAward(
+                    prize="fastest_rung",
+                    team_id=solve.team_id,
+                    team_name=teams[solve.team_id].name,
+                    player_name=player.name if player else None,
+                    value=round(solve.seconds, 1),
+                )
+            )
+
+    if tiers.best_streak:
+        streaks = best_streaks(guesses)
+        if streaks:
+            team_id, (length, _) = min(streaks.items(), key=lambda item: (-item[1][0], item[1][1]))
+            awards.append(
+                PrizeAward(prize="best_streak", team_id=team_id, team_name=teams[team_id].name, value=length)
+            )
+
+    return awards
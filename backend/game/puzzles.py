@@ -15,6 +15,23 @@ class LadderStep(BaseModel):
     word: str
     clue: Optional[str] = None
     transform: Optional[str] = None
+    open_ended: bool = False  # Answers are approved by the host; word is only an example answer
+    alternates: List[str] = []  # Other accepted answers, e.g. other spellings; solving one reveals word
+
+    @field_validator("alternates")
+    @classmethod
+    def clean_alternates(cls, v: List[str]) -> List[str]:
+        """Drop blank and repeated alternates, keeping the first spelling given."""
+        cleaned: List[str] = []
+        for alternate in v:
+            alternate = alternate.strip()
+            if alternate and alternate.upper() not in {seen.upper() for seen in cleaned}:
+                cleaned.append(alternate)
+        return cleaned
+
+    def accepted_answers(self) -> Set[str]:
+        """The word and its alternates, uppercased as guesses are compared."""
+        return {self.word.upper()} | {alternate.upper() for alternate in self.alternates}
 
 
 class PuzzleMeta(BaseModel):
@@ -22,6 +39,7 @@ class PuzzleMeta(BaseModel):
 
     title: str
     author: Optional[str] = None
+    author_handle: Optional[str] = None
     difficulty: Optional[str] = None
     theme: Optional[str] = None
     message: Optional[str] = None
@@ -52,6 +70,34 @@ class Puzzle(BaseModel):
             raise ValueError(f"Ladder must have at least 5 steps, got {len(v)}")
         return v
 
+    @field_validator("ladder")
+    @classmethod
+    def validate_alternates_dont_overlap(cls, v: List[LadderStep]) -> List[LadderStep]:
+        """An alternate can't also be accepted on another rung, or the guess would be ambiguous."""
+        for index, step in enumerate(v):
+            for alternate in step.alternates:
+                answer = alternate.upper()
+                for other_index, other in enumerate(v):
+                    if other_index != index and answer in other.accepted_answers():
+                        raise ValueError(f"{answer} is accepted on both step {index} and step {other_index}")
+        return v
+
+
+# Prefix for puzzle paths that point at a puzzle stored in the database rather than a JSON file
+STORED_PUZZLE_PREFIX = "db:"
+
+
+def stored_puzzle_path(puzzle_id: int) -> str:
+    return f"{STORED_PUZZLE_PREFIX}{puzzle_id}"
+
+
+def stored_puzzle_id(puzzle_path: str) -> Optional[int]:
+    """The stored puzzle id a puzzle_path refers to, or None for puzzle files."""
+    if not puzzle_path.startswith(STORED_PUZZLE_PREFIX):
+        return None
+    puzzle_id = puzzle_path[len(STORED_PUZZLE_PREFIX) :]
+    return int(puzzle_id) if puzzle_id.isdigit() else None
+
 
 @dataclass(frozen=True)
 class PuzzleFile:
@@ -132,8 +178,8 @@ class PuzzleManager:
 
         puzzles: List[PuzzleFile] = []
 
-        # Recursively find all JSON files
-        for json_file in self.puzzle_dir.rglob("*.json"):
+        # Recursively find all JSON files, sorted so seeded draws don't depend on filesystem order
+        for json_file in sorted(self.puzzle_dir.rglob("*.json")):
             puzzle = self._load_puzzle_from_file(json_file)
             if puzzle and puzzle.puzzle.meta.difficulty == difficulty:
                 puzzles.append(puzzle)
@@ -159,13 +205,19 @@ class PuzzleManager:
             return puzzles
         return [puzzle for puzzle in puzzles if self.normalize_puzzle_path(puzzle.path) not in normalized_excludes]
 
-    def get_random_puzzle(self, difficulty: str, exclude_paths: Optional[Iterable[str]] = None) -> Optional[PuzzleFile]:
+    def get_random_puzzle(
+        self,
+        difficulty: str,
+        exclude_paths: Optional[Iterable[str]] = None,
+        rng: Optional[random.Random] = None,
+    ) -> Optional[PuzzleFile]:
         """
         Get a random puzzle of a given difficulty.
 
         Args:
             difficulty: Difficulty level ("easy", "medium", "hard")
             exclude_paths: Puzzle paths to exclude from selection
+            rng: Random source; pass a seeded one for reproducible picks
 
         Returns:
             Random puzzle of the specified difficulty, or None if no puzzles found
@@ -173,10 +225,14 @@ class PuzzleManager:
         puzzles = self._filter_unused_puzzles(self.load_puzzles_by_difficulty(difficulty), exclude_paths)
         if not puzzles:
             return None
-        return random.choice(puzzles)
+        return (rng or random.Random()).choice(puzzles)
 
     def get_same_puzzle_for_teams(
-        self, num_teams: int, difficulty: str, exclude_paths: Optional[Iterable[str]] = None
+        self,
+        num_teams: int,
+        difficulty: str,
+        exclude_paths: Optional[Iterable[str]] = None,
+        rng: Optional[random.Random] = None,
     ) -> List[PuzzleFile]:
         """
         Get the same puzzle for all teams.
@@ -185,6 +241,7 @@ class PuzzleManager:
             num_teams: Number of teams that will use this puzzle
             difficulty: Difficulty level for the puzzle
             exclude_paths: Puzzle paths to exclude from selection
+            rng: Random source; pass a seeded one for reproducible picks
 
         Returns:
             List of the same puzzle repeated num_teams times
@@ -192,7 +249,7 @@ class PuzzleManager:
         Raises:
             ValueError: If no puzzles available for the difficulty
         """
-        puzzle_file = self.get_random_puzzle(difficulty, exclude_paths=exclude_paths)
+        puzzle_file = self.get_random_puzzle(difficulty, exclude_paths=exclude_paths, rng=rng)
         if not puzzle_file:
             raise ValueError(
                 f"No unused {difficulty} puzzles are available yet. "
@@ -207,6 +264,7 @@ class PuzzleManager:
         difficulty: str,
         word_count_mode: str = "balanced",
         exclude_paths: Optional[Iterable[str]] = None,
+        rng: Optional[random.Random] = None,
     ) -> List[PuzzleFile]:
         """
         Get different puzzles for each team, all of the same difficulty.
@@ -216,6 +274,7 @@ class PuzzleManager:
             difficulty: Difficulty level for all puzzles
             word_count_mode: "exact" for same word count, "balanced" for ±1 words
             exclude_paths: Puzzle paths to exclude from selection
+            rng: Random source; pass a seeded one for reproducible picks
 
         Returns:
             List of puzzles (length = num_teams)
@@ -224,6 +283,7 @@ class PuzzleManager:
             ValueError: If not enough puzzles available for the difficulty
         """
         puzzles = self._filter_unused_puzzles(self.load_puzzles_by_difficulty(difficulty), exclude_paths)
+        rng = rng or random.Random()
 
         if not puzzles:
             raise ValueError(
@@ -249,7 +309,7 @@ class PuzzleManager:
             # Find a word count that has enough puzzles for exact match
             for target_length in sorted_lengths:
                 if len(puzzles_by_length[target_length]) >= num_teams:
-                    return random.sample(puzzles_by_length[target_length], num_teams)
+                    return rng.sample(puzzles_by_length[target_length], num_teams)
 
             # If no exact match available, raise error
             raise ValueError(
@@ -261,7 +321,7 @@ class PuzzleManager:
             # Strategy: Pick one puzzle first, then find others within ±1 of its size
 
             # Pick the first puzzle randomly from all available
-            first_puzzle = random.choice(puzzles)
+            first_puzzle = rng.choice(puzzles)
             selected = [first_puzzle]
 
             # Get the target length from the first puzzle
@@ -279,14 +339,14 @@ class PuzzleManager:
             # Check if we have enough puzzles in this range
             if len(candidates) >= num_teams - 1:
                 # Randomly select the remaining puzzles from candidates
-                selected.extend(random.sample(candidates, num_teams - 1))
+                selected.extend(rng.sample(candidates, num_teams - 1))
                 return selected
 
             # Fallback: if we don't have enough puzzles in the ±1 range,
             # just select randomly from all available (excluding the first)
             remaining = [p for p in puzzles if p != first_puzzle]
             if len(remaining) >= num_teams - 1:
-                selected.extend(random.sample(remaining, num_teams - 1))
+                selected.extend(rng.sample(remaining, num_teams - 1))
                 return selected
 
             # Edge case: not enough total puzzles
@@ -318,18 +378,43 @@ class PuzzleManager:
             return candidate
         return self.puzzle_dir / candidate
 
+    def evict_puzzle(self, puzzle_path: str) -> None:
+        """Drop a cached puzzle so the next load picks up edits."""
+        self._puzzle_cache.pop(self._cache_key(puzzle_path), None)
+
     def load_puzzle_by_path(self, puzzle_path: str) -> Puzzle:
-        """Load a puzzle from a stored path string."""
+        """Load a puzzle from a stored path string (a file path, or "db:<id>" for a stored puzzle)."""
         cache_key = self._cache_key(puzzle_path)
         cached = self._puzzle_cache.get(cache_key)
         if cached:
             return cached
+        puzzle_id = stored_puzzle_id(puzzle_path)
+        if puzzle_id is not None:
+            from backend.game.stored_puzzles import load_stored_puzzle
+
+            puzzle = load_stored_puzzle(puzzle_id)
+            if not puzzle:
+                raise ValueError(f"Stored puzzle {puzzle_id} not found")
+            self.cache_puzzle(puzzle_path, puzzle)
+            return puzzle
         file_path = self.resolve_puzzle_path(puzzle_path)
         puzzle_file = self._load_puzzle_from_file(file_path)
         if not puzzle_file:
             raise ValueError(f"Failed to load puzzle from {file_path}")
         return puzzle_file.puzzle
 
+    def puzzle_path_for_date(self, puzzle_date: str) -> str:
+        """
+        Convert a YYYY-MM-DD puzzle date into its stored puzzle path.
+
+        Raises:
+            ValueError: If the date is not in YYYY-MM-DD format
+        """
+        date_parts = puzzle_date.split("-")
+        if len(date_parts) != 3 or not all(part.isdigit() for part in date_parts):
+            raise ValueError("Invalid date format. Expected YYYY-MM-DD")
+        return f"{date_parts[0]}/{date_parts[1]}/{date_parts[2]}.json"
+
     def get_available_puzzle_dates(self) -> List[str]:
         """Get a list of available puzzle dates in YYYY-MM-DD format."""
         dates = []
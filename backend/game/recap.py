@@ -0,0 +1,67 @@
+"""
+Public game recaps: a round's guess log turned into a timeline that's safe to share outside the lobby.
+
+The timeline is spoiler-ordered: rung answers only appear in the entry where a team solved them, so a viewer
+stepping through it sees the round unfold as the teams did. Player names can be swapped for aliases, and wrong
+guesses (free text players typed) are shown, masked or dropped per config. Team chat is private to the team
+and stays out of recaps.
+"""
+
+from dataclasses import dataclass
+from datetime import datetime
+from typing import Dict, Iterable, List, Literal, Optional
+
+WrongGuessPolicy = Literal["show", "mask", "hide"]
+
+
+@dataclass
+class RecapGuess:
+    team_id: int
+    player_id: int
+    word_index: int
+    guess: str
+    is_correct: bool
+    created_at: datetime
+
+
+@dataclass
+class RecapEntry:
+    at_seconds: float  # Since the round started
+    team_id: int
+    player: str
+    word_index: int
+    solved: bool
+    guess: Optional[str]  # None when a wrong guess is masked
+
+
+def player_aliases(player_ids: Iterable[int]) -> Dict[int, str]:
+    """Number players by first appearance: "Player 1", "Player 2", ..."""
+    aliases: Dict[int, str] = {}
+    for player_id in player_ids:
+        if player_id not in aliases:
+            aliases[player_id] = f"Player {len(aliases) + 1}"
+    return aliases
+
+
+def build_timeline(
+    started_at: datetime,
+    guesses: Iterable[RecapGuess],
+    player_names: Dict[int, str],
+    wrong_guesses: WrongGuessPolicy,
+) -> List[RecapEntry]:
+    """Guesses in the order they were made, with wrong ones handled per `wrong_guesses`."""
+    timeline = []
+    for guess in sorted(guesses, key=lambda guess: guess.created_at):
+        if not guess.is_correct and wrong_guesses == "hide":
+            continue
+        timeline.append(
+            RecapEntry(
+                at_seconds=max(0.0, (guess.created_at - started_at).total_seconds()),
+                team_id=guess.team_id,
+                player=player_names.get(guess.player_id, "Unknown player"),
+                word_index=guess.word_index,
+                solved=guess.is_correct,
+                guess=guess.guess if guess.is_correct or wrong_guesses == "show" else None,
+            )
+        )
+    return timeline
@@ -0,0 +1,169 @@
+"""Scoring a finished round into RoundResult rows and reading them back as final standings.
+
+A round is scored once: when the last team finishes its puzzle, or when the host (or the round timer) ends it with
+teams still playing. Either way the lobby gets a game_finished event with the ranking, and the results screen reads
+the same standings from GET /api/lobby/{lobby_id}/results. Lobbies in contest mode also get their prize awards.
+"""
+
+import json
+from typing import Dict, List, Optional
+
+from sqlmodel import Session, func, select
+
+from backend.custom_logging import api_logger
+from backend.database.models import Game, RoundResult, Team
+from backend.game.prizes import contest_prizes
+from backend.game.standings import calculate_points
+from backend.websocket.events import FinalStanding, GameFinishedEvent
+
+
+def record_round_results(db: Session, lobby_id: int, teams: List[Team], puzzle_length: int) -> int:
+    """
+    Rank the teams playing this round, add their RoundResult rows and tournament stats, and return the round number.
+
+    Finished teams are placed by completion time, the rest by how much of their puzzle they revealed. The caller
+    commits.
+    """
+    completed_teams = []
+    dnf_teams = []
+    games: Dict[int, Game] = {}
+
+    for team in teams:
+        if team.game_id is None:
+            continue
+
+        team_game = db.get(Game, team.game_id)
+        if not team_game:
+            continue
+        games[team.id] = team_game
+
+        # Check if team completed (game marked as completed for this team)
+        if team_game.completed_at is not None:
+            completed_teams.append(team)
+        else:
+            dnf_teams.append(team)
+
+    # Sort completed teams by completed_at
+    completed_teams.sort(key=lambda t: games[t.id].completed_at)
+
+    # Calculate completion percentage for DNF teams
+    team_completion_pct = {}
+    for team in dnf_teams:
+        team_game = games[team.id]
+        revealed = json.loads(team_game.revealed_steps) if team_game.revealed_steps else []
+        team_completion_pct[team.id] = len(revealed) / puzzle_length if puzzle_length > 0 else 0.0
+
+    # Sort DNF teams by completion percentage (descending)
+    dnf_teams.sort(key=lambda t: team_completion_pct[t.id], reverse=True)
+
+    # Combine: completed teams first, then DNF teams
+    all_teams_ranked = completed_teams + dnf_teams
+
+    round_number = (
+        db.exec(select(func.max(RoundResult.round_number)).where(RoundResult.lobby_id == lobby_id)).first() or 0
+    )
+    round_number += 1
+
+    # Calculate worst_finished_points: points awarded to the lowest finished placement
+    if completed_teams:
+        worst_finished_points = len(teams) - len(completed_teams) + 1
+    else:
+        worst_finished_points = len(teams)
+
+    for placement, team in enumerate(all_teams_ranked, start=1):
+        completed = team in completed_teams
+        completion_pct = 1.0 if completed else team_completion_pct.get(team.id, 0.0)
+
+        points = calculate_points(
+            placement,
+            len(teams),
+            completion_pct,
+            completed,
+            worst_finished_points,
+        )
+
+        # Update team statistics
+        team.total_points += points
+        team.rounds_played += 1
+        if placement == 1:
+            team.rounds_won += 1
+        db.add(team)
+
+        time_to_complete = None
+        completed_at = None
+        if completed:
+            team_game = games[team.id]
+            time_to_complete = int((team_game.completed_at - team_game.started_at).total_seconds())
+            completed_at = team_game.completed_at
+
+        db.add(
+            RoundResult(
+                lobby_id=lobby_id,
+                game_id=team.game_id,
+                team_id=team.id,
+                round_number=round_number,
+                placement=placement,
+                points_earned=points,
+                completion_percentage=completion_pct,
+                time_to_complete=time_to_complete,
+                completed_at=completed_at,
+            )
+        )
+
+        api_logger.info(
+            f"Round {round_number} result: team={team.name} placement={placement} "
+            f"points={points} completed={completed} completion_pct={completion_pct:.2%}"
+        )
+
+    return round_number
+
+
+def round_already_recorded(db: Session, teams: List[Team]) -> bool:
+    """Whether the round these teams are playing already has its results, so it isn't scored twice."""
+    game_ids = [team.game_id for team in teams if team.game_id is not None]
+    if not game_ids:
+        return False
+    return db.exec(select(RoundResult.id).where(RoundResult.game_id.in_(game_ids))).first() is not None
+
+
+def final_standings(db: Session, lobby_id: int, round_number: Optional[int] = None) -> tuple[int, List[FinalStanding]]:
+    """The ranking of a round (the latest when round_number is None) and its number; 0 and [] before any round."""
+    if round_number is None:
+        round_number = (
+            db.exec(select(func.max(RoundResult.round_number)).where(RoundResult.lobby_id == lobby_id)).first() or 0
+        )
+    rows = db.exec(
+        select(RoundResult, Team)
+        .join(Team, Team.id == RoundResult.team_id)
+        .where(RoundResult.lobby_id == lobby_id)
+        .where(RoundResult.round_number == round_number)
+        .order_by(RoundResult.placement)
+    ).all()
+    standings = [
+        FinalStanding(
+            team_id=team.id,
+            team_name=team.name,
+            team_color=team.color,
+            placement=result.placement,
+            points_earned=result.points_earned,
+            total_points=team.total_points,
+            completion_percentage=result.completion_percentage,
+            time_to_complete=result.time_to_complete,
+            completed=result.completed_at is not None,
+        )
+        for result, team in rows
+    ]
+    return (round_number if standings else 0), standings
+
+
+def game_finished_event(db: Session, lobby_id: int, round_number: int) -> GameFinishedEvent:
+    _, standings = final_standings(db, lobby_id, round_number)
+    winner = standings[0] if standings else None
+    return GameFinishedEvent(
+        lobby_id=lobby_id,
+        round_number=round_number,
+        winning_team_id=winner.team_id if winner else None,
+        winning_team_name=winner.team_name if winner else None,
+        standings=standings,
+        prizes=contest_prizes(db, lobby_id),
+    )
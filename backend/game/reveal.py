@@ -0,0 +1,70 @@
+"""
+End-of-game answer reveal: step through every rung of the round's puzzles together, one answer at a time.
+
+Once a round is over the host can start a reveal, and each step is broadcast to the whole lobby so everyone sees
+the same rung at the same moment. Steps advance when the host clicks next, or every `interval_seconds` when a
+pace is set (the host can still skip ahead). With different puzzles per team, the puzzles are revealed one after
+another in a stable order. A step's explanation is the clue (and transform) linking the previous word to it;
+the first word of a ladder is given, so it has none.
+"""
+
+from dataclasses import dataclass, field
+from datetime import datetime, timedelta
+from typing import List, Optional
+
+from backend.game.puzzles import Puzzle
+
+
+@dataclass(frozen=True)
+class RevealStep:
+    puzzle_title: str
+    puzzle_number: int  # 1-based, among the puzzles being revealed
+    word_index: int
+    word: str
+    clue: Optional[str]
+    transform: Optional[str]
+
+    @property
+    def explanation(self) -> Optional[str]:
+        if self.clue and self.transform:
+            return f"{self.clue} ({self.transform})"
+        return self.clue or self.transform
+
+
+def build_reveal_steps(puzzles: List[Puzzle]) -> List[RevealStep]:
+    return [
+        RevealStep(
+            puzzle_title=puzzle.meta.title,
+            puzzle_number=puzzle_number,
+            word_index=word_index,
+            word=step.word,
+            clue=step.clue,
+            transform=step.transform,
+        )
+        for puzzle_number, puzzle in enumerate(puzzles, start=1)
+        for word_index, step in enumerate(puzzle.ladder)
+    ]
+
+
+@dataclass
+class RevealSession:
+    steps: List[RevealStep]
+    interval_seconds: Optional[float] = None  # None: only the host advances
+    position: int = -1  # Index of the step on screen; -1 before the first
+    next_step_at: Optional[datetime] = field(default=None)
+
+    @property
+    def finished(self) -> bool:
+        return self.position >= len(self.steps) - 1
+
+    def advance(self, now: datetime) -> Optional[RevealStep]:
+        """Move to the next step and return it, or None once every step has been shown."""
+        if self.finished:
+            self.next_step_at = None
+            return None
+        self.position += 1
+        self.next_step_at = None if self.interval_seconds is None else now + timedelta(seconds=self.interval_seconds)
+        return self.steps[self.position]
+
+    def is_due(self, now: datetime) -> bool:
+        return self.next_step_at is not None and now >= self.next_step_at
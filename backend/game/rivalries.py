@@ -0,0 +1,90 @@
+"""
+Head-to-head records between recurring team line-ups, for the weekly groups that keep playing each other.
+
+Players don't have accounts, so a line-up is identified by its members' names (case-insensitive, bots left out):
+the same friends under a new team name in next week's lobby still count as the same team. Teams are taken as they
+are now, since rosters aren't recorded per round. Two line-ups meet whenever they play the same round of the same
+lobby; the better placement wins it and equal placements are a tie.
+"""
+
+from collections import defaultdict
+from dataclasses import dataclass, field
+from datetime import datetime
+from typing import Dict, List, Optional, Tuple
+
+LineupKey = Tuple[str, ...]
+
+
+@dataclass(frozen=True)
+class TeamRound:
+    """One team's result in one round, with the members it has now."""
+
+    lobby_id: int
+    round_number: int
+    team_name: str
+    members: Tuple[str, ...]
+    placement: int
+    points: int
+    played_at: datetime
+
+
+@dataclass
+class Rivalry:
+    members_a: List[str]
+    members_b: List[str]
+    team_names_a: List[str] = field(default_factory=list)  # Every name the line-up has played under
+    team_names_b: List[str] = field(default_factory=list)
+    meetings: int = 0
+    wins_a: int = 0
+    wins_b: int = 0
+    ties: int = 0
+    point_margin_total: int = 0  # points_a - points_b summed over meetings
+    last_met_at: Optional[datetime] = None
+
+    @property
+    def avg_point_margin(self) -> float:
+        """Average points A finished ahead of B by; negative when B usually comes out on top."""
+        return self.point_margin_total / self.meetings if self.meetings else 0.0
+
+
+def lineup_key(members: Tuple[str, ...]) -> LineupKey:
+    return tuple(sorted(name.strip().casefold() for name in members))
+
+
+def head_to_head(results: List[TeamRound], min_meetings: int = 1) -> List[Rivalry]:
+    """Rivalries met at least min_meetings times, most played first and, among those, the closest first."""
+    rounds: Dict[Tuple[int, int], List[TeamRound]] = defaultdict(list)
+    for result in results:
+        if result.members:
+            rounds[(result.lobby_id, result.round_number)].append(result)
+
+    rivalries: Dict[Tuple[LineupKey, LineupKey], Rivalry] = {}
+    for teams in rounds.values():
+        for i, first in enumerate(teams):
+            for second in teams[i + 1 :]:
+                if lineup_key(first.members) == lineup_key(second.members):
+                    continue
+                a, b = sorted((first, second), key=lambda team: lineup_key(team.members))
+                key = (lineup_key(a.members), lineup_key(b.members))
+                rivalry = rivalries.setdefault(key, Rivalry(sorted(a.members), sorted(b.members)))
+                _record_meeting(rivalry, a, b)
+
+    qualifying = [rivalry for rivalry in rivalries.values() if rivalry.meetings >= min_meetings]
+    return sorted(qualifying, key=lambda rivalry: (-rivalry.meetings, abs(rivalry.wins_a - rivalry.wins_b)))
+
+
+def _record_meeting(rivalry: Rivalry, a: TeamRound, b: TeamRound):
+    rivalry.meetings += 1
+    if a.placement < b.placement:
+        rivalry.wins_a += 1
+    elif b.placement < a.placement:
+        rivalry.wins_b += 1
+    else:
+        rivalry.ties += 1
+    rivalry.point_margin_total += a.points - b.points
+    for names, team_name in ((rivalry.team_names_a, a.team_name), (rivalry.team_names_b, b.team_name)):
+        if team_name not in names:
+            names.append(team_name)
+    played_at = max(a.played_at, b.played_at)
+    if rivalry.last_met_at is None or played_at > rivalry.last_met_at:
+        rivalry.last_met_at = played_at
@@ -0,0 +1,19 @@
+"""Per-lobby seeded randomness, so team shuffles and puzzle assignments can be reproduced."""
+
+import random
+
+SEED_LIMIT = 2**31
+
+
+def new_seed() -> int:
+    return random.randrange(SEED_LIMIT)
+
+
+def seeded_rng(seed: int, purpose: str) -> random.Random:
+    """
+    An independent, reproducible random stream for one use of a lobby's seed.
+
+    Each purpose (e.g. "teams", "puzzles:2" for round 2) gets its own stream, so
+    reshuffling teams doesn't change which puzzles later rounds draw.
+    """
+    return random.Random(f"{seed}:{purpose}")
@@ -0,0 +1,155 @@
+"""
+Dry-run games: virtual teams play a puzzle through TeamStateMachine so hosts can see how long a round will run.
+
+Each virtual team works down the ladder one rung at a time. Its guesses take a log-normally distributed number
+of seconds, and each is right with the team's accuracy, drawn once per team around the requested mean and divided
+by the rung's difficulty (1.0 unless past games of the puzzle say otherwise, see difficulty_curve). The whole
+game is played `runs` times, and the projection is the median over those runs of when the last team finished.
+A rung is a stall point when teams take PACE_KEEPER_STALL_MULTIPLIER x the typical solve time on it, which is
+roughly where the pace keeper would start handing out hints in a real game, or when teams run out of time on it.
+"""
+
+import math
+import random
+from collections import defaultdict
+from dataclasses import dataclass, field
+from statistics import median
+from typing import Dict, List, Optional
+
+from backend.game.pacing import next_rung
+from backend.game.puzzles import Puzzle
+from backend.game.state_machine import TeamStateMachine
+from backend.metrics import percentile
+from backend.settings import settings
+
+MAX_TEAMS = 100
+MAX_TEAM_PLAYS = 5000  # num_teams x runs
+MAX_GUESSES_PER_PLAY = 1000  # A team still going after this many guesses is counted as out of time
+WRONG_GUESS = "?"  # Never matches a ladder word
+
+
+@dataclass
+class SimulationParams:
+    num_teams: int
+    runs: int
+    accuracy: float  # Mean chance that a guess is right
+    accuracy_spread: float  # Standard deviation of each team's accuracy around the mean
+    guess_seconds: float  # Median seconds a team takes per guess
+    guess_seconds_spread: float  # Log-normal sigma of the seconds per guess; 0 makes every guess take the same
+    time_limit_seconds: float  # Teams still playing by then are counted as unfinished
+    rung_difficulty: Dict[int, float] = field(default_factory=dict)  # word_index -> relative difficulty
+
+
+@dataclass
+class SimulatedPlay:
+    finish_seconds: Optional[float]  # None when the team ran into the time limit
+    stuck_on: Optional[int] = None  # The rung it was on when time ran out
+    solve_seconds: Dict[int, float] = field(default_factory=dict)  # word_index -> seconds spent on it
+    wrong_guesses: Dict[int, int] = field(default_factory=dict)
+
+
+@dataclass
+class RungProjection:
+    word_index: int
+    median_solve_seconds: Optional[float]
+    avg_wrong_guesses: float  # Per team play
+    teams_stuck: int  # Team plays that ran out of time on this rung
+    stall_point: bool
+
+
+@dataclass
+class SimulationResult:
+    projected_duration_seconds: float  # Median over runs of when the last team finished
+    p90_duration_seconds: float
+    median_team_finish_seconds: Optional[float]  # None when no team finished
+    unfinished_rate: float  # Share of team plays that ran into the time limit
+    rungs: List[RungProjection]
+
+
+def simulate_team(puzzle: Puzzle, accuracy: float, params: SimulationParams, rng: random.Random) -> SimulatedPlay:
+    machine = TeamStateMachine(puzzle)
+    play = SimulatedPlay(finish_seconds=None)
+    wrong_guesses: Dict[int, int] = defaultdict(int)
+    elapsed = 0.0
+    last_solve = 0.0
+    guesses = 0
+    while not machine.is_completed():
+        word_index = next_rung(machine.state.revealed_steps, len(puzzle.ladder))
+        elapsed += rng.lognormvariate(math.log(params.guess_seconds), params.guess_seconds_spread)
+        if elapsed > params.time_limit_seconds or guesses >= MAX_GUESSES_PER_PLAY:
+            play.stuck_on = word_index
+            break
+        guesses += 1
+
+        word = puzzle.ladder[word_index].word
+        correct = rng.random() < accuracy / params.rung_difficulty.get(word_index, 1.0)
+        result = machine.submit_guess(word if correct else WRONG_GUESS, word_index)
+        if result.pending_review and correct:
+            # Open-ended rungs: assume the host approves a right answer straight away
+            result = machine.approve_answer(word, word_index)
+
+        if result.is_correct:
+            play.solve_seconds[word_index] = elapsed - last_solve
+            last_solve = elapsed
+        else:
+            wrong_guesses[word_index] += 1
+    else:
+        play.finish_seconds = elapsed
+
+    play.wrong_guesses = dict(wrong_guesses)
+    return play
+
+
+def rung_difficulty_from_curve(curve: List[float]) -> Dict[int, float]:
+    """
+    Turn a difficulty_curve (hidden rungs only) into per-rung difficulties.
+
+    Rungs nobody has solved are reported as 0.0 there; they're treated as the hardest rung seen.
+    """
+    hardest = max(curve, default=0.0) or 1.0
+    return {index: value or hardest for index, value in enumerate(curve, start=1)}
+
+
+def simulate_game(puzzle: Puzzle, params: SimulationParams, rng: random.Random) -> SimulationResult:
+    durations: List[float] = []
+    plays: List[SimulatedPlay] = []
+    for _ in range(params.runs):
+        accuracies = [
+            min(1.0, max(0.01, rng.gauss(params.accuracy, params.accuracy_spread))) for _ in range(params.num_teams)
+        ]
+        run_plays = [simulate_team(puzzle, accuracy, params, rng) for accuracy in accuracies]
+        finishes = [play.finish_seconds for play in run_plays]
+        durations.append(params.time_limit_seconds if None in finishes else max(finishes))
+        plays.extend(run_plays)
+
+    finished = [play.finish_seconds for play in plays if play.finish_seconds is not None]
+    # The first and last words start revealed, so only the rungs between them are ever solved
+    rung_times = {
+        index: [play.solve_seconds[index] for play in plays if index in play.solve_seconds]
+        for index in range(1, len(puzzle.ladder) - 1)
+    }
+    all_times = [seconds for times in rung_times.values() for seconds in times]
+    stall_seconds = settings.PACE_KEEPER_STALL_MULTIPLIER * median(all_times) if all_times else None
+
+    rungs = []
+    for word_index, times in rung_times.items():
+        rung_median = median(times) if times else None
+        teams_stuck = sum(1 for play in plays if play.stuck_on == word_index)
+        slow = rung_median is not None and stall_seconds is not None and rung_median >= stall_seconds
+        rungs.append(
+            RungProjection(
+                word_index=word_index,
+                median_solve_seconds=rung_median,
+                avg_wrong_guesses=sum(play.wrong_guesses.get(word_index, 0) for play in plays) / len(plays),
+                teams_stuck=teams_stuck,
+                stall_point=slow or teams_stuck > 0,
+            )
+        )
+
+    return SimulationResult(
+        projected_duration_seconds=median(durations),
+        p90_duration_seconds=percentile(durations, 90),
+        median_team_finish_seconds=median(finished) if finished else None,
+        unfinished_rate=1 - len(finished) / len(plays),
+        rungs=rungs,
+    )
@@ -0,0 +1,142 @@
+"""Live standings and round scoring for teams in a lobby."""
+
+import math
+from dataclasses import dataclass
+from datetime import datetime
+from typing import Dict, List, Optional
+
+
+@dataclass(frozen=True)
+class TeamProgress:
+    """A team's progress on its current puzzle."""
+
+    team_id: int
+    revealed_count: int
+    ladder_length: int
+    completed_at: Optional[datetime] = None
+    last_updated_at: Optional[datetime] = None
+
+    @property
+    def completion_percentage(self) -> float:
+        return self.revealed_count / self.ladder_length if self.ladder_length > 0 else 0.0
+
+
+@dataclass(frozen=True)
+class Standing:
+    team_id: int
+    rank: int
+    completion_percentage: float
+    projected_points: int  # Points the team would earn if the round ended now
+
+
+def calculate_points(
+    placement: int,
+    total_teams: int,
+    completion_percentage: float,
+    completed: bool,
+    worst_finished_points: int,
+) -> int:
+    """
+    Calculate points for a team based on placement and completion.
+
+    Reverse placement for finishers: 1st gets n points, 2nd gets n-1, etc.
+    DNFs get up to 75% of worst finished points, scaled by completion %, ceil, min 1.
+    """
+    if completed:
+        return total_teams - placement + 1
+
+    base = worst_finished_points
+    cap = base * 0.75
+    return max(1, math.ceil(min(cap, base * completion_percentage)))
+
+
+def rank_teams(progress: List[TeamProgress]) -> List[TeamProgress]:
+    """
+    Order teams the way a round is scored.
+
+    Finished teams come first by completion time; the rest by completion percentage,
+    with ties going to the team that reached its progress first.
+    """
+    finished = sorted(
+        (p for p in progress if p.completed_at is not None), key=lambda p: (p.completed_at, p.team_id)
+    )
+    unfinished = sorted(
+        (p for p in progress if p.completed_at is None),
+        key=lambda p: (
+            -p.completion_percentage,
+            p.last_updated_at is None,
+            p.last_updated_at or datetime.min,
+            p.team_id,
+        ),
+    )
+    return finished + unfinished
+
+
+def compute_standings(progress: List[TeamProgress]) -> Dict[int, Standing]:
+    """Rank teams and project the points each would earn if the round ended now."""
+    ranked = rank_teams(progress)
+    total_teams = len(ranked)
+    finished_count = sum(1 for p in ranked if p.completed_at is not None)
+    worst_finished_points = total_teams - finished_count + 1 if finished_count else total_teams
+
+    standings: Dict[int, Standing] = {}
+    for placement, team_progress in enumerate(ranked, start=1):
+        completed = team_progress.completed_at is not None
+        completion = 1.0 if completed else team_progress.completion_percentage
+        standings[team_progress.team_id] = Standing(
+            team_id=team_progress.team_id,
+            rank=placement,
+            completion_percentage=completion,
+            projected_points=calculate_points(placement, total_teams, completion, completed, worst_finished_points),
+        )
+    return standings
+
+
+# How much of other teams' progress players may see
+PROGRESS_VISIBILITY_LEVELS = ("none", "coarse", "exact")
+COARSE_PROGRESS_STEPS = 4
+
+
+@dataclass(frozen=True)
+class VisibleProgress:
+    """The parts of a team's progress that other teams are allowed to see."""
+
+    progress: float  # 0.0 to 1.0 over the hidden rungs
+    solved_count: Optional[int]  # Hidden rungs solved; exact visibility only
+    total_count: Optional[int]
+    is_completed: bool
+
+
+def visible_progress(
+    visibility: str, revealed_count: int, ladder_length: int, completed: bool
+) -> Optional[VisibleProgress]:
+    """
+    Mask a team's progress to the lobby's visibility level.
+
+    Coarse progress snaps down to quarter steps and never reads full until the team finishes,
+    so opponents can't work out the exact rung. Never includes which rungs were solved.
+
+    Returns:
+        The visible progress, or None when opponents may not see any
+    """
+    if visibility not in ("coarse", "exact"):
+        return None
+
+    total = max(ladder_length - 2, 1)  # First and last words are given
+    solved = min(max(revealed_count - 2, 0), total)
+    if completed:
+        return VisibleProgress(
+            progress=1.0,
+            solved_count=total if visibility == "exact" else None,
+            total_count=total if visibility == "exact" else None,
+            is_completed=True,
+        )
+
+    if visibility == "exact":
+        return VisibleProgress(progress=solved / total, solved_count=solved, total_count=total, is_completed=False)
+
+    step = math.floor(solved / total * COARSE_PROGRESS_STEPS)
+    step = min(step, COARSE_PROGRESS_STEPS - 1)
+    return VisibleProgress(
+        progress=step / COARSE_PROGRESS_STEPS, solved_count=None, total_count=None, is_completed=False
+    )
@@ -50,6 +50,7 @@ class GuessResult:
     word_index: int = -1
     expected_word: Optional[str] = None
     new_state: Optional[TeamState] = None
+    pending_review: bool = False  # Open-ended rung; the host decides if the answer counts
 
 
 class TeamStateMachine:
@@ -125,10 +126,19 @@ class TeamStateMachine:
                 expected_word=None,
             )
 
-        expected_word = self.puzzle.ladder[word_index].word.upper()
+        # Open-ended rungs have no single right answer, so the host reviews them
+        if self.puzzle.ladder[word_index].open_ended:
+            return GuessResult(
+                is_correct=False,
+                word_index=word_index,
+                pending_review=True,
+            )
+
+        step = self.puzzle.ladder[word_index]
+        expected_word = step.word.upper()
 
-        # Check if guess is correct
-        is_correct = guess.upper() == expected_word
+        # Check if guess is correct; an alternate solves the rung but reveals the puzzle's own word
+        is_correct = guess.upper() in step.accepted_answers()
 
         if not is_correct:
             return GuessResult(
@@ -137,7 +147,36 @@ class TeamStateMachine:
                 expected_word=expected_word,
             )
 
-        # Correct guess - reveal the word
+        return self._reveal(word_index, expected_word)
+
+    def approve_answer(self, answer: str, word_index: int) -> GuessResult:
+        """
+        Accept a host-approved answer for an open-ended rung.
+
+        Args:
+            answer: The team's answer being approved
+            word_index: Index in the ladder the answer was for
+
+        Returns:
+            GuessResult with outcome
+        """
+        if word_index in self.state.revealed_steps:
+            return GuessResult(
+                is_correct=False,
+                already_solved=True,
+                word_index=word_index,
+            )
+
+        if word_index < 0 or word_index >= len(self.puzzle.ladder):
+            return GuessResult(
+                is_correct=False,
+                word_index=word_index,
+            )
+
+        return self._reveal(word_index, answer.upper())
+
+    def _reveal(self, word_index: int, word: str) -> GuessResult:
+        """Reveal a word and check for completion."""
         self.state.revealed_steps.add(word_index)
         self.state.last_updated_at = datetime.now(tz=timezone.utc)
 
@@ -148,10 +187,28 @@ class TeamStateMachine:
         return GuessResult(
             is_correct=True,
             word_index=word_index,
-            expected_word=expected_word,
+            expected_word=word,
             new_state=self.get_current_state(),
         )
 
+    def rollback(self, word_index: int) -> Optional[TeamState]:
+        """
+        Hide a solved word again, e.g. when the host finds it was accepted against a typo in the answer key.
+
+        Args:
+            word_index: Index of the solved word to hide
+
+        Returns:
+            The new state, or None if the word isn't a solved rung (the first and last words are never solved)
+        """
+        if word_index not in self.state.revealed_steps or word_index in (0, len(self.puzzle.ladder) - 1):
+            return None
+
+        self.state.revealed_steps.discard(word_index)
+        self.state.is_completed = False
+        self.state.last_updated_at = datetime.now(tz=timezone.utc)
+        return self.get_current_state()
+
     def is_completed(self) -> bool:
         """Check if the puzzle is completed."""
         return self.state.is_completed
@@ -0,0 +1,35 @@
+"""Puzzles hosts manage through the admin API, stored in the database alongside the JSON puzzle files.
+
+Games point at a stored puzzle with a "db:<id>" puzzle_path (see stored_puzzle_path), so everything that
+loads puzzles through PuzzleManager.load_puzzle_by_path works the same for both sources.
+"""
+
+import json
+from typing import Optional
+
+from sqlmodel import Session
+
+import backend.database as database
+from backend.database.models import Puzzle as PuzzleRecord
+from backend.game.puzzles import LadderStep, Puzzle, PuzzleMeta
+
+
+def record_ladder(record: PuzzleRecord) -> list[LadderStep]:
+    steps = json.loads(record.ladder) if isinstance(record.ladder, str) else record.ladder
+    return [LadderStep(**step) for step in steps]
+
+
+def to_game_puzzle(record: PuzzleRecord) -> Puzzle:
+    meta = PuzzleMeta(
+        title=record.title,
+        author=record.author_name,
+        author_handle=record.author_handle,
+        difficulty=record.difficulty,
+    )
+    return Puzzle(meta=meta, ladder=record_ladder(record))
+
+
+def load_stored_puzzle(puzzle_id: int) -> Optional[Puzzle]:
+    with Session(database.engine) as session:
+        record = session.get(PuzzleRecord, puzzle_id)
+        return to_game_puzzle(record) if record else None
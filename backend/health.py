@@ -0,0 +1,130 @@
+"""
+Lightweight health score for this node, used to shed non-critical features when it's struggling.
+
+The score covers the last HEALTH_WINDOW_SECONDS: p95 latency of a trivial DB query the health task runs every
+HEALTH_TICK_SECONDS, and the share of API requests answered with a 5xx. When either passes its threshold the node
+is degraded and backend/features.py switches off DEGRADABLE_FEATURES until both are back under; admins are told
+each time that flips, so guess submission stays responsive instead of competing with chat and analytics.
+"""
+
+import asyncio
+import time
+from collections import deque
+from dataclasses import dataclass
+from typing import Deque, Optional, Tuple
+
+from sqlalchemy import text
+
+from backend.custom_logging import server_logger
+from backend.database import get_session_context
+from backend.in_memory_state import on_reset
+from backend.metrics import percentile
+from backend.settings import settings
+from backend.websocket.events import HealthStatusEvent
+
+_health_task: Optional[asyncio.Task] = None
+
+
+@dataclass
+class HealthScore:
+    db_latency_ms: Optional[float]  # p95 over the window, None before the first probe
+    error_rate: Optional[float]  # None until the window has HEALTH_MIN_REQUESTS requests
+    request_count: int
+
+    @property
+    def degraded(self) -> bool:
+        slow_db = self.db_latency_ms is not None and self.db_latency_ms > settings.HEALTH_DB_LATENCY_MS
+        failing = self.error_rate is not None and self.error_rate > settings.HEALTH_ERROR_RATE
+        return slow_db or failing
+
+
+class HealthMonitor:
+    """Rolling window of DB probe latencies and request outcomes."""
+
+    def __init__(self):
+        self.db_latencies: Deque[Tuple[float, float]] = deque()  # (recorded_at, latency_ms)
+        self.requests: Deque[Tuple[float, bool]] = deque()  # (recorded_at, is_server_error)
+
+    def record_db_latency(self, latency_ms: float, now: float):
+        self.db_latencies.append((now, latency_ms))
+
+    def record_request(self, status_code: int, now: float):
+        self.requests.append((now, status_code >= 500))
+
+    def prune(self, now: float):
+        cutoff = now - settings.HEALTH_WINDOW_SECONDS
+        for samples in (self.db_latencies, self.requests):
+            while samples and samples[0][0] < cutoff:
+                samples.popleft()
+
+    def score(self, now: float) -> HealthScore:
+        self.prune(now)
+        request_count = len(self.requests)
+        error_rate = None
+        if request_count >= settings.HEALTH_MIN_REQUESTS:
+            error_rate = sum(1 for _, is_error in self.requests if is_error) / request_count
+        return HealthScore(
+            db_latency_ms=percentile([latency for _, latency in self.db_latencies], 95),
+            error_rate=error_rate,
+            request_count=request_count,
+        )
+
+
+health_monitor = HealthMonitor()
+on_reset(health_monitor.__init__)
+
+
+async def probe_db_latency() -> float:
+    started = time.perf_counter()
+    async with get_session_context() as db:
+        db.execute(text("SELECT 1"))
+    return (time.perf_counter() - started) * 1000
+
+
+async def health_tick(now: float) -> HealthScore:
+    """Probe the DB, rescore, and when that flips the degraded state, switch features and tell every admin."""
+    from backend.features import feature_flags
+    from backend.websocket.managers import lobby_websocket_manager
+
+    health_monitor.record_db_latency(await probe_db_latency(), now)
+    score = health_monitor.score(now)
+    if feature_flags.set_degraded(score.degraded):
+        disabled = [feature.value for feature in feature_flags.disabled()]
+        if score.degraded:
+            server_logger.warning(
+                f"Node degraded (db p95 {score.db_latency_ms}ms, error rate {score.error_rate}); disabled {disabled}"
+            )
+        else:
+            server_logger.info("Node healthy again; degraded features restored")
+        await lobby_websocket_manager.admin_web_socket_manager.broadcast_to_all(
+            HealthStatusEvent(
+                degraded=score.degraded,
+                db_latency_ms=score.db_latency_ms,
+                error_rate=score.error_rate,
+                disabled_features=disabled,
+            )
+        )
+    return score
+
+
+async def health_task():
+    while True:
+        try:
+            await health_tick(time.time())
+        except Exception as e:
+            server_logger.exception(f"Error checking node health: {e}")
+        await asyncio.sleep(settings.HEALTH_TICK_SECONDS)
+
+
+def start_health_monitor():
+    global _health_task
+    _health_task = asyncio.create_task(health_task())
+    server_logger.info("Started health monitor background task")
+
+
+def stop_health_monitor():
+    global _health_task
+    if _health_task:
+        _health_task.cancel()
+        _health_task = None
+        server_logger.info("Stopped health monitor background task")
@@ -0,0 +1,27 @@
+"""
+Process-wide state kept in memory: connection registries, sessions, counters and caches.
+
+Routes import these singletons directly, so they're reset in place rather than replaced. Each module that holds
+one registers how to reset it next to where it's created:
+
+    rate_limiter = RateLimiter()
+    on_reset(rate_limiter.__init__)
+
+AppBuilder.build() calls reset_in_memory_state() so every app it builds starts from nothing.
+"""
+
+from typing import Callable, List
+
+_reset_hooks: List[Callable[[], None]] = []
+
+
+def on_reset(hook: Callable[[], None]) -> Callable[[], None]:
+    """Run hook whenever in-memory state is reset, after the hooks registered before it."""
+    _reset_hooks.append(hook)
+    return hook
+
+
+def reset_in_memory_state():
+    """Clear every registered singleton."""
+    for hook in _reset_hooks:
+        hook()
@@ -0,0 +1,149 @@
+"""
+Intermission between rounds, for lobbies with intermission_enabled.
+
+When a round ends such a lobby goes to the intermission phase (see backend/game/lobby_state.py) instead of
+finished, and every screen shows the standings so far until the next round. The admin starts it with
+POST /api/admin/lobby/{lobby_id}/next-round, which plays it like the last one. With intermission_seconds set,
+the background task here does the same once that long has passed, checking every INTERMISSION_TICK_SECONDS.
+"""
+
+import asyncio
+from datetime import datetime, timedelta, timezone
+from typing import Optional
+
+from sqlmodel import Session, select
+
+from backend.api.lobby_phase import announce_phase_change, get_lobby_phase
+from backend.custom_logging import server_logger
+from backend.database import get_session_context
+from backend.database.models import Game, Lobby
+from backend.errors import AppError, ConflictError, NotFoundError
+from backend.game.lobby_state import LobbyPhase
+from backend.game.results import final_standings
+from backend.schemas import AdminStartGameRequest, NextRoundRequest
+from backend.settings import settings
+from backend.websocket.events import IntermissionStartedEvent
+
+_intermission_task: Optional[asyncio.Task] = None
+
+
+def auto_advance_at(lobby: Lobby) -> Optional[datetime]:
+    """When the lobby's intermission ends on its own, or None while it waits for the admin (or isn't in one)."""
+    started_at = lobby.intermission_started_at
+    if started_at is None or not lobby.intermission_seconds:
+        return None
+    if started_at.tzinfo is None:
+        started_at = started_at.replace(tzinfo=timezone.utc)  # SQLite hands datetimes back naive; stored as UTC
+    return started_at + timedelta(seconds=lobby.intermission_seconds)
+
+
+async def begin_intermission(db: Session, lobby_id: int, round_number: int) -> LobbyPhase:
+    """
+    Put the lobby into intermission after round_number, if it holds one between rounds.
+
+    Returns the phase the round leaves the lobby in, for the caller to announce: intermission, or finished.
+    """
+    from backend.websocket.managers import lobby_websocket_manager
+
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby or not lobby.intermission_enabled:
+        return LobbyPhase.FINISHED
+    lobby.intermission_started_at = datetime.now(timezone.utc)
+    db.add(lobby)
+    db.commit()
+
+    _, standings = final_standings(db, lobby_id, round_number)
+    advance_at = auto_advance_at(lobby)
+    server_logger.info(
+        f"Intermission after round {round_number}: lobby_id={lobby_id} "
+        f"auto_advance_at={advance_at.isoformat() if advance_at else None}"
+    )
+    await lobby_websocket_manager.broadcast_to_lobby(
+        lobby_id,
+        IntermissionStartedEvent(
+            lobby_id=lobby_id,
+            round_number=round_number,
+            standings=standings,
+            auto_advance_at=advance_at.isoformat() if advance_at else None,
+        ),
+    )
+    return LobbyPhase.INTERMISSION
+
+
+async def end_intermission(db: Session, lobby: Lobby):
+    """Leave intermission without starting a round, e.g. when the admin turns it off; the lobby is finished."""
+    if lobby.intermission_started_at is None:
+        return
+    previous_phase = get_lobby_phase(db, lobby.id)
+    lobby.intermission_started_at = None
+    db.add(lobby)
+    db.commit()
+    await announce_phase_change(lobby.id, previous_phase, get_lobby_phase(db, lobby.id))
+
+
+async def advance(db: Session, lobby_id: int, request: NextRoundRequest):
+    """
+    Start the next round from intermission, at the last round's difficulty unless the request picks another.
+
+    Players' ready flags are cleared at the end of every round, so the round is always force-started.
+    """
+    from backend.api.game import start_game
+
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        raise NotFoundError("Lobby not found")
+    if get_lobby_phase(db, lobby_id) != LobbyPhase.INTERMISSION:
+        raise ConflictError("The lobby isn't in intermission")
+
+    last_game = db.exec(
+        select(Game).where(Game.lobby_id == lobby_id).where(Game.puzzle_path != "").order_by(Game.id.desc())
+    ).first()
+    start_request = AdminStartGameRequest(
+        difficulty=request.difficulty or (last_game.difficulty if last_game else "medium"),
+        puzzle_mode=request.puzzle_mode,
+        word_count_mode=request.word_count_mode,
+        force_start=True,
+    )
+    return await start_game(lobby_id, start_request, session=db, is_admin=True)
+
+
+async def intermission_tick(now: datetime):
+    async with get_session_context() as db:
+        lobbies = db.exec(
+            select(Lobby).where(Lobby.intermission_started_at.isnot(None)).where(Lobby.intermission_seconds.isnot(None))
+        ).all()
+        for lobby in lobbies:
+            advance_at = auto_advance_at(lobby)
+            if advance_at is None or now < advance_at or get_lobby_phase(db, lobby.id) != LobbyPhase.INTERMISSION:
+                continue
+            try:
+                await advance(db, lobby.id, NextRoundRequest())
+                server_logger.info(f"Intermission over, next round started: lobby_id={lobby.id}")
+            except AppError as e:
+                # Left in intermission it would be retried every tick; finished, the admin can start a round by hand
+                server_logger.warning(f"Next round could not start after intermission: lobby_id={lobby.id} {e}")
+                db.rollback()
+                await end_intermission(db, lobby)
+
+
+async def intermission_task():
+    while True:
+        try:
+            await intermission_tick(datetime.now(timezone.utc))
+        except Exception as e:
+            server_logger.exception(f"Error advancing lobbies out of intermission: {e}")
+        await asyncio.sleep(settings.INTERMISSION_TICK_SECONDS)
+
+
+def start_intermission_task():
+    global _intermission_task
+    _intermission_task = asyncio.create_task(intermission_task())
+    server_logger.info("Started intermission background task")
+
+
+def stop_intermission_task():
+    global _intermission_task
+    if _intermission_task:
+        _intermission_task.cancel()
+        _intermission_task = None
+        server_logger.info("Stopped intermission background task")
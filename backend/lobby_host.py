@@ -0,0 +1,51 @@
+"""
+Lobby hosts: a player who can run a casual game without the admin panel.
+
+The first player to join a lobby becomes its host, and an admin can hand the role to someone else with
+PUT /api/admin/lobby/{lobby_id}/host/{player_id}. The host may create and shuffle teams and start the game through
+the /api/lobby/host/... endpoints. When the host leaves or is kicked, the role passes to the player who has been in
+the lobby longest. Bots are never hosts, and the server-run quick play lobby has none.
+"""
+
+from typing import Optional
+
+from sqlmodel import Session, select
+
+from backend.database import Lobby, Player
+from backend.websocket.events import HostChangedEvent
+
+
+def next_host(db: Session, lobby_id: int, leaving_player_id: Optional[int] = None) -> Optional[Player]:
+    """The player who has been in the lobby longest, leaving out bots and a player on their way out."""
+    query = select(Player).where(Player.lobby_id == lobby_id, Player.is_bot.is_(False))
+    if leaving_player_id is not None:
+        query = query.where(Player.id != leaving_player_id)
+    return db.exec(query.order_by(Player.join_order, Player.id)).first()
+
+
+def claim_host_if_free(lobby: Lobby, player: Player) -> bool:
+    """Make a joining player host of a lobby that doesn't have one; the caller commits."""
+    if lobby.host_player_id is not None or lobby.is_quick_play or player.is_bot:
+        return False
+    lobby.host_player_id = player.id
+    return True
+
+
+def hand_off_host(db: Session, lobby: Lobby, leaving_player_id: int) -> Optional[Player]:
+    """
+    Pass the role on when the host is leaving; the caller commits.
+
+    Returns the new host, or None when the leaving player wasn't host or nobody is left to take over.
+    """
+    if lobby.host_player_id != leaving_player_id:
+        return None
+    host = next_host(db, lobby.id, leaving_player_id)
+    lobby.host_player_id = host.id if host else None
+    db.add(lobby)
+    return host
+
+
+def host_changed_event(lobby_id: int, host: Player) -> HostChangedEvent:
+    return HostChangedEvent(
+        lobby_id=lobby_id, player_session_id=host.session_id, host_player_id=host.id, host_name=host.name
+    )
@@ -0,0 +1,77 @@
+"""In-memory per-day counters for the daily summary: request latency, error counts and peak concurrent players."""
+
+import logging
+from dataclasses import dataclass, field
+from datetime import date, datetime, timezone
+from typing import Dict, List, Optional
+
+from backend.in_memory_state import on_reset
+
+
+@dataclass
+class DayMetrics:
+    request_count: int = 0
+    error_log_count: int = 0
+    server_error_count: int = 0  # HTTP 5xx responses
+    peak_concurrent_players: int = 0
+    latencies_ms: List[float] = field(default_factory=list)
+
+    @property
+    def p99_latency_ms(self) -> Optional[float]:
+        return percentile(self.latencies_ms, 99)
+
+
+def percentile(values: List[float], pct: float) -> Optional[float]:
+    """Nearest-rank percentile, or None with no samples."""
+    if not values:
+        return None
+    ordered = sorted(values)
+    index = min(len(ordered) - 1, max(0, int(len(ordered) * pct / 100 + 0.5) - 1))
+    return ordered[index]
+
+
+def today() -> date:
+    return datetime.now(timezone.utc).date()
+
+
+class MetricsCollector:
+    """Collects metrics bucketed by UTC day until the daily summary task writes them out."""
+
+    def __init__(self):
+        self.days: Dict[date, DayMetrics] = {}
+
+    def day(self, day: Optional[date] = None) -> DayMetrics:
+        return self.days.setdefault(day or today(), DayMetrics())
+
+    def record_request(self, duration_ms: float, status_code: int, day: Optional[date] = None):
+        metrics = self.day(day)
+        metrics.request_count += 1
+        metrics.latencies_ms.append(duration_ms)
+        if status_code >= 500:
+            metrics.server_error_count += 1
+
+    def record_error_log(self, day: Optional[date] = None):
+        self.day(day).error_log_count += 1
+
+    def record_concurrent_players(self, count: int, day: Optional[date] = None):
+        metrics = self.day(day)
+        metrics.peak_concurrent_players = max(metrics.peak_concurrent_players, count)
+
+    def pop(self, day: date) -> DayMetrics:
+        """Remove and return a finished day's metrics (empty if nothing was recorded)."""
+        return self.days.pop(day, DayMetrics())
+
+
+class ErrorCountingHandler(logging.Handler):
+    """Counts ERROR and above records from the app loggers into the current day's metrics."""
+
+    def __init__(self, collector: MetricsCollector):
+        super().__init__(level=logging.ERROR)
+        self.collector = collector
+
+    def emit(self, record: logging.LogRecord):
+        self.collector.record_error_log()
+
+
+metrics_collector = MetricsCollector()
+on_reset(metrics_collector.__init__)
@@ -0,0 +1,143 @@
+"""
+Guesses a client queued while its connection was down, sent once it's back.
+
+Each queued guess carries a client_guess_id and the time it was typed (submitted_at). A guess whose id the player
+already sent is answered from the stored guess rather than judged again, so a client can resend its whole queue,
+or retry a request whose response it never got, without anything counting twice.
+
+Only POST /lobby/guess/batch dates a guess by its submitted_at, and only when OFFLINE_GUESS_GRACE_SECONDS is set
+and the time is at most that old: the guess is recorded at that time, and if it completes the ladder the team
+finishes at that time, so placements go by when a team actually solved it rather than when the venue wifi came
+back. It's never dated before the server last heard from the player (see PlayerSightings), so a client that was
+online all along can't claim an earlier time. Older and future timestamps are ignored and the guess counts from
+when it arrives.
+
+A dated guess is judged against the team's puzzle as it is now unless OFFLINE_GUESS_JUDGE_AS_TYPED is set. Then a
+right answer for a rung a teammate solved after it was typed still counts: the queued guess gets the solve and the
+teammate's later one is marked redundant, as if both had arrived in the order they were typed (see overtaken_solve).
+That's as far back as it goes: the team's progress is never rewound, and a guess for a round that has since ended,
+or a ladder the team has since finished, is refused either way, since its results have already been announced.
+"""
+
+from datetime import datetime, timedelta, timezone
+from typing import Dict, Optional
+
+from sqlmodel import Session, select
+
+from backend.database.models import Game, Guess
+from backend.game.puzzles import Puzzle
+from backend.in_memory_state import on_reset
+from backend.settings import settings
+
+CLIENT_GUESS_ID_MAX_LENGTH = 64
+MAX_QUEUED_GUESSES = 50
+
+
+def _as_utc(value: datetime) -> datetime:
+    # SQLite hands datetimes back naive; they're stored as UTC
+    return value.replace(tzinfo=timezone.utc) if value.tzinfo is None else value
+
+
+class PlayerSightings:
+    """When the server last heard from each player session, in memory, for dating queued guesses."""
+
+    def __init__(self):
+        self.disconnected_at: Dict[str, datetime] = {}  # Websocket drops not yet followed by a flushed queue
+        self.last_seen_at: Dict[str, datetime] = {}  # Authenticated HTTP requests
+
+    def disconnected(self, player_session_id: str, at: Optional[datetime] = None):
+        self.disconnected_at[player_session_id] = at or datetime.now(timezone.utc)
+
+    def seen(self, player_session_id: str, at: Optional[datetime] = None):
+        self.last_seen_at[player_session_id] = at or datetime.now(timezone.utc)
+
+    def offline_since(self, player_session_id: str, connected: bool, now: Optional[datetime] = None) -> datetime:
+        """
+        The earliest time a queued guess from the session may be dated.
+
+        That's when its websocket last dropped; failing that now if it's connected, since it has been online all
+        along, or else its last HTTP request. A session the server hasn't heard from since it started gets now.
+        """
+        now = now or datetime.now(timezone.utc)
+        if player_session_id in self.disconnected_at:
+            return self.disconnected_at[player_session_id]
+        if connected:
+            return now
+        return self.last_seen_at.get(player_session_id, now)
+
+    def flushed(self, player_session_id: str, at: Optional[datetime] = None):
+        """The session sent its queue; a later one can't reach back past this."""
+        self.disconnected_at.pop(player_session_id, None)
+        self.seen(player_session_id, at)
+
+
+player_sightings = PlayerSightings()
+on_reset(player_sightings.__init__)
+
+
+def guessed_at(
+    submitted_at: Optional[datetime], game: Game, offline_since: datetime, now: Optional[datetime] = None
+) -> Optional[datetime]:
+    """
+    When to date a queued guess the client says was typed at submitted_at, or None to date it on arrival.
+
+    Never before the player went offline, nor before the game started, so a client with a slow clock can't finish
+    a ladder before it was dealt.
+    """
+    if submitted_at is None or not settings.OFFLINE_GUESS_GRACE_SECONDS:
+        return None
+    now = now or datetime.now(timezone.utc)
+    submitted_at = _as_utc(submitted_at)
+    if not now - timedelta(seconds=settings.OFFLINE_GUESS_GRACE_SECONDS) <= submitted_at < now:
+        return None
+    dated = max(submitted_at, _as_utc(game.started_at), _as_utc(offline_since))
+    return dated if dated < now else None
+
+
+def find_replayed_guess(db: Session, player_id: int, client_guess_id: Optional[str]) -> Optional[Guess]:
+    """The guess the player already sent under client_guess_id, if any."""
+    if not client_guess_id:
+        return None
+    return db.exec(
+        select(Guess).where(Guess.player_id == player_id).where(Guess.client_guess_id == client_guess_id)
+    ).first()
+
+
+def overtaken_solve(db: Session, puzzle: Puzzle, guess: Guess) -> Optional[Guess]:
+    """
+    The solve a dated queued guess was beaten to by a guess typed after it, if judging as typed gives it the rung.
+
+    Only with OFFLINE_GUESS_JUDGE_AS_TYPED, and only when the queued guess is one of the rung's accepted answers;
+    open-ended rungs, which the host judged, and rungs revealed without a guess keep their solve.
+    """
+    if not settings.OFFLINE_GUESS_JUDGE_AS_TYPED or guess.created_at is None:
+        return None
+    step = puzzle.ladder[guess.word_index]
+    if step.open_ended or guess.guess.upper() not in step.accepted_answers():
+        return None
+    solve = db.exec(
+        select(Guess)
+        .where(Guess.game_id == guess.game_id)
+        .where(Guess.word_index == guess.word_index)
+        .where(Guess.is_correct.is_(True))
+        .order_by(Guess.created_at)
+    ).first()
+    if solve and _as_utc(solve.created_at) > _as_utc(guess.created_at):
+        return solve
+    return None
+
+
+def replayed_outcome(guess: Guess, revealed_steps: set[int]) -> str:
+    """
+    The result to send again for a stored guess, in GuessSubmitResponse's terms.
+
+    A wrong guess isn't stored apart from one for a rung that was already solved, so a wrong guess at a rung that
+    has been solved since also comes back as already_solved; either way there's nothing left for the client to do.
+    """
+    if guess.is_correct or guess.review_status == "approved":
+        return "correct"
+    if guess.review_status == "pending":
+        return "pending"
+    if guess.review_status is None and guess.word_index in revealed_steps:
+        return "already_solved"
+    return "wrong"
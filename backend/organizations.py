@@ -0,0 +1,95 @@
+"""
+Organizations: independent groups hosting games on one shared deployment.
+
+Each organization has its own admin password. Logging in with it (POST /api/admin/login with the organization's
+slug) gives a session scoped to that organization: its admins only see and change its own lobbies and stored
+puzzles, and deployment-wide pages (traffic, health, retention...) are off limits. Admins using ADMIN_PASSWORD
+aren't scoped and see everything, including lobbies that belong to no organization.
+
+check_admin_token enforces the scope for every admin route from the ids in its path and the query parameters naming a
+lobby to read from (see check_organization_scope), so routes only need to filter their list endpoints and stamp new
+rows with the organization.
+"""
+
+import hashlib
+import hmac
+import secrets
+from typing import Mapping, Optional
+
+from sqlmodel import Session
+
+from backend.database.models import Guess, Lobby, Player, Puzzle, Team
+from backend.errors import NotFoundError
+
+PASSWORD_HASH_ITERATIONS = 200_000
+
+
+def hash_password(password: str) -> str:
+    salt = secrets.token_hex(16)
+    digest = hashlib.pbkdf2_hmac("sha256", password.encode("utf-8"), bytes.fromhex(salt), PASSWORD_HASH_ITERATIONS)
+    return f"{salt}${digest.hex()}"
+
+
+def verify_password(password: str, password_hash: str) -> bool:
+    salt, _, expected = password_hash.partition("$")
+    digest = hashlib.pbkdf2_hmac("sha256", password.encode("utf-8"), bytes.fromhex(salt), PASSWORD_HASH_ITERATIONS)
+    return hmac.compare_digest(digest.hex(), expected)
+
+
+def lobby_organization(db: Session, lobby_id: Optional[int]) -> tuple[bool, Optional[int]]:
+    lobby = db.get(Lobby, lobby_id) if lobby_id is not None else None
+    return (lobby is not None, lobby.organization_id if lobby else None)
+
+
+def resource_organization(db: Session, label: str, resource_id: int) -> tuple[bool, Optional[int]]:
+    """(exists, organization_id) for the lobby, team, player, guess or stored puzzle with the id."""
+    if label == "Lobby":
+        return lobby_organization(db, resource_id)
+    if label == "Team":
+        team = db.get(Team, resource_id)
+        return lobby_organization(db, team.lobby_id if team else None)
+    if label == "Player":
+        player = db.get(Player, resource_id)
+        return lobby_organization(db, player.lobby_id if player else None)
+    if label == "Guess":
+        guess = db.get(Guess, resource_id)
+        team = db.get(Team, guess.team_id) if guess else None
+        return lobby_organization(db, team.lobby_id if team else None)
+    if label == "Puzzle":
+        puzzle = db.get(Puzzle, resource_id)
+        return (puzzle is not None, puzzle.organization_id if puzzle else None)
+    return (False, None)
+
+
+SCOPED_PATH_PARAMS = {
+    "lobby_id": "Lobby",
+    "source_id": "Lobby",  # Lobby merges
+    "target_id": "Lobby",
+    "team_id": "Team",
+    "player_id": "Player",
+    "guess_id": "Guess",
+    "puzzle_id": "Puzzle",  # Stored puzzles; date ids (YYYY-MM-DD) are puzzle files every organization shares
+}
+
+# Query parameters naming something a route reads from, like the lobby players are imported from
+SCOPED_QUERY_PARAMS = {
+    "from_lobby": "Lobby",
+}
+
+
+def check_organization_scope(
+    db: Session, organization_id: int, path_params: Mapping[str, str], query_params: Mapping[str, str]
+):
+    """
+    404 when a path or query parameter names something outside the organization, as if it didn't exist.
+
+    Ids that really don't exist are left for the route to 404 on in its own words.
+    """
+    for params, scoped in [(path_params, SCOPED_PATH_PARAMS), (query_params, SCOPED_QUERY_PARAMS)]:
+        for param, label in scoped.items():
+            value = params.get(param)
+            if value is None or not str(value).isdigit():
+                continue
+            exists, owner = resource_organization(db, label, int(value))
+            if exists and owner != organization_id:
+                raise NotFoundError(f"{label} not found")
@@ -0,0 +1,151 @@
+"""
+Background "pace keeper" for lobbies with pace_keeper_enabled.
+
+Every PACE_KEEPER_TICK_SECONDS it looks at each such lobby's running round, works out the median solve time across
+all teams, and when the slowest team has been stuck for PACE_KEEPER_STALL_MULTIPLIER times that, reveals the first
+letters of its next rung (see backend/game/pacing.py). Hints are recorded as PaceHint rows so they escalate across
+ticks and restarts, and are sent to the team and the lobby's admins.
+"""
+
+import asyncio
+import json
+from datetime import datetime, timezone
+from typing import Optional
+
+from sqlmodel import Session, select
+
+from backend.custom_logging import server_logger
+from backend.database import get_session_context
+from backend.database.models import Game, Guess, Lobby, PaceHint, Team
+from backend.game.pacing import (
+    TeamPace,
+    due_hint_level,
+    hint_text,
+    median_solve_seconds,
+    next_rung,
+    slowest_team,
+    solve_durations,
+)
+from backend.settings import settings
+from backend.websocket.events import PaceHintEvent
+
+_pace_keeper_task: Optional[asyncio.Task] = None
+
+
+def as_aware(value: datetime) -> datetime:
+    # SQLite hands datetimes back naive; they're stored in UTC
+    return value.replace(tzinfo=timezone.utc) if value.tzinfo is None else value
+
+
+async def keep_pace(db: Session, lobby: Lobby, now: datetime) -> Optional[PaceHint]:
+    """Grant the lobby's slowest team a hint if it's due one; returns the hint granted, if any."""
+    from backend.api.game import get_team_state_machine
+    from backend.websocket.managers import lobby_websocket_manager
+
+    rows = db.exec(
+        select(Team, Game).join(Game, Team.game_id == Game.id).where(Team.lobby_id == lobby.id, Game.puzzle_path != "")
+    ).all()
+    if not any(game.completed_at is None for _, game in rows):
+        return None
+
+    game_ids = [game.id for _, game in rows]
+    solves = {}
+    for game_id, created_at in db.exec(
+        select(Guess.game_id, Guess.created_at).where(Guess.game_id.in_(game_ids), Guess.is_correct.is_(True))
+    ).all():
+        solves.setdefault(game_id, []).append(as_aware(created_at))
+    hint_times = {}
+    for game_id, created_at in db.exec(
+        select(PaceHint.game_id, PaceHint.created_at).where(PaceHint.game_id.in_(game_ids))
+    ).all():
+        hint_times.setdefault(game_id, []).append(as_aware(created_at))
+
+    durations = []
+    stalled = {}
+    for team, game in rows:
+        started_at = as_aware(game.started_at)
+        durations.extend(solve_durations(started_at, solves.get(game.id, [])))
+        if game.completed_at is not None:
+            continue
+        stalled_since = max(solves.get(game.id, []), default=started_at)
+        revealed = json.loads(game.revealed_steps) if isinstance(game.revealed_steps, str) else game.revealed_steps
+        stalled[team.id] = (
+            team,
+            game,
+            TeamPace(
+                team_id=team.id,
+                revealed_count=len(revealed or []),
+                stalled_since=stalled_since,
+                hints_since_solve=sum(1 for hinted_at in hint_times.get(game.id, []) if hinted_at >= stalled_since),
+            ),
+        )
+
+    median_seconds = median_solve_seconds(durations, settings.PACE_KEEPER_MIN_SOLVES)
+    slowest = slowest_team(pace for _, _, pace in stalled.values())
+    if median_seconds is None or slowest is None:
+        return None
+    level = due_hint_level(
+        slowest, median_seconds, now, settings.PACE_KEEPER_STALL_MULTIPLIER, settings.PACE_KEEPER_MAX_HINT_LEVEL
+    )
+    if level is None:
+        return None
+
+    team, game, _ = stalled[slowest.team_id]
+    machine = get_team_state_machine(team, game)
+    word_index = next_rung(machine.state.revealed_steps, len(machine.puzzle.ladder))
+    if word_index is None:
+        return None
+
+    hint = PaceHint(
+        game_id=game.id,
+        team_id=team.id,
+        word_index=word_index,
+        level=level,
+        hint=hint_text(machine.puzzle.ladder[word_index].word, level),
+        created_at=now,
+    )
+    db.add(hint)
+    db.commit()
+    db.refresh(hint)
+    server_logger.info(
+        f"Pace keeper hint: lobby_id={lobby.id} team_id={team.id} word_index={word_index} level={level} "
+        f"(median solve {median_seconds:.0f}s)"
+    )
+
+    event = PaceHintEvent(team_id=team.id, word_index=word_index, level=level, hint=hint.hint)
+    await lobby_websocket_manager.broadcast_to_team(lobby.id, team.id, event)
+    await lobby_websocket_manager.admin_web_socket_manager.broadcast_to_lobby(lobby.id, event)
+    return hint
+
+
+async def pace_keeper_tick(now: datetime):
+    async with get_session_context() as db:
+        lobbies = db.exec(select(Lobby).where(Lobby.pace_keeper_enabled.is_(True))).all()
+        for lobby in lobbies:
+            try:
+                await keep_pace(db, lobby, now)
+            except Exception as e:
+                server_logger.exception(f"Pace keeper failed for lobby_id={lobby.id}: {e}")
+
+
+async def pace_keeper_task():
+    while True:
+        try:
+            await pace_keeper_tick(datetime.now(timezone.utc))
+        except Exception as e:
+            server_logger.exception(f"Error running pace keeper: {e}")
+        await asyncio.sleep(settings.PACE_KEEPER_TICK_SECONDS)
+
+
+def start_pace_keeper():
+    global _pace_keeper_task
+    _pace_keeper_task = asyncio.create_task(pace_keeper_task())
+    server_logger.info("Started pace keeper background task")
+
+
+def stop_pace_keeper():
+    global _pace_keeper_task
+    if _pace_keeper_task:
+        _pace_keeper_task.cancel()
+        _pace_keeper_task = None
+        server_logger.info("Stopped pace keeper background task")
@@ -0,0 +1,208 @@
+"""
+Always-open "quick play" lobby, run entirely by a background task.
+
+When QUICK_PLAY_ENABLED is set the server keeps one lobby (code QUICK_PLAY_CODE) alive. Players who join are
+grouped into teams of QUICK_PLAY_TEAM_SIZE as they arrive; once the lobby has had players for
+QUICK_PLAY_INTERMISSION_SECONDS a short round starts on its own, with a round timer so the timer poller ends
+and scores it. Late joiners drop onto the smallest team mid-round, and the loop repeats after each round.
+"""
+
+import asyncio
+from datetime import datetime, timedelta, timezone
+from typing import Iterable, List, Optional, Tuple
+
+from sqlmodel import Session, func, select
+
+from backend.api.admin.lobby.timer_poller import arm_round_timer
+from backend.api.lobby_phase import lobby_has_active_game
+from backend.custom_logging import server_logger
+from backend.database import get_session_context
+from backend.database.models import Lobby, Player, Team
+from backend.errors import AppError
+from backend.in_memory_state import on_reset
+from backend.roster import bump_roster_version
+from backend.settings import settings
+from backend.utils.team_colors import pick_team_colors
+from backend.websocket.events import TeamAssignedEvent
+
+# Lobby codes are otherwise 6 characters (see backend/utils/lobby_codes.py), so this can't collide with a host's lobby
+QUICK_PLAY_CODE = "QUICK"
+QUICK_PLAY_NAME = "Quick Play"
+
+_quick_play_task: Optional[asyncio.Task] = None
+
+
+class QuickPlayState:
+    """In-memory countdown to the next round; lost on restart, which only delays the next round."""
+
+    def __init__(self):
+        self.next_round_at: Optional[datetime] = None
+        self.rounds_started = 0
+
+
+quick_play_state = QuickPlayState()
+on_reset(quick_play_state.__init__)
+
+
+def plan_team_assignments(team_counts: Iterable[Tuple[int, int]], waiting: int, team_size: int) -> List[int]:
+    """
+    Pick a team for each waiting player, filling the fullest team with room first so teams reach `team_size`.
+
+    Args:
+        team_counts: (team_id, player_count) for existing teams
+        waiting: Number of unassigned players, in join order
+        team_size: Target players per team
+
+    Returns:
+        A team id per waiting player; negative ids (-1, -2, ...) are new teams to create
+    """
+    counts = dict(team_counts)
+    plan = []
+    new_teams = 0
+    for _ in range(waiting):
+        open_teams = [team_id for team_id, count in counts.items() if count < team_size]
+        if open_teams:
+            team_id = max(open_teams, key=lambda t: (counts[t], -abs(t)))
+        else:
+            new_teams += 1
+            team_id = -new_teams
+            counts[team_id] = 0
+        counts[team_id] += 1
+        plan.append(team_id)
+    return plan
+
+
+def ensure_quick_play_lobby(db: Session) -> Lobby:
+    lobby = db.exec(select(Lobby).where(Lobby.is_quick_play.is_(True))).first()
+    if lobby:
+        return lobby
+    lobby = Lobby(code=QUICK_PLAY_CODE, name=QUICK_PLAY_NAME, is_quick_play=True, late_join_enabled=True)
+    db.add(lobby)
+    db.commit()
+    db.refresh(lobby)
+    server_logger.info(f"Created quick play lobby id={lobby.id} code={lobby.code}")
+    return lobby
+
+
+async def form_teams(db: Session, lobby: Lobby) -> int:
+    """Drop teams everyone has left and place waiting players; returns how many players were placed."""
+    from backend.api.admin.lobby.team import generate_unique_team_name
+    from backend.websocket.managers import lobby_websocket_manager
+
+    teams = db.exec(select(Team).where(Team.lobby_id == lobby.id).order_by(Team.id)).all()
+    counts = dict(
+        db.exec(
+            select(Player.team_id, func.count(Player.id))
+            .where(Player.lobby_id == lobby.id)
+            .where(Player.team_id.isnot(None))
+            .group_by(Player.team_id)
+        ).all()
+    )
+    for team in teams:
+        if not counts.get(team.id):
+            db.delete(team)
+    teams = [team for team in teams if counts.get(team.id)]
+
+    waiting = db.exec(
+        select(Player)
+        .where(Player.lobby_id == lobby.id)
+        .where(Player.team_id.is_(None))
+        .order_by(Player.created_at, Player.id)
+    ).all()
+    if not waiting:
+        db.commit()
+        return 0
+
+    plan = plan_team_assignments(
+        [(team.id, counts[team.id]) for team in teams], len(waiting), settings.QUICK_PLAY_TEAM_SIZE
+    )
+    existing_names = {team.name for team in teams}
+    new_team_keys = sorted({key for key in plan if key < 0}, reverse=True)
+    new_team_colors = pick_team_colors([team.color for team in teams], len(new_team_keys), settings.TEAM_COLOR_PALETTE)
+    new_teams = {}
+    for team_key, color in zip(new_team_keys, new_team_colors):
+        name = generate_unique_team_name(existing_names)
+        existing_names.add(name)
+        new_teams[team_key] = Team(name=name, color=color, lobby_id=lobby.id)
+        db.add(new_teams[team_key])
+    db.flush()
+
+    for player, team_key in zip(waiting, plan):
+        player.team_id = new_teams[team_key].id if team_key < 0 else team_key
+        player.is_ready = True  # Nobody readies up in quick play; rounds start on the countdown
+        db.add(player)
+    roster_version = bump_roster_version(db, lobby.id)
+    db.commit()
+
+    for player in waiting:
+        lobby_websocket_manager.register_player_team(player.session_id, player.team_id)
+    await lobby_websocket_manager.broadcast_to_lobby(
+        lobby.id,
+        TeamAssignedEvent(lobby_id=lobby.id, player_session_id=waiting[-1].session_id, roster_version=roster_version),
+    )
+    server_logger.info(f"Quick play placed {len(waiting)} players ({len(new_teams)} new teams)")
+    return len(waiting)
+
+
+async def start_round(db: Session, lobby: Lobby):
+    from backend.api.game import start_game
+    from backend.schemas import AdminStartGameRequest
+
+    request = AdminStartGameRequest(
+        difficulty=settings.QUICK_PLAY_DIFFICULTY, puzzle_mode="different", force_start=True
+    )
+    try:
+        await start_game(lobby.id, request, session=db, is_admin=True)
+    except AppError as e:
+        server_logger.warning(f"Quick play round could not start: {e.message}")
+        return
+    await arm_round_timer(db, lobby.id, int(settings.QUICK_PLAY_ROUND_SECONDS))
+    quick_play_state.rounds_started += 1
+    server_logger.info(f"Quick play round {quick_play_state.rounds_started} started in lobby_id={lobby.id}")
+
+
+async def quick_play_tick(now: datetime):
+    async with get_session_context() as db:
+        lobby = ensure_quick_play_lobby(db)
+        if lobby_has_active_game(db, lobby.id):
+            quick_play_state.next_round_at = None
+            return
+
+        await form_teams(db, lobby)
+        has_players = db.exec(select(Player.id).where(Player.lobby_id == lobby.id)).first() is not None
+        if not has_players:
+            quick_play_state.next_round_at = None
+            return
+
+        if quick_play_state.next_round_at is None:
+            quick_play_state.next_round_at = now + timedelta(seconds=settings.QUICK_PLAY_INTERMISSION_SECONDS)
+            return
+        if now >= quick_play_state.next_round_at:
+            quick_play_state.next_round_at = None
+            await start_round(db, lobby)
+
+
+async def quick_play_task():
+    while True:
+        try:
+            await quick_play_tick(datetime.now(timezone.utc))
+        except Exception as e:
+            server_logger.exception(f"Error running quick play lobby: {e}")
+        await asyncio.sleep(settings.QUICK_PLAY_TICK_SECONDS)
+
+
+def start_quick_play():
+    global _quick_play_task
+    if not settings.QUICK_PLAY_ENABLED:
+        server_logger.info("Quick play disabled; not starting quick play task")
+        return
+    _quick_play_task = asyncio.create_task(quick_play_task())
+    server_logger.info("Started quick play background task")
+
+
+def stop_quick_play():
+    global _quick_play_task
+    if _quick_play_task:
+        _quick_play_task.cancel()
+        _quick_play_task = None
+        server_logger.info("Stopped quick play background task")
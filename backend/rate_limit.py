@@ -0,0 +1,85 @@
+"""
+Per-minute limits on joining lobbies and submitting guesses, so one client can't spam joins or brute-force answers.
+
+Each limit counts requests over a sliding one-minute window. Joins are counted per client address, since there's
+no session yet; guesses are counted per player session, over HTTP and the websocket together, so teammates behind
+the same wifi don't eat into each other's share. Counts live in memory, per process, like the socket cap in
+backend/websocket/admission.py.
+"""
+
+import math
+import time
+from collections import deque
+from typing import Deque, Dict, Optional, Tuple
+
+from fastapi import Depends, Request
+
+from backend.custom_logging import api_logger
+from backend.database import Player
+from backend.dependencies import require_player_session
+from backend.errors import TooManyRequestsError
+from backend.in_memory_state import on_reset
+from backend.settings import settings
+
+WINDOW_SECONDS = 60.0
+
+
+class RateLimiter:
+    def __init__(self):
+        self.hits: Dict[Tuple[str, str], Deque[float]] = {}
+        self.next_sweep = 0.0
+
+    def retry_after(self, bucket: str, key: str, limit: int, now: Optional[float] = None) -> Optional[int]:
+        """
+        Count a request from key against limit per minute.
+
+        Returns None when it's allowed, or the whole seconds until key may try again (the request isn't counted).
+        A limit of 0 allows everything.
+        """
+        if not limit:
+            return None
+        now = time.monotonic() if now is None else now
+        self.sweep(now)
+        hits = self.hits.setdefault((bucket, key), deque())
+        while hits and hits[0] <= now - WINDOW_SECONDS:
+            hits.popleft()
+        if len(hits) >= limit:
+            return max(1, math.ceil(hits[0] + WINDOW_SECONDS - now))
+        hits.append(now)
+        return None
+
+    def sweep(self, now: float):
+        """Forget keys with nothing in the window, at most once a window, so one-off clients don't pile up."""
+        if now < self.next_sweep:
+            return
+        self.next_sweep = now + WINDOW_SECONDS
+        stale = [key for key, hits in self.hits.items() if not hits or hits[-1] <= now - WINDOW_SECONDS]
+        for key in stale:
+            del self.hits[key]
+
+
+rate_limiter = RateLimiter()
+on_reset(rate_limiter.__init__)
+
+
+def guess_retry_after(session_id: str) -> Optional[int]:
+    """Count a guess from the player with session_id; see RateLimiter.retry_after."""
+    return rate_limiter.retry_after("guess", session_id, settings.RATE_LIMIT_GUESSES_PER_MINUTE)
+
+
+def limit_joins(request: Request):
+    """Refuse a join with 429 once the client's address has used up RATE_LIMIT_JOINS_PER_MINUTE."""
+    ip = request.client.host if request.client else "unknown"
+    wait = rate_limiter.retry_after("join", ip, settings.RATE_LIMIT_JOINS_PER_MINUTE)
+    if wait is not None:
+        api_logger.warning(f"Join rate limited: ip={ip} retry_after={wait}")
+        raise TooManyRequestsError("Too many joins from this address", retry_after=wait)
+
+
+def limit_guesses(player: Player = Depends(require_player_session)) -> Player:
+    """Refuse a guess with 429 once the player has used up RATE_LIMIT_GUESSES_PER_MINUTE."""
+    wait = guess_retry_after(player.session_id)
+    if wait is not None:
+        api_logger.warning(f"Guess rate limited: session_id={player.session_id} retry_after={wait}")
+        raise TooManyRequestsError("Too many guesses, slow down", retry_after=wait)
+    return player
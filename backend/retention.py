@@ -0,0 +1,182 @@
+"""
+Background pruning of old guess logs and team chat so the database doesn't grow without bound.
+
+Events aren't stored in the database: the ones a reconnecting player missed are only kept in memory, in the replay
+buffer bounded by WS_REPLAY_BUFFER_SIZE and WS_REPLAY_MAX_BYTES, so there's no event log to prune here.
+"""
+
+import asyncio
+from collections import defaultdict
+from dataclasses import dataclass
+from datetime import datetime, timedelta, timezone
+from typing import Dict, Iterable, Optional, Set, Tuple
+
+from sqlalchemy import delete
+from sqlmodel import select
+
+from backend.custom_logging import server_logger
+from backend.database import get_session_context
+from backend.database.models import ChatMessage, Game, Guess
+from backend.in_memory_state import on_reset
+from backend.settings import settings
+
+# Keep IN (...) lists under SQLite's bound-parameter limit
+DELETE_BATCH_SIZE = 500
+
+
+@dataclass
+class RetentionMetrics:
+    runs: int = 0
+    guesses_pruned_total: int = 0
+    guesses_pruned_last_run: int = 0
+    chat_messages_pruned_total: int = 0
+    chat_messages_pruned_last_run: int = 0
+    last_run_at: Optional[datetime] = None
+
+
+retention_metrics = RetentionMetrics()
+on_reset(retention_metrics.__init__)
+
+_retention_task: Optional[asyncio.Task] = None
+
+
+def retention_enabled() -> bool:
+    return any(
+        limit > 0
+        for limit in (
+            settings.GUESS_RETENTION_DAYS,
+            settings.GUESS_MAX_ROWS_PER_LOBBY,
+            settings.CHAT_RETENTION_DAYS,
+            settings.CHAT_MAX_ROWS_PER_LOBBY,
+        )
+    )
+
+
+def select_rows_to_prune(
+    rows: Iterable[Tuple[int, int, datetime]],
+    now: datetime,
+    max_age_days: int,
+    max_rows_per_lobby: int,
+) -> Set[int]:
+    """
+    Pick row ids (guesses or chat lines) that fall outside the retention window.
+
+    Args:
+        rows: (row_id, lobby_id, created_at) for rows eligible for pruning
+        now: Current time (timezone-aware)
+        max_age_days: Prune rows older than this many days; 0 keeps them regardless of age
+        max_rows_per_lobby: Keep only the newest this-many rows per lobby; 0 disables the cap
+
+    Returns:
+        Set of row ids to delete
+    """
+    cutoff = now - timedelta(days=max_age_days) if max_age_days > 0 else None
+    to_prune: Set[int] = set()
+    by_lobby: Dict[int, list[Tuple[datetime, int]]] = defaultdict(list)
+
+    for row_id, lobby_id, created_at in rows:
+        if created_at.tzinfo is None:
+            created_at = created_at.replace(tzinfo=timezone.utc)
+        if cutoff and created_at < cutoff:
+            to_prune.add(row_id)
+        else:
+            by_lobby[lobby_id].append((created_at, row_id))
+
+    if max_rows_per_lobby > 0:
+        for lobby_rows in by_lobby.values():
+            lobby_rows.sort(reverse=True)
+            to_prune.update(row_id for _, row_id in lobby_rows[max_rows_per_lobby:])
+
+    return to_prune
+
+
+async def prune_guesses() -> int:
+    """
+    Delete guesses outside the retention window, returning how many were removed.
+
+    Guesses from games still in progress are never pruned, since live scoring and stats read them.
+    """
+    max_age_days = settings.GUESS_RETENTION_DAYS
+    max_rows_per_lobby = settings.GUESS_MAX_ROWS_PER_LOBBY
+    if max_age_days <= 0 and max_rows_per_lobby <= 0:
+        return 0
+
+    async with get_session_context() as session:
+        rows = session.exec(
+            select(Guess.id, Game.lobby_id, Guess.created_at)
+            .join(Game, Guess.game_id == Game.id)
+            .where(Game.completed_at.isnot(None))
+        ).all()
+        to_prune = select_rows_to_prune(rows, datetime.now(timezone.utc), max_age_days, max_rows_per_lobby)
+        guess_ids = sorted(to_prune)
+        for start in range(0, len(guess_ids), DELETE_BATCH_SIZE):
+            session.execute(delete(Guess).where(Guess.id.in_(guess_ids[start : start + DELETE_BATCH_SIZE])))
+        if guess_ids:
+            session.commit()
+    return len(to_prune)
+
+
+async def prune_chat_messages() -> int:
+    """
+    Delete team chat lines outside the retention window, returning how many were removed.
+
+    Lines in lobbies with a game still in progress are kept, since reconnecting players catch up from them.
+    """
+    max_age_days = settings.CHAT_RETENTION_DAYS
+    max_rows_per_lobby = settings.CHAT_MAX_ROWS_PER_LOBBY
+    if max_age_days <= 0 and max_rows_per_lobby <= 0:
+        return 0
+
+    async with get_session_context() as session:
+        playing = select(Game.lobby_id).where(Game.completed_at.is_(None))
+        rows = session.exec(
+            select(ChatMessage.id, ChatMessage.lobby_id, ChatMessage.created_at).where(
+                ChatMessage.lobby_id.not_in(playing)
+            )
+        ).all()
+        to_prune = select_rows_to_prune(rows, datetime.now(timezone.utc), max_age_days, max_rows_per_lobby)
+        message_ids = sorted(to_prune)
+        for start in range(0, len(message_ids), DELETE_BATCH_SIZE):
+            batch = message_ids[start : start + DELETE_BATCH_SIZE]
+            session.execute(delete(ChatMessage).where(ChatMessage.id.in_(batch)))
+        if message_ids:
+            session.commit()
+    return len(to_prune)
+
+
+async def retention_task():
+    interval_seconds = settings.RETENTION_PRUNE_INTERVAL_MINUTES * 60
+    while True:
+        try:
+            pruned = await prune_guesses()
+            chat_pruned = await prune_chat_messages()
+            retention_metrics.runs += 1
+            retention_metrics.guesses_pruned_last_run = pruned
+            retention_metrics.guesses_pruned_total += pruned
+            retention_metrics.chat_messages_pruned_last_run = chat_pruned
+            retention_metrics.chat_messages_pruned_total += chat_pruned
+            retention_metrics.last_run_at = datetime.now(timezone.utc)
+            server_logger.info(
+                f"Retention pruning removed {pruned} guesses (total={retention_metrics.guesses_pruned_total}) "
+                f"and {chat_pruned} chat lines (total={retention_metrics.chat_messages_pruned_total})"
+            )
+        except Exception as e:
+            server_logger.exception(f"Error pruning guess and chat history: {e}")
+        await asyncio.sleep(interval_seconds)
+
+
+def start_retention_task():
+    global _retention_task
+    if not retention_enabled():
+        server_logger.info("Guess and chat retention disabled; not starting pruning task")
+        return
+    _retention_task = asyncio.create_task(retention_task())
+    server_logger.info("Started retention pruning background task")
+
+
+def stop_retention_task():
+    global _retention_task
+    if _retention_task:
+        _retention_task.cancel()
+        _retention_task = None
+        server_logger.info("Stopped retention pruning background task")
@@ -0,0 +1,109 @@
+"""
+Answer reveals in progress, and the background task that paces the timed ones.
+
+Reveals are kept in memory (see backend/game/reveal.py for the stepping itself): a restart only drops the
+ceremony, and the host can start it again. Every REVEAL_TICK_SECONDS the task moves timed reveals whose step is
+due on to the next one, ending them after their last step.
+"""
+
+import asyncio
+from datetime import datetime, timezone
+from typing import Dict, Optional
+
+from backend.custom_logging import server_logger
+from backend.game.reveal import RevealSession
+from backend.in_memory_state import on_reset
+from backend.settings import settings
+from backend.websocket.events import RevealEndedEvent, RevealStepEvent
+
+_reveal_task: Optional[asyncio.Task] = None
+
+
+class RevealStore:
+    def __init__(self):
+        self.sessions: Dict[int, RevealSession] = {}
+
+    def get(self, lobby_id: int) -> Optional[RevealSession]:
+        return self.sessions.get(lobby_id)
+
+    def start(self, lobby_id: int, session: RevealSession):
+        self.sessions[lobby_id] = session
+
+    def forget(self, lobby_id: int) -> Optional[RevealSession]:
+        return self.sessions.pop(lobby_id, None)
+
+
+reveal_store = RevealStore()
+on_reset(reveal_store.__init__)
+
+
+async def advance_reveal(lobby_id: int, now: datetime) -> Optional[RevealStepEvent]:
+    """Broadcast the lobby's next reveal step, or end the reveal when there's none left; returns the step sent."""
+    from backend.websocket.managers import lobby_websocket_manager
+
+    session = reveal_store.get(lobby_id)
+    if session is None:
+        return None
+    step = session.advance(now)
+    if step is None:
+        await end_reveal(lobby_id, completed=True)
+        return None
+
+    event = RevealStepEvent(
+        lobby_id=lobby_id,
+        step_number=session.position + 1,
+        total_steps=len(session.steps),
+        puzzle_title=step.puzzle_title,
+        puzzle_number=step.puzzle_number,
+        puzzle_count=session.steps[-1].puzzle_number,
+        word_index=step.word_index,
+        word=step.word,
+        explanation=step.explanation,
+        next_step_in_seconds=session.interval_seconds,
+    )
+    await lobby_websocket_manager.broadcast_to_lobby(lobby_id, event)
+    return event
+
+
+async def end_reveal(lobby_id: int, completed: bool) -> bool:
+    """Stop the lobby's reveal and tell everyone; False when there was none running."""
+    from backend.websocket.managers import lobby_websocket_manager
+
+    if reveal_store.forget(lobby_id) is None:
+        return False
+    server_logger.info(f"Answer reveal ended: lobby_id={lobby_id} completed={completed}")
+    event = RevealEndedEvent(lobby_id=lobby_id, completed=completed)
+    await lobby_websocket_manager.broadcast_to_lobby(lobby_id, event)
+    return True
+
+
+async def reveal_tick(now: datetime):
+    for lobby_id, session in list(reveal_store.sessions.items()):
+        if session.is_due(now):
+            try:
+                await advance_reveal(lobby_id, now)
+            except Exception as e:
+                server_logger.exception(f"Answer reveal failed for lobby_id={lobby_id}: {e}")
+
+
+async def reveal_task():
+    while True:
+        try:
+            await reveal_tick(datetime.now(timezone.utc))
+        except Exception as e:
+            server_logger.exception(f"Error pacing answer reveals: {e}")
+        await asyncio.sleep(settings.REVEAL_TICK_SECONDS)
+
+
+def start_reveal_task():
+    global _reveal_task
+    _reveal_task = asyncio.create_task(reveal_task())
+    server_logger.info("Started answer reveal background task")
+
+
+def stop_reveal_task():
+    global _reveal_task
+    if _reveal_task:
+        _reveal_task.cancel()
+        _reveal_task = None
+        server_logger.info("Stopped answer reveal background task")
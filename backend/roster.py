@@ -0,0 +1,34 @@
+"""
+Stable roster ordering and a version number for noticing roster changes.
+
+Each player gets a join_order when they enter a lobby, one more than the last player who joined, and rosters are
+always listed by it (then by id, for players that share one). Anything that changes who is in a lobby, which team
+they're on, whether they're ready, or the teams themselves bumps Lobby.roster_version in the same transaction, and the
+roster events carry the new value. A client compares it with the version it last rendered and only then fetches
+GET /api/lobby/{lobby_id}/roster?since_version=N, which answers without the players when nothing has changed.
+"""
+
+from typing import Iterable
+
+from sqlmodel import Session, func, select
+
+from backend.database import Lobby, Player
+
+
+def next_join_order(db: Session, lobby_id: int) -> int:
+    latest = db.exec(select(func.max(Player.join_order)).where(Player.lobby_id == lobby_id)).one()
+    return (latest or 0) + 1
+
+
+def bump_roster_version(db: Session, lobby_id: int) -> int:
+    """Record a roster change; committed with the caller's other changes. Returns the new version."""
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        return 0
+    lobby.roster_version += 1
+    db.add(lobby)
+    return lobby.roster_version
+
+
+def in_join_order(players: Iterable[Player]) -> list[Player]:
+    return sorted(players, key=lambda player: (player.join_order, player.id))
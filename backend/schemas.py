@@ -1,33 +1,216 @@
-from pydantic import BaseModel
+from datetime import datetime, timezone
 
-from backend.database.models import Lobby, Player, Team
+from pydantic import BaseModel, model_validator
+
+from backend.chaos import ChaosConfig
+from backend.database.models import DEFAULT_TIMEZONE, Lobby, Player, Team
+from backend.game.lobby_state import LobbyPhase
+from backend.game.puzzles import LadderStep, Puzzle, PuzzleMeta
+from backend.offline_guesses import CLIENT_GUESS_ID_MAX_LENGTH, MAX_QUEUED_GUESSES
+from backend.utils.scheduling import LobbySchedule, as_utc, get_zone
+from backend.websocket.delivery import DeliveryReport
+from backend.websocket.events import CommentaryEvent
 
 
 #############################################################################
 # ? Request Models
 #############################################################################
+class PlayerPreferences(BaseModel):
+    reduced_motion: bool = False
+    high_contrast: bool = False
+    larger_text: bool = False
+
+
 class PlayerCreate(BaseModel):
     name: str
+    preferences: PlayerPreferences | None = None
 
 
 class LobbyCreate(BaseModel):
     name: str | None = None
+    rng_seed: int | None = None  # Reuse another lobby's seed to reproduce its team and puzzle assignments
+
+
+class LateJoinUpdate(BaseModel):
+    enabled: bool
+
+
+class LobbySettingsUpdate(BaseModel):
+    """Only the fields sent are changed; send max_players or round_time_limit_seconds as null to remove them."""
+
+    max_players: int | None = None
+    locked: bool | None = None
+    is_private: bool | None = None
+    round_time_limit_seconds: int | None = None
+
+
+class SoftPlayerCapUpdate(BaseModel):
+    soft_player_cap: int | None  # None falls back to the server default, 0 disables the cap
+
+
+class PaceKeeperUpdate(BaseModel):
+    enabled: bool
+
+
+class LobbySeedUpdate(BaseModel):
+    seed: int | None = None  # None rerolls a fresh random seed
+
+
+class LobbyScheduleUpdate(BaseModel):
+    """Naive times are read in `timezone`; times with an offset keep it. Omitted times clear the field."""
+
+    timezone: str = DEFAULT_TIMEZONE
+    opens_at: datetime | None = None
+    closes_at: datetime | None = None
+    starts_at: datetime | None = None
+
+    @model_validator(mode="after")
+    def normalize_to_utc(self) -> "LobbyScheduleUpdate":
+        zone = get_zone(self.timezone)
+        self.opens_at = as_utc(self.opens_at, zone) if self.opens_at else None
+        self.closes_at = as_utc(self.closes_at, zone) if self.closes_at else None
+        self.starts_at = as_utc(self.starts_at, zone) if self.starts_at else None
+        if self.opens_at and self.closes_at and self.opens_at >= self.closes_at:
+            raise ValueError("opens_at must be before closes_at")
+        return self
 
 
 class TeamCreate(BaseModel):
     num_teams: int
+    theme: str | None = None  # "animals", "colors" or "cities"; stored on the lobby for later teams
 
 
 class TeamUpdate(BaseModel):
     name: str
 
 
+class TeamColorUpdate(BaseModel):
+    color: str  # "#RRGGBB"
+
+
+class TeamAssignmentsUpdate(BaseModel):
+    assignments: dict[int, int]  # player_id to team_id; 0 unassigns, as with PUT .../team/0/player/{player_id}
+
+
+class TeamPatch(BaseModel):
+    """Only the fields sent are changed."""
+
+    name: str | None = None
+    color: str | None = None  # "#RRGGBB"
+
+
+class ScoreAdjustRequest(BaseModel):
+    delta: int  # Points to add; negative to take points away
+    reason: str  # Required, kept with the adjustment in the audit trail
+
+
+class AdminLobbyListEntry(BaseModel):
+    lobby: Lobby
+    player_count: int
+    soft_player_cap: int | None  # The cap in effect, after falling back to the server default
+    over_soft_cap: bool
+    team_count: int
+    connected_count: int  # Players with an open websocket right now
+    phase: LobbyPhase
+    last_activity_at: datetime  # Latest join, round start or guess; the lobby's creation when there's been none
+
+
+class AdminLobbyListResponse(BaseModel):
+    items: list[AdminLobbyListEntry]
+    total: int  # Lobbies matching the filter, across all pages
+    page: int
+    per_page: int
+    has_more: bool
+
+
+class ProgressVisibilityUpdate(BaseModel):
+    visibility: str  # "none", "coarse" or "exact"
+
+
 class AdminStartGameRequest(BaseModel):
     difficulty: str
     puzzle_mode: str = "different"  # "same" or "different"
     word_count_mode: str = "balanced"  # "exact" or "balanced"
     force_start: bool = False  # Allow starting even if not all players are ready
     puzzle_date: str | None = None  # Format: "YYYY-MM-DD"
+    puzzle_id: int | None = None  # A stored puzzle every team plays; takes precedence over puzzle_date
+
+
+class HostStartGameRequest(BaseModel):
+    """A lobby host's start: AdminStartGameRequest without picking specific puzzles."""
+
+    difficulty: str
+    puzzle_mode: str = "different"
+    word_count_mode: str = "balanced"
+    force_start: bool = False
+
+
+class NextRoundRequest(BaseModel):
+    """Starting the next round from intermission; difficulty defaults to the last round's."""
+
+    difficulty: str | None = None
+    puzzle_mode: str = "different"
+    word_count_mode: str = "balanced"
+
+
+class IntermissionUpdate(BaseModel):
+    enabled: bool
+    seconds: int | None = None  # Auto-advance to the next round after this long; None waits for the admin
+
+
+class GuessSubmitRequest(BaseModel):
+    guess: str
+    word_index: int
+    # For guesses queued while offline (see backend/offline_guesses.py): the client's id for the guess, so sending
+    # it again returns the first outcome, and when it was typed (naive times are read as UTC; only
+    # POST /lobby/guess/batch reads it)
+    client_guess_id: str | None = None
+    submitted_at: datetime | None = None
+
+    @model_validator(mode="after")
+    def validate_offline_fields(self) -> "GuessSubmitRequest":
+        self.client_guess_id = (self.client_guess_id or "").strip() or None
+        if self.client_guess_id and len(self.client_guess_id) > CLIENT_GUESS_ID_MAX_LENGTH:
+            raise ValueError(f"client_guess_id must be at most {CLIENT_GUESS_ID_MAX_LENGTH} characters")
+        if self.submitted_at:
+            self.submitted_at = as_utc(self.submitted_at, timezone.utc)
+        return self
+
+
+class GuessBatchRequest(BaseModel):
+    """A client's queue of guesses made while it was offline."""
+
+    guesses: list[GuessSubmitRequest]
+
+    @model_validator(mode="after")
+    def validate_size(self) -> "GuessBatchRequest":
+        if not 1 <= len(self.guesses) <= MAX_QUEUED_GUESSES:
+            raise ValueError(f"Send between 1 and {MAX_QUEUED_GUESSES} guesses")
+        return self
+
+
+class PuzzleCreate(BaseModel):
+    """Used for both creating and replacing a stored puzzle; validated with the same rules as puzzle files."""
+
+    title: str
+    difficulty: str  # "easy", "medium", "hard"
+    ladder: list[LadderStep]
+    author_name: str | None = None
+    author_handle: str | None = None
+    author_notes: str | None = None
+
+    @model_validator(mode="after")
+    def validate_puzzle(self) -> "PuzzleCreate":
+        puzzle = Puzzle(meta=PuzzleMeta(title=self.title, difficulty=self.difficulty), ladder=self.ladder)
+        self.title = self.title.strip()
+        if not self.title:
+            raise ValueError("title must not be empty")
+        self.difficulty = puzzle.meta.difficulty
+        # Blank author fields are stored as missing rather than credited as ""
+        self.author_name = (self.author_name or "").strip() or None
+        self.author_handle = (self.author_handle or "").strip() or None
+        self.author_notes = (self.author_notes or "").strip() or None
+        return self
 
 
 
@@ -39,6 +222,128 @@ class LobbyInfo(BaseModel):
     players: list[Player]
     players_by_team: dict[int, list[Player]]
     teams: list[Team]
+    schedule: LobbySchedule
+    phase: LobbyPhase
+
+
+class TeamGuessEntry(BaseModel):
+    id: int
+    word_index: int
+    direction: str
+    guess: str
+    result: str  # "correct", "wrong" or "pending" (awaiting admin review)
+    player_id: int
+    player_name: str
+    created_at: datetime
+
+
+class TeamGuessHistoryResponse(BaseModel):
+    game_id: int | None  # None when the team hasn't been given a puzzle yet
+    page: int
+    page_size: int
+    total: int
+    has_more: bool
+    guesses: list[TeamGuessEntry]
+
+
+class SpectatorSessionResponse(BaseModel):
+    spectator_session_id: str  # Goes in /ws/lobby/{lobby_id}/spectator/{spectator_session_id}
+    lobby_id: int
+    lobby_code: str
+    lobby_name: str
+
+
+class WebSocketTokenResponse(BaseModel):
+    token: str  # Goes in /ws/lobby/{lobby_id}/player?token=...
+    lobby_id: int
+    expires_in_seconds: int  # Connect before then; an open connection isn't affected by the token expiring
+
+
+class AdminWebSocketTokenResponse(BaseModel):
+    token: str  # Goes in /ws/admin/{session_id}?token=...
+    session_id: str
+    expires_in_seconds: int
+
+
+class TeamChatEntry(BaseModel):
+    id: int
+    team_id: int
+    player_id: int
+    player_name: str
+    text: str
+    sent_at: datetime
+
+
+class TeamChatHistoryResponse(BaseModel):
+    messages: list[TeamChatEntry]  # Oldest first, at most TEAM_CHAT_HISTORY_SIZE
+
+
+class CommentaryFeedResponse(BaseModel):
+    lines: list[CommentaryEvent]  # Oldest first, at most COMMENTARY_HISTORY_SIZE
+
+
+class GuessSubmitResponse(BaseModel):
+    # "correct", "wrong", "pending" (awaiting host review), "already_solved", or in a batch "rejected"
+    result: str
+    word_index: int
+    revealed_steps: list[int]  # The team's revealed rungs after this guess
+    is_completed: bool
+    client_guess_id: str | None = None
+    duplicate: bool = False  # client_guess_id was sent before; result is from the first time
+    detail: str | None = None  # Why a rejected guess wasn't judged
+
+
+class GuessBatchResponse(BaseModel):
+    results: list[GuessSubmitResponse]  # One per guess, in the order they were sent
+
+
+class PollResponse(BaseModel):
+    events: list[dict]  # Same payloads the WebSocket would have sent, each with its "seq"
+    last_seq: int  # Pass as after_seq on the next poll
+    resync_required: bool  # Events after after_seq are gone; reload state, then poll from last_seq
+
+
+class RosterResponse(BaseModel):
+    roster_version: int
+    changed: bool  # False when since_version is already current; players is then left empty
+    players: list[Player]  # In join order
+
+
+class LobbyGuessEntry(TeamGuessEntry):
+    team_id: int
+    team_name: str
+    game_id: int  # The team's puzzle for that round
+
+
+class LobbyGuessHistoryResponse(BaseModel):
+    page: int
+    page_size: int
+    total: int
+    has_more: bool
+    guesses: list[LobbyGuessEntry]
+
+
+class QuickPlayInfoResponse(BaseModel):
+    enabled: bool
+    code: str | None  # Join code, None until the lobby exists
+    player_count: int
+    next_round_at: datetime | None  # When the countdown to the next round ends, if one is running
+
+
+class PuzzleSummary(BaseModel):
+    id: int
+    title: str
+    difficulty: str
+    word_count: int
+    author_name: str | None
+    author_handle: str | None
+    author_notes: str | None
+    created_at: datetime
+    updated_at: datetime | None
+
+
+class PuzzleDetail(PuzzleSummary):
+    ladder: list[LadderStep]
 
 
 class MessageResponse(BaseModel):
@@ -46,6 +351,33 @@ class MessageResponse(BaseModel):
     message: str
 
 
+class DeliveryFailureInfo(BaseModel):
+    connection: str  # "player:<session_id>" or "admin:<web_session_id>"
+    reason: str
+
+
+class DeliverySummary(BaseModel):
+    """How a critical broadcast went: connections tried, how many got it, and why the rest didn't."""
+
+    attempted: int
+    succeeded: int
+    failed: int
+    failures: list[DeliveryFailureInfo]
+
+    @classmethod
+    def of(cls, report: DeliveryReport) -> "DeliverySummary":
+        return cls(
+            attempted=report.attempted,
+            succeeded=report.succeeded,
+            failed=report.failed,
+            failures=[DeliveryFailureInfo(connection=f.connection, reason=f.reason) for f in report.failures],
+        )
+
+
+class BroadcastMessageResponse(MessageResponse):
+    delivery: DeliverySummary
+
+
 class GeneratedNameResponse(BaseModel):
     name: str
 
@@ -56,5 +388,243 @@ class ApiRootResponse(BaseModel):
     documentation_endpoints: dict[str, str]
 
 
+class AdminLoginRequest(BaseModel):
+    password: str
+    organization: str | None = None  # Slug of the organization whose admin password this is
+
+
+class OrganizationCreate(BaseModel):
+    name: str
+    slug: str
+    password: str
+
+
+class OrganizationPasswordUpdate(BaseModel):
+    password: str
+
+
+class OrganizationResponse(BaseModel):
+    id: int
+    name: str
+    slug: str
+    lobby_count: int
+    puzzle_count: int
+    created_at: datetime
+
+
 class AdminAuthenticatedResponse(BaseModel):
     session_id: str
+    token: str  # Bearer token for admin requests, also traded for admin websocket connect tokens
+    is_owner: bool
+    organization_id: int | None  # Set when the session only sees one organization's lobbies and puzzles
+    expires_at: str  # Pushed back by activity, up to ADMIN_SESSION_MAX_HOURS after login
+
+
+class AdminSessionInfo(BaseModel):
+    session_id: str
+    ip: str | None
+    is_owner: bool
+    organization_id: int | None
+    created_at: str
+    last_activity_at: str
+    websocket_connected: bool
+
+
+class ShareReplayRequest(BaseModel):
+    anonymize: bool = True  # Replace player names with "Player 1", "Player 2", ...
+
+
+class ShareResultsResponse(BaseModel):
+    token: str
+    url: str
+    expires_at: str
+
+
+class ImportedPlayer(BaseModel):
+    name: str
+    session_id: str
+    join_url: str  # Opening this link signs the player into the new lobby
+    team_name: str | None
+
+
+class ImportPlayersResponse(BaseModel):
+    imported: list[ImportedPlayer]
+    skipped_names: list[str]  # Names already taken in the target lobby
+
+
+class MergeLobbiesResponse(BaseModel):
+    target_lobby_id: int
+    moved_players: int
+    moved_teams: int
+    renamed_players: dict[str, str]  # Old name to new, for names the target lobby already had
+    renamed_teams: dict[str, str]
+
+
+class RetentionMetricsResponse(BaseModel):
+    guess_retention_days: int
+    guess_max_rows_per_lobby: int
+    chat_retention_days: int
+    chat_max_rows_per_lobby: int
+    runs: int
+    guesses_pruned_total: int
+    guesses_pruned_last_run: int
+    chat_messages_pruned_total: int
+    chat_messages_pruned_last_run: int
+    last_run_at: str | None
+
+
+class DailySummaryResponse(BaseModel):
+    date: str
+    is_final: bool  # False for today's running totals, which haven't been written yet
+    games_played: int
+    peak_concurrent_players: int
+    request_count: int
+    error_log_count: int
+    server_error_count: int
+    p99_latency_ms: float | None
+
+
+class PlayerAggregateStats(BaseModel):
+    name: str  # Most recent spelling; players are matched by name across lobbies
+    games_played: int
+    guesses: int
+    correct_guesses: int
+    avg_solve_seconds: float | None  # Over the games their team finished
+
+
+class PlayerStatsResponse(BaseModel):
+    date_from: str | None
+    date_to: str | None
+    players: list[PlayerAggregateStats]
+
+
+class RivalryStats(BaseModel):
+    members_a: list[str]
+    members_b: list[str]
+    team_names_a: list[str]
+    team_names_b: list[str]
+    meetings: int
+    wins_a: int
+    wins_b: int
+    ties: int
+    avg_point_margin: float  # Points A finished ahead of B by, on average; negative favours B
+    last_met_at: str | None
+
+
+class RivalriesResponse(BaseModel):
+    rivalries: list[RivalryStats]
+
+
+class DeprecatedRouteUsageResponse(BaseModel):
+    route: str  # Configured pattern, prefixed with the method when it has one
+    sunset: str | None
+    replacement: str | None
+    calls: int
+    last_called_at: str | None
+    last_path: str | None
+    last_user_agent: str | None
+    last_referer: str | None
+
+
+class RouteTrafficResponse(BaseModel):
+    method: str
+    route: str  # Route template, e.g. "/api/lobby/{lobby_code}"
+    requests: dict[int, int]  # Window length in minutes -> requests in that window
+    errors: int  # 4xx/5xx responses over the longest window
+    avg_duration_ms: float
+
+
+class TrafficResponse(BaseModel):
+    windows_minutes: list[int]
+    routes: list[RouteTrafficResponse]
+
+
+class HealthResponse(BaseModel):
+    degraded: bool
+    db_latency_ms: float | None  # p95 of the DB probe over HEALTH_WINDOW_SECONDS
+    error_rate: float | None  # Share of API requests with a 5xx; None until there are HEALTH_MIN_REQUESTS
+    request_count: int
+    disabled_features: list[str]
+
+
+class LivenessResponse(BaseModel):
+    status: str  # Always "ok"; a failed probe is one that gets no answer
+    timestamp: str
+
+
+class ReadinessResponse(BaseModel):
+    ready: bool  # False (with a 503) while the database can't be reached or the server is shutting down
+    database: bool
+    draining: bool  # Shutting down: websockets are closed and new API requests refused
+    db_latency_ms: float | None  # This probe's SELECT 1, None when it failed
+    degraded: bool  # Still ready, but shedding DEGRADABLE_FEATURES (see backend/health.py)
+    player_connections: int
+    admin_connections: int
+
+
+class ConnectionSendStats(BaseModel):
+    kind: str  # "player", "spectator" or "admin"
+    connection_id: str  # Player, spectator or admin web session id
+    lobby_ids: list[int]
+    messages_sent: int
+    avg_send_ms: float
+    p95_send_ms: float
+    max_send_ms: float
+    is_slow: bool
+    unacked_events: int  # Critical events the player hasn't acknowledged; always 0 for admins
+    queued_events: int  # Events waiting in the connection's outbox; always 0 for admins, whose sends aren't queued
+
+
+class AckStatsResponse(BaseModel):
+    opted_in_players: int  # Players whose clients acknowledge critical events
+    unacked_events: int
+    resends: int  # Since startup
+    expired: int  # Given up on (out of resends, or too many pending for the player), since startup
+
+
+class DiffStatsResponse(BaseModel):
+    opted_in_players: int  # Connected players getting snapshot-style events as merge patches
+    full_copies: int  # Since startup
+    patches: int  # Since startup
+
+
+class BufferStatsResponse(BaseModel):
+    replay_lobbies: int  # Lobbies with a replay buffer in memory
+    replay_events: int
+    replay_bytes: int
+    replay_max_bytes: int  # WS_REPLAY_MAX_BYTES, 0 for no cap
+    replay_evicted_lobbies: int  # Buffers dropped to stay under the cap, since startup
+    replay_trimmed_events: int  # Events dropped from a single lobby over the cap, since startup
+    unacked_events: int
+
+
+class EventDeliveryStats(BaseModel):
+    event_type: str
+    attempted: int
+    succeeded: int
+    failed: int
+
+
+class RecentDeliveryFailure(BaseModel):
+    at: str
+    event_type: str
+    connection: str
+    reason: str
+
+
+class DeliveryStatsResponse(BaseModel):
+    event_types: list[EventDeliveryStats]  # Most failures first
+    recent_failures: list[RecentDeliveryFailure]  # Newest first
+
+
+class TestEmitRequest(BaseModel):
+    event: dict  # Raw websocket payload; needs a "type", lobby_id is filled in from the path when missing
+    target: str = "lobby"  # "lobby" (players and admins), "team", "player" or "admins"
+    team_id: int | None = None
+    player_session_id: str | None = None
+
+
+class ChaosStatusResponse(BaseModel):
+    enabled: bool
+    config: ChaosConfig
+    injected: dict[str, int]  # Faults injected since chaos was last configured, e.g. {"dropped_sends": 3}
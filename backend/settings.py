@@ -1,18 +1,314 @@
 import os
+import re
+import secrets
+from pathlib import Path
+from typing import Literal
 
+from pydantic import ValidationError
 from pydantic_settings import BaseSettings, SettingsConfigDict
 
+from backend.deprecation import DeprecatedRoute
+
 testing = os.environ.get("RADDLE_ENV") == "testing"
+env_file = ".env.testing" if testing else ".env"
 
 
 class Settings(BaseSettings):
     model_config = SettingsConfigDict(
-        env_file=".env.testing" if testing else ".env", env_file_encoding="utf-8", extra="ignore"
+        env_file=env_file, env_file_encoding="utf-8", extra="ignore"
     )
 
     ADMIN_PASSWORD: str
     DATABASE_URL: str
     TESTING: bool = testing
 
+    # Owner-only admin actions (session management). Without it, every admin is treated as an owner.
+    ADMIN_OWNER_PASSWORD: str | None = None
+    # Admin web sessions (issued by POST /api/admin/login) expire after this long without activity...
+    ADMIN_SESSION_IDLE_HOURS: float = 12.0
+    # ...and after this long however active they are, so a leaked token stops working by itself
+    ADMIN_SESSION_MAX_HOURS: float = 24.0
+    # Also hand admin sessions out as an HttpOnly cookie; state-changing requests authenticated by the cookie need
+    # an X-CSRF-Token header (see backend/csrf.py). Turn ADMIN_COOKIE_SECURE off only for plain-http setups.
+    ADMIN_COOKIE_AUTH: bool = False
+    ADMIN_COOKIE_SECURE: bool = True
+
+    # Signs public share links and websocket connect tokens. Without it, a random secret is generated on first use
+    # and kept in SHARE_SECRET_FILE, so links survive restarts; set it when several servers have to agree on one.
+    SHARE_SECRET: str | None = None
+    SHARE_SECRET_FILE: str = "./databases/share_secret"
+    SHARE_LINK_TTL_HOURS: int = 72
+    # Wrong guesses in shared game recaps are free text players typed: "show" them, "mask" the text, or "hide" them
+    SHARED_REPLAY_WRONG_GUESSES: Literal["show", "mask", "hide"] = "mask"
+
+    # WebSocket keepalive. Pings go out well inside common reverse-proxy idle windows (nginx defaults to 60s);
+    # clients answer with a pong, so a socket idle past the timeout is dead. 0 disables the lifetime cap.
+    WS_PING_INTERVAL_SECONDS: float = 25.0
+    WS_IDLE_TIMEOUT_SECONDS: float = 75.0
+    WS_MAX_CONNECTION_LIFETIME_SECONDS: float = 0
+    # A client that leaves this many pings in a row unanswered is disconnected (and, for players, reported to the
+    # lobby as player_disconnected). Only pongs count, unlike the idle timeout; 0 disables the check.
+    WS_MAX_MISSED_PINGS: int = 2
+    # Connections whose p95 send time exceeds this are flagged as slow in the admin connection inspector
+    WS_SLOW_CLIENT_P95_MS: float = 200.0
+    # Recent events kept per lobby for players reconnecting with ?last_seq=N; 0 disables replay
+    WS_REPLAY_BUFFER_SIZE: int = 200
+    # Memory budget for all lobbies' replay buffers together; past it, the buffers of the lobbies that went longest
+    # without an event are dropped (their players resync on reconnect). 0 disables the cap
+    WS_REPLAY_MAX_BYTES: int = 64 * 1024 * 1024
+    # Events queued per player connection waiting to be written to its socket. A client that falls this far behind
+    # is disconnected with 1013 and catches up from the replay buffer when it reconnects
+    WS_OUTBOX_SIZE: int = 256
+    # Longest a GET /api/lobby/{lobby_id}/poll request is held open waiting for events
+    LONG_POLL_MAX_SECONDS: float = 30.0
+    # Critical events (game start, kick) are resent to players who connected with ?acks=true until acknowledged:
+    # every WS_ACK_TIMEOUT_SECONDS, at most WS_ACK_MAX_RESENDS times. 0 turns the timed resends off
+    WS_ACK_TIMEOUT_SECONDS: float = 5.0
+    WS_ACK_MAX_RESENDS: int = 5
+    # Unacknowledged events kept per player; past it the oldest are given up on (counted as expired)
+    WS_ACK_MAX_PENDING_PER_PLAYER: int = 50
+    # Lifetime of the connect tokens from POST /api/lobby/ws-token, which player websockets carry instead of the
+    # session id (signed with the share secret). Clients fetch a fresh one for every connection attempt
+    WS_TOKEN_TTL_SECONDS: int = 60
+    # Websockets (player, spectator and admin together) one address may hold open; further upgrades get a 429.
+    # A whole venue can share one address behind its wifi, so keep this generous; 0 turns the cap off
+    WS_MAX_CONNECTIONS_PER_IP: int = 200
+    # Joins one address may make, and guesses (HTTP and websocket together) one player may submit, per minute;
+    # past them requests get a 429 with Retry-After. Joins are per address, so leave room for a venue's wifi;
+    # 0 turns each limit off
+    RATE_LIMIT_JOINS_PER_MINUTE: int = 60
+    RATE_LIMIT_GUESSES_PER_MINUTE: int = 30
+    # How far back a guess queued while the client was offline may be dated by its submitted_at; within it the
+    # guess counts from when it was typed, older ones from when they arrive. 0 (the default) dates every guess on
+    # arrival
+    OFFLINE_GUESS_GRACE_SECONDS: int = 0
+    # Judge such a dated guess against the ladder as it was when typed: a right answer for a rung a teammate has
+    # solved since takes the solve from them rather than coming back already_solved
+    OFFLINE_GUESS_JUDGE_AS_TYPED: bool = False
+
+    # Guess log and team chat retention, enforced by a periodic pruning task. Only guesses from finished games and
+    # chat in lobbies with no game in progress are pruned; 0 disables each limit, and with all disabled the task
+    # doesn't run.
+    GUESS_RETENTION_DAYS: int = 0
+    GUESS_MAX_ROWS_PER_LOBBY: int = 0
+    CHAT_RETENTION_DAYS: int = 0
+    CHAT_MAX_ROWS_PER_LOBBY: int = 0
+    RETENTION_PRUNE_INTERVAL_MINUTES: float = 60.0
+    # Team chat lines kept per team for GET /api/lobby/team/chat; older ones are deleted as new ones arrive.
+    # 0 stores nothing, so chat is only relayed live
+    TEAM_CHAT_HISTORY_SIZE: int = 50
+    # Commentary lines kept per lobby (in memory) for GET /api/lobby/{lobby_id}/commentary; 0 keeps none, so the
+    # narration only goes out live to spectators and admins
+    COMMENTARY_HISTORY_SIZE: int = 100
+    # Word filter for player and lobby names (see backend/utils/name_filter.py): "reject" refuses names with a
+    # blocked word, "sanitize" stars the word out, "off" allows anything. The blocklist file, one word per line,
+    # adds to the built-in list
+    NAME_FILTER_MODE: Literal["reject", "sanitize", "off"] = "reject"
+    NAME_BLOCKLIST_FILE: str | None = None
+    # Before migrating an SQLite database file, a copy goes to a backups/ folder next to it (restore one with
+    # `./rt db-restore <backup>`). This many of the newest copies are kept; 0 turns the backups off
+    MIGRATION_BACKUPS_KEEP: int = 5
+    # Instances starting against the same database take turns creating and migrating it; a startup lock held
+    # longer than this is taken to be left behind by a crashed instance and is taken over
+    STARTUP_LOCK_TIMEOUT_SECONDS: int = 120
+
+    # Joins past this many players still succeed but warn the lobby's admins and flag it in the admin lobby list.
+    # Lobbies can set their own cap; 0 means no default cap.
+    LOBBY_SOFT_PLAYER_CAP: int = 0
+
+    # Pace keeper, for lobbies that turn it on: the slowest team gets an escalating hint once it has gone
+    # PACE_KEEPER_STALL_MULTIPLIER x the lobby's median solve time without solving (hint N after N x that).
+    # The median isn't trusted until the round has PACE_KEEPER_MIN_SOLVES solves.
+    PACE_KEEPER_STALL_MULTIPLIER: float = 3.0
+    PACE_KEEPER_MIN_SOLVES: int = 3
+    PACE_KEEPER_MAX_HINT_LEVEL: int = 3
+    PACE_KEEPER_TICK_SECONDS: float = 5.0
+
+    # Graceful shutdown (see backend/shutdown.py): in-flight requests get SHUTDOWN_GRACE_SECONDS to finish, and
+    # clients are told to wait SHUTDOWN_RECONNECT_AFTER_SECONDS before reconnecting
+    SHUTDOWN_GRACE_SECONDS: float = 10.0
+    SHUTDOWN_RECONNECT_AFTER_SECONDS: float = 5.0
+
+    # How often a running round timer sends timer_tick with the time left; 0 sends none
+    TIMER_TICK_SECONDS: float = 10.0
+
+    # How often timed answer reveals (see backend/game/reveal.py) are checked for their next step
+    REVEAL_TICK_SECONDS: float = 0.5
+    # How often lobbies in intermission (see backend/intermission.py) are checked for an auto-advance that's due
+    INTERMISSION_TICK_SECONDS: float = 1.0
+
+    # Server-run "quick play" lobby: players are grouped into teams of QUICK_PLAY_TEAM_SIZE as they join and
+    # short rounds start on their own after an intermission, ending when the round timer runs out.
+    QUICK_PLAY_ENABLED: bool = False
+    QUICK_PLAY_TEAM_SIZE: int = 3
+    QUICK_PLAY_DIFFICULTY: str = "easy"
+    QUICK_PLAY_ROUND_SECONDS: float = 300.0
+    QUICK_PLAY_INTERMISSION_SECONDS: float = 30.0
+    QUICK_PLAY_TICK_SECONDS: float = 2.0
+
+    # Colors handed to new teams, fewest-used first, so every device shows a team in the same color
+    TEAM_COLOR_PALETTE: list[str] = [
+        "#E6194B",
+        "#3CB44B",
+        "#4363D8",
+        "#F58231",
+        "#911EB4",
+        "#42D4F4",
+        "#F032E6",
+        "#BFEF45",
+        "#FABED4",
+        "#469990",
+    ]
+
+    # Once every team has finished a round, tell players who wrote the puzzles they played (those with an author)
+    SHOW_PUZZLE_CREDITS: bool = True
+
+    # Health score over the last HEALTH_WINDOW_SECONDS: the node is degraded while the DB probe's p95 latency is over
+    # HEALTH_DB_LATENCY_MS, or more than HEALTH_ERROR_RATE of API requests get a 5xx (once there are
+    # HEALTH_MIN_REQUESTS). Degraded nodes switch off DEGRADABLE_FEATURES; DISABLED_FEATURES are always off.
+    HEALTH_WINDOW_SECONDS: float = 60.0
+    HEALTH_DB_LATENCY_MS: float = 250.0
+    HEALTH_ERROR_RATE: float = 0.05
+    HEALTH_MIN_REQUESTS: int = 20
+    HEALTH_TICK_SECONDS: float = 5.0
+    DEGRADABLE_FEATURES: list[str] = ["team_chat", "progress_broadcasts", "puzzle_analytics"]
+    DISABLED_FEATURES: list[str] = []
+
+    # Bot players admins add to fill out uneven teams. Every BOT_TICK_SECONDS each bot with a skill solves its
+    # team's next rung with that chance; bots without one just hold a roster slot.
+    MAX_BOTS_PER_LOBBY: int = 8
+    BOT_TICK_SECONDS: float = 15.0
+
+    # Routes answered with Deprecation/Sunset headers and counted in the admin deprecation report, as JSON, e.g.
+    # [{"pattern": "/api/lobby/*/team", "method": "PUT", "sunset": "2027-01-01", "replacement": "/api/v1/team"}]
+    DEPRECATED_ROUTES: list[DeprecatedRoute] = []
+
+    @property
+    def share_secret(self) -> str:
+        if not self.SHARE_SECRET:
+            self.SHARE_SECRET = load_or_create_secret(Path(self.SHARE_SECRET_FILE))
+        return self.SHARE_SECRET
+
+
+def load_or_create_secret(path: Path) -> str:
+    """The secret kept in path, generated and written there (readable only by this user) if there isn't one yet."""
+    if path.is_file():
+        return path.read_text().strip()
+    path.parent.mkdir(parents=True, exist_ok=True)
+    try:
+        descriptor = os.open(path, os.O_WRONLY | os.O_CREAT | os.O_EXCL, 0o600)
+    except FileExistsError:
+        # Another worker starting alongside this one wrote it first
+        return path.read_text().strip()
+    secret = secrets.token_urlsafe(32)
+    with os.fdopen(descriptor, "w") as file:
+        file.write(secret)
+    return secret
+
+
+class SettingsError(Exception):
+    """Every problem with the configuration, reported together at startup instead of one per restart."""
+
+    def __init__(self, problems: list[str]):
+        self.problems = problems
+        listed = "\n".join(f"  - {problem}" for problem in problems)
+        super().__init__(f"Invalid configuration ({env_file} or environment), {len(problems)} problem(s):\n{listed}")
+
+
+NON_NEGATIVE = (
+    "ADMIN_SESSION_IDLE_HOURS",
+    "ADMIN_SESSION_MAX_HOURS",
+    "SHARE_LINK_TTL_HOURS",
+    "WS_PING_INTERVAL_SECONDS",
+    "WS_IDLE_TIMEOUT_SECONDS",
+    "WS_MAX_CONNECTION_LIFETIME_SECONDS",
+    "WS_MAX_MISSED_PINGS",
+    "WS_REPLAY_BUFFER_SIZE",
+    "WS_REPLAY_MAX_BYTES",
+    "LONG_POLL_MAX_SECONDS",
+    "WS_ACK_TIMEOUT_SECONDS",
+    "WS_ACK_MAX_RESENDS",
+    "WS_ACK_MAX_PENDING_PER_PLAYER",
+    "WS_MAX_CONNECTIONS_PER_IP",
+    "RATE_LIMIT_JOINS_PER_MINUTE",
+    "RATE_LIMIT_GUESSES_PER_MINUTE",
+    "OFFLINE_GUESS_GRACE_SECONDS",
+    "GUESS_RETENTION_DAYS",
+    "GUESS_MAX_ROWS_PER_LOBBY",
+    "CHAT_RETENTION_DAYS",
+    "CHAT_MAX_ROWS_PER_LOBBY",
+    "TEAM_CHAT_HISTORY_SIZE",
+    "COMMENTARY_HISTORY_SIZE",
+    "MIGRATION_BACKUPS_KEEP",
+    "LOBBY_SOFT_PLAYER_CAP",
+    "MAX_BOTS_PER_LOBBY",
+    "SHUTDOWN_RECONNECT_AFTER_SECONDS",
+    "TIMER_TICK_SECONDS",
+)
+POSITIVE = (
+    "WS_OUTBOX_SIZE",
+    "WS_TOKEN_TTL_SECONDS",
+    "STARTUP_LOCK_TIMEOUT_SECONDS",
+    "RETENTION_PRUNE_INTERVAL_MINUTES",
+    "PACE_KEEPER_STALL_MULTIPLIER",
+    "PACE_KEEPER_TICK_SECONDS",
+    "REVEAL_TICK_SECONDS",
+    "INTERMISSION_TICK_SECONDS",
+    "SHUTDOWN_GRACE_SECONDS",
+    "QUICK_PLAY_TEAM_SIZE",
+    "QUICK_PLAY_ROUND_SECONDS",
+    "QUICK_PLAY_TICK_SECONDS",
+    "HEALTH_WINDOW_SECONDS",
+    "HEALTH_TICK_SECONDS",
+    "BOT_TICK_SECONDS",
+)
+HEX_COLOR = re.compile(r"^#[0-9A-Fa-f]{6}$")
+
+
+def settings_problems(config: Settings) -> list[str]:
+    """Checks that need more than a field's type: ranges, formats and settings that contradict each other."""
+    values = {name: getattr(config, name) for name in NON_NEGATIVE + POSITIVE}
+    problems = [f"{name}: must be 0 or more, got {values[name]}" for name in NON_NEGATIVE if values[name] < 0]
+    problems += [f"{name}: must be more than 0, got {values[name]}" for name in POSITIVE if values[name] <= 0]
+
+    if not config.ADMIN_PASSWORD.strip():
+        problems.append("ADMIN_PASSWORD: must not be empty")
+    if config.ADMIN_OWNER_PASSWORD and config.ADMIN_OWNER_PASSWORD == config.ADMIN_PASSWORD:
+        problems.append("ADMIN_OWNER_PASSWORD: must differ from ADMIN_PASSWORD, or every admin is an owner")
+    if config.ADMIN_SESSION_IDLE_HOURS > config.ADMIN_SESSION_MAX_HOURS:
+        problems.append("ADMIN_SESSION_IDLE_HOURS: must not be longer than ADMIN_SESSION_MAX_HOURS")
+    if "://" not in config.DATABASE_URL:
+        problems.append(f"DATABASE_URL: {config.DATABASE_URL!r} isn't a URL like sqlite:///./databases/main.db")
+    if 0 < config.WS_IDLE_TIMEOUT_SECONDS <= config.WS_PING_INTERVAL_SECONDS:
+        problems.append("WS_IDLE_TIMEOUT_SECONDS: must be longer than WS_PING_INTERVAL_SECONDS")
+    if not 0 <= config.HEALTH_ERROR_RATE <= 1:
+        problems.append(f"HEALTH_ERROR_RATE: must be between 0 and 1, got {config.HEALTH_ERROR_RATE}")
+    if config.NAME_BLOCKLIST_FILE and not Path(config.NAME_BLOCKLIST_FILE).is_file():
+        problems.append(f"NAME_BLOCKLIST_FILE: {config.NAME_BLOCKLIST_FILE!r} doesn't exist")
+    if not config.TEAM_COLOR_PALETTE:
+        problems.append("TEAM_COLOR_PALETTE: must have at least one color")
+    problems += [
+        f"TEAM_COLOR_PALETTE: {color!r} isn't a #RRGGBB color"
+        for color in config.TEAM_COLOR_PALETTE
+        if not HEX_COLOR.match(color)
+    ]
+    return problems
+
+
+def load_settings(**overrides) -> Settings:
+    """Read and validate the settings, raising SettingsError with everything that's wrong at once."""
+    try:
+        config = Settings(**overrides)  # ty: ignore[missing-argument]
+    except ValidationError as e:
+        problems = []
+        for error in e.errors():
+            name = ".".join(str(part) for part in error["loc"])
+            problems.append(f"{name}: {'required but not set' if error['type'] == 'missing' else error['msg']}")
+        raise SettingsError(problems) from None
+    problems = settings_problems(config)
+    if problems:
+        raise SettingsError(problems)
+    return config
+
 
-settings = Settings()  # ty: ignore[missing-argument]
+settings = load_settings()
@@ -0,0 +1,96 @@
+"""
+Graceful shutdown: tell connected clients the server is going away before closing their sockets.
+
+On SIGINT/SIGTERM uvicorn stops listening and then closes every connection itself, which players see as a
+dropped socket and start hammering with reconnects. GracefulServer runs notify_clients_of_shutdown() in between:
+every player and admin socket gets a `server_shutdown` event and is closed with 1012 (service restart), new
+requests that still arrive on open connections get a 503, and in-flight requests get SHUTDOWN_GRACE_SECONDS to
+finish before uvicorn gives up on them. A bare `uvicorn backend.main:app` still closes sockets with 1012, just
+without the event.
+"""
+
+from datetime import datetime, timezone
+from typing import Optional
+
+import uvicorn
+from fastapi import Request
+
+from backend.api.probes import PROBE_PATHS
+from backend.custom_logging import server_logger
+from backend.errors import ErrorCode, ServiceUnavailableError, app_error_handler
+from backend.in_memory_state import on_reset
+from backend.settings import settings
+from backend.websocket.events import ServerShutdownEvent
+
+SERVICE_RESTART = 1012
+
+
+class ShutdownState:
+    def __init__(self):
+        self.draining_since: Optional[datetime] = None
+
+    @property
+    def draining(self) -> bool:
+        return self.draining_since is not None
+
+
+shutdown_state = ShutdownState()
+on_reset(shutdown_state.__init__)
+
+
+async def notify_clients_of_shutdown(reason: str = "Server is shutting down") -> bool:
+    """Start draining and close every websocket with a server_shutdown event; False if already draining."""
+    from backend.websocket.managers import admin_web_socket_manager, lobby_websocket_manager
+
+    if shutdown_state.draining:
+        return False
+    shutdown_state.draining_since = datetime.now(timezone.utc)
+    event = ServerShutdownEvent(reason=reason, reconnect_after_seconds=settings.SHUTDOWN_RECONNECT_AFTER_SECONDS)
+    players = await lobby_websocket_manager.close_all(event, SERVICE_RESTART, reason)
+    admins = await admin_web_socket_manager.close_all(event, SERVICE_RESTART, reason)
+    server_logger.info(
+        f"Draining for shutdown: notified and closed {players} player/spectator and {admins} admin sockets"
+    )
+    return True
+
+
+async def reject_while_draining(request: Request, call_next):
+    # Probes still answer, so load balancers can see /api/ready go unready
+    path = request.url.path
+    if shutdown_state.draining and path.startswith("/api") and path not in PROBE_PATHS:
+        error = ServiceUnavailableError(
+            "Server is shutting down", code=ErrorCode.SHUTTING_DOWN, headers={"Connection": "close"}
+        )
+        return await app_error_handler(request, error)
+    return await call_next(request)
+
+
+class GracefulServer(uvicorn.Server):
+    async def shutdown(self, sockets=None):
+        # Stop accepting connections first so nobody connects after being told to go away
+        for server in self.servers:
+            server.close()
+        try:
+            await notify_clients_of_shutdown()
+        except Exception as e:
+            server_logger.exception(f"Failed to notify clients of shutdown: {e}")
+        await super().shutdown(sockets)
+
+
+def serve(app: str, host: str, port: int, log_level: str, reload: bool = False):
+    """uvicorn.run(), but notifying clients before connections are closed and waiting out in-flight requests."""
+    config = uvicorn.Config(
+        app,
+        host=host,
+        port=port,
+        log_level=log_level,
+        reload=reload,
+        timeout_graceful_shutdown=settings.SHUTDOWN_GRACE_SECONDS,
+    )
+    server = GracefulServer(config)
+    if config.should_reload:
+        from uvicorn.supervisors import ChangeReload
+
+        ChangeReload(config, target=server.run, sockets=[config.bind_socket()]).run()
+    else:
+        server.run()
@@ -0,0 +1,47 @@
+"""
+In-memory registry of spectator sessions: read-only viewers of a lobby.
+
+POST /api/lobby/spectate/{lobby_code} issues one for a public lobby. Its id goes in the websocket path
+(/ws/lobby/{lobby_id}/spectator/{spectator_session_id}), which receives the lobby-wide broadcasts players get but
+nothing team-scoped or meant for one player; anything the socket sends apart from keepalive pongs is ignored, so
+spectators can't guess or chat. Sessions don't survive a restart or the lobby's deletion, after which the page asks
+for a new one.
+"""
+
+import uuid
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from typing import Dict, Optional
+
+from backend.custom_logging import api_logger
+from backend.in_memory_state import on_reset
+
+
+@dataclass
+class SpectatorSession:
+    session_id: str
+    lobby_id: int
+    created_at: datetime = field(default_factory=lambda: datetime.now(tz=timezone.utc))
+
+
+class SpectatorSessionStore:
+    def __init__(self):
+        self.sessions: Dict[str, SpectatorSession] = {}
+
+    def create(self, lobby_id: int) -> SpectatorSession:
+        session = SpectatorSession(session_id=str(uuid.uuid4()), lobby_id=lobby_id)
+        self.sessions[session.session_id] = session
+        api_logger.info(f"Spectator session created: session_id={session.session_id} lobby_id={lobby_id}")
+        return session
+
+    def get(self, session_id: str) -> Optional[SpectatorSession]:
+        return self.sessions.get(session_id)
+
+    def forget_lobby(self, lobby_id: int):
+        self.sessions = {
+            session_id: session for session_id, session in self.sessions.items() if session.lobby_id != lobby_id
+        }
+
+
+spectator_store = SpectatorSessionStore()
+on_reset(spectator_store.__init__)
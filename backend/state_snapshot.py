@@ -0,0 +1,185 @@
+"""Export and import a full server snapshot (database, puzzles and optionally .env) as a .tar.gz.
+
+Used to prepare lobbies and puzzles on one machine and load them onto an offline laptop at the venue:
+
+    uv run python -m backend.state_snapshot export --out state.tar.gz
+    uv run python -m backend.state_snapshot import state.tar.gz
+"""
+
+import argparse
+import json
+import shutil
+import sqlite3
+import sys
+import tarfile
+import tempfile
+from contextlib import closing
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Optional
+
+SNAPSHOT_VERSION = 1
+MANIFEST_NAME = "manifest.json"
+DATABASE_NAME = "database.sqlite3"
+PUZZLES_NAME = "json_puzzles"
+ENV_NAME = "env"
+
+PROJECT_ROOT = Path(__file__).parent.parent
+DEFAULT_PUZZLES_DIR = PROJECT_ROOT / "puzzles_from_raddle" / "json_puzzles"
+
+
+class SnapshotError(Exception):
+    pass
+
+
+def sqlite_path_from_url(database_url: str) -> Path:
+    """Resolve the file path of a sqlite:/// database URL."""
+    prefix = "sqlite:///"
+    if not database_url.startswith(prefix):
+        raise SnapshotError(f"Only SQLite databases can be snapshotted, got {database_url}")
+    return Path(database_url[len(prefix) :])
+
+
+def table_row_counts(db_path: Path) -> dict[str, int]:
+    connection = sqlite3.connect(db_path)
+    try:
+        tables = [
+            row[0]
+            for row in connection.execute(
+                "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name"
+            )
+        ]
+        return {table: connection.execute(f'SELECT COUNT(*) FROM "{table}"').fetchone()[0] for table in tables}
+    finally:
+        connection.close()
+
+
+def export_state(out: Path, db_path: Path, puzzles_dir: Path, env_file: Optional[Path] = None) -> dict:
+    """
+    Write a snapshot archive of the database, puzzle files and (optionally) the .env file.
+
+    The database is copied with SQLite's backup API, so a running server can keep writing during export.
+
+    Returns:
+        The snapshot manifest
+    """
+    if not db_path.exists():
+        raise SnapshotError(f"Database not found: {db_path}")
+
+    with tempfile.TemporaryDirectory() as tmp:
+        db_copy = Path(tmp) / DATABASE_NAME
+        source = sqlite3.connect(db_path)
+        target = sqlite3.connect(db_copy)
+        try:
+            source.backup(target)
+        finally:
+            target.close()
+            source.close()
+
+        manifest = {
+            "version": SNAPSHOT_VERSION,
+            "created_at": datetime.now(timezone.utc).isoformat(),
+            "tables": table_row_counts(db_copy),
+            "puzzle_files": len(list(puzzles_dir.rglob("*.json"))) if puzzles_dir.exists() else 0,
+            "includes_env": env_file is not None and env_file.exists(),
+        }
+        manifest_path = Path(tmp) / MANIFEST_NAME
+        manifest_path.write_text(json.dumps(manifest, indent=2))
+
+        out.parent.mkdir(parents=True, exist_ok=True)
+        with tarfile.open(out, "w:gz") as archive:
+            archive.add(manifest_path, arcname=MANIFEST_NAME)
+            archive.add(db_copy, arcname=DATABASE_NAME)
+            if puzzles_dir.exists():
+                archive.add(puzzles_dir, arcname=PUZZLES_NAME)
+            if manifest["includes_env"]:
+                archive.add(env_file, arcname=ENV_NAME)
+
+    return manifest
+
+
+def import_state(
+    archive_path: Path,
+    db_path: Path,
+    puzzles_dir: Path,
+    env_file: Optional[Path] = None,
+    force: bool = False,
+) -> dict:
+    """
+    Restore a snapshot archive written by export_state.
+
+    Puzzle files are merged into puzzles_dir; the database replaces db_path, which must not exist unless force is set.
+    The .env file is only restored when env_file is given and the snapshot contains one.
+
+    Returns:
+        The snapshot manifest
+    """
+    if db_path.exists() and not force:
+        raise SnapshotError(f"Database already exists at {db_path}; pass --force to overwrite it")
+
+    with tempfile.TemporaryDirectory() as tmp:
+        with tarfile.open(archive_path, "r:gz") as archive:
+            # The "data" filter rejects absolute paths, parent-directory escapes and special files
+            archive.extractall(tmp, filter="data")
+
+        extracted = Path(tmp)
+        manifest_path = extracted / MANIFEST_NAME
+        if not manifest_path.exists() or not (extracted / DATABASE_NAME).exists():
+            raise SnapshotError("Not a snapshot archive: manifest or database missing")
+
+        manifest = json.loads(manifest_path.read_text())
+        if manifest.get("version") != SNAPSHOT_VERSION:
+            raise SnapshotError(f"Unsupported snapshot version {manifest.get('version')}")
+
+        db_path.parent.mkdir(parents=True, exist_ok=True)
+        # SQLite's backup API rather than a file copy, so a WAL journal left next to the old database can't be
+        # replayed over the new one, and a server still holding it open sees the restored copy
+        with closing(sqlite3.connect(extracted / DATABASE_NAME)) as source, closing(sqlite3.connect(db_path)) as target:
+            source.backup(target)
+
+        if (extracted / PUZZLES_NAME).exists():
+            shutil.copytree(extracted / PUZZLES_NAME, puzzles_dir, dirs_exist_ok=True)
+
+        if env_file is not None and (extracted / ENV_NAME).exists():
+            shutil.copyfile(extracted / ENV_NAME, env_file)
+
+    return manifest
+
+
+def main(argv: Optional[list[str]] = None) -> int:
+    parser = argparse.ArgumentParser(description="Export or import a full Raddle Teams server snapshot")
+    subparsers = parser.add_subparsers(dest="command", required=True)
+
+    export_parser = subparsers.add_parser("export", help="Write a snapshot archive")
+    export_parser.add_argument("--out", type=Path, required=True, help="Archive to write, e.g. state.tar.gz")
+    export_parser.add_argument("--include-env", action="store_true", help="Include the .env file (contains secrets)")
+
+    import_parser = subparsers.add_parser("import", help="Restore a snapshot archive")
+    import_parser.add_argument("archive", type=Path, help="Archive written by export")
+    import_parser.add_argument("--force", action="store_true", help="Overwrite an existing database")
+    import_parser.add_argument("--restore-env", action="store_true", help="Restore .env from the snapshot")
+
+    args = parser.parse_args(argv)
+
+    # Imported here so --help works without a configured .env
+    from backend.settings import settings
+
+    env_file = PROJECT_ROOT / ".env"
+    try:
+        db_path = sqlite_path_from_url(settings.DATABASE_URL)
+        if args.command == "export":
+            manifest = export_state(args.out, db_path, DEFAULT_PUZZLES_DIR, env_file if args.include_env else None)
+            print(f"Exported snapshot to {args.out}: {json.dumps(manifest['tables'])}")
+        else:
+            manifest = import_state(
+                args.archive, db_path, DEFAULT_PUZZLES_DIR, env_file if args.restore_env else None, force=args.force
+            )
+            print(f"Imported snapshot from {manifest['created_at']}: {json.dumps(manifest['tables'])}")
+    except SnapshotError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        return 1
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,49 @@
+"""
+Fixtures shared by the backend tests.
+
+`client` talks to a fresh app built from `app_builder`, and `lobby` is a lobby on it with Alice and Bob split into two
+teams. Modules that need other settings override `app_builder`, extending the shared one:
+
+    @pytest.fixture
+    def app_builder(app_builder):
+        return app_builder.setting("TEAM_CHAT_HISTORY_SIZE", 3)
+
+Constants such as ADMIN_HEADERS and PUZZLE are in helpers.py.
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+from fastapi.testclient import TestClient
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.app_builder import AppBuilder
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_PASSWORD, ADMIN_TOKEN, SHARE_SECRET
+
+
+@pytest.fixture
+def app_builder() -> AppBuilder:
+    return (
+        AppBuilder()
+        .setting("ADMIN_PASSWORD", ADMIN_PASSWORD)
+        .setting("SHARE_SECRET", SHARE_SECRET)
+        .admin_session(ADMIN_TOKEN)
+    )
+
+
+@pytest.fixture
+def client(app_builder):
+    with app_builder as app, TestClient(app) as test_client:
+        yield test_client
+
+
+@pytest.fixture
+def lobby(client) -> dict:
+    lobby = client.post("/api/admin/lobby", json={"name": "Pub Quiz"}, headers=ADMIN_HEADERS).json()
+    for name in ["Alice", "Bob"]:
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": name})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    return lobby
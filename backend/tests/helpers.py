@@ -0,0 +1,39 @@
+"""Constants, data and small helpers shared by the backend tests; the shared fixtures are in conftest.py."""
+
+ADMIN_PASSWORD = "test-secret"
+# A session the app_builder fixture issues up front (see AppBuilder.admin_session); only /login takes the password
+ADMIN_TOKEN = "test-admin-session"
+ADMIN_HEADERS = {"Authorization": f"Bearer {ADMIN_TOKEN}"}
+# Set by the app_builder fixture so tests don't generate one into SHARE_SECRET_FILE
+SHARE_SECRET = "test-share-secret"
+
+# Five rungs, SNOW to WEB, for tests that just need a game to play; modules testing the ladder itself define their own
+PUZZLE = {
+    "title": "Cold Snap",
+    "difficulty": "easy",
+    "ladder": [
+        {"word": "SNOW", "clue": None, "transform": None},
+        {"word": "FLAKE", "clue": "Snow ___", "transform": None},
+        {"word": "CORN", "clue": "Corn ___ (breakfast)", "transform": None},
+        {"word": "COB", "clue": "Corn on the ___", "transform": None},
+        {"word": "WEB", "clue": "Change one letter, spider's home", "transform": None},
+    ],
+}
+
+# Seven rungs, on to MAP, for tests that need a round to last a while
+LONG_PUZZLE = {
+    "title": "Long Way Down",
+    "difficulty": "easy",
+    "ladder": PUZZLE["ladder"]
+    + [
+        {"word": "SITE", "clue": "Web ___", "transform": None},
+        {"word": "MAP", "clue": "Site ___", "transform": None},
+    ],
+}
+
+
+def admin_websocket_url(client, session: dict) -> str:
+    """The admin websocket URL for a session from /api/admin/login, with a fresh connect token."""
+    response = client.post("/api/admin/ws-token", headers={"Authorization": f"Bearer {session['token']}"})
+    assert response.status_code == 200, response.text
+    return f"/ws/admin/{session['session_id']}?token={response.json()['token']}"
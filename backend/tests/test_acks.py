@@ -0,0 +1,135 @@
+"""Unit tests for acknowledging critical events."""
+
+import asyncio
+import json
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.settings import settings
+from backend.websocket.acks import AckTracker, ack_tick
+from backend.websocket.events import GameStartedEvent
+from backend.websocket.managers import AdminWebSocketManager, LobbyWebSocketManager
+from backend.websocket.prepared import PreparedEvent
+
+LOBBY_ID = 1
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+
+    async def send_text(self, text):
+        self.sent.append(json.loads(text))
+
+
+def numbered(seq: int) -> PreparedEvent:
+    event = PreparedEvent({"type": "game_started"})
+    event.stamp("seq", seq)
+    return event
+
+
+def manager_with_team(acks: dict) -> tuple[LobbyWebSocketManager, dict]:
+    """Players on team 10, connected with fake sockets; acks maps each to whether it opted in."""
+    manager = LobbyWebSocketManager(admin_web_socket_manager=AdminWebSocketManager())
+    sockets = {session_id: FakeWebSocket() for session_id in acks}
+    manager.lobby_websockets[LOBBY_ID] = dict(sockets)
+    for session_id, opted_in in acks.items():
+        manager.register_player_team(session_id, 10)
+        manager.acks.set_opt_in(session_id, LOBBY_ID, opted_in)
+    return manager, sockets
+
+
+def delivered(manager: LobbyWebSocketManager, call):
+    """Run a manager call and wait for what it queued to reach the sockets."""
+
+    async def run():
+        result = await call
+        await manager.flush()
+        return result
+
+    return asyncio.run(run())
+
+
+def game_started() -> GameStartedEvent:
+    return GameStartedEvent(team_id=10, puzzle_title="Cold Snap", puzzle_length=5)
+
+
+class TestAckTracker:
+    """Tests for keeping critical events until they're acknowledged."""
+
+    def test_only_opted_in_players_are_tracked(self):
+        tracker = AckTracker()
+        tracker.set_opt_in("alice", LOBBY_ID, True)
+
+        tracker.track("alice", LOBBY_ID, numbered(1), now=0)
+        tracker.track("bob", LOBBY_ID, numbered(1), now=0)
+
+        assert tracker.unacked_count() == 1
+        assert tracker.ack("alice", 1)
+        assert not tracker.ack("alice", 1)
+        assert tracker.unacked_count() == 0
+
+    def test_overdue_events_are_resent_until_out_of_resends(self, monkeypatch):
+        monkeypatch.setattr(settings, "WS_ACK_TIMEOUT_SECONDS", 5.0)
+        monkeypatch.setattr(settings, "WS_ACK_MAX_RESENDS", 1)
+        tracker = AckTracker()
+        tracker.set_opt_in("alice", LOBBY_ID, True)
+        tracker.track("alice", LOBBY_ID, numbered(1), now=0)
+
+        assert tracker.due(4, lambda _: True) == []
+        assert [entry.event.data["seq"] for _, entry in tracker.due(5, lambda _: True)] == [1]
+        assert tracker.due(10, lambda _: True) == []
+        assert (tracker.resends, tracker.expired, tracker.unacked_count()) == (1, 1, 0)
+
+    def test_oldest_events_are_dropped_past_the_per_player_cap(self, monkeypatch):
+        monkeypatch.setattr(settings, "WS_ACK_MAX_PENDING_PER_PLAYER", 2)
+        tracker = AckTracker()
+        tracker.set_opt_in("alice", LOBBY_ID, True)
+        for seq in (1, 2, 3):
+            tracker.track("alice", LOBBY_ID, numbered(seq), now=0)
+
+        assert [entry.event.data["seq"] for entry in tracker.pending_for("alice")] == [2, 3]
+        assert tracker.expired == 1
+
+    def test_disconnected_players_keep_their_resends(self, monkeypatch):
+        monkeypatch.setattr(settings, "WS_ACK_TIMEOUT_SECONDS", 5.0)
+        tracker = AckTracker()
+        tracker.set_opt_in("alice", LOBBY_ID, True)
+        tracker.track("alice", LOBBY_ID, numbered(1), now=0)
+
+        assert tracker.due(60, lambda _: False) == []
+        assert tracker.pending_for("alice")[0].resends == 0
+
+
+class TestCriticalBroadcasts:
+    """Tests for critical team broadcasts through the lobby websocket manager."""
+
+    def test_game_start_asks_for_an_ack(self):
+        manager, sockets = manager_with_team({"alice": True, "bob": False})
+
+        delivered(manager, manager.broadcast_to_team(LOBBY_ID, 10, game_started(), requires_ack=True))
+
+        assert sockets["bob"].sent[0]["ack_required"] is True
+        assert manager.acks.unacked_count("alice") == 1
+        assert manager.acks.unacked_count("bob") == 0
+
+    def test_unacked_game_start_is_resent(self, monkeypatch):
+        monkeypatch.setattr(settings, "WS_ACK_TIMEOUT_SECONDS", 0.0)
+        manager, sockets = manager_with_team({"alice": True})
+        delivered(manager, manager.broadcast_to_team(LOBBY_ID, 10, game_started(), requires_ack=True))
+
+        monkeypatch.setattr("backend.websocket.managers.lobby_websocket_manager", manager)
+        delivered(manager, ack_tick(now=10**9))
+
+        assert [event["seq"] for event in sockets["alice"].sent] == [1, 1]
+
+    def test_ack_message_clears_the_event(self):
+        manager, _ = manager_with_team({"alice": True})
+        delivered(manager, manager.broadcast_to_team(LOBBY_ID, 10, game_started(), requires_ack=True))
+
+        asyncio.run(manager.handle_game_message(LOBBY_ID, "alice", {"action": "ack", "seq": 1}))
+
+        assert manager.acks.unacked_count() == 0
@@ -0,0 +1,79 @@
+"""Tests for admin websocket messages."""
+
+import sys
+from pathlib import Path
+
+import pytest
+from pydantic import ValidationError
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_PASSWORD, admin_websocket_url
+from backend.websocket.admin_messages import (
+    AdminPongMessage,
+    PresenceMessage,
+    RequestLobbyStateMessage,
+    SubscribeLobbyMessage,
+    UnsubscribeLobbyMessage,
+    parse_admin_message,
+)
+
+
+class TestParseAdminMessage:
+    """Tests for routing frames to an AdminMessage by action."""
+
+    @pytest.mark.parametrize(
+        "data, expected_type",
+        [
+            ({"action": "subscribe_lobby", "lobby_id": 1}, SubscribeLobbyMessage),
+            ({"action": "unsubscribe_lobby", "lobby_id": 1}, UnsubscribeLobbyMessage),
+            ({"action": "presence", "lobby_id": 1, "team_id": None}, PresenceMessage),
+            ({"action": "request_lobby_state", "lobby_id": 1}, RequestLobbyStateMessage),
+            ({"action": "pong"}, AdminPongMessage),
+        ],
+    )
+    def test_each_action_parses(self, data, expected_type):
+        """Every supported action becomes its own message type."""
+        assert isinstance(parse_admin_message(data), expected_type)
+
+    @pytest.mark.parametrize(
+        "data",
+        [
+            {"action": "shutdown"},
+            {"action": "request_lobby_state"},
+            {"action": "subscribe_lobby", "lobby_id": "lobby"},
+        ],
+    )
+    def test_invalid_messages_are_rejected(self, data):
+        """Unknown actions and missing or mistyped lobby ids fail validation."""
+        with pytest.raises(ValidationError):
+            parse_admin_message(data)
+
+
+@pytest.mark.integration
+class TestRequestLobbyState:
+    """Tests for answering request_lobby_state over the admin websocket."""
+
+    def test_replies_with_lobby_snapshot(self, client):
+        """The requesting admin gets the same LobbyInfo the HTTP endpoint returns."""
+        lobby = client.post("/api/admin/lobby", json={"name": "Snapshot Night"}, headers=ADMIN_HEADERS).json()
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"})
+        session = client.post("/api/admin/login", json={"password": ADMIN_PASSWORD}).json()
+
+        with client.websocket_connect(admin_websocket_url(client, session)) as websocket:
+            websocket.send_json({"action": "request_lobby_state", "lobby_id": lobby["id"]})
+            event = websocket.receive_json()
+
+        assert event["type"] == "lobby_state"
+        assert event["state"]["lobby"]["name"] == "Snapshot Night"
+        assert [player["name"] for player in event["state"]["players"]] == ["Alice"]
+
+    def test_unknown_lobby_replies_with_no_state(self, client):
+        session = client.post("/api/admin/login", json={"password": ADMIN_PASSWORD}).json()
+
+        with client.websocket_connect(admin_websocket_url(client, session)) as websocket:
+            websocket.send_json({"action": "request_lobby_state", "lobby_id": 999})
+            event = websocket.receive_json()
+
+        assert event == {"type": "lobby_state", "lobby_id": 999, "state": None}
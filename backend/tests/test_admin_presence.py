@@ -0,0 +1,102 @@
+"""Tests for co-hosts seeing which team each other is editing."""
+
+import sys
+from contextlib import contextmanager
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_PASSWORD, admin_websocket_url
+
+
+@pytest.fixture
+def teams(client, lobby):
+    """The shared two-team lobby; returns (lobby id, team ids)."""
+    teams = client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()["teams"]
+    return lobby["id"], [team["id"] for team in teams]
+
+
+@contextmanager
+def watching(client, lobby_id: int):
+    """Log in as a fresh admin and subscribe to the lobby; yields (websocket, the id co-hosts see)."""
+    session = client.post("/api/admin/login", json={"password": ADMIN_PASSWORD}).json()
+    with client.websocket_connect(admin_websocket_url(client, session)) as websocket:
+        websocket.send_json({"action": "subscribe_lobby", "lobby_id": lobby_id})
+        sync(websocket, lobby_id)
+        yield websocket, session["session_id"][:8]
+
+
+def sync(websocket, lobby_id: int) -> list[dict]:
+    """Wait until every message sent before this point has arrived, returning them."""
+    websocket.send_json({"action": "request_lobby_state", "lobby_id": lobby_id})
+    received = []
+    while (message := websocket.receive_json())["type"] != "lobby_state":
+        received.append(message)
+    return received
+
+
+def next_presence(websocket) -> dict:
+    while (message := websocket.receive_json())["type"] != "admin_presence":
+        pass
+    return message
+
+
+@pytest.mark.integration
+class TestAdminPresence:
+    """Tests for the presence action on the admin websocket."""
+
+    def test_co_hosts_are_told_who_is_editing(self, client, teams):
+        lobby_id, team_ids = teams
+
+        with watching(client, lobby_id) as (alice, alice_id), watching(client, lobby_id) as (bob, _):
+            alice.send_json({"action": "presence", "lobby_id": lobby_id, "team_id": team_ids[0]})
+            editing = next_presence(bob)
+            alice.send_json({"action": "presence", "lobby_id": lobby_id, "team_id": None})
+            stopped = next_presence(bob)
+
+            # Alice isn't told about her own edits
+            assert [message["type"] for message in sync(alice, lobby_id)].count("admin_presence") == 0
+
+        assert (editing["lobby_id"], editing["admin_id"], editing["team_id"]) == (lobby_id, alice_id, team_ids[0])
+        assert (stopped["admin_id"], stopped["team_id"]) == (alice_id, None)
+
+    def test_late_subscribers_see_edits_already_underway(self, client, teams):
+        lobby_id, team_ids = teams
+
+        with watching(client, lobby_id) as (alice, alice_id):
+            alice.send_json({"action": "presence", "lobby_id": lobby_id, "team_id": team_ids[1]})
+            sync(alice, lobby_id)
+            session = client.post("/api/admin/login", json={"password": ADMIN_PASSWORD}).json()
+            with client.websocket_connect(admin_websocket_url(client, session)) as bob:
+                # Sent straight after the subscription, before anything Bob asks for
+                bob.send_json({"action": "subscribe_lobby", "lobby_id": lobby_id})
+                existing = next_presence(bob)
+
+        assert (existing["admin_id"], existing["team_id"]) == (alice_id, team_ids[1])
+
+    def test_leaving_releases_the_team(self, client, teams):
+        lobby_id, team_ids = teams
+
+        with watching(client, lobby_id) as (bob, _):
+            with watching(client, lobby_id) as (alice, alice_id):
+                alice.send_json({"action": "presence", "lobby_id": lobby_id, "team_id": team_ids[0]})
+                next_presence(bob)
+
+            released = next_presence(bob)
+
+        assert (released["admin_id"], released["team_id"]) == (alice_id, None)
+
+    def test_unsubscribing_releases_the_team(self, client, teams):
+        lobby_id, team_ids = teams
+
+        with watching(client, lobby_id) as (alice, alice_id), watching(client, lobby_id) as (bob, _):
+            alice.send_json({"action": "presence", "lobby_id": lobby_id, "team_id": team_ids[0]})
+            next_presence(bob)
+            alice.send_json({"action": "unsubscribe_lobby", "lobby_id": lobby_id})
+
+            released = next_presence(bob)
+
+        assert (released["admin_id"], released["team_id"]) == (alice_id, None)
@@ -0,0 +1,193 @@
+"""Tests for issuing, expiring and revoking admin web sessions."""
+
+import sys
+from datetime import timedelta
+from pathlib import Path
+
+import pytest
+from starlette.websockets import WebSocketDisconnect
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.admin_sessions import AdminSessionStore
+from backend.settings import settings
+from backend.tests.helpers import ADMIN_PASSWORD, admin_websocket_url
+
+
+class TestAdminSessionStore:
+    """Tests for validating session ids against the store."""
+
+    def test_only_issued_ids_are_valid(self):
+        """An id the store never issued is rejected rather than adopted."""
+        store = AdminSessionStore()
+        session = store.create(ip="127.0.0.1")
+
+        assert store.touch(session.session_id) is session
+        assert store.touch("made-up-id") is None
+
+    def test_idle_sessions_expire(self):
+        """Activity pushes the expiry back; going idle past it drops the session."""
+        store = AdminSessionStore()
+        session = store.create(ip=None)
+        idle = timedelta(hours=settings.ADMIN_SESSION_IDLE_HOURS)
+
+        almost_idle = session.last_activity_at + idle - timedelta(minutes=1)
+        assert store.touch(session.session_id, now=almost_idle) is session
+        assert session.expires_at == almost_idle + idle
+
+        assert store.touch(session.session_id, now=almost_idle + idle) is None
+        assert store.list() == []
+
+    def test_revoked_sessions_are_rejected(self):
+        store = AdminSessionStore()
+        session = store.create(ip=None)
+
+        assert store.revoke(session.session_id) is True
+        assert store.touch(session.session_id) is None
+        assert store.revoke(session.session_id) is False
+
+    def test_revoked_tokens_are_remembered_until_they_would_expire(self):
+        store = AdminSessionStore()
+        session = store.create(ip=None)
+        store.revoke(session.session_id)
+
+        assert store.authenticate(session.token) is None
+        assert store.is_revoked(session.token) is True
+        assert store.is_revoked("made-up-token") is False
+
+        store.prune_expired(now=session.expires_at)
+        assert store.is_revoked(session.token) is False
+
+    def test_tokens_authenticate_their_session(self):
+        store = AdminSessionStore()
+        session = store.create(ip=None)
+
+        assert store.authenticate(session.token) is session
+        assert store.authenticate(session.session_id) is None
+
+    def test_sessions_expire_after_max_lifetime_however_active(self):
+        store = AdminSessionStore()
+        session = store.create(ip=None)
+        lifetime = timedelta(hours=settings.ADMIN_SESSION_MAX_HOURS)
+
+        now = session.created_at
+        while now + timedelta(hours=1) < session.created_at + lifetime:
+            now += timedelta(hours=1)
+            assert store.authenticate(session.token, now=now) is session
+
+        assert store.authenticate(session.token, now=session.created_at + lifetime) is None
+
+
+@pytest.mark.integration
+class TestAdminSessionEndpoints:
+    """Tests for the login/check/logout endpoints and websocket validation."""
+
+    def login(self, client) -> dict:
+        response = client.post("/api/admin/login", json={"password": ADMIN_PASSWORD})
+        assert response.status_code == 200
+        return response.json()
+
+    def test_login_rejects_wrong_password(self, client):
+        assert client.post("/api/admin/login", json={"password": "guess"}).status_code == 401
+
+    def test_session_token_authenticates_admin_requests(self, client):
+        session = self.login(client)
+        headers = {"Authorization": f"Bearer {session['token']}"}
+
+        response = client.get("/api/admin/check", headers=headers)
+        assert response.status_code == 200
+        assert response.json()["session_id"] == session["session_id"]
+        assert session["is_owner"] is True
+
+    def test_raw_password_is_not_a_bearer_token(self, client):
+        """Only /login takes the password; everywhere else it's refused like any unknown token."""
+        headers = {"Authorization": f"Bearer {ADMIN_PASSWORD}"}
+
+        assert client.get("/api/admin/check", headers=headers).status_code == 401
+        assert client.get("/api/admin/lobby", headers=headers).status_code == 401
+        assert client.get("/api/admin/sessions", headers=headers).status_code == 401
+
+    def test_websocket_rejects_unissued_session(self, client):
+        with pytest.raises(WebSocketDisconnect):
+            with client.websocket_connect("/ws/admin/made-up-id?token=made-up-token") as websocket:
+                websocket.receive_text()
+
+    @pytest.mark.parametrize("credential", ["password", "session token"])
+    def test_websocket_only_takes_a_connect_token(self, client, credential):
+        """Neither the password nor the long-lived session token belong in the URL."""
+        session = self.login(client)
+        token = ADMIN_PASSWORD if credential == "password" else session["token"]
+
+        with pytest.raises(WebSocketDisconnect) as refused:
+            with client.websocket_connect(f"/ws/admin/{session['session_id']}?token={token}") as websocket:
+                websocket.receive_text()
+        assert (refused.value.code, refused.value.reason) == (1008, "Connect token is not valid")
+
+    def test_connect_tokens_only_open_their_own_session(self, client):
+        alice, bob = self.login(client), self.login(client)
+        url = admin_websocket_url(client, alice)
+
+        with pytest.raises(WebSocketDisconnect):
+            with client.websocket_connect(url.replace(alice["session_id"], bob["session_id"])) as websocket:
+                websocket.receive_text()
+
+    def test_logout_revokes_the_session(self, client):
+        """After logout neither the token nor a connect token issued before it work."""
+        session = self.login(client)
+        headers = {"Authorization": f"Bearer {session['token']}"}
+        url = admin_websocket_url(client, session)
+
+        response = client.post("/api/admin/logout", headers=headers)
+        assert response.status_code == 200
+
+        assert client.get("/api/admin/check", headers=headers).status_code == 401
+        assert client.post("/api/admin/ws-token", headers=headers).status_code == 401
+        with pytest.raises(WebSocketDisconnect):
+            with client.websocket_connect(url) as websocket:
+                websocket.receive_text()
+
+    def test_logout_only_ends_the_callers_session(self, client):
+        """Naming another admin's session in the body doesn't log them out."""
+        alice, bob = self.login(client), self.login(client)
+        bob_headers = {"Authorization": f"Bearer {bob['token']}"}
+
+        response = client.post(
+            "/api/admin/logout",
+            json={"session_id": bob["session_id"]},
+            headers={"Authorization": f"Bearer {alice['token']}"},
+        )
+        assert response.status_code == 200
+
+        assert client.get("/api/admin/check", headers=bob_headers).status_code == 200
+        assert client.get("/api/admin/check", headers={"Authorization": f"Bearer {alice['token']}"}).status_code == 401
+
+    def test_owner_revocation_cuts_off_rest_and_websocket(self, client):
+        """A session the owner revokes loses its open websocket and can't use its token again anywhere."""
+        owner = self.login(client)
+        admin = self.login(client)
+        owner_headers = {"Authorization": f"Bearer {owner['token']}"}
+        admin_headers = {"Authorization": f"Bearer {admin['token']}"}
+        admin_url = admin_websocket_url(client, admin)
+        assert client.get("/api/admin/lobby", headers=admin_headers).status_code == 200
+
+        with client.websocket_connect(admin_url) as websocket:
+            response = client.delete(f"/api/admin/sessions/{admin['session_id']}", headers=owner_headers)
+            assert response.status_code == 200
+            with pytest.raises(WebSocketDisconnect) as closed:
+                while True:
+                    websocket.receive_text()
+        assert closed.value.code == 1008
+
+        response = client.get("/api/admin/lobby", headers=admin_headers)
+        assert response.status_code == 401
+        assert response.json()["detail"] == "Admin session revoked"
+        assert client.get("/api/admin/sessions", headers=admin_headers).status_code == 401
+        assert client.post("/api/admin/ws-token", headers=admin_headers).json()["detail"] == "Admin session revoked"
+        # Its connect token, still within its lifetime, no longer opens the websocket either
+        with pytest.raises(WebSocketDisconnect) as refused:
+            with client.websocket_connect(admin_url) as websocket:
+                websocket.receive_text()
+        assert refused.value.code == 1008
+        # The owner's own session is untouched
+        assert client.get("/api/admin/lobby", headers=owner_headers).status_code == 200
@@ -0,0 +1,124 @@
+"""Unit tests for the admin websocket manager's lobby subscriber index."""
+
+import asyncio
+import json
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.websocket.managers import AdminWebSocketManager
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+        self.closed = False
+
+    async def accept(self):
+        pass
+
+    async def send_text(self, text):
+        self.sent.append(json.loads(text))
+
+    async def close(self, code=1000, reason=None):
+        self.closed = True
+
+
+def connect_admins(manager: AdminWebSocketManager, *session_ids: str) -> dict:
+    sockets = {session_id: FakeWebSocket() for session_id in session_ids}
+    for session_id, websocket in sockets.items():
+        asyncio.run(manager.connect(websocket, session_id))
+    return sockets
+
+
+def assert_index_matches_connections(manager: AdminWebSocketManager):
+    expected = {}
+    for session_id, connection in manager.admin_websockets.items():
+        for lobby_id in connection["subscribed_lobbies"]:
+            expected.setdefault(lobby_id, set()).add(session_id)
+    assert manager.lobby_subscribers == expected
+
+
+class TestLobbySubscriberIndex:
+    """Tests for keeping lobby_subscribers in step with admin subscriptions."""
+
+    def test_subscribe_adds_to_index(self):
+        """Each subscription lands under its lobby, once."""
+        manager = AdminWebSocketManager()
+        connect_admins(manager, "admin-a", "admin-b")
+
+        asyncio.run(manager.subscribe_to_lobby("admin-a", 1))
+        asyncio.run(manager.subscribe_to_lobby("admin-a", 1))
+        asyncio.run(manager.subscribe_to_lobby("admin-b", 1))
+        asyncio.run(manager.subscribe_to_lobby("admin-b", 2))
+
+        assert manager.lobby_subscribers == {1: {"admin-a", "admin-b"}, 2: {"admin-b"}}
+        assert_index_matches_connections(manager)
+
+    def test_unsubscribe_drops_empty_lobbies(self):
+        """Unsubscribing the last admin removes the lobby entry entirely."""
+        manager = AdminWebSocketManager()
+        connect_admins(manager, "admin-a")
+        asyncio.run(manager.subscribe_to_lobby("admin-a", 1))
+
+        asyncio.run(manager.unsubscribe_from_lobby("admin-a", 1))
+        asyncio.run(manager.unsubscribe_from_lobby("admin-a", 1))
+
+        assert manager.lobby_subscribers == {}
+        assert_index_matches_connections(manager)
+
+    def test_disconnect_removes_session_from_every_lobby(self):
+        """A disconnected admin disappears from all its lobbies, leaving other subscribers in place."""
+        manager = AdminWebSocketManager()
+        sockets = connect_admins(manager, "admin-a", "admin-b")
+        for lobby_id in (1, 2, 3):
+            asyncio.run(manager.subscribe_to_lobby("admin-a", lobby_id))
+        asyncio.run(manager.subscribe_to_lobby("admin-b", 2))
+
+        asyncio.run(manager.disconnect("admin-a"))
+
+        assert sockets["admin-a"].closed
+        assert manager.lobby_subscribers == {2: {"admin-b"}}
+        assert_index_matches_connections(manager)
+
+    def test_disconnect_releases_presence_without_sending_to_departed_admin(self):
+        """The presence release on disconnect reaches the remaining co-hosts only."""
+        manager = AdminWebSocketManager()
+        sockets = connect_admins(manager, "admin-a", "admin-b")
+        asyncio.run(manager.subscribe_to_lobby("admin-a", 1))
+        asyncio.run(manager.subscribe_to_lobby("admin-b", 1))
+        asyncio.run(manager.set_presence("admin-a", 1, 7))
+        sockets["admin-a"].sent.clear()
+        sockets["admin-b"].sent.clear()
+
+        asyncio.run(manager.disconnect("admin-a"))
+
+        assert sockets["admin-a"].sent == []
+        assert [event["team_id"] for event in sockets["admin-b"].sent] == [None]
+
+    def test_reconnect_starts_without_subscriptions(self):
+        """Reconnecting with the same web session clears the old socket's lobbies from the index."""
+        manager = AdminWebSocketManager()
+        connect_admins(manager, "admin-a")
+        asyncio.run(manager.subscribe_to_lobby("admin-a", 1))
+
+        connect_admins(manager, "admin-a")
+
+        assert manager.lobby_subscribers == {}
+        assert_index_matches_connections(manager)
+
+    def test_broadcast_reaches_only_subscribers(self):
+        """Lobby broadcasts go to that lobby's admins and honour the excluded session."""
+        manager = AdminWebSocketManager()
+        sockets = connect_admins(manager, "admin-a", "admin-b", "admin-c")
+        asyncio.run(manager.subscribe_to_lobby("admin-a", 1))
+        asyncio.run(manager.subscribe_to_lobby("admin-b", 1))
+        asyncio.run(manager.subscribe_to_lobby("admin-c", 2))
+
+        asyncio.run(manager.broadcast_to_lobby(1, {"type": "ping"}, exclude_web_session_id="admin-b"))
+
+        assert sockets["admin-a"].sent == [{"type": "ping"}]
+        assert sockets["admin-b"].sent == []
+        assert sockets["admin-c"].sent == []
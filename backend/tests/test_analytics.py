@@ -0,0 +1,93 @@
+"""Unit tests for puzzle analytics aggregation."""
+
+from datetime import datetime, timedelta, timezone
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.game.analytics import GuessRecord, aggregate_rung_stats, difficulty_curve, solve_times_for_play
+
+START = datetime(2026, 1, 1, tzinfo=timezone.utc)
+
+
+def guess(play_key, word_index, is_correct, seconds, word=""):
+    return GuessRecord(
+        play_key=play_key,
+        word_index=word_index,
+        is_correct=is_correct,
+        created_at=START + timedelta(seconds=seconds),
+        guess=word,
+    )
+
+
+class TestSolveTimes:
+    """Tests for per-team rung solve times."""
+
+    def test_solve_time_measured_from_previous_solve(self):
+        """Each rung's time starts at the team's previous solve."""
+        play = (1, 1)
+        times = solve_times_for_play(START, [guess(play, 1, True, 30), guess(play, 2, True, 100)])
+
+        assert times == {1: 30.0, 2: 70.0}
+
+    def test_only_first_correct_guess_counts(self):
+        """Duplicate correct guesses for a rung are ignored."""
+        play = (1, 1)
+        times = solve_times_for_play(START, [guess(play, 1, True, 10), guess(play, 1, True, 50)])
+
+        assert times == {1: 10.0}
+
+
+class TestRungAggregation:
+    """Tests for aggregating guesses across teams."""
+
+    def test_aggregates_hidden_rungs_only(self):
+        """First and last rungs are pre-revealed and excluded."""
+        rungs, _ = aggregate_rung_stats(5, {(1, 1): START}, [])
+
+        assert [rung.word_index for rung in rungs] == [1, 2, 3]
+
+    def test_solve_rate_and_wrong_guesses(self):
+        """Unsolved rungs lower the solve rate; wrong guesses are counted per rung."""
+        plays = {(1, 1): START, (1, 2): START}
+        guesses = [
+            guess((1, 1), 1, False, 5),
+            guess((1, 1), 1, True, 20),
+            guess((1, 2), 1, False, 8),
+        ]
+        rungs, play_times = aggregate_rung_stats(5, plays, guesses)
+
+        assert rungs[0].teams_attempted == 2
+        assert rungs[0].teams_solved == 1
+        assert rungs[0].solve_rate == 0.5
+        assert rungs[0].wrong_guesses == 2
+        assert rungs[0].median_solve_seconds == 20.0
+        assert play_times[(1, 2)] == {}
+
+    def test_common_wrong_answers_across_teams(self):
+        """Wrong answers are counted case-insensitively across every team, most guessed first."""
+        plays = {(1, 1): START, (1, 2): START, (2, 1): START}
+        guesses = [
+            guess((1, 1), 1, False, 5, "loaf"),
+            guess((1, 2), 1, False, 6, "Bread "),
+            guess((2, 1), 1, False, 7, "BREAD"),
+            guess((2, 1), 1, False, 9, "crust"),
+            guess((2, 1), 1, True, 12, "toast"),
+        ]
+        rungs, _ = aggregate_rung_stats(5, plays, guesses)
+
+        assert rungs[0].common_wrong_answers(2) == [("BREAD", 2), ("CRUST", 1)]
+
+
+class TestDifficultyCurve:
+    """Tests for the relative difficulty curve."""
+
+    def test_curve_relative_to_median(self):
+        """Rungs are scaled against the puzzle-wide median; unsolved rungs are 0."""
+        plays = {(1, 1): START}
+        guesses = [guess((1, 1), 1, True, 10), guess((1, 1), 2, True, 40)]
+        rungs, _ = aggregate_rung_stats(5, plays, guesses)
+
+        assert difficulty_curve(rungs) == [0.5, 1.5, 0.0]
@@ -0,0 +1,66 @@
+"""Integration tests for building a working app with AppBuilder."""
+
+import sys
+from pathlib import Path
+
+import pytest
+from fastapi.testclient import TestClient
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.app_builder import AppBuilder
+from backend.offline_guesses import player_sightings
+from backend.settings import settings
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_PASSWORD, ADMIN_TOKEN
+
+
+@pytest.mark.integration
+class TestAppBuilder:
+    """Tests for the in-memory app harness."""
+
+    def test_api_root(self, client):
+        """The built app serves the API without a built frontend."""
+        response = client.get("/api")
+
+        assert response.status_code == 200
+
+    def test_admin_and_player_flow(self, client):
+        """An admin can create a lobby and a player can join it."""
+        lobby = client.post("/api/admin/lobby", json={"name": "Harness"}, headers=ADMIN_HEADERS).json()
+
+        player = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+        info = client.get(
+            f"/api/lobby/{lobby['id']}", headers={"Authorization": f"Bearer {player['session_id']}"}
+        ).json()
+
+        assert [p["name"] for p in info["players"]] == ["Alice"]
+
+    def test_each_build_starts_empty(self, client):
+        """Building again gives a fresh database."""
+        client.post("/api/admin/lobby", json={"name": "First"}, headers=ADMIN_HEADERS)
+
+        builder = AppBuilder().setting("ADMIN_PASSWORD", ADMIN_PASSWORD).admin_session(ADMIN_TOKEN)
+        with builder as rebuilt, TestClient(rebuilt) as fresh_client:
+            assert fresh_client.get("/api/admin/lobby", headers=ADMIN_HEADERS).json()["total"] == 0
+
+    def test_each_build_resets_in_memory_state(self):
+        """Singletons registered with on_reset start over, e.g. who the server has seen."""
+        player_sightings.seen("left-over")
+
+        with AppBuilder():
+            assert player_sightings.last_seen_at == {}
+
+    def test_settings_are_put_back(self):
+        """Overrides only last as long as the with block, so they don't leak into later tests."""
+        before = settings.ADMIN_PASSWORD
+
+        with AppBuilder().setting("ADMIN_PASSWORD", "scoped-secret"):
+            assert settings.ADMIN_PASSWORD == "scoped-secret"
+
+        assert settings.ADMIN_PASSWORD == before
+
+    def test_unknown_setting_rejected(self):
+        """Typos in setting names fail loudly instead of being ignored."""
+        with pytest.raises(ValueError, match="Unknown setting"):
+            AppBuilder().setting("ADMIN_PASWORD", "oops")
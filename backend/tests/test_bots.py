@@ -0,0 +1,92 @@
+"""Tests for drop-in bot players."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.bots import bot_names, bot_target_rung
+from backend.game.puzzles import LadderStep
+from backend.tests.helpers import ADMIN_HEADERS
+
+
+def ladder(*open_ended: bool) -> list[LadderStep]:
+    return [LadderStep(word=f"WORD{index}", open_ended=flag) for index, flag in enumerate(open_ended)]
+
+
+class TestBotNames:
+    """Tests for naming new bots."""
+
+    def test_numbers_count_up(self):
+        assert bot_names(["Alice"], 2) == ["Bot 1", "Bot 2"]
+
+    def test_freed_numbers_are_reused(self):
+        """A removed bot's number goes to the next one added."""
+        assert bot_names(["Bot 1", "Bot 3"], 2) == ["Bot 2", "Bot 4"]
+
+
+class TestBotTargetRung:
+    """Tests for choosing which rung a bot solves."""
+
+    def test_first_unrevealed_from_the_top(self):
+        assert bot_target_rung(ladder(False, False, False, False, False), {0, 1, 4}) == 2
+
+    def test_open_ended_rungs_are_left_to_players(self):
+        """The host judges open-ended answers, so bots skip past them."""
+        assert bot_target_rung(ladder(False, True, False, False, False), {0, 4}) == 2
+
+    def test_nothing_left(self):
+        assert bot_target_rung(ladder(False, False, False, False, False), {0, 1, 2, 3, 4}) is None
+
+
+@pytest.fixture
+def app_builder(app_builder):
+    return app_builder.setting("MAX_BOTS_PER_LOBBY", 3)
+
+
+@pytest.mark.integration
+class TestAddBots:
+    """Tests for POST /api/admin/lobby/{lobby_id}/bots."""
+
+    def test_bots_fill_the_smallest_teams(self, client):
+        """With three players on two teams, one bot evens them out and the next starts on the first team."""
+        lobby = client.post("/api/admin/lobby", json={"name": "Short Handed"}, headers=ADMIN_HEADERS).json()
+        for name in ("Alice", "Bob", "Carol"):
+            client.post(f"/api/lobby/{lobby['code']}", json={"name": name})
+        client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+
+        response = client.post(f"/api/admin/lobby/{lobby['id']}/bots?count=1&skill=0.5", headers=ADMIN_HEADERS)
+        assert response.status_code == 200
+        [bot] = response.json()
+        assert (bot["name"], bot["is_bot"], bot["bot_skill"], bot["is_ready"]) == ("Bot 1", True, 0.5, True)
+
+        players = client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()["players"]
+        team_sizes = sorted(
+            sum(1 for player in players if player["team_id"] == team_id)
+            for team_id in {player["team_id"] for player in players}
+        )
+        assert team_sizes == [2, 2]
+
+    def test_bot_cap_is_enforced(self, client):
+        lobby = client.post("/api/admin/lobby", json={"name": "Bot Heavy"}, headers=ADMIN_HEADERS).json()
+
+        assert client.post(f"/api/admin/lobby/{lobby['id']}/bots?count=3", headers=ADMIN_HEADERS).status_code == 200
+        assert client.post(f"/api/admin/lobby/{lobby['id']}/bots?count=1", headers=ADMIN_HEADERS).status_code == 400
+
+    def test_unknown_lobby(self, client):
+        assert client.post("/api/admin/lobby/999/bots?count=1", headers=ADMIN_HEADERS).status_code == 404
+
+    def test_player_cap_is_enforced(self, client):
+        """Bots take roster slots, so they can't push a lobby past its max_players."""
+        lobby = client.post("/api/admin/lobby", json={"name": "Full House"}, headers=ADMIN_HEADERS).json()
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"})
+        client.patch(f"/api/admin/lobby/{lobby['id']}", json={"max_players": 2}, headers=ADMIN_HEADERS)
+
+        refused = client.post(f"/api/admin/lobby/{lobby['id']}/bots?count=2", headers=ADMIN_HEADERS)
+
+        assert refused.status_code == 409
+        assert refused.json()["detail"]["code"] == "LOBBY_FULL"
+        assert client.post(f"/api/admin/lobby/{lobby['id']}/bots?count=1", headers=ADMIN_HEADERS).status_code == 200
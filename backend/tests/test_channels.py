@@ -0,0 +1,108 @@
+"""Unit tests for per-lobby broadcast channels and their per-connection outboxes."""
+
+import asyncio
+import json
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.settings import settings
+from backend.websocket.channels import TRY_AGAIN_LATER
+from backend.websocket.managers import AdminWebSocketManager, LobbyWebSocketManager
+from backend.websocket.send_stats import player_key
+
+LOBBY_ID = 1
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+        self.closed_with = None
+
+    async def send_text(self, text):
+        self.sent.append(json.loads(text)["type"])
+
+    async def close(self, code=1000, reason=None):
+        self.closed_with = code
+
+
+class StuckWebSocket(FakeWebSocket):
+    """A client that stops reading: sends hang until `release` is set."""
+
+    def __init__(self):
+        super().__init__()
+        self.release = asyncio.Event()
+
+    async def send_text(self, text):
+        await self.release.wait()
+        await super().send_text(text)
+
+
+def manager_with(sockets: dict) -> LobbyWebSocketManager:
+    manager = LobbyWebSocketManager(admin_web_socket_manager=AdminWebSocketManager())
+    manager.lobby_websockets[LOBBY_ID] = dict(sockets)
+    return manager
+
+
+class TestLobbyChannel:
+    """Tests for queueing broadcasts instead of awaiting every socket."""
+
+    def test_stuck_client_does_not_hold_up_the_broadcast(self):
+        async def scenario():
+            fast, stuck = FakeWebSocket(), StuckWebSocket()
+            manager = manager_with({"alice": fast, "bob": stuck})
+
+            await asyncio.wait_for(manager.broadcast_to_lobby(LOBBY_ID, {"type": "timer_expired"}), timeout=1)
+            await asyncio.sleep(0.01)
+            received_before_release = (list(fast.sent), list(stuck.sent), manager.backlog(LOBBY_ID, player_key("bob")))
+
+            stuck.release.set()
+            await manager.flush()
+            return received_before_release, stuck.sent
+
+        before, after = asyncio.run(scenario())
+
+        assert before == (["timer_expired"], [], 0)
+        assert after == ["timer_expired"]
+
+    def test_lagging_client_is_disconnected_to_catch_up(self, monkeypatch):
+        """Past WS_OUTBOX_SIZE queued events the client is closed with 1013; everyone else keeps receiving."""
+        monkeypatch.setattr(settings, "WS_OUTBOX_SIZE", 2)
+
+        async def scenario():
+            fast, stuck = FakeWebSocket(), StuckWebSocket()
+            manager = manager_with({"alice": fast, "bob": stuck})
+            reports = [await manager.broadcast_to_lobby(LOBBY_ID, {"type": "guess_submitted"}) for _ in range(4)]
+            await manager.flush()
+            return fast, stuck, reports
+
+        fast, stuck, reports = asyncio.run(scenario())
+
+        assert fast.sent == ["guess_submitted"] * 4
+        assert stuck.closed_with == TRY_AGAIN_LATER
+        assert [report.failed for report in reports] == [0, 0, 1, 1]
+        assert reports[2].failures[0].reason.startswith("lagging")
+
+    def test_events_to_one_player_keep_their_order(self):
+        async def scenario():
+            alice = FakeWebSocket()
+            manager = manager_with({"alice": alice})
+            await manager.send_to_player(LOBBY_ID, "alice", {"type": "lobby_state"})
+            await manager.broadcast_to_lobby(LOBBY_ID, {"type": "timer_expired"})
+            await manager.send_to_player(LOBBY_ID, "alice", {"type": "pong"}, replayable=False)
+            await manager.flush()
+            return alice.sent
+
+        assert asyncio.run(scenario()) == ["lobby_state", "timer_expired", "pong"]
+
+    def test_last_disconnect_drops_the_lobby_channel(self):
+        async def scenario():
+            manager = manager_with({"alice": FakeWebSocket()})
+            await manager.send_to_player(LOBBY_ID, "alice", {"type": "pong"}, replayable=False)
+            had_channel = LOBBY_ID in manager.channels
+            await manager.disconnect(LOBBY_ID, "alice")
+            return had_channel, manager.channels
+
+        assert asyncio.run(scenario()) == (True, {})
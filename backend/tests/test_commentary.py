@@ -0,0 +1,95 @@
+"""Tests for the spectator commentary feed."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.commentary import commentary_feed
+from backend.game import commentary
+from backend.settings import settings
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+from backend.websocket.events import CommentaryEvent
+
+
+class TestCommentaryLines:
+    """Tests for the narration sentences."""
+
+    def test_solves_count_the_guesses(self):
+        assert commentary.rung_solved("Red Foxes", 3, 4) == "Red Foxes solved rung 3 after 4 guesses!"
+        assert commentary.rung_solved("Red Foxes", 1, 1) == "Red Foxes solved rung 1 after 1 guess!"
+
+    @pytest.mark.parametrize(
+        "placement, expected",
+        [(1, "first!"), (2, "in 2nd place."), (3, "in 3rd place."), (11, "in 11th place."), (22, "in 22nd place.")],
+    )
+    def test_placements(self, placement, expected):
+        assert commentary.ladder_completed("Owls", placement) == f"Owls finished the ladder {expected}"
+
+    def test_round_without_a_winner(self):
+        assert commentary.round_won(2, None) == "Round 2 is over."
+
+
+class TestCommentaryFeed:
+    """Tests for the in-memory feed."""
+
+    def test_keeps_the_latest_lines(self, monkeypatch):
+        monkeypatch.setattr(settings, "COMMENTARY_HISTORY_SIZE", 2)
+        feed = type(commentary_feed)()
+        for text in ("one", "two", "three"):
+            feed.record(CommentaryEvent(lobby_id=1, text=text, created_at=""))
+
+        assert [line.text for line in feed.recent(1)] == ["two", "three"]
+        assert feed.recent(2) == []
+
+
+def play_one_rung(client, lobby: dict, visibility: str = "exact"):
+    client.put(
+        f"/api/admin/lobby/{lobby['id']}/progress-visibility", json={"visibility": visibility}, headers=ADMIN_HEADERS
+    )
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    headers = {"Authorization": f"Bearer {alice['session_id']}"}
+    for word in ("ICE", "FLAKE"):
+        client.post("/api/lobby/guess", json={"guess": word, "word_index": 1}, headers=headers)
+
+
+@pytest.mark.integration
+class TestCommentaryRoute:
+    """Tests for GET /api/lobby/{lobby_id}/commentary."""
+
+    def test_narrates_the_game(self, client):
+        lobby = client.post("/api/admin/lobby", json={"name": "Public Night"}, headers=ADMIN_HEADERS).json()
+        play_one_rung(client, lobby)
+
+        response = client.get(f"/api/lobby/{lobby['id']}/commentary")
+
+        assert response.status_code == 200
+        lines = [line["text"] for line in response.json()["lines"]]
+        assert lines[0] == "Round 1 is under way with 2 teams racing!"
+        assert lines[1].endswith(" solved rung 1 after 2 guesses!")
+
+    def test_solves_stay_quiet_when_progress_is_hidden(self, client):
+        """Anyone can spectate, so narrating solves would show players what progress_visibility hides."""
+        lobby = client.post("/api/admin/lobby", json={"name": "Quiet Night"}, headers=ADMIN_HEADERS).json()
+        play_one_rung(client, lobby, visibility="none")
+
+        lines = [line["text"] for line in client.get(f"/api/lobby/{lobby['id']}/commentary").json()["lines"]]
+
+        assert lines == ["Round 1 is under way with 2 teams racing!"]
+
+    def test_private_lobbies_are_refused(self, client):
+        lobby = client.post("/api/admin/lobby", json={"name": "Private Night"}, headers=ADMIN_HEADERS).json()
+        client.patch(f"/api/admin/lobby/{lobby['id']}", json={"is_private": True}, headers=ADMIN_HEADERS)
+
+        assert client.get(f"/api/lobby/{lobby['id']}/commentary").status_code == 403
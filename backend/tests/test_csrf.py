@@ -0,0 +1,58 @@
+"""Integration tests for admin cookie sessions and their CSRF check."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.csrf import CSRF_COOKIE, CSRF_HEADER, SESSION_COOKIE, csrf_token_for
+from backend.settings import settings
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_PASSWORD
+
+
+@pytest.fixture
+def app_builder(app_builder):
+    return app_builder.setting("ADMIN_COOKIE_AUTH", True).setting("ADMIN_COOKIE_SECURE", False)
+
+
+@pytest.fixture
+def csrf(client) -> str:
+    """Log in, leaving the session cookies on the client; returns the CSRF token to echo."""
+    response = client.post("/api/admin/login", json={"password": ADMIN_PASSWORD})
+    assert response.status_code == 200
+    return response.cookies[CSRF_COOKIE]
+
+
+@pytest.mark.integration
+class TestAdminCookieAuth:
+    """Tests for cookie-authenticated admin requests."""
+
+    def test_login_sets_a_session_bound_csrf_token(self, client, csrf):
+        assert csrf == csrf_token_for(client.cookies[SESSION_COOKIE])
+
+    def test_cookie_authenticates_reads(self, client, csrf):
+        assert client.get("/api/admin/check").status_code == 200
+
+    def test_cookie_write_needs_the_csrf_header(self, client, csrf):
+        response = client.post("/api/admin/lobby", json={"name": "Forged"})
+
+        assert response.status_code == 403
+        assert response.json()["detail"]["code"] == "CSRF_FAILED"
+
+    def test_cookie_write_with_a_wrong_token_is_rejected(self, client, csrf):
+        response = client.post("/api/admin/lobby", json={"name": "Forged"}, headers={CSRF_HEADER: "0" * 64})
+        assert response.status_code == 403
+
+    def test_cookie_write_with_the_csrf_header(self, client, csrf):
+        response = client.post("/api/admin/lobby", json={"name": "Real"}, headers={CSRF_HEADER: csrf})
+        assert response.status_code == 200
+
+    def test_bearer_requests_are_exempt(self, client, csrf):
+        assert client.post("/api/admin/lobby", json={"name": "Script"}, headers=ADMIN_HEADERS).status_code == 200
+
+    def test_cookie_is_ignored_when_cookie_auth_is_off(self, client, csrf, monkeypatch):
+        monkeypatch.setattr(settings, "ADMIN_COOKIE_AUTH", False)
+        assert client.get("/api/admin/check").status_code == 401
@@ -0,0 +1,114 @@
+"""Tests for the db-reset command behind ./rt db-reset."""
+
+import sys
+from pathlib import Path
+
+import pytest
+import requests
+from fastapi.testclient import TestClient
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+import backend.database as database
+from backend import db_reset
+from backend.database.migrations import current_version, head_version
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_PASSWORD
+from backend.websocket.managers import lobby_websocket_manager
+
+
+@pytest.fixture
+def production_client(app_builder):
+    """A server without TESTING, so without /api/reset-db."""
+    with app_builder.setting("TESTING", False) as app, TestClient(app) as test_client:
+        yield test_client
+
+
+class ServerResponse:
+    """The parts of a requests.Response that reset_server reads, from a TestClient response."""
+
+    def __init__(self, response):
+        self.status_code = response.status_code
+        self.ok = response.is_success
+        self.text = response.text
+
+
+def route_to(monkeypatch, client):
+    """Send reset_server's request to the test app instead of over the network."""
+    monkeypatch.setattr(requests, "delete", lambda url, timeout: ServerResponse(client.delete(url)))
+
+
+def lobby_names(client, headers: dict = ADMIN_HEADERS) -> list[str]:
+    return [entry["lobby"]["name"] for entry in client.get("/api/admin/lobby", headers=headers).json()["items"]]
+
+
+def log_in(client) -> dict:
+    """Headers for a new admin session, since a server reset ends the existing ones."""
+    session = client.post("/api/admin/login", json={"password": ADMIN_PASSWORD}).json()
+    return {"Authorization": f"Bearer {session['token']}"}
+
+
+@pytest.mark.integration
+class TestDbReset:
+    """Tests for backend.db_reset.main."""
+
+    def test_refuses_without_yes(self, client, capsys):
+        client.post("/api/admin/lobby", json={"name": "Keep Me"}, headers=ADMIN_HEADERS)
+
+        assert db_reset.main([]) == 2
+        assert "--yes" in capsys.readouterr().err
+        assert lobby_names(client) == ["Keep Me"]
+
+    def test_local_reset_recreates_empty_tables_at_the_latest_version(self, client, capsys):
+        client.post("/api/admin/lobby", json={"name": "Old Lobby"}, headers=ADMIN_HEADERS)
+
+        assert db_reset.main(["--yes"]) == 0
+
+        assert "Reset database via" in capsys.readouterr().out
+        assert lobby_names(client) == []
+        with database.engine.connect() as connection:
+            assert current_version(connection) == head_version()
+        # Still usable afterwards
+        client.post("/api/admin/lobby", json={"name": "New Lobby"}, headers=ADMIN_HEADERS)
+        assert lobby_names(client) == ["New Lobby"]
+
+    def test_server_reset_goes_through_the_endpoint(self, client, monkeypatch, capsys):
+        client.post("/api/admin/lobby", json={"name": "Old Lobby"}, headers=ADMIN_HEADERS)
+        route_to(monkeypatch, client)
+
+        assert db_reset.main(["--yes", "--server", "http://testserver/"]) == 0
+
+        assert "http://testserver/api/reset-db" in capsys.readouterr().out
+        assert lobby_names(client, log_in(client)) == []
+
+    def test_server_reset_clears_in_memory_state(self, client, lobby, monkeypatch):
+        """Sockets and sessions for the dropped rows don't outlive them."""
+        player = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Carol"}).json()
+        token = client.post("/api/lobby/ws-token", headers={"Authorization": f"Bearer {player['session_id']}"}).json()
+        route_to(monkeypatch, client)
+
+        with client.websocket_connect(f"/ws/lobby/{lobby['id']}/player?token={token['token']}") as websocket:
+            assert db_reset.main(["--yes", "--server", "http://testserver"]) == 0
+            while websocket.receive_json()["type"] != "server_shutdown":
+                pass
+
+        assert lobby_websocket_manager.lobby_websockets == {}
+        assert client.get("/api/admin/lobby", headers=ADMIN_HEADERS).status_code == 401
+
+    def test_server_without_testing_mode_is_reported(self, production_client, monkeypatch, capsys):
+        production_client.post("/api/admin/lobby", json={"name": "Keep Me"}, headers=ADMIN_HEADERS)
+        route_to(monkeypatch, production_client)
+
+        assert db_reset.main(["--yes", "--server", "http://testserver"]) == 1
+
+        assert "TESTING=true" in capsys.readouterr().err
+        assert lobby_names(production_client) == ["Keep Me"]
+
+    def test_unreachable_server_is_reported(self, monkeypatch, capsys):
+        def refuse(url, timeout):
+            raise requests.ConnectionError("Connection refused")
+
+        monkeypatch.setattr(requests, "delete", refuse)
+
+        assert db_reset.main(["--yes", "--server", "http://localhost:1"]) == 1
+        assert "Couldn't reach http://localhost:1/api/reset-db" in capsys.readouterr().err
@@ -0,0 +1,137 @@
+"""Unit tests for websocket delivery reports."""
+
+import asyncio
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.websocket.delivery import DeliveryFailure, DeliveryReport, delivery_stats
+from backend.websocket.events import PlayerKickedEvent, TeamChatEvent
+from backend.websocket.managers import AdminWebSocketManager, LobbyWebSocketManager
+
+LOBBY_ID = 1
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+        self.closed_with = None
+
+    async def send_text(self, text):
+        self.sent.append(text)
+
+    async def close(self, code=1000, reason=None):
+        self.closed_with = code
+
+
+class BrokenWebSocket(FakeWebSocket):
+    async def send_text(self, text):
+        raise RuntimeError("socket is closed")
+
+
+@pytest.fixture(autouse=True)
+def fresh_stats():
+    delivery_stats.__init__()
+    yield
+    delivery_stats.__init__()
+
+
+def manager_with(sockets: dict, admins: dict | None = None) -> LobbyWebSocketManager:
+    admin_manager = AdminWebSocketManager()
+    for web_session_id, websocket in (admins or {}).items():
+        admin_manager.admin_websockets[web_session_id] = {
+            "websocket": websocket,
+            "subscribed_lobbies": [LOBBY_ID],
+            "editing_teams": {},
+        }
+        admin_manager.lobby_subscribers.setdefault(LOBBY_ID, set()).add(web_session_id)
+    manager = LobbyWebSocketManager(admin_web_socket_manager=admin_manager)
+    manager.lobby_websockets[LOBBY_ID] = dict(sockets)
+    return manager
+
+
+def broadcast_rounds(manager: LobbyWebSocketManager, rounds: int, event_type: str = "ping") -> list[DeliveryReport]:
+    """Broadcast to the lobby `rounds` times in one event loop, letting the outboxes drain after each."""
+
+    async def run():
+        reports = []
+        for _ in range(rounds):
+            reports.append(await manager.broadcast_to_lobby(LOBBY_ID, {"type": event_type}))
+            await manager.flush()
+        return reports
+
+    return asyncio.run(run())
+
+
+def chat() -> TeamChatEvent:
+    return TeamChatEvent(
+        team_id=10, player_id=1, player_name="Alice", text="try the bottom", sent_at="2026-01-01T00:00:00+00:00"
+    )
+
+
+class TestDeliveryReport:
+    """Tests for what broadcasts report back."""
+
+    def test_broken_player_socket_is_reported_once_its_forwarder_tried_it(self):
+        """Player copies are only queued, so a dead socket shows up from the broadcast after the failed send."""
+        players = {"alice": FakeWebSocket(), "bob": BrokenWebSocket()}
+        manager = manager_with(players, admins={"admin-1": FakeWebSocket()})
+
+        first, second = broadcast_rounds(manager, 2)
+
+        assert (first.attempted, first.succeeded, first.failed) == (3, 3, 0)
+        assert (second.attempted, second.succeeded, second.failed) == (3, 2, 1)
+        assert second.failures[0] == DeliveryFailure("player:bob", "RuntimeError: socket is closed")
+
+    def test_team_broadcast_only_counts_the_team(self):
+        manager = manager_with({"alice": FakeWebSocket(), "carol": FakeWebSocket()})
+        manager.register_player_team("alice", 10)
+
+        report = asyncio.run(manager.broadcast_to_team(LOBBY_ID, 10, chat()))
+
+        assert (report.event_type, report.attempted, report.succeeded) == ("team_chat", 1, 1)
+
+    def test_kick_reports_the_kicked_players_own_copy(self):
+        alice = FakeWebSocket()
+        manager = manager_with({"alice": alice, "bob": FakeWebSocket()})
+
+        async def kick():
+            report = await manager.kick_player(LOBBY_ID, "alice")
+            await manager.flush()
+            return report
+
+        report = asyncio.run(kick())
+
+        assert report.event_type == PlayerKickedEvent(lobby_id=LOBBY_ID, player_session_id="alice").type
+        assert (report.attempted, report.succeeded) == (2, 2)
+        assert len(alice.sent) == 1
+        assert alice.closed_with == 1008
+
+    def test_nobody_connected_is_an_empty_report(self):
+        report = asyncio.run(manager_with({}).send_to_player(LOBBY_ID, "ghost", {"type": "ping"}))
+        assert (report.attempted, report.failed) == (0, 0)
+
+    def test_summary_lists_a_few_failures(self):
+        report = DeliveryReport("game_started")
+        broken = manager_with({f"p{i}": BrokenWebSocket() for i in range(7)})
+        report.merge(broadcast_rounds(broken, 2, "game_started")[-1])
+
+        assert report.summary().startswith("game_started: 7/7 sends failed: player:p0 (RuntimeError")
+        assert report.summary().endswith("and 2 more")
+
+
+class TestDeliveryStats:
+    """Tests for the per-event-type totals behind GET /api/admin/connections/deliveries."""
+
+    def test_sends_are_tallied_by_event_type(self):
+        manager = manager_with({"alice": FakeWebSocket(), "bob": BrokenWebSocket()})
+
+        broadcast_rounds(manager, 2)
+
+        totals = delivery_stats.by_event_type["ping"]
+        assert (totals.attempted, totals.succeeded, totals.failed) == (4, 2, 2)
+        assert [recent.failure.connection for recent in delivery_stats.recent_failures] == ["player:bob"] * 2
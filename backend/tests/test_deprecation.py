@@ -0,0 +1,64 @@
+"""Unit tests for deprecated route matching and headers."""
+
+from datetime import date
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.deprecation import DeprecatedRoute, DeprecationTracker, deprecation_headers, find_deprecated_route
+
+
+class TestFindDeprecatedRoute:
+    """Tests for matching requests against configured deprecated routes."""
+
+    def test_matches_wildcard_pattern(self):
+        """Path patterns use fnmatch wildcards."""
+        routes = [DeprecatedRoute(pattern="/api/lobby/*/team")]
+
+        assert find_deprecated_route(routes, "GET", "/api/lobby/5/team") is routes[0]
+        assert find_deprecated_route(routes, "GET", "/api/lobby/5") is None
+
+    def test_method_restricts_match(self):
+        """A route with a method only matches that method, case-insensitively."""
+        routes = [DeprecatedRoute(pattern="/api/game/guess", method="post")]
+
+        assert find_deprecated_route(routes, "POST", "/api/game/guess") is routes[0]
+        assert find_deprecated_route(routes, "GET", "/api/game/guess") is None
+
+
+class TestDeprecationHeaders:
+    """Tests for the headers sent on deprecated routes."""
+
+    def test_sunset_and_successor(self):
+        """Sunset is an HTTP-date and the replacement is a successor Link."""
+        route = DeprecatedRoute(pattern="/api/old", sunset=date(2027, 1, 1), replacement="/api/v1/new")
+
+        assert deprecation_headers(route) == {
+            "Deprecation": "true",
+            "Sunset": "Fri, 01 Jan 2027 00:00:00 GMT",
+            "Link": '</api/v1/new>; rel="successor-version"',
+        }
+
+    def test_minimal_route(self):
+        """Without sunset or replacement only the Deprecation header is sent."""
+        assert deprecation_headers(DeprecatedRoute(pattern="/api/old")) == {"Deprecation": "true"}
+
+
+class TestDeprecationTracker:
+    """Tests for counting deprecated route usage."""
+
+    def test_counts_calls_and_last_caller(self):
+        """Usage is keyed by route and keeps the most recent caller."""
+        tracker = DeprecationTracker()
+        route = DeprecatedRoute(pattern="/api/old/*", method="GET")
+
+        tracker.record(route, "/api/old/1", "agent-a", None)
+        tracker.record(route, "/api/old/2", "agent-b", "http://localhost/admin")
+
+        usage = tracker.usage["GET /api/old/*"]
+        assert usage.calls == 2
+        assert usage.last_path == "/api/old/2"
+        assert usage.last_user_agent == "agent-b"
+        assert usage.last_referer == "http://localhost/admin"
@@ -0,0 +1,130 @@
+"""Unit tests for sending snapshot-style events as merge patches to players in diff mode."""
+
+import asyncio
+import json
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.websocket.diffs import DiffTracker, merge_patch
+from backend.websocket.events import StateUpdateEvent
+from backend.websocket.managers import AdminWebSocketManager, LobbyWebSocketManager
+from backend.websocket.prepared import PreparedEvent
+
+LOBBY_ID = 1
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+
+    async def send_text(self, text):
+        self.sent.append(json.loads(text))
+
+
+def state_update(revealed_steps: list[int], is_completed: bool = False) -> StateUpdateEvent:
+    return StateUpdateEvent(
+        team_id=10, revealed_steps=revealed_steps, is_completed=is_completed, last_updated_at="2026-03-02T20:00:00"
+    )
+
+
+def apply_patch(target: dict, patch: dict) -> dict:
+    """RFC 7386, the way clients apply a state_diff."""
+    result = dict(target)
+    for key, value in patch.items():
+        if value is None:
+            result.pop(key, None)
+        elif isinstance(value, dict) and isinstance(result.get(key), dict):
+            result[key] = apply_patch(result[key], value)
+        else:
+            result[key] = value
+    return result
+
+
+class TestMergePatch:
+    """Tests for building RFC 7386 merge patches."""
+
+    def test_only_changed_fields_are_included(self):
+        old = {"revealed_steps": [0, 4], "is_completed": False, "team_id": 10}
+        new = {"revealed_steps": [0, 1, 4], "is_completed": False, "team_id": 10}
+
+        assert merge_patch(old, new) == {"revealed_steps": [0, 1, 4]}
+
+    def test_removed_fields_are_null_and_nested_objects_are_patched(self):
+        old = {"a": {"b": 1, "c": 2}, "gone": 1}
+        new = {"a": {"b": 1, "c": 3}}
+
+        patch = merge_patch(old, new)
+
+        assert patch == {"a": {"c": 3}, "gone": None}
+        assert apply_patch(old, patch) == new
+
+    def test_a_field_becoming_null_has_no_patch(self):
+        assert merge_patch({"solved_count": 2}, {"solved_count": None}) is None
+
+
+class TestDiffTracker:
+    """Tests for choosing between full copies and patches per player."""
+
+    @staticmethod
+    def sent(tracker: DiffTracker, event) -> dict:
+        return json.loads(tracker.text_for("alice", PreparedEvent(event)))
+
+    def test_players_not_in_diff_mode_get_the_event_itself(self):
+        tracker = DiffTracker()
+
+        assert self.sent(tracker, state_update([0, 4])) == state_update([0, 4]).model_dump()
+
+    def test_first_copy_is_full_and_later_ones_are_patches(self):
+        tracker = DiffTracker()
+        tracker.set_opt_in("alice", True)
+
+        first = self.sent(tracker, state_update([0, 4]))
+        second = self.sent(tracker, state_update([0, 1, 4]))
+
+        assert (first["type"], first["state_key"], first["state_version"]) == ("state_update", "state_update:10", 1)
+        assert second["type"] == "state_diff"
+        assert (second["base_version"], second["version"]) == (1, 2)
+        assert second["patch"] == {"revealed_steps": [0, 1, 4]}
+        rebuilt = apply_patch(first, second["patch"])
+        assert rebuilt["revealed_steps"] == [0, 1, 4]
+
+    def test_other_events_pass_through(self):
+        tracker = DiffTracker()
+        tracker.set_opt_in("alice", True)
+
+        assert self.sent(tracker, {"type": "team_chat", "team_id": 10}) == {"type": "team_chat", "team_id": 10}
+
+    def test_reset_sends_full_copies_again(self):
+        tracker = DiffTracker()
+        tracker.set_opt_in("alice", True)
+        self.sent(tracker, state_update([0, 4]))
+
+        tracker.reset("alice")
+
+        assert self.sent(tracker, state_update([0, 1, 4]))["type"] == "state_update"
+
+
+class TestDiffModeConnections:
+    """Tests for diffing in LobbyWebSocketManager broadcasts."""
+
+    def test_only_players_who_asked_get_patches(self):
+        manager = LobbyWebSocketManager(admin_web_socket_manager=AdminWebSocketManager())
+        sockets = {"alice": FakeWebSocket(), "bob": FakeWebSocket()}
+        manager.lobby_websockets[LOBBY_ID] = dict(sockets)
+        for session_id in sockets:
+            manager.register_player_team(session_id, 10)
+        manager.diffs.set_opt_in("alice", True)
+
+        async def run():
+            await manager.broadcast_to_team(LOBBY_ID, 10, state_update([0, 4]))
+            await manager.broadcast_to_team(LOBBY_ID, 10, state_update([0, 1, 4]))
+            await manager.flush()
+
+        asyncio.run(run())
+
+        assert [event["type"] for event in sockets["alice"].sent] == ["state_update", "state_diff"]
+        assert [event["type"] for event in sockets["bob"].sent] == ["state_update", "state_update"]
+        assert sockets["alice"].sent[1]["seq"] == sockets["bob"].sent[1]["seq"]
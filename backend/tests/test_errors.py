@@ -0,0 +1,76 @@
+"""Tests for the shared API error types."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.errors import ERROR_HINTS, ConflictError, ErrorCode, NotFoundError, UnauthorizedError
+from backend.tests.helpers import ADMIN_HEADERS
+
+
+class TestAppError:
+    """Tests for how errors describe themselves."""
+
+    def test_plain_errors_carry_just_the_message(self):
+        error = NotFoundError("Lobby not found")
+
+        assert (error.status_code, error.detail) == (404, "Lobby not found")
+
+    def test_coded_errors_carry_their_extra_fields(self):
+        """A code turns the detail into an object clients can branch on."""
+        error = ConflictError("Cannot join while a game is in progress", code="GAME_IN_PROGRESS", lobby_state={"id": 1})
+
+        assert error.detail == {
+            "code": "GAME_IN_PROGRESS",
+            "message": "Cannot join while a game is in progress",
+            "hint": ERROR_HINTS[ErrorCode.GAME_IN_PROGRESS],
+            "lobby_state": {"id": 1},
+        }
+
+    def test_codes_without_a_hint_leave_it_out(self):
+        error = ConflictError("No answer reveal is running", code=ErrorCode.NO_REVEAL)
+
+        assert "hint" not in error.detail
+
+    def test_unknown_codes_are_refused(self):
+        """Codes come from ErrorCode, so the frontend's copy of the list stays complete."""
+        with pytest.raises(ValueError):
+            ConflictError("Something odd", code="SOMETHING_ODD")
+
+    def test_unauthorized_asks_for_a_bearer_token(self):
+        assert UnauthorizedError("Invalid admin credentials").headers == {"WWW-Authenticate": "Bearer"}
+
+
+@pytest.mark.integration
+class TestErrorResponses:
+    """Tests for errors rendered by the app's handler."""
+
+    def test_not_found_keeps_the_detail_body(self, client):
+        response = client.get("/api/admin/lobby/999", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 404
+        assert response.json() == {"detail": "Lobby not found"}
+
+    def test_bad_credentials(self, client):
+        response = client.get("/api/admin/lobby", headers={"Authorization": "Bearer wrong"})
+
+        assert response.status_code == 401
+        assert response.headers["WWW-Authenticate"] == "Bearer"
+        assert response.json() == {"detail": "Invalid admin credentials"}
+
+    def test_taken_name_comes_with_a_hint(self, client):
+        lobby = client.post("/api/admin/lobby", json={"name": "Hint Night"}, headers=ADMIN_HEADERS).json()
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": "Sam"})
+
+        response = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Sam"})
+
+        assert response.status_code == 400
+        assert response.json()["detail"] == {
+            "code": "NAME_TAKEN",
+            "message": "Player name already taken in this lobby",
+            "hint": ERROR_HINTS[ErrorCode.NAME_TAKEN],
+        }
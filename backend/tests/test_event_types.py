@@ -0,0 +1,61 @@
+"""Tests keeping outgoing websocket event types unique and in step with the frontend."""
+
+import re
+import sys
+from pathlib import Path
+from typing import get_args
+
+from pydantic import BaseModel
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.websocket import events
+from backend.websocket.events import SERVER_EVENT_TYPES, ServerEvent, event_type_of
+
+FRONTEND_TYPES = Path(__file__).parent.parent.parent / "frontend" / "src" / "types" / "index.ts"
+
+# Shared bases that are never sent themselves
+BASE_EVENTS = {events.LobbyEvent, events.GameEvent}
+
+
+def frontend_event_types() -> set[str]:
+    source = FRONTEND_TYPES.read_text()
+    blocks = [
+        re.search(r"export enum LobbyWebSocketEvents \{(.*?)\}", source, re.S).group(1),
+        re.search(r"export enum GameWebSocketEvents \{(.*?)\}", source, re.S).group(1),
+        re.search(r"export type ServerEventType =(.*?);", source, re.S).group(1),
+    ]
+    return {value for block in blocks for value in re.findall(r"'([a-z_]+)'", block)}
+
+
+class TestServerEventTypes:
+    """Tests for the ServerEvent union."""
+
+    def test_types_are_unique(self):
+        """No two events share a type string, so clients can dispatch on it."""
+        event_classes = get_args(ServerEvent)
+
+        assert len(SERVER_EVENT_TYPES) == len(event_classes)
+
+    def test_every_event_class_is_listed(self):
+        """Each event model defined in events.py is part of ServerEvent."""
+        defined = {
+            value
+            for value in vars(events).values()
+            if isinstance(value, type) and issubclass(value, BaseModel) and value.__name__.endswith("Event")
+        }
+
+        assert defined - BASE_EVENTS == set(get_args(ServerEvent))
+
+    def test_enum_types_are_sent_as_plain_strings(self):
+        """Enum-typed events resolve to the same string the JSON payload carries."""
+        event = events.PlayerKickedEvent(lobby_id=1, player_session_id="abc")
+
+        assert event_type_of(events.PlayerKickedEvent) == event.model_dump(mode="json")["type"] == "player_kicked"
+
+    def test_frontend_knows_every_type(self):
+        """Every server event type is declared in the frontend's ServerEventType."""
+        missing = set(SERVER_EVENT_TYPES) - frontend_event_types()
+
+        assert not missing, f"Add these to ServerEventType in {FRONTEND_TYPES.name}: {sorted(missing)}"
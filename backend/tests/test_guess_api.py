@@ -0,0 +1,131 @@
+"""Integration tests for submitting guesses over HTTP."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+
+@pytest.fixture
+def player_headers(client):
+    """A player on a team whose puzzle is PUZZLE, mid-game."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Guess Night"}, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    return {"Authorization": f"Bearer {alice['session_id']}"}
+
+
+@pytest.mark.integration
+class TestSubmitGuess:
+    """Tests for POST /api/lobby/guess."""
+
+    def test_correct_guess_reveals_word(self, client, player_headers):
+        """A right answer, in any case, reveals the rung for the team."""
+        response = client.post("/api/lobby/guess", json={"guess": " flake ", "word_index": 1}, headers=player_headers)
+
+        body = response.json()
+        assert body["result"] == "correct"
+        assert body["revealed_steps"] == [0, 1, 4]
+        assert body["is_completed"] is False
+
+    def test_wrong_guess_leaves_state(self, client, player_headers):
+        """A wrong answer is recorded but reveals nothing."""
+        body = client.post("/api/lobby/guess", json={"guess": "ICE", "word_index": 1}, headers=player_headers).json()
+
+        assert body["result"] == "wrong"
+        assert body["revealed_steps"] == [0, 4]
+
+    def test_already_solved(self, client, player_headers):
+        """Guessing a rung the team already has is reported rather than counted again."""
+        body = client.post("/api/lobby/guess", json={"guess": "SNOW", "word_index": 0}, headers=player_headers).json()
+
+        assert body["result"] == "already_solved"
+
+    def test_word_index_out_of_range(self, client, player_headers):
+        """Indexes past the end of the ladder are rejected."""
+        response = client.post("/api/lobby/guess", json={"guess": "WEB", "word_index": 9}, headers=player_headers)
+
+        assert response.status_code == 400
+
+    def test_no_game_in_progress(self, client):
+        """Players who aren't mid-game get a conflict instead of a guess being recorded."""
+        lobby = client.post("/api/admin/lobby", json={"name": "Idle"}, headers=ADMIN_HEADERS).json()
+        player = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Cara"}).json()
+        client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+
+        response = client.post(
+            "/api/lobby/guess",
+            json={"guess": "WEB", "word_index": 1},
+            headers={"Authorization": f"Bearer {player['session_id']}"},
+        )
+
+        assert response.status_code == 409
+
+
+@pytest.mark.integration
+class TestRollbackWord:
+    """Tests for the host undoing a team's last solved rung."""
+
+    def test_rollback_reopens_rung(self, client, player_headers):
+        """The rolled back rung is hidden again and can be re-solved."""
+        team_id = client.get("/api/lobby/active", headers=player_headers).json()["team_id"]
+        client.post("/api/lobby/guess", json={"guess": "FLAKE", "word_index": 1}, headers=player_headers)
+        client.post("/api/lobby/guess", json={"guess": "CORN", "word_index": 2}, headers=player_headers)
+
+        response = client.post(f"/api/admin/lobby/team/{team_id}/rollback-word", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 200
+        body = client.post("/api/lobby/guess", json={"guess": "CORN", "word_index": 2}, headers=player_headers).json()
+        assert body["result"] == "correct"
+        assert body["revealed_steps"] == [0, 1, 2, 4]
+
+    def test_nothing_to_roll_back(self, client, player_headers):
+        """A team that hasn't solved anything has nothing to undo."""
+        team_id = client.get("/api/lobby/active", headers=player_headers).json()["team_id"]
+
+        response = client.post(f"/api/admin/lobby/team/{team_id}/rollback-word", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 400
+
+
+@pytest.mark.integration
+class TestLobbyGuessAudit:
+    """Tests for the admin view of every guess in a lobby."""
+
+    def test_lists_guesses_with_attribution(self, client, player_headers):
+        """Each guess names the player and team that made it, newest first."""
+        lobby_id = client.get("/api/lobby", headers=player_headers).json()["id"]
+        client.post("/api/lobby/guess", json={"guess": "ICE", "word_index": 1}, headers=player_headers)
+        client.post("/api/lobby/guess", json={"guess": "FLAKE", "word_index": 1}, headers=player_headers)
+
+        body = client.get(f"/api/admin/lobby/{lobby_id}/guesses", headers=ADMIN_HEADERS).json()
+
+        assert body["total"] == 2
+        assert [(g["guess"], g["result"], g["player_name"]) for g in body["guesses"]] == [
+            ("FLAKE", "correct", "Alice"),
+            ("ICE", "wrong", "Alice"),
+        ]
+
+    def test_filter_by_team(self, client, player_headers):
+        """Filtering by another team leaves out this team's guesses."""
+        player = client.get("/api/lobby/active", headers=player_headers).json()
+        client.post("/api/lobby/guess", json={"guess": "FLAKE", "word_index": 1}, headers=player_headers)
+
+        body = client.get(
+            f"/api/admin/lobby/{player['lobby_id']}/guesses?team_id={player['team_id'] + 1000}", headers=ADMIN_HEADERS
+        ).json()
+
+        assert body["total"] == 0
+        assert body["guesses"] == []
@@ -0,0 +1,101 @@
+"""Tests for the node health score and shedding non-critical features."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.features import Feature, FeatureFlags
+from backend.health import HealthMonitor
+from backend.settings import settings
+from backend.tests.helpers import ADMIN_HEADERS
+
+NOW = 1_800_000_000.0
+
+
+def monitor_with_requests(ok: int, errors: int) -> HealthMonitor:
+    monitor = HealthMonitor()
+    for _ in range(ok):
+        monitor.record_request(200, NOW - 1)
+    for _ in range(errors):
+        monitor.record_request(500, NOW - 1)
+    return monitor
+
+
+class TestHealthMonitor:
+    """Tests for scoring the rolling window."""
+
+    def test_healthy_when_empty(self):
+        score = HealthMonitor().score(NOW)
+        assert score.db_latency_ms is None
+        assert score.error_rate is None
+        assert not score.degraded
+
+    def test_error_rate_waits_for_enough_requests(self):
+        """A couple of 500s right after startup don't degrade the node."""
+        score = monitor_with_requests(0, settings.HEALTH_MIN_REQUESTS - 1).score(NOW)
+        assert score.error_rate is None
+        assert not score.degraded
+
+    def test_high_error_rate_degrades(self):
+        score = monitor_with_requests(settings.HEALTH_MIN_REQUESTS, settings.HEALTH_MIN_REQUESTS).score(NOW)
+        assert score.error_rate == 0.5
+        assert score.degraded
+
+    def test_slow_db_degrades(self):
+        monitor = HealthMonitor()
+        monitor.record_db_latency(settings.HEALTH_DB_LATENCY_MS * 2, NOW - 1)
+        assert monitor.score(NOW).degraded
+
+    def test_samples_outside_the_window_are_dropped(self):
+        monitor = HealthMonitor()
+        monitor.record_db_latency(settings.HEALTH_DB_LATENCY_MS * 2, NOW - settings.HEALTH_WINDOW_SECONDS - 1)
+        monitor.record_request(500, NOW - settings.HEALTH_WINDOW_SECONDS - 1)
+
+        score = monitor.score(NOW)
+        assert score.db_latency_ms is None
+        assert score.request_count == 0
+        assert not score.degraded
+
+
+class TestFeatureFlags:
+    """Tests for which features are on."""
+
+    def test_everything_on_while_healthy(self):
+        assert FeatureFlags().disabled() == []
+
+    def test_degrading_switches_off_degradable_features(self, monkeypatch):
+        monkeypatch.setattr(settings, "DEGRADABLE_FEATURES", ["team_chat"])
+        flags = FeatureFlags()
+
+        assert flags.set_degraded(True)
+        assert not flags.set_degraded(True)
+        assert flags.disabled() == [Feature.TEAM_CHAT]
+
+        assert flags.set_degraded(False)
+        assert flags.is_enabled(Feature.TEAM_CHAT)
+
+    def test_disabled_features_stay_off_while_healthy(self, monkeypatch):
+        monkeypatch.setattr(settings, "DISABLED_FEATURES", ["puzzle_analytics"])
+        assert not FeatureFlags().is_enabled(Feature.PUZZLE_ANALYTICS)
+
+
+@pytest.mark.integration
+class TestHealthEndpoint:
+    """Tests for the admin health endpoint."""
+
+    def test_reports_healthy_node(self, client):
+        response = client.get("/api/admin/health", headers=ADMIN_HEADERS)
+        assert response.status_code == 200
+        assert response.json()["degraded"] is False
+        assert response.json()["disabled_features"] == []
+
+    def test_switched_off_analytics_are_unavailable(self, client, monkeypatch):
+        monkeypatch.setattr(settings, "DISABLED_FEATURES", ["puzzle_analytics"])
+
+        response = client.get("/api/admin/health", headers=ADMIN_HEADERS)
+        assert response.json()["disabled_features"] == ["puzzle_analytics"]
+        assert client.get("/api/admin/puzzle/any/analytics", headers=ADMIN_HEADERS).status_code == 503
@@ -0,0 +1,91 @@
+"""Tests for holding lobbies in intermission between rounds."""
+
+import asyncio
+import sys
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.intermission import intermission_tick
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+
+def play_round(client, lobby: dict, difficulty: str = "medium"):
+    """Play a round of a stored puzzle; the next round draws from the bundled puzzles, which are all medium."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": difficulty, "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    client.post(f"/api/admin/lobby/{lobby['id']}/game/end", headers=ADMIN_HEADERS)
+
+
+def phase(client, lobby: dict) -> str:
+    return client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()["phase"]
+
+
+def set_intermission(client, lobby: dict, enabled: bool, seconds=None):
+    return client.put(
+        f"/api/admin/lobby/{lobby['id']}/intermission",
+        json={"enabled": enabled, "seconds": seconds},
+        headers=ADMIN_HEADERS,
+    )
+
+
+@pytest.mark.integration
+class TestIntermission:
+    """Tests for entering and leaving intermission."""
+
+    def test_lobbies_without_it_finish_as_before(self, client, lobby):
+        play_round(client, lobby)
+
+        assert phase(client, lobby) == "finished"
+        response = client.post(f"/api/admin/lobby/{lobby['id']}/next-round", headers=ADMIN_HEADERS)
+        assert response.status_code == 409
+
+    def test_admin_advances_to_the_next_round(self, client, lobby):
+        set_intermission(client, lobby, True)
+        play_round(client, lobby)
+        assert phase(client, lobby) == "intermission"
+
+        response = client.post(f"/api/admin/lobby/{lobby['id']}/next-round", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 200
+        assert phase(client, lobby) == "in_progress"
+        info = client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()
+        assert info["lobby"]["intermission_started_at"] is None
+
+    def test_turning_it_off_leaves_the_lobby_finished(self, client, lobby):
+        set_intermission(client, lobby, True)
+        play_round(client, lobby)
+
+        set_intermission(client, lobby, False)
+
+        assert phase(client, lobby) == "finished"
+
+    def test_auto_advance_starts_the_round_once_due(self, client, lobby):
+        set_intermission(client, lobby, True, seconds=30)
+        play_round(client, lobby)
+
+        asyncio.run(intermission_tick(datetime.now(timezone.utc)))
+        assert phase(client, lobby) == "intermission"
+
+        asyncio.run(intermission_tick(datetime.now(timezone.utc) + timedelta(seconds=31)))
+        assert phase(client, lobby) == "in_progress"
+
+    def test_auto_advance_that_cannot_start_leaves_the_lobby_finished(self, client, lobby):
+        """Otherwise it would be retried on every tick."""
+        set_intermission(client, lobby, True, seconds=30)
+        play_round(client, lobby, difficulty="easy")
+
+        asyncio.run(intermission_tick(datetime.now(timezone.utc) + timedelta(seconds=31)))
+
+        assert phase(client, lobby) == "finished"
+
+    def test_rejects_out_of_range_timers(self, client, lobby):
+        assert set_intermission(client, lobby, True, seconds=0).status_code == 400
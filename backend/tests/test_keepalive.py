@@ -0,0 +1,127 @@
+"""Unit tests for websocket heartbeats and stale connection cleanup."""
+
+import asyncio
+import json
+import sys
+from pathlib import Path
+
+import pytest
+from fastapi import WebSocketDisconnect
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.settings import settings
+from backend.websocket.keepalive import KEEPALIVE_CLOSE_CODE, Heartbeat, receive_text
+from backend.websocket.managers import AdminWebSocketManager, LobbyWebSocketManager
+
+
+class SilentWebSocket:
+    """A client that never sends anything, like one whose network dropped."""
+
+    def __init__(self):
+        self.sent = []
+        self.closed_with = None
+
+    async def accept(self):
+        pass
+
+    async def receive_text(self):
+        await asyncio.Event().wait()
+
+    async def send_text(self, text):
+        self.sent.append(json.loads(text))
+
+    async def close(self, code=1000, reason=None):
+        self.closed_with = (code, reason)
+
+
+@pytest.fixture
+def fast_heartbeat(monkeypatch):
+    monkeypatch.setattr(settings, "WS_PING_INTERVAL_SECONDS", 0.01)
+    monkeypatch.setattr(settings, "WS_MAX_MISSED_PINGS", 2)
+    monkeypatch.setattr(settings, "WS_IDLE_TIMEOUT_SECONDS", 0)
+    monkeypatch.setattr(settings, "WS_MAX_CONNECTION_LIFETIME_SECONDS", 0)
+
+
+class TestHeartbeat:
+    """Tests for the pong deadline."""
+
+    def test_deadline_allows_missed_pings_plus_grace(self, monkeypatch):
+        """The next pong is due after the allowed missed pings and one more interval."""
+        monkeypatch.setattr(settings, "WS_PING_INTERVAL_SECONDS", 25.0)
+        monkeypatch.setattr(settings, "WS_MAX_MISSED_PINGS", 2)
+
+        heartbeat = Heartbeat(now=100.0)
+        assert heartbeat.deadline() == 175.0
+
+        heartbeat.record_pong(now=150.0)
+        assert heartbeat.deadline() == 225.0
+
+    @pytest.mark.parametrize("setting", ["WS_PING_INTERVAL_SECONDS", "WS_MAX_MISSED_PINGS"])
+    def test_disabled_without_pings_or_limit(self, monkeypatch, setting):
+        """No pings, or no missed-ping limit, means no deadline."""
+        monkeypatch.setattr(settings, setting, 0)
+
+        assert Heartbeat(now=0.0).deadline() is None
+
+    def test_receive_closes_socket_after_missed_pings(self, fast_heartbeat):
+        """A silent client is closed with the keepalive code once its pong deadline passes."""
+        websocket = SilentWebSocket()
+
+        with pytest.raises(WebSocketDisconnect) as exc_info:
+            asyncio.run(receive_text(websocket, 0.0, "test", Heartbeat()))
+
+        assert exc_info.value.reason == "Missed pings"
+        assert websocket.closed_with == (KEEPALIVE_CLOSE_CODE, "Missed pings")
+
+
+class TestPlayerDisconnected:
+    """Tests for reporting dropped players to the lobby."""
+
+    def test_disconnect_notifies_admins(self):
+        """Removing a player's socket broadcasts player_disconnected with the close reason."""
+        admins = AdminWebSocketManager()
+        manager = LobbyWebSocketManager(admin_web_socket_manager=admins)
+        admin_socket = SilentWebSocket()
+        asyncio.run(admins.connect(admin_socket, "admin-a"))
+        asyncio.run(admins.subscribe_to_lobby("admin-a", 1))
+        player_socket = SilentWebSocket()
+        manager.lobby_websockets[1] = {"ghost": player_socket}
+
+        asyncio.run(manager.disconnect(1, "ghost", reason="Missed pings"))
+
+        assert manager.lobby_websockets[1] == {}
+        assert player_socket.closed_with is not None
+        assert admin_socket.sent == [
+            {"lobby_id": 1, "player_session_id": "ghost", "reason": "Missed pings", "type": "player_disconnected"}
+        ]
+
+    def test_unknown_player_is_not_reported(self):
+        """Disconnecting a session that isn't connected sends nothing."""
+        admins = AdminWebSocketManager()
+        manager = LobbyWebSocketManager(admin_web_socket_manager=admins)
+        admin_socket = SilentWebSocket()
+        asyncio.run(admins.connect(admin_socket, "admin-a"))
+        asyncio.run(admins.subscribe_to_lobby("admin-a", 1))
+        manager.lobby_websockets[1] = {}
+
+        asyncio.run(manager.disconnect(1, "ghost"))
+
+        assert admin_socket.sent == []
+
+    def test_replaced_socket_is_not_reported(self):
+        """A socket the player has since replaced leaves the newer one alone when its listener ends."""
+        admins = AdminWebSocketManager()
+        manager = LobbyWebSocketManager(admin_web_socket_manager=admins)
+        admin_socket = SilentWebSocket()
+        asyncio.run(admins.connect(admin_socket, "admin-a"))
+        asyncio.run(admins.subscribe_to_lobby("admin-a", 1))
+        current = SilentWebSocket()
+        manager.lobby_websockets[1] = {"alice": current}
+
+        asyncio.run(manager.disconnect(1, "alice", websocket=SilentWebSocket()))
+
+        assert manager.lobby_websockets[1] == {"alice": current}
+        assert current.closed_with is None
+        assert admin_socket.sent == []
@@ -0,0 +1,56 @@
+"""Tests for generating and matching lobby codes."""
+
+import random
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+from backend.utils import lobby_codes
+from backend.utils.lobby_codes import CODE_ALPHABET, CODE_LENGTH, generate_lobby_code, normalize_lobby_code
+
+
+class TestLobbyCodes:
+    """Tests for the code alphabet and normalizing typed codes."""
+
+    def test_codes_use_only_the_safe_alphabet(self):
+        code = generate_lobby_code(random.Random(3).choice)
+
+        assert len(code) == CODE_LENGTH
+        assert set(code) <= set(CODE_ALPHABET)
+
+    def test_alphabet_has_no_lookalikes(self):
+        assert not set("0O1IL") & set(CODE_ALPHABET)
+
+    def test_normalizes_typed_codes(self):
+        assert normalize_lobby_code(" abc234 ") == "ABC234"
+
+
+@pytest.mark.integration
+class TestLobbyCodeEndpoints:
+    """Tests for codes on created lobbies and joining by code."""
+
+    def test_collisions_are_retried(self, client, monkeypatch):
+        codes = iter(["AAAAAA", "AAAAAA", "BBBBBB"])
+        monkeypatch.setattr(lobby_codes, "generate_lobby_code", lambda: next(codes))
+
+        first = client.post("/api/admin/lobby", json={"name": "First"}, headers=ADMIN_HEADERS).json()
+        second = client.post("/api/admin/lobby", json={"name": "Second"}, headers=ADMIN_HEADERS).json()
+        assert (first["code"], second["code"]) == ("AAAAAA", "BBBBBB")
+
+    def test_gives_up_when_every_code_is_taken(self, client, monkeypatch):
+        monkeypatch.setattr(lobby_codes, "generate_lobby_code", lambda: "AAAAAA")
+        client.post("/api/admin/lobby", json={"name": "First"}, headers=ADMIN_HEADERS)
+
+        assert client.post("/api/admin/lobby", json={"name": "Second"}, headers=ADMIN_HEADERS).status_code == 500
+
+    def test_join_ignores_case(self, client):
+        lobby = client.post("/api/admin/lobby", json={"name": "Loud Room"}, headers=ADMIN_HEADERS).json()
+
+        response = client.post(f"/api/lobby/{lobby['code'].lower()}", json={"name": "Alice"})
+        assert response.status_code == 200
+        assert response.json()["lobby_id"] == lobby["id"]
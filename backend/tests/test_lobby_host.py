@@ -0,0 +1,116 @@
+"""Integration tests for the lobby host, a player who can run a casual game without the admin panel."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+
+
+@pytest.fixture
+def lobby(client):
+    return client.post("/api/admin/lobby", json={"name": "Host Night"}, headers=ADMIN_HEADERS).json()
+
+
+def join(client, lobby, name):
+    player = client.post(f"/api/lobby/{lobby['code']}", json={"name": name}).json()
+    return player, {"Authorization": f"Bearer {player['session_id']}"}
+
+
+def host_id(client, lobby):
+    return client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()["lobby"]["host_player_id"]
+
+
+@pytest.mark.integration
+class TestHostAssignment:
+    """Tests for who holds the host role."""
+
+    def test_first_player_to_join_is_host(self, client, lobby):
+        """The first joiner becomes host and later joiners don't take it over."""
+        alice, _ = join(client, lobby, "Alice")
+        join(client, lobby, "Bob")
+
+        assert host_id(client, lobby) == alice["id"]
+
+    def test_admin_can_designate_host(self, client, lobby):
+        """PUT /api/admin/lobby/{lobby_id}/host/{player_id} moves the role."""
+        join(client, lobby, "Alice")
+        bob, _ = join(client, lobby, "Bob")
+
+        response = client.put(f"/api/admin/lobby/{lobby['id']}/host/{bob['id']}", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 200
+        assert response.json()["host_player_id"] == bob["id"]
+
+    def test_designated_host_must_be_in_the_lobby(self, client, lobby):
+        """Players from another lobby can't be made host."""
+        other = client.post("/api/admin/lobby", json={"name": "Elsewhere"}, headers=ADMIN_HEADERS).json()
+        stranger, _ = join(client, other, "Cara")
+
+        response = client.put(f"/api/admin/lobby/{lobby['id']}/host/{stranger['id']}", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 404
+
+    def test_host_leaving_hands_the_role_on(self, client, lobby):
+        """When the host leaves, the player who has been there longest takes over."""
+        _, alice_headers = join(client, lobby, "Alice")
+        bob, _ = join(client, lobby, "Bob")
+        join(client, lobby, "Cara")
+
+        client.delete("/api/lobby", headers=alice_headers)
+
+        assert host_id(client, lobby) == bob["id"]
+
+    def test_kicked_host_hands_the_role_on(self, client, lobby):
+        """Kicking the host passes the role on the same way."""
+        alice, _ = join(client, lobby, "Alice")
+        bob, _ = join(client, lobby, "Bob")
+
+        client.delete(f"/api/admin/lobby/player/{alice['id']}", headers=ADMIN_HEADERS)
+
+        assert host_id(client, lobby) == bob["id"]
+
+
+@pytest.mark.integration
+class TestHostActions:
+    """Tests for the /api/lobby/host/... endpoints."""
+
+    def test_non_host_is_forbidden(self, client, lobby):
+        """Only the host may create teams, shuffle or start."""
+        join(client, lobby, "Alice")
+        _, bob_headers = join(client, lobby, "Bob")
+
+        assert client.post("/api/lobby/host/teams", json={"num_teams": 2}, headers=bob_headers).status_code == 403
+        assert client.post("/api/lobby/host/teams/shuffle", headers=bob_headers).status_code == 403
+        response = client.post("/api/lobby/host/game/start", json={"difficulty": "easy"}, headers=bob_headers)
+        assert response.status_code == 403
+
+    def test_host_creates_and_shuffles_teams(self, client, lobby):
+        """The host can split the lobby into teams and deal them out again."""
+        _, alice_headers = join(client, lobby, "Alice")
+        for name in ("Bob", "Cara", "Dan"):
+            join(client, lobby, name)
+
+        created = client.post("/api/lobby/host/teams", json={"num_teams": 2}, headers=alice_headers)
+        shuffled = client.post("/api/lobby/host/teams/shuffle", headers=alice_headers)
+
+        assert created.status_code == 200
+        assert shuffled.status_code == 200
+        info = client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()
+        assert len(info["teams"]) == 2
+        assert all(player["team_id"] is not None for player in info["players"])
+
+    def test_host_start_goes_through_the_usual_checks(self, client, lobby):
+        """Starting as host runs the admin start, including waiting for everyone to be ready."""
+        _, alice_headers = join(client, lobby, "Alice")
+        join(client, lobby, "Bob")
+        client.post("/api/lobby/host/teams", json={"num_teams": 2}, headers=alice_headers)
+
+        response = client.post("/api/lobby/host/game/start", json={"difficulty": "easy"}, headers=alice_headers)
+
+        assert response.status_code == 400
+        assert "Not all players are ready" in response.json()["detail"]
@@ -0,0 +1,90 @@
+"""Tests for paging, sorting and filtering the admin lobby list."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+
+@pytest.fixture
+def lobbies(client):
+    """Three lobbies created in order: Bravo (3 players), alpha (2 players, round running) and Charlie (empty)."""
+    created = {}
+    for name, players in (("Bravo", ["Ann", "Ben", "Dan"]), ("alpha", ["Cat", "Eve"]), ("Charlie", [])):
+        lobby = client.post("/api/admin/lobby", json={"name": name}, headers=ADMIN_HEADERS).json()
+        for player in players:
+            client.post(f"/api/lobby/{lobby['code']}", json={"name": player})
+        created[name] = lobby["id"]
+
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    client.post(f"/api/admin/lobby/{created['alpha']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{created['alpha']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    return created
+
+
+def lobby_names(client, query: str = "") -> list[str]:
+    response = client.get(f"/api/admin/lobby{query}", headers=ADMIN_HEADERS)
+    assert response.status_code == 200, response.text
+    return [entry["lobby"]["name"] for entry in response.json()["items"]]
+
+
+@pytest.mark.integration
+class TestLobbyList:
+    """Tests for GET /api/admin/lobby."""
+
+    def test_newest_first_by_default(self, client, lobbies):
+        assert lobby_names(client) == ["Charlie", "alpha", "Bravo"]
+
+    @pytest.mark.parametrize(
+        "sort, expected",
+        [
+            ("oldest", ["Bravo", "alpha", "Charlie"]),
+            ("name", ["alpha", "Bravo", "Charlie"]),
+            ("players", ["Bravo", "alpha", "Charlie"]),
+        ],
+    )
+    def test_sort_orders(self, client, lobbies, sort, expected):
+        """Names sort case-insensitively; player counts sort largest first."""
+        assert lobby_names(client, f"?sort={sort}") == expected
+
+    def test_pages_report_the_full_total(self, client, lobbies):
+        """Each page carries the total so the dashboard can show how many pages there are."""
+        first = client.get("/api/admin/lobby?per_page=2&sort=oldest", headers=ADMIN_HEADERS).json()
+        second = client.get("/api/admin/lobby?per_page=2&page=2&sort=oldest", headers=ADMIN_HEADERS).json()
+
+        assert (first["total"], first["page"], first["has_more"]) == (3, 1, True)
+        assert [entry["lobby"]["name"] for entry in second["items"]] == ["Charlie"]
+        assert second["has_more"] is False
+
+    def test_active_only_keeps_lobbies_with_a_round_running(self, client, lobbies):
+        page = client.get("/api/admin/lobby?active_only=true", headers=ADMIN_HEADERS).json()
+
+        assert [entry["lobby"]["name"] for entry in page["items"]] == ["alpha"]
+        assert page["total"] == 1
+
+    def test_rejects_unknown_sort(self, client, lobbies):
+        assert client.get("/api/admin/lobby?sort=loudest", headers=ADMIN_HEADERS).status_code == 422
+
+    def test_entries_carry_occupancy(self, client, lobbies):
+        """Team counts, phase and last activity come with each entry; nobody has a websocket open here."""
+        page = client.get("/api/admin/lobby?sort=name", headers=ADMIN_HEADERS).json()
+        entries = {entry["lobby"]["name"]: entry for entry in page["items"]}
+
+        assert {name: entry["team_count"] for name, entry in entries.items()} == {"alpha": 2, "Bravo": 0, "Charlie": 0}
+        assert {name: entry["phase"] for name, entry in entries.items()} == {
+            "alpha": "in_progress",
+            "Bravo": "waiting",
+            "Charlie": "waiting",
+        }
+        assert all(entry["connected_count"] == 0 for entry in entries.values())
+        assert entries["alpha"]["last_activity_at"] > entries["alpha"]["lobby"]["created_at"]
+        assert entries["Charlie"]["last_activity_at"].startswith(entries["Charlie"]["lobby"]["created_at"][:19])
@@ -0,0 +1,46 @@
+"""Integration tests for keeping players' lobby routes to their own lobby."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+
+
+@pytest.fixture
+def lobbies(client):
+    """Two lobbies, and the session of a player who joined the first."""
+    ours = client.post("/api/admin/lobby", json={"name": "Ours"}, headers=ADMIN_HEADERS).json()
+    theirs = client.post("/api/admin/lobby", json={"name": "Theirs"}, headers=ADMIN_HEADERS).json()
+    player = client.post(f"/api/lobby/{ours['code']}", json={"name": "Alice"}).json()
+    return ours, theirs, {"Authorization": f"Bearer {player['session_id']}"}
+
+
+@pytest.mark.integration
+class TestLobbyMembership:
+    """Tests for the lobby routes behind require_lobby_member."""
+
+    @pytest.mark.parametrize("suffix", ["", "/roster", "/poll?timeout=0"])
+    def test_own_lobby(self, client, lobbies, suffix):
+        ours, _, headers = lobbies
+
+        assert client.get(f"/api/lobby/{ours['id']}{suffix}", headers=headers).status_code == 200
+
+    @pytest.mark.parametrize("suffix", ["", "/roster", "/poll?timeout=0"])
+    def test_other_lobby_is_forbidden(self, client, lobbies, suffix):
+        _, theirs, headers = lobbies
+
+        response = client.get(f"/api/lobby/{theirs['id']}{suffix}", headers=headers)
+
+        assert response.status_code == 403
+        assert response.json() == {"detail": "You are not in this lobby"}
+
+    def test_missing_lobby_looks_the_same(self, client, lobbies):
+        """Whether a lobby id exists isn't given away to players outside it."""
+        _, _, headers = lobbies
+
+        assert client.get("/api/lobby/9999", headers=headers).status_code == 403
@@ -0,0 +1,116 @@
+"""Tests for merging an accidental duplicate lobby into another."""
+
+import sys
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+from backend.utils.lobby_merge import MERGED_SETTINGS, dedupe_name, merged_settings
+
+
+class TestDedupeName:
+    """Tests for resolving name clashes between merged lobbies."""
+
+    def test_free_name_is_kept(self):
+        assert dedupe_name("Alice", {"Bob"}) == "Alice"
+
+    def test_taken_name_gets_the_first_free_suffix(self):
+        assert dedupe_name("Alice", {"Alice", "Alice (2)"}) == "Alice (3)"
+
+
+class TestMergedSettings:
+    """Tests for which source settings the target takes."""
+
+    @staticmethod
+    def lobby(**overrides):
+        return SimpleNamespace(**{**dict.fromkeys(MERGED_SETTINGS), **overrides})
+
+    def test_only_settings_the_target_left_unset_are_copied(self):
+        source = self.lobby(team_name_theme="animals", max_players=20, soft_player_cap=0)
+        target = self.lobby(max_players=12)
+
+        assert merged_settings(source, target) == {"team_name_theme": "animals", "soft_player_cap": 0}
+
+
+def create_lobby(client, name: str) -> dict:
+    return client.post("/api/admin/lobby", json={"name": name}, headers=ADMIN_HEADERS).json()
+
+
+def join(client, lobby: dict, name: str) -> dict:
+    return client.post(f"/api/lobby/{lobby['code']}", json={"name": name}).json()
+
+
+def merge(client, source: dict, target: dict):
+    return client.post(f"/api/admin/lobby/{source['id']}/merge-into/{target['id']}", headers=ADMIN_HEADERS)
+
+
+@pytest.mark.integration
+class TestMergeLobbies:
+    """Tests for POST /api/admin/lobby/{source_id}/merge-into/{target_id}."""
+
+    def test_players_move_over_and_clashing_names_get_a_suffix(self, client):
+        target, source = create_lobby(client, "Trivia Night"), create_lobby(client, "Trivia Night 2")
+        join(client, target, "Alice")
+        moved_alice = join(client, source, "Alice")
+        join(client, source, "Bob")
+
+        response = merge(client, source, target)
+
+        assert response.status_code == 200, response.text
+        assert response.json()["renamed_players"] == {"Alice": "Alice (2)"}
+        players = client.get(f"/api/admin/lobby/{target['id']}", headers=ADMIN_HEADERS).json()["players"]
+        assert [player["name"] for player in players] == ["Alice", "Alice (2)", "Bob"]
+        active = client.get("/api/lobby/active", headers={"Authorization": f"Bearer {moved_alice['session_id']}"})
+        assert active.json()["lobby_id"] == target["id"]
+
+    def test_source_lobby_is_gone_afterwards(self, client):
+        target, source = create_lobby(client, "Keep"), create_lobby(client, "Duplicate")
+        join(client, source, "Alice")
+
+        merge(client, source, target)
+
+        assert client.get(f"/api/admin/lobby/{source['id']}", headers=ADMIN_HEADERS).status_code == 404
+
+    def test_teams_come_along(self, client):
+        target, source = create_lobby(client, "Keep"), create_lobby(client, "Duplicate")
+        for name in ["Alice", "Bob"]:
+            join(client, source, name)
+        client.post(f"/api/admin/lobby/{source['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+
+        response = merge(client, source, target)
+
+        assert response.json()["moved_teams"] == 2
+        info = client.get(f"/api/admin/lobby/{target['id']}", headers=ADMIN_HEADERS).json()
+        assert len(info["teams"]) == 2
+        assert all(player["team_id"] for player in info["players"])
+
+    def test_merging_a_lobby_into_itself_is_refused(self, client):
+        lobby = create_lobby(client, "Solo")
+
+        assert merge(client, lobby, lobby).status_code == 400
+
+    def test_merges_past_the_player_cap_are_refused(self, client):
+        target, source = create_lobby(client, "Keep"), create_lobby(client, "Duplicate")
+        join(client, target, "Alice")
+        for name in ["Bob", "Carol"]:
+            join(client, source, name)
+        client.patch(f"/api/admin/lobby/{target['id']}", json={"max_players": 2}, headers=ADMIN_HEADERS)
+
+        response = merge(client, source, target)
+
+        assert response.status_code == 409
+        assert response.json()["detail"]["code"] == "LOBBY_FULL"
+        assert client.get(f"/api/admin/lobby/{source['id']}", headers=ADMIN_HEADERS).status_code == 200
+
+    def test_the_sources_cap_applies_when_the_target_takes_it_over(self, client):
+        target, source = create_lobby(client, "Keep"), create_lobby(client, "Duplicate")
+        join(client, target, "Alice")
+        join(client, source, "Bob")
+        client.patch(f"/api/admin/lobby/{source['id']}", json={"max_players": 1}, headers=ADMIN_HEADERS)
+
+        assert merge(client, source, target).status_code == 409
@@ -0,0 +1,80 @@
+"""Tests for lobby settings: the player cap, join lock, private flag and round time limit."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+
+
+@pytest.fixture
+def lobby(client):
+    return client.post("/api/admin/lobby", json={"name": "Settings Night"}, headers=ADMIN_HEADERS).json()
+
+
+def update_settings(client, lobby_id: int, **changes):
+    return client.patch(f"/api/admin/lobby/{lobby_id}", json=changes, headers=ADMIN_HEADERS)
+
+
+@pytest.mark.integration
+class TestLobbySettings:
+    """Tests for PATCH /api/admin/lobby/{lobby_id} and what it changes for players."""
+
+    def test_only_sent_fields_change(self, client, lobby):
+        update_settings(client, lobby["id"], max_players=4, locked=True)
+
+        response = update_settings(client, lobby["id"], is_private=True)
+        assert response.status_code == 200
+        assert response.json()["max_players"] == 4
+        assert response.json()["locked"] is True
+        assert response.json()["is_private"] is True
+
+    def test_null_removes_the_cap(self, client, lobby):
+        update_settings(client, lobby["id"], max_players=4)
+
+        assert update_settings(client, lobby["id"], max_players=None).json()["max_players"] is None
+
+    def test_cap_must_be_positive(self, client, lobby):
+        assert update_settings(client, lobby["id"], max_players=0).status_code == 400
+
+    def test_full_lobby_turns_players_away(self, client, lobby):
+        update_settings(client, lobby["id"], max_players=1)
+        assert client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).status_code == 200
+
+        response = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"})
+        assert response.status_code == 409
+        assert response.json()["detail"]["code"] == "LOBBY_FULL"
+        assert response.json()["detail"]["max_players"] == 1
+
+    def test_locked_lobby_turns_players_away(self, client, lobby):
+        update_settings(client, lobby["id"], locked=True)
+
+        response = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"})
+        assert response.status_code == 409
+        assert response.json()["detail"]["code"] == "LOBBY_LOCKED"
+
+        update_settings(client, lobby["id"], locked=False)
+        assert client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).status_code == 200
+
+    def test_private_lobby_cant_be_shared(self, client, lobby):
+        share = client.post(f"/api/admin/lobby/{lobby['id']}/share-results", headers=ADMIN_HEADERS)
+        assert share.status_code == 200
+
+        update_settings(client, lobby["id"], is_private=True)
+        assert client.post(f"/api/admin/lobby/{lobby['id']}/share-results", headers=ADMIN_HEADERS).status_code == 403
+        assert client.get(f"/api/shared/results/{share.json()['token']}").status_code == 404
+
+    def test_round_time_limit_can_be_set_and_cleared(self, client, lobby):
+        response = update_settings(client, lobby["id"], round_time_limit_seconds=300)
+        assert response.json()["round_time_limit_seconds"] == 300
+
+        response = update_settings(client, lobby["id"], round_time_limit_seconds=None)
+        assert response.json()["round_time_limit_seconds"] is None
+
+    def test_round_time_limit_is_bounded(self, client, lobby):
+        assert update_settings(client, lobby["id"], round_time_limit_seconds=0).status_code == 400
+        assert update_settings(client, lobby["id"], round_time_limit_seconds=3601).status_code == 400
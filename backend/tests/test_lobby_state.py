@@ -0,0 +1,67 @@
+"""Unit tests for the lobby phase state machine."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.game.lobby_state import InvalidPhaseTransition, LobbyPhase, check_transition, derive_phase
+
+
+class TestDerivePhase:
+    """Tests for working out a lobby's phase from its games."""
+
+    def test_new_lobby_is_waiting(self):
+        """A lobby that has never played a round is waiting."""
+        assert derive_phase(has_active_game=False, has_finished_game=False) == LobbyPhase.WAITING
+
+    def test_active_game_is_in_progress(self):
+        """An unfinished puzzle means a round is running, even after earlier rounds."""
+        assert derive_phase(has_active_game=True, has_finished_game=True) == LobbyPhase.IN_PROGRESS
+
+    def test_only_finished_games_is_finished(self):
+        """Once every puzzle is done the lobby is finished until the next round starts."""
+        assert derive_phase(has_active_game=False, has_finished_game=True) == LobbyPhase.FINISHED
+
+    def test_intermission_after_a_round(self):
+        """A lobby marked in intermission is there between rounds, but not once the next round is running."""
+        assert derive_phase(False, True, in_intermission=True) == LobbyPhase.INTERMISSION
+        assert derive_phase(True, True, in_intermission=True) == LobbyPhase.IN_PROGRESS
+
+
+class TestCheckTransition:
+    """Tests for which phase changes are allowed."""
+
+    @pytest.mark.parametrize(
+        "current,target",
+        [
+            (LobbyPhase.WAITING, LobbyPhase.IN_PROGRESS),
+            (LobbyPhase.IN_PROGRESS, LobbyPhase.FINISHED),
+            (LobbyPhase.FINISHED, LobbyPhase.IN_PROGRESS),
+            (LobbyPhase.IN_PROGRESS, LobbyPhase.INTERMISSION),
+            (LobbyPhase.INTERMISSION, LobbyPhase.IN_PROGRESS),
+            (LobbyPhase.INTERMISSION, LobbyPhase.FINISHED),
+        ],
+    )
+    def test_allowed_transitions(self, current, target):
+        """Starting, ending and starting the next round are all allowed."""
+        check_transition(current, target)
+
+    @pytest.mark.parametrize(
+        "current,target",
+        [
+            (LobbyPhase.IN_PROGRESS, LobbyPhase.IN_PROGRESS),
+            (LobbyPhase.WAITING, LobbyPhase.FINISHED),
+            (LobbyPhase.FINISHED, LobbyPhase.WAITING),
+            (LobbyPhase.WAITING, LobbyPhase.INTERMISSION),
+        ],
+    )
+    def test_rejected_transitions(self, current, target):
+        """A round can't be started twice, ended before it starts, or un-played."""
+        with pytest.raises(InvalidPhaseTransition) as exc_info:
+            check_transition(current, target)
+        assert exc_info.value.current == current
+        assert exc_info.value.target == target
@@ -0,0 +1,77 @@
+"""Unit tests for the daily metrics collector."""
+
+from datetime import date
+import logging
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.metrics import ErrorCountingHandler, MetricsCollector, percentile
+
+DAY = date(2026, 6, 1)
+
+
+class TestPercentile:
+    """Tests for the nearest-rank percentile."""
+
+    def test_empty_is_none(self):
+        """No samples means no percentile."""
+        assert percentile([], 99) is None
+
+    def test_p99_picks_tail_sample(self):
+        """The p99 of 1..100 is 99."""
+        assert percentile([float(n) for n in range(100, 0, -1)], 99) == 99.0
+
+    def test_single_sample(self):
+        """A single sample is every percentile."""
+        assert percentile([12.5], 99) == 12.5
+
+
+class TestMetricsCollector:
+    """Tests for per-day metric buckets."""
+
+    def test_requests_and_server_errors(self):
+        """Every request counts; only 5xx responses count as server errors."""
+        collector = MetricsCollector()
+        collector.record_request(10.0, 200, day=DAY)
+        collector.record_request(30.0, 404, day=DAY)
+        collector.record_request(50.0, 503, day=DAY)
+
+        metrics = collector.day(DAY)
+        assert metrics.request_count == 3
+        assert metrics.server_error_count == 1
+        assert metrics.p99_latency_ms == 50.0
+
+    def test_peak_players_only_rises(self):
+        """Peak concurrent players keeps the highest count seen."""
+        collector = MetricsCollector()
+        for count in (3, 8, 5):
+            collector.record_concurrent_players(count, day=DAY)
+
+        assert collector.day(DAY).peak_concurrent_players == 8
+
+    def test_pop_clears_day(self):
+        """Popping a day hands back its metrics and starts it fresh."""
+        collector = MetricsCollector()
+        collector.record_request(10.0, 200, day=DAY)
+
+        assert collector.pop(DAY).request_count == 1
+        assert collector.pop(DAY).request_count == 0
+
+    def test_error_handler_counts_only_errors(self):
+        """The logging handler counts ERROR records and ignores lower levels."""
+        collector = MetricsCollector()
+        logger = logging.getLogger("raddle_test_metrics")
+        logger.propagate = False
+        handler = ErrorCountingHandler(collector)
+        logger.addHandler(handler)
+        try:
+            logger.warning("not counted")
+            logger.error("counted")
+            logger.critical("counted")
+        finally:
+            logger.removeHandler(handler)
+
+        assert sum(metrics.error_log_count for metrics in collector.days.values()) == 2
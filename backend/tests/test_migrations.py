@@ -0,0 +1,435 @@
+"""Unit tests for versioned schema migrations."""
+
+import json
+import sys
+import threading
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+import pytest
+from sqlalchemy import inspect, text
+from sqlmodel import Session, SQLModel, select
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+import backend.database as database
+from backend.database import Lobby, Player, build_engine, migrations
+from backend.database.migrations import (
+    BASELINE_VERSION,
+    Migration,
+    MigrationError,
+    backup_database,
+    current_version,
+    head_version,
+    main,
+    restore_backup,
+    run_migrations,
+    startup_lock,
+    startup_lock_table,
+)
+
+
+def add_lobby_theme(connection):
+    connection.execute(text("ALTER TABLE lobby ADD COLUMN theme VARCHAR"))
+
+
+def broken(connection):
+    connection.execute(text("ALTER TABLE no_such_table ADD COLUMN theme VARCHAR"))
+
+
+def version_of(engine):
+    with engine.connect() as connection:
+        return current_version(connection)
+
+
+@pytest.fixture
+def engine():
+    return build_engine("sqlite://")
+
+
+# The schema at BASELINE_VERSION, as the first release's models created it in SQLite
+BASELINE_DDL = [
+    """
+    CREATE TABLE lobby (
+        id INTEGER NOT NULL,
+        code VARCHAR NOT NULL,
+        name VARCHAR NOT NULL,
+        created_at DATETIME NOT NULL,
+        PRIMARY KEY (id)
+    )
+    """,
+    "CREATE UNIQUE INDEX ix_lobby_code ON lobby (code)",
+    """
+    CREATE TABLE game (
+        id INTEGER NOT NULL,
+        lobby_id INTEGER NOT NULL,
+        difficulty VARCHAR NOT NULL,
+        puzzle_path VARCHAR NOT NULL,
+        started_at DATETIME NOT NULL,
+        completed_at DATETIME,
+        revealed_steps JSON,
+        last_updated_at DATETIME,
+        timer_started_at DATETIME,
+        timer_duration_seconds INTEGER,
+        PRIMARY KEY (id),
+        FOREIGN KEY(lobby_id) REFERENCES lobby (id) ON DELETE CASCADE
+    )
+    """,
+    "CREATE INDEX ix_game_lobby_id ON game (lobby_id)",
+    """
+    CREATE TABLE team (
+        id INTEGER NOT NULL,
+        name VARCHAR NOT NULL,
+        lobby_id INTEGER NOT NULL,
+        game_id INTEGER,
+        current_word_index INTEGER NOT NULL,
+        created_at DATETIME NOT NULL,
+        total_points INTEGER NOT NULL,
+        rounds_won INTEGER NOT NULL,
+        rounds_played INTEGER NOT NULL,
+        PRIMARY KEY (id),
+        FOREIGN KEY(lobby_id) REFERENCES lobby (id) ON DELETE CASCADE,
+        FOREIGN KEY(game_id) REFERENCES game (id) ON DELETE SET NULL
+    )
+    """,
+    "CREATE INDEX ix_team_lobby_id ON team (lobby_id)",
+    """
+    CREATE TABLE player (
+        id INTEGER NOT NULL,
+        name VARCHAR NOT NULL,
+        session_id VARCHAR NOT NULL,
+        lobby_id INTEGER NOT NULL,
+        team_id INTEGER,
+        is_ready BOOLEAN NOT NULL,
+        created_at DATETIME NOT NULL,
+        PRIMARY KEY (id),
+        CONSTRAINT uq_player_name_lobby UNIQUE (name, lobby_id),
+        UNIQUE (session_id),
+        FOREIGN KEY(lobby_id) REFERENCES lobby (id) ON DELETE CASCADE,
+        FOREIGN KEY(team_id) REFERENCES team (id) ON DELETE CASCADE
+    )
+    """,
+    "CREATE INDEX ix_player_session_id ON player (session_id)",
+    "CREATE INDEX ix_player_lobby_id ON player (lobby_id)",
+    "CREATE INDEX ix_player_team_id ON player (team_id)",
+    """
+    CREATE TABLE guess (
+        id INTEGER NOT NULL,
+        team_id INTEGER NOT NULL,
+        player_id INTEGER NOT NULL,
+        game_id INTEGER NOT NULL,
+        word_index INTEGER NOT NULL,
+        direction VARCHAR NOT NULL,
+        guess VARCHAR NOT NULL,
+        is_correct BOOLEAN NOT NULL,
+        created_at DATETIME NOT NULL,
+        PRIMARY KEY (id),
+        FOREIGN KEY(team_id) REFERENCES team (id) ON DELETE CASCADE,
+        FOREIGN KEY(player_id) REFERENCES player (id) ON DELETE CASCADE,
+        FOREIGN KEY(game_id) REFERENCES game (id) ON DELETE CASCADE
+    )
+    """,
+    "CREATE INDEX ix_guess_team_id ON guess (team_id)",
+    "CREATE INDEX ix_guess_player_id ON guess (player_id)",
+    "CREATE INDEX ix_guess_game_id ON guess (game_id)",
+    "CREATE INDEX ix_guess_team_word ON guess (team_id, word_index)",
+    """
+    CREATE TABLE roundresult (
+        id INTEGER NOT NULL,
+        lobby_id INTEGER NOT NULL,
+        game_id INTEGER NOT NULL,
+        team_id INTEGER NOT NULL,
+        round_number INTEGER NOT NULL,
+        placement INTEGER NOT NULL,
+        points_earned INTEGER NOT NULL,
+        completion_percentage FLOAT NOT NULL,
+        time_to_complete INTEGER,
+        completed_at DATETIME,
+        created_at DATETIME NOT NULL,
+        PRIMARY KEY (id),
+        FOREIGN KEY(lobby_id) REFERENCES lobby (id) ON DELETE CASCADE,
+        FOREIGN KEY(game_id) REFERENCES game (id) ON DELETE CASCADE,
+        FOREIGN KEY(team_id) REFERENCES team (id) ON DELETE CASCADE
+    )
+    """,
+    "CREATE INDEX ix_round_lobby_id ON roundresult (lobby_id)",
+    "CREATE INDEX ix_round_team_id ON roundresult (team_id)",
+]
+
+
+def create_legacy_schema(engine):
+    """Create the baseline schema, as a database from before migrations existed has it."""
+    with engine.begin() as connection:
+        for statement in BASELINE_DDL:
+            connection.execute(text(statement))
+    return engine
+
+
+@pytest.fixture
+def legacy_engine(engine):
+    """A database created from the models before migrations existed."""
+    return create_legacy_schema(engine)
+
+
+@pytest.fixture
+def legacy_file_engine(tmp_path):
+    """Like legacy_engine, but in a database file that can be backed up."""
+    return create_legacy_schema(build_engine(f"sqlite:///{tmp_path / 'main.db'}"))
+
+
+def insert_legacy(connection, row):
+    """Insert a model's row into a legacy database, leaving out the columns it doesn't have yet."""
+    columns = {column["name"] for column in inspect(connection).get_columns(row.__tablename__)}
+    values = {name: value for name, value in row.model_dump().items() if name in columns}
+    connection.execute(type(row).__table__.insert().values(**values))
+
+
+def at(hour: int, minute: int) -> datetime:
+    return datetime(2026, 1, 1, hour, minute, tzinfo=timezone.utc)
+
+
+class TestRunMigrations:
+    """Tests for bringing a database up to the latest schema version."""
+
+    def test_new_database_is_created_at_the_latest_version(self, engine, monkeypatch):
+        monkeypatch.setattr(migrations, "MIGRATIONS", [Migration(2, "add lobby theme", add_lobby_theme)])
+
+        assert run_migrations(engine) == []
+
+        assert version_of(engine) == 2
+        assert "lobby" in inspect(engine).get_table_names()
+
+    def test_database_without_versions_is_taken_as_the_baseline(self, legacy_engine, monkeypatch):
+        monkeypatch.setattr(migrations, "MIGRATIONS", [])
+
+        assert run_migrations(legacy_engine) == []
+        assert version_of(legacy_engine) == BASELINE_VERSION
+
+    def test_baseline_database_gets_the_chat_history_table(self, legacy_engine):
+        assert run_migrations(legacy_engine) == list(range(1, head_version() + 1))
+        assert "chat_message" in inspect(legacy_engine).get_table_names()
+
+    def test_baseline_database_ends_up_with_the_models_schema(self, legacy_engine):
+        run_migrations(legacy_engine)
+
+        inspector = inspect(legacy_engine)
+        for table in SQLModel.metadata.sorted_tables:
+            columns = {column["name"] for column in inspector.get_columns(table.name)}
+            assert columns == set(table.columns.keys()), table.name
+        with Session(legacy_engine) as session:
+            assert session.exec(select(Lobby)).all() == []
+
+    def test_baseline_lobbies_get_their_own_seeds(self, legacy_engine):
+        with legacy_engine.begin() as connection:
+            for lobby_id, code in ((1, "ABCDEF"), (2, "GHIJKL")):
+                insert_legacy(connection, Lobby(id=lobby_id, code=code, name="Old Lobby"))
+
+        run_migrations(legacy_engine)
+
+        with legacy_engine.connect() as connection:
+            seeds = connection.execute(text("SELECT rng_seed FROM lobby")).scalars().all()
+        assert len(set(seeds)) == 2
+
+    def test_columns_a_database_already_has_are_left_alone(self, legacy_engine):
+        """Databases created between the baseline and the first migration have some of the later columns."""
+        with legacy_engine.begin() as connection:
+            connection.execute(text("ALTER TABLE player ADD COLUMN is_bot BOOLEAN NOT NULL DEFAULT 0"))
+
+        assert run_migrations(legacy_engine) == list(range(1, head_version() + 1))
+
+    def test_existing_players_are_numbered_in_join_order(self, legacy_engine):
+        with legacy_engine.begin() as connection:
+            insert_legacy(connection, Lobby(id=1, code="ABCDEF", name="Old Lobby"))
+            insert_legacy(connection, Player(id=1, name="Bob", session_id="b", lobby_id=1, created_at=at(10, 5)))
+            insert_legacy(connection, Player(id=2, name="Alice", session_id="a", lobby_id=1, created_at=at(10, 0)))
+
+        run_migrations(legacy_engine)
+
+        with legacy_engine.connect() as connection:
+            rows = connection.execute(text("SELECT name, join_order FROM player ORDER BY join_order")).all()
+        assert [tuple(row) for row in rows] == [("Alice", 1), ("Bob", 2)]
+
+    def test_existing_lobbies_get_their_first_player_as_host(self, legacy_engine):
+        with legacy_engine.begin() as connection:
+            # A database from after bots were added, but before migrations
+            connection.execute(text("ALTER TABLE player ADD COLUMN is_bot BOOLEAN NOT NULL DEFAULT 0"))
+            insert_legacy(connection, Lobby(id=1, code="ABCDEF", name="Old Lobby"))
+            bot = Player(id=1, name="Bot", session_id="x", lobby_id=1, is_bot=True, created_at=at(9, 0))
+            insert_legacy(connection, bot)
+            insert_legacy(connection, Player(id=2, name="Bob", session_id="b", lobby_id=1, created_at=at(10, 5)))
+            insert_legacy(connection, Player(id=3, name="Alice", session_id="a", lobby_id=1, created_at=at(10, 0)))
+
+        run_migrations(legacy_engine)
+
+        with legacy_engine.connect() as connection:
+            assert connection.execute(text("SELECT host_player_id FROM lobby WHERE id = 1")).scalar() == 3
+
+    def test_pending_migrations_are_applied_once(self, legacy_engine, monkeypatch):
+        monkeypatch.setattr(migrations, "MIGRATIONS", [Migration(2, "add lobby theme", add_lobby_theme)])
+
+        assert run_migrations(legacy_engine) == [2]
+        assert run_migrations(legacy_engine) == []
+
+        assert "theme" in [column["name"] for column in inspect(legacy_engine).get_columns("lobby")]
+        assert version_of(legacy_engine) == 2
+
+    def test_failed_migration_keeps_the_previous_version(self, legacy_engine, monkeypatch):
+        monkeypatch.setattr(migrations, "MIGRATIONS", [Migration(2, "broken", broken)])
+
+        with pytest.raises(MigrationError):
+            run_migrations(legacy_engine)
+
+        assert version_of(legacy_engine) == BASELINE_VERSION
+
+    def test_database_newer_than_the_code_is_refused(self, legacy_engine, monkeypatch):
+        monkeypatch.setattr(migrations, "MIGRATIONS", [Migration(2, "add lobby theme", add_lobby_theme)])
+        run_migrations(legacy_engine)
+        monkeypatch.setattr(migrations, "MIGRATIONS", [])
+
+        with pytest.raises(MigrationError):
+            run_migrations(legacy_engine)
+
+    def test_versions_must_increase(self, engine, monkeypatch):
+        duplicate = [Migration(2, "add lobby theme", add_lobby_theme), Migration(2, "broken", broken)]
+        monkeypatch.setattr(migrations, "MIGRATIONS", duplicate)
+
+        with pytest.raises(MigrationError):
+            run_migrations(engine)
+
+
+class TestMigrateCommand:
+    """Tests for the command line behind ./rt migrate, and its --json output."""
+
+    @pytest.fixture(autouse=True)
+    def head_at_two(self, monkeypatch):
+        monkeypatch.setattr(migrations, "MIGRATIONS", [Migration(2, "add lobby theme", add_lobby_theme)])
+
+    def run(self, monkeypatch, capsys, engine, *argv) -> tuple[int, str]:
+        monkeypatch.setattr(database, "engine", engine)
+        returncode = main(list(argv))
+        return returncode, capsys.readouterr().out
+
+    def test_status_as_json(self, legacy_engine, monkeypatch, capsys):
+        returncode, out = self.run(monkeypatch, capsys, legacy_engine, "--status", "--json")
+
+        assert returncode == 0
+        assert json.loads(out) == {"current_version": None, "latest_version": 2, "up_to_date": False}
+
+    def test_status_as_text(self, legacy_engine, monkeypatch, capsys):
+        run_migrations(legacy_engine)
+
+        assert self.run(monkeypatch, capsys, legacy_engine, "--status") == (0, "Schema version 2, latest is 2\n")
+
+    def test_migrating_as_json(self, legacy_engine, monkeypatch, capsys):
+        returncode, out = self.run(monkeypatch, capsys, legacy_engine, "--json")
+
+        assert returncode == 0
+        assert json.loads(out) == {"applied": [2], "current_version": 2}
+        assert json.loads(self.run(monkeypatch, capsys, legacy_engine, "--json")[1])["applied"] == []
+
+    def test_errors_as_json(self, legacy_engine, monkeypatch, capsys):
+        run_migrations(legacy_engine)
+        monkeypatch.setattr(migrations, "MIGRATIONS", [])
+
+        returncode, out = self.run(monkeypatch, capsys, legacy_engine, "--json")
+
+        assert returncode == 1
+        assert "newer than this code" in json.loads(out)["error"]
+
+
+def lobby_columns(engine):
+    return [column["name"] for column in inspect(engine).get_columns("lobby")]
+
+
+class TestMigrationBackups:
+    """Tests for backing up SQLite files before migrating and restoring the backups."""
+
+    def test_database_file_is_backed_up_before_migrating(self, legacy_file_engine, tmp_path, monkeypatch):
+        monkeypatch.setattr(migrations, "MIGRATIONS", [Migration(2, "add lobby theme", add_lobby_theme)])
+
+        run_migrations(legacy_file_engine, backups_keep=5)
+
+        [backup] = (tmp_path / "backups").iterdir()
+        assert backup.name.startswith("main-") and backup.name.endswith(f"-v{BASELINE_VERSION}.db")
+        with legacy_file_engine.connect() as connection:
+            recorded = connection.execute(text("SELECT backup_path FROM schema_version WHERE version = 2")).scalar()
+        assert recorded == str(backup)
+
+    def test_no_backup_without_pending_migrations(self, legacy_file_engine, tmp_path, monkeypatch):
+        monkeypatch.setattr(migrations, "MIGRATIONS", [])
+
+        run_migrations(legacy_file_engine, backups_keep=5)
+
+        assert not (tmp_path / "backups").exists()
+
+    def test_backups_can_be_turned_off(self, legacy_file_engine, tmp_path, monkeypatch):
+        monkeypatch.setattr(migrations, "MIGRATIONS", [Migration(2, "add lobby theme", add_lobby_theme)])
+
+        run_migrations(legacy_file_engine, backups_keep=0)
+
+        assert not (tmp_path / "backups").exists()
+
+    def test_only_the_newest_backups_are_kept(self, legacy_file_engine, tmp_path):
+        backups = [backup_database(tmp_path / "main.db", BASELINE_VERSION, keep=2) for _ in range(3)]
+
+        assert sorted((tmp_path / "backups").iterdir()) == backups[1:]
+
+    def test_restoring_a_backup_undoes_the_migration(self, legacy_file_engine, tmp_path, monkeypatch):
+        monkeypatch.setattr(migrations, "MIGRATIONS", [Migration(2, "add lobby theme", add_lobby_theme)])
+        run_migrations(legacy_file_engine, backups_keep=5)
+        [backup] = (tmp_path / "backups").iterdir()
+
+        restore_backup(backup, legacy_file_engine)
+
+        assert version_of(legacy_file_engine) == BASELINE_VERSION
+        assert "theme" not in lobby_columns(legacy_file_engine)
+
+    def test_missing_backup_is_refused(self, legacy_file_engine, tmp_path):
+        with pytest.raises(MigrationError):
+            restore_backup(tmp_path / "backups" / "nope.db", legacy_file_engine)
+
+    def test_in_memory_database_cannot_be_restored(self, engine, tmp_path):
+        with pytest.raises(MigrationError):
+            restore_backup(tmp_path / "main.db", engine)
+
+
+@pytest.fixture
+def file_engine(tmp_path):
+    return build_engine(f"sqlite:///{tmp_path / 'main.db'}")
+
+
+class TestStartupLock:
+    """Tests for instances taking turns to set up the same database."""
+
+    def test_second_instance_waits_for_the_first(self, file_engine):
+        second = threading.Thread(target=run_migrations, args=(file_engine,))
+
+        with startup_lock(file_engine):
+            second.start()
+            second.join(timeout=0.3)
+            assert second.is_alive()
+            assert "lobby" not in inspect(file_engine).get_table_names()
+
+        second.join(timeout=5)
+        assert not second.is_alive()
+        assert version_of(file_engine) == head_version()
+
+    def test_lock_is_released_after_migrating(self, file_engine):
+        run_migrations(file_engine)
+
+        with file_engine.connect() as connection:
+            assert connection.execute(startup_lock_table.select()).first() is None
+
+    def test_abandoned_lock_is_taken_over(self, file_engine):
+        startup_lock_table.create(file_engine)
+        with file_engine.begin() as connection:
+            connection.execute(
+                startup_lock_table.insert().values(
+                    id=1, holder="crashed:1", acquired_at=datetime.now(timezone.utc) - timedelta(minutes=10)
+                )
+            )
+
+        with startup_lock(file_engine, timeout=60):
+            with file_engine.connect() as connection:
+                assert connection.execute(startup_lock_table.select()).first().holder != "crashed:1"
@@ -36,6 +36,14 @@ class TestPlayerModel:
         assert player.created_at is not None
         assert isinstance(player.created_at, datetime)
 
+    def test_player_accessibility_preferences_default_off(self):
+        """Accessibility preferences are opt-in."""
+        player = Player(name="TestPlayer", session_id="test-session-a11y", lobby_id=1)
+
+        assert not player.reduced_motion
+        assert not player.high_contrast
+        assert not player.larger_text
+
 
 class TestTeamModel:
     """Tests for Team model."""
@@ -81,6 +89,12 @@ class TestLobbyModel:
         assert lobby.created_at is not None
         assert isinstance(lobby.created_at, datetime)
 
+    def test_lobby_late_join_disabled_by_default(self):
+        """Players can't join a running game unless the host opts in."""
+        lobby = Lobby(code="LATE01", name="Test Lobby")
+
+        assert lobby.late_join_enabled is False
+
 
 class TestGameModel:
     """Tests for Game model."""
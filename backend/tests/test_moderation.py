@@ -0,0 +1,124 @@
+"""Tests for the host reviewing answers to open-ended rungs."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+# CORN is open-ended, so guesses at it wait for an admin
+PUZZLE = {
+    **PUZZLE,
+    "ladder": [
+        {**step, "clue": "Something you'd find in a field", "open_ended": True} if step["word"] == "CORN" else step
+        for step in PUZZLE["ladder"]
+    ],
+}
+
+
+@pytest.fixture
+def game(client):
+    """A lobby mid-game with Alice and Bob on separate teams; returns (lobby, alice, alice's headers)."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Open Night"}, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    return lobby, alice, {"Authorization": f"Bearer {alice['session_id']}"}
+
+
+def pending_guess(client, lobby: dict, headers: dict, guess: str = "WHEAT") -> int:
+    """Answer the open-ended rung and return the id of the guess waiting for review."""
+    result = client.post("/api/lobby/guess", json={"guess": guess, "word_index": 2}, headers=headers).json()
+    assert result["result"] == "pending"
+    pending = client.get(f"/api/admin/lobby/{lobby['id']}/pending-guesses", headers=ADMIN_HEADERS).json()
+    return next(entry["guess_id"] for entry in pending if entry["guess"] == guess)
+
+
+def review(client, guess_id: int, action: str, headers: dict = ADMIN_HEADERS):
+    return client.post(f"/api/admin/lobby/guess/{guess_id}/{action}", headers=headers)
+
+
+@pytest.mark.integration
+class TestPendingGuesses:
+    """Tests for GET /api/admin/lobby/{lobby_id}/pending-guesses."""
+
+    def test_lists_answers_waiting_for_review(self, client, game):
+        lobby, _, headers = game
+        pending_guess(client, lobby, headers)
+
+        pending = client.get(f"/api/admin/lobby/{lobby['id']}/pending-guesses", headers=ADMIN_HEADERS).json()
+
+        assert [(entry["guess"], entry["player_name"], entry["example_answer"]) for entry in pending] == [
+            ("WHEAT", "Alice", "CORN")
+        ]
+
+    def test_requires_an_admin(self, client, game):
+        lobby, _, headers = game
+
+        assert client.get(f"/api/admin/lobby/{lobby['id']}/pending-guesses").status_code == 401
+        assert client.get(f"/api/admin/lobby/{lobby['id']}/pending-guesses", headers=headers).status_code == 401
+
+
+@pytest.mark.integration
+class TestReviewGuess:
+    """Tests for POST /api/admin/lobby/guess/{guess_id}/approve and /reject."""
+
+    def test_approval_solves_the_rung_and_tells_the_team(self, client, game):
+        lobby, alice, headers = game
+        guess_id = pending_guess(client, lobby, headers)
+        token = client.post("/api/lobby/ws-token", headers=headers).json()["token"]
+
+        with client.websocket_connect(f"/ws/lobby/{lobby['id']}/player?token={token}") as websocket:
+            response = review(client, guess_id, "approve")
+            reviewed = websocket.receive_json()
+            while reviewed["type"] != "guess_reviewed":
+                reviewed = websocket.receive_json()
+            solved = websocket.receive_json()
+            while solved["type"] != "word_solved":
+                solved = websocket.receive_json()
+
+        assert response.status_code == 200, response.text
+        assert (reviewed["guess_id"], reviewed["approved"]) == (guess_id, True)
+        # Revealed as the team answered it, not as the example answer
+        assert (solved["word_index"], solved["word"], solved["player_name"]) == (2, "WHEAT", "Alice")
+        state = client.get(f"/api/game/puzzle?player_session_id={alice['session_id']}").json()["state"]
+        assert 2 in state["revealed_steps"]
+
+    def test_rejection_leaves_the_rung_open(self, client, game):
+        lobby, alice, headers = game
+        guess_id = pending_guess(client, lobby, headers)
+
+        assert review(client, guess_id, "reject").status_code == 200
+        state = client.get(f"/api/game/puzzle?player_session_id={alice['session_id']}").json()["state"]
+        assert 2 not in state["revealed_steps"]
+        assert client.get(f"/api/admin/lobby/{lobby['id']}/pending-guesses", headers=ADMIN_HEADERS).json() == []
+
+    @pytest.mark.parametrize("first, then", [("approve", "approve"), ("approve", "reject"), ("reject", "approve")])
+    def test_already_reviewed_guesses_are_not_found(self, client, game, first, then):
+        lobby, _, headers = game
+        guess_id = pending_guess(client, lobby, headers)
+        review(client, guess_id, first)
+
+        assert review(client, guess_id, then).status_code == 404
+
+    @pytest.mark.parametrize("action", ["approve", "reject"])
+    def test_unknown_guesses_are_not_found(self, client, game, action):
+        assert review(client, 9999, action).status_code == 404
+
+    @pytest.mark.parametrize("action", ["approve", "reject"])
+    def test_requires_an_admin(self, client, game, action):
+        lobby, _, headers = game
+        guess_id = pending_guess(client, lobby, headers)
+
+        assert client.post(f"/api/admin/lobby/guess/{guess_id}/{action}").status_code == 401
+        assert review(client, guess_id, action, headers=headers).status_code == 401
@@ -0,0 +1,74 @@
+"""Tests for filtering offensive and reserved player and lobby names."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.settings import settings
+from backend.tests.helpers import ADMIN_HEADERS
+from backend.utils.name_filter import BLOCKED_WORDS, NameNotAllowed, load_blocklist, screen_name
+
+
+class TestScreenName:
+    """Tests for matching names against the blocklist."""
+
+    @pytest.mark.parametrize("name", ["Shit Happens", "sh1t", "a$$hole", "Big_Fucker"])
+    def test_blocked_words_are_refused(self, name):
+        with pytest.raises(NameNotAllowed):
+            screen_name(name, "reject", BLOCKED_WORDS)
+
+    @pytest.mark.parametrize("name", ["Scunthorpe", "Cassandra", "Sam K", "Hi!"])
+    def test_words_merely_containing_one_pass(self, name):
+        assert screen_name(name, "reject", BLOCKED_WORDS) == name
+
+    def test_sanitize_stars_the_word_out(self):
+        assert screen_name("Holy Sh1t Team", "sanitize", BLOCKED_WORDS) == "Holy S*** Team"
+
+    def test_off_allows_anything(self):
+        assert screen_name("shit", "off", BLOCKED_WORDS, reserve=True) == "shit"
+
+    @pytest.mark.parametrize("mode", ["reject", "sanitize"])
+    def test_reserved_names(self, mode):
+        with pytest.raises(NameNotAllowed):
+            screen_name("  A d m i n ", mode, BLOCKED_WORDS, reserve=True)
+        assert screen_name("Admin", mode, BLOCKED_WORDS) == "Admin"
+
+    def test_blocklist_file_adds_words(self, tmp_path):
+        path = tmp_path / "blocklist.txt"
+        path.write_text("# venue rules\nGrumpkin\n")
+
+        blocklist = load_blocklist(str(path))
+
+        assert "grumpkin" in blocklist and BLOCKED_WORDS <= blocklist
+
+
+@pytest.mark.integration
+class TestNameFilterRoutes:
+    """Tests for the filter on joining and creating lobbies."""
+
+    def test_join_refuses_offensive_and_reserved_names(self, client):
+        lobby = client.post("/api/admin/lobby", json={"name": "Party"}, headers=ADMIN_HEADERS).json()
+
+        for name in ("Sh1t Lord", "Host"):
+            response = client.post(f"/api/lobby/{lobby['code']}", json={"name": name})
+
+            assert response.status_code == 400
+            assert response.json()["detail"]["code"] == "NAME_NOT_ALLOWED"
+
+    def test_create_lobby_refuses_offensive_names(self, client):
+        response = client.post("/api/admin/lobby", json={"name": "Bitch Please"}, headers=ADMIN_HEADERS)
+
+        assert response.status_code == 400
+
+    def test_sanitize_mode_joins_with_the_word_starred_out(self, client, monkeypatch):
+        monkeypatch.setattr(settings, "NAME_FILTER_MODE", "sanitize")
+        lobby = client.post("/api/admin/lobby", json={"name": "Party"}, headers=ADMIN_HEADERS).json()
+
+        response = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Shit Lord"})
+
+        assert response.status_code == 200
+        assert response.json()["name"] == "S*** Lord"
@@ -11,7 +11,9 @@ from backend.utils.name_generator import (
     generate_team_name,
     generate_lobby_name,
     generate_multiple_team_names,
+    generate_themed_team_names,
     TEAM_ADJECTIVES,
+    TEAM_NAME_THEMES,
     TEAM_NOUNS,
     LOBBY_ADJECTIVES,
     LOBBY_NOUNS,
@@ -74,6 +76,41 @@ class TestTeamNameGeneration:
         assert len(set(names)) == count
 
 
+class TestThemedTeamNames:
+    """Tests for themed team name selection."""
+
+    def test_names_come_from_theme(self):
+        """Themed names should be drawn from the chosen theme list."""
+        names = generate_themed_team_names("animals", 4)
+
+        assert len(names) == 4
+        assert all(name in TEAM_NAME_THEMES["animals"] for name in names)
+
+    def test_names_are_distinct(self):
+        """Every name in the theme can be handed out exactly once."""
+        count = len(TEAM_NAME_THEMES["colors"])
+        names = generate_themed_team_names("colors", count)
+
+        assert len(set(names)) == count
+
+    def test_existing_names_are_skipped(self):
+        """Names already used in the lobby are never picked again."""
+        existing = set(TEAM_NAME_THEMES["cities"][:-1])
+        names = generate_themed_team_names("cities", 1, existing_names=existing)
+
+        assert names == [TEAM_NAME_THEMES["cities"][-1]]
+
+    def test_too_many_names_raises(self):
+        """Requesting more names than remain should raise."""
+        with pytest.raises(ValueError, match="Cannot generate"):
+            generate_themed_team_names("animals", len(TEAM_NAME_THEMES["animals"]) + 1)
+
+    def test_unknown_theme_raises(self):
+        """Unknown themes should raise."""
+        with pytest.raises(ValueError, match="Unknown team name theme"):
+            generate_themed_team_names("planets", 2)
+
+
 class TestLobbyNameGeneration:
     """Tests for lobby name generation."""
 
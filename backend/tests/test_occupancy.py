@@ -0,0 +1,79 @@
+"""Tests for soft player caps."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+from backend.utils.occupancy import effective_soft_cap, is_over_soft_cap
+
+
+class TestEffectiveSoftCap:
+    """Tests for resolving a lobby's cap against the server default."""
+
+    @pytest.mark.parametrize(
+        "lobby_cap, default_cap, expected",
+        [
+            (None, 0, None),
+            (None, 20, 20),
+            (8, 20, 8),
+            (0, 20, None),
+        ],
+    )
+    def test_lobby_cap_overrides_default(self, lobby_cap, default_cap, expected):
+        """The lobby's own cap wins, None falls back and 0 turns the cap off."""
+        assert effective_soft_cap(lobby_cap, default_cap) == expected
+
+    def test_only_exceeding_the_cap_counts(self):
+        """Reaching the cap exactly is fine; one more player is over."""
+        assert not is_over_soft_cap(8, 8)
+        assert is_over_soft_cap(9, 8)
+        assert not is_over_soft_cap(100, None)
+
+
+@pytest.fixture
+def app_builder(app_builder):
+    return app_builder.setting("LOBBY_SOFT_PLAYER_CAP", 0)
+
+
+@pytest.mark.integration
+class TestSoftCapAdmin:
+    """Tests for setting a lobby's soft cap and seeing it in the admin lobby list."""
+
+    def test_joins_past_cap_are_admitted_and_flagged(self, client):
+        """Players over the cap still join, and the lobby list flags the lobby."""
+        lobby = client.post("/api/admin/lobby", json={"name": "Packed"}, headers=ADMIN_HEADERS).json()
+        url = f"/api/admin/lobby/{lobby['id']}/soft-cap"
+        response = client.put(url, json={"soft_player_cap": 1}, headers=ADMIN_HEADERS)
+        assert response.status_code == 200, response.text
+
+        assert client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).status_code == 200
+        assert client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"}).status_code == 200
+
+        [entry] = client.get("/api/admin/lobby", headers=ADMIN_HEADERS).json()["items"]
+        assert entry["lobby"]["id"] == lobby["id"]
+        assert entry["player_count"] == 2
+        assert entry["soft_player_cap"] == 1
+        assert entry["over_soft_cap"] is True
+
+    def test_clearing_cap_uses_server_default(self, client):
+        """A null cap falls back to LOBBY_SOFT_PLAYER_CAP, which is off here."""
+        lobby = client.post("/api/admin/lobby", json={"name": "Roomy"}, headers=ADMIN_HEADERS).json()
+        client.put(f"/api/admin/lobby/{lobby['id']}/soft-cap", json={"soft_player_cap": None}, headers=ADMIN_HEADERS)
+
+        [entry] = client.get("/api/admin/lobby", headers=ADMIN_HEADERS).json()["items"]
+        assert entry["soft_player_cap"] is None
+        assert entry["over_soft_cap"] is False
+
+    def test_rejects_negative_cap(self, client):
+        """Caps can't be negative."""
+        lobby = client.post("/api/admin/lobby", json={"name": "Odd"}, headers=ADMIN_HEADERS).json()
+
+        url = f"/api/admin/lobby/{lobby['id']}/soft-cap"
+        response = client.put(url, json={"soft_player_cap": -1}, headers=ADMIN_HEADERS)
+
+        assert response.status_code == 400
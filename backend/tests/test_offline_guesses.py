@@ -0,0 +1,361 @@
+"""Tests for guesses queued while a client was offline."""
+
+import sys
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.database.models import Game, Guess
+from backend.offline_guesses import PlayerSightings, guessed_at, replayed_outcome
+from backend.settings import Settings, settings
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+
+NOW = datetime(2026, 3, 14, 20, 0, tzinfo=timezone.utc)
+
+
+def ago(seconds: float) -> str:
+    return (datetime.now(timezone.utc) - timedelta(seconds=seconds)).isoformat()
+
+
+class TestGuessedAt:
+    """Tests for when a queued guess is dated."""
+
+    started_at = NOW - timedelta(minutes=10)
+
+    def game(self) -> Game:
+        return Game(lobby_id=1, puzzle_path="", started_at=self.started_at)
+
+    def test_recent_guesses_keep_their_time(self, monkeypatch):
+        monkeypatch.setattr(settings, "OFFLINE_GUESS_GRACE_SECONDS", 60)
+
+        dated = guessed_at(NOW - timedelta(seconds=20), self.game(), self.started_at, now=NOW)
+
+        assert dated == NOW - timedelta(seconds=20)
+
+    def test_old_and_future_times_are_ignored(self, monkeypatch):
+        monkeypatch.setattr(settings, "OFFLINE_GUESS_GRACE_SECONDS", 60)
+
+        assert guessed_at(NOW - timedelta(seconds=61), self.game(), self.started_at, now=NOW) is None
+        assert guessed_at(NOW + timedelta(seconds=5), self.game(), self.started_at, now=NOW) is None
+
+    def test_never_before_the_game_started(self, monkeypatch):
+        monkeypatch.setattr(settings, "OFFLINE_GUESS_GRACE_SECONDS", 60)
+        game = Game(lobby_id=1, puzzle_path="", started_at=NOW - timedelta(seconds=5))
+
+        assert guessed_at(NOW - timedelta(seconds=20), game, self.started_at, now=NOW) == game.started_at
+
+    def test_never_before_the_player_went_offline(self, monkeypatch):
+        monkeypatch.setattr(settings, "OFFLINE_GUESS_GRACE_SECONDS", 60)
+        offline_since = NOW - timedelta(seconds=8)
+
+        assert guessed_at(NOW - timedelta(seconds=20), self.game(), offline_since, now=NOW) == offline_since
+        # A player the server heard from just now was online, so there's nothing to backdate
+        assert guessed_at(NOW - timedelta(seconds=20), self.game(), NOW, now=NOW) is None
+
+    def test_zero_grace_dates_everything_on_arrival(self, monkeypatch):
+        monkeypatch.setattr(settings, "OFFLINE_GUESS_GRACE_SECONDS", 0)
+
+        assert guessed_at(NOW - timedelta(seconds=1), self.game(), self.started_at, now=NOW) is None
+
+    def test_off_by_default(self):
+        assert Settings.model_fields["OFFLINE_GUESS_GRACE_SECONDS"].default == 0
+
+
+class TestPlayerSightings:
+    """Tests for the earliest time a session's queued guesses may be dated."""
+
+    def test_from_the_last_websocket_drop(self):
+        sightings = PlayerSightings()
+        sightings.seen("s", at=NOW - timedelta(seconds=50))
+        sightings.disconnected("s", at=NOW - timedelta(seconds=30))
+
+        # Still the drop after reconnecting, since the queue is flushed once the socket is back
+        assert sightings.offline_since("s", connected=True, now=NOW) == NOW - timedelta(seconds=30)
+
+    def test_connected_sessions_were_online_all_along(self):
+        sightings = PlayerSightings()
+        sightings.seen("s", at=NOW - timedelta(seconds=50))
+
+        assert sightings.offline_since("s", connected=True, now=NOW) == NOW
+
+    def test_otherwise_from_the_last_request(self):
+        sightings = PlayerSightings()
+        sightings.seen("s", at=NOW - timedelta(seconds=50))
+
+        assert sightings.offline_since("s", connected=False, now=NOW) == NOW - timedelta(seconds=50)
+        assert sightings.offline_since("unknown", connected=False, now=NOW) == NOW
+
+    def test_a_flushed_queue_closes_the_gap(self):
+        sightings = PlayerSightings()
+        sightings.disconnected("s", at=NOW - timedelta(seconds=30))
+
+        sightings.flushed("s", at=NOW)
+
+        assert sightings.offline_since("s", connected=False, now=NOW + timedelta(seconds=5)) == NOW
+
+
+class TestReplayedOutcome:
+    """Tests for answering a resent guess from the stored one."""
+
+    def guess(self, **fields) -> Guess:
+        defaults = dict(team_id=1, player_id=1, game_id=1, word_index=2, direction="", guess="CORN", is_correct=False)
+        return Guess(**{**defaults, **fields})
+
+    def test_outcomes(self):
+        assert replayed_outcome(self.guess(is_correct=True), {0, 2, 4}) == "correct"
+        assert replayed_outcome(self.guess(review_status="pending"), {0, 4}) == "pending"
+        assert replayed_outcome(self.guess(review_status="rejected"), {0, 2, 4}) == "wrong"
+        assert replayed_outcome(self.guess(), {0, 4}) == "wrong"
+        assert replayed_outcome(self.guess(), {0, 2, 4}) == "already_solved"
+
+
+@pytest.fixture
+def app_builder(app_builder):
+    return app_builder.setting("OFFLINE_GUESS_GRACE_SECONDS", 60)
+
+
+@pytest.fixture
+def game(client):
+    """A lobby mid-game with Alice and Bob on separate teams; returns (lobby, alice headers, bob headers)."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Wifi Trouble"}, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    bob = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"}).json()
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    return (
+        lobby,
+        {"Authorization": f"Bearer {alice['session_id']}"},
+        {"Authorization": f"Bearer {bob['session_id']}"},
+    )
+
+
+def flush(client, headers, guesses: list[dict]):
+    return client.post("/api/lobby/guess/batch", json={"guesses": guesses}, headers=headers)
+
+
+def websocket_url(client, lobby: dict, headers: dict) -> str:
+    token = client.post("/api/lobby/ws-token", headers=headers).json()["token"]
+    return f"/ws/lobby/{lobby['id']}/player?token={token}"
+
+
+def solve(client, headers):
+    for word_index, word in enumerate(("FLAKE", "CORN", "COB"), start=1):
+        client.post("/api/lobby/guess", json={"guess": word, "word_index": word_index}, headers=headers)
+
+
+def queued_solve(typed_at: str) -> list[dict]:
+    return [
+        {"guess": word, "word_index": word_index, "client_guess_id": f"q-{word_index}", "submitted_at": typed_at}
+        for word_index, word in enumerate(("FLAKE", "CORN", "COB"), start=1)
+    ]
+
+
+def winning_team(client, lobby: dict) -> int:
+    return client.get(f"/api/lobby/{lobby['id']}/results").json()["winning_team_id"]
+
+
+@pytest.mark.integration
+class TestResentGuesses:
+    """Tests for guesses sent again under the same client_guess_id."""
+
+    def test_resent_guess_is_answered_without_counting_again(self, client, game):
+        _, alice, _ = game
+        guess = {"guess": "ICE", "word_index": 1, "client_guess_id": "q-1"}
+
+        first = client.post("/api/lobby/guess", json=guess, headers=alice).json()
+        again = client.post("/api/lobby/guess", json=guess, headers=alice).json()
+
+        assert (first["result"], first["duplicate"]) == ("wrong", False)
+        assert (again["result"], again["duplicate"]) == ("wrong", True)
+        assert client.get("/api/lobby/team/guesses", headers=alice).json()["total"] == 1
+
+    def test_ids_are_per_player(self, client, game):
+        _, alice, bob = game
+        guess = {"guess": "FLAKE", "word_index": 1, "client_guess_id": "q-1"}
+        client.post("/api/lobby/guess", json=guess, headers=alice)
+
+        body = client.post("/api/lobby/guess", json=guess, headers=bob).json()
+
+        assert (body["result"], body["duplicate"]) == ("correct", False)
+
+    def test_overlong_ids_are_refused(self, client, game):
+        _, alice, _ = game
+        guess = {"guess": "FLAKE", "word_index": 1, "client_guess_id": "q" * 65}
+
+        assert client.post("/api/lobby/guess", json=guess, headers=alice).status_code == 422
+
+
+@pytest.mark.integration
+class TestQueuedGuessBatch:
+    """Tests for POST /api/lobby/guess/batch."""
+
+    def test_judged_in_the_order_typed_with_results_in_the_order_sent(self, client, game):
+        _, alice, _ = game
+
+        body = flush(
+            client,
+            alice,
+            [
+                {"guess": "ICE", "word_index": 1, "client_guess_id": "q-4", "submitted_at": ago(5)},
+                {"guess": "COB", "word_index": 3, "client_guess_id": "q-3", "submitted_at": ago(10)},
+                {"guess": "CORN", "word_index": 2, "client_guess_id": "q-2", "submitted_at": ago(20)},
+                {"guess": "FLAKE", "word_index": 1, "client_guess_id": "q-1", "submitted_at": ago(30)},
+            ],
+        ).json()
+
+        results = body["results"]
+        assert [r["client_guess_id"] for r in results] == ["q-4", "q-3", "q-2", "q-1"]
+        # ICE was typed after the ladder was finished, so there was nothing left to guess
+        assert [r["result"] for r in results] == ["rejected", "correct", "correct", "correct"]
+        assert results[0]["detail"] == "Your team has no puzzle in progress"
+        assert results[1]["is_completed"] is True
+
+    def test_resending_the_queue_changes_nothing(self, client, game):
+        _, alice, _ = game
+        queue = [{"guess": "FLAKE", "word_index": 1, "client_guess_id": "q-1", "submitted_at": ago(5)}]
+        flush(client, alice, queue)
+
+        results = flush(client, alice, queue).json()["results"]
+
+        assert [(r["result"], r["duplicate"]) for r in results] == [("correct", True)]
+        assert client.get("/api/lobby/team/guesses", headers=alice).json()["total"] == 1
+
+    def test_a_finish_typed_earlier_places_ahead(self, client, game):
+        """Alice's team finished offline before Bob's did live, so it wins even though its guesses arrived later."""
+        lobby, alice, bob = game
+        with client.websocket_connect(websocket_url(client, lobby, alice)):
+            pass
+        typed_at = ago(0)
+        solve(client, bob)
+
+        flush(client, alice, queued_solve(typed_at))
+
+        alice_team = client.get("/api/lobby/active", headers=alice).json()["team_id"]
+        assert winning_team(client, lobby) == alice_team
+
+    def test_rate_limited_guesses_are_rejected_one_by_one(self, client, game, monkeypatch):
+        monkeypatch.setattr(settings, "RATE_LIMIT_GUESSES_PER_MINUTE", 1)
+        _, alice, _ = game
+
+        results = flush(
+            client,
+            alice,
+            [{"guess": "ICE", "word_index": 1}, {"guess": "FLAKE", "word_index": 1}],
+        ).json()["results"]
+
+        assert [r["result"] for r in results] == ["wrong", "rejected"]
+
+    def test_empty_queues_are_refused(self, client, game):
+        _, alice, _ = game
+
+        assert flush(client, alice, []).status_code == 422
+
+
+@pytest.mark.integration
+class TestBackdating:
+    """Tests that a client which was online can't date its guesses earlier than they arrived."""
+
+    def test_not_before_the_player_dropped(self, client, game):
+        lobby, alice, bob = game
+        with client.websocket_connect(websocket_url(client, lobby, alice)):
+            solve(client, bob)
+
+        # Claims the finish came before Bob's, while Alice was still connected
+        flush(client, alice, queued_solve(ago(30)))
+
+        bob_team = client.get("/api/lobby/active", headers=bob).json()["team_id"]
+        assert winning_team(client, lobby) == bob_team
+
+    def test_connected_clients_cannot_backdate(self, client, game):
+        lobby, alice, bob = game
+        with client.websocket_connect(websocket_url(client, lobby, alice)):
+            solve(client, bob)
+            flush(client, alice, queued_solve(ago(30)))
+
+        bob_team = client.get("/api/lobby/active", headers=bob).json()["team_id"]
+        assert winning_team(client, lobby) == bob_team
+
+    def test_single_guesses_are_dated_on_arrival(self, client, game):
+        lobby, alice, bob = game
+        solve(client, bob)
+
+        for guess in queued_solve(ago(30)):
+            client.post("/api/lobby/guess", json=guess, headers=alice)
+
+        bob_team = client.get("/api/lobby/active", headers=bob).json()["team_id"]
+        assert winning_team(client, lobby) == bob_team
+
+
+@pytest.fixture
+def teammates(client):
+    """A lobby mid-game with four players on two teams; returns (lobby, alice headers, her teammate's headers)."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Wifi Trouble"}, headers=ADMIN_HEADERS).json()
+    players = {}
+    for name in ["Alice", "Bob", "Carol", "Dan"]:
+        session_id = client.post(f"/api/lobby/{lobby['code']}", json={"name": name}).json()["session_id"]
+        players[name] = {"Authorization": f"Bearer {session_id}"}
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    team_of = {
+        name: client.get("/api/lobby/active", headers=headers).json()["team_id"] for name, headers in players.items()
+    }
+    teammate = next(name for name in ["Bob", "Carol", "Dan"] if team_of[name] == team_of["Alice"])
+    return lobby, players["Alice"], players[teammate]
+
+
+def beaten_to_it(client, teammates, word: str = "FLAKE") -> tuple[dict, list[dict]]:
+    """Alice types word offline, then her teammate solves the rung live; returns her outcome and the team's history."""
+    lobby, alice, teammate = teammates
+    with client.websocket_connect(websocket_url(client, lobby, alice)):
+        pass
+    guess = {"guess": word, "word_index": 1, "client_guess_id": "q-1", "submitted_at": ago(0)}
+    client.post("/api/lobby/guess", json={"guess": "FLAKE", "word_index": 1}, headers=teammate)
+
+    result = flush(client, alice, [guess]).json()["results"][0]
+    return result, client.get("/api/lobby/team/guesses", headers=alice).json()["guesses"]
+
+
+@pytest.mark.integration
+class TestJudgedAsTyped:
+    """Tests for OFFLINE_GUESS_JUDGE_AS_TYPED."""
+
+    def test_off_a_rung_solved_since_is_already_solved(self, client, teammates):
+        result, guesses = beaten_to_it(client, teammates)
+
+        assert result["result"] == "already_solved"
+        # Newest first, and Alice's is dated when she typed it
+        assert [entry["result"] for entry in guesses] == ["correct", "wrong"]
+
+    def test_on_the_guess_typed_first_takes_the_solve(self, client, teammates, monkeypatch):
+        monkeypatch.setattr(settings, "OFFLINE_GUESS_JUDGE_AS_TYPED", True)
+
+        result, guesses = beaten_to_it(client, teammates)
+
+        assert result["result"] == "correct"
+        assert [entry["result"] for entry in guesses] == ["wrong", "correct"]
+        assert guesses[1]["player_name"] == "Alice"
+
+    def test_on_wrong_answers_are_still_already_solved(self, client, teammates, monkeypatch):
+        monkeypatch.setattr(settings, "OFFLINE_GUESS_JUDGE_AS_TYPED", True)
+
+        result, _ = beaten_to_it(client, teammates, word="ICE")
+
+        assert result["result"] == "already_solved"
+
+    def test_off_by_default(self):
+        assert Settings.model_fields["OFFLINE_GUESS_JUDGE_AS_TYPED"].default is False
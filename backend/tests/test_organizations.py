@@ -0,0 +1,122 @@
+"""Tests for organizations: their admin logins and keeping their data apart."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.organizations import hash_password, verify_password
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_PASSWORD, PUZZLE
+
+
+class TestPasswordHashing:
+    """Tests for storing organization passwords."""
+
+    def test_verifies_only_the_right_password(self):
+        password_hash = hash_password("correct horse")
+
+        assert verify_password("correct horse", password_hash)
+        assert not verify_password("wrong horse", password_hash)
+
+    def test_hashes_are_salted(self):
+        assert hash_password("same") != hash_password("same")
+
+
+def org_headers(client, slug: str) -> dict:
+    """Create an organization and log in as its admin."""
+    response = client.post(
+        "/api/admin/organizations",
+        json={"name": slug.title(), "slug": slug, "password": f"{slug}-password"},
+        headers=ADMIN_HEADERS,
+    )
+    assert response.status_code == 200, response.text
+    session = client.post("/api/admin/login", json={"password": f"{slug}-password", "organization": slug}).json()
+    assert session["organization_id"] == response.json()["id"]
+    return {"Authorization": f"Bearer {session['token']}"}
+
+
+@pytest.mark.integration
+class TestOrganizationScope:
+    """Tests for what organization admins can see and change."""
+
+    def test_login_needs_the_organizations_own_password(self, client):
+        org_headers(client, "quiz-club")
+
+        response = client.post("/api/admin/login", json={"password": ADMIN_PASSWORD, "organization": "quiz-club"})
+        assert response.status_code == 401
+
+    def test_lobby_list_only_shows_the_organizations_lobbies(self, client):
+        headers = org_headers(client, "quiz-club")
+        client.post("/api/admin/lobby", json={"name": "Deployment Night"}, headers=ADMIN_HEADERS)
+        client.post("/api/admin/lobby", json={"name": "Club Night"}, headers=headers)
+
+        listed = client.get("/api/admin/lobby", headers=headers).json()
+        assert [entry["lobby"]["name"] for entry in listed["items"]] == ["Club Night"]
+
+        everything = client.get("/api/admin/lobby", headers=ADMIN_HEADERS).json()
+        assert everything["total"] == 2
+
+    def test_other_organizations_lobbies_look_missing(self, client):
+        """Ids from another organization 404 rather than revealing they exist."""
+        club = org_headers(client, "quiz-club")
+        rivals = org_headers(client, "rivals")
+        lobby = client.post("/api/admin/lobby", json={"name": "Club Night"}, headers=club).json()
+
+        assert client.get(f"/api/admin/lobby/{lobby['id']}", headers=club).status_code == 200
+        assert client.get(f"/api/admin/lobby/{lobby['id']}", headers=rivals).status_code == 404
+        assert client.delete(f"/api/admin/lobby/{lobby['id']}", headers=rivals).status_code == 404
+
+    def test_puzzles_stay_inside_their_organization(self, client):
+        club = org_headers(client, "quiz-club")
+        rivals = org_headers(client, "rivals")
+        puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=club).json()
+
+        assert [entry["id"] for entry in client.get("/api/admin/puzzle", headers=club).json()] == [puzzle["id"]]
+        assert client.get("/api/admin/puzzle", headers=rivals).json() == []
+        assert client.get(f"/api/admin/puzzle/{puzzle['id']}", headers=rivals).status_code == 404
+
+    def test_deployment_pages_are_off_limits(self, client):
+        headers = org_headers(client, "quiz-club")
+
+        assert client.get("/api/admin/traffic", headers=headers).status_code == 403
+        assert client.get("/api/admin/traffic", headers=ADMIN_HEADERS).status_code == 200
+
+    def test_changing_the_password_logs_admins_out(self, client):
+        headers = org_headers(client, "quiz-club")
+        organization_id = client.get("/api/admin/organizations", headers=ADMIN_HEADERS).json()[0]["id"]
+
+        response = client.put(
+            f"/api/admin/organizations/{organization_id}/password",
+            json={"password": "a-new-password"},
+            headers=ADMIN_HEADERS,
+        )
+        assert response.status_code == 200
+        assert client.get("/api/admin/lobby", headers=headers).status_code == 401
+
+    def test_cant_merge_another_organizations_lobby(self, client):
+        club = org_headers(client, "quiz-club")
+        rivals = org_headers(client, "rivals")
+        theirs = client.post("/api/admin/lobby", json={"name": "Club Night"}, headers=club).json()
+        ours = client.post("/api/admin/lobby", json={"name": "Rivals Night"}, headers=rivals).json()
+
+        for source, target in [(theirs, ours), (ours, theirs)]:
+            response = client.post(f"/api/admin/lobby/{source['id']}/merge-into/{target['id']}", headers=rivals)
+            assert response.status_code == 404
+        assert client.get(f"/api/admin/lobby/{theirs['id']}", headers=club).status_code == 200
+
+    def test_cant_import_players_from_another_organizations_lobby(self, client):
+        club = org_headers(client, "quiz-club")
+        rivals = org_headers(client, "rivals")
+        theirs = client.post("/api/admin/lobby", json={"name": "Club Night"}, headers=club).json()
+        client.post(f"/api/lobby/{theirs['code']}", json={"name": "Alice"})
+        ours = client.post("/api/admin/lobby", json={"name": "Rivals Night"}, headers=rivals).json()
+
+        response = client.post(
+            f"/api/admin/lobby/{ours['id']}/import-players", params={"from_lobby": theirs["id"]}, headers=rivals
+        )
+
+        assert response.status_code == 404
+        assert client.get(f"/api/admin/lobby/{ours['id']}", headers=rivals).json()["players"] == []
@@ -0,0 +1,153 @@
+"""Tests for pace keeper stall detection and auto-hints."""
+
+import asyncio
+import json
+import sys
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+import pytest
+from sqlmodel import Session, select
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+import backend.database as database
+from backend.database.models import Game, Guess, Lobby, PaceHint, Player, Team
+from backend.game.pacing import (
+    TeamPace,
+    due_hint_level,
+    hint_text,
+    median_solve_seconds,
+    next_rung,
+    slowest_team,
+    solve_durations,
+)
+from backend.pace_keeper import keep_pace
+from backend.tests.helpers import ADMIN_HEADERS, LONG_PUZZLE
+
+T0 = datetime(2026, 1, 1, 19, 0, tzinfo=timezone.utc)
+
+
+def at(seconds: float) -> datetime:
+    return T0 + timedelta(seconds=seconds)
+
+
+class TestStallDetection:
+    """Tests for the pure pacing rules."""
+
+    def test_solve_durations_start_from_round_start(self):
+        """The first solve is timed from the round start, later ones from the previous solve."""
+        assert solve_durations(T0, [at(30), at(10)]) == [10.0, 20.0]
+
+    def test_median_needs_enough_solves(self):
+        """Too few solves means no median, so nobody is judged stalled yet."""
+        assert median_solve_seconds([10.0, 20.0], min_solves=3) is None
+        assert median_solve_seconds([10.0, 20.0, 90.0], min_solves=3) == 20.0
+
+    def test_slowest_team_is_fewest_revealed_then_longest_stalled(self):
+        """Progress decides first; among equals the team stuck longest is slowest."""
+        ahead = TeamPace(team_id=1, revealed_count=5, stalled_since=T0, hints_since_solve=0)
+        behind_recent = TeamPace(team_id=2, revealed_count=2, stalled_since=at(50), hints_since_solve=0)
+        behind_longer = TeamPace(team_id=3, revealed_count=2, stalled_since=at(20), hints_since_solve=0)
+
+        assert slowest_team([ahead, behind_recent, behind_longer]) is behind_longer
+        assert slowest_team([]) is None
+
+    def test_hints_escalate_with_stall_length(self):
+        """Hint N is due after N x multiplier x median, and stops at the maximum level."""
+        team = TeamPace(team_id=1, revealed_count=2, stalled_since=T0, hints_since_solve=0)
+
+        assert due_hint_level(team, 10.0, at(29), multiplier=3, max_level=2) is None
+        assert due_hint_level(team, 10.0, at(30), multiplier=3, max_level=2) == 1
+
+        team.hints_since_solve = 1
+        assert due_hint_level(team, 10.0, at(45), multiplier=3, max_level=2) is None
+        assert due_hint_level(team, 10.0, at(60), multiplier=3, max_level=2) == 2
+
+        team.hints_since_solve = 2
+        assert due_hint_level(team, 10.0, at(600), multiplier=3, max_level=2) is None
+
+    def test_hint_reveals_leading_letters_but_never_the_word(self):
+        """Each level shows one more letter, stopping one short of the answer."""
+        assert hint_text("FLAKE", 1) == "F____"
+        assert hint_text("FLAKE", 2) == "FL___"
+        assert hint_text("COB", 5) == "CO_"
+
+    def test_next_rung_is_first_unrevealed_from_top(self):
+        """Help goes to the topmost rung still hidden."""
+        assert next_rung({0, 1, 6}, 7) == 2
+        assert next_rung(range(7), 7) is None
+
+
+@pytest.fixture
+def app_builder(app_builder):
+    return (
+        app_builder.setting("PACE_KEEPER_STALL_MULTIPLIER", 3.0)
+        .setting("PACE_KEEPER_MIN_SOLVES", 3)
+        .setting("PACE_KEEPER_MAX_HINT_LEVEL", 3)
+    )
+
+
+@pytest.fixture
+def lobby_id(client):
+    """Two teams on LONG_PUZZLE: the first solved three rungs 10s apart, the second nothing."""
+    puzzle = client.post("/api/admin/puzzle", json=LONG_PUZZLE, headers=ADMIN_HEADERS).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Pace Night"}, headers=ADMIN_HEADERS).json()
+    for name in ("Alice", "Bob"):
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": name})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+
+    with Session(database.engine) as db:
+        fast, slow = db.exec(select(Team).where(Team.lobby_id == lobby["id"]).order_by(Team.id)).all()
+        player = db.exec(select(Player).where(Player.team_id == fast.id)).first()
+        for team in (fast, slow):
+            game = db.get(Game, team.game_id)
+            game.started_at = T0
+            db.add(game)
+        fast_game = db.get(Game, fast.game_id)
+        fast_game.revealed_steps = json.dumps([0, 1, 2, 3, 6])
+        for word_index in (1, 2, 3):
+            db.add(
+                Guess(
+                    team_id=fast.id,
+                    player_id=player.id,
+                    game_id=fast.game_id,
+                    word_index=word_index,
+                    direction="down",
+                    guess=LONG_PUZZLE["ladder"][word_index]["word"],
+                    is_correct=True,
+                    created_at=at(10 * word_index),
+                )
+            )
+        db.commit()
+    return lobby["id"]
+
+
+def run_keep_pace(lobby_id: int, now: datetime):
+    with Session(database.engine) as db:
+        return asyncio.run(keep_pace(db, db.get(Lobby, lobby_id), now))
+
+
+@pytest.mark.integration
+class TestKeepPace:
+    """Tests for granting hints from recorded solves."""
+
+    def test_stalled_team_gets_escalating_hints(self, client, lobby_id):
+        """The slow team is hinted after 3 x the 10s median, then again after 6 x, one more letter each time."""
+        assert run_keep_pace(lobby_id, at(29)) is None
+
+        first = run_keep_pace(lobby_id, at(30))
+        assert (first.word_index, first.level, first.hint) == (1, 1, "F____")
+        assert run_keep_pace(lobby_id, at(45)) is None
+
+        second = run_keep_pace(lobby_id, at(60))
+        assert (second.word_index, second.level, second.hint) == (1, 2, "FL___")
+
+        with Session(database.engine) as db:
+            assert len(db.exec(select(PaceHint)).all()) == 2
@@ -0,0 +1,138 @@
+"""Tests for copying players from an earlier lobby into a new one."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_PASSWORD, admin_websocket_url
+
+
+def create_lobby(client, name: str) -> dict:
+    return client.post("/api/admin/lobby", json={"name": name}, headers=ADMIN_HEADERS).json()
+
+
+def join(client, lobby: dict, name: str) -> dict:
+    return client.post(f"/api/lobby/{lobby['code']}", json={"name": name}).json()
+
+
+def import_players(client, target_id: int, source_id: int, preserve_teams: bool = False):
+    return client.post(
+        f"/api/admin/lobby/{target_id}/import-players",
+        params={"from_lobby": source_id, "preserve_teams": preserve_teams},
+        headers=ADMIN_HEADERS,
+    )
+
+
+def lobby_info(client, lobby: dict) -> dict:
+    return client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()
+
+
+@pytest.mark.integration
+class TestImportPlayers:
+    """Tests for POST /api/admin/lobby/{lobby_id}/import-players."""
+
+    def test_players_get_fresh_sessions_in_the_new_lobby(self, client):
+        last_week, tonight = create_lobby(client, "Last Week"), create_lobby(client, "Tonight")
+        old_alice = join(client, last_week, "Alice")
+        join(client, last_week, "Bob")
+
+        response = import_players(client, tonight["id"], last_week["id"])
+
+        assert response.status_code == 200, response.text
+        imported = response.json()["imported"]
+        assert [player["name"] for player in imported] == ["Alice", "Bob"]
+        assert imported[0]["session_id"] != old_alice["session_id"]
+        assert imported[0]["join_url"].endswith(f"/?session={imported[0]['session_id']}")
+        assert [player["team_name"] for player in imported] == [None, None]
+        active = client.get("/api/lobby/active", headers={"Authorization": f"Bearer {imported[0]['session_id']}"})
+        assert active.json()["lobby_id"] == tonight["id"]
+        # The originals stay where they were
+        assert [player["name"] for player in lobby_info(client, last_week)["players"]] == ["Alice", "Bob"]
+
+    def test_preserve_teams_recreates_the_groupings(self, client):
+        last_week, tonight = create_lobby(client, "Last Week"), create_lobby(client, "Tonight")
+        for name in ["Alice", "Bob", "Carol", "Dan"]:
+            join(client, last_week, name)
+        client.post(f"/api/admin/lobby/{last_week['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+        source = lobby_info(client, last_week)
+        source_team_names = {team["id"]: team["name"] for team in source["teams"]}
+        expected = {player["name"]: source_team_names[player["team_id"]] for player in source["players"]}
+
+        response = import_players(client, tonight["id"], last_week["id"], preserve_teams=True)
+
+        assert {player["name"]: player["team_name"] for player in response.json()["imported"]} == expected
+        target = lobby_info(client, tonight)
+        target_team_names = {team["id"]: team["name"] for team in target["teams"]}
+        assert sorted(target_team_names.values()) == sorted(source_team_names.values())
+        assert {player["name"]: target_team_names[player["team_id"]] for player in target["players"]} == expected
+
+    def test_names_already_in_the_lobby_are_skipped(self, client):
+        last_week, tonight = create_lobby(client, "Last Week"), create_lobby(client, "Tonight")
+        for name in ["Alice", "Bob"]:
+            join(client, last_week, name)
+        join(client, tonight, "Alice")
+
+        body = import_players(client, tonight["id"], last_week["id"]).json()
+
+        assert [player["name"] for player in body["imported"]] == ["Bob"]
+        assert body["skipped_names"] == ["Alice"]
+        assert [player["name"] for player in lobby_info(client, tonight)["players"]] == ["Alice", "Bob"]
+
+    def test_missing_lobbies_are_not_found(self, client):
+        lobby = create_lobby(client, "Tonight")
+
+        assert import_players(client, lobby["id"], 9999).status_code == 404
+        assert import_players(client, 9999, lobby["id"]).status_code == 404
+
+    def test_importing_from_the_same_lobby_is_refused(self, client):
+        lobby = create_lobby(client, "Tonight")
+
+        assert import_players(client, lobby["id"], lobby["id"]).status_code == 400
+
+    def test_each_imported_player_is_announced(self, client):
+        last_week, tonight = create_lobby(client, "Last Week"), create_lobby(client, "Tonight")
+        for name in ["Alice", "Bob"]:
+            join(client, last_week, name)
+        session = client.post("/api/admin/login", json={"password": ADMIN_PASSWORD}).json()
+
+        with client.websocket_connect(admin_websocket_url(client, session)) as websocket:
+            websocket.send_json({"action": "subscribe_lobby", "lobby_id": tonight["id"]})
+            imported = import_players(client, tonight["id"], last_week["id"]).json()["imported"]
+            # Answered after the import's events, which were sent before the request returned
+            websocket.send_json({"action": "request_lobby_state", "lobby_id": tonight["id"]})
+            announced = []
+            while (message := websocket.receive_json())["type"] != "lobby_state":
+                if message["type"] == "player_joined":
+                    announced.append(message["player_session_id"])
+
+        assert announced == [player["session_id"] for player in imported]
+
+    def test_locked_lobby_is_refused(self, client):
+        last_week, tonight = create_lobby(client, "Last Week"), create_lobby(client, "Tonight")
+        join(client, last_week, "Alice")
+        client.patch(f"/api/admin/lobby/{tonight['id']}", json={"locked": True}, headers=ADMIN_HEADERS)
+
+        response = import_players(client, tonight["id"], last_week["id"])
+
+        assert response.status_code == 409
+        assert response.json()["detail"]["code"] == "LOBBY_LOCKED"
+
+    def test_players_past_the_cap_are_refused_and_nothing_is_imported(self, client):
+        last_week, tonight = create_lobby(client, "Last Week"), create_lobby(client, "Tonight")
+        for name in ["Alice", "Bob", "Carol"]:
+            join(client, last_week, name)
+        client.post(f"/api/admin/lobby/{last_week['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+        join(client, tonight, "Dan")
+        client.patch(f"/api/admin/lobby/{tonight['id']}", json={"max_players": 3}, headers=ADMIN_HEADERS)
+
+        response = import_players(client, tonight["id"], last_week["id"], preserve_teams=True)
+
+        assert response.status_code == 409
+        assert response.json()["detail"]["code"] == "LOBBY_FULL"
+        target = lobby_info(client, tonight)
+        assert [player["name"] for player in target["players"]] == ["Dan"]
+        assert target["teams"] == []
@@ -0,0 +1,55 @@
+"""Unit tests for parsing player websocket messages."""
+
+import sys
+from pathlib import Path
+
+import pytest
+from pydantic import ValidationError
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.websocket.player_messages import (
+    TEAM_CHAT_MAX_LENGTH,
+    PingMessage,
+    PongMessage,
+    SubmitGuessMessage,
+    TeamChatMessage,
+    parse_player_message,
+)
+
+
+class TestParsePlayerMessage:
+    """Tests for routing frames to a PlayerMessage by action."""
+
+    @pytest.mark.parametrize(
+        "data, expected_type",
+        [
+            ({"action": "submit_guess", "guess": "FLAKE", "word_index": 1}, SubmitGuessMessage),
+            ({"action": "team_chat", "text": "try the bottom"}, TeamChatMessage),
+            ({"action": "ping"}, PingMessage),
+            ({"action": "pong"}, PongMessage),
+        ],
+    )
+    def test_each_action_parses(self, data, expected_type):
+        """Every supported action becomes its own message type."""
+        assert isinstance(parse_player_message(data), expected_type)
+
+    def test_chat_is_trimmed(self):
+        """Surrounding whitespace is dropped before relaying."""
+        assert parse_player_message({"action": "team_chat", "text": "  hi  "}).text == "hi"
+
+    @pytest.mark.parametrize(
+        "data",
+        [
+            {"action": "dance"},
+            {"guess": "FLAKE", "word_index": 1},
+            {"action": "submit_guess", "guess": "FLAKE"},
+            {"action": "team_chat", "text": "   "},
+            {"action": "team_chat", "text": "x" * (TEAM_CHAT_MAX_LENGTH + 1)},
+        ],
+    )
+    def test_invalid_messages_are_rejected(self, data):
+        """Unknown actions, missing fields, blank and oversized chat all fail validation."""
+        with pytest.raises(ValidationError):
+            parse_player_message(data)
@@ -0,0 +1,109 @@
+"""Tests for per-player stats across games and GET /api/admin/stats/players."""
+
+import sys
+from datetime import datetime, timedelta
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.game.player_stats import PlayerGuess, PlayerResult, aggregate_player_stats
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+MONDAY = datetime(2026, 3, 2, 20, 0)
+
+
+class TestAggregatePlayerStats:
+    """Tests for folding guesses and team results into per-player totals."""
+
+    def test_same_name_in_different_lobbies_is_one_player(self):
+        stats = aggregate_player_stats(
+            [
+                PlayerGuess("Alice", game_id=1, is_correct=True, created_at=MONDAY),
+                PlayerGuess("alice ", game_id=2, is_correct=False, created_at=MONDAY + timedelta(days=7)),
+            ],
+            [],
+        )
+
+        assert len(stats) == 1
+        assert (stats[0].name, stats[0].games_played, stats[0].guesses, stats[0].correct_guesses) == ("alice", 2, 2, 1)
+
+    def test_average_solve_time_only_counts_finished_games(self):
+        stats = aggregate_player_stats(
+            [],
+            [
+                PlayerResult("Alice", game_id=1, time_to_complete=100, created_at=MONDAY),
+                PlayerResult("Alice", game_id=2, time_to_complete=200, created_at=MONDAY),
+                PlayerResult("Alice", game_id=3, time_to_complete=None, created_at=MONDAY),
+            ],
+        )
+
+        assert stats[0].games_played == 3
+        assert stats[0].avg_solve_seconds == 150
+
+    def test_players_are_ranked_by_correct_guesses(self):
+        stats = aggregate_player_stats(
+            [
+                PlayerGuess("Bob", game_id=1, is_correct=True, created_at=MONDAY),
+                PlayerGuess("Bob", game_id=1, is_correct=True, created_at=MONDAY),
+                PlayerGuess("Alice", game_id=1, is_correct=True, created_at=MONDAY),
+            ],
+            [PlayerResult("Carol", game_id=1, time_to_complete=None, created_at=MONDAY)],
+        )
+
+        assert [entry.name for entry in stats] == ["Bob", "Alice", "Carol"]
+        assert stats[2].avg_solve_seconds is None
+
+
+def play_round(client) -> None:
+    """Alice and Bob on separate teams; Alice solves the puzzle and Bob makes one wrong guess."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Stats Night"}, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    bob = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"}).json()
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    bob_headers = {"Authorization": f"Bearer {bob['session_id']}"}
+    client.post("/api/lobby/guess", json={"guess": "RAIN", "word_index": 1}, headers=bob_headers)
+    for word_index, word in enumerate(("FLAKE", "CORN", "COB"), start=1):
+        client.post(
+            "/api/lobby/guess",
+            json={"guess": word, "word_index": word_index},
+            headers={"Authorization": f"Bearer {alice['session_id']}"},
+        )
+
+
+@pytest.mark.integration
+class TestPlayerStatsEndpoint:
+    """Tests for GET /api/admin/stats/players."""
+
+    def test_guesses_are_counted_per_player(self, client):
+        play_round(client)
+
+        response = client.get("/api/admin/stats/players", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 200, response.text
+        players = {player["name"]: player for player in response.json()["players"]}
+        assert (players["Alice"]["games_played"], players["Alice"]["correct_guesses"]) == (1, 3)
+        assert (players["Bob"]["guesses"], players["Bob"]["correct_guesses"]) == (1, 0)
+
+    def test_date_range_excludes_other_days(self, client):
+        play_round(client)
+
+        response = client.get("/api/admin/stats/players?date_from=2000-01-01&date_to=2000-01-31", headers=ADMIN_HEADERS)
+
+        assert response.json() == {"date_from": "2000-01-01", "date_to": "2000-01-31", "players": []}
+
+    def test_reversed_date_range_is_rejected(self, client):
+        response = client.get("/api/admin/stats/players?date_from=2026-03-02&date_to=2026-03-01", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 400
+
+    def test_requires_an_admin(self, client):
+        assert client.get("/api/admin/stats/players").status_code == 401
@@ -0,0 +1,87 @@
+"""Unit tests for serialize-once broadcast events."""
+
+import asyncio
+import json
+import sys
+from pathlib import Path
+from typing import ClassVar
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.websocket.events import PlayerKickedEvent
+from backend.websocket.fanout_benchmark import fan_out_prepared, run_fanout_benchmark
+from backend.websocket.prepared import ADMIN_AUDIENCE, PLAYER_AUDIENCE, PreparedEvent
+from backend.websocket.send_stats import SendStatsRegistry
+
+
+class CountingKickEvent(PlayerKickedEvent):
+    dumps: ClassVar[int] = 0
+
+    def model_dump(self, **kwargs):
+        CountingKickEvent.dumps += 1
+        return super().model_dump(**kwargs)
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+
+    async def send_text(self, text):
+        self.sent.append(text)
+
+
+class TestPreparedEvent:
+    """Tests for PreparedEvent caching and audience variants."""
+
+    def test_text_matches_model_dump(self):
+        """The cached text is the same JSON the managers used to build per recipient."""
+        event = PlayerKickedEvent(lobby_id=1, player_session_id="abc")
+
+        assert json.loads(PreparedEvent(event).text) == event.model_dump()
+
+    def test_serializes_once_for_many_recipients(self):
+        """A 100-recipient fan-out dumps the model once and sends identical text to everyone."""
+        CountingKickEvent.dumps = 0
+        sockets = {f"session-{i}": FakeWebSocket() for i in range(100)}
+        event = CountingKickEvent(lobby_id=1, player_session_id="a")
+
+        asyncio.run(fan_out_prepared(SendStatsRegistry(), sockets, event))
+
+        assert CountingKickEvent.dumps == 1
+        assert len({ws.sent[0] for ws in sockets.values()}) == 1
+
+    def test_of_reuses_prepared_event(self):
+        """Passing a PreparedEvent along (e.g. players -> admins) keeps its cache."""
+        prepared = PreparedEvent({"type": "ping"})
+
+        assert PreparedEvent.of(prepared) is prepared
+
+    def test_audience_variant_built_once(self):
+        """A variant transforms a copy of the payload and is cached separately."""
+        calls = []
+
+        def hide_session(data):
+            calls.append(1)
+            data.pop("player_session_id")
+            return data
+
+        prepared = PreparedEvent(
+            PlayerKickedEvent(lobby_id=1, player_session_id="secret"), variants={PLAYER_AUDIENCE: hide_session}
+        )
+
+        player_text = prepared.for_audience(PLAYER_AUDIENCE)
+        prepared.for_audience(PLAYER_AUDIENCE)
+
+        assert "secret" not in player_text
+        assert "secret" in prepared.for_audience(ADMIN_AUDIENCE)
+        assert len(calls) == 1
+
+    @pytest.mark.slow
+    def test_prepared_fan_out_uses_less_cpu(self):
+        """Benchmark: serializing once beats serializing per recipient at 100 recipients."""
+        result = run_fanout_benchmark(recipients=100, rounds=200)
+
+        assert result["prepared_seconds"] < result["per_recipient_seconds"]
@@ -0,0 +1,114 @@
+"""Tests for contest-mode prize tiers."""
+
+import csv
+import io
+import sys
+from datetime import datetime, timedelta
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.game.prizes import PrizeGuess, best_streaks, fastest_rung
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+START = datetime(2026, 10, 16, 19, 0, 0)
+
+
+def guess(team_id: int, word_index: int, seconds: int, correct: bool = True, player_id: int = 0) -> PrizeGuess:
+    return PrizeGuess(team_id, player_id or team_id * 10, 1, word_index, correct, START + timedelta(seconds=seconds))
+
+
+class TestFastestRung:
+    """Tests for timing single rungs."""
+
+    def test_rungs_are_timed_from_the_teams_previous_solve(self):
+        guesses = [guess(1, 1, 30), guess(2, 1, 20), guess(1, 2, 35), guess(2, 2, 60)]
+
+        solve = fastest_rung(guesses, {1: START})
+
+        assert (solve.team_id, solve.seconds) == (1, 5.0)
+
+    def test_only_the_first_correct_guess_on_a_rung_counts(self):
+        """A teammate retyping a solved answer isn't a new (instant) solve."""
+        guesses = [guess(1, 1, 30), guess(1, 1, 31, player_id=11), guess(1, 2, 50)]
+
+        assert fastest_rung(guesses, {1: START}).seconds == 20.0
+
+    def test_no_correct_guesses(self):
+        assert fastest_rung([guess(1, 1, 30, correct=False)], {1: START}) is None
+
+
+class TestBestStreaks:
+    """Tests for runs of correct guesses."""
+
+    def test_wrong_guess_resets_the_run(self):
+        guesses = [guess(1, 1, 1), guess(1, 2, 2), guess(1, 3, 3, correct=False), guess(1, 3, 4), guess(2, 1, 5)]
+
+        streaks = best_streaks(guesses)
+
+        assert {team_id: length for team_id, (length, _) in streaks.items()} == {1: 2, 2: 1}
+        assert streaks[1][1] == START + timedelta(seconds=2)
+
+
+@pytest.fixture
+def lobby(client):
+    """A contest lobby where Alice's team solved two rungs and Bob's team guessed wrong once."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Contest Night"}, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    bob = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"}).json()
+    client.put(
+        f"/api/admin/lobby/{lobby['id']}/prizes",
+        json={"top_teams": 2, "fastest_rung": True, "best_streak": True},
+        headers=ADMIN_HEADERS,
+    )
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    for word_index, word in [(1, "FLAKE"), (2, "CORN")]:
+        client.post(
+            "/api/lobby/guess",
+            json={"guess": word, "word_index": word_index},
+            headers={"Authorization": f"Bearer {alice['session_id']}"},
+        )
+    client.post(
+        "/api/lobby/guess",
+        json={"guess": "ICE", "word_index": 1},
+        headers={"Authorization": f"Bearer {bob['session_id']}"},
+    )
+    return lobby
+
+
+@pytest.mark.integration
+class TestContestMode:
+    """Tests for PUT /api/admin/lobby/{lobby_id}/prizes and the awards in the results export."""
+
+    def test_top_teams_is_bounded(self, client, lobby):
+        response = client.put(f"/api/admin/lobby/{lobby['id']}/prizes", json={"top_teams": 50}, headers=ADMIN_HEADERS)
+
+        assert response.status_code == 400
+
+    def test_export_includes_awards(self, client, lobby):
+        body = client.get(f"/api/admin/lobby/{lobby['id']}/results/export?format=json", headers=ADMIN_HEADERS).json()
+
+        prizes = {award["prize"]: award for award in body["prizes"]}
+        assert prizes["fastest_rung"]["player_name"] == "Alice"
+        assert prizes["best_streak"]["value"] == 2
+        assert "top_team" not in prizes  # No round has been scored yet
+
+        response = client.get(f"/api/admin/lobby/{lobby['id']}/results/export?format=csv", headers=ADMIN_HEADERS)
+        rows = list(csv.reader(io.StringIO(response.text)))
+        assert [row[1] for row in rows if row[0] == "prize"] == ["Fastest rung (Alice)", "Best streak"]
+
+    def test_turning_contest_mode_off(self, client, lobby):
+        client.put(f"/api/admin/lobby/{lobby['id']}/prizes", json={}, headers=ADMIN_HEADERS)
+
+        body = client.get(f"/api/admin/lobby/{lobby['id']}/results/export?format=json", headers=ADMIN_HEADERS).json()
+
+        assert body["prizes"] == []
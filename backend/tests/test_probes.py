@@ -0,0 +1,50 @@
+"""Integration tests for the liveness and readiness probes."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.health import health_monitor
+
+
+async def unreachable_db():
+    raise ConnectionError("database is down")
+
+
+@pytest.mark.integration
+class TestProbes:
+    """Tests for GET /api/health and GET /api/ready."""
+
+    def test_liveness(self, client):
+        response = client.get("/api/health")
+        assert response.status_code == 200
+        assert response.json()["status"] == "ok"
+
+    def test_ready_with_a_reachable_database(self, client):
+        response = client.get("/api/ready")
+
+        assert response.status_code == 200
+        body = response.json()
+        assert body["ready"] and body["database"]
+        assert body["db_latency_ms"] >= 0
+        assert body["player_connections"] == 0
+
+    def test_not_ready_without_the_database(self, client, monkeypatch):
+        monkeypatch.setattr("backend.api.probes.probe_db_latency", unreachable_db)
+
+        response = client.get("/api/ready")
+
+        assert response.status_code == 503
+        assert response.json()["database"] is False
+
+    def test_probes_dont_count_towards_the_health_score(self, client, monkeypatch):
+        monkeypatch.setattr("backend.api.probes.probe_db_latency", unreachable_db)
+
+        client.get("/api/health")
+        client.get("/api/ready")
+
+        assert len(health_monitor.requests) == 0
@@ -0,0 +1,97 @@
+"""Tests for the team_progress events other teams see."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, LONG_PUZZLE
+
+# Eight hidden rungs, so a single solve doesn't move a team out of the first coarse quarter
+PUZZLE = {
+    **LONG_PUZZLE,
+    "title": "Long Winter",
+    "ladder": LONG_PUZZLE["ladder"]
+    + [
+        {"word": "LEAF", "clue": "Map ___", "transform": None},
+        {"word": "BLOWER", "clue": "Leaf ___", "transform": None},
+        {"word": "FAN", "clue": "Blower, or a supporter", "transform": None},
+    ],
+}
+WORDS = [step["word"] for step in PUZZLE["ladder"]]
+
+
+def start_game(client, visibility: str):
+    """Start a two-team game; returns (lobby, Alice's headers, Bob's websocket URL)."""
+    lobby = client.post("/api/admin/lobby", json={"name": "Progress Night"}, headers=ADMIN_HEADERS).json()
+    client.put(
+        f"/api/admin/lobby/{lobby['id']}/progress-visibility", json={"visibility": visibility}, headers=ADMIN_HEADERS
+    )
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    bob = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"}).json()
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    token = client.post("/api/lobby/ws-token", headers={"Authorization": f"Bearer {bob['session_id']}"}).json()
+    bob_url = f"/ws/lobby/{lobby['id']}/player?token={token['token']}"
+    return lobby, {"Authorization": f"Bearer {alice['session_id']}"}, bob_url
+
+
+def solve(client, headers, word_index: int):
+    client.post("/api/lobby/guess", json={"guess": WORDS[word_index], "word_index": word_index}, headers=headers)
+
+
+def next_progress(websocket) -> dict:
+    while True:
+        message = websocket.receive_json()
+        if message["type"] == "team_progress":
+            return message
+
+
+@pytest.mark.integration
+class TestProgressBroadcasts:
+    """Tests for when other teams are told about a team's progress."""
+
+    def test_coarse_solves_inside_a_quarter_stay_quiet(self, client):
+        """One rung of eight leaves Alice's team in the first quarter, so Bob first hears of it at the second."""
+        _, alice, bob_url = start_game(client, "coarse")
+
+        with client.websocket_connect(bob_url) as websocket:
+            solve(client, alice, 1)
+            solve(client, alice, 2)
+
+            progress = next_progress(websocket)
+
+        assert progress["progress"] == 0.25
+        assert progress["solved_count"] is None
+
+    def test_coarse_completion_is_always_sent(self, client):
+        _, alice, bob_url = start_game(client, "coarse")
+        for word_index in range(1, 8):
+            solve(client, alice, word_index)
+
+        with client.websocket_connect(bob_url) as websocket:
+            # Seven of eight still reads as three quarters; the finish is what changes it
+            solve(client, alice, 8)
+
+            progress = next_progress(websocket)
+
+        assert (progress["progress"], progress["is_completed"]) == (1.0, True)
+
+    def test_exact_sends_every_solve(self, client):
+        _, alice, bob_url = start_game(client, "exact")
+
+        with client.websocket_connect(bob_url) as websocket:
+            solve(client, alice, 1)
+            solve(client, alice, 2)
+
+            counts = [next_progress(websocket)["solved_count"], next_progress(websocket)["solved_count"]]
+
+        assert counts == [1, 2]
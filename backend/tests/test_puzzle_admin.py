@@ -0,0 +1,202 @@
+"""Integration tests for the stored puzzle admin API."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_PASSWORD, PUZZLE, admin_websocket_url
+
+
+# Mixed-case difficulty, which the API stores lowercased
+PUZZLE = {**PUZZLE, "difficulty": "Easy"}
+
+
+def create_puzzle(client, **overrides):
+    response = client.post("/api/admin/puzzle", json={**PUZZLE, **overrides}, headers=ADMIN_HEADERS)
+    assert response.status_code == 200, response.text
+    return response.json()
+
+
+@pytest.mark.integration
+class TestPuzzleAdmin:
+    """Tests for creating, listing, editing and deleting stored puzzles."""
+
+    def test_create_and_get(self, client):
+        """A created puzzle comes back with its ladder and normalized difficulty."""
+        created = create_puzzle(client)
+
+        detail = client.get(f"/api/admin/puzzle/{created['id']}", headers=ADMIN_HEADERS).json()
+
+        assert detail["title"] == "Cold Snap"
+        assert detail["difficulty"] == "easy"
+        assert detail["word_count"] == 5
+        assert [step["word"] for step in detail["ladder"]] == ["SNOW", "FLAKE", "CORN", "COB", "WEB"]
+
+    def test_list_filters_by_difficulty(self, client):
+        """The list endpoint returns summaries and can be narrowed to one difficulty."""
+        create_puzzle(client)
+        create_puzzle(client, title="Hard One", difficulty="hard")
+
+        listed = client.get("/api/admin/puzzle?difficulty=hard", headers=ADMIN_HEADERS).json()
+
+        assert [puzzle["title"] for puzzle in listed] == ["Hard One"]
+        assert "ladder" not in listed[0]
+
+    def test_author_is_stored_and_blank_fields_dropped(self, client):
+        created = create_puzzle(client, author_name="  Ada  ", author_handle="@ada", author_notes="")
+
+        assert created["author_name"] == "Ada"
+        assert created["author_handle"] == "@ada"
+        assert created["author_notes"] is None
+
+    def test_list_filters_by_author(self, client):
+        """Writers can be found by name or handle, with or without the @."""
+        create_puzzle(client, title="Ada's", author_name="Ada", author_handle="@ada")
+        create_puzzle(client, title="Grace's", author_name="Grace")
+        create_puzzle(client, title="Anonymous")
+
+        for author in ("ada", "@ADA", "Ada"):
+            listed = client.get(f"/api/admin/puzzle?author={author}", headers=ADMIN_HEADERS).json()
+            assert [puzzle["title"] for puzzle in listed] == ["Ada's"]
+
+    def test_invalid_puzzle_rejected(self, client):
+        """Ladders shorter than puzzle files allow are rejected up front."""
+        response = client.post(
+            "/api/admin/puzzle", json={**PUZZLE, "ladder": PUZZLE["ladder"][:3]}, headers=ADMIN_HEADERS
+        )
+
+        assert response.status_code == 422
+
+    def test_alternates_are_stored_and_overlaps_rejected(self, client):
+        """Alternate answers round-trip, but not when another rung already accepts them."""
+        ladder = [dict(step) for step in PUZZLE["ladder"]]
+        ladder[2] = {**ladder[2], "alternates": ["KORN"]}
+        created = create_puzzle(client, ladder=ladder)
+        assert created["ladder"][2]["alternates"] == ["KORN"]
+
+        ladder[2] = {**ladder[2], "alternates": ["COB"]}
+        response = client.put(
+            f"/api/admin/puzzle/{created['id']}", json={**PUZZLE, "ladder": ladder}, headers=ADMIN_HEADERS
+        )
+        assert response.status_code == 422
+
+    def test_update_replaces_puzzle(self, client):
+        """PUT replaces the title and ladder and records when it changed."""
+        created = create_puzzle(client)
+
+        updated = client.put(
+            f"/api/admin/puzzle/{created['id']}", json={**PUZZLE, "title": "Warm Snap"}, headers=ADMIN_HEADERS
+        ).json()
+
+        assert updated["title"] == "Warm Snap"
+        assert updated["updated_at"] is not None
+
+    def test_delete_unused_puzzle(self, client):
+        """A puzzle no game has used can be deleted."""
+        created = create_puzzle(client)
+
+        response = client.delete(f"/api/admin/puzzle/{created['id']}", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 200
+        assert client.get(f"/api/admin/puzzle/{created['id']}", headers=ADMIN_HEADERS).status_code == 404
+
+    def test_puzzle_in_use_cannot_be_edited_or_deleted(self, client):
+        """Once a lobby plays a stored puzzle, edits and deletes are refused with the lobbies that use it."""
+        created = create_puzzle(client)
+        lobby = client.post("/api/admin/lobby", json={"name": "Puzzle Night"}, headers=ADMIN_HEADERS).json()
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"})
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"})
+        client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+
+        started = client.post(
+            f"/api/admin/lobby/{lobby['id']}/game/start",
+            json={"difficulty": "easy", "force_start": True, "puzzle_id": created["id"]},
+            headers=ADMIN_HEADERS,
+        )
+        assert started.status_code == 200, started.text
+
+        edit = client.put(f"/api/admin/puzzle/{created['id']}", json=PUZZLE, headers=ADMIN_HEADERS)
+        delete = client.delete(f"/api/admin/puzzle/{created['id']}", headers=ADMIN_HEADERS)
+
+        assert edit.status_code == 409
+        assert delete.status_code == 409
+        assert delete.json()["detail"]["code"] == "PUZZLE_IN_USE"
+        assert [entry["lobby_id"] for entry in delete.json()["detail"]["lobbies"]] == [lobby["id"]]
+
+    def test_puzzle_only_finished_games_used_can_be_deleted(self, client, lobby):
+        """Finished games don't block a delete; they're moved to a bundled puzzle so their results still load."""
+        created = create_puzzle(client)
+        client.post(
+            f"/api/admin/lobby/{lobby['id']}/game/start",
+            json={"difficulty": "easy", "force_start": True, "puzzle_id": created["id"]},
+            headers=ADMIN_HEADERS,
+        )
+        client.post(f"/api/admin/lobby/{lobby['id']}/game/end", headers=ADMIN_HEADERS)
+
+        response = client.delete(f"/api/admin/puzzle/{created['id']}", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 200, response.text
+        assert client.get(f"/api/admin/puzzle/{created['id']}", headers=ADMIN_HEADERS).status_code == 404
+        state = client.get(f"/api/admin/lobby/{lobby['id']}/game-state", headers=ADMIN_HEADERS)
+        assert state.status_code == 200, state.text
+        assert {team["puzzle"]["title"] for team in state.json()["teams"]} != {PUZZLE["title"]}
+
+
+@pytest.fixture
+def lobby_playing(client):
+    """A lobby two teams are playing the stored puzzle in, with one rung solved; returns (puzzle, lobby, alice)."""
+    created = create_puzzle(client)
+    lobby = client.post("/api/admin/lobby", json={"name": "Puzzle Night"}, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": created["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    headers = {"Authorization": f"Bearer {alice['session_id']}"}
+    client.post("/api/lobby/guess", json={"guess": "FLAKE", "word_index": 1}, headers=headers)
+    return created, lobby, alice
+
+
+@pytest.mark.integration
+class TestForceDeletePuzzle:
+    """Tests for DELETE /api/admin/puzzle/{id}?force=true on a puzzle lobbies are playing."""
+
+    def test_lobbies_restart_on_a_bundled_puzzle(self, client, lobby_playing):
+        created, _, alice = lobby_playing
+
+        response = client.delete(f"/api/admin/puzzle/{created['id']}?force=true", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 200, response.text
+        assert client.get(f"/api/admin/puzzle/{created['id']}", headers=ADMIN_HEADERS).status_code == 404
+        game = client.get(f"/api/game/puzzle?player_session_id={alice['session_id']}").json()
+        assert game["puzzle"]["title"] != PUZZLE["title"]
+        assert sorted(game["state"]["revealed_steps"]) == [0, len(game["puzzle"]["ladder"]) - 1]
+        headers = {"Authorization": f"Bearer {alice['session_id']}"}
+        assert client.get("/api/lobby/team/guesses", headers=headers).json()["total"] == 0
+
+    def test_admins_watching_the_lobby_are_told(self, client, lobby_playing):
+        created, lobby, _ = lobby_playing
+        session = client.post("/api/admin/login", json={"password": ADMIN_PASSWORD}).json()
+
+        with client.websocket_connect(admin_websocket_url(client, session)) as websocket:
+            websocket.send_json({"action": "subscribe_lobby", "lobby_id": lobby["id"]})
+            # Answered after the subscription is in place
+            websocket.send_json({"action": "request_lobby_state", "lobby_id": lobby["id"]})
+            while websocket.receive_json()["type"] != "lobby_state":
+                pass
+
+            client.delete(f"/api/admin/puzzle/{created['id']}?force=true", headers=ADMIN_HEADERS)
+            event = websocket.receive_json()
+            while event["type"] != "puzzle_replaced":
+                event = websocket.receive_json()
+
+        assert event["lobby_id"] == lobby["id"]
+        assert event["deleted_title"] == PUZZLE["title"]
+        assert len(event["restarted_team_ids"]) == 2
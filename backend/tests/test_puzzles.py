@@ -16,6 +16,7 @@ from backend.game.puzzles import (
     LadderStep,
     get_puzzle_manager,
 )
+from backend.game.rng import seeded_rng
 
 
 @pytest.fixture
@@ -249,6 +250,20 @@ class TestTeamPuzzleAssignment:
         # Both should be medium difficulty
         assert all(p.puzzle.meta.difficulty == "medium" for p in puzzles)
 
+    def test_same_seed_reproduces_assignment(self, puzzle_manager):
+        """The same lobby seed and round draw the same puzzles in the same order."""
+        first = puzzle_manager.get_puzzles_for_teams(3, "easy", rng=seeded_rng(42, "puzzles:1"))
+        second = puzzle_manager.get_puzzles_for_teams(3, "easy", rng=seeded_rng(42, "puzzles:1"))
+
+        assert [p.path for p in first] == [p.path for p in second]
+
+    def test_same_seed_reproduces_same_puzzle_mode(self, puzzle_manager):
+        """Seeded draws are reproducible when every team shares one puzzle too."""
+        first = puzzle_manager.get_same_puzzle_for_teams(2, "easy", rng=seeded_rng(7, "puzzles:1"))
+        second = puzzle_manager.get_same_puzzle_for_teams(2, "easy", rng=seeded_rng(7, "puzzles:1"))
+
+        assert first[0].path == second[0].path
+
 
 class TestPuzzleValidation:
     """Tests for puzzle models and validation."""
@@ -295,6 +310,21 @@ class TestPuzzleValidation:
         with pytest.raises(ValueError, match="at least 5 steps"):
             Puzzle(meta=PuzzleMeta(title="Test", difficulty="easy"), ladder=short_ladder)
 
+    def test_alternates_are_cleaned(self):
+        """Blank and repeated alternates are dropped."""
+        step = LadderStep(word="DONUT", alternates=[" Doughnut", "DOUGHNUT", ""])
+        assert step.alternates == ["Doughnut"]
+        assert step.accepted_answers() == {"DONUT", "DOUGHNUT"}
+
+    def test_alternates_cant_overlap_other_rungs(self):
+        """An alternate accepted on another rung is rejected; repeated ladder words are still allowed."""
+        ladder = [LadderStep(word=f"W{i}") for i in range(5)]
+        Puzzle(meta=PuzzleMeta(title="Test"), ladder=[*ladder, LadderStep(word="W0")])
+
+        ladder[1].alternates = ["w3"]
+        with pytest.raises(ValueError, match="accepted on both step 1 and step 3"):
+            Puzzle(meta=PuzzleMeta(title="Test"), ladder=ladder)
+
 
 class TestPuzzleSerialization:
     """Tests for puzzle serialization."""
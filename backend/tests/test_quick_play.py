@@ -0,0 +1,29 @@
+"""Unit tests for quick play team formation."""
+
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.quick_play import plan_team_assignments
+
+
+class TestPlanTeamAssignments:
+    """Tests for placing players who trickle into the quick play lobby."""
+
+    def test_first_players_form_a_team_of_n(self):
+        """Players fill one new team before a second one is started."""
+        assert plan_team_assignments([], 4, 3) == [-1, -1, -1, -2]
+
+    def test_fills_existing_team_with_room(self):
+        """A team a player left is topped back up before new teams form."""
+        assert plan_team_assignments([(7, 3), (8, 2)], 2, 3) == [8, -1]
+
+    def test_prefers_fullest_open_team(self):
+        """Getting one team to full size beats spreading players thinly."""
+        assert plan_team_assignments([(1, 1), (2, 2)], 1, 4) == [2]
+
+    def test_no_waiting_players(self):
+        """Nothing to place means nothing planned."""
+        assert plan_team_assignments([(1, 3)], 0, 3) == []
@@ -0,0 +1,85 @@
+"""Tests for the join and guess rate limits."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.rate_limit import RateLimiter
+from backend.settings import settings
+from backend.tests.helpers import ADMIN_HEADERS
+
+
+class TestRateLimiter:
+    """Tests for the sliding window."""
+
+    def test_refuses_past_the_limit_until_the_window_slides(self):
+        limiter = RateLimiter()
+
+        assert [limiter.retry_after("join", "1.2.3.4", 2, now=t) for t in (0.0, 10.0, 20.0)] == [None, None, 40]
+        assert limiter.retry_after("join", "5.6.7.8", 2, now=20.0) is None
+        assert limiter.retry_after("join", "1.2.3.4", 2, now=60.5) is None
+
+    def test_buckets_are_counted_apart(self):
+        limiter = RateLimiter()
+        limiter.retry_after("join", "abc", 1, now=0.0)
+
+        assert limiter.retry_after("guess", "abc", 1, now=0.0) is None
+
+    def test_zero_turns_the_limit_off(self):
+        limiter = RateLimiter()
+
+        assert all(limiter.retry_after("join", "1.2.3.4", 0, now=0.0) is None for _ in range(500))
+
+    def test_quiet_keys_are_forgotten(self):
+        limiter = RateLimiter()
+        limiter.retry_after("join", "1.2.3.4", 5, now=0.0)
+
+        limiter.retry_after("join", "5.6.7.8", 5, now=61.0)
+
+        assert list(limiter.hits) == [("join", "5.6.7.8")]
+
+
+@pytest.fixture
+def lobby(client):
+    return client.post("/api/admin/lobby", json={"name": "Limit Night"}, headers=ADMIN_HEADERS).json()
+
+
+@pytest.mark.integration
+class TestRateLimitedRoutes:
+    """Tests for the limits on POST /api/lobby/{lobby_code} and POST /api/lobby/guess."""
+
+    def test_joins_past_the_limit_get_a_429(self, client, lobby, monkeypatch):
+        monkeypatch.setattr(settings, "RATE_LIMIT_JOINS_PER_MINUTE", 2)
+        for name in ("Alice", "Bob"):
+            assert client.post(f"/api/lobby/{lobby['code']}", json={"name": name}).status_code == 200
+
+        response = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Carol"})
+
+        assert response.status_code == 429
+        assert response.json()["detail"]["code"] == "RATE_LIMITED"
+        assert int(response.headers["Retry-After"]) == response.json()["detail"]["retry_after"] > 0
+
+    def test_other_lobby_routes_are_not_counted_as_joins(self, client, lobby, monkeypatch):
+        monkeypatch.setattr(settings, "RATE_LIMIT_JOINS_PER_MINUTE", 1)
+        player = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+
+        response = client.post("/api/lobby/ws-token", headers={"Authorization": f"Bearer {player['session_id']}"})
+
+        assert response.status_code == 200
+
+    def test_guesses_are_limited_per_player(self, client, lobby, monkeypatch):
+        monkeypatch.setattr(settings, "RATE_LIMIT_GUESSES_PER_MINUTE", 1)
+        alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+        bob = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"}).json()
+
+        def guess(player: dict) -> int:
+            headers = {"Authorization": f"Bearer {player['session_id']}"}
+            return client.post("/api/lobby/guess", json={"guess": "ICE", "word_index": 1}, headers=headers).status_code
+
+        assert guess(alice) != 429
+        assert guess(alice) == 429
+        assert guess(bob) != 429
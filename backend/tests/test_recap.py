@@ -0,0 +1,125 @@
+"""Tests for shareable game recaps."""
+
+import sys
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.game.recap import RecapGuess, build_timeline, player_aliases
+from backend.tests.helpers import ADMIN_HEADERS, LONG_PUZZLE
+
+T0 = datetime(2026, 1, 1, 19, 0, tzinfo=timezone.utc)
+
+
+def guess(seconds: float, word: str, is_correct: bool, player_id: int = 1) -> RecapGuess:
+    return RecapGuess(
+        team_id=1,
+        player_id=player_id,
+        word_index=1,
+        guess=word,
+        is_correct=is_correct,
+        created_at=T0 + timedelta(seconds=seconds),
+    )
+
+
+class TestBuildTimeline:
+    """Tests for turning the guess log into a shareable timeline."""
+
+    def test_entries_are_in_guess_order(self):
+        """Entries follow when guesses were made, timed from the round start."""
+        timeline = build_timeline(T0, [guess(20, "FLAKE", True), guess(5, "FLOCK", False)], {1: "Alice"}, "show")
+
+        assert [(entry.at_seconds, entry.guess, entry.solved) for entry in timeline] == [
+            (5.0, "FLOCK", False),
+            (20.0, "FLAKE", True),
+        ]
+
+    @pytest.mark.parametrize(
+        "policy, expected",
+        [
+            ("show", [("FLOCK", False), ("FLAKE", True)]),
+            ("mask", [(None, False), ("FLAKE", True)]),
+            ("hide", [("FLAKE", True)]),
+        ],
+    )
+    def test_wrong_guesses_follow_policy(self, policy, expected):
+        """Wrong guesses are shown, masked or dropped; solves always carry the answer."""
+        timeline = build_timeline(T0, [guess(5, "FLOCK", False), guess(20, "FLAKE", True)], {1: "Alice"}, policy)
+
+        assert [(entry.guess, entry.solved) for entry in timeline] == expected
+
+    def test_aliases_number_players_by_first_appearance(self):
+        assert player_aliases([7, 3, 7, 9]) == {7: "Player 1", 3: "Player 2", 9: "Player 3"}
+
+
+@pytest.fixture
+def app_builder(app_builder):
+    return app_builder.setting("SHARED_REPLAY_WRONG_GUESSES", "mask")
+
+
+@pytest.fixture
+def lobby_id(client):
+    """A round on LONG_PUZZLE where Alice's team missed FLAKE once, then solved it."""
+    puzzle = client.post("/api/admin/puzzle", json=LONG_PUZZLE, headers=ADMIN_HEADERS).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Recap Night"}, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+
+    alice_headers = {"Authorization": f"Bearer {alice['session_id']}"}
+    client.post("/api/lobby/guess", json={"guess": "FLOCK", "word_index": 1}, headers=alice_headers)
+    client.post("/api/lobby/guess", json={"guess": "FLAKE", "word_index": 1}, headers=alice_headers)
+    return lobby["id"]
+
+
+def share_replay(client, lobby_id: int, anonymize: bool) -> str:
+    response = client.post(
+        f"/api/admin/lobby/{lobby_id}/share-replay", json={"anonymize": anonymize}, headers=ADMIN_HEADERS
+    )
+    assert response.status_code == 200
+    return response.json()["token"]
+
+
+@pytest.mark.integration
+class TestSharedReplay:
+    """Tests for GET /api/shared/replay/{token}."""
+
+    def test_replay_is_sanitized(self, client, lobby_id):
+        """Anonymized links hide names, wrong guesses are masked, and no session ids leak."""
+        response = client.get(f"/api/shared/replay/{share_replay(client, lobby_id, anonymize=True)}")
+        assert response.status_code == 200
+        replay = response.json()
+
+        assert replay["anonymized"] is True
+        assert [(entry["player"], entry["guess"], entry["solved"]) for entry in replay["timeline"]] == [
+            ("Player 1", None, False),
+            ("Player 1", "FLAKE", True),
+        ]
+        assert "Alice" not in response.text
+        assert "session_id" not in response.text
+
+    def test_ladder_answers_are_not_given_away(self, client, lobby_id):
+        """Teams get the clues and the two ends of the ladder, not the rung answers."""
+        replay = client.get(f"/api/shared/replay/{share_replay(client, lobby_id, anonymize=False)}").json()
+
+        team = replay["teams"][0]
+        assert (team["start_word"], team["end_word"]) == ("SNOW", "MAP")
+        assert "CORN" not in str(replay["teams"])
+        assert replay["timeline"][0]["player"] == "Alice"
+
+    def test_results_token_is_not_a_replay_token(self, client, lobby_id):
+        results_token = client.post(f"/api/admin/lobby/{lobby_id}/share-results", headers=ADMIN_HEADERS).json()["token"]
+
+        assert client.get(f"/api/shared/replay/{results_token}").status_code == 404
+
+    def test_non_ascii_token_is_not_found(self, client):
+        assert client.get("/api/shared/replay/caf%C3%A9.sig%C3%A9").status_code == 404
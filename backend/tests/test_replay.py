@@ -0,0 +1,144 @@
+"""Unit tests for the per-lobby event replay buffer."""
+
+import asyncio
+import json
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.websocket.prepared import PreparedEvent
+from backend.websocket.replay import ReplayBuffer
+
+LOBBY_ID = 1
+
+
+def event(name: str) -> PreparedEvent:
+    return PreparedEvent({"type": name})
+
+
+def types_of(events) -> list:
+    return [json.loads(prepared.text)["type"] for prepared in events]
+
+
+class TestReplayBuffer:
+    """Tests for numbering events and replaying what a player missed."""
+
+    def test_events_are_numbered_per_lobby_and_stamped(self):
+        """Each lobby counts from 1, and the number travels in the payload."""
+        buffer = ReplayBuffer(size=10)
+        first = event("a")
+        assert json.loads(first.text) == {"type": "a"}  # Already serialized before it's stamped
+
+        assert buffer.record(LOBBY_ID, first) == 1
+        assert buffer.record(LOBBY_ID, event("b")) == 2
+        assert buffer.record(2, event("c")) == 1
+        assert json.loads(first.text) == {"type": "a", "seq": 1}
+
+    def test_replays_only_what_the_player_would_have_received(self):
+        """Lobby-wide events, the player's team's events and their own direct events, in order."""
+        buffer = ReplayBuffer(size=10)
+        buffer.record(LOBBY_ID, event("seen"))
+        buffer.record(LOBBY_ID, event("lobby"))
+        buffer.record(LOBBY_ID, event("my_team"), team_id=10)
+        buffer.record(LOBBY_ID, event("other_team"), team_id=20)
+        buffer.record(LOBBY_ID, event("to_me"), player_session_id="alice")
+        buffer.record(LOBBY_ID, event("to_bob"), player_session_id="bob")
+
+        missed = buffer.missed_since(LOBBY_ID, 1, "alice", team_id=10)
+        assert types_of(missed) == ["lobby", "my_team", "to_me"]
+
+    def test_caught_up_player_gets_nothing(self):
+        buffer = ReplayBuffer(size=10)
+        buffer.record(LOBBY_ID, event("a"))
+
+        assert buffer.missed_since(LOBBY_ID, 1, "alice", team_id=None) == []
+
+    def test_gap_past_the_buffer_needs_a_resync(self):
+        """Once events after last_seq have been dropped, replay would leave a hole, so it's refused."""
+        buffer = ReplayBuffer(size=2)
+        for name in ("a", "b", "c", "d"):
+            buffer.record(LOBBY_ID, event(name))
+
+        assert buffer.missed_since(LOBBY_ID, 1, "alice", team_id=None) is None
+        assert types_of(buffer.missed_since(LOBBY_ID, 2, "alice", team_id=None)) == ["c", "d"]
+
+    def test_seq_from_before_a_restart_needs_a_resync(self):
+        """A last_seq ahead of the counter can't be trusted."""
+        buffer = ReplayBuffer(size=10)
+        buffer.record(LOBBY_ID, event("a"))
+
+        assert buffer.missed_since(LOBBY_ID, 50, "alice", team_id=None) is None
+
+    def test_forget_drops_the_lobby(self):
+        buffer = ReplayBuffer(size=10)
+        buffer.record(LOBBY_ID, event("a"))
+        buffer.forget(LOBBY_ID)
+
+        assert buffer.record(LOBBY_ID, event("b")) == 1
+
+
+class TestReplayMemoryBudget:
+    """Tests for keeping every lobby's buffer together under max_bytes."""
+
+    EVENT_BYTES = len(json.dumps({"type": "a", "seq": 1}))
+
+    def test_least_recently_active_lobby_is_evicted_first(self):
+        buffer = ReplayBuffer(size=10, max_bytes=self.EVENT_BYTES * 2)
+        buffer.record(LOBBY_ID, event("a"))
+        buffer.record(2, event("b"))
+        buffer.record(LOBBY_ID, event("c"))
+
+        assert list(buffer.events) == [LOBBY_ID]
+        assert buffer.evicted_lobbies == 1
+        assert types_of(buffer.missed_since(LOBBY_ID, 0, "alice", team_id=None)) == ["a", "c"]
+        assert buffer.missed_since(2, 0, "alice", team_id=None) is None  # Its players resync instead
+
+    def test_lobby_over_budget_on_its_own_loses_its_oldest_events(self):
+        buffer = ReplayBuffer(size=10, max_bytes=self.EVENT_BYTES * 2)
+        for name in ("a", "b", "c"):
+            buffer.record(LOBBY_ID, event(name))
+
+        assert buffer.trimmed_events == 1
+        assert buffer.total_bytes == self.EVENT_BYTES * 2
+        assert types_of(buffer.missed_since(LOBBY_ID, 1, "alice", team_id=None)) == ["b", "c"]
+
+    def test_bytes_are_released_when_events_leave(self):
+        buffer = ReplayBuffer(size=2)
+        for name in ("a", "b", "c"):
+            buffer.record(LOBBY_ID, event(name))
+        assert buffer.total_bytes == self.EVENT_BYTES * 2
+
+        buffer.forget(LOBBY_ID)
+        assert buffer.total_bytes == 0
+
+
+class TestWaitForEvents:
+    """Tests for the long-poll wait on top of the buffer."""
+
+    def test_returns_buffered_events_straight_away(self):
+        buffer = ReplayBuffer(size=10)
+        buffer.record(LOBBY_ID, event("a"))
+
+        assert types_of(asyncio.run(buffer.wait_for_events(LOBBY_ID, 0, "alice", None, timeout=5))) == ["a"]
+
+    def test_wakes_when_an_event_for_the_player_arrives(self):
+        """Events meant for someone else don't end the wait."""
+        buffer = ReplayBuffer(size=10)
+
+        async def poll():
+            waiting = asyncio.create_task(buffer.wait_for_events(LOBBY_ID, 0, "alice", 10, timeout=5))
+            await asyncio.sleep(0)
+            buffer.record(LOBBY_ID, event("other_team"), team_id=20)
+            await asyncio.sleep(0)
+            assert not waiting.done()
+            buffer.record(LOBBY_ID, event("my_team"), team_id=10)
+            return await waiting
+
+        assert types_of(asyncio.run(poll())) == ["my_team"]
+
+    def test_times_out_with_nothing(self):
+        buffer = ReplayBuffer(size=10)
+
+        assert asyncio.run(buffer.wait_for_events(LOBBY_ID, 0, "alice", None, timeout=0.01)) == []
@@ -0,0 +1,104 @@
+"""Integration tests for scoring finished rounds, GET /api/lobby/{lobby_id}/results and shared results links."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+
+@pytest.fixture
+def game(client):
+    """A lobby mid-game with Alice and Bob on separate teams; returns (lobby, alice headers, bob headers)."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Results Night"}, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    bob = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"}).json()
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    return (
+        lobby,
+        {"Authorization": f"Bearer {alice['session_id']}"},
+        {"Authorization": f"Bearer {bob['session_id']}"},
+    )
+
+
+def solve(client, headers, words=("FLAKE", "CORN", "COB")):
+    for word_index, word in enumerate(words, start=1):
+        client.post("/api/lobby/guess", json={"guess": word, "word_index": word_index}, headers=headers)
+
+
+def results(client, lobby: dict):
+    return client.get(f"/api/lobby/{lobby['id']}/results")
+
+
+@pytest.mark.integration
+class TestResults:
+    """Tests for the final standings of a round."""
+
+    def test_no_results_before_a_round_is_scored(self, client, game):
+        lobby, _, _ = game
+
+        assert results(client, lobby).json() == {
+            "round_number": 0,
+            "winning_team_id": None,
+            "winning_team_name": None,
+            "standings": [],
+        }
+
+    def test_round_is_scored_when_every_team_finishes(self, client, game):
+        lobby, alice, bob = game
+        solve(client, alice)
+        solve(client, bob)
+
+        body = results(client, lobby).json()
+
+        assert body["round_number"] == 1
+        assert [standing["placement"] for standing in body["standings"]] == [1, 2]
+        assert all(standing["completed"] for standing in body["standings"])
+        assert body["winning_team_id"] == body["standings"][0]["team_id"]
+
+    def test_ending_the_round_early_ranks_unfinished_teams_last(self, client, game):
+        lobby, alice, bob = game
+        solve(client, bob, words=("FLAKE",))
+        solve(client, alice)
+
+        client.post(f"/api/admin/lobby/{lobby['id']}/end", headers=ADMIN_HEADERS)
+
+        standings = results(client, lobby).json()["standings"]
+        assert [standing["completed"] for standing in standings] == [True, False]
+        assert standings[1]["completion_percentage"] == pytest.approx(3 / 5)
+
+    def test_unknown_lobby(self, client):
+        assert client.get("/api/lobby/9999/results").status_code == 404
+
+
+@pytest.mark.integration
+class TestSharedResults:
+    """Tests for GET /api/shared/results/{token} only showing finished rounds."""
+
+    def test_shared_once_the_round_finishes(self, client, game):
+        lobby, alice, _ = game
+        token = client.post(f"/api/admin/lobby/{lobby['id']}/share-results", headers=ADMIN_HEADERS).json()["token"]
+        solve(client, alice)
+
+        assert client.get(f"/api/shared/results/{token}").status_code == 409
+
+        client.post(f"/api/admin/lobby/{lobby['id']}/end", headers=ADMIN_HEADERS)
+        shared = client.get(f"/api/shared/results/{token}")
+
+        assert shared.status_code == 200, shared.text
+        assert shared.json()["lobby_name"] == "Results Night"
+
+    def test_nothing_to_share_before_a_round_is_scored(self, client, lobby):
+        token = client.post(f"/api/admin/lobby/{lobby['id']}/share-results", headers=ADMIN_HEADERS).json()["token"]
+
+        assert client.get(f"/api/shared/results/{token}").status_code == 404
@@ -0,0 +1,99 @@
+"""Tests for the per-team and per-player results export."""
+
+import csv
+import io
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+from backend.utils.results_export import RESULTS_COLUMNS, PlayerStatsRow, TeamStatsRow, iter_results_csv
+
+
+def parse(text: str) -> list[list[str]]:
+    return list(csv.reader(io.StringIO(text)))
+
+
+class TestResultsCsv:
+    """Tests for the CSV layout."""
+
+    def test_team_rows_then_player_rows(self):
+        team = TeamStatsRow(
+            name="Owls",
+            words_solved=3,
+            guesses=5,
+            hints=1,
+            time_seconds=90,
+            total_points=2,
+            rounds_played=1,
+            rounds_won=1,
+        )
+        player = PlayerStatsRow(name="=Alice", team="Owls", words_solved=2, guesses=3)
+
+        assert parse("".join(iter_results_csv([team], [player]))) == [
+            RESULTS_COLUMNS,
+            ["team", "Owls", "", "3", "5", "1", "90", "2", "1", "1"],
+            ["player", "'=Alice", "Owls", "2", "3", "", "", "", "", ""],
+        ]
+
+
+@pytest.fixture
+def lobby(client):
+    """A lobby where Alice's team solved two rungs and Bob's team guessed wrong once."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Archive Night"}, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    bob = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"}).json()
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    for word_index, word in [(1, "FLAKE"), (2, "CORN")]:
+        client.post(
+            "/api/lobby/guess",
+            json={"guess": word, "word_index": word_index},
+            headers={"Authorization": f"Bearer {alice['session_id']}"},
+        )
+    client.post(
+        "/api/lobby/guess",
+        json={"guess": "ICE", "word_index": 1},
+        headers={"Authorization": f"Bearer {bob['session_id']}"},
+    )
+    return lobby
+
+
+def export(client, lobby: dict, format: str):
+    return client.get(f"/api/admin/lobby/{lobby['id']}/results/export?format={format}", headers=ADMIN_HEADERS)
+
+
+@pytest.mark.integration
+class TestResultsExportEndpoint:
+    """Tests for GET /api/admin/lobby/{lobby_id}/results/export."""
+
+    def test_json_has_team_and_player_stats(self, client, lobby):
+        response = export(client, lobby, "json")
+
+        assert response.status_code == 200, response.text
+        assert response.headers["content-disposition"] == f'attachment; filename="{lobby["code"]}-results.json"'
+        body = response.json()
+        players = {player["name"]: (player["words_solved"], player["guesses"]) for player in body["players"]}
+        assert players == {"Alice": (2, 2), "Bob": (0, 1)}
+        assert sorted((team["words_solved"], team["guesses"]) for team in body["teams"]) == [(0, 1), (2, 2)]
+
+    def test_csv_download(self, client, lobby):
+        response = export(client, lobby, "csv")
+
+        assert response.headers["content-type"].startswith("text/csv")
+        rows = parse(response.text)
+        assert rows[0] == RESULTS_COLUMNS
+        assert [row[0] for row in rows[1:]] == ["team", "team", "player", "player"]
+
+    def test_unknown_format_and_lobby(self, client, lobby):
+        assert export(client, lobby, "xml").status_code == 422
+        assert client.get("/api/admin/lobby/9999/results/export", headers=ADMIN_HEADERS).status_code == 404
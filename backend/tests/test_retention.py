@@ -0,0 +1,119 @@
+"""Tests for guess log and team chat retention."""
+
+import asyncio
+from datetime import datetime, timedelta, timezone
+import sys
+from pathlib import Path
+
+import pytest
+from sqlmodel import Session, select
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+import backend.database as database
+from backend.database.models import ChatMessage, Player
+from backend.retention import prune_chat_messages, select_rows_to_prune
+from backend.settings import settings
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+
+NOW = datetime(2026, 6, 1, tzinfo=timezone.utc)
+
+
+def days_ago(days):
+    return NOW - timedelta(days=days)
+
+
+class TestSelectRowsToPrune:
+    """Tests for choosing which guesses fall outside retention."""
+
+    def test_prunes_by_age(self):
+        """Guesses older than the retention window are pruned."""
+        rows = [(1, 1, days_ago(40)), (2, 1, days_ago(10))]
+
+        assert select_rows_to_prune(rows, NOW, max_age_days=30, max_rows_per_lobby=0) == {1}
+
+    def test_naive_timestamps_treated_as_utc(self):
+        """SQLite returns naive datetimes; they compare as UTC."""
+        rows = [(1, 1, days_ago(40).replace(tzinfo=None))]
+
+        assert select_rows_to_prune(rows, NOW, max_age_days=30, max_rows_per_lobby=0) == {1}
+
+    def test_per_lobby_cap_keeps_newest(self):
+        """Only the newest guesses per lobby survive the row cap."""
+        rows = [(1, 1, days_ago(3)), (2, 1, days_ago(2)), (3, 1, days_ago(1)), (4, 2, days_ago(5))]
+
+        assert select_rows_to_prune(rows, NOW, max_age_days=0, max_rows_per_lobby=2) == {1}
+
+    def test_disabled_limits_prune_nothing(self):
+        """With both limits at 0 nothing is pruned."""
+        rows = [(1, 1, days_ago(400))]
+
+        assert select_rows_to_prune(rows, NOW, max_age_days=0, max_rows_per_lobby=0) == set()
+
+
+@pytest.fixture
+def app_builder(app_builder):
+    return app_builder.setting("CHAT_RETENTION_DAYS", 30)
+
+
+def lobby_with_chat(client, name: str, playing: bool = False) -> int:
+    """A lobby whose teams chatted last month and yesterday, mid-game if playing; returns its id."""
+    lobby = client.post("/api/admin/lobby", json={"name": name}, headers=ADMIN_HEADERS).json()
+    for player_name in ("Alice", "Bob"):
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": player_name})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    if playing:
+        puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+        client.post(
+            f"/api/admin/lobby/{lobby['id']}/game/start",
+            json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+            headers=ADMIN_HEADERS,
+        )
+
+    with Session(database.engine) as db:
+        alice = db.exec(select(Player).where(Player.lobby_id == lobby["id"]).where(Player.name == "Alice")).one()
+        for text, days in (("last month", 40), ("yesterday", 1)):
+            db.add(
+                ChatMessage(
+                    lobby_id=lobby["id"],
+                    team_id=alice.team_id,
+                    player_id=alice.id,
+                    player_name=alice.name,
+                    text=text,
+                    created_at=datetime.now(timezone.utc) - timedelta(days=days),
+                )
+            )
+        db.commit()
+    return lobby["id"]
+
+
+def chat_lines(lobby_id: int) -> list[str]:
+    with Session(database.engine) as db:
+        return sorted(db.exec(select(ChatMessage.text).where(ChatMessage.lobby_id == lobby_id)).all())
+
+
+@pytest.mark.integration
+class TestPruneChatMessages:
+    """Tests for deleting team chat outside the retention window."""
+
+    def test_old_lines_are_pruned(self, client):
+        lobby_id = lobby_with_chat(client, "Last Month")
+
+        assert asyncio.run(prune_chat_messages()) == 1
+        assert chat_lines(lobby_id) == ["yesterday"]
+
+    def test_lobbies_mid_game_keep_their_chat(self, client):
+        """Players reconnecting during a game catch up from it, however old."""
+        lobby_id = lobby_with_chat(client, "Marathon", playing=True)
+
+        assert asyncio.run(prune_chat_messages()) == 0
+        assert chat_lines(lobby_id) == ["last month", "yesterday"]
+
+    def test_zero_keeps_everything(self, client, monkeypatch):
+        monkeypatch.setattr(settings, "CHAT_RETENTION_DAYS", 0)
+        lobby_id = lobby_with_chat(client, "Forever")
+
+        assert asyncio.run(prune_chat_messages()) == 0
+        assert chat_lines(lobby_id) == ["last month", "yesterday"]
@@ -0,0 +1,116 @@
+"""Tests for the end-of-game answer reveal."""
+
+import asyncio
+import sys
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.game.puzzles import LadderStep, Puzzle, PuzzleMeta
+from backend.game.reveal import RevealSession, build_reveal_steps
+from backend.reveal import advance_reveal, reveal_store, reveal_tick
+from backend.websocket.managers import lobby_websocket_manager
+
+START = datetime(2026, 10, 16, 20, 0, tzinfo=timezone.utc)
+LADDER = [
+    {"word": "SNOW", "clue": None, "transform": None},
+    {"word": "FLAKE", "clue": "Snow ___", "transform": None},
+    {"word": "CORN", "clue": "Corn ___ (breakfast)", "transform": None},
+    {"word": "COB", "clue": "Corn on the ___", "transform": None},
+    {"word": "WEB", "clue": "Spider's home", "transform": "Change one letter"},
+]
+
+
+def puzzle(title: str) -> Puzzle:
+    return Puzzle(meta=PuzzleMeta(title=title, difficulty="easy"), ladder=[LadderStep(**step) for step in LADDER])
+
+
+@pytest.fixture
+def sent(monkeypatch):
+    events = []
+
+    async def record(lobby_id, event):
+        events.append(event)
+
+    monkeypatch.setattr(lobby_websocket_manager, "broadcast_to_lobby", record)
+    reveal_store.__init__()
+    yield events
+    reveal_store.__init__()
+
+
+class TestBuildRevealSteps:
+    """Tests for laying out reveal steps."""
+
+    def test_puzzles_are_revealed_one_after_another(self):
+        steps = build_reveal_steps([puzzle("Cold Snap"), puzzle("Warm Front")])
+
+        assert len(steps) == 10
+        assert (steps[5].puzzle_title, steps[5].puzzle_number, steps[5].word_index) == ("Warm Front", 2, 0)
+
+    def test_explanation_combines_clue_and_transform(self):
+        steps = build_reveal_steps([puzzle("Cold Snap")])
+
+        assert steps[0].explanation is None
+        assert steps[1].explanation == "Snow ___"
+        assert steps[4].explanation == "Spider's home (Change one letter)"
+
+
+class TestRevealSession:
+    """Tests for stepping through a reveal."""
+
+    def test_host_paced_reveal_is_never_due(self):
+        session = RevealSession(steps=build_reveal_steps([puzzle("Cold Snap")]))
+        session.advance(START)
+
+        assert not session.is_due(START + timedelta(hours=1))
+
+    def test_timed_reveal_is_due_after_its_interval(self):
+        session = RevealSession(steps=build_reveal_steps([puzzle("Cold Snap")]), interval_seconds=5)
+        session.advance(START)
+
+        assert not session.is_due(START + timedelta(seconds=4))
+        assert session.is_due(START + timedelta(seconds=5))
+
+    def test_advancing_past_the_last_step_returns_none(self):
+        session = RevealSession(steps=build_reveal_steps([puzzle("Cold Snap")]))
+        words = [session.advance(START).word for _ in LADDER]
+
+        assert words == ["SNOW", "FLAKE", "CORN", "COB", "WEB"]
+        assert session.finished
+        assert session.advance(START) is None
+
+
+class TestRevealBroadcasts:
+    """Tests for the lobby-wide reveal events."""
+
+    def test_steps_are_numbered_across_puzzles(self, sent):
+        reveal_store.start(1, RevealSession(steps=build_reveal_steps([puzzle("Cold Snap"), puzzle("Warm Front")])))
+
+        for _ in range(6):
+            asyncio.run(advance_reveal(1, START))
+
+        assert [event.step_number for event in sent] == [1, 2, 3, 4, 5, 6]
+        assert (sent[-1].puzzle_number, sent[-1].puzzle_count, sent[-1].total_steps) == (2, 2, 10)
+
+    def test_tick_advances_due_reveals_and_ends_them(self, sent):
+        reveal_store.start(1, RevealSession(steps=build_reveal_steps([puzzle("Cold Snap")]), interval_seconds=5))
+        asyncio.run(advance_reveal(1, START))
+
+        for seconds in range(5, 30, 5):
+            asyncio.run(reveal_tick(START + timedelta(seconds=seconds)))
+
+        assert [event.type for event in sent] == ["reveal_step"] * 5 + ["reveal_ended"]
+        assert sent[-1].completed
+        assert reveal_store.get(1) is None
+
+    def test_tick_leaves_host_paced_reveals_alone(self, sent):
+        reveal_store.start(1, RevealSession(steps=build_reveal_steps([puzzle("Cold Snap")])))
+        asyncio.run(advance_reveal(1, START))
+
+        asyncio.run(reveal_tick(START + timedelta(hours=1)))
+
+        assert len(sent) == 1
@@ -0,0 +1,132 @@
+"""Tests for head-to-head records between recurring team line-ups and GET /api/admin/stats/rivalries."""
+
+import sys
+from datetime import datetime, timezone
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.game.rivalries import TeamRound, head_to_head
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+WEEK_1 = datetime(2026, 10, 1, tzinfo=timezone.utc)
+WEEK_2 = datetime(2026, 10, 8, tzinfo=timezone.utc)
+SPARKS = ("Alice", "Bob")
+OWLS = ("Cara", "Dev")
+
+
+def played(lobby_id, round_number, team_name, members, placement, points, played_at=WEEK_1) -> TeamRound:
+    return TeamRound(lobby_id, round_number, team_name, members, placement, points, played_at)
+
+
+class TestHeadToHead:
+    """Tests for tallying meetings between line-ups."""
+
+    def test_line_ups_are_matched_across_lobbies_and_team_names(self):
+        results = [
+            played(1, 1, "Sparks", SPARKS, 1, 2),
+            played(1, 1, "Owls", OWLS, 2, 1),
+            played(2, 1, "Sparks Again", ("bob", "ALICE"), 2, 1, WEEK_2),
+            played(2, 1, "Night Owls", OWLS, 1, 2, WEEK_2),
+        ]
+
+        [rivalry] = head_to_head(results)
+
+        assert (rivalry.meetings, rivalry.wins_a, rivalry.wins_b, rivalry.ties) == (2, 1, 1, 0)
+        assert rivalry.team_names_a == ["Sparks", "Sparks Again"]
+        assert rivalry.last_met_at == WEEK_2
+
+    def test_margin_is_from_the_first_line_up(self):
+        results = [played(1, round_number, "Sparks", SPARKS, 1, 3) for round_number in (1, 2)]
+        results += [played(1, 1, "Owls", OWLS, 2, 2), played(1, 2, "Owls", OWLS, 2, 1)]
+
+        [rivalry] = head_to_head(results)
+
+        assert rivalry.members_a == ["Alice", "Bob"]
+        assert rivalry.avg_point_margin == 1.5
+
+    def test_equal_placements_are_ties(self):
+        [rivalry] = head_to_head([played(1, 1, "Sparks", SPARKS, 1, 2), played(1, 1, "Owls", OWLS, 1, 2)])
+        assert rivalry.ties == 1
+
+    def test_rare_meetings_and_empty_teams_are_left_out(self):
+        results = [
+            played(1, 1, "Sparks", SPARKS, 1, 2),
+            played(1, 1, "Owls", OWLS, 2, 1),
+            played(1, 1, "Bots", (), 3, 0),
+        ]
+
+        assert head_to_head(results, min_meetings=2) == []
+        assert len(head_to_head(results)) == 1
+
+    def test_most_played_rivalries_come_first(self):
+        results = [played(1, round_number, "Sparks", SPARKS, 1, 2) for round_number in (1, 2)]
+        results += [played(1, round_number, "Owls", OWLS, 2, 1) for round_number in (1, 2)]
+        results.append(played(1, 1, "Foxes", ("Eve",), 3, 0))
+
+        assert [rivalry.meetings for rivalry in head_to_head(results)] == [2, 1, 1]
+
+
+def play_round(client, headers: dict) -> dict:
+    """Alice against Bob for one round, ended by the admin."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=headers).json()
+    lobby = client.post("/api/admin/lobby", json={"name": "Rivalry Night"}, headers=headers).json()
+    for name in ["Alice", "Bob"]:
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": name})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=headers)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=headers,
+    )
+    client.post(f"/api/admin/lobby/{lobby['id']}/game/end", headers=headers)
+    return lobby
+
+
+def organization_headers(client) -> dict:
+    client.post(
+        "/api/admin/organizations",
+        json={"name": "Quiz Club", "slug": "quiz-club", "password": "club-password"},
+        headers=ADMIN_HEADERS,
+    )
+    session = client.post("/api/admin/login", json={"password": "club-password", "organization": "quiz-club"}).json()
+    return {"Authorization": f"Bearer {session['token']}"}
+
+
+def rivalries(client, headers: dict, **params) -> list[dict]:
+    response = client.get("/api/admin/stats/rivalries", params={"min_meetings": 1, **params}, headers=headers)
+    assert response.status_code == 200, response.text
+    return response.json()["rivalries"]
+
+
+@pytest.mark.integration
+class TestRivalriesEndpoint:
+    """Tests for GET /api/admin/stats/rivalries."""
+
+    def test_lists_line_ups_that_have_met(self, client):
+        lobby = play_round(client, ADMIN_HEADERS)
+
+        [rivalry] = rivalries(client, ADMIN_HEADERS, lobby_id=lobby["id"])
+
+        assert sorted(rivalry["members_a"] + rivalry["members_b"]) == ["Alice", "Bob"]
+        assert rivalry["meetings"] == 1
+
+    def test_requires_an_admin(self, client):
+        play_round(client, ADMIN_HEADERS)
+
+        assert client.get("/api/admin/stats/rivalries").status_code == 401
+        assert client.get("/api/stats/rivalries").status_code == 404
+
+    def test_organization_admins_only_see_their_own_lobbies(self, client):
+        deployment_lobby = play_round(client, ADMIN_HEADERS)
+        club = organization_headers(client)
+
+        assert rivalries(client, club) == []
+        response = client.get(f"/api/admin/stats/rivalries?lobby_id={deployment_lobby['id']}", headers=club)
+        assert response.status_code == 404
+
+        play_round(client, club)
+        assert len(rivalries(client, club)) == 1
@@ -0,0 +1,55 @@
+"""Unit tests for the lobby roster CSV export."""
+
+import csv
+import io
+import sys
+from datetime import datetime, timezone
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.utils.roster import ROSTER_COLUMNS, RosterRow, build_roster_csv
+
+JOINED_AT = datetime(2026, 3, 4, 17, 30, tzinfo=timezone.utc)
+
+
+def parse(text: str) -> list[list[str]]:
+    return list(csv.reader(io.StringIO(text)))
+
+
+class TestBuildRosterCsv:
+    """Tests for the attendance CSV hosts download after a session."""
+
+    def test_header_and_rows(self):
+        """Each player becomes a row under the header, in the order given."""
+        rows = [
+            RosterRow(name="Alice", team="Red Pandas", joined_at=JOINED_AT, is_connected=True, guesses_made=12),
+            RosterRow(name="Bob", team=None, joined_at=JOINED_AT, is_connected=False, guesses_made=0),
+        ]
+
+        assert parse(build_roster_csv(rows)) == [
+            ROSTER_COLUMNS,
+            ["Alice", "Red Pandas", "2026-03-04T17:30:00+00:00", "connected", "12"],
+            ["Bob", "", "2026-03-04T17:30:00+00:00", "disconnected", "0"],
+        ]
+
+    def test_empty_roster_has_header_only(self):
+        """A lobby nobody joined still downloads a usable file."""
+        assert parse(build_roster_csv([])) == [ROSTER_COLUMNS]
+
+    def test_names_with_commas_and_quotes_round_trip(self):
+        """Free-text names are quoted rather than splitting into extra columns."""
+        row = RosterRow(name='Smith, "Jo"', team="A, B", joined_at=JOINED_AT, is_connected=True, guesses_made=1)
+
+        parsed = parse(build_roster_csv([row]))
+
+        assert parsed[1][:2] == ['Smith, "Jo"', "A, B"]
+
+    def test_formula_like_names_are_neutralised(self):
+        """Names that a spreadsheet would evaluate are prefixed so they open as plain text."""
+        row = RosterRow(name="=HYPERLINK(1)", team="-Team", joined_at=JOINED_AT, is_connected=True, guesses_made=1)
+
+        parsed = parse(build_roster_csv([row]))
+
+        assert parsed[1][:2] == ["'=HYPERLINK(1)", "'-Team"]
@@ -0,0 +1,63 @@
+"""Integration tests for join-ordered rosters and roster_version."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+
+
+@pytest.fixture
+def lobby(client):
+    return client.post("/api/admin/lobby", json={"name": "Roll Call"}, headers=ADMIN_HEADERS).json()
+
+
+def join(client, lobby: dict, name: str) -> dict:
+    return client.post(f"/api/lobby/{lobby['code']}", json={"name": name}).json()
+
+
+def roster(client, lobby: dict, player: dict, since_version: int | None = None):
+    query = "" if since_version is None else f"?since_version={since_version}"
+    headers = {"Authorization": f"Bearer {player['session_id']}"}
+    return client.get(f"/api/lobby/{lobby['id']}/roster{query}", headers=headers)
+
+
+@pytest.mark.integration
+class TestRosterVersion:
+    """Tests for GET /api/lobby/{lobby_id}/roster and the version bumps behind it."""
+
+    def test_players_are_listed_in_join_order(self, client, lobby):
+        for name in ["Zed", "Amy", "Mo"]:
+            join(client, lobby, name)
+        zoe = join(client, lobby, "Zoe")
+
+        response = roster(client, lobby, zoe)
+
+        assert response.status_code == 200, response.text
+        players = response.json()["players"]
+        assert [player["name"] for player in players] == ["Zed", "Amy", "Mo", "Zoe"]
+        assert [player["join_order"] for player in players] == [1, 2, 3, 4]
+
+    def test_each_roster_change_bumps_the_version(self, client, lobby):
+        alice = join(client, lobby, "Alice")
+        after_join = roster(client, lobby, alice).json()["roster_version"]
+        bob = join(client, lobby, "Bob")
+        client.delete("/api/lobby", headers={"Authorization": f"Bearer {bob['session_id']}"})
+
+        assert roster(client, lobby, alice).json()["roster_version"] == after_join + 2
+
+    def test_unchanged_roster_is_not_sent_again(self, client, lobby):
+        alice = join(client, lobby, "Alice")
+        version = roster(client, lobby, alice).json()["roster_version"]
+
+        unchanged = roster(client, lobby, alice, since_version=version).json()
+        join(client, lobby, "Bob")
+        changed = roster(client, lobby, alice, since_version=version).json()
+
+        assert unchanged == {"roster_version": version, "changed": False, "players": []}
+        assert changed["changed"] is True
+        assert [player["name"] for player in changed["players"]] == ["Alice", "Bob"]
@@ -0,0 +1,64 @@
+"""Unit tests for lobby schedule validation and timezone-aware display."""
+
+import sys
+from datetime import datetime, timezone
+from pathlib import Path
+
+import pytest
+from pydantic import ValidationError
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.database.models import Lobby
+from backend.schemas import LobbyScheduleUpdate
+from backend.utils.scheduling import build_lobby_schedule
+
+
+class TestLobbyScheduleUpdate:
+    """Tests for schedule request validation."""
+
+    def test_naive_times_read_in_lobby_timezone(self):
+        """An admin typing 7pm in New York means 23:00 UTC in summer."""
+        update = LobbyScheduleUpdate(timezone="America/New_York", starts_at=datetime(2025, 7, 4, 19, 0))
+
+        assert update.starts_at == datetime(2025, 7, 4, 23, 0, tzinfo=timezone.utc)
+
+    def test_opens_must_precede_closes(self):
+        """A window that closes before it opens is rejected."""
+        with pytest.raises(ValidationError, match="opens_at must be before closes_at"):
+            LobbyScheduleUpdate(opens_at=datetime(2025, 1, 2, 12, 0), closes_at=datetime(2025, 1, 2, 11, 0))
+
+    def test_unknown_timezone_rejected(self):
+        """Typos in timezone names fail validation."""
+        with pytest.raises(ValidationError, match="Unknown timezone"):
+            LobbyScheduleUpdate(timezone="Mars/Olympus_Mons")
+
+
+class TestBuildLobbySchedule:
+    """Tests for schedule display data."""
+
+    def test_formats_in_lobby_timezone(self):
+        """Stored UTC times are shown on the lobby's clock with its abbreviation."""
+        lobby = Lobby(
+            code="ABC123",
+            name="Game Night",
+            timezone="Europe/London",
+            starts_at=datetime(2025, 10, 17, 18, 30),  # naive, as SQLite returns it
+        )
+
+        schedule = build_lobby_schedule(lobby)
+
+        assert schedule.starts_at.utc == "2025-10-17T18:30:00+00:00"
+        assert schedule.starts_at.local == "2025-10-17T19:30:00+01:00"
+        assert schedule.starts_at.display == "Fri, Oct 17, 7:30 PM BST"
+        assert schedule.opens_at is None
+
+    def test_unknown_stored_timezone_falls_back_to_utc(self):
+        """A lobby with a timezone the server no longer knows still renders."""
+        lobby = Lobby(code="ABC123", name="Game Night", timezone="Gone/Zone", opens_at=datetime(2025, 1, 1, 9, 5))
+
+        schedule = build_lobby_schedule(lobby)
+
+        assert schedule.timezone == "UTC"
+        assert schedule.opens_at.display == "Wed, Jan 1, 9:05 AM UTC"
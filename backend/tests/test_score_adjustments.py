@@ -0,0 +1,56 @@
+"""Integration tests for manual score adjustments and their audit trail."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+
+
+def team_ids(client, lobby: dict) -> list[int]:
+    info = client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()
+    return [team["id"] for team in info["teams"]]
+
+
+def adjust(client, team_id: int, delta: int, reason: str = "Won the trivia side quest"):
+    return client.post(
+        f"/api/admin/lobby/team/{team_id}/score-adjust", json={"delta": delta, "reason": reason}, headers=ADMIN_HEADERS
+    )
+
+
+@pytest.mark.integration
+class TestScoreAdjustments:
+    """Tests for POST /api/admin/lobby/team/{team_id}/score-adjust."""
+
+    def test_adjustment_shows_in_the_leaderboard(self, client, lobby):
+        first, second = team_ids(client, lobby)
+
+        response = adjust(client, second, 3)
+        adjust(client, first, -1, reason="Peeked at the answer key")
+
+        assert response.status_code == 200, response.text
+        assert response.json()["total_points"] == 3
+        leaderboard = client.get(f"/api/lobby/{lobby['id']}/leaderboard").json()
+        assert [(team["team_id"], team["total_points"]) for team in leaderboard["teams"]] == [(second, 3), (first, -1)]
+
+    def test_adjustments_are_kept_with_their_reason(self, client, lobby):
+        first, _ = team_ids(client, lobby)
+        adjust(client, first, 2, reason="  Best costume  ")
+
+        trail = client.get(f"/api/admin/lobby/{lobby['id']}/score-adjustments", headers=ADMIN_HEADERS).json()
+
+        assert [(entry["team_id"], entry["delta"], entry["reason"]) for entry in trail] == [(first, 2, "Best costume")]
+
+    def test_zero_delta_and_missing_reason_are_refused(self, client, lobby):
+        first, _ = team_ids(client, lobby)
+
+        assert adjust(client, first, 0).status_code == 400
+        assert adjust(client, first, 2, reason="   ").status_code == 400
+        assert client.get(f"/api/admin/lobby/{lobby['id']}/score-adjustments", headers=ADMIN_HEADERS).json() == []
+
+    def test_unknown_team(self, client):
+        assert adjust(client, 9999, 1).status_code == 404
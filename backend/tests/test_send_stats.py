@@ -0,0 +1,68 @@
+"""Unit tests for per-connection send latency tracking."""
+
+import asyncio
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.websocket.send_stats import MIN_SAMPLES_FOR_SLOW, SendStats, SendStatsRegistry
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+
+    async def send_text(self, text):
+        self.sent.append(text)
+
+
+class TestSendStats:
+    """Tests for send latency aggregation."""
+
+    def test_averages_and_max(self):
+        """Totals and max are tracked across every send."""
+        stats = SendStats()
+        stats.record(0.010)
+        stats.record(0.030)
+
+        assert stats.messages_sent == 2
+        assert round(stats.avg_ms, 3) == 20.0
+        assert round(stats.max_seconds * 1000, 3) == 30.0
+
+    def test_p95_ignores_single_outlier_in_large_window(self):
+        """One slow send in a hundred doesn't move the p95."""
+        stats = SendStats()
+        for _ in range(99):
+            stats.record(0.001)
+        stats.record(1.0)
+
+        assert round(stats.p95_ms, 3) == 1.0
+
+    def test_slow_flag_needs_enough_samples(self):
+        """A client isn't flagged slow until there are enough sends to judge."""
+        stats = SendStats()
+        for _ in range(MIN_SAMPLES_FOR_SLOW - 1):
+            stats.record(0.5)
+        assert not stats.is_slow(200)
+
+        stats.record(0.5)
+        assert stats.is_slow(200)
+
+
+class TestSendStatsRegistry:
+    """Tests for recording sends by connection."""
+
+    def test_send_records_per_connection(self):
+        """Sends are forwarded and timed under the connection key."""
+        registry = SendStatsRegistry()
+        websocket = FakeWebSocket()
+
+        asyncio.run(registry.send_text("player:abc", websocket, "hello"))
+
+        assert websocket.sent == ["hello"]
+        assert registry.get("player:abc").messages_sent == 1
+
+        registry.remove("player:abc")
+        assert registry.get("player:abc") is None
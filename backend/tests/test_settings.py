@@ -0,0 +1,82 @@
+"""Unit tests for validating the configuration at startup."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.settings import SettingsError, load_or_create_secret, load_settings
+
+VALID = {"ADMIN_PASSWORD": "secret", "DATABASE_URL": "sqlite:///./databases/main.db", "_env_file": None}
+
+
+def problems(**overrides) -> list[str]:
+    with pytest.raises(SettingsError) as exc_info:
+        load_settings(**{**VALID, **overrides})
+    return exc_info.value.problems
+
+
+class TestLoadSettings:
+    """Tests for reporting every configuration problem at once."""
+
+    def test_valid_settings_load(self):
+        assert load_settings(**VALID).ADMIN_PASSWORD == "secret"
+
+    def test_missing_variables_are_reported_together(self, monkeypatch):
+        monkeypatch.delenv("ADMIN_PASSWORD", raising=False)
+        monkeypatch.delenv("DATABASE_URL", raising=False)
+
+        with pytest.raises(SettingsError) as exc_info:
+            load_settings(_env_file=None)
+
+        assert exc_info.value.problems == ["ADMIN_PASSWORD: required but not set", "DATABASE_URL: required but not set"]
+
+    def test_badly_typed_values_are_reported_together(self):
+        reported = problems(WS_PING_INTERVAL_SECONDS="soon", QUICK_PLAY_ENABLED="maybe")
+
+        assert [problem.split(":")[0] for problem in reported] == ["WS_PING_INTERVAL_SECONDS", "QUICK_PLAY_ENABLED"]
+
+    def test_out_of_range_and_conflicting_values_are_reported_together(self):
+        reported = problems(
+            DATABASE_URL="main.db",
+            QUICK_PLAY_TEAM_SIZE=0,
+            WS_IDLE_TIMEOUT_SECONDS=10,
+            ADMIN_OWNER_PASSWORD="secret",
+            TEAM_COLOR_PALETTE=["red"],
+        )
+
+        assert sorted(problem.split(":")[0] for problem in reported) == [
+            "ADMIN_OWNER_PASSWORD",
+            "DATABASE_URL",
+            "QUICK_PLAY_TEAM_SIZE",
+            "TEAM_COLOR_PALETTE",
+            "WS_IDLE_TIMEOUT_SECONDS",
+        ]
+
+    def test_report_lists_each_problem(self):
+        message = str(SettingsError(["ADMIN_PASSWORD: required but not set", "DATABASE_URL: required but not set"]))
+
+        assert "2 problem(s)" in message
+        assert "\n  - DATABASE_URL: required but not set" in message
+
+
+class TestShareSecret:
+    """Tests for the share secret generated when SHARE_SECRET isn't set."""
+
+    def test_generated_once_and_kept(self, tmp_path):
+        path = tmp_path / "secrets" / "share_secret"
+
+        secret = load_or_create_secret(path)
+
+        assert len(secret) >= 32
+        assert load_or_create_secret(path) == secret
+        assert path.stat().st_mode & 0o077 == 0
+
+    def test_used_when_no_secret_is_configured(self, tmp_path):
+        config = load_settings(**VALID, SHARE_SECRET_FILE=str(tmp_path / "share_secret"))
+
+        assert config.share_secret not in ("", VALID["ADMIN_PASSWORD"])
+        assert config.share_secret == (tmp_path / "share_secret").read_text()
@@ -0,0 +1,101 @@
+"""Tests for draining connections on graceful shutdown."""
+
+import asyncio
+import json
+import sys
+from pathlib import Path
+
+import pytest
+from starlette.websockets import WebSocketDisconnect
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.in_memory_state import reset_in_memory_state
+from backend.shutdown import SERVICE_RESTART, notify_clients_of_shutdown, shutdown_state
+from backend.websocket.managers import admin_web_socket_manager, lobby_websocket_manager
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+        self.closed_with = None
+
+    async def send_text(self, text):
+        self.sent.append(json.loads(text))
+
+    async def close(self, code=1000, reason=None):
+        self.closed_with = code
+
+
+@pytest.fixture
+def sockets():
+    reset_in_memory_state()
+    player, admin = FakeWebSocket(), FakeWebSocket()
+    lobby_websocket_manager.lobby_websockets[1] = {"alice": player}
+    admin_web_socket_manager.admin_websockets["admin-1"] = {
+        "websocket": admin,
+        "subscribed_lobbies": {1},
+        "editing_teams": {},
+    }
+    admin_web_socket_manager.lobby_subscribers[1] = {"admin-1"}
+    yield player, admin
+    reset_in_memory_state()
+
+
+@pytest.fixture
+def client(client):
+    yield client
+    shutdown_state.__init__()
+
+
+class TestNotifyClientsOfShutdown:
+    """Tests for telling every socket the server is going away."""
+
+    def test_players_and_admins_are_told_then_closed(self, sockets):
+        asyncio.run(notify_clients_of_shutdown())
+
+        for websocket in sockets:
+            assert [event["type"] for event in websocket.sent] == ["server_shutdown"]
+            assert websocket.closed_with == SERVICE_RESTART
+        assert lobby_websocket_manager.lobby_websockets == {}
+        assert admin_web_socket_manager.admin_websockets == {}
+
+    def test_shutdown_event_is_not_kept_for_replay(self, sockets):
+        asyncio.run(notify_clients_of_shutdown())
+        assert lobby_websocket_manager.replay.event_count() == 0
+
+    def test_only_runs_once(self, sockets):
+        assert asyncio.run(notify_clients_of_shutdown())
+        assert not asyncio.run(notify_clients_of_shutdown())
+
+
+@pytest.mark.integration
+class TestDraining:
+    """Tests for requests arriving after shutdown has started."""
+
+    def test_api_requests_are_refused(self, client):
+        asyncio.run(notify_clients_of_shutdown())
+
+        response = client.get("/api/lobby/ABCDEF")
+
+        assert response.status_code == 503
+        assert response.json()["detail"]["code"] == "SHUTTING_DOWN"
+
+    def test_readiness_fails_but_liveness_holds(self, client):
+        asyncio.run(notify_clients_of_shutdown())
+
+        ready = client.get("/api/ready")
+
+        assert ready.status_code == 503
+        assert ready.json()["draining"] is True
+        assert client.get("/api/health").status_code == 200
+
+    def test_new_websockets_are_turned_away(self, client):
+        asyncio.run(notify_clients_of_shutdown())
+
+        with pytest.raises(WebSocketDisconnect) as closed:
+            with client.websocket_connect("/ws/lobby/1/player?token=abc") as websocket:
+                websocket.receive_text()
+
+        assert closed.value.code == SERVICE_RESTART
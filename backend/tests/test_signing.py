@@ -0,0 +1,61 @@
+"""Unit tests for signed share tokens."""
+
+import pytest
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.utils.signing import InvalidTokenError, sign_token, verify_token
+
+SECRET = "test-secret"
+
+
+class TestSignedTokens:
+    """Tests for token signing and verification."""
+
+    def test_round_trip(self):
+        """A freshly signed token verifies and returns its payload."""
+        token = sign_token({"scope": "results", "lobby_id": 7}, SECRET, 60)
+        payload = verify_token(token, SECRET)
+
+        assert payload["scope"] == "results"
+        assert payload["lobby_id"] == 7
+
+    def test_expired_token_rejected(self):
+        """Tokens past their expiry are rejected."""
+        token = sign_token({"lobby_id": 1}, SECRET, 60, now=1000)
+
+        with pytest.raises(InvalidTokenError):
+            verify_token(token, SECRET, now=1061)
+
+    def test_wrong_secret_rejected(self):
+        """Tokens signed with another secret are rejected."""
+        token = sign_token({"lobby_id": 1}, SECRET, 60)
+
+        with pytest.raises(InvalidTokenError):
+            verify_token(token, "other-secret")
+
+    def test_tampered_payload_rejected(self):
+        """Changing the payload invalidates the signature."""
+        token = sign_token({"lobby_id": 1}, SECRET, 60)
+        forged_body = sign_token({"lobby_id": 2}, SECRET, 60).split(".")[0]
+        tampered = f"{forged_body}.{token.split('.')[1]}"
+
+        with pytest.raises(InvalidTokenError):
+            verify_token(tampered, SECRET)
+
+    def test_malformed_token_rejected(self):
+        """Garbage input is rejected rather than raising unexpected errors."""
+        with pytest.raises(InvalidTokenError):
+            verify_token("not-a-token", SECRET)
+
+    @pytest.mark.parametrize("part", ["body", "signature"])
+    def test_non_ascii_token_rejected(self, part):
+        """Non-ASCII characters are rejected as malformed rather than failing to encode."""
+        body, signature = sign_token({"lobby_id": 1}, SECRET, 60).split(".")
+        token = f"{body}é.{signature}" if part == "body" else f"{body}.{signature}é"
+
+        with pytest.raises(InvalidTokenError):
+            verify_token(token, SECRET)
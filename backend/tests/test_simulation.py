@@ -0,0 +1,96 @@
+"""Tests for dry-run game simulation."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.game.puzzles import LadderStep, Puzzle, PuzzleMeta
+from backend.game.rng import seeded_rng
+from backend.game.simulation import SimulationParams, rung_difficulty_from_curve, simulate_game, simulate_team
+from backend.tests.helpers import ADMIN_HEADERS
+
+LADDER = [
+    {"word": "SNOW", "clue": None, "transform": None},
+    {"word": "FLAKE", "clue": "Snow ___", "transform": None},
+    {"word": "CORN", "clue": "Corn ___ (breakfast)", "transform": None},
+    {"word": "COB", "clue": "Corn on the ___", "transform": None},
+    {"word": "WEB", "clue": "Change one letter, spider's home", "transform": None},
+]
+PUZZLE = Puzzle(meta=PuzzleMeta(title="Cold Snap", difficulty="easy"), ladder=[LadderStep(**step) for step in LADDER])
+
+
+def params(**overrides) -> SimulationParams:
+    values = {
+        "num_teams": 4,
+        "runs": 20,
+        "accuracy": 0.5,
+        "accuracy_spread": 0.0,
+        "guess_seconds": 4.0,
+        "guess_seconds_spread": 0.0,
+        "time_limit_seconds": 3600.0,
+    }
+    return SimulationParams(**{**values, **overrides})
+
+
+class TestSimulation:
+    """Tests for virtual teams playing a puzzle."""
+
+    def test_perfect_team_solves_one_rung_per_guess(self):
+        play = simulate_team(PUZZLE, 1.0, params(), seeded_rng(1, "simulation"))
+
+        assert play.finish_seconds == 12.0
+        assert play.solve_seconds == {1: 4.0, 2: 4.0, 3: 4.0}
+        assert play.wrong_guesses == {}
+
+    def test_team_out_of_time_is_stuck_on_its_rung(self):
+        play = simulate_team(PUZZLE, 1.0, params(time_limit_seconds=6.0), seeded_rng(1, "simulation"))
+
+        assert play.finish_seconds is None
+        assert play.stuck_on == 2
+
+    def test_same_seed_gives_the_same_projection(self):
+        first = simulate_game(PUZZLE, params(), seeded_rng(7, "simulation"))
+        second = simulate_game(PUZZLE, params(), seeded_rng(7, "simulation"))
+
+        assert first == second
+
+    def test_hard_rung_is_a_stall_point(self):
+        result = simulate_game(PUZZLE, params(accuracy=0.9, rung_difficulty={2: 9.0}), seeded_rng(1, "simulation"))
+
+        assert [rung.word_index for rung in result.rungs if rung.stall_point] == [2]
+
+    def test_unsolved_rungs_count_as_the_hardest(self):
+        assert rung_difficulty_from_curve([0.5, 0.0, 2.0]) == {1: 0.5, 2: 2.0, 3: 2.0}
+
+
+@pytest.fixture
+def puzzle_id(client):
+    puzzle = {"title": "Cold Snap", "difficulty": "easy", "ladder": LADDER}
+    return client.post("/api/admin/puzzle", json=puzzle, headers=ADMIN_HEADERS).json()["id"]
+
+
+@pytest.mark.integration
+class TestSimulateEndpoint:
+    """Tests for POST /api/admin/puzzle/{puzzle_id}/simulate."""
+
+    def test_simulates_a_stored_puzzle(self, client, puzzle_id):
+        request = {"num_teams": 3, "runs": 5, "seed": 4}
+        response = client.post(f"/api/admin/puzzle/{puzzle_id}/simulate", json=request, headers=ADMIN_HEADERS)
+        assert response.status_code == 200
+        body = response.json()
+        assert body["seed"] == 4
+        assert body["history_plays"] == 0
+        assert [rung["word"] for rung in body["rungs"]] == ["FLAKE", "CORN", "COB"]
+        assert body["projected_duration_seconds"] > 0
+
+    def test_rejects_too_much_work(self, client, puzzle_id):
+        request = {"num_teams": 100, "runs": 1000}
+        response = client.post(f"/api/admin/puzzle/{puzzle_id}/simulate", json=request, headers=ADMIN_HEADERS)
+        assert response.status_code == 400
+
+    def test_missing_puzzle(self, client):
+        assert client.post("/api/admin/puzzle/999/simulate", json={}, headers=ADMIN_HEADERS).status_code == 404
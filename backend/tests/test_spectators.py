@@ -0,0 +1,106 @@
+"""Tests for read-only lobby spectators."""
+
+import asyncio
+import json
+import sys
+from pathlib import Path
+
+import pytest
+from starlette.websockets import WebSocketDisconnect
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+from backend.websocket.events import TeamChatEvent
+from backend.websocket.managers import AdminWebSocketManager, LobbyWebSocketManager
+
+LOBBY_ID = 1
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+        self.closed_with = None
+
+    async def send_text(self, text):
+        self.sent.append(json.loads(text)["type"])
+
+    async def close(self, code=1000, reason=None):
+        self.closed_with = code
+
+
+@pytest.fixture
+def lobby(client):
+    return client.post("/api/admin/lobby", json={"name": "Watch Party"}, headers=ADMIN_HEADERS).json()
+
+
+def watched_lobby() -> tuple[LobbyWebSocketManager, FakeWebSocket, FakeWebSocket]:
+    """A lobby with one player on team 10 and one spectator; returns (manager, player, spectator)."""
+    manager = LobbyWebSocketManager(admin_web_socket_manager=AdminWebSocketManager())
+    player, spectator = FakeWebSocket(), FakeWebSocket()
+    manager.lobby_websockets[LOBBY_ID] = {"alice": player}
+    manager.spectators[LOBBY_ID] = {"watcher": spectator}
+    manager.register_player_team("alice", 10)
+    return manager, player, spectator
+
+
+class TestSpectatorBroadcasts:
+    """Tests for what reaches a spectator's socket."""
+
+    def test_spectators_get_lobby_broadcasts_only(self):
+        manager, player, spectator = watched_lobby()
+        chat = TeamChatEvent(
+            team_id=10, player_id=1, player_name="Alice", text="try the bottom", sent_at="2026-01-01T00:00:00+00:00"
+        )
+
+        async def scenario():
+            await manager.broadcast_to_lobby(LOBBY_ID, {"type": "timer_expired"})
+            await manager.broadcast_to_team(LOBBY_ID, 10, chat)
+            await manager.send_to_player(LOBBY_ID, "alice", {"type": "pong"}, replayable=False)
+            await manager.flush()
+
+        asyncio.run(scenario())
+
+        assert player.sent == ["timer_expired", "team_chat", "pong"]
+        assert spectator.sent == ["timer_expired"]
+
+    def test_deleting_the_lobby_tells_spectators_then_closes(self):
+        manager, _, spectator = watched_lobby()
+
+        async def scenario():
+            await manager.broadcast_to_lobby(LOBBY_ID, {"type": "lobby_deleted"})
+            await manager.close_spectators(LOBBY_ID, "Lobby deleted")
+            await manager.flush()
+
+        asyncio.run(scenario())
+
+        assert spectator.sent == ["lobby_deleted"]
+        assert spectator.closed_with == 1000
+        assert manager.spectators == {}
+
+
+@pytest.mark.integration
+class TestSpectateEndpoint:
+    """Tests for POST /api/lobby/spectate/{lobby_code} and the spectator websocket."""
+
+    def test_public_lobby_issues_a_session(self, client, lobby):
+        response = client.post(f"/api/lobby/spectate/{lobby['code']}")
+
+        assert response.status_code == 200, response.text
+        assert response.json()["lobby_id"] == lobby["id"]
+
+    def test_private_and_unknown_lobbies_are_refused(self, client, lobby):
+        client.patch(f"/api/admin/lobby/{lobby['id']}", json={"is_private": True}, headers=ADMIN_HEADERS)
+
+        assert client.post(f"/api/lobby/spectate/{lobby['code']}").status_code == 403
+        assert client.post("/api/lobby/spectate/ZZZZZZ").status_code == 404
+
+    def test_session_only_opens_its_own_lobby(self, client, lobby):
+        session = client.post(f"/api/lobby/spectate/{lobby['code']}").json()["spectator_session_id"]
+
+        with pytest.raises(WebSocketDisconnect) as closed:
+            with client.websocket_connect(f"/ws/lobby/{lobby['id'] + 1}/spectator/{session}") as websocket:
+                websocket.receive_text()
+
+        assert closed.value.code == 1008
@@ -0,0 +1,112 @@
+"""Unit tests for live standings and round scoring."""
+
+from datetime import datetime, timedelta, timezone
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.game.standings import TeamProgress, calculate_points, compute_standings, visible_progress
+
+START = datetime(2026, 1, 1, tzinfo=timezone.utc)
+
+
+def at(seconds):
+    return START + timedelta(seconds=seconds)
+
+
+class TestCalculatePoints:
+    """Tests for round point calculation."""
+
+    def test_finishers_get_reverse_placement(self):
+        """First place gets one point per team."""
+        assert calculate_points(1, 4, 1.0, True, 3) == 4
+        assert calculate_points(2, 4, 1.0, True, 3) == 3
+
+    def test_dnf_capped_at_three_quarters(self):
+        """Unfinished teams never earn more than 75% of the worst finisher."""
+        assert calculate_points(3, 4, 0.9, False, 4) == 3
+
+    def test_dnf_minimum_one_point(self):
+        """Unfinished teams always earn at least one point."""
+        assert calculate_points(4, 4, 0.0, False, 4) == 1
+
+
+class TestComputeStandings:
+    """Tests for live lobby standings."""
+
+    def test_finished_teams_rank_first_by_completion_time(self):
+        """Finishers are ordered by when they completed, ahead of everyone else."""
+        standings = compute_standings(
+            [
+                TeamProgress(team_id=1, revealed_count=9, ladder_length=10, last_updated_at=at(5)),
+                TeamProgress(team_id=2, revealed_count=10, ladder_length=10, completed_at=at(20)),
+                TeamProgress(team_id=3, revealed_count=10, ladder_length=10, completed_at=at(10)),
+            ]
+        )
+
+        assert [standings[team_id].rank for team_id in (3, 2, 1)] == [1, 2, 3]
+        assert standings[3].projected_points == 3
+        assert standings[1].completion_percentage == 0.9
+
+    def test_progress_ties_go_to_earlier_team(self):
+        """Equal progress ranks the team that got there first higher."""
+        standings = compute_standings(
+            [
+                TeamProgress(team_id=1, revealed_count=4, ladder_length=10, last_updated_at=at(30)),
+                TeamProgress(team_id=2, revealed_count=4, ladder_length=10, last_updated_at=at(10)),
+                TeamProgress(team_id=3, revealed_count=2, ladder_length=10),
+            ]
+        )
+
+        assert standings[2].rank == 1
+        assert standings[1].rank == 2
+        assert standings[3].rank == 3
+
+    def test_solve_moves_team_up(self):
+        """A solve that passes another team improves rank and projected points."""
+        before = compute_standings(
+            [
+                TeamProgress(team_id=1, revealed_count=4, ladder_length=10, last_updated_at=at(10)),
+                TeamProgress(team_id=2, revealed_count=3, ladder_length=10, last_updated_at=at(5)),
+            ]
+        )
+        after = compute_standings(
+            [
+                TeamProgress(team_id=1, revealed_count=4, ladder_length=10, last_updated_at=at(10)),
+                TeamProgress(team_id=2, revealed_count=5, ladder_length=10, last_updated_at=at(20)),
+            ]
+        )
+
+        assert before[2].rank - after[2].rank == 1
+        assert after[2].projected_points >= before[2].projected_points
+
+
+class TestVisibleProgress:
+    """Tests for masking other teams' progress."""
+
+    def test_none_hides_everything(self):
+        """With visibility off nothing is shared."""
+        assert visible_progress("none", 5, 10, False) is None
+
+    def test_exact_reports_hidden_rungs_solved(self):
+        """Exact visibility counts solved rungs, excluding the two given words."""
+        visible = visible_progress("exact", 5, 10, False)
+
+        assert visible.solved_count == 3
+        assert visible.total_count == 8
+        assert visible.progress == 3 / 8
+
+    def test_coarse_snaps_to_quarters_without_counts(self):
+        """Coarse visibility only reveals which quarter a team is in."""
+        visible = visible_progress("coarse", 5, 10, False)
+
+        assert visible.progress == 0.25
+        assert visible.solved_count is None
+        assert visible.total_count is None
+
+    def test_coarse_only_full_when_completed(self):
+        """One rung from the end still reads as three quarters."""
+        assert visible_progress("coarse", 9, 10, False).progress == 0.75
+        assert visible_progress("coarse", 10, 10, True).progress == 1.0
@@ -111,6 +111,17 @@ class TestGuessSubmission:
         result_mixed = state_machine.submit_guess("ShArE", 2)
         assert result_mixed.is_correct
 
+    def test_alternate_answer_reveals_the_puzzle_word(self, sample_puzzle):
+        """An alternate solves the rung, and the ladder shows the puzzle's own word."""
+        sample_puzzle.ladder[3].alternates = ["Coast"]
+        machine = TeamStateMachine(sample_puzzle)
+
+        result = machine.submit_guess("COAST", 3)
+
+        assert result.is_correct
+        assert result.expected_word == "SHORE"
+        assert 3 in machine.state.revealed_steps
+
     def test_out_of_bounds_guess_index(self, state_machine):
         """Guessing an out-of-bounds index should fail gracefully."""
         result_negative = state_machine.submit_guess("ANYTHING", -1)
@@ -258,3 +269,67 @@ class TestGuessResult:
         assert not result.is_correct
         assert result.already_solved
         assert result.word_index == 1
+
+
+class TestOpenEndedRungs:
+    """Tests for rungs whose answers the host approves."""
+
+    def test_open_ended_guess_goes_to_review(self, sample_puzzle):
+        """Guesses on open-ended rungs are neither right nor wrong until reviewed."""
+        sample_puzzle.ladder[2].open_ended = True
+        machine = TeamStateMachine(sample_puzzle)
+
+        result = machine.submit_guess("ANYTHING", 2)
+
+        assert result.pending_review
+        assert not result.is_correct
+        assert 2 not in machine.state.revealed_steps
+
+    def test_approved_answer_reveals_rung(self, sample_puzzle):
+        """Approving an answer reveals the rung with the team's answer."""
+        sample_puzzle.ladder[2].open_ended = True
+        machine = TeamStateMachine(sample_puzzle)
+
+        result = machine.approve_answer("sunrise", 2)
+
+        assert result.is_correct
+        assert result.expected_word == "SUNRISE"
+        assert 2 in result.new_state.revealed_steps
+
+    def test_approving_solved_rung_reports_already_solved(self, state_machine):
+        """A second approval for the same rung doesn't count twice."""
+        state_machine.approve_answer("FIRST", 3)
+
+        result = state_machine.approve_answer("SECOND", 3)
+
+        assert result.already_solved
+
+
+class TestRollback:
+    """Tests for hiding a solved rung again."""
+
+    def test_rollback_hides_solved_word(self, state_machine):
+        """A rolled back word is no longer revealed and can be guessed again."""
+        state_machine.submit_guess("STARE", 1)
+
+        new_state = state_machine.rollback(1)
+
+        assert 1 not in new_state.revealed_steps
+        assert state_machine.submit_guess("STARE", 1).is_correct
+
+    def test_rollback_reopens_completed_puzzle(self, state_machine, sample_puzzle):
+        """Rolling back any rung of a finished puzzle marks it unfinished."""
+        for i in range(1, len(sample_puzzle.ladder) - 1):
+            state_machine.submit_guess(sample_puzzle.ladder[i].word, i)
+        assert state_machine.is_completed()
+
+        new_state = state_machine.rollback(3)
+
+        assert not new_state.is_completed
+
+    def test_rollback_ignores_unsolved_and_given_words(self, state_machine):
+        """Only solved rungs can be rolled back; the first and last words are given, not solved."""
+        assert state_machine.rollback(2) is None
+        assert state_machine.rollback(0) is None
+        assert state_machine.rollback(7) is None
+        assert state_machine.get_current_state().revealed_steps == {0, 7}
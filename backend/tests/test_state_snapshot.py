@@ -0,0 +1,121 @@
+"""Unit tests for server state export and import."""
+
+import sqlite3
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.state_snapshot import SnapshotError, export_state, import_state, sqlite_path_from_url
+
+
+def make_database(path: Path, lobby_names: list[str]):
+    connection = sqlite3.connect(path)
+    connection.execute("CREATE TABLE lobby (id INTEGER PRIMARY KEY, name TEXT)")
+    connection.executemany("INSERT INTO lobby (name) VALUES (?)", [(name,) for name in lobby_names])
+    connection.commit()
+    connection.close()
+
+
+def make_puzzles(puzzles_dir: Path):
+    (puzzles_dir / "2025" / "01").mkdir(parents=True)
+    (puzzles_dir / "2025" / "01" / "01.json").write_text('{"meta": {"title": "New Year"}}')
+
+
+class TestSqlitePath:
+    """Tests for resolving the database file."""
+
+    def test_resolves_sqlite_url(self):
+        """The path after sqlite:/// is the database file."""
+        assert sqlite_path_from_url("sqlite:///./databases/main.db") == Path("./databases/main.db")
+
+    def test_rejects_other_databases(self):
+        """Only SQLite files can be snapshotted."""
+        with pytest.raises(SnapshotError):
+            sqlite_path_from_url("postgresql://localhost/raddle")
+
+
+class TestSnapshotRoundTrip:
+    """Tests for exporting on one machine and importing on another."""
+
+    def test_round_trip_restores_database_and_puzzles(self, tmp_path):
+        """Everything exported comes back on import."""
+        source_db = tmp_path / "source.db"
+        source_puzzles = tmp_path / "source_puzzles"
+        make_database(source_db, ["Quiz Night", "Finals"])
+        make_puzzles(source_puzzles)
+        archive = tmp_path / "state.tar.gz"
+
+        manifest = export_state(archive, source_db, source_puzzles)
+        assert manifest["tables"] == {"lobby": 2}
+        assert manifest["puzzle_files"] == 1
+
+        target_db = tmp_path / "venue" / "main.db"
+        target_puzzles = tmp_path / "venue_puzzles"
+        import_state(archive, target_db, target_puzzles)
+
+        connection = sqlite3.connect(target_db)
+        names = [row[0] for row in connection.execute("SELECT name FROM lobby ORDER BY id")]
+        connection.close()
+        assert names == ["Quiz Night", "Finals"]
+        assert (target_puzzles / "2025" / "01" / "01.json").exists()
+
+    def test_import_refuses_to_overwrite_without_force(self, tmp_path):
+        """An existing database is only replaced with force."""
+        source_db = tmp_path / "source.db"
+        make_database(source_db, ["Quiz Night"])
+        archive = tmp_path / "state.tar.gz"
+        export_state(archive, source_db, tmp_path / "missing_puzzles")
+
+        target_db = tmp_path / "target.db"
+        make_database(target_db, ["Existing"])
+
+        with pytest.raises(SnapshotError):
+            import_state(archive, target_db, tmp_path / "puzzles")
+
+        import_state(archive, target_db, tmp_path / "puzzles", force=True)
+        connection = sqlite3.connect(target_db)
+        names = [row[0] for row in connection.execute("SELECT name FROM lobby")]
+        connection.close()
+        assert names == ["Quiz Night"]
+
+    def test_env_only_included_when_requested(self, tmp_path):
+        """The .env file travels only when asked for on both ends."""
+        source_db = tmp_path / "source.db"
+        make_database(source_db, [])
+        env_file = tmp_path / ".env"
+        env_file.write_text("ADMIN_PASSWORD=secret\n")
+        archive = tmp_path / "state.tar.gz"
+
+        manifest = export_state(archive, source_db, tmp_path / "puzzles", env_file)
+        assert manifest["includes_env"]
+
+        restored_env = tmp_path / "venue.env"
+        import_state(archive, tmp_path / "venue.db", tmp_path / "puzzles", restored_env)
+        assert restored_env.read_text() == "ADMIN_PASSWORD=secret\n"
+
+    def test_forced_import_wins_over_the_old_databases_journal(self, tmp_path):
+        """Changes the old database still had in its WAL journal don't resurface over the imported one."""
+        source_db = tmp_path / "source.db"
+        make_database(source_db, ["Quiz Night"])
+        archive = tmp_path / "state.tar.gz"
+        export_state(archive, source_db, tmp_path / "missing_puzzles")
+
+        target_db = tmp_path / "target.db"
+        make_database(target_db, [])
+        server = sqlite3.connect(target_db)
+        server.execute("PRAGMA journal_mode=WAL")
+        server.execute("INSERT INTO lobby (name) VALUES ('Existing')")
+        server.commit()
+
+        import_state(archive, target_db, tmp_path / "puzzles", force=True)
+        names = [row[0] for row in server.execute("SELECT name FROM lobby")]
+        server.close()
+
+        assert names == ["Quiz Night"]
+        connection = sqlite3.connect(target_db)
+        assert [row[0] for row in connection.execute("SELECT name FROM lobby")] == ["Quiz Night"]
+        connection.close()
@@ -0,0 +1,71 @@
+"""Integration tests for bulk player-to-team assignment."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+
+
+@pytest.fixture
+def lobby(client):
+    """Four players over two teams."""
+    lobby = client.post("/api/admin/lobby", json={"name": "Drag Night"}, headers=ADMIN_HEADERS).json()
+    for name in ("Ann", "Ben", "Cat", "Dan"):
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": name})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    return lobby
+
+
+def lobby_info(client, lobby) -> dict:
+    return client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()
+
+
+def assign(client, lobby, assignments: dict):
+    return client.put(
+        f"/api/admin/lobby/{lobby['id']}/assignments", json={"assignments": assignments}, headers=ADMIN_HEADERS
+    )
+
+
+@pytest.mark.integration
+class TestBulkAssignments:
+    """Tests for PUT /api/admin/lobby/{lobby_id}/assignments."""
+
+    def test_applies_every_move(self, client, lobby):
+        info = lobby_info(client, lobby)
+        first, second = (team["id"] for team in info["teams"])
+        players = {player["name"]: player["id"] for player in info["players"]}
+
+        response = assign(client, lobby, {players["Ann"]: first, players["Ben"]: first, players["Cat"]: 0})
+
+        assert response.status_code == 200
+        teams = {player["name"]: player["team_id"] for player in lobby_info(client, lobby)["players"]}
+        assert (teams["Ann"], teams["Ben"], teams["Cat"]) == (first, first, None)
+        assert teams["Dan"] in (first, second)
+
+    def test_unknown_player_changes_nothing(self, client, lobby):
+        info = lobby_info(client, lobby)
+        before = {player["id"]: player["team_id"] for player in info["players"]}
+        other_team = next(team["id"] for team in info["teams"] if team["id"] != info["players"][0]["team_id"])
+
+        response = assign(client, lobby, {info["players"][0]["id"]: other_team, 9999: other_team})
+
+        assert response.status_code == 400
+        assert {player["id"]: player["team_id"] for player in lobby_info(client, lobby)["players"]} == before
+
+    def test_team_must_be_in_the_lobby(self, client, lobby):
+        other = client.post("/api/admin/lobby", json={"name": "Elsewhere"}, headers=ADMIN_HEADERS).json()
+        client.post(f"/api/lobby/{other['code']}", json={"name": "Eve"})
+        client.post(f"/api/lobby/{other['code']}", json={"name": "Fay"})
+        client.post(f"/api/admin/lobby/{other['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+        foreign_team = lobby_info(client, other)["teams"][0]["id"]
+        player_id = lobby_info(client, lobby)["players"][0]["id"]
+
+        assert assign(client, lobby, {player_id: foreign_team}).status_code == 400
+
+    def test_empty_map_is_refused(self, client, lobby):
+        assert assign(client, lobby, {}).status_code == 400
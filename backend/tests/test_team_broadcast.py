@@ -0,0 +1,92 @@
+"""Unit tests for team-scoped broadcasts in the lobby websocket manager."""
+
+import asyncio
+import json
+import sys
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.websocket.events import TeamChangedEvent, TeamChatEvent
+from backend.websocket.managers import AdminWebSocketManager, LobbyWebSocketManager
+
+LOBBY_ID = 1
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+
+    async def send_text(self, text):
+        self.sent.append(json.loads(text))
+
+
+def manager_with_players(teams: dict) -> tuple[LobbyWebSocketManager, dict]:
+    """Connect one fake socket per player session, registered to the given team (None for unassigned)."""
+    manager = LobbyWebSocketManager(admin_web_socket_manager=AdminWebSocketManager())
+    sockets = {session_id: FakeWebSocket() for session_id in teams}
+    manager.lobby_websockets[LOBBY_ID] = dict(sockets)
+    for session_id, team_id in teams.items():
+        if team_id is not None:
+            manager.register_player_team(session_id, team_id)
+    return manager, sockets
+
+
+def delivered(manager: LobbyWebSocketManager, call):
+    """Run a manager call and wait for what it queued to reach the sockets."""
+
+    async def run():
+        result = await call
+        await manager.flush()
+        return result
+
+    return asyncio.run(run())
+
+
+def chat(team_id: int) -> TeamChatEvent:
+    return TeamChatEvent(
+        team_id=team_id, player_id=1, player_name="Alice", text="try the bottom", sent_at="2026-01-01T00:00:00+00:00"
+    )
+
+
+def received_chat(sockets: dict) -> set:
+    return {
+        session_id
+        for session_id, websocket in sockets.items()
+        if any(event["type"] == "team_chat" for event in websocket.sent)
+    }
+
+
+class TestBroadcastToTeam:
+    """Tests for delivering events only to a team's connected members."""
+
+    def test_only_team_members_receive(self):
+        """Other teams and unassigned players don't see the event."""
+        manager, sockets = manager_with_players({"alice": 10, "bob": 10, "carol": 20, "dave": None})
+
+        delivered(manager, manager.broadcast_to_team(LOBBY_ID, 10, chat(10)))
+
+        assert received_chat(sockets) == {"alice", "bob"}
+
+    def test_team_changed_broadcast_moves_the_player(self):
+        """Broadcasting team_changed updates membership, so the next team broadcast follows the player."""
+        manager, sockets = manager_with_players({"alice": 10, "bob": 20})
+
+        move = TeamChangedEvent(lobby_id=LOBBY_ID, player_session_id="alice", old_team_id=10, new_team_id=20)
+        delivered(manager, manager.broadcast_to_lobby(LOBBY_ID, move))
+        delivered(manager, manager.broadcast_to_team(LOBBY_ID, 20, chat(20)))
+
+        assert manager.player_teams["alice"] == 20
+        assert received_chat(sockets) == {"alice", "bob"}
+
+    def test_team_changed_to_zero_unassigns(self):
+        """A move to team 0 drops the player from every team broadcast."""
+        manager, sockets = manager_with_players({"alice": 10})
+
+        unassign = TeamChangedEvent(lobby_id=LOBBY_ID, player_session_id="alice", old_team_id=10, new_team_id=0)
+        delivered(manager, manager.broadcast_to_lobby(LOBBY_ID, unassign))
+        delivered(manager, manager.broadcast_to_team(LOBBY_ID, 10, chat(10)))
+
+        assert "alice" not in manager.player_teams
+        assert received_chat(sockets) == set()
@@ -0,0 +1,70 @@
+"""Integration tests for stored team chat history."""
+
+import asyncio
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.api.game import handle_team_chat
+from backend.tests.helpers import ADMIN_HEADERS
+from backend.websocket.managers import lobby_websocket_manager
+from backend.websocket.player_messages import TeamChatMessage
+
+
+@pytest.fixture
+def app_builder(app_builder):
+    return app_builder.setting("TEAM_CHAT_HISTORY_SIZE", 3)
+
+
+@pytest.fixture
+def team(client):
+    """Alice and Bob on the only team of a lobby; returns (lobby_id, alice, bob)."""
+    lobby = client.post("/api/admin/lobby", json={"name": "Chat Night"}, headers=ADMIN_HEADERS).json()
+    alice = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+    bob = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Bob"}).json()
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 1}, headers=ADMIN_HEADERS)
+    return lobby["id"], alice, bob
+
+
+def say(lobby_id: int, player: dict, text: str):
+    message = TeamChatMessage(action="team_chat", text=text)
+    asyncio.run(handle_team_chat(lobby_id, player["session_id"], message, lobby_websocket_manager))
+
+
+def history(client, player: dict):
+    return client.get("/api/lobby/team/chat", headers={"Authorization": f"Bearer {player['session_id']}"})
+
+
+@pytest.mark.integration
+class TestTeamChatHistory:
+    """Tests for GET /api/lobby/team/chat."""
+
+    def test_teammates_see_the_conversation_oldest_first(self, client, team):
+        lobby_id, alice, bob = team
+        say(lobby_id, alice, "try the bottom")
+        say(lobby_id, bob, "on it")
+
+        response = history(client, bob)
+
+        assert response.status_code == 200, response.text
+        lines = [(line["player_name"], line["text"]) for line in response.json()["messages"]]
+        assert lines == [("Alice", "try the bottom"), ("Bob", "on it")]
+
+    def test_only_the_newest_lines_are_kept(self, client, team):
+        lobby_id, alice, _ = team
+        for number in range(1, 6):
+            say(lobby_id, alice, f"line {number}")
+
+        texts = [line["text"] for line in history(client, alice).json()["messages"]]
+
+        assert texts == ["line 3", "line 4", "line 5"]
+
+    def test_player_without_a_team_is_refused(self, client):
+        lobby = client.post("/api/admin/lobby", json={"name": "No Teams"}, headers=ADMIN_HEADERS).json()
+        carol = client.post(f"/api/lobby/{lobby['code']}", json={"name": "Carol"}).json()
+
+        assert history(client, carol).status_code == 400
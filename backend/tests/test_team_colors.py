@@ -0,0 +1,110 @@
+"""Tests for team color assignment and personalizing team names and colors."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+from backend.utils.team_colors import is_hex_color, pick_team_colors
+
+PALETTE = ["#111111", "#222222", "#333333"]
+
+
+class TestPickTeamColors:
+    """Tests for handing palette colors to new teams."""
+
+    def test_new_lobby_takes_the_palette_in_order(self):
+        assert pick_team_colors([], 2, PALETTE) == ["#111111", "#222222"]
+
+    def test_skips_colors_already_in_use(self):
+        """A team added later gets a color none of its lobby's teams has."""
+        assert pick_team_colors(["#111111", "#333333"], 1, PALETTE) == ["#222222"]
+
+    def test_shares_colors_evenly_once_the_palette_runs_out(self):
+        assert pick_team_colors(["#111111", "#222222", "#333333", "#111111"], 2, PALETTE) == ["#222222", "#333333"]
+
+    def test_overrides_outside_the_palette_dont_count(self):
+        assert pick_team_colors(["#ABCDEF"], 1, PALETTE) == ["#111111"]
+
+    def test_hex_colors(self):
+        assert is_hex_color("#1a2B3c")
+        assert not is_hex_color("red")
+        assert not is_hex_color("#12345")
+
+
+@pytest.fixture
+def app_builder(app_builder):
+    return app_builder.setting("TEAM_COLOR_PALETTE", PALETTE)
+
+
+def team_colors(client, lobby_id: int) -> list[str]:
+    teams = client.get(f"/api/admin/lobby/{lobby_id}", headers=ADMIN_HEADERS).json()["teams"]
+    return [team["color"] for team in sorted(teams, key=lambda team: team["id"])]
+
+
+@pytest.mark.integration
+class TestTeamColorEndpoints:
+    """Tests for colors on created teams and the admin override."""
+
+    def test_teams_get_distinct_palette_colors(self, client, lobby):
+        client.post(f"/api/admin/lobby/{lobby['id']}/team/add-one", headers=ADMIN_HEADERS)
+
+        assert team_colors(client, lobby["id"]) == PALETTE
+
+    def test_admin_can_override_a_color(self, client, lobby):
+        team_id = client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()["teams"][0]["id"]
+
+        url = f"/api/admin/lobby/team/{team_id}/color"
+        response = client.put(url, json={"color": "#abcdef"}, headers=ADMIN_HEADERS)
+        assert response.status_code == 200
+        assert "#ABCDEF" in team_colors(client, lobby["id"])
+
+    def test_override_must_be_a_hex_color(self, client, lobby):
+        team_id = client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()["teams"][0]["id"]
+
+        response = client.put(f"/api/admin/lobby/team/{team_id}/color", json={"color": "red"}, headers=ADMIN_HEADERS)
+        assert response.status_code == 400
+
+
+@pytest.mark.integration
+class TestPatchTeam:
+    """Tests for PATCH /api/admin/lobby/team/{team_id}."""
+
+    @staticmethod
+    def teams(client, lobby) -> list[dict]:
+        return client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()["teams"]
+
+    def test_only_the_fields_sent_change(self, client, lobby):
+        team = self.teams(client, lobby)[0]
+        url = f"/api/admin/lobby/team/{team['id']}"
+
+        renamed = client.patch(url, json={"name": " Night Owls "}, headers=ADMIN_HEADERS)
+        recolored = client.patch(url, json={"color": "#abcdef"}, headers=ADMIN_HEADERS)
+
+        assert renamed.status_code == 200
+        assert (renamed.json()["name"], renamed.json()["color"]) == ("Night Owls", team["color"])
+        assert (recolored.json()["name"], recolored.json()["color"]) == ("Night Owls", "#ABCDEF")
+
+    def test_names_must_be_unique_in_the_lobby(self, client, lobby):
+        first, second = self.teams(client, lobby)
+
+        response = client.patch(
+            f"/api/admin/lobby/team/{second['id']}", json={"name": first["name"]}, headers=ADMIN_HEADERS
+        )
+
+        assert response.status_code == 409
+
+    def test_rejects_blank_names_and_bad_colors(self, client, lobby):
+        url = f"/api/admin/lobby/team/{self.teams(client, lobby)[0]['id']}"
+
+        assert client.patch(url, json={"name": "  "}, headers=ADMIN_HEADERS).status_code == 400
+        assert client.patch(url, json={"color": "red"}, headers=ADMIN_HEADERS).status_code == 400
+
+    def test_unknown_team(self, client, lobby):
+        response = client.patch("/api/admin/lobby/team/9999", json={"name": "Ghosts"}, headers=ADMIN_HEADERS)
+
+        assert response.status_code == 404
@@ -0,0 +1,111 @@
+"""Tests for players reviewing their team's guesses."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, PUZZLE
+
+
+def create_lobby(client) -> dict:
+    lobby = client.post("/api/admin/lobby", json={"name": "History Night"}, headers=ADMIN_HEADERS).json()
+    players = {}
+    for name in ["Alice", "Bob", "Carol", "Dan"]:
+        session_id = client.post(f"/api/lobby/{lobby['code']}", json={"name": name}).json()["session_id"]
+        players[name] = {"Authorization": f"Bearer {session_id}"}
+    return {**lobby, "players": players}
+
+
+def start_game(client, lobby: dict) -> dict:
+    """Split the lobby into two teams and start; returns each player's teammates' and opponents' headers."""
+    puzzle = client.post("/api/admin/puzzle", json=PUZZLE, headers=ADMIN_HEADERS).json()
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+    client.post(
+        f"/api/admin/lobby/{lobby['id']}/game/start",
+        json={"difficulty": "easy", "force_start": True, "puzzle_id": puzzle["id"]},
+        headers=ADMIN_HEADERS,
+    )
+    team_of = {
+        name: client.get("/api/lobby/active", headers=headers).json()["team_id"]
+        for name, headers in lobby["players"].items()
+    }
+    teammate = next(name for name in ["Bob", "Carol", "Dan"] if team_of[name] == team_of["Alice"])
+    opponent = next(name for name in ["Bob", "Carol", "Dan"] if team_of[name] != team_of["Alice"])
+    return {"teammate": teammate, "opponent": opponent}
+
+
+def guess(client, headers: dict, word: str, word_index: int):
+    client.post("/api/lobby/guess", json={"guess": word, "word_index": word_index}, headers=headers)
+
+
+def history(client, headers: dict, **params):
+    return client.get("/api/lobby/team/guesses", params=params, headers=headers)
+
+
+@pytest.mark.integration
+class TestTeamGuessHistory:
+    """Tests for GET /api/lobby/team/guesses."""
+
+    def test_lists_the_teams_guesses_newest_first(self, client):
+        lobby = create_lobby(client)
+        names = start_game(client, lobby)
+        alice, teammate = lobby["players"]["Alice"], lobby["players"][names["teammate"]]
+        guess(client, alice, "ICE", 1)
+        guess(client, teammate, "FLAKE", 1)
+        guess(client, lobby["players"][names["opponent"]], "SLEET", 1)
+
+        response = history(client, alice)
+
+        assert response.status_code == 200, response.text
+        body = response.json()
+        assert body["total"] == 2
+        assert body["has_more"] is False
+        assert [(entry["guess"], entry["result"], entry["player_name"]) for entry in body["guesses"]] == [
+            ("FLAKE", "correct", names["teammate"]),
+            ("ICE", "wrong", "Alice"),
+        ]
+        assert body["guesses"][0]["word_index"] == 1
+        # Teammates see the same history
+        assert history(client, teammate).json()["guesses"] == body["guesses"]
+
+    def test_pages_through_long_histories(self, client):
+        lobby = create_lobby(client)
+        start_game(client, lobby)
+        alice = lobby["players"]["Alice"]
+        for word in ["ICE", "HAIL", "SLEET"]:
+            guess(client, alice, word, 1)
+
+        first = history(client, alice, page=1, page_size=2).json()
+        second = history(client, alice, page=2, page_size=2).json()
+
+        assert [entry["guess"] for entry in first["guesses"]] == ["SLEET", "HAIL"]
+        assert (first["total"], first["has_more"]) == (3, True)
+        assert [entry["guess"] for entry in second["guesses"]] == ["ICE"]
+        assert second["has_more"] is False
+
+    def test_empty_before_the_game_starts(self, client):
+        lobby = create_lobby(client)
+        client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+
+        body = history(client, lobby["players"]["Alice"]).json()
+
+        assert (body["game_id"], body["total"], body["guesses"]) == (None, 0, [])
+
+    def test_players_without_a_team_are_refused(self, client):
+        lobby = create_lobby(client)
+
+        assert history(client, lobby["players"]["Alice"]).status_code == 400
+
+    def test_requires_a_player_session(self, client):
+        assert client.get("/api/lobby/team/guesses").status_code == 401
+
+    @pytest.mark.parametrize("params", [{"page": 0}, {"page_size": 0}, {"page_size": 101}])
+    def test_out_of_range_paging_is_rejected(self, client, params):
+        lobby = create_lobby(client)
+        client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+
+        assert history(client, lobby["players"]["Alice"], **params).status_code == 422
@@ -0,0 +1,111 @@
+"""Integration tests for adding, removing and rebalancing teams after the lobby's teams were created."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+
+
+@pytest.fixture
+def lobby(client):
+    """Six players over three teams."""
+    lobby = client.post("/api/admin/lobby", json={"name": "Resize Night"}, headers=ADMIN_HEADERS).json()
+    for name in ("Ann", "Ben", "Cat", "Dan", "Eve", "Fay"):
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": name})
+    client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 3}, headers=ADMIN_HEADERS)
+    return lobby
+
+
+def lobby_info(client, lobby) -> dict:
+    return client.get(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS).json()
+
+
+@pytest.mark.integration
+class TestAddSingleTeam:
+    """Tests for POST /api/admin/lobby/{lobby_id}/team/single."""
+
+    def test_adds_an_empty_team_and_keeps_assignments(self, client, lobby):
+        before = lobby_info(client, lobby)
+
+        response = client.post(f"/api/admin/lobby/{lobby['id']}/team/single", headers=ADMIN_HEADERS)
+
+        after = lobby_info(client, lobby)
+        assert response.status_code == 200
+        assert len(after["teams"]) == 4
+        assert {p["name"]: p["team_id"] for p in after["players"]} == {
+            p["name"]: p["team_id"] for p in before["players"]
+        }
+
+
+@pytest.mark.integration
+class TestRemoveTeam:
+    """Tests for DELETE /api/admin/lobby/team/{team_id}."""
+
+    def test_players_are_unassigned_by_default(self, client, lobby):
+        team_id = lobby_info(client, lobby)["teams"][0]["id"]
+
+        client.delete(f"/api/admin/lobby/team/{team_id}", headers=ADMIN_HEADERS)
+
+        info = lobby_info(client, lobby)
+        assert len(info["teams"]) == 2
+        assert sum(1 for p in info["players"] if p["team_id"] is None) == 2
+
+    def test_reassign_deals_players_onto_the_smallest_teams(self, client, lobby):
+        team_id = lobby_info(client, lobby)["teams"][0]["id"]
+
+        response = client.delete(f"/api/admin/lobby/team/{team_id}?reassign=true", headers=ADMIN_HEADERS)
+
+        info = lobby_info(client, lobby)
+        assert response.status_code == 200
+        assert all(p["team_id"] is not None for p in info["players"])
+        assert sorted(len(players) for players in info["players_by_team"].values()) == [3, 3]
+
+    def test_keeps_at_least_two_teams(self, client, lobby):
+        teams = lobby_info(client, lobby)["teams"]
+        client.delete(f"/api/admin/lobby/team/{teams[0]['id']}", headers=ADMIN_HEADERS)
+
+        response = client.delete(f"/api/admin/lobby/team/{teams[1]['id']}?reassign=true", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 400
+
+
+def join_late_arrivals(client, lobby, names=("Gus", "Hal", "Ivy")):
+    for name in names:
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": name})
+
+
+@pytest.mark.integration
+class TestRebalanceTeams:
+    """Tests for POST /api/admin/lobby/{lobby_id}/team/rebalance."""
+
+    def test_late_arrivals_fill_the_teams_without_moving_anyone(self, client, lobby):
+        before = {p["name"]: p["team_id"] for p in lobby_info(client, lobby)["players"]}
+        join_late_arrivals(client, lobby)
+
+        response = client.post(f"/api/admin/lobby/{lobby['id']}/team/rebalance", headers=ADMIN_HEADERS)
+
+        info = lobby_info(client, lobby)
+        assert response.status_code == 200
+        assert {p["name"]: p["team_id"] for p in info["players"] if p["name"] in before} == before
+        assert sorted(len(players) for players in info["players_by_team"].values()) == [3, 3, 3]
+
+    def test_shuffle_deals_everyone_out_again(self, client, lobby):
+        join_late_arrivals(client, lobby)
+
+        client.post(f"/api/admin/lobby/{lobby['id']}/team/rebalance?shuffle=true", headers=ADMIN_HEADERS)
+
+        info = lobby_info(client, lobby)
+        assert all(p["team_id"] is not None for p in info["players"])
+        assert sorted(len(players) for players in info["players_by_team"].values()) == [3, 3, 3]
+
+    def test_needs_teams(self, client):
+        lobby = client.post("/api/admin/lobby", json={"name": "No Teams"}, headers=ADMIN_HEADERS).json()
+
+        response = client.post(f"/api/admin/lobby/{lobby['id']}/team/rebalance", headers=ADMIN_HEADERS)
+
+        assert response.status_code == 400
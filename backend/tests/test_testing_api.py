@@ -0,0 +1,140 @@
+"""Integration tests for the testing-only event injection and chaos endpoints."""
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+from fastapi.testclient import TestClient
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.app_builder import AppBuilder
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_PASSWORD, ADMIN_TOKEN
+from backend.websocket.managers import lobby_websocket_manager
+
+
+class FakeWebSocket:
+    def __init__(self):
+        self.sent = []
+        self.close_code = None
+
+    async def send_text(self, text):
+        self.sent.append(json.loads(text))
+
+    async def close(self, code=1000, reason=None):
+        self.close_code = code
+
+
+@pytest.fixture
+def lobby(client):
+    return client.post("/api/admin/lobby", json={"name": "Emit Night"}, headers=ADMIN_HEADERS).json()
+
+
+def add_player_socket(lobby_id: int, session_id: str, team_id: int | None = None) -> FakeWebSocket:
+    websocket = FakeWebSocket()
+    lobby_websocket_manager.lobby_websockets.setdefault(lobby_id, {})[session_id] = websocket
+    if team_id is not None:
+        lobby_websocket_manager.register_player_team(session_id, team_id)
+    return websocket
+
+
+@pytest.mark.integration
+class TestEmitEvent:
+    """Tests for POST /api/test/lobby/{lobby_id}/emit."""
+
+    def test_lobby_target_reaches_every_player(self, client, lobby):
+        """The raw event goes to each connected player, with the lobby id filled in."""
+        alice = add_player_socket(lobby["id"], "alice")
+        bob = add_player_socket(lobby["id"], "bob")
+
+        response = client.post(
+            f"/api/test/lobby/{lobby['id']}/emit",
+            json={"event": {"type": "lobby_deleted", "player_session_id": "alice"}},
+        )
+
+        assert response.status_code == 200, response.text
+        expected = {"lobby_id": lobby["id"], "type": "lobby_deleted", "player_session_id": "alice"}
+        assert alice.sent == [expected]
+        assert bob.sent == [expected]
+
+    def test_team_target_skips_other_teams(self, client, lobby):
+        """Team events only reach players registered to that team."""
+        alice = add_player_socket(lobby["id"], "alice", team_id=1)
+        bob = add_player_socket(lobby["id"], "bob", team_id=2)
+
+        client.post(
+            f"/api/test/lobby/{lobby['id']}/emit",
+            json={"event": {"type": "timer_expired"}, "target": "team", "team_id": 1},
+        )
+
+        assert [event["type"] for event in alice.sent] == ["timer_expired"]
+        assert bob.sent == []
+
+    def test_rejects_bad_requests(self, client, lobby):
+        """Unknown lobbies, untyped events and targets missing their id are refused."""
+        assert client.post("/api/test/lobby/9999/emit", json={"event": {"type": "x"}}).status_code == 404
+        assert client.post(f"/api/test/lobby/{lobby['id']}/emit", json={"event": {}}).status_code == 400
+        response = client.post(f"/api/test/lobby/{lobby['id']}/emit", json={"event": {"type": "x"}, "target": "team"})
+        assert response.status_code == 400
+
+    def test_not_mounted_outside_testing(self):
+        """Without TESTING the route doesn't exist."""
+        builder = (
+            AppBuilder().setting("ADMIN_PASSWORD", ADMIN_PASSWORD).setting("TESTING", False).admin_session(ADMIN_TOKEN)
+        )
+        with builder as app, TestClient(app) as client:
+            lobby = client.post("/api/admin/lobby", json={"name": "Live"}, headers=ADMIN_HEADERS).json()
+
+            response = client.post(f"/api/test/lobby/{lobby['id']}/emit", json={"event": {"type": "x"}})
+
+        assert response.status_code in (404, 405)
+
+
+@pytest.mark.integration
+class TestChaos:
+    """Tests for /api/test/chaos."""
+
+    def test_off_by_default(self, client):
+        body = client.get("/api/test/chaos").json()
+
+        assert body["enabled"] is False
+        assert body["injected"] == {"db_delays": 0, "dropped_sends": 0, "disconnects": 0}
+
+    def test_dropped_sends_never_arrive(self, client, lobby):
+        """Dropped sends look delivered, like a lost packet would."""
+        alice = add_player_socket(lobby["id"], "alice")
+        client.put("/api/test/chaos", json={"drop_send_probability": 1})
+
+        client.post(f"/api/test/lobby/{lobby['id']}/emit", json={"event": {"type": "timer_expired"}})
+
+        assert alice.sent == []
+        assert client.get("/api/test/chaos").json()["injected"]["dropped_sends"] == 1
+
+    def test_forced_disconnect_closes_the_socket(self, client, lobby):
+        alice = add_player_socket(lobby["id"], "alice")
+        client.put("/api/test/chaos", json={"disconnect_probability": 1})
+
+        client.post(f"/api/test/lobby/{lobby['id']}/emit", json={"event": {"type": "timer_expired"}})
+
+        assert (alice.sent, alice.close_code) == ([], 1012)
+
+    def test_delays_database_statements(self, client):
+        client.put("/api/test/chaos", json={"db_delay_probability": 1, "db_delay_ms": 1})
+
+        client.get("/api/admin/lobby", headers=ADMIN_HEADERS)
+
+        assert client.get("/api/test/chaos").json()["injected"]["db_delays"] > 0
+
+    def test_delete_turns_it_off(self, client, lobby):
+        alice = add_player_socket(lobby["id"], "alice")
+        client.put("/api/test/chaos", json={"drop_send_probability": 1})
+
+        assert client.delete("/api/test/chaos").json()["enabled"] is False
+        client.post(f"/api/test/lobby/{lobby['id']}/emit", json={"event": {"type": "timer_expired"}})
+
+        assert [event["type"] for event in alice.sent] == ["timer_expired"]
+
+    def test_probabilities_are_bounded(self, client):
+        assert client.put("/api/test/chaos", json={"drop_send_probability": 1.5}).status_code == 422
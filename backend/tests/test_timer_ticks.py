@@ -0,0 +1,52 @@
+"""Tests for spacing out timer_tick events while a round timer runs."""
+
+import sys
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.api.admin.lobby.timer_poller import TimerTicks
+
+STARTED = datetime(2026, 1, 1, 20, 0, tzinfo=timezone.utc)
+
+
+def later(seconds: float) -> datetime:
+    return STARTED + timedelta(seconds=seconds)
+
+
+class TestTimerTicks:
+    """Tests for when TimerTicks says a tick is due."""
+
+    def test_first_tick_comes_one_interval_after_the_start(self):
+        ticks = TimerTicks()
+
+        assert not ticks.due(1, STARTED, later(9), 10)
+        assert ticks.due(1, STARTED, later(10), 10)
+
+    def test_ticks_are_spaced_by_the_interval(self):
+        ticks = TimerTicks()
+        ticks.due(1, STARTED, later(10), 10)
+
+        assert not ticks.due(1, STARTED, later(15), 10)
+        assert ticks.due(1, STARTED, later(20), 10)
+
+    def test_a_new_timer_starts_counting_again(self):
+        ticks = TimerTicks()
+        ticks.due(1, STARTED, later(10), 10)
+
+        assert not ticks.due(1, later(12), later(15), 10)
+        assert ticks.due(1, later(12), later(22), 10)
+
+    def test_zero_interval_sends_no_ticks(self):
+        assert not TimerTicks().due(1, STARTED, later(600), 0)
+
+    def test_retain_drops_lobbies_without_a_running_timer(self):
+        ticks = TimerTicks()
+        ticks.due(1, STARTED, later(10), 10)
+        ticks.due(2, STARTED, later(10), 10)
+
+        ticks.retain([2])
+
+        assert list(ticks.sent) == [2]
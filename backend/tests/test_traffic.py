@@ -0,0 +1,99 @@
+"""Tests for access logging and rolling per-route traffic counts."""
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, ADMIN_TOKEN
+from backend.traffic import UNMATCHED_ROUTE, TrafficTracker, access_log_line, session_fingerprint
+
+NOW = 1_800_000_000.0  # On a minute boundary
+
+
+def minutes_ago(minutes: float) -> float:
+    return NOW - minutes * 60
+
+
+class TestTrafficTracker:
+    """Tests for bucketing requests into rolling windows."""
+
+    def test_requests_fall_into_each_window_they_fit(self):
+        """A request 10 minutes ago counts towards the 15 and 60 minute windows but not the 5 minute one."""
+        tracker = TrafficTracker()
+        tracker.record("GET", "/api/lobby/{lobby_id}", 200, 10.0, minutes_ago(1))
+        tracker.record("GET", "/api/lobby/{lobby_id}", 200, 30.0, minutes_ago(10))
+        tracker.record("GET", "/api/lobby/{lobby_id}", 404, 20.0, minutes_ago(30))
+
+        (route,) = tracker.summary(NOW)
+        assert route.requests == {5: 1, 15: 2, 60: 3}
+        assert route.errors == 1
+        assert route.avg_duration_ms == 20.0
+
+    def test_old_buckets_are_dropped(self):
+        """Nothing older than the longest window is kept."""
+        tracker = TrafficTracker()
+        tracker.record("GET", "/api", 200, 1.0, minutes_ago(90))
+        tracker.record("GET", "/api", 200, 1.0, NOW)
+
+        assert len(tracker.buckets) == 1
+        assert tracker.summary(NOW)[0].requests[60] == 1
+
+    def test_routes_are_split_by_method_and_sorted_busiest_first(self):
+        """GET and POST on the same template are separate rows; the busiest recent route leads."""
+        tracker = TrafficTracker()
+        tracker.record("POST", "/api/lobby/{lobby_code}", 200, 1.0, NOW)
+        for _ in range(3):
+            tracker.record("GET", "/api/lobby/{lobby_code}", 200, 1.0, NOW)
+
+        assert [(route.method, route.requests[5]) for route in tracker.summary(NOW)] == [("GET", 3), ("POST", 1)]
+
+
+class TestAccessLog:
+    """Tests for the structured access log line."""
+
+    def test_line_is_json_with_hashed_session(self):
+        """The bearer token is fingerprinted, never logged as-is."""
+        session = session_fingerprint(ADMIN_HEADERS["Authorization"])
+        line = json.loads(access_log_line("GET", "/api/admin/traffic", 200, 12.345, session))
+
+        assert line == {
+            "method": "GET",
+            "route": "/api/admin/traffic",
+            "status": 200,
+            "duration_ms": 12.3,
+            "session": session,
+        }
+        assert ADMIN_TOKEN not in json.dumps(line)
+        assert session == session_fingerprint(ADMIN_HEADERS["Authorization"])
+
+    def test_no_token_means_no_session(self):
+        assert session_fingerprint(None) is None
+        assert session_fingerprint("Bearer ") is None
+
+
+@pytest.mark.integration
+class TestTrafficEndpoint:
+    """Tests for GET /api/admin/traffic."""
+
+    def test_counts_requests_by_route_template(self, client):
+        """Requests for different lobbies are grouped under one template; unknown paths are lumped together."""
+        client.get("/api/lobby/1")
+        client.get("/api/lobby/2")
+        client.get("/api/no-such-route")
+
+        response = client.get("/api/admin/traffic", headers=ADMIN_HEADERS)
+        assert response.status_code == 200
+        body = response.json()
+        assert body["windows_minutes"] == [5, 15, 60]
+
+        routes = {(route["method"], route["route"]): route for route in body["routes"]}
+        assert routes[("GET", "/api/lobby/{lobby_id}")]["requests"]["5"] == 2
+        assert routes[("GET", UNMATCHED_ROUTE)]["errors"] == 1
+
+    def test_requires_admin(self, client):
+        assert client.get("/api/admin/traffic").status_code == 401
@@ -0,0 +1,81 @@
+"""Integration tests for multi-step admin operations rolling back as a whole."""
+
+import sys
+from pathlib import Path
+
+import pytest
+from sqlalchemy.exc import SQLAlchemyError
+from sqlmodel import Session
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS
+from backend.websocket.managers import lobby_websocket_manager
+
+
+class FailingRng:
+    def shuffle(self, items):
+        raise SQLAlchemyError("connection lost")
+
+
+def fail(*args, **kwargs):
+    raise SQLAlchemyError("connection lost")
+
+
+@pytest.fixture
+def lobby(client):
+    lobby = client.post("/api/admin/lobby", json={"name": "Atomic"}, headers=ADMIN_HEADERS).json()
+    for name in ("Alice", "Bob"):
+        client.post(f"/api/lobby/{lobby['code']}", json={"name": name})
+    return lobby
+
+
+def lobby_info(client, lobby_id: int) -> dict:
+    return client.get(f"/api/admin/lobby/{lobby_id}", headers=ADMIN_HEADERS).json()
+
+
+@pytest.mark.integration
+class TestCreateTeamsTransaction:
+    """Tests for POST /api/admin/lobby/{lobby_id}/team failing part-way."""
+
+    def test_failed_assignment_leaves_no_teams(self, client, lobby, monkeypatch):
+        monkeypatch.setattr("backend.api.admin.lobby.team.seeded_rng", lambda *args: FailingRng())
+
+        response = client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+
+        assert response.status_code == 500
+        info = lobby_info(client, lobby["id"])
+        assert info["teams"] == []
+        assert [player["team_id"] for player in info["players"]] == [None, None]
+
+    def test_teams_can_be_created_after_a_failure(self, client, lobby, monkeypatch):
+        monkeypatch.setattr("backend.api.admin.lobby.team.seeded_rng", lambda *args: FailingRng())
+        client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+        monkeypatch.undo()
+
+        response = client.post(f"/api/admin/lobby/{lobby['id']}/team", json={"num_teams": 2}, headers=ADMIN_HEADERS)
+
+        assert response.status_code == 200
+        assert len(lobby_info(client, lobby["id"])["teams"]) == 2
+
+
+@pytest.mark.integration
+class TestDeleteLobbyTransaction:
+    """Tests for DELETE /api/admin/lobby/{lobby_id} failing to commit."""
+
+    def test_failed_delete_keeps_the_lobby_and_its_players(self, client, lobby, monkeypatch):
+        kicked = []
+
+        async def record_kick(lobby_id, player_session_id):
+            kicked.append(player_session_id)
+
+        monkeypatch.setattr(lobby_websocket_manager, "kick_player", record_kick)
+        monkeypatch.setattr(Session, "commit", fail)
+
+        response = client.delete(f"/api/admin/lobby/{lobby['id']}", headers=ADMIN_HEADERS)
+        monkeypatch.undo()
+
+        assert response.status_code == 500
+        assert kicked == []
+        assert len(lobby_info(client, lobby["id"])["players"]) == 2
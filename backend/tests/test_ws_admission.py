@@ -0,0 +1,93 @@
+"""Tests for the checks made before accepting a websocket upgrade."""
+
+import sys
+from pathlib import Path
+
+import pytest
+from starlette.testclient import WebSocketDenialResponse
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.settings import settings
+from backend.tests.helpers import ADMIN_HEADERS
+from backend.websocket.admission import ConnectionsPerIp
+
+
+class TestConnectionsPerIp:
+    """Tests for counting sockets per address."""
+
+    def test_caps_each_address(self, monkeypatch):
+        monkeypatch.setattr(settings, "WS_MAX_CONNECTIONS_PER_IP", 2)
+        limiter = ConnectionsPerIp()
+
+        assert [limiter.acquire("1.2.3.4") for _ in range(3)] == [True, True, False]
+        assert limiter.acquire("5.6.7.8")
+
+    def test_released_sockets_free_their_slot(self, monkeypatch):
+        monkeypatch.setattr(settings, "WS_MAX_CONNECTIONS_PER_IP", 1)
+        limiter = ConnectionsPerIp()
+        limiter.acquire("1.2.3.4")
+
+        limiter.release("1.2.3.4")
+
+        assert limiter.open == {}
+        assert limiter.acquire("1.2.3.4")
+
+    def test_zero_turns_the_cap_off(self, monkeypatch):
+        monkeypatch.setattr(settings, "WS_MAX_CONNECTIONS_PER_IP", 0)
+        limiter = ConnectionsPerIp()
+
+        assert all(limiter.acquire("1.2.3.4") for _ in range(500))
+
+
+@pytest.fixture
+def app_builder(app_builder):
+    return app_builder.setting("WS_MAX_CONNECTIONS_PER_IP", 1)
+
+
+@pytest.fixture
+def lobby(client):
+    return client.post("/api/admin/lobby", json={"name": "Admission Night"}, headers=ADMIN_HEADERS).json()
+
+
+def player_url(client, lobby: dict, name: str) -> str:
+    player = client.post(f"/api/lobby/{lobby['code']}", json={"name": name}).json()
+    token = client.post("/api/lobby/ws-token", headers={"Authorization": f"Bearer {player['session_id']}"}).json()
+    return f"/ws/lobby/{lobby['id']}/player?token={token['token']}"
+
+
+def refusal(client, url: str) -> int:
+    with pytest.raises(WebSocketDenialResponse) as denied:
+        with client.websocket_connect(url):
+            pass
+    return denied.value.status_code
+
+
+@pytest.mark.integration
+class TestUpgradeChecks:
+    """Tests for refusing websocket upgrades over HTTP."""
+
+    def test_unknown_lobby(self, client):
+        assert refusal(client, "/ws/lobby/9999/player?token=anything") == 404
+        assert refusal(client, "/ws/lobby/9999/spectator/anyone") == 404
+
+    def test_closed_lobby(self, client, lobby):
+        url = player_url(client, lobby, "Alice")
+        client.put(
+            f"/api/admin/lobby/{lobby['id']}/schedule",
+            json={"timezone": "UTC", "closes_at": "2020-01-01T00:00:00"},
+            headers=ADMIN_HEADERS,
+        )
+
+        assert refusal(client, url) == 410
+
+    def test_one_address_is_capped(self, client, lobby):
+        first, second = player_url(client, lobby, "Alice"), player_url(client, lobby, "Bob")
+
+        with client.websocket_connect(first) as websocket:
+            websocket.send_json({"action": "pong"})
+            assert refusal(client, second) == 429
+
+        with client.websocket_connect(second) as websocket:
+            websocket.send_json({"action": "pong"})
@@ -0,0 +1,97 @@
+"""Integration tests for player websocket connect tokens."""
+
+import sys
+from pathlib import Path
+
+import pytest
+from starlette.websockets import WebSocketDisconnect
+
+# Add parent directory to path
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from backend.tests.helpers import ADMIN_HEADERS, SHARE_SECRET
+from backend.utils.signing import sign_token
+from backend.websocket.managers import REPLACED_CLOSE_CODE, lobby_websocket_manager
+
+
+@pytest.fixture
+def player(client):
+    lobby = client.post("/api/admin/lobby", json={"name": "Token Night"}, headers=ADMIN_HEADERS).json()
+    return client.post(f"/api/lobby/{lobby['code']}", json={"name": "Alice"}).json()
+
+
+def issue(client, player) -> dict:
+    return client.post("/api/lobby/ws-token", headers={"Authorization": f"Bearer {player['session_id']}"}).json()
+
+
+def assert_refused(client, url):
+    with pytest.raises(WebSocketDisconnect) as closed:
+        with client.websocket_connect(url) as websocket:
+            websocket.receive_text()
+    assert closed.value.code == 1008
+
+
+@pytest.mark.integration
+class TestConnectTokens:
+    """Tests for POST /api/lobby/ws-token and /ws/lobby/{lobby_id}/player?token=..."""
+
+    def test_token_needs_a_player_session(self, client):
+        assert client.post("/api/lobby/ws-token").status_code == 401
+
+    def test_token_opens_the_players_websocket(self, client, player):
+        """A fresh token connects, and the session id appears nowhere in it."""
+        body = issue(client, player)
+
+        assert body["lobby_id"] == player["lobby_id"]
+        assert player["session_id"] not in body["token"]
+        with client.websocket_connect(f"/ws/lobby/{player['lobby_id']}/player?token={body['token']}") as websocket:
+            websocket.send_json({"action": "pong"})
+
+    def test_token_only_opens_its_own_lobby(self, client, player):
+        token = issue(client, player)["token"]
+        other = client.post("/api/admin/lobby", json={"name": "Other Night"}, headers=ADMIN_HEADERS).json()
+
+        assert_refused(client, f"/ws/lobby/{other['id']}/player?token={token}")
+
+    def test_forged_and_expired_tokens_are_refused(self, client, player):
+        payload = {"scope": "ws", "player_id": player["id"], "lobby_id": player["lobby_id"]}
+        forged = sign_token(payload, "not-the-secret", 60)
+        expired = sign_token(payload, SHARE_SECRET, 60, now=0)
+
+        assert_refused(client, f"/ws/lobby/{player['lobby_id']}/player?token={forged}")
+        assert_refused(client, f"/ws/lobby/{player['lobby_id']}/player?token={expired}")
+
+    def test_token_stops_working_once_the_player_leaves(self, client, player):
+        token = issue(client, player)["token"]
+        client.delete("/api/lobby", headers={"Authorization": f"Bearer {player['session_id']}"})
+
+        assert_refused(client, f"/ws/lobby/{player['lobby_id']}/player?token={token}")
+
+    def test_reconnecting_replaces_the_previous_socket(self, client, player):
+        """The old socket is closed, and its listener ending afterwards leaves the new one registered."""
+        url = f"/ws/lobby/{player['lobby_id']}/player?token="
+        old = client.websocket_connect(url + issue(client, player)["token"]).__enter__()
+
+        with client.websocket_connect(url + issue(client, player)["token"]) as new:
+            with pytest.raises(WebSocketDisconnect) as closed:
+                old.receive_text()
+            old.__exit__(None, None, None)
+
+            # Pongs are addressed through the registry, so this only arrives if it still holds the new socket
+            new.send_json({"action": "ping"})
+            while new.receive_json()["type"] != "pong":
+                pass
+
+        assert closed.value.code == REPLACED_CLOSE_CODE
+
+    def test_listener_stopping_on_an_error_unregisters_the_socket(self, client, player):
+        """Unreadable messages end the listener without a disconnect from the client; the socket still goes."""
+        token = issue(client, player)["token"]
+
+        with client.websocket_connect(f"/ws/lobby/{player['lobby_id']}/player?token={token}") as websocket:
+            websocket.send_text("not json")
+            with pytest.raises(WebSocketDisconnect):
+                while True:
+                    websocket.receive_text()
+
+        assert player["session_id"] not in lobby_websocket_manager.lobby_websockets.get(player["lobby_id"], {})
@@ -0,0 +1,105 @@
+"""Structured access log lines and rolling per-route request counts for the admin traffic view."""
+
+import hashlib
+import json
+from dataclasses import dataclass
+from typing import Dict, List, Optional, Sequence, Tuple
+
+from backend.in_memory_state import on_reset
+
+TRAFFIC_WINDOWS_MINUTES = (5, 15, 60)
+UNMATCHED_ROUTE = "<unmatched>"  # 404s are lumped together so scanners can't grow the table without bound
+
+
+@dataclass
+class RouteBucket:
+    count: int = 0
+    errors: int = 0  # 4xx and 5xx responses
+    total_duration_ms: float = 0.0
+
+
+@dataclass
+class RouteTraffic:
+    method: str
+    route: str
+    requests: Dict[int, int]  # Window length in minutes -> requests in that window
+    errors: int  # Over the longest window
+    avg_duration_ms: float  # Over the longest window
+
+
+def session_fingerprint(authorization: Optional[str]) -> Optional[str]:
+    """
+    A short, stable hash of the bearer token so log lines from one session can be correlated.
+
+    The token itself is never logged: for admins it is their session token, as good as the password while it lasts.
+    """
+    if not authorization:
+        return None
+    token = authorization.removeprefix("Bearer ").strip()
+    if not token:
+        return None
+    return hashlib.sha256(token.encode()).hexdigest()[:12]
+
+
+def access_log_line(method: str, route: str, status: int, duration_ms: float, session: Optional[str]) -> str:
+    return json.dumps(
+        {"method": method, "route": route, "status": status, "duration_ms": round(duration_ms, 1), "session": session}
+    )
+
+
+class TrafficTracker:
+    """Counts requests per (method, route template) in one-minute buckets, keeping the longest window's worth."""
+
+    def __init__(self):
+        self.buckets: Dict[int, Dict[Tuple[str, str], RouteBucket]] = {}
+
+    def record(self, method: str, route: str, status: int, duration_ms: float, now: float):
+        minute = int(now // 60)
+        bucket = self.buckets.setdefault(minute, {}).setdefault((method, route), RouteBucket())
+        bucket.count += 1
+        bucket.total_duration_ms += duration_ms
+        if status >= 400:
+            bucket.errors += 1
+        self.prune(now)
+
+    def prune(self, now: float):
+        oldest = int(now // 60) - max(TRAFFIC_WINDOWS_MINUTES) + 1
+        for minute in [minute for minute in self.buckets if minute < oldest]:
+            del self.buckets[minute]
+
+    def window(self, minutes: int, now: float) -> Dict[Tuple[str, str], RouteBucket]:
+        """Totals per route over the last `minutes` minutes, counting the current partial minute."""
+        oldest = int(now // 60) - minutes + 1
+        totals: Dict[Tuple[str, str], RouteBucket] = {}
+        for minute, routes in self.buckets.items():
+            if minute < oldest:
+                continue
+            for key, bucket in routes.items():
+                total = totals.setdefault(key, RouteBucket())
+                total.count += bucket.count
+                total.errors += bucket.errors
+                total.total_duration_ms += bucket.total_duration_ms
+        return totals
+
+    def summary(self, now: float, windows: Sequence[int] = TRAFFIC_WINDOWS_MINUTES) -> List[RouteTraffic]:
+        """One entry per route seen in the longest window, busiest over the shortest window first."""
+        self.prune(now)
+        totals = {minutes: self.window(minutes, now) for minutes in windows}
+        results = []
+        for key, bucket in totals[max(windows)].items():
+            method, route = key
+            results.append(
+                RouteTraffic(
+                    method=method,
+                    route=route,
+                    requests={minutes: totals[minutes].get(key, RouteBucket()).count for minutes in windows},
+                    errors=bucket.errors,
+                    avg_duration_ms=round(bucket.total_duration_ms / bucket.count, 1),
+                )
+            )
+        shortest, longest = min(windows), max(windows)
+        return sorted(results, key=lambda r: (-r.requests[shortest], -r.requests[longest], r.route, r.method))
+
+
+traffic_tracker = TrafficTracker()
+on_reset(traffic_tracker.__init__)
@@ -0,0 +1,32 @@
+"""Lobby join codes: short, unambiguous to read out loud, and unique."""
+
+import secrets
+from typing import Callable
+
+from sqlmodel import Session, select
+
+from backend.database.models import Lobby
+from backend.errors import DatabaseError
+
+# No 0/O or 1/I/L, which get mixed up when a code is read off a projector or said out loud
+CODE_ALPHABET = "ABCDEFGHJKMNPQRSTUVWXYZ23456789"
+CODE_LENGTH = 6
+MAX_ATTEMPTS = 10
+
+
+def generate_lobby_code(choice: Callable[[str], str] = secrets.choice) -> str:
+    return "".join(choice(CODE_ALPHABET) for _ in range(CODE_LENGTH))
+
+
+def normalize_lobby_code(code: str) -> str:
+    """Codes are matched case-insensitively, so players can type them however they like."""
+    return code.strip().upper()
+
+
+def unique_lobby_code(db: Session) -> str:
+    """A code no lobby has yet, retrying on collisions; fails after MAX_ATTEMPTS rather than looping forever."""
+    for _ in range(MAX_ATTEMPTS):
+        code = generate_lobby_code()
+        if not db.exec(select(Lobby.id).where(Lobby.code == code)).first():
+            return code
+    raise DatabaseError("Couldn't find a free lobby code, please try again")
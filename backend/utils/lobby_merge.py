@@ -0,0 +1,26 @@
+"""Folding an accidental duplicate lobby into the one its host meant to use."""
+
+from typing import Any, Dict, Iterable
+
+# Lobby settings the target takes from the source when it hasn't set them itself; anything the target has set wins
+MERGED_SETTINGS = ("team_name_theme", "soft_player_cap", "max_players", "opens_at", "closes_at", "starts_at")
+
+
+def dedupe_name(name: str, taken: Iterable[str]) -> str:
+    """The name itself when it's free, otherwise the first free "Name (2)", "Name (3)", ..."""
+    taken = set(taken)
+    if name not in taken:
+        return name
+    number = 2
+    while f"{name} ({number})" in taken:
+        number += 1
+    return f"{name} ({number})"
+
+
+def merged_settings(source: Any, target: Any) -> Dict[str, Any]:
+    """The MERGED_SETTINGS values to copy onto the target: those it leaves unset and the source has."""
+    return {
+        setting: getattr(source, setting)
+        for setting in MERGED_SETTINGS
+        if getattr(target, setting) is None and getattr(source, setting) is not None
+    }
@@ -0,0 +1,106 @@
+"""
+Word filter for player and lobby names, which end up on a shared screen at parties.
+
+Names are matched word by word after folding case and common look-alike characters ("sh1t" is "shit"), so a
+blocked word inside an innocent one ("Scunthorpe") isn't caught. NAME_FILTER_MODE picks what happens to a match:
+"reject" refuses the name, "sanitize" stars the word out, and "off" lets everything through. NAME_BLOCKLIST_FILE
+adds words (one per line, # for comments) to the built-in list. Players also can't take the names the interface
+uses for the people running the game, whatever the mode.
+"""
+
+import re
+from functools import lru_cache
+from pathlib import Path
+from typing import FrozenSet, Optional
+
+from backend.errors import ErrorCode, InvalidRequestError
+from backend.settings import settings
+
+BLOCKED_WORDS = frozenset(
+    {
+        "arse",
+        "arsehole",
+        "asshole",
+        "bastard",
+        "bitch",
+        "bollocks",
+        "cunt",
+        "dickhead",
+        "fag",
+        "faggot",
+        "fuck",
+        "fucker",
+        "fucking",
+        "motherfucker",
+        "nigger",
+        "piss",
+        "prick",
+        "pussy",
+        "retard",
+        "shit",
+        "slut",
+        "twat",
+        "wanker",
+        "whore",
+    }
+)
+RESERVED_NAMES = frozenset({"admin", "administrator", "host", "moderator", "mod", "system", "server", "raddle"})
+
+LOOK_ALIKES = str.maketrans({"0": "o", "1": "i", "3": "e", "4": "a", "5": "s", "7": "t", "@": "a", "$": "s", "!": "i"})
+WORD = re.compile(r"(?:[^\W_]|[@$!])+")
+
+
+class NameNotAllowed(ValueError):
+    pass
+
+
+def fold(word: str) -> str:
+    return word.lower().strip("!").translate(LOOK_ALIKES)
+
+
+@lru_cache(maxsize=4)
+def load_blocklist(path: Optional[str]) -> FrozenSet[str]:
+    """The built-in words plus those in the file at path."""
+    if not path:
+        return BLOCKED_WORDS
+    lines = Path(path).read_text(encoding="utf-8").splitlines()
+    extra = {fold(line.strip()) for line in lines if line.strip() and not line.lstrip().startswith("#")}
+    return BLOCKED_WORDS | extra
+
+
+def blocked_words(name: str, blocklist: FrozenSet[str]) -> list[re.Match]:
+    return [match for match in WORD.finditer(name) if fold(match.group()) in blocklist]
+
+
+def is_reserved(name: str) -> bool:
+    return fold(re.sub(r"[\s_.-]+", "", name)) in RESERVED_NAMES
+
+
+def screen_name(name: str, mode: str, blocklist: FrozenSet[str], reserve: bool = False) -> str:
+    """
+    The name to use, with blocked words starred out in sanitize mode.
+
+    Raises NameNotAllowed when a blocked word is found in reject mode, or when reserve is set and the name is one
+    of RESERVED_NAMES (in any mode but off).
+    """
+    if mode == "off":
+        return name
+    if reserve and is_reserved(name):
+        raise NameNotAllowed(f'"{name.strip()}" is reserved')
+    matches = blocked_words(name, blocklist)
+    if not matches:
+        return name
+    if mode == "reject":
+        raise NameNotAllowed("That name isn't allowed here")
+    for match in reversed(matches):
+        start, end = match.span()
+        name = name[:start] + name[start] + "*" * (end - start - 1) + name[end:]
+    return name
+
+
+def allowed_name(name: str, reserve: bool = False) -> str:
+    """screen_name with the configured mode and blocklist, refusing the name as a 400 NAME_NOT_ALLOWED."""
+    try:
+        return screen_name(name, settings.NAME_FILTER_MODE, load_blocklist(settings.NAME_BLOCKLIST_FILE), reserve)
+    except NameNotAllowed as e:
+        raise InvalidRequestError(str(e), code=ErrorCode.NAME_NOT_ALLOWED)
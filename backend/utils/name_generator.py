@@ -128,6 +128,64 @@ LOBBY_NOUNS = [
     "Jamboree",
 ]
 
+# Themed team name lists, selectable per lobby
+TEAM_NAME_THEMES = {
+    "animals": [
+        "Otters",
+        "Falcons",
+        "Foxes",
+        "Badgers",
+        "Owls",
+        "Wolves",
+        "Tigers",
+        "Koalas",
+        "Herons",
+        "Lynxes",
+        "Bison",
+        "Orcas",
+        "Ravens",
+        "Geckos",
+        "Moose",
+        "Puffins",
+    ],
+    "colors": [
+        "Crimson",
+        "Cobalt",
+        "Amber",
+        "Emerald",
+        "Violet",
+        "Teal",
+        "Scarlet",
+        "Saffron",
+        "Indigo",
+        "Magenta",
+        "Ivory",
+        "Onyx",
+        "Coral",
+        "Jade",
+        "Copper",
+        "Azure",
+    ],
+    "cities": [
+        "Tokyo",
+        "Lagos",
+        "Lima",
+        "Oslo",
+        "Cairo",
+        "Seoul",
+        "Dublin",
+        "Havana",
+        "Mumbai",
+        "Quebec",
+        "Nairobi",
+        "Lisbon",
+        "Sydney",
+        "Prague",
+        "Bogota",
+        "Kyoto",
+    ],
+}
+
 
 def generate_team_name() -> str:
     """Generate a funny team name."""
@@ -155,3 +213,20 @@ def generate_multiple_team_names(count: int) -> list[str]:
         names.add(generate_team_name())
 
     return list(names)
+
+
+def generate_themed_team_names(theme: str, count: int, existing_names: set[str] | None = None) -> list[str]:
+    """
+    Pick distinct team names from a theme, skipping names already in use.
+
+    Raises:
+        ValueError: If the theme is unknown or has too few unused names
+    """
+    if theme not in TEAM_NAME_THEMES:
+        raise ValueError(f"Unknown team name theme '{theme}'. Choose one of {sorted(TEAM_NAME_THEMES)}")
+
+    available = [name for name in TEAM_NAME_THEMES[theme] if name not in (existing_names or set())]
+    if count > len(available):
+        raise ValueError(f"Cannot generate {count} unique '{theme}' names. Only {len(available)} remain")
+
+    return random.sample(available, count)
@@ -0,0 +1,19 @@
+"""Soft player caps: joins past the cap still succeed, but hosts get an early warning."""
+
+from typing import Optional
+
+
+def effective_soft_cap(lobby_cap: Optional[int], default_cap: int) -> Optional[int]:
+    """
+    The soft cap that applies to a lobby, or None when it has none.
+
+    Args:
+        lobby_cap: The lobby's own cap; None falls back to the server default, 0 turns the cap off for the lobby
+        default_cap: LOBBY_SOFT_PLAYER_CAP; 0 means no default cap
+    """
+    cap = default_cap if lobby_cap is None else lobby_cap
+    return cap if cap > 0 else None
+
+
+def is_over_soft_cap(player_count: int, cap: Optional[int]) -> bool:
+    return cap is not None and player_count > cap
@@ -0,0 +1,120 @@
+"""CSV and JSON export of a lobby's per-team and per-player stats, for organizers archiving a game night."""
+
+import csv
+import io
+from datetime import datetime
+from typing import Iterable, Iterator, Optional
+
+from pydantic import BaseModel
+
+from backend.utils.roster import spreadsheet_safe
+from backend.websocket.events import PrizeAward
+
+RESULTS_COLUMNS = [
+    "scope",
+    "name",
+    "team",
+    "words_solved",
+    "guesses",
+    "hints",
+    "time_seconds",
+    "total_points",
+    "rounds_played",
+    "rounds_won",
+]
+
+# Where a prize row puts the award's value
+PRIZE_VALUE_COLUMNS = {"top_team": "total_points", "fastest_rung": "time_seconds", "best_streak": "words_solved"}
+
+
+class TeamStatsRow(BaseModel):
+    name: str
+    words_solved: int
+    guesses: int
+    hints: int  # Pace keeper hints granted to the team
+    time_seconds: int  # Summed over the rounds the team finished
+    total_points: int  # Including manual score adjustments
+    rounds_played: int
+    rounds_won: int
+
+
+class PlayerStatsRow(BaseModel):
+    name: str
+    team: Optional[str]
+    words_solved: int
+    guesses: int
+
+
+class ResultsExport(BaseModel):
+    lobby_id: int
+    lobby_code: str
+    lobby_name: str
+    exported_at: datetime
+    teams: list[TeamStatsRow]
+    players: list[PlayerStatsRow]
+    prizes: list[PrizeAward] = []  # Contest mode only
+
+
+def prize_label(award: PrizeAward) -> str:
+    if award.prize == "top_team":
+        return f"Top team #{award.place}"
+    if award.prize == "fastest_rung":
+        return f"Fastest rung ({award.player_name})" if award.player_name else "Fastest rung"
+    return "Best streak"
+
+
+def iter_results_csv(
+    teams: Iterable[TeamStatsRow], players: Iterable[PlayerStatsRow], prizes: Iterable[PrizeAward] = ()
+) -> Iterator[str]:
+    """
+    One CSV line at a time: a "team" row per team, then a "player" row per player with the team-only cells empty.
+
+    Contest-mode awards follow as "prize" rows, with the award's value in the matching column: total_points for
+    top teams, time_seconds for the fastest rung and words_solved for the best streak.
+    """
+    buffer = io.StringIO()
+    writer = csv.writer(buffer, lineterminator="\n")
+
+    def line(values: list) -> str:
+        buffer.seek(0)
+        buffer.truncate()
+        writer.writerow(values)
+        return buffer.getvalue()
+
+    yield line(RESULTS_COLUMNS)
+    for team in teams:
+        yield line(
+            [
+                "team",
+                spreadsheet_safe(team.name),
+                "",
+                team.words_solved,
+                team.guesses,
+                team.hints,
+                team.time_seconds,
+                team.total_points,
+                team.rounds_played,
+                team.rounds_won,
+            ]
+        )
+    for player in players:
+        yield line(
+            [
+                "player",
+                spreadsheet_safe(player.name),
+                spreadsheet_safe(player.team or ""),
+                player.words_solved,
+                player.guesses,
+                "",
+                "",
+                "",
+                "",
+                "",
+            ]
+        )
+    for award in prizes:
+        cells = dict.fromkeys(RESULTS_COLUMNS, "")
+        cells.update(scope="prize", name=spreadsheet_safe(prize_label(award)), team=spreadsheet_safe(award.team_name))
+        value = int(award.value) if award.value == int(award.value) else award.value
+        cells[PRIZE_VALUE_COLUMNS.get(award.prize, "total_points")] = value
+        yield line([cells[column] for column in RESULTS_COLUMNS])
@@ -0,0 +1,42 @@
+"""CSV export of a lobby's roster, for hosts compiling attendance after a session."""
+
+import csv
+import io
+from datetime import datetime
+from typing import Iterable, Optional
+
+from pydantic import BaseModel
+
+ROSTER_COLUMNS = ["name", "team", "joined_at", "connection_status", "guesses_made"]
+
+# Spreadsheet apps run cells starting with these as formulas, and player names are free text
+FORMULA_PREFIXES = ("=", "+", "-", "@", "\t", "\r")
+
+
+class RosterRow(BaseModel):
+    name: str
+    team: Optional[str]
+    joined_at: datetime
+    is_connected: bool
+    guesses_made: int
+
+
+def spreadsheet_safe(value: str) -> str:
+    return f"'{value}" if value.startswith(FORMULA_PREFIXES) else value
+
+
+def build_roster_csv(rows: Iterable[RosterRow]) -> str:
+    buffer = io.StringIO()
+    writer = csv.writer(buffer, lineterminator="\n")
+    writer.writerow(ROSTER_COLUMNS)
+    for row in rows:
+        writer.writerow(
+            [
+                spreadsheet_safe(row.name),
+                spreadsheet_safe(row.team or ""),
+                row.joined_at.isoformat(),
+                "connected" if row.is_connected else "disconnected",
+                row.guesses_made,
+            ]
+        )
+    return buffer.getvalue()
@@ -0,0 +1,72 @@
+"""
+Timezone-aware display data for lobby schedules.
+
+Times are stored in UTC; each payload also carries the lobby's local time and a readable string like
+"Fri, Oct 16, 7:00 PM EDT" so players in other timezones know which clock the host means.
+"""
+
+from datetime import datetime, timezone
+from typing import Optional
+from zoneinfo import ZoneInfo, ZoneInfoNotFoundError
+
+from pydantic import BaseModel
+
+from backend.database.models import DEFAULT_TIMEZONE, Lobby
+
+
+class ScheduledTime(BaseModel):
+    utc: str  # ISO 8601 in UTC
+    local: str  # ISO 8601 with the lobby timezone's offset
+    display: str  # e.g. "Fri, Oct 16, 7:00 PM EDT"
+
+
+class LobbySchedule(BaseModel):
+    timezone: str
+    opens_at: Optional[ScheduledTime] = None
+    closes_at: Optional[ScheduledTime] = None
+    starts_at: Optional[ScheduledTime] = None
+
+
+def get_zone(name: str) -> ZoneInfo:
+    """Look up an IANA timezone name such as "America/New_York"; raises ValueError when unknown."""
+    try:
+        return ZoneInfo(name)
+    except (ZoneInfoNotFoundError, ValueError):
+        raise ValueError(f"Unknown timezone: {name}")
+
+
+def as_utc(value: datetime, zone: ZoneInfo) -> datetime:
+    """Naive input is read as wall-clock time in `zone`; aware input keeps its own offset."""
+    if value.tzinfo is None:
+        value = value.replace(tzinfo=zone)
+    return value.astimezone(timezone.utc)
+
+
+def format_display(local: datetime) -> str:
+    # Built by hand because strftime's unpadded day/hour flags aren't portable
+    hour = local.hour % 12 or 12
+    return f"{local:%a, %b} {local.day}, {hour}:{local:%M %p} {local.tzname()}"
+
+
+def scheduled_time(value: Optional[datetime], zone: ZoneInfo) -> Optional[ScheduledTime]:
+    if value is None:
+        return None
+    # SQLite hands datetimes back naive; they were stored as UTC
+    utc = value.replace(tzinfo=timezone.utc) if value.tzinfo is None else value.astimezone(timezone.utc)
+    local = utc.astimezone(zone)
+    return ScheduledTime(utc=utc.isoformat(), local=local.isoformat(), display=format_display(local))
+
+
+def build_lobby_schedule(lobby: Lobby) -> LobbySchedule:
+    timezone_name = lobby.timezone
+    try:
+        zone = get_zone(timezone_name)
+    except ValueError:
+        # A timezone dropped from the system tz database shouldn't break lobby payloads
+        timezone_name, zone = DEFAULT_TIMEZONE, ZoneInfo(DEFAULT_TIMEZONE)
+    return LobbySchedule(
+        timezone=timezone_name,
+        opens_at=scheduled_time(lobby.opens_at, zone),
+        closes_at=scheduled_time(lobby.closes_at, zone),
+        starts_at=scheduled_time(lobby.starts_at, zone),
+    )
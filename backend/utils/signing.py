@@ -0,0 +1,78 @@
+"""HMAC-signed, expiring tokens for public read-only links."""
+
+import base64
+import hashlib
+import hmac
+import json
+import time
+from typing import Any, Dict, Optional
+
+
+class InvalidTokenError(ValueError):
+    """Raised when a token is malformed, tampered with, or expired."""
+
+
+def _b64encode(data: bytes) -> str:
+    return base64.urlsafe_b64encode(data).rstrip(b"=").decode("ascii")
+
+
+def _b64decode(data: str) -> bytes:
+    padding = "=" * (-len(data) % 4)
+    return base64.urlsafe_b64decode(data + padding)
+
+
+def _signature(body: str, secret: str) -> str:
+    digest = hmac.new(secret.encode("utf-8"), body.encode("ascii"), hashlib.sha256).digest()
+    return _b64encode(digest)
+
+
+def sign_token(payload: Dict[str, Any], secret: str, expires_in_seconds: int, now: Optional[float] = None) -> str:
+    """
+    Create a signed token carrying the payload and an expiry timestamp.
+
+    Args:
+        payload: JSON-serializable data to embed
+        secret: Signing secret
+        expires_in_seconds: Token lifetime
+        now: Override for the current unix time (testing)
+
+    Returns:
+        URL-safe token string
+    """
+    issued_at = now if now is not None else time.time()
+    body = _b64encode(
+        json.dumps({**payload, "exp": int(issued_at + expires_in_seconds)}, separators=(",", ":")).encode("utf-8")
+    )
+    return f"{body}.{_signature(body, secret)}"
+
+
+def verify_token(token: str, secret: str, now: Optional[float] = None) -> Dict[str, Any]:
+    """
+    Verify a token's signature and expiry and return its payload.
+
+    Raises:
+        InvalidTokenError: If the token is malformed, has a bad signature, or has expired
+    """
+    try:
+        body, signature = token.split(".", 1)
+    except ValueError:
+        raise InvalidTokenError("Malformed token")
+
+    try:
+        valid = hmac.compare_digest(signature, _signature(body, secret))
+    except (UnicodeEncodeError, TypeError):
+        # Tokens arrive in URLs, so anything outside ASCII can't be one we signed
+        raise InvalidTokenError("Malformed token")
+    if not valid:
+        raise InvalidTokenError("Invalid token signature")
+
+    try:
+        payload = json.loads(_b64decode(body))
+    except (ValueError, UnicodeDecodeError):
+        raise InvalidTokenError("Malformed token payload")
+
+    current_time = now if now is not None else time.time()
+    if not isinstance(payload, dict) or payload.get("exp", 0) < current_time:
+        raise InvalidTokenError("Token has expired")
+
+    return payload
@@ -0,0 +1,27 @@
+"""Team colors, handed out from TEAM_COLOR_PALETTE and stored on the team so every device shows the same one."""
+
+import re
+from collections import Counter
+from typing import Iterable, List, Optional, Sequence
+
+HEX_COLOR = re.compile(r"^#[0-9a-fA-F]{6}$")
+
+
+def is_hex_color(value: str) -> bool:
+    return HEX_COLOR.match(value) is not None
+
+
+def pick_team_colors(used_colors: Iterable[Optional[str]], count: int, palette: Sequence[str]) -> List[str]:
+    """
+    Colors for `count` new teams in a lobby whose teams already use `used_colors`.
+
+    Each pick is the palette color fewest teams have, earliest in the palette on ties: unused colors go first, and
+    once every color is taken they're shared as evenly as possible.
+    """
+    usage = Counter(color for color in used_colors if color in palette)
+    picks = []
+    for _ in range(count):
+        color = min(palette, key=lambda candidate: (usage[candidate], palette.index(candidate)))
+        usage[color] += 1
+        picks.append(color)
+    return picks
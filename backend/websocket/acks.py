@@ -0,0 +1,139 @@
+"""
+Acknowledgements for the events players can't afford to miss, like their team's game starting or being kicked.
+
+Sleepy phones often keep a socket that looks open but drops what's sent on it, so a player can sit in the lobby
+after everyone else has started. Clients opt in by connecting with ?acks=true. Critical events then carry
+"ack_required": true next to their "seq", and the client answers {"action": "ack", "seq": N}. Until it does, the
+event is resent every WS_ACK_TIMEOUT_SECONDS while the player is connected (at most WS_ACK_MAX_RESENDS times) and
+whenever they reconnect, so clients should ignore a seq they've already handled. A kicked player's socket is closed
+straight after the kick, so theirs only comes again if the phone reconnects without having seen it.
+"""
+
+import asyncio
+import time
+from dataclasses import dataclass
+from typing import Callable, Dict, List, Optional, Tuple
+
+from backend.custom_logging import websocket_logger
+from backend.settings import settings
+from backend.websocket.prepared import PreparedEvent
+
+_ack_task: Optional[asyncio.Task] = None
+
+
+@dataclass
+class PendingAck:
+    lobby_id: int
+    event: PreparedEvent
+    sent_at: float
+    resends: int = 0
+
+
+class AckTracker:
+    """Critical events each opted-in player hasn't acknowledged yet, keyed by their seq."""
+
+    def __init__(self):
+        self.opted_in: Dict[str, int] = {}  # player_session_id -> lobby_id
+        self.pending: Dict[str, Dict[int, PendingAck]] = {}
+        self.resends = 0
+        self.expired = 0  # Given up on: out of resends, or pushed out past WS_ACK_MAX_PENDING_PER_PLAYER
+
+    def set_opt_in(self, player_session_id: str, lobby_id: int, enabled: bool):
+        if enabled:
+            self.opted_in[player_session_id] = lobby_id
+        else:
+            self.opted_in.pop(player_session_id, None)
+            self.pending.pop(player_session_id, None)
+
+    def track(self, player_session_id: str, lobby_id: int, event: PreparedEvent, now: float):
+        """Expect an ack for an already numbered event, if the player opted in."""
+        if self.opted_in.get(player_session_id) != lobby_id:
+            return
+        seq = event.data["seq"]
+        pending = self.pending.setdefault(player_session_id, {})
+        pending[seq] = PendingAck(lobby_id, event, now)
+        # A client that never acks mustn't pile up events forever; seqs only grow, so the smallest is the oldest
+        while settings.WS_ACK_MAX_PENDING_PER_PLAYER > 0 and len(pending) > settings.WS_ACK_MAX_PENDING_PER_PLAYER:
+            del pending[min(pending)]
+            self.expired += 1
+
+    def ack(self, player_session_id: str, seq: int) -> bool:
+        """Returns whether the seq was waiting for an ack."""
+        pending = self.pending.get(player_session_id, {})
+        acked = pending.pop(seq, None) is not None
+        if not pending:
+            self.pending.pop(player_session_id, None)
+        return acked
+
+    def pending_for(self, player_session_id: str) -> List[PendingAck]:
+        return [self.pending[player_session_id][seq] for seq in sorted(self.pending.get(player_session_id, {}))]
+
+    def due(self, now: float, is_connected: Callable[[str], bool]) -> List[Tuple[str, PendingAck]]:
+        """
+        Overdue events for connected players, counted as resent; ones out of resends are dropped instead.
+
+        Events for disconnected players wait for them to reconnect without using up resends.
+        """
+        due = []
+        for player_session_id, pending in list(self.pending.items()):
+            if not is_connected(player_session_id):
+                continue
+            for seq, entry in list(pending.items()):
+                if now - entry.sent_at < settings.WS_ACK_TIMEOUT_SECONDS:
+                    continue
+                if entry.resends >= settings.WS_ACK_MAX_RESENDS:
+                    websocket_logger.warning(
+                        f"Giving up on ack for seq={seq} from player_session_id={player_session_id}"
+                    )
+                    del pending[seq]
+                    self.expired += 1
+                    continue
+                entry.resends += 1
+                entry.sent_at = now
+                self.resends += 1
+                due.append((player_session_id, entry))
+            if not pending:
+                del self.pending[player_session_id]
+        return due
+
+    def unacked_count(self, player_session_id: Optional[str] = None) -> int:
+        if player_session_id is not None:
+            return len(self.pending.get(player_session_id, {}))
+        return sum(len(pending) for pending in self.pending.values())
+
+    def forget_lobby(self, lobby_id: int):
+        for player_session_id in [sid for sid, opted_lobby in self.opted_in.items() if opted_lobby == lobby_id]:
+            self.opted_in.pop(player_session_id)
+            self.pending.pop(player_session_id, None)
+
+
+async def ack_tick(now: float):
+    from backend.websocket.managers import lobby_websocket_manager
+
+    for player_session_id, entry in lobby_websocket_manager.acks.due(now, lobby_websocket_manager.is_player_connected):
+        await lobby_websocket_manager.resend(entry.lobby_id, player_session_id, entry.event)
+
+
+async def ack_task():
+    while True:
+        await asyncio.sleep(max(settings.WS_ACK_TIMEOUT_SECONDS / 2, 0.5))
+        try:
+            await ack_tick(time.monotonic())
+        except Exception as e:
+            websocket_logger.exception(f"Error resending unacknowledged events: {e}")
+
+
+def start_ack_resender():
+    global _ack_task
+    if settings.WS_ACK_TIMEOUT_SECONDS <= 0:
+        return
+    _ack_task = asyncio.create_task(ack_task())
+    websocket_logger.info("Started ack resend background task")
+
+
+def stop_ack_resender():
+    global _ack_task
+    if _ack_task:
+        _ack_task.cancel()
+        _ack_task = None
+        websocket_logger.info("Stopped ack resend background task")
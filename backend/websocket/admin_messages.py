@@ -0,0 +1,63 @@
+"""
+Messages admins send over their websocket, told apart by "action".
+
+AdminWebSocketManager.handle_message parses every frame into an AdminMessage and routes it; frames that
+don't match are logged and dropped.
+"""
+
+from typing import Annotated, Literal, Union
+
+from pydantic import BaseModel, Field, TypeAdapter
+
+
+class SubscribeLobbyMessage(BaseModel):
+    action: Literal["subscribe_lobby"]
+    lobby_id: int
+
+
+class UnsubscribeLobbyMessage(BaseModel):
+    action: Literal["unsubscribe_lobby"]
+    lobby_id: int
+
+
+class PresenceMessage(BaseModel):
+    """Which team the admin is editing in a lobby; None once they stop."""
+
+    action: Literal["presence"]
+    lobby_id: int
+    team_id: int | None = None
+
+
+class RequestLobbyStateMessage(BaseModel):
+    """Ask for a full LobbyInfo snapshot, answered with a lobby_state event to this admin only."""
+
+    action: Literal["request_lobby_state"]
+    lobby_id: int
+
+
+class AdminPongMessage(BaseModel):
+    """Answer to the server's keepalive ping."""
+
+    action: Literal["pong"]
+
+
+AdminMessage = Annotated[
+    Union[
+        SubscribeLobbyMessage,
+        UnsubscribeLobbyMessage,
+        PresenceMessage,
+        RequestLobbyStateMessage,
+        AdminPongMessage,
+    ],
+    Field(discriminator="action"),
+]
+
+_admin_message_adapter = TypeAdapter(AdminMessage)
+
+
+def parse_admin_message(data: dict) -> AdminMessage:
+    """
+    Raises:
+        pydantic.ValidationError: Unknown action, or fields missing/invalid for the action
+    """
+    return _admin_message_adapter.validate_python(data)
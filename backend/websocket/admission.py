@@ -0,0 +1,93 @@
+"""
+Checks made before a websocket upgrade is accepted.
+
+Upgrades used to be checked only for a valid token or session, and nothing bounded how many sockets one address
+could hold open. Now each address may hold at most WS_MAX_CONNECTIONS_PER_IP sockets (0 for no cap; keep it
+generous, a whole venue can share one address), and sockets for a lobby that doesn't exist or whose schedule has
+closed are refused before anything else is looked at. A finished round isn't a reason to refuse: the host can start another from there.
+
+Refusals go out as plain HTTP responses (404, 410, 429) through the websocket denial response extension, or as a
+close before accepting, which clients see as a 403, on servers without it.
+"""
+
+from datetime import datetime, timezone
+from functools import wraps
+from typing import Dict, Optional, Tuple
+
+from fastapi import WebSocket
+from fastapi.responses import JSONResponse
+from sqlmodel import Session
+
+from backend.custom_logging import websocket_logger
+from backend.database.models import Lobby
+from backend.in_memory_state import on_reset
+from backend.settings import settings
+
+
+class ConnectionsPerIp:
+    def __init__(self):
+        self.open: Dict[str, int] = {}
+
+    def acquire(self, ip: str) -> bool:
+        """Count a new socket from ip, unless that would go past WS_MAX_CONNECTIONS_PER_IP."""
+        count = self.open.get(ip, 0)
+        if settings.WS_MAX_CONNECTIONS_PER_IP and count >= settings.WS_MAX_CONNECTIONS_PER_IP:
+            return False
+        self.open[ip] = count + 1
+        return True
+
+    def release(self, ip: str):
+        count = self.open.get(ip, 0) - 1
+        if count > 0:
+            self.open[ip] = count
+        else:
+            self.open.pop(ip, None)
+
+
+connections_per_ip = ConnectionsPerIp()
+on_reset(connections_per_ip.__init__)
+
+
+def client_ip(websocket: WebSocket) -> str:
+    return websocket.client.host if websocket.client else "unknown"
+
+
+def lobby_refusal(db: Session, lobby_id: int, now: Optional[datetime] = None) -> Optional[Tuple[int, str]]:
+    """The HTTP status and reason to refuse a socket for lobby_id with, or None if it may connect."""
+    lobby = db.get(Lobby, lobby_id)
+    if not lobby:
+        return 404, "Lobby not found"
+    closes_at = lobby.closes_at
+    if closes_at and closes_at.tzinfo is None:
+        closes_at = closes_at.replace(tzinfo=timezone.utc)  # SQLite hands datetimes back naive; stored as UTC
+    if closes_at and closes_at <= (now or datetime.now(tz=timezone.utc)):
+        return 410, "Lobby has closed"
+    return None
+
+
+async def refuse(websocket: WebSocket, status_code: int, reason: str):
+    """Turn the upgrade down with an HTTP response, before the socket is accepted."""
+    websocket_logger.warning(f"Refused websocket upgrade: path={websocket.url.path} status={status_code} {reason}")
+    try:
+        await websocket.send_denial_response(JSONResponse({"detail": reason}, status_code=status_code))
+    except RuntimeError:
+        # Server without the denial response extension
+        await websocket.close(code=1008, reason=reason)
+
+
+def limit_connections_per_ip(endpoint):
+    """Refuse the upgrade with 429 when the client's address already holds its share of sockets."""
+
+    @wraps(endpoint)
+    async def wrapper(*args, **kwargs):
+        websocket: WebSocket = kwargs["websocket"]
+        ip = client_ip(websocket)
+        if not connections_per_ip.acquire(ip):
+            await refuse(websocket, 429, "Too many connections from this address")
+            return
+        try:
+            await endpoint(*args, **kwargs)
+        finally:
+            connections_per_ip.release(ip)
+
+    return wrapper
@@ -1,57 +1,146 @@
 from backend.custom_logging import websocket_logger
-from fastapi import APIRouter, Depends, WebSocket, WebSocketDisconnect
+from fastapi import APIRouter, Query, WebSocket, WebSocketDisconnect
 
-from backend.dependencies import check_admin_token_query
+from backend.database import get_session_context
+from backend.shutdown import SERVICE_RESTART, shutdown_state
+from backend.spectators import spectator_store
+from backend.websocket.admission import limit_connections_per_ip, lobby_refusal, refuse
+from backend.websocket.connect_tokens import admin_session_for_connect_token, player_for_connect_token
 from backend.websocket.managers import admin_web_socket_manager, lobby_websocket_manager
 
 router = APIRouter()
 
 
 @router.websocket("/admin/{web_session_id}")
+@limit_connections_per_ip
 async def admin_websocket(
     websocket: WebSocket,
     web_session_id: str,
-    is_admin: bool = Depends(check_admin_token_query),
+    token: str = Query(..., description="A connect token from POST /api/admin/ws-token"),
 ):
-    websocket_logger.info(f"Admin websocket endpoint invoked: web_session_id={web_session_id} is_admin={is_admin}")
+    websocket_logger.info(f"Admin websocket endpoint invoked: web_session_id={web_session_id}")
+    if shutdown_state.draining:
+        await websocket.close(code=SERVICE_RESTART, reason="Server is shutting down")
+        return
+    ip = websocket.client.host if websocket.client else None
+    if not admin_session_for_connect_token(token, web_session_id, ip=ip):
+        websocket_logger.warning(f"Rejected admin websocket, invalid connect token: web_session_id={web_session_id}")
+        await websocket.close(code=1008, reason="Connect token is not valid")
+        return
+
     try:
         await admin_web_socket_manager.connect(websocket, web_session_id)
     except Exception:
         websocket_logger.exception(f"Failed to establish admin websocket connection: web_session_id={web_session_id}")
         return
 
+    # The listener also returns on errors it only logs, so the socket is unregistered however it ends
     try:
         await admin_web_socket_manager.continuous_listening(websocket, web_session_id)
     except WebSocketDisconnect:
         websocket_logger.info(f"Admin websocket disconnected (WebSocketDisconnect): web_session_id={web_session_id}")
-        await admin_web_socket_manager.disconnect(web_session_id)
     except Exception:
         websocket_logger.exception(f"Unexpected error in admin websocket: web_session_id={web_session_id}")
-        await admin_web_socket_manager.disconnect(web_session_id)
+    await admin_web_socket_manager.disconnect(web_session_id, websocket=websocket)
 
 
-@router.websocket("/lobby/{lobby_id}/player/{player_session_id}")
-async def lobby_websocket(websocket: WebSocket, lobby_id: int, player_session_id: str):
-    websocket_logger.info(
-        f"Player websocket endpoint invoked: lobby_id={lobby_id} player_session_id={player_session_id}"
-    )
+@router.websocket("/lobby/{lobby_id}/player")
+@limit_connections_per_ip
+async def lobby_websocket(
+    websocket: WebSocket,
+    lobby_id: int,
+    token: str = Query(..., description="A connect token from POST /api/lobby/ws-token"),
+    last_seq: int | None = None,
+    acks: bool = False,
+    diff: bool = False,
+):
+    """
+    Pass last_seq (the highest "seq" seen before dropping) when reconnecting to have missed events replayed.
+
+    Pass acks=true to acknowledge critical events (see backend/websocket/acks.py), and diff=true to get
+    snapshot-style events as merge patches (see backend/websocket/diffs.py).
+    """
+    websocket_logger.info(f"Player websocket endpoint invoked: lobby_id={lobby_id}")
+    if shutdown_state.draining:
+        await websocket.close(code=SERVICE_RESTART, reason="Server is shutting down")
+        return
+    async with get_session_context() as db:
+        refusal = lobby_refusal(db, lobby_id)
+        player = None if refusal else player_for_connect_token(db, token, lobby_id)
+    if refusal:
+        await refuse(websocket, *refusal)
+        return
+    if not player:
+        websocket_logger.warning(f"Rejected player websocket with an invalid connect token: lobby_id={lobby_id}")
+        await websocket.close(code=1008, reason="Connect token is not valid")
+        return
+    player_session_id = player.session_id
+
     try:
-        await lobby_websocket_manager.connect(websocket, lobby_id=lobby_id, player_session_id=player_session_id)
+        await lobby_websocket_manager.connect(
+            websocket, lobby_id=lobby_id, player_session_id=player_session_id, acks=acks, diff=diff
+        )
     except Exception:
         websocket_logger.exception(
             f"Failed to establish player websocket: lobby_id={lobby_id} player_session_id={player_session_id}"
         )
         return
 
+    if last_seq is not None:
+        await lobby_websocket_manager.resume(lobby_id, player_session_id, last_seq)
+    if acks:
+        await lobby_websocket_manager.resend_unacked(lobby_id, player_session_id)
+
+    reason = None
     try:
         await lobby_websocket_manager.continuous_listening(websocket, lobby_id, player_session_id)
-    except WebSocketDisconnect:
+    except WebSocketDisconnect as e:
         websocket_logger.info(
             f"Player websocket disconnected (WebSocketDisconnect): lobby_id={lobby_id} player_session_id={player_session_id}"
         )
-        await lobby_websocket_manager.disconnect(lobby_id=lobby_id, player_session_id=player_session_id)
+        reason = e.reason or None
     except Exception:
         websocket_logger.exception(
             f"Unexpected error in player websocket: lobby_id={lobby_id} player_session_id={player_session_id}"
         )
-        await lobby_websocket_manager.disconnect(lobby_id=lobby_id, player_session_id=player_session_id)
+    await lobby_websocket_manager.disconnect(
+        lobby_id=lobby_id, player_session_id=player_session_id, reason=reason, websocket=websocket
+    )
+
+
+@router.websocket("/lobby/{lobby_id}/spectator/{spectator_session_id}")
+@limit_connections_per_ip
+async def spectator_websocket(websocket: WebSocket, lobby_id: int, spectator_session_id: str):
+    """Read-only: lobby-wide broadcasts, with a session from POST /api/lobby/spectate/{lobby_code}."""
+    websocket_logger.info(
+        f"Spectator websocket endpoint invoked: lobby_id={lobby_id} spectator_session_id={spectator_session_id}"
+    )
+    if shutdown_state.draining:
+        await websocket.close(code=SERVICE_RESTART, reason="Server is shutting down")
+        return
+    async with get_session_context() as db:
+        refusal = lobby_refusal(db, lobby_id)
+    if refusal:
+        await refuse(websocket, *refusal)
+        return
+    session = spectator_store.get(spectator_session_id)
+    if not session or session.lobby_id != lobby_id:
+        websocket_logger.warning(f"Rejected spectator websocket for invalid session: lobby_id={lobby_id}")
+        await websocket.close(code=1008, reason="Spectator session is not valid")
+        return
+
+    try:
+        await lobby_websocket_manager.connect_spectator(websocket, lobby_id, spectator_session_id)
+    except Exception:
+        websocket_logger.exception(f"Failed to establish spectator websocket: lobby_id={lobby_id}")
+        return
+
+    try:
+        await lobby_websocket_manager.listen_to_spectator(websocket, lobby_id, spectator_session_id)
+    except WebSocketDisconnect:
+        websocket_logger.info(f"Spectator websocket disconnected: spectator_session_id={spectator_session_id}")
+    except Exception:
+        websocket_logger.exception(
+            f"Unexpected error in spectator websocket: spectator_session_id={spectator_session_id}"
+        )
+    await lobby_websocket_manager.disconnect_spectator(lobby_id, spectator_session_id)
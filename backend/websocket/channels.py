@@ -0,0 +1,168 @@
+"""
+Per-lobby broadcast channels: each player connection gets a bounded outbox drained by its own forwarding task.
+
+Broadcasts used to await every player's socket in turn, so one phone on a bad connection held up everyone after it
+and the request that triggered the broadcast. Publishing now only queues the event on each connection's outbox,
+and the connection's forwarder writes it out as fast as that client takes it. An outbox holds at most
+WS_OUTBOX_SIZE events. A client that falls that far behind is lagging: its backlog is dropped and its socket closed
+with 1013 (try again later), and it reconnects with ?last_seq=N to catch up from the replay buffer (or is told to
+resync if even that has moved on).
+"""
+
+import asyncio
+from dataclasses import dataclass
+from typing import Any, Dict, List, Optional
+
+from backend.custom_logging import websocket_logger
+from backend.websocket.delivery import DeliveryFailure, DeliveryReport, deliver, delivery_stats
+
+TRY_AGAIN_LATER = 1013
+
+
+@dataclass(frozen=True)
+class Outgoing:
+    event_type: str
+    text: str
+
+
+@dataclass(frozen=True)
+class Closing:
+    code: int
+    reason: str
+
+
+class Outbox:
+    """One connection's queue of outgoing events and the task writing them to its socket."""
+
+    def __init__(self, key: str, websocket: Any, capacity: int):
+        self.key = key
+        self.websocket = websocket
+        self.capacity = capacity
+        # Unbounded as far as asyncio is concerned: offer() enforces the capacity, so a close can always be queued
+        self.queue: asyncio.Queue[Outgoing | Closing] = asyncio.Queue()
+        self.closed_reason: Optional[str] = None
+        self.task = asyncio.get_running_loop().create_task(self.forward())
+        self.closer: Optional[asyncio.Task] = None
+
+    @property
+    def stale(self) -> bool:
+        """Forwarder stopped without being closed, e.g. its event loop ended; a new outbox should take over."""
+        return self.closed_reason is None and self.task.done()
+
+    def offer(self, report: DeliveryReport, text: str) -> bool:
+        """Queue one copy of the report's event without waiting; a full outbox marks the connection as lagging."""
+        report.attempted += 1
+        if self.closed_reason is None and self.queue.qsize() >= self.capacity:
+            self.lag()
+        if self.closed_reason is None:
+            self.queue.put_nowait(Outgoing(report.event_type, text))
+            report.succeeded += 1
+            return True
+        failure = DeliveryFailure(self.key, self.closed_reason or "closed")
+        report.failures.append(failure)
+        delivery_stats.record(report.event_type, failure)
+        return False
+
+    def close_after_pending(self, code: int, reason: str) -> bool:
+        """Close the socket once everything already queued has been written, e.g. after a kick notice."""
+        if self.closed_reason is not None:
+            return False
+        self.queue.put_nowait(Closing(code, reason))
+        return True
+
+    async def forward(self):
+        while True:
+            item = await self.queue.get()
+            try:
+                if isinstance(item, Closing):
+                    self.stop(f"closed: {item.reason}")
+                    await self.websocket.close(code=item.code, reason=item.reason)
+                    return
+                report = DeliveryReport(item.event_type)
+                if not await deliver(report, self.key, self.websocket, item.text):
+                    websocket_logger.warning(f"Send failed, stopping forwarder: {report.summary()}")
+                    self.stop(report.failures[0].reason)
+                    return
+            except Exception:
+                websocket_logger.debug(f"Websocket already closed: {self.key}")
+                return
+            finally:
+                self.queue.task_done()
+
+    def lag(self):
+        backlog = self.queue.qsize()
+        websocket_logger.warning(f"{self.key} is lagging {backlog} events behind, disconnecting it to catch up")
+        self.stop(f"lagging: {backlog} events behind")
+        self.closer = asyncio.get_running_loop().create_task(self.close_lagging())
+
+    async def close_lagging(self):
+        try:
+            await self.websocket.close(code=TRY_AGAIN_LATER, reason="Too far behind, reconnect to catch up")
+        except Exception:
+            websocket_logger.debug(f"Lagging websocket already closed: {self.key}")
+
+    def stop(self, reason: str):
+        """Stop forwarding and drop the backlog; the socket itself is left to whoever owns it."""
+        self.closed_reason = reason
+        while not self.queue.empty():
+            self.queue.get_nowait()
+            self.queue.task_done()
+        if self.task is not asyncio.current_task():
+            self.task.cancel()
+
+    async def flush(self):
+        if not self.task.done():
+            await self.queue.join()
+        if self.closer:
+            await self.closer
+
+
+class LobbyChannel:
+    """A lobby's outboxes, keyed by connection, e.g. "player:<session_id>"."""
+
+    def __init__(self, capacity: int):
+        self.capacity = capacity
+        self.outboxes: Dict[str, Outbox] = {}
+        # Outboxes let go of with a close still queued (kicks), kept until they finish so flush() covers them
+        self.leaving: List[Outbox] = []
+
+    def outbox(self, key: str, websocket: Any) -> Outbox:
+        """The connection's outbox, started on first use and replaced if the socket changed."""
+        outbox = self.outboxes.get(key)
+        if outbox is None or outbox.websocket is not websocket or outbox.stale:
+            if outbox is not None:
+                outbox.stop("replaced")
+            outbox = Outbox(key, websocket, self.capacity)
+            self.outboxes[key] = outbox
+        return outbox
+
+    def unsubscribe(self, key: str):
+        outbox = self.outboxes.pop(key, None)
+        if outbox is not None:
+            outbox.stop("disconnected")
+
+    def release(self, key: str) -> Optional[Outbox]:
+        """Stop tracking a connection but let its outbox finish what's queued."""
+        outbox = self.outboxes.pop(key, None)
+        if outbox is not None:
+            self.leaving.append(outbox)
+        return outbox
+
+    @property
+    def idle(self) -> bool:
+        self.leaving = [outbox for outbox in self.leaving if not outbox.task.done()]
+        return not self.outboxes and not self.leaving
+
+    def close(self):
+        for outbox in list(self.outboxes.values()) + self.leaving:
+            outbox.stop("channel closed")
+        self.outboxes.clear()
+        self.leaving.clear()
+
+    def backlog(self, key: str) -> int:
+        outbox = self.outboxes.get(key)
+        return outbox.queue.qsize() if outbox else 0
+
+    async def flush(self):
+        """Wait until every outbox has written what's queued so far."""
+        await asyncio.gather(*(outbox.flush() for outbox in list(self.outboxes.values()) + self.leaving))
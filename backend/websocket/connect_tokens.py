@@ -0,0 +1,59 @@
+"""
+Short-lived tokens for opening a player or admin websocket.
+
+The session id authenticates every REST call for as long as the player stays in the lobby, so it shouldn't sit in a
+websocket URL, where proxies and access logs record it. Clients trade it for a connect token with
+POST /api/lobby/ws-token and open /ws/lobby/{lobby_id}/player?token=... within WS_TOKEN_TTL_SECONDS. The token is
+signed like share links but only names the player by id; the session id is looked up again when it's used.
+
+Admins do the same with their session token: POST /api/admin/ws-token, then /ws/admin/{session_id}?token=... The
+token names the admin session by its public id, and only works while that session hasn't been revoked or expired.
+"""
+
+from typing import Optional
+
+from sqlmodel import Session
+
+from backend.admin_sessions import AdminSession, admin_session_store
+from backend.database import Player
+from backend.settings import settings
+from backend.utils.signing import InvalidTokenError, sign_token, verify_token
+
+WS_TOKEN_SCOPE = "ws"
+ADMIN_WS_TOKEN_SCOPE = "admin_ws"
+
+
+def issue_connect_token(player: Player) -> str:
+    payload = {"scope": WS_TOKEN_SCOPE, "player_id": player.id, "lobby_id": player.lobby_id}
+    return sign_token(payload, settings.share_secret, settings.WS_TOKEN_TTL_SECONDS)
+
+
+def player_for_connect_token(db: Session, token: str, lobby_id: int) -> Optional[Player]:
+    """The player a token was issued to, or None if it's invalid, expired, or for another lobby."""
+    try:
+        payload = verify_token(token, settings.share_secret)
+    except InvalidTokenError:
+        return None
+    if payload.get("scope") != WS_TOKEN_SCOPE or payload.get("lobby_id") != lobby_id:
+        return None
+
+    player = db.get(Player, payload.get("player_id"))
+    if not player or player.lobby_id != lobby_id:
+        return None
+    return player
+
+
+def issue_admin_connect_token(session: AdminSession) -> str:
+    payload = {"scope": ADMIN_WS_TOKEN_SCOPE, "session_id": session.session_id}
+    return sign_token(payload, settings.share_secret, settings.WS_TOKEN_TTL_SECONDS)
+
+
+def admin_session_for_connect_token(token: str, session_id: str, ip: Optional[str] = None) -> Optional[AdminSession]:
+    """The live admin session a token was issued to, or None if it's invalid, expired, or for another session."""
+    try:
+        payload = verify_token(token, settings.share_secret)
+    except InvalidTokenError:
+        return None
+    if payload.get("scope") != ADMIN_WS_TOKEN_SCOPE or payload.get("session_id") != session_id:
+        return None
+    return admin_session_store.touch(session_id, ip=ip)
@@ -0,0 +1,115 @@
+"""
+Delivery reports for websocket sends and broadcasts.
+
+Every manager send and broadcast returns a DeliveryReport: how many connections it tried, how many took the event
+and why the rest didn't. Failed sends used to be logged one at a time and otherwise dropped, so every admin socket
+in a lobby failing looked no different from one flaky phone. Each send is also tallied per event type in
+delivery_stats (GET /api/admin/connections/deliveries), and the admin routes behind critical broadcasts (game
+start, round end, kick) return their report so the host can see who didn't get it.
+
+Player copies go through their connection's outbox (see channels.py), so a player's entry in the report means the
+event was queued; a socket that fails its write stops its outbox and shows up as a failure from then on.
+"""
+
+from collections import deque
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from typing import Any, Deque, Dict, Iterable, List
+
+from backend.chaos import chaos
+from backend.in_memory_state import on_reset
+from backend.websocket.send_stats import send_stats
+
+RECENT_FAILURES = 50
+
+
+@dataclass(frozen=True)
+class DeliveryFailure:
+    connection: str  # Send stats key, e.g. "player:<session_id>"
+    reason: str
+
+
+@dataclass
+class DeliveryReport:
+    event_type: str
+    attempted: int = 0
+    succeeded: int = 0
+    failures: List[DeliveryFailure] = field(default_factory=list)
+
+    @property
+    def failed(self) -> int:
+        return len(self.failures)
+
+    def merge(self, other: "DeliveryReport") -> "DeliveryReport":
+        """Add another report's counts to this one, e.g. a lobby broadcast's admin copies; returns self."""
+        self.attempted += other.attempted
+        self.succeeded += other.succeeded
+        self.failures.extend(other.failures)
+        return self
+
+    def summary(self) -> str:
+        reasons = ", ".join(f"{failure.connection} ({failure.reason})" for failure in self.failures[:5])
+        more = f" and {self.failed - 5} more" if self.failed > 5 else ""
+        return f"{self.event_type}: {self.failed}/{self.attempted} sends failed: {reasons}{more}"
+
+
+def combine_reports(event_type: str, reports: Iterable[DeliveryReport]) -> DeliveryReport:
+    combined = DeliveryReport(event_type)
+    for report in reports:
+        combined.merge(report)
+    return combined
+
+
+def describe_failure(error: Exception) -> str:
+    return f"{type(error).__name__}: {error}" if str(error) else type(error).__name__
+
+
+@dataclass
+class EventDeliveryTotals:
+    attempted: int = 0
+    succeeded: int = 0
+    failed: int = 0
+
+
+@dataclass(frozen=True)
+class RecentFailure:
+    at: datetime
+    event_type: str
+    failure: DeliveryFailure
+
+
+class DeliveryStats:
+    """Send outcomes per event type since startup, and the latest failures."""
+
+    def __init__(self):
+        self.by_event_type: Dict[str, EventDeliveryTotals] = {}
+        self.recent_failures: Deque[RecentFailure] = deque(maxlen=RECENT_FAILURES)
+
+    def record(self, event_type: str, failure: DeliveryFailure | None = None):
+        totals = self.by_event_type.setdefault(event_type, EventDeliveryTotals())
+        totals.attempted += 1
+        if failure is None:
+            totals.succeeded += 1
+            return
+        totals.failed += 1
+        self.recent_failures.append(RecentFailure(datetime.now(timezone.utc), event_type, failure))
+
+
+delivery_stats = DeliveryStats()
+on_reset(delivery_stats.__init__)
+
+
+async def deliver(report: DeliveryReport, key: str, websocket: Any, text: str) -> bool:
+    """Send one copy of the report's event, counting the outcome in the report and in delivery_stats."""
+    report.attempted += 1
+    try:
+        if not chaos.enabled or await chaos.before_send(key, websocket):
+            await send_stats.send_text(key, websocket, text)
+    except Exception as e:
+        failure = DeliveryFailure(key, describe_failure(e))
+        report.failures.append(failure)
+        delivery_stats.record(report.event_type, failure)
+        return False
+    report.succeeded += 1
+    delivery_stats.record(report.event_type)
+    return True
@@ -0,0 +1,101 @@
+"""
+Diff mode: snapshot-style events as JSON merge patches, for players on slow connections.
+
+state_update and team_progress each carry the whole of a team's state, so on every solve a big lobby resends mostly
+unchanged payloads to every phone. Players that connect with ?diff=true get the first copy of each state in full,
+stamped with its "state_key" and "state_version", and later copies as a state_diff event: an RFC 7386 merge patch
+against the copy before, with the same key and the version it applies to. A client whose copy of that key isn't at
+base_version has missed something; it sends {"action": "diff_reset"} and every state comes in full again. Diff state
+belongs to the connection, so reconnecting (and replaying with ?last_seq=N) also starts from full copies.
+"""
+
+import json
+from dataclasses import dataclass
+from typing import Any, Dict, Optional
+
+from backend.websocket.events import StateDiffEvent
+from backend.websocket.prepared import PLAYER_AUDIENCE, PreparedEvent
+
+# Snapshot event type -> the payload field telling its states apart
+SNAPSHOT_KEYS: Dict[str, str] = {"state_update": "team_id", "team_progress": "team_id"}
+
+
+def merge_patch(old: Dict[str, Any], new: Dict[str, Any]) -> Optional[Dict[str, Any]]:
+    """
+    The RFC 7386 merge patch turning old into new, or None when there isn't one.
+
+    Merge patches use null for "remove", so a field that changes to null can't be expressed; lists are replaced whole.
+    """
+    patch: Dict[str, Any] = {}
+    for key, value in new.items():
+        if key in old and old[key] == value:
+            continue
+        if isinstance(value, dict) and isinstance(old.get(key), dict):
+            nested = merge_patch(old[key], value)
+            if nested is None:
+                return None
+            patch[key] = nested
+        elif value is None:
+            return None
+        else:
+            patch[key] = value
+    for key in old:
+        if key not in new:
+            patch[key] = None
+    return patch
+
+
+@dataclass
+class SentState:
+    version: int
+    payload: Dict[str, Any]
+
+
+class DiffTracker:
+    """The last copy of each state sent to each player connected in diff mode."""
+
+    def __init__(self):
+        self.sent: Dict[str, Dict[str, SentState]] = {}  # player_session_id -> state_key -> SentState
+        self.full = 0  # Snapshot copies sent whole to diff-mode players, since startup
+        self.patched = 0  # ...and as state_diff patches
+
+    def set_opt_in(self, player_session_id: str, enabled: bool):
+        """Start (or stop) diffing for a fresh connection, forgetting what an earlier one was sent."""
+        if enabled:
+            self.sent[player_session_id] = {}
+        else:
+            self.sent.pop(player_session_id, None)
+
+    def reset(self, player_session_id: str):
+        """Send every state in full again, for a client that lost track."""
+        if player_session_id in self.sent:
+            self.sent[player_session_id] = {}
+
+    def text_for(self, player_session_id: str, prepared: PreparedEvent) -> str:
+        """What to send the player for this event: a state_diff when possible, otherwise the event itself."""
+        text = prepared.for_audience(PLAYER_AUDIENCE)
+        states = self.sent.get(player_session_id)
+        key_field = SNAPSHOT_KEYS.get(prepared.event_type)
+        if states is None or key_field is None:
+            return text
+
+        payload = json.loads(text)
+        state_key = f"{prepared.event_type}:{payload.get(key_field)}"
+        previous = states.get(state_key)
+        patch = merge_patch(previous.payload, payload) if previous else None
+        version = previous.version + 1 if previous else 1
+        states[state_key] = SentState(version, payload)
+        if patch is None:
+            self.full += 1
+            return json.dumps({**payload, "state_key": state_key, "state_version": version})
+
+        self.patched += 1
+        event = StateDiffEvent(
+            of=prepared.event_type,
+            state_key=state_key,
+            base_version=previous.version,
+            version=version,
+            patch=patch,
+            seq=payload.get("seq"),
+        )
+        return json.dumps(event.model_dump())
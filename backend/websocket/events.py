@@ -1,4 +1,5 @@
 from enum import Enum
+from typing import Union, get_args
 
 from pydantic import BaseModel
 
@@ -15,12 +16,20 @@ class LobbyWebSocketEvents(str, Enum):
     PLAYER_KICKED = "player_kicked"
     READY_STATUS_CHANGED = "ready_status_changed"
     LOBBY_DELETED = "lobby_deleted"
+    LATE_JOIN = "late_join"
+    PREFERENCES_UPDATED = "preferences_updated"
+    PLAYER_DISCONNECTED = "player_disconnected"
+    LOBBY_MERGED = "lobby_merged"
+    HOST_CHANGED = "host_changed"
+    TEAM_UPDATED = "team_updated"
+    TEAMS_REASSIGNED = "teams_reassigned"
 
 
 class LobbyEvent(BaseModel):
     lobby_id: int
     player_session_id: str
     type: LobbyWebSocketEvents
+    roster_version: int | None = None  # Set on events that change the roster, see backend/roster.py
 
 
 class JoinedLobbyEvent(LobbyEvent):
@@ -31,6 +40,13 @@ class DisconnectedLobbyEvent(LobbyEvent):
     type: LobbyWebSocketEvents = LobbyWebSocketEvents.DISCONNECTED
 
 
+class PlayerDisconnectedEvent(LobbyEvent):
+    """A player's websocket closed (dropped, timed out or missed pings); they're still in the lobby."""
+
+    reason: str | None = None
+    type: LobbyWebSocketEvents = LobbyWebSocketEvents.PLAYER_DISCONNECTED
+
+
 class PlayerKickedEvent(LobbyEvent):
     type: LobbyWebSocketEvents = LobbyWebSocketEvents.PLAYER_KICKED
 
@@ -42,9 +58,33 @@ class TeamAssignedEvent(LobbyEvent):
 class TeamChangedEvent(LobbyEvent):
     new_team_id: int
     old_team_id: int
+    new_team_color: str | None = None
     type: LobbyWebSocketEvents = LobbyWebSocketEvents.TEAM_CHANGED
 
 
+class TeamUpdatedEvent(LobbyEvent):
+    """A team's name or color changed; player_session_id is empty, as on TeamAssignedEvent."""
+
+    team_id: int
+    team_name: str
+    team_color: str | None = None
+    type: LobbyWebSocketEvents = LobbyWebSocketEvents.TEAM_UPDATED
+
+
+class PlayerTeamMove(BaseModel):
+    player_session_id: str
+    old_team_id: int  # 0 when the player had no team
+    new_team_id: int  # 0 when the player was unassigned
+    new_team_color: str | None = None
+
+
+class TeamsReassignedEvent(LobbyEvent):
+    """Several players moved at once (PUT /api/admin/lobby/{lobby_id}/assignments); player_session_id is empty."""
+
+    moves: list[PlayerTeamMove]
+    type: LobbyWebSocketEvents = LobbyWebSocketEvents.TEAMS_REASSIGNED
+
+
 class ReadyStatusChangedEvent(LobbyEvent):
     player_id: int
     player_name: str
@@ -56,6 +96,43 @@ class LobbyDeletedEvent(LobbyEvent):
     type: LobbyWebSocketEvents = LobbyWebSocketEvents.LOBBY_DELETED
 
 
+class LobbyMergedEvent(LobbyEvent):
+    """The lobby was folded into another one; its players are now in the target lobby, under the same sessions."""
+
+    target_lobby_id: int
+    target_lobby_code: str
+    target_lobby_name: str
+    type: LobbyWebSocketEvents = LobbyWebSocketEvents.LOBBY_MERGED
+
+
+class LateJoinEvent(LobbyEvent):
+    """A player joined while a game was running and was placed on the smallest team."""
+
+    player_id: int
+    player_name: str
+    team_id: int
+    team_name: str
+    team_color: str | None = None
+    type: LobbyWebSocketEvents = LobbyWebSocketEvents.LATE_JOIN
+
+
+class PreferencesUpdatedEvent(LobbyEvent):
+    """Sent only to the player whose accessibility preferences changed."""
+
+    reduced_motion: bool
+    high_contrast: bool
+    larger_text: bool
+    type: LobbyWebSocketEvents = LobbyWebSocketEvents.PREFERENCES_UPDATED
+
+
+class HostChangedEvent(LobbyEvent):
+    """Another player is now the lobby's host (see backend/lobby_host.py); player_session_id is theirs."""
+
+    host_player_id: int
+    host_name: str
+    type: LobbyWebSocketEvents = LobbyWebSocketEvents.HOST_CHANGED
+
+
 ####################################################################
 # ? GAME EVENTS
 ####################################################################
@@ -67,10 +144,19 @@ class GameWebSocketEvents(str, Enum):
     TEAM_COMPLETED = "team_completed"
     TEAM_PLACED = "team_placed"
     GAME_WON = "game_won"
+    GAME_FINISHED = "game_finished"
+    SCORE_ADJUSTED = "score_adjusted"
     STATE_UPDATE = "state_update"
     ALREADY_SOLVED = "already_solved"
     TIMER_STARTED = "timer_started"
+    TIMER_TICK = "timer_tick"
     TIMER_EXPIRED = "timer_expired"
+    GUESS_PENDING_REVIEW = "guess_pending_review"
+    GUESS_REVIEWED = "guess_reviewed"
+    TEAM_PROGRESS = "team_progress"
+    WORD_ROLLED_BACK = "word_rolled_back"
+    PACE_HINT = "pace_hint"
+    TEAM_CHAT = "team_chat"
 
 
 class GameEvent(BaseModel):
@@ -101,6 +187,11 @@ class WordSolvedEvent(GameEvent):
     word_index: int
     word: str
     direction: str
+    revealed_count: int = 0  # Steps revealed after this solve, including the two given ones
+    rank: int | None = None  # Live rank among teams in the lobby
+    rank_delta: int = 0  # Positive when the team moved up
+    projected_points: int = 0  # Points the team would earn if the round ended now
+    points_earned: int = 0  # Change in projected points from this solve
 
 
 class DirectionChangedEvent(GameEvent):
@@ -120,9 +211,22 @@ class StateUpdateEvent(GameEvent):
 class TeamCompletedEvent(GameEvent):
     type: GameWebSocketEvents = GameWebSocketEvents.TEAM_COMPLETED
     team_name: str
+    team_color: str | None = None
     completed_at: str
 
 
+class TeamProgressEvent(GameEvent):
+    """Another team's progress, masked to the lobby's progress visibility setting."""
+
+    type: GameWebSocketEvents = GameWebSocketEvents.TEAM_PROGRESS
+    team_name: str
+    team_color: str | None = None
+    progress: float
+    solved_count: int | None
+    total_count: int | None
+    is_completed: bool
+
+
 class GameWonEvent(BaseModel):
     type: GameWebSocketEvents = GameWebSocketEvents.GAME_WON
     lobby_id: int
@@ -130,6 +234,53 @@ class GameWonEvent(BaseModel):
     winning_team_name: str
 
 
+class FinalStanding(BaseModel):
+    team_id: int
+    team_name: str
+    team_color: str | None = None
+    placement: int
+    points_earned: int
+    total_points: int  # Tournament total including this round
+    completion_percentage: float
+    time_to_complete: int | None  # Seconds, None when the team didn't finish
+    completed: bool
+
+
+class PrizeAward(BaseModel):
+    """A contest-mode award, see backend/game/prizes.py."""
+
+    prize: str  # "top_team", "fastest_rung" or "best_streak"
+    place: int = 1  # 1..N for top_team
+    team_id: int
+    team_name: str
+    player_name: str | None = None  # Who solved the fastest rung
+    value: float  # Total points, seconds for the rung, or correct guesses in a row
+
+
+class GameFinishedEvent(BaseModel):
+    """The round's final ranking, sent once it has been scored (everyone finished, or the round was ended)."""
+
+    type: GameWebSocketEvents = GameWebSocketEvents.GAME_FINISHED
+    lobby_id: int
+    round_number: int
+    winning_team_id: int | None
+    winning_team_name: str | None
+    standings: list[FinalStanding]
+    prizes: list[PrizeAward] = []  # Only in contest mode
+
+
+class ScoreAdjustedEvent(BaseModel):
+    """A host changed a team's points by hand, e.g. for a bonus challenge run away from the game."""
+
+    type: GameWebSocketEvents = GameWebSocketEvents.SCORE_ADJUSTED
+    lobby_id: int
+    team_id: int
+    team_name: str
+    delta: int
+    reason: str
+    total_points: int
+
+
 class AlreadySolvedEvent(GameEvent):
     type: GameWebSocketEvents = GameWebSocketEvents.ALREADY_SOLVED
     word_index: int
@@ -138,6 +289,7 @@ class AlreadySolvedEvent(GameEvent):
 class TeamPlacedEvent(GameEvent):
     type: GameWebSocketEvents = GameWebSocketEvents.TEAM_PLACED
     team_name: str
+    team_color: str | None = None
     placement: int
     points_earned: int  # Will be 0 until Phase 5
     completed_at: str
@@ -151,6 +303,78 @@ class RoundEndedEvent(BaseModel):
     # results will contain summary data - team placements and points
 
 
+class GameEndedEvent(BaseModel):
+    type: str = "game_ended"
+    lobby_id: int
+
+
+class PuzzleCredit(BaseModel):
+    title: str
+    author: str
+    author_handle: str | None = None
+
+
+class CreditsEvent(BaseModel):
+    """Who wrote the puzzles the lobby just played, sent after game_ended when SHOW_PUZZLE_CREDITS is on."""
+
+    type: str = "credits"
+    lobby_id: int
+    puzzles: list[PuzzleCredit]
+
+
+class RevealStepEvent(BaseModel):
+    """The next rung of the end-of-game answer reveal, shown to the whole lobby at once (see backend/game/reveal.py)."""
+
+    type: str = "reveal_step"
+    lobby_id: int
+    step_number: int  # 1-based, across every puzzle being revealed
+    total_steps: int
+    puzzle_title: str
+    puzzle_number: int
+    puzzle_count: int
+    word_index: int
+    word: str
+    explanation: str | None = None
+    next_step_in_seconds: float | None = None  # Set when the reveal is on a timer
+
+
+class RevealEndedEvent(BaseModel):
+    """The reveal is over, after its last step or because the host stopped it."""
+
+    type: str = "reveal_ended"
+    lobby_id: int
+    completed: bool  # False when the host stopped it early
+
+
+class LobbyPhaseChangedEvent(BaseModel):
+    """The lobby moved between waiting, in_progress, intermission and finished (see backend/game/lobby_state.py)."""
+
+    type: str = "lobby_phase_changed"
+    lobby_id: int
+    phase: str
+    previous_phase: str
+
+
+class IntermissionStartedEvent(BaseModel):
+    """The lobby is holding between rounds (see backend/intermission.py), showing the standings so far."""
+
+    type: str = "intermission_started"
+    lobby_id: int
+    round_number: int  # The round just played
+    standings: list[FinalStanding]
+    auto_advance_at: str | None = None  # When the next round starts on its own; None waits for the admin
+
+
+class PuzzleReplacedEvent(BaseModel):
+    """A stored puzzle the lobby was using was force-deleted and a bundled one swapped in, for admins."""
+
+    type: str = "puzzle_replaced"
+    lobby_id: int
+    deleted_title: str
+    replacement_title: str
+    restarted_team_ids: list[int]  # Teams that were mid-puzzle and start over on the replacement
+
+
 class NewRoundStartedEvent(BaseModel):
     type: str = "new_round_started"
     lobby_id: int
@@ -158,6 +382,51 @@ class NewRoundStartedEvent(BaseModel):
     round_number: int
 
 
+class GuessPendingReviewEvent(GameEvent):
+    type: GameWebSocketEvents = GameWebSocketEvents.GUESS_PENDING_REVIEW
+    guess_id: int
+    player_id: int
+    player_name: str
+    word_index: int
+    guess: str
+
+
+class GuessReviewedEvent(GameEvent):
+    type: GameWebSocketEvents = GameWebSocketEvents.GUESS_REVIEWED
+    guess_id: int
+    word_index: int
+    guess: str
+    approved: bool
+
+
+class WordRolledBackEvent(GameEvent):
+    """The host un-solved a rung that was accepted by mistake; the team has to solve it again."""
+
+    type: GameWebSocketEvents = GameWebSocketEvents.WORD_ROLLED_BACK
+    word_index: int
+    word: str
+    revealed_count: int
+
+
+class PaceHintEvent(GameEvent):
+    """The pace keeper noticed the team stalling and revealed part of a rung."""
+
+    type: GameWebSocketEvents = GameWebSocketEvents.PACE_HINT
+    word_index: int
+    level: int
+    hint: str
+
+
+class TeamChatEvent(GameEvent):
+    """A chat line from a teammate; the last TEAM_CHAT_HISTORY_SIZE are kept for GET /api/lobby/team/chat."""
+
+    type: GameWebSocketEvents = GameWebSocketEvents.TEAM_CHAT
+    player_id: int
+    player_name: str
+    text: str
+    sent_at: str  # ISO timestamp
+
+
 class TimerStartedEvent(BaseModel):
     type: GameWebSocketEvents = GameWebSocketEvents.TIMER_STARTED
     lobby_id: int
@@ -166,6 +435,179 @@ class TimerStartedEvent(BaseModel):
     expires_at: str  # ISO timestamp for easier client-side handling
 
 
+class TimerTickEvent(BaseModel):
+    """Sent every TIMER_TICK_SECONDS while a round timer runs so clients can correct their local countdown."""
+
+    type: GameWebSocketEvents = GameWebSocketEvents.TIMER_TICK
+    lobby_id: int
+    remaining_seconds: int
+    expires_at: str  # ISO timestamp
+
+
 class TimerExpiredEvent(BaseModel):
     type: GameWebSocketEvents = GameWebSocketEvents.TIMER_EXPIRED
     lobby_id: int
+
+
+####################################################################
+# ? CONNECTION EVENTS
+####################################################################
+class PingEvent(BaseModel):
+    type: str = "ping"
+
+
+class PongEvent(BaseModel):
+    """Answer to a client's ping message."""
+
+    type: str = "pong"
+
+
+class ResyncRequiredEvent(BaseModel):
+    """Sent on reconnect when the events after the client's last_seq are no longer buffered."""
+
+    type: str = "resync_required"
+    lobby_id: int
+    last_seq: int  # What the client asked to resume from
+    current_seq: int
+
+
+class StateDiffEvent(BaseModel):
+    """A snapshot-style event as a merge patch, for players connected with ?diff=true (see websocket/diffs.py)."""
+
+    type: str = "state_diff"
+    of: str  # The event type patched, e.g. "state_update"
+    state_key: str
+    base_version: int  # The client's copy must be at this version for the patch to apply
+    version: int
+    patch: dict
+    seq: int | None = None
+
+
+####################################################################
+# ? ADMIN EVENTS
+####################################################################
+class OccupancyWarningEvent(BaseModel):
+    """A join took the lobby past its soft player cap; the player was still admitted."""
+
+    type: str = "occupancy_warning"
+    lobby_id: int
+    player_name: str
+    player_count: int
+    soft_player_cap: int
+
+
+class LobbyStateEvent(BaseModel):
+    """Reply to an admin's request_lobby_state: a LobbyInfo snapshot, or None for a lobby that doesn't exist."""
+
+    type: str = "lobby_state"
+    lobby_id: int
+    state: dict | None
+
+
+class AdminPresenceEvent(BaseModel):
+    type: str = "admin_presence"
+    lobby_id: int
+    admin_id: str  # Short, stable id for the admin's web session
+    team_id: int | None  # Team the admin is editing, None once they stop
+
+
+class HealthStatusEvent(BaseModel):
+    """Sent to every admin when the node becomes degraded or recovers."""
+
+    type: str = "health_status"
+    degraded: bool
+    db_latency_ms: float | None
+    error_rate: float | None
+    disabled_features: list[str]
+
+
+class CommentaryEvent(BaseModel):
+    """A line of plain-text game narration for spectators and admins, see backend/commentary.py."""
+
+    type: str = "commentary"
+    lobby_id: int
+    text: str  # e.g. "Red Foxes solved rung 3 after 4 guesses!"
+    created_at: str
+
+
+class ServerShutdownEvent(BaseModel):
+    """Sent to every connection just before the server closes it to shut down or restart."""
+
+    type: str = "server_shutdown"
+    reason: str
+    reconnect_after_seconds: float  # How long clients should wait before trying to reconnect
+
+
+####################################################################
+# ? ALL OUTGOING EVENTS
+####################################################################
+ServerEvent = Union[
+    JoinedLobbyEvent,
+    DisconnectedLobbyEvent,
+    PlayerDisconnectedEvent,
+    PlayerKickedEvent,
+    TeamAssignedEvent,
+    TeamChangedEvent,
+    TeamUpdatedEvent,
+    TeamsReassignedEvent,
+    ReadyStatusChangedEvent,
+    LobbyDeletedEvent,
+    LobbyMergedEvent,
+    LateJoinEvent,
+    PreferencesUpdatedEvent,
+    HostChangedEvent,
+    GameStartedEvent,
+    GuessSubmittedEvent,
+    WordSolvedEvent,
+    DirectionChangedEvent,
+    StateUpdateEvent,
+    TeamCompletedEvent,
+    TeamProgressEvent,
+    GameWonEvent,
+    GameFinishedEvent,
+    ScoreAdjustedEvent,
+    AlreadySolvedEvent,
+    TeamPlacedEvent,
+    RoundEndedEvent,
+    GameEndedEvent,
+    CreditsEvent,
+    RevealStepEvent,
+    RevealEndedEvent,
+    LobbyPhaseChangedEvent,
+    NewRoundStartedEvent,
+    GuessPendingReviewEvent,
+    GuessReviewedEvent,
+    WordRolledBackEvent,
+    PaceHintEvent,
+    TeamChatEvent,
+    TimerStartedEvent,
+    TimerTickEvent,
+    TimerExpiredEvent,
+    PingEvent,
+    PongEvent,
+    ResyncRequiredEvent,
+    StateDiffEvent,
+    OccupancyWarningEvent,
+    LobbyStateEvent,
+    AdminPresenceEvent,
+    HealthStatusEvent,
+    ServerShutdownEvent,
+    CommentaryEvent,
+    IntermissionStartedEvent,
+    PuzzleReplacedEvent,
+]
+"""
+Every event the server sends over a websocket; the manager broadcast methods take these.
+
+New events go here too, and their type string into ServerEventType in frontend/src/types/index.ts;
+backend/tests/test_event_types.py fails until both sides agree.
+"""
+
+
+def event_type_of(event_class: type[BaseModel]) -> str:
+    """The `type` string an event class is sent with."""
+    default = event_class.model_fields["type"].default
+    return default.value if isinstance(default, Enum) else default
+
+
+SERVER_EVENT_TYPES: dict[str, type[BaseModel]] = {event_type_of(cls): cls for cls in get_args(ServerEvent)}
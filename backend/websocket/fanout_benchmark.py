@@ -0,0 +1,79 @@
+"""
+Compare per-recipient serialization against PreparedEvent for a lobby-wide broadcast.
+
+    uv run python -m backend.websocket.fanout_benchmark --recipients 100 --rounds 500
+
+Both paths send through SendStatsRegistry to in-memory sockets, mirroring the manager loops, and report
+process CPU time so other load on the machine doesn't skew the comparison.
+"""
+
+import argparse
+import asyncio
+import json
+import time
+from typing import Dict, List
+
+from backend.websocket.events import StateUpdateEvent
+from backend.websocket.prepared import PLAYER_AUDIENCE, PreparedEvent
+from backend.websocket.send_stats import SendStatsRegistry, player_key
+
+
+class NullWebSocket:
+    async def send_text(self, text: str):
+        pass
+
+
+def sample_event() -> StateUpdateEvent:
+    return StateUpdateEvent(
+        team_id=1,
+        revealed_steps=list(range(12)),
+        is_completed=False,
+        last_updated_at="2025-01-01T00:00:00",
+    )
+
+
+async def fan_out_per_recipient(registry: SendStatsRegistry, sockets: Dict[str, NullWebSocket], event):
+    for session_id, websocket in sockets.items():
+        await registry.send_text(player_key(session_id), websocket, json.dumps(event.model_dump()))
+
+
+async def fan_out_prepared(registry: SendStatsRegistry, sockets: Dict[str, NullWebSocket], event):
+    prepared = PreparedEvent.of(event)
+    for session_id, websocket in sockets.items():
+        await registry.send_text(player_key(session_id), websocket, prepared.for_audience(PLAYER_AUDIENCE))
+
+
+async def time_fan_out(fan_out, recipients: int, rounds: int) -> float:
+    registry = SendStatsRegistry()
+    sockets = {f"session-{i}": NullWebSocket() for i in range(recipients)}
+    started = time.process_time()
+    for _ in range(rounds):
+        await fan_out(registry, sockets, sample_event())
+    return time.process_time() - started
+
+
+def run_fanout_benchmark(recipients: int = 100, rounds: int = 200) -> Dict[str, float]:
+    """CPU seconds spent broadcasting `rounds` fresh events to `recipients` sockets, per approach."""
+    per_recipient = asyncio.run(time_fan_out(fan_out_per_recipient, recipients, rounds))
+    prepared = asyncio.run(time_fan_out(fan_out_prepared, recipients, rounds))
+    return {"per_recipient_seconds": per_recipient, "prepared_seconds": prepared}
+
+
+def main(argv: List[str] | None = None):
+    parser = argparse.ArgumentParser(description="Benchmark broadcast serialization")
+    parser.add_argument("--recipients", type=int, default=100)
+    parser.add_argument("--rounds", type=int, default=500)
+    args = parser.parse_args(argv)
+
+    result = run_fanout_benchmark(args.recipients, args.rounds)
+    per_recipient = result["per_recipient_seconds"]
+    prepared = result["prepared_seconds"]
+    print(f"{args.rounds} broadcasts to {args.recipients} recipients (CPU time)")
+    print(f"  serialize per recipient: {per_recipient * 1000:.1f} ms")
+    print(f"  PreparedEvent:           {prepared * 1000:.1f} ms")
+    if prepared > 0:
+        print(f"  speedup:                 {per_recipient / prepared:.1f}x")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,96 @@
+"""Keepalive helpers shared by the lobby and admin WebSocket handlers."""
+
+import asyncio
+import json
+import time
+from typing import Optional
+
+from fastapi import WebSocket, WebSocketDisconnect
+
+from backend.custom_logging import websocket_logger
+from backend.settings import settings
+from backend.websocket.events import PingEvent
+
+# 1001 "Going Away" tells clients to reconnect rather than treat the close as an error
+KEEPALIVE_CLOSE_CODE = 1001
+
+
+class Heartbeat:
+    """Tracks pongs on one connection so a socket that stopped answering pings can be dropped."""
+
+    def __init__(self, now: Optional[float] = None):
+        # The connection opening counts as the first sign of life
+        self.last_pong_at = time.monotonic() if now is None else now
+
+    def record_pong(self, now: Optional[float] = None):
+        self.last_pong_at = time.monotonic() if now is None else now
+
+    def deadline(self) -> Optional[float]:
+        """
+        Monotonic time by which the next pong must arrive, or None when the check is disabled.
+
+        Pings go out every WS_PING_INTERVAL_SECONDS, so WS_MAX_MISSED_PINGS unanswered pings plus one interval of
+        grace for the last pong to come back.
+        """
+        interval = settings.WS_PING_INTERVAL_SECONDS
+        max_missed = settings.WS_MAX_MISSED_PINGS
+        if interval <= 0 or max_missed <= 0:
+            return None
+        return self.last_pong_at + interval * (max_missed + 1)
+
+
+async def ping_loop(websocket: WebSocket, label: str):
+    """Send an application-level ping every WS_PING_INTERVAL_SECONDS so proxies see traffic."""
+    interval = settings.WS_PING_INTERVAL_SECONDS
+    if interval <= 0:
+        return
+
+    while True:
+        await asyncio.sleep(interval)
+        try:
+            await websocket.send_text(json.dumps(PingEvent().model_dump()))
+            websocket_logger.debug(f"Sent keepalive ping: {label}")
+        except Exception:
+            websocket_logger.debug(f"Keepalive ping failed, stopping pinger: {label}")
+            return
+
+
+def start_ping_loop(websocket: WebSocket, label: str) -> asyncio.Task:
+    return asyncio.create_task(ping_loop(websocket, label))
+
+
+async def receive_text(
+    websocket: WebSocket, connected_at: float, label: str, heartbeat: Optional[Heartbeat] = None
+) -> str:
+    """
+    Receive the next text frame, enforcing the idle timeout, missed pings and maximum connection lifetime.
+
+    Raises:
+        WebSocketDisconnect: After closing the socket when either limit is hit
+    """
+    timeout = settings.WS_IDLE_TIMEOUT_SECONDS if settings.WS_IDLE_TIMEOUT_SECONDS > 0 else None
+    reason = "Idle timeout"
+
+    max_lifetime = settings.WS_MAX_CONNECTION_LIFETIME_SECONDS
+    if max_lifetime > 0:
+        remaining = connected_at + max_lifetime - time.monotonic()
+        if timeout is None or remaining < timeout:
+            timeout = max(remaining, 0)
+            reason = "Maximum connection lifetime reached"
+
+    deadline = heartbeat.deadline() if heartbeat else None
+    if deadline is not None:
+        remaining = deadline - time.monotonic()
+        if timeout is None or remaining < timeout:
+            timeout = max(remaining, 0)
+            reason = "Missed pings"
+
+    try:
+        return await asyncio.wait_for(websocket.receive_text(), timeout=timeout)
+    except asyncio.TimeoutError:
+        websocket_logger.info(f"Closing websocket ({reason}): {label}")
+        try:
+            await websocket.close(code=KEEPALIVE_CLOSE_CODE, reason=reason)
+        except Exception:
+            websocket_logger.debug(f"Websocket close failed (probably already closed): {label}")
+        raise WebSocketDisconnect(code=KEEPALIVE_CLOSE_CODE, reason=reason)
@@ -1,24 +1,96 @@
 import json
-from typing import Dict, TypedDict
+import time
+from typing import Dict, Iterable, Set, TypedDict
 
-from fastapi import WebSocket
+from fastapi import WebSocket, WebSocketDisconnect
+from pydantic import ValidationError
 from sqlmodel import select
 
+from backend.admin_sessions import AdminSession, admin_session_store
 from backend.custom_logging import websocket_logger
 from backend.database import get_session_context
-from backend.database.models import Player
-from backend.websocket.events import LobbyEvent, PlayerKickedEvent
+from backend.database.models import Lobby, Player
+from backend.in_memory_state import on_reset
+from backend.metrics import metrics_collector
+from backend.offline_guesses import player_sightings
+from backend.settings import settings
+from backend.websocket.acks import AckTracker
+from backend.websocket.admin_messages import (
+    AdminPongMessage,
+    PresenceMessage,
+    RequestLobbyStateMessage,
+    SubscribeLobbyMessage,
+    UnsubscribeLobbyMessage,
+    parse_admin_message,
+)
+from backend.websocket.channels import LobbyChannel, Outbox
+from backend.websocket.delivery import DeliveryReport, deliver
+from backend.websocket.diffs import DiffTracker
+from backend.websocket.events import (
+    AdminPresenceEvent,
+    LobbyStateEvent,
+    PlayerDisconnectedEvent,
+    PlayerKickedEvent,
+    PongEvent,
+    ResyncRequiredEvent,
+    ServerEvent,
+    TeamChangedEvent,
+)
+from backend.websocket.keepalive import Heartbeat, receive_text, start_ping_loop
+from backend.websocket.player_messages import (
+    AckMessage,
+    DiffResetMessage,
+    PingMessage,
+    PongMessage,
+    SubmitGuessMessage,
+    TeamChatMessage,
+    parse_player_message,
+)
+from backend.websocket.prepared import ADMIN_AUDIENCE, PLAYER_AUDIENCE, PreparedEvent
+from backend.websocket.replay import ReplayBuffer
+from backend.websocket.send_stats import admin_key, player_key, send_stats, spectator_key
+
+# Application close code (4000-4999); clients don't reconnect on it, or two tabs would keep taking the session back
+REPLACED_CLOSE_CODE = 4000
+REPLACED_REASON = "Replaced by a newer connection"
 
 
 class AdminWebSocketConnection(TypedDict):
     websocket: WebSocket
     subscribed_lobbies: list[int]
+    editing_teams: Dict[int, int]  # lobby_id -> team_id the admin is currently editing
+
+
+def admin_presence_id(web_session_id: str) -> str:
+    """Short id other admins see, so presence doesn't leak full session ids."""
+    return web_session_id[:8]
+
+
+async def close_replaced(websocket: WebSocket, label: str):
+    """Close a socket a newer connection for the same session has taken over from."""
+    try:
+        await websocket.close(code=REPLACED_CLOSE_CODE, reason=REPLACED_REASON)
+        websocket_logger.info(f"Closed websocket replaced by a newer connection: {label}")
+    except Exception:
+        websocket_logger.debug(f"Replaced websocket already closed: {label}")
 
 
 class AdminWebSocketManager:
     def __init__(self):
         # keyed by web_session_id
         self.admin_websockets: Dict[str, AdminWebSocketConnection] = {}
+        # lobby_id -> web_session_ids subscribed to it, kept in step with each connection's subscribed_lobbies
+        # so lobby broadcasts only visit that lobby's admins
+        self.lobby_subscribers: Dict[int, Set[str]] = {}
+
+    def _unindex(self, web_session_id: str, lobby_ids: Iterable[int]):
+        for lobby_id in lobby_ids:
+            subscribers = self.lobby_subscribers.get(lobby_id)
+            if subscribers is None:
+                continue
+            subscribers.discard(web_session_id)
+            if not subscribers:
+                del self.lobby_subscribers[lobby_id]
 
     async def connect(self, websocket: WebSocket, web_session_id: str):
         try:
@@ -28,27 +100,54 @@ class AdminWebSocketManager:
             websocket_logger.exception(f"Admin websocket.accept() failed: web_session_id={web_session_id}")
             raise
 
+        send_stats.remove(admin_key(web_session_id))
+        previous = self.admin_websockets.get(web_session_id)
+        if previous:
+            # A reconnect starts with no subscriptions; the client re-subscribes on open
+            self._unindex(web_session_id, previous["subscribed_lobbies"])
         self.admin_websockets[web_session_id] = {
             "websocket": websocket,
             "subscribed_lobbies": [],
+            "editing_teams": {},
         }
+        if previous and previous["websocket"] is not websocket:
+            await close_replaced(previous["websocket"], f"web_session_id={web_session_id}")
         websocket_logger.info(
             f"Admin connected: web_session_id={web_session_id}. Total admins={len(self.admin_websockets)}"
         )
 
-    async def broadcast_to_lobby(self, lobby_id: int, event: LobbyEvent):
-        recipients = [conn for conn in self.admin_websockets.values() if lobby_id in conn["subscribed_lobbies"]]
+    async def broadcast_to_lobby(
+        self, lobby_id: int, event: ServerEvent | PreparedEvent, exclude_web_session_id: str | None = None
+    ) -> DeliveryReport:
+        prepared = PreparedEvent.of(event)
+        recipients = [
+            (web_session_id, self.admin_websockets[web_session_id])
+            for web_session_id in self.lobby_subscribers.get(lobby_id, ())
+            if web_session_id != exclude_web_session_id
+        ]
         websocket_logger.debug(
-            f"Broadcasting event to admins for lobby={lobby_id}. Event={event.model_dump()}. Recipients={len(recipients)}"
+            f"Broadcasting event to admins for lobby={lobby_id}. Event={prepared.text}. Recipients={len(recipients)}"
         )
         if not recipients:
             websocket_logger.debug("No admin connections available")
-        for connection in recipients:
-            try:
-                await connection["websocket"].send_text(json.dumps(event.model_dump()))
-                websocket_logger.debug("Sent event to admin websocket")
-            except Exception:
-                websocket_logger.exception("Failed to send event to admin websocket; continuing.")
+        report = DeliveryReport(prepared.event_type)
+        text = prepared.for_audience(ADMIN_AUDIENCE)
+        for web_session_id, connection in recipients:
+            await deliver(report, admin_key(web_session_id), connection["websocket"], text)
+        if report.failures:
+            websocket_logger.warning(f"Admin broadcast to lobby={lobby_id} partly failed: {report.summary()}")
+        return report
+
+    async def broadcast_to_all(self, event: ServerEvent | PreparedEvent) -> DeliveryReport:
+        """Send to every connected admin, whatever lobbies they're subscribed to."""
+        prepared = PreparedEvent.of(event)
+        report = DeliveryReport(prepared.event_type)
+        text = prepared.for_audience(ADMIN_AUDIENCE)
+        for web_session_id, connection in list(self.admin_websockets.items()):
+            await deliver(report, admin_key(web_session_id), connection["websocket"], text)
+        if report.failures:
+            websocket_logger.warning(f"Broadcast to all admins partly failed: {report.summary()}")
+        return report
 
     async def subscribe_to_lobby(self, web_session_id: str, lobby_id: int):
         connection = self.admin_websockets.get(web_session_id)
@@ -60,7 +159,9 @@ class AdminWebSocketManager:
 
         if lobby_id not in connection["subscribed_lobbies"]:
             connection["subscribed_lobbies"].append(lobby_id)
+            self.lobby_subscribers.setdefault(lobby_id, set()).add(web_session_id)
             websocket_logger.info(f"Admin web_session_id={web_session_id} subscribed to lobby_id={lobby_id}")
+            await self.send_existing_presence(web_session_id, lobby_id)
         else:
             websocket_logger.debug(f"Admin web_session_id={web_session_id} already subscribed to lobby_id={lobby_id}")
 
@@ -74,40 +175,177 @@ class AdminWebSocketManager:
 
         try:
             connection["subscribed_lobbies"].remove(lobby_id)
+            self._unindex(web_session_id, [lobby_id])
             websocket_logger.info(f"Admin web_session_id={web_session_id} unsubscribed from lobby_id={lobby_id}")
         except ValueError:
             websocket_logger.debug(f"Admin web_session_id={web_session_id} was not subscribed to lobby_id={lobby_id}")
 
-    async def handle_message(self, web_session_id: str, message: dict):
-        action = message.get("action")
-        lobby_id = message.get("lobby_id")
+        if lobby_id in connection["editing_teams"]:
+            await self.set_presence(web_session_id, lobby_id, None)
 
-        if action == "subscribe_lobby" and lobby_id is not None:
-            await self.subscribe_to_lobby(web_session_id, lobby_id)
-        elif action == "unsubscribe_lobby" and lobby_id is not None:
-            await self.unsubscribe_from_lobby(web_session_id, lobby_id)
+    async def set_presence(self, web_session_id: str, lobby_id: int, team_id: int | None):
+        """Record which team an admin is editing and relay it to the other admins watching the lobby."""
+        connection = self.admin_websockets.get(web_session_id)
+        if not connection:
+            websocket_logger.warning(f"Cannot set presence for unknown admin web_session_id={web_session_id}")
+            return
+
+        if team_id is None:
+            connection["editing_teams"].pop(lobby_id, None)
         else:
-            websocket_logger.warning(f"Unknown admin websocket message: {message}")
+            connection["editing_teams"][lobby_id] = team_id
+        websocket_logger.debug(
+            f"Admin presence: web_session_id={web_session_id} lobby_id={lobby_id} editing team_id={team_id}"
+        )
 
-    async def continuous_listening(self, websocket: WebSocket, web_session_id: str):
-        while True:
+        event = AdminPresenceEvent(lobby_id=lobby_id, admin_id=admin_presence_id(web_session_id), team_id=team_id)
+        await self.broadcast_to_lobby(lobby_id, event, exclude_web_session_id=web_session_id)
+
+    async def send_existing_presence(self, web_session_id: str, lobby_id: int):
+        """Tell a newly subscribed admin which teams other admins are already editing."""
+        connection = self.admin_websockets.get(web_session_id)
+        if not connection:
+            return
+
+        for other_session_id, other in self.admin_websockets.items():
+            team_id = other["editing_teams"].get(lobby_id)
+            if other_session_id == web_session_id or team_id is None:
+                continue
+            event = AdminPresenceEvent(lobby_id=lobby_id, admin_id=admin_presence_id(other_session_id), team_id=team_id)
             try:
-                data = await websocket.receive_text()
-                message = json.loads(data)
-                websocket_logger.debug(f"Admin WS received message: {message}")
-                await self.handle_message(web_session_id, message)
+                await send_stats.send_text(
+                    admin_key(web_session_id), connection["websocket"], json.dumps(event.model_dump())
+                )
             except Exception:
-                websocket_logger.exception("Error while reading from admin websocket. Stopping continuous listening.")
-                break
+                websocket_logger.exception("Failed to send existing presence to admin websocket; continuing.")
+
+    def is_connected(self, web_session_id: str) -> bool:
+        return web_session_id in self.admin_websockets
+
+    async def lobby_in_scope(self, session: AdminSession, lobby_id: int) -> bool:
+        """Organization admins only get to watch their own organization's lobbies."""
+        if session.organization_id is None:
+            return True
+        async with get_session_context() as db:
+            lobby = db.get(Lobby, lobby_id)
+            return lobby is not None and lobby.organization_id == session.organization_id
+
+    async def handle_message(self, web_session_id: str, message: dict):
+        session = admin_session_store.touch(web_session_id)
+        if not session:
+            websocket_logger.warning(f"Closing admin websocket with expired session: web_session_id={web_session_id}")
+            await self.disconnect(web_session_id, code=1008, reason="Admin session expired")
+            return
+        try:
+            parsed = parse_admin_message(message)
+        except ValidationError as e:
+            websocket_logger.warning(
+                f"Invalid admin websocket message from web_session_id={web_session_id}: "
+                f"action={message.get('action')} errors={e.errors(include_url=False)}"
+            )
+            return
+
+        lobby_id = getattr(parsed, "lobby_id", None)
+        if lobby_id is not None and not await self.lobby_in_scope(session, lobby_id):
+            websocket_logger.warning(
+                f"Ignoring admin message for a lobby outside its organization: web_session_id={web_session_id} "
+                f"lobby_id={lobby_id}"
+            )
+            if isinstance(parsed, RequestLobbyStateMessage):
+                await self.send_lobby_state(web_session_id, lobby_id, hidden=True)
+            return
+
+        if isinstance(parsed, SubscribeLobbyMessage):
+            await self.subscribe_to_lobby(web_session_id, parsed.lobby_id)
+        elif isinstance(parsed, UnsubscribeLobbyMessage):
+            await self.unsubscribe_from_lobby(web_session_id, parsed.lobby_id)
+        elif isinstance(parsed, PresenceMessage):
+            await self.set_presence(web_session_id, parsed.lobby_id, parsed.team_id)
+        elif isinstance(parsed, RequestLobbyStateMessage):
+            await self.send_lobby_state(web_session_id, parsed.lobby_id)
+        elif isinstance(parsed, AdminPongMessage):
+            websocket_logger.debug(f"Admin pong received: web_session_id={web_session_id}")
+
+    async def send_lobby_state(self, web_session_id: str, lobby_id: int, hidden: bool = False):
+        """
+        Send one admin a full LobbyInfo snapshot, so subscribing doesn't need a follow-up HTTP fetch.
 
-    async def disconnect(self, web_session_id: str):
+        Hidden lobbies (another organization's) get the same null state as ones that don't exist.
+        """
+        # Import here to avoid circular dependency
+        from backend.api.admin.lobby.index import build_lobby_info
+
+        connection = self.admin_websockets.get(web_session_id)
+        if not connection:
+            websocket_logger.warning(f"Cannot send lobby state to unknown admin web_session_id={web_session_id}")
+            return
+
+        async with get_session_context() as db:
+            lobby_info = None if hidden else build_lobby_info(db, lobby_id)
+            state = lobby_info.model_dump(mode="json") if lobby_info else None
+        if state is None:
+            websocket_logger.warning(
+                f"Admin web_session_id={web_session_id} requested state of unknown lobby_id={lobby_id}"
+            )
+
+        event = LobbyStateEvent(lobby_id=lobby_id, state=state)
+        try:
+            await send_stats.send_text(
+                admin_key(web_session_id), connection["websocket"], json.dumps(event.model_dump())
+            )
+            websocket_logger.debug(f"Sent lobby state for lobby_id={lobby_id} to web_session_id={web_session_id}")
+        except Exception:
+            websocket_logger.exception(f"Failed to send lobby state to admin web_session_id={web_session_id}")
+
+    async def continuous_listening(self, websocket: WebSocket, web_session_id: str):
+        label = f"admin web_session_id={web_session_id}"
+        connected_at = time.monotonic()
+        heartbeat = Heartbeat(connected_at)
+        pinger = start_ping_loop(websocket, label)
+        try:
+            while True:
+                try:
+                    data = await receive_text(websocket, connected_at, label, heartbeat)
+                    message = json.loads(data)
+                    if message.get("action") == "pong":
+                        heartbeat.record_pong()
+                    websocket_logger.debug(f"Admin WS received message: {message}")
+                    await self.handle_message(web_session_id, message)
+                except WebSocketDisconnect:
+                    raise
+                except Exception:
+                    websocket_logger.exception(
+                        "Error while reading from admin websocket. Stopping continuous listening."
+                    )
+                    break
+        finally:
+            pinger.cancel()
+
+    async def disconnect(
+        self, web_session_id: str, code: int = 1000, reason: str | None = None, websocket: WebSocket | None = None
+    ):
+        """
+        Args:
+            websocket: Only disconnect if this is still the session's socket, as for players
+        """
+        connection = self.admin_websockets.get(web_session_id)
+        if connection and websocket is not None and connection["websocket"] is not websocket:
+            websocket_logger.debug(f"Not disconnecting a replaced admin websocket: web_session_id={web_session_id}")
+            return
         connection = self.admin_websockets.pop(web_session_id, None)
         if not connection:
             websocket_logger.debug(f"Tried to disconnect unknown admin web_session_id={web_session_id}")
             return
+        send_stats.remove(admin_key(web_session_id))
+        self._unindex(web_session_id, connection["subscribed_lobbies"])
+
+        # Release any teams this admin was editing so co-hosts aren't left with stale locks
+        for lobby_id in list(connection["editing_teams"].keys()):
+            event = AdminPresenceEvent(lobby_id=lobby_id, admin_id=admin_presence_id(web_session_id), team_id=None)
+            await self.broadcast_to_lobby(lobby_id, event)
 
         try:
-            await connection["websocket"].close()
+            await connection["websocket"].close(code=code, reason=reason)
             websocket_logger.debug(f"Admin websocket.close() succeeded: web_session_id={web_session_id}")
         except Exception:
             websocket_logger.debug(
@@ -117,8 +355,24 @@ class AdminWebSocketManager:
             f"Admin disconnected: web_session_id={web_session_id}. Remaining admins={len(self.admin_websockets)}"
         )
 
+    async def close_all(self, event: ServerEvent, code: int, reason: str) -> int:
+        """Send `event` to every admin and close their sockets; returns how many were connected."""
+        prepared = PreparedEvent.of(event)
+        connections = list(self.admin_websockets.items())
+        self.admin_websockets.clear()
+        self.lobby_subscribers.clear()
+        for web_session_id, connection in connections:
+            send_stats.remove(admin_key(web_session_id))
+            try:
+                await connection["websocket"].send_text(prepared.for_audience(ADMIN_AUDIENCE))
+                await connection["websocket"].close(code=code, reason=reason)
+            except Exception:
+                websocket_logger.debug(f"Admin websocket already closed: web_session_id={web_session_id}")
+        return len(connections)
+
 
 admin_web_socket_manager = AdminWebSocketManager()
+on_reset(admin_web_socket_manager.__init__)
 
 
 class LobbyWebSocketManager:
@@ -136,9 +390,22 @@ class LobbyWebSocketManager:
         """
         player_teams maps player_session_id to team_id for team-based broadcasts
         """
+        self.spectators: Dict[int, Dict[str, WebSocket]] = {}
+        """
+        spectators maps lobby_id to {spectator_session_id: websocket}, read-only viewers of lobby-wide broadcasts
+        """
+        self.channels: Dict[int, LobbyChannel] = {}
+        """
+        channels maps lobby_id to the outboxes events are queued on for that lobby's players; see channels.py
+        """
+        self.replay = ReplayBuffer(settings.WS_REPLAY_BUFFER_SIZE, settings.WS_REPLAY_MAX_BYTES)
+        self.acks = AckTracker()
+        self.diffs = DiffTracker()
         self.admin_web_socket_manager = admin_web_socket_manager
 
-    async def connect(self, websocket: WebSocket, lobby_id: int, player_session_id: str):
+    async def connect(
+        self, websocket: WebSocket, lobby_id: int, player_session_id: str, acks: bool = False, diff: bool = False
+    ):
         try:
             await websocket.accept()
             websocket_logger.debug(
@@ -150,7 +417,16 @@ class LobbyWebSocketManager:
             )
             raise
 
-        self.lobby_websockets.setdefault(lobby_id, {})[player_session_id] = websocket
+        send_stats.remove(player_key(player_session_id))
+        self.acks.set_opt_in(player_session_id, lobby_id, acks)
+        self.diffs.set_opt_in(player_session_id, diff)
+        previous = self.lobby_websockets.setdefault(lobby_id, {}).get(player_session_id)
+        self.lobby_websockets[lobby_id][player_session_id] = websocket
+        if previous is not None and previous is not websocket:
+            # Usually a reconnect before the old socket noticed it dropped; disconnect() ignores its listener ending
+            await close_replaced(previous, f"lobby_id={lobby_id} player_session_id={player_session_id}")
+        self._outbox(lobby_id, player_session_id)
+        metrics_collector.record_concurrent_players(sum(len(sockets) for sockets in self.lobby_websockets.values()))
         websocket_logger.info(
             f"Player connected: lobby_id={lobby_id} player_session_id={player_session_id}. Lobby size={len(self.lobby_websockets[lobby_id])}"
         )
@@ -161,13 +437,26 @@ class LobbyWebSocketManager:
             if player and player.team_id:
                 self.register_player_team(player_session_id, player.team_id)
 
-    async def disconnect(self, lobby_id: int, player_session_id: str):
+    async def disconnect(
+        self, lobby_id: int, player_session_id: str, reason: str | None = None, websocket: WebSocket | None = None
+    ):
+        """
+        Args:
+            websocket: Only disconnect if this is still the player's socket, so a replaced one's listener ending
+                doesn't drop the connection that replaced it
+        """
         if lobby_id not in self.lobby_websockets:
             websocket_logger.debug(
                 f"Tried to disconnect from unknown lobby: lobby_id={lobby_id} player_session_id={player_session_id}"
             )
             return
 
+        current = self.lobby_websockets[lobby_id].get(player_session_id)
+        if websocket is not None and current is not websocket:
+            websocket_logger.debug(
+                f"Not disconnecting a replaced websocket: lobby_id={lobby_id} player_session_id={player_session_id}"
+            )
+            return
         websocket = self.lobby_websockets[lobby_id].pop(player_session_id, None)
         if not websocket:
             websocket_logger.debug(
@@ -175,6 +464,7 @@ class LobbyWebSocketManager:
             )
             return
 
+        self._unsubscribe(lobby_id, player_key(player_session_id))
         try:
             await websocket.close()
             websocket_logger.debug(
@@ -189,58 +479,301 @@ class LobbyWebSocketManager:
         )
 
         self.unregister_player_team(player_session_id)
+        self.diffs.set_opt_in(player_session_id, False)
+        send_stats.remove(player_key(player_session_id))
+        player_sightings.disconnected(player_session_id)
 
-    async def send_to_player(self, lobby_id: int, player_session_id: str, event: LobbyEvent):
-        websocket = self.lobby_websockets.get(lobby_id, {}).get(player_session_id)
-        if websocket:
-            try:
-                await websocket.send_text(json.dumps(event.model_dump()))
-                websocket_logger.debug(f"Sent event to player_session_id={player_session_id} in lobby={lobby_id}")
-            except Exception:
-                websocket_logger.exception(
-                    f"Failed to send event to player_session_id={player_session_id} in lobby={lobby_id}"
-                )
-        else:
-            websocket_logger.debug(f"No websocket found for player_session_id={player_session_id} in lobby={lobby_id}")
+        # Let admins (and teammates) drop the player's connected indicator instead of showing a ghost
+        await self.broadcast_to_lobby(
+            lobby_id, PlayerDisconnectedEvent(lobby_id=lobby_id, player_session_id=player_session_id, reason=reason)
+        )
 
-    async def broadcast_to_lobby(self, lobby_id: int, event: LobbyEvent):
-        websocket_logger.debug(f"Broadcasting event to lobby {lobby_id}: {event.model_dump()}")
+    async def send_to_player(
+        self, lobby_id: int, player_session_id: str, event: ServerEvent | PreparedEvent, replayable: bool = True
+    ) -> DeliveryReport:
+        """
+        Args:
+            replayable: Keep the event for replay on reconnect; off for connection chatter like pongs
+        """
+        prepared = PreparedEvent.of(event)
+        if replayable:
+            self.replay.record(lobby_id, prepared, player_session_id=player_session_id)
+        report = self._publish(lobby_id, prepared, [player_session_id])
+        if not report.attempted:
+            websocket_logger.debug(f"No websocket found for player_session_id={player_session_id} in lobby={lobby_id}")
+        elif report.failures:
+            websocket_logger.warning(f"Send to player in lobby={lobby_id} failed: {report.summary()}")
+        return report
+
+    async def broadcast_to_lobby(self, lobby_id: int, event: ServerEvent | PreparedEvent) -> DeliveryReport:
+        """Queue for every player in the lobby and send to its subscribed admins; dead sockets are cleaned up later."""
+        if isinstance(event, TeamChangedEvent):
+            self.apply_team_change(event)
+        prepared = PreparedEvent.of(event)
+        self.replay.record(lobby_id, prepared)
+        websocket_logger.debug(f"Broadcasting event to lobby {lobby_id}: {prepared.text}")
         members = self.lobby_websockets.get(lobby_id, {})
         if not members:
             websocket_logger.debug(f"No connected players in lobby={lobby_id} to broadcast to")
-        for ws_id, websocket in list(members.items()):
-            try:
-                await websocket.send_text(json.dumps(event.model_dump()))
-                websocket_logger.debug(f"Sent event to player_session_id={ws_id} in lobby={lobby_id}")
-            except Exception:
-                websocket_logger.exception(
-                    f"Failed to send event to player_session_id={ws_id} in lobby={lobby_id}; removing or ignoring."
-                )
-                # Ignore failed sends, cleanup will happen elsewhere
-                pass
-        await self.admin_web_socket_manager.broadcast_to_lobby(lobby_id, event)
+        report = self._publish(lobby_id, prepared, list(members))
+        report.merge(self._publish_to_spectators(lobby_id, prepared))
+        if report.failures:
+            websocket_logger.warning(f"Broadcast to lobby={lobby_id} partly failed: {report.summary()}")
+        return report.merge(await self.admin_web_socket_manager.broadcast_to_lobby(lobby_id, prepared))
+
+    async def broadcast_to_watchers(self, lobby_id: int, event: ServerEvent | PreparedEvent) -> DeliveryReport:
+        """Send to the lobby's spectators and subscribed admins only, e.g. commentary players have no use for."""
+        prepared = PreparedEvent.of(event)
+        report = self._publish_to_spectators(lobby_id, prepared)
+        return report.merge(await self.admin_web_socket_manager.broadcast_to_lobby(lobby_id, prepared))
+
+    async def resume(self, lobby_id: int, player_session_id: str, last_seq: int):
+        """
+        Replay what a reconnecting player missed since last_seq, or ask them to reload if it's no longer buffered.
 
-    async def kick_player(self, lobby_id: int, player_session_id: str):
-        websocket_logger.info(f"Kicking player: lobby_id={lobby_id} player_session_id={player_session_id}")
+        Args:
+            lobby_id: Lobby ID
+            player_session_id: Player's session ID
+            last_seq: Highest "seq" the client saw before it dropped
+        """
+        team_id = self.player_teams.get(player_session_id)
+        missed = self.replay.missed_since(lobby_id, last_seq, player_session_id, team_id)
+        if missed is None:
+            current_seq = self.replay.last_seq.get(lobby_id, 0)
+            websocket_logger.info(
+                f"Cannot replay for player_session_id={player_session_id} in lobby={lobby_id}: "
+                f"last_seq={last_seq} current_seq={current_seq}"
+            )
+            event = ResyncRequiredEvent(lobby_id=lobby_id, last_seq=last_seq, current_seq=current_seq)
+            await self.send_to_player(lobby_id, player_session_id, event, replayable=False)
+            return
+
+        websocket_logger.info(
+            f"Replaying {len(missed)} events to player_session_id={player_session_id} in lobby={lobby_id} "
+            f"after seq={last_seq}"
+        )
+        # Through the outbox, so replayed events can't interleave with live ones queued meanwhile
+        outbox = self._outbox(lobby_id, player_session_id)
+        for prepared in missed:
+            report = DeliveryReport(prepared.event_type)
+            if not outbox or not outbox.offer(report, self.diffs.text_for(player_session_id, prepared)):
+                websocket_logger.warning(f"Replay to player_session_id={player_session_id} stopped: {report.summary()}")
+                break
+
+    def _outbox(self, lobby_id: int, player_session_id: str) -> Outbox | None:
         websocket = self.lobby_websockets.get(lobby_id, {}).get(player_session_id)
-        if websocket:
-            try:
-                kick_event = PlayerKickedEvent(lobby_id=lobby_id, player_session_id=player_session_id)
-                await websocket.send_text(json.dumps(kick_event.model_dump()))
-                # Force close the connection, 1008 is Policy Violation
-                await websocket.close(code=1008, reason="Player kicked by admin")
-            except Exception:
-                websocket_logger.exception(f"Error while kicking player {player_session_id}")
-            finally:
-                if lobby_id in self.lobby_websockets and player_session_id in self.lobby_websockets[lobby_id]:
-                    del self.lobby_websockets[lobby_id][player_session_id]
-                    websocket_logger.info(f"Player {player_session_id} removed from lobby {lobby_id} after kick")
-                self.unregister_player_team(player_session_id)
+        return self._channel_outbox(lobby_id, player_key(player_session_id), websocket)
+
+    def _channel_outbox(self, lobby_id: int, key: str, websocket: WebSocket | None) -> Outbox | None:
+        if not websocket:
+            return None
+        channel = self.channels.setdefault(lobby_id, LobbyChannel(settings.WS_OUTBOX_SIZE))
+        return channel.outbox(key, websocket)
+
+    def _unsubscribe(self, lobby_id: int, key: str):
+        channel = self.channels.get(lobby_id)
+        if channel:
+            channel.unsubscribe(key)
+            if channel.idle:
+                del self.channels[lobby_id]
+
+    def _publish(self, lobby_id: int, prepared: PreparedEvent, player_session_ids: Iterable[str]) -> DeliveryReport:
+        """Queue the event on each connected player's outbox; returns right away, however slow their sockets are."""
+        report = DeliveryReport(prepared.event_type)
+        for player_session_id in player_session_ids:
+            outbox = self._outbox(lobby_id, player_session_id)
+            if outbox:
+                # The shared text unless the player gets this event as a diff
+                outbox.offer(report, self.diffs.text_for(player_session_id, prepared))
+        return report
+
+    async def flush(self, lobby_id: int | None = None):
+        """Wait until everything queued for the lobby's players (or every lobby's) has been written out."""
+        if lobby_id is None:
+            channels = list(self.channels.values())
+        else:
+            channels = [self.channels[lobby_id]] if lobby_id in self.channels else []
+        for channel in channels:
+            await channel.flush()
+
+    def backlog(self, lobby_id: int, key: str) -> int:
+        """Events queued for a connection (e.g. player_key(...)) that haven't been written to its socket yet."""
+        channel = self.channels.get(lobby_id)
+        return channel.backlog(key) if channel else 0
+
+    def is_player_connected(self, player_session_id: str) -> bool:
+        return any(player_session_id in members for members in self.lobby_websockets.values())
+
+    def require_ack(self, lobby_id: int, prepared: PreparedEvent, player_session_ids: Iterable[str]):
+        """Mark an already numbered event as critical and expect an ack from each opted-in recipient."""
+        prepared.stamp("ack_required", True)
+        now = time.monotonic()
+        for player_session_id in player_session_ids:
+            self.acks.track(player_session_id, lobby_id, prepared, now)
+
+    async def resend(self, lobby_id: int, player_session_id: str, prepared: PreparedEvent):
+        """Send an already numbered event again, without recording it a second time."""
+        report = self._publish(lobby_id, prepared, [player_session_id])
+        if report.failures:
+            websocket_logger.warning(f"Failed to resend seq={prepared.data.get('seq')}: {report.summary()}")
+        elif report.attempted:
+            websocket_logger.debug(f"Resent seq={prepared.data.get('seq')} to player_session_id={player_session_id}")
+
+    async def resend_unacked(self, lobby_id: int, player_session_id: str):
+        """On reconnect, send every critical event the player hasn't acknowledged yet."""
+        for entry in self.acks.pending_for(player_session_id):
+            await self.resend(lobby_id, player_session_id, entry.event)
+
+    async def kick_player(
+        self, lobby_id: int, player_session_id: str, roster_version: int | None = None
+    ) -> DeliveryReport:
+        websocket_logger.info(f"Kicking player: lobby_id={lobby_id} player_session_id={player_session_id}")
+        event = PlayerKickedEvent(lobby_id=lobby_id, player_session_id=player_session_id, roster_version=roster_version)
+        kick_event = PreparedEvent(event)
+        if player_session_id in self.acks.opted_in:
+            # The player's own copy is numbered separately, so a phone that slept through it is told on reconnect
+            own_kick_event = PreparedEvent(event)
+            self.replay.record(lobby_id, own_kick_event, player_session_id=player_session_id)
+            self.require_ack(lobby_id, own_kick_event, [player_session_id])
+        else:
+            own_kick_event = kick_event
+        outbox = self._outbox(lobby_id, player_session_id)
+        report = DeliveryReport(kick_event.event_type)
+        if outbox:
+            if not outbox.offer(report, own_kick_event.for_audience(PLAYER_AUDIENCE)):
+                websocket_logger.warning(f"Kicked player wasn't told: {report.summary()}")
+            # Force close the connection once the notice is out, 1008 is Policy Violation
+            if not outbox.close_after_pending(code=1008, reason="Player kicked by admin"):
+                try:
+                    await outbox.websocket.close(code=1008, reason="Player kicked by admin")
+                except Exception:
+                    websocket_logger.debug(f"Kicked player's websocket already closed: {player_session_id}")
+            self.channels[lobby_id].release(player_key(player_session_id))
+            del self.lobby_websockets[lobby_id][player_session_id]
+            websocket_logger.info(f"Player {player_session_id} removed from lobby {lobby_id} after kick")
+            self.unregister_player_team(player_session_id)
+            self.diffs.set_opt_in(player_session_id, False)
+            send_stats.remove(player_key(player_session_id))
+
+        # Reaches the lobby's admins too
+        return report.merge(await self.broadcast_to_lobby(lobby_id, kick_event))
+
+    async def release_players(self, lobby_id: int, player_session_ids: list[str], reason: str):
+        """
+        Close players' sockets once what's queued for them has gone out, without announcing a disconnect.
+
+        For players who now belong to another lobby: their team registrations stay, and a socket closing late can't
+        unregister them from under a connection they've already opened to the new lobby.
+        """
+        for player_session_id in player_session_ids:
+            outbox = self._outbox(lobby_id, player_session_id)
+            if not outbox:
+                continue
+            if not outbox.close_after_pending(code=1000, reason=reason):
+                try:
+                    await outbox.websocket.close(code=1000, reason=reason)
+                except Exception:
+                    websocket_logger.debug(f"Released player's websocket already closed: {player_session_id}")
+            self.channels[lobby_id].release(player_key(player_session_id))
+            del self.lobby_websockets[lobby_id][player_session_id]
+            send_stats.remove(player_key(player_session_id))
+        websocket_logger.info(f"Released {len(player_session_ids)} players from lobby_id={lobby_id}: {reason}")
+
+    async def connect_spectator(self, websocket: WebSocket, lobby_id: int, spectator_session_id: str):
+        await websocket.accept()
+        key = spectator_key(spectator_session_id)
+        send_stats.remove(key)
+        self.spectators.setdefault(lobby_id, {})[spectator_session_id] = websocket
+        self._channel_outbox(lobby_id, key, websocket)
+        websocket_logger.info(
+            f"Spectator connected: lobby_id={lobby_id} spectator_session_id={spectator_session_id}. "
+            f"Spectators={len(self.spectators[lobby_id])}"
+        )
 
-        kick_notification_event = PlayerKickedEvent(lobby_id=lobby_id, player_session_id=player_session_id)
-        await self.broadcast_to_lobby(lobby_id, kick_notification_event)
+    async def disconnect_spectator(self, lobby_id: int, spectator_session_id: str):
+        websocket = self.spectators.get(lobby_id, {}).pop(spectator_session_id, None)
+        if not websocket:
+            return
+        key = spectator_key(spectator_session_id)
+        self._unsubscribe(lobby_id, key)
+        send_stats.remove(key)
+        try:
+            await websocket.close()
+        except Exception:
+            websocket_logger.debug(f"Spectator websocket already closed: spectator_session_id={spectator_session_id}")
+        websocket_logger.info(f"Spectator disconnected: lobby_id={lobby_id} {key}")
+
+    async def close_spectators(self, lobby_id: int, reason: str):
+        """Close every spectator of the lobby once what's queued for them (e.g. lobby_deleted) has gone out."""
+        for spectator_session_id, websocket in self.spectators.pop(lobby_id, {}).items():
+            key = spectator_key(spectator_session_id)
+            outbox = self._channel_outbox(lobby_id, key, websocket)
+            if not outbox.close_after_pending(code=1000, reason=reason):
+                try:
+                    await websocket.close(code=1000, reason=reason)
+                except Exception:
+                    websocket_logger.debug(f"Spectator websocket already closed: {key}")
+            self.channels[lobby_id].release(key)
+            send_stats.remove(key)
+
+    def _publish_to_spectators(self, lobby_id: int, prepared: PreparedEvent) -> DeliveryReport:
+        report = DeliveryReport(prepared.event_type)
+        text = prepared.for_audience(PLAYER_AUDIENCE)
+        for spectator_session_id, websocket in list(self.spectators.get(lobby_id, {}).items()):
+            self._channel_outbox(lobby_id, spectator_key(spectator_session_id), websocket).offer(report, text)
+        return report
+
+    async def listen_to_spectator(self, websocket: WebSocket, lobby_id: int, spectator_session_id: str):
+        """Keep a spectator's socket alive; spectators are read-only, so anything but a pong is ignored."""
+        label = f"lobby_id={lobby_id} spectator_session_id={spectator_session_id}"
+        connected_at = time.monotonic()
+        heartbeat = Heartbeat(connected_at)
+        pinger = start_ping_loop(websocket, label)
+        try:
+            while True:
+                data = await receive_text(websocket, connected_at, label, heartbeat)
+                try:
+                    action = json.loads(data).get("action")
+                except (ValueError, AttributeError):
+                    action = None
+                if action == "pong":
+                    heartbeat.record_pong()
+                else:
+                    websocket_logger.debug(f"Ignored message from read-only spectator: {label} action={action}")
+        finally:
+            pinger.cancel()
+
+    async def close_all(self, event: ServerEvent, code: int, reason: str) -> int:
+        """
+        Send `event` to every player and spectator and close their sockets; returns how many were connected.
 
-        await self.admin_web_socket_manager.broadcast_to_lobby(lobby_id, kick_notification_event)
+        The event isn't kept for replay: it's meant for the connections open now, not for resuming later.
+        """
+        prepared = PreparedEvent.of(event)
+        sockets = [
+            (lobby_id, player_key(player_session_id), websocket)
+            for lobby_id, members in self.lobby_websockets.items()
+            for player_session_id, websocket in members.items()
+        ] + [
+            (lobby_id, spectator_key(spectator_session_id), websocket)
+            for lobby_id, viewers in self.spectators.items()
+            for spectator_session_id, websocket in viewers.items()
+        ]
+        self.lobby_websockets.clear()
+        self.spectators.clear()
+        self.player_teams.clear()
+        # Queued events are dropped: the shutdown notice goes out directly, ahead of anything still waiting
+        for channel in self.channels.values():
+            channel.close()
+        self.channels.clear()
+        for lobby_id, key, websocket in sockets:
+            send_stats.remove(key)
+            try:
+                await websocket.send_text(prepared.for_audience(PLAYER_AUDIENCE))
+                await websocket.close(code=code, reason=reason)
+            except Exception:
+                websocket_logger.debug(f"Websocket already closed: lobby_id={lobby_id} {key}")
+        return len(sockets)
 
     def register_player_team(self, player_session_id: str, team_id: int):
         """
@@ -263,62 +796,83 @@ class LobbyWebSocketManager:
         self.player_teams.pop(player_session_id, None)
         websocket_logger.debug(f"Unregistered player {player_session_id} from team")
 
-    async def broadcast_to_team(self, lobby_id: int, team_id: int, event: dict):
+    def apply_team_change(self, event: TeamChangedEvent):
+        """
+        Keep player_teams in step with a team_changed broadcast, so later team broadcasts follow the move.
+
+        Args:
+            event: The team change; new_team_id 0 means the player was unassigned
+        """
+        if event.new_team_id:
+            self.register_player_team(event.player_session_id, event.new_team_id)
+        else:
+            self.unregister_player_team(event.player_session_id)
+
+    async def broadcast_to_team(
+        self, lobby_id: int, team_id: int, event: ServerEvent | PreparedEvent, requires_ack: bool = False
+    ) -> DeliveryReport:
         """
         Broadcast a message to all players on a specific team.
 
         Args:
             lobby_id: Lobby ID
             team_id: Team ID to broadcast to
-            event: Event data to broadcast (dict or Pydantic model)
+            event: Event to broadcast, or a PreparedEvent; serialized once
+            requires_ack: Critical event, resent to players who opted into acks until they acknowledge it
         """
-        from pydantic import BaseModel
-
-        # Convert Pydantic models to dict
-        if isinstance(event, BaseModel):
-            event_data = event.model_dump()
-        else:
-            event_data = event
-
-        websocket_logger.debug(f"Broadcasting event to team {team_id} in lobby {lobby_id}: {event_data}")
+        prepared = PreparedEvent.of(event)
+        self.replay.record(lobby_id, prepared, team_id=team_id)
+        if requires_ack:
+            members = [session_id for session_id, member_team in self.player_teams.items() if member_team == team_id]
+            self.require_ack(lobby_id, prepared, members)
+        websocket_logger.debug(f"Broadcasting event to team {team_id} in lobby {lobby_id}: {prepared.text}")
 
         members = self.lobby_websockets.get(lobby_id, {})
-        team_players = [
-            (session_id, websocket)
-            for session_id, websocket in members.items()
-            if self.player_teams.get(session_id) == team_id
-        ]
+        team_players = [session_id for session_id in members if self.player_teams.get(session_id) == team_id]
 
         if not team_players:
             websocket_logger.debug(f"No connected players in team={team_id} to broadcast to")
 
-        for session_id, websocket in team_players:
-            try:
-                await websocket.send_text(json.dumps(event_data))
-                websocket_logger.debug(f"Sent event to player_session_id={session_id} in team={team_id}")
-            except Exception:
-                websocket_logger.exception(
-                    f"Failed to send event to player_session_id={session_id} in team={team_id}; continuing."
-                )
+        report = self._publish(lobby_id, prepared, team_players)
+        if report.failures:
+            websocket_logger.warning(f"Broadcast to team={team_id} partly failed: {report.summary()}")
+        return report
 
     async def handle_game_message(self, lobby_id: int, player_session_id: str, message: dict):
         """
-        Handle game-related messages from players.
+        Parse a message from a player into a PlayerMessage and route it to its handler.
 
         Args:
             lobby_id: Lobby ID
             player_session_id: Player's session ID
             message: Message data from the player
         """
-        action = message.get("action")
-
-        if action == "submit_guess":
-            # Import here to avoid circular dependency
-            from backend.api.game import handle_guess_submission
+        try:
+            parsed = parse_player_message(message)
+        except ValidationError as e:
+            websocket_logger.warning(
+                f"Invalid player message from player_session_id={player_session_id}: "
+                f"action={message.get('action')} errors={e.errors(include_url=False)}"
+            )
+            return
 
-            await handle_guess_submission(lobby_id, player_session_id, message, self)
-        else:
-            websocket_logger.warning(f"Unknown game message action: {action}")
+        # Import here to avoid circular dependency
+        from backend.api.game import handle_guess_submission, handle_team_chat
+
+        if isinstance(parsed, SubmitGuessMessage):
+            await handle_guess_submission(lobby_id, player_session_id, parsed, self)
+        elif isinstance(parsed, TeamChatMessage):
+            await handle_team_chat(lobby_id, player_session_id, parsed, self)
+        elif isinstance(parsed, PingMessage):
+            await self.send_to_player(lobby_id, player_session_id, PongEvent(), replayable=False)
+        elif isinstance(parsed, PongMessage):
+            websocket_logger.debug(f"Player pong received: player_session_id={player_session_id}")
+        elif isinstance(parsed, AckMessage):
+            if not self.acks.ack(player_session_id, parsed.seq):
+                websocket_logger.debug(f"Ack for unknown seq={parsed.seq} from player_session_id={player_session_id}")
+        elif isinstance(parsed, DiffResetMessage):
+            websocket_logger.info(f"Diff state reset: player_session_id={player_session_id}")
+            self.diffs.reset(player_session_id)
 
     async def continuous_listening(self, websocket: WebSocket, lobby_id: int, player_session_id: str):
         """
@@ -329,17 +883,31 @@ class LobbyWebSocketManager:
             lobby_id: Lobby ID
             player_session_id: Player's session ID
         """
-        while True:
-            try:
-                data = await websocket.receive_text()
-                message = json.loads(data)
-                websocket_logger.debug(f"Player WS received message: {message}")
-
-                # Handle game messages
-                await self.handle_game_message(lobby_id, player_session_id, message)
-            except Exception:
-                websocket_logger.exception("Error while reading from player websocket. Stopping continuous listening.")
-                break
+        label = f"lobby_id={lobby_id} player_session_id={player_session_id}"
+        connected_at = time.monotonic()
+        heartbeat = Heartbeat(connected_at)
+        pinger = start_ping_loop(websocket, label)
+        try:
+            while True:
+                try:
+                    data = await receive_text(websocket, connected_at, label, heartbeat)
+                    message = json.loads(data)
+                    if message.get("action") == "pong":
+                        heartbeat.record_pong()
+                    websocket_logger.debug(f"Player WS received message: {message}")
+
+                    # Handle game messages
+                    await self.handle_game_message(lobby_id, player_session_id, message)
+                except WebSocketDisconnect:
+                    raise
+                except Exception:
+                    websocket_logger.exception(
+                        "Error while reading from player websocket. Stopping continuous listening."
+                    )
+                    break
+        finally:
+            pinger.cancel()
 
 
 lobby_websocket_manager = LobbyWebSocketManager(admin_web_socket_manager=admin_web_socket_manager)
+on_reset(lambda: lobby_websocket_manager.__init__(admin_web_socket_manager=admin_web_socket_manager))
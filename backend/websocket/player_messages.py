@@ -0,0 +1,77 @@
+"""
+Messages players send over their lobby websocket, told apart by "action".
+
+LobbyWebSocketManager.handle_game_message parses every frame into a PlayerMessage and routes it, so gameplay
+(guesses and team chat) works over the socket alone without HTTP round-trips. Frames that don't match are
+logged and dropped.
+"""
+
+from typing import Annotated, Literal, Optional, Union
+
+from pydantic import BaseModel, Field, TypeAdapter, field_validator
+
+from backend.offline_guesses import CLIENT_GUESS_ID_MAX_LENGTH
+
+TEAM_CHAT_MAX_LENGTH = 500
+
+
+class SubmitGuessMessage(BaseModel):
+    action: Literal["submit_guess"]
+    guess: str
+    word_index: int
+    # Set on guesses queued while offline; see backend/offline_guesses.py
+    client_guess_id: Optional[str] = Field(default=None, max_length=CLIENT_GUESS_ID_MAX_LENGTH)
+
+
+class TeamChatMessage(BaseModel):
+    action: Literal["team_chat"]
+    text: str = Field(max_length=TEAM_CHAT_MAX_LENGTH)
+
+    @field_validator("text")
+    @classmethod
+    def not_blank(cls, text: str) -> str:
+        text = text.strip()
+        if not text:
+            raise ValueError("Chat message is empty")
+        return text
+
+
+class PingMessage(BaseModel):
+    """Client-initiated liveness check; answered with a pong event."""
+
+    action: Literal["ping"]
+
+
+class PongMessage(BaseModel):
+    """Answer to the server's keepalive ping."""
+
+    action: Literal["pong"]
+
+
+class AckMessage(BaseModel):
+    """Acknowledges a critical event by its seq; only sent by clients that connected with ?acks=true."""
+
+    action: Literal["ack"]
+    seq: int
+
+
+class DiffResetMessage(BaseModel):
+    """Asks for every state in full again; only sent by clients that connected with ?diff=true and lost track."""
+
+    action: Literal["diff_reset"]
+
+
+PlayerMessage = Annotated[
+    Union[SubmitGuessMessage, TeamChatMessage, PingMessage, PongMessage, AckMessage, DiffResetMessage],
+    Field(discriminator="action"),
+]
+
+_player_message_adapter = TypeAdapter(PlayerMessage)
+
+
+def parse_player_message(data: dict) -> PlayerMessage:
+    """
+    Raises:
+        pydantic.ValidationError: Unknown action, or fields missing/invalid for the action
+    """
+    return _player_message_adapter.validate_python(data)
@@ -0,0 +1,67 @@
+"""
+Serialize a broadcast event once and reuse the text for every recipient.
+
+Fan-out used to call json.dumps(event.model_dump()) per connection, so a lobby of 100 players paid
+for 100 identical serializations. A PreparedEvent dumps lazily on first send and caches the result.
+Audiences that need a different payload (e.g. admins seeing fields players shouldn't) register a
+variant, which is also built once and cached.
+"""
+
+import json
+from enum import Enum
+from typing import Any, Callable, Dict, Optional, Union
+
+from pydantic import BaseModel
+
+PLAYER_AUDIENCE = "player"
+ADMIN_AUDIENCE = "admin"
+
+EventVariant = Callable[[dict], dict]
+
+
+class PreparedEvent:
+    def __init__(self, event: Union[BaseModel, dict], variants: Optional[Dict[str, EventVariant]] = None):
+        """
+        Args:
+            event: Pydantic event or plain dict to send
+            variants: Audience name -> function building that audience's payload from the default one
+        """
+        self.event = event
+        self.variants = variants or {}
+        self._data: Optional[dict] = None
+        self._texts: Dict[Optional[str], str] = {}
+
+    @classmethod
+    def of(cls, event: Union["PreparedEvent", BaseModel, dict]) -> "PreparedEvent":
+        """Wrap an event, passing an already prepared one through so its cache is shared."""
+        return event if isinstance(event, PreparedEvent) else cls(event)
+
+    @property
+    def data(self) -> Dict[str, Any]:
+        if self._data is None:
+            self._data = self.event.model_dump() if isinstance(self.event, BaseModel) else self.event
+        return self._data
+
+    @property
+    def event_type(self) -> str:
+        value = self.data.get("type", "")
+        return value.value if isinstance(value, Enum) else str(value)
+
+    @property
+    def text(self) -> str:
+        return self.for_audience(None)
+
+    def stamp(self, key: str, value: Any):
+        """Add a field to every audience's payload, dropping anything already serialized."""
+        self._data = {**self.data, key: value}
+        self._texts.clear()
+
+    def for_audience(self, audience: Optional[str]) -> str:
+        """Serialized payload for an audience; audiences without a variant get the default payload."""
+        key = audience if audience in self.variants else None
+        text = self._texts.get(key)
+        if text is None:
+            payload = self.variants[key](dict(self.data)) if key is not None else self.data
+            text = json.dumps(payload)
+            self._texts[key] = text
+        return text
@@ -0,0 +1,149 @@
+"""
+Recent player-facing events per lobby, numbered so a reconnecting player can catch up.
+
+Every event sent through LobbyWebSocketManager to players is stamped with a per-lobby "seq" and kept in a
+bounded ring buffer along with who it was for (the whole lobby, one team, or one player). A player
+reconnecting with ?last_seq=N gets whatever they would have received after N. When N has already fallen
+out of the buffer the gap can't be filled, and the client is told to reload its state instead.
+
+The long-poll endpoint (GET /api/lobby/{lobby_id}/poll) reads the same buffer, waiting in wait_for_events
+until something new is recorded for the player.
+
+All lobbies' buffers share a budget of max_bytes (their events' JSON). Past it, whole buffers of the lobbies that
+went longest without an event are dropped first, which on a long-running node are mostly finished games; their
+players just resync if they ever reconnect. A single lobby over budget on its own loses its oldest events instead.
+"""
+
+import asyncio
+import time
+from collections import OrderedDict, deque
+from dataclasses import dataclass
+from typing import Deque, Dict, List, Optional
+
+from backend.websocket.prepared import PLAYER_AUDIENCE, PreparedEvent
+
+
+@dataclass
+class BufferedEvent:
+    seq: int
+    event: PreparedEvent
+    team_id: Optional[int] = None  # Only for this team's members
+    player_session_id: Optional[str] = None  # Only for this player
+    size: int = 0  # Bytes of the player-facing JSON, counted against max_bytes
+
+    def is_for(self, player_session_id: str, team_id: Optional[int]) -> bool:
+        if self.player_session_id is not None:
+            return self.player_session_id == player_session_id
+        if self.team_id is not None:
+            return self.team_id == team_id
+        return True
+
+
+class ReplayBuffer:
+    def __init__(self, size: int, max_bytes: int = 0):
+        self.size = size
+        self.max_bytes = max_bytes  # 0 means no budget
+        # Least recently recorded-to lobby first, so eviction can take from the front
+        self.events: "OrderedDict[int, Deque[BufferedEvent]]" = OrderedDict()
+        self.last_seq: Dict[int, int] = {}
+        self.waiters: Dict[int, asyncio.Event] = {}  # Set and replaced whenever the lobby records an event
+        self.total_bytes = 0
+        self.evicted_lobbies = 0  # Whole buffers dropped to stay under max_bytes, since startup
+        self.trimmed_events = 0  # Single events dropped from a lobby over budget on its own, since startup
+
+    def record(
+        self,
+        lobby_id: int,
+        event: PreparedEvent,
+        team_id: Optional[int] = None,
+        player_session_id: Optional[str] = None,
+    ) -> int:
+        """Number the event, stamp the number into its payload, and keep it; returns the sequence number."""
+        seq = self.last_seq.get(lobby_id, 0) + 1
+        self.last_seq[lobby_id] = seq
+        event.stamp("seq", seq)
+        if self.size > 0:
+            buffer = self.events.setdefault(lobby_id, deque())
+            self.events.move_to_end(lobby_id)
+            if len(buffer) >= self.size:
+                self.total_bytes -= buffer.popleft().size
+            size = len(event.for_audience(PLAYER_AUDIENCE).encode())
+            buffer.append(BufferedEvent(seq, event, team_id, player_session_id, size))
+            self.total_bytes += size
+            self.enforce_budget(lobby_id)
+        waiter = self.waiters.pop(lobby_id, None)
+        if waiter:
+            waiter.set()
+        return seq
+
+    def missed_since(
+        self, lobby_id: int, last_seq: int, player_session_id: str, team_id: Optional[int]
+    ) -> Optional[List[PreparedEvent]]:
+        """
+        Events after last_seq meant for this player, oldest first.
+
+        Returns None when events after last_seq have already been dropped, so replaying would leave a gap.
+        A last_seq from before a server restart (ahead of the current counter) is treated the same way.
+        """
+        current = self.last_seq.get(lobby_id, 0)
+        if last_seq > current:
+            return None
+        if last_seq == current:
+            return []
+        buffer = self.events.get(lobby_id)
+        if not buffer or buffer[0].seq > last_seq + 1:
+            return None
+        return [
+            buffered.event
+            for buffered in buffer
+            if buffered.seq > last_seq and buffered.is_for(player_session_id, team_id)
+        ]
+
+    async def wait_for_events(
+        self, lobby_id: int, last_seq: int, player_session_id: str, team_id: Optional[int], timeout: float
+    ) -> Optional[List[PreparedEvent]]:
+        """
+        Like missed_since, but when nothing is waiting for the player, wait up to timeout seconds for something.
+
+        Returns an empty list when the timeout passes with nothing new for them.
+        """
+        deadline = time.monotonic() + timeout
+        while True:
+            missed = self.missed_since(lobby_id, last_seq, player_session_id, team_id)
+            remaining = deadline - time.monotonic()
+            if missed != [] or remaining <= 0:
+                return missed
+            waiter = self.waiters.setdefault(lobby_id, asyncio.Event())
+            try:
+                await asyncio.wait_for(waiter.wait(), remaining)
+            except asyncio.TimeoutError:
+                return []
+
+    def enforce_budget(self, active_lobby_id: int):
+        """Drop the least recently active lobbies' buffers, then this lobby's oldest events, until under budget."""
+        while self.max_bytes > 0 and self.total_bytes > self.max_bytes:
+            oldest_lobby_id = next(iter(self.events))
+            if oldest_lobby_id != active_lobby_id:
+                self._drop_buffer(oldest_lobby_id)
+                self.evicted_lobbies += 1
+                continue
+            buffer = self.events[active_lobby_id]
+            if len(buffer) <= 1:
+                break  # Keep the event just recorded, however big
+            self.total_bytes -= buffer.popleft().size
+            self.trimmed_events += 1
+
+    def _drop_buffer(self, lobby_id: int):
+        buffer = self.events.pop(lobby_id, None)
+        if buffer:
+            self.total_bytes -= sum(buffered.size for buffered in buffer)
+
+    def event_count(self) -> int:
+        return sum(len(buffer) for buffer in self.events.values())
+
+    def forget(self, lobby_id: int):
+        self._drop_buffer(lobby_id)
+        self.last_seq.pop(lobby_id, None)
+        waiter = self.waiters.pop(lobby_id, None)
+        if waiter:
+            waiter.set()
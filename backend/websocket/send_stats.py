@@ -0,0 +1,76 @@
+"""Per-connection send latency tracking, used to spot slow clients holding up broadcasts."""
+
+import time
+from collections import deque
+from typing import Any, Deque, Dict, Optional
+
+from backend.in_memory_state import on_reset
+
+# Recent samples kept per connection for the percentile; older sends only count toward the totals
+SAMPLE_WINDOW = 200
+# Too few sends make the p95 meaningless, so don't flag a client before this many
+MIN_SAMPLES_FOR_SLOW = 20
+
+
+class SendStats:
+    def __init__(self):
+        self.messages_sent = 0
+        self.total_seconds = 0.0
+        self.max_seconds = 0.0
+        self.samples: Deque[float] = deque(maxlen=SAMPLE_WINDOW)
+
+    def record(self, seconds: float):
+        self.messages_sent += 1
+        self.total_seconds += seconds
+        self.max_seconds = max(self.max_seconds, seconds)
+        self.samples.append(seconds)
+
+    @property
+    def avg_ms(self) -> float:
+        return self.total_seconds / self.messages_sent * 1000 if self.messages_sent else 0.0
+
+    @property
+    def p95_ms(self) -> float:
+        if not self.samples:
+            return 0.0
+        ordered = sorted(self.samples)
+        index = min(len(ordered) - 1, int(len(ordered) * 0.95))
+        return ordered[index] * 1000
+
+    def is_slow(self, threshold_ms: float) -> bool:
+        return len(self.samples) >= MIN_SAMPLES_FOR_SLOW and self.p95_ms > threshold_ms
+
+
+class SendStatsRegistry:
+    """Send stats keyed by connection, e.g. "player:<session_id>" or "admin:<web_session_id>"."""
+
+    def __init__(self):
+        self.stats: Dict[str, SendStats] = {}
+
+    async def send_text(self, key: str, websocket: Any, text: str):
+        """Send on the websocket, recording how long the send took. Failed sends aren't recorded."""
+        started = time.perf_counter()
+        await websocket.send_text(text)
+        self.stats.setdefault(key, SendStats()).record(time.perf_counter() - started)
+
+    def get(self, key: str) -> Optional[SendStats]:
+        return self.stats.get(key)
+
+    def remove(self, key: str):
+        self.stats.pop(key, None)
+
+
+send_stats = SendStatsRegistry()
+on_reset(send_stats.__init__)
+
+
+def player_key(player_session_id: str) -> str:
+    return f"player:{player_session_id}"
+
+
+def admin_key(web_session_id: str) -> str:
+    return f"admin:{web_session_id}"
+
+
+def spectator_key(spectator_session_id: str) -> str:
+    return f"spectator:{spectator_session_id}"
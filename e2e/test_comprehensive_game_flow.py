@@ -486,7 +486,7 @@ class TestComprehensiveGameFlow:
         player_actions_fixture: PlayerFixture,
         settings: Settings,
     ):
-        """Test moving a player to a different team during an active game."""
+        """Test that moving a player to a different team is refused during an active game."""
         test_name = "TEST_10"
 
         # Setup admin with lobby
@@ -546,22 +546,21 @@ class TestComprehensiveGameFlow:
         await admin_actions.move_player_to_team("Eva", team2_name)
         await admin_session.screenshot("23_eva_moved_to_team2")
 
-        # Wait for WebSocket message
+        # Give any (unexpected) WebSocket message time to arrive
         await player2_page.wait_for_timeout(3000)
 
-        # Check console logs
-        ws_logs = [log for log in console_logs if "GameState" in log or "team_changed" in log or "Team changed" in log]
-        print(f"WebSocket/GameState logs: {ws_logs}")
+        # The move is rejected with GAME_IN_PROGRESS, so no team change is broadcast
+        ws_logs = [log for log in console_logs if "team_changed" in log or "Team changed" in log]
+        print(f"Team change logs: {ws_logs}")
+        assert not ws_logs
 
-        # Check URL after change
+        # Eva keeps playing with her original team
         eva_url_after = player2_page.url
-        print(f"After team change - Eva URL: {eva_url_after}")
+        print(f"After attempted team change - Eva URL: {eva_url_after}")
+        assert "/game" in eva_url_after
+        await player2_session.screenshot("24_eva_still_in_game_with_original_team")
 
-        # Eva should be redirected to game with new team's puzzle
-        await player2_page.wait_for_url("**/game", timeout=10000)
-        await player2_session.screenshot("24_eva_back_in_game_with_new_team")
-
-        print("Player moved during game")
+        print("Player move refused during game")
 
     async def test_11_complete_game_multi_player_multi_direction(
         self,
@@ -767,13 +766,7 @@ class TestComprehensiveGameFlow:
         await player1_actions.submit_incorrect_guess()
         await player2_actions.submit_incorrect_guess()
 
-        # Alice returns to lobby
-        await player1_actions.leave_lobby()
-        await player1_page.wait_for_timeout(500)
-        await player1_actions.fill_name_and_code("Alice", lobby_code)
-        await player1_actions.join_lobby()
-
-        # Charlie stays on game screen
+        # Both players stay on the game screen
         # End the game and start a new one
         await admin_actions.goto_admin_page()
         await admin_actions.peek_into_lobby(lobby_code)
@@ -938,7 +931,7 @@ class TestComprehensiveGameFlow:
         player_actions_fixture: PlayerFixture,
         settings: Settings,
     ):
-        """Test player voluntarily leaving during a game and rejoining."""
+        """Test that a player can't leave during a game and is free to once it ends."""
         test_name = "TEST_16"
 
         # Setup admin with lobby
@@ -967,32 +960,26 @@ class TestComprehensiveGameFlow:
         await player1_actions.wait_for_game_to_start(timeout=15000)
         await player2_actions.wait_for_game_to_start(timeout=15000)
 
-        # Diana leaves mid-game
-        print("Diana leaving game...")
-        await player2_actions.leave_lobby()
-        await player2_session.screenshot("44_diana_left_during_game")
+        # Diana tries to leave mid-game; the server refuses with GAME_IN_PROGRESS
+        print("Diana trying to leave game...")
+        await player2_page.locator('[data-testid="logout-button"]').click()
+        await player2_page.wait_for_timeout(1500)
+        assert "/game" in player2_page.url
+        await player2_session.screenshot("44_diana_still_in_game")
 
-        # Charlie continues
+        # Both keep playing
         await player1_actions.submit_incorrect_guess()
-
-        # Diana rejoins
-        print("Diana rejoining...")
-        await player2_actions.fill_name_and_code("Diana", lobby_code)
-        await player2_actions.join_lobby()
-        await player2_session.screenshot("46_diana_rejoined_lobby")
-
-        # Verify Diana is unassigned
-        await player2_actions.verify_unassigned(timeout=5000)
-
-        # Admin assigns Diana back
-        await admin_actions.move_player_to_team("Diana", team2_name)
-
-        # Diana redirected to game
-        await player2_actions.wait_for_game_to_start(timeout=10000)
         await player2_actions.submit_incorrect_guess()
-        await player2_session.screenshot("47_diana_playing_after_reassignment")
 
-        print("Player leaving and rejoining mid-game works")
+        # Once the game ends Diana can leave
+        await admin_actions.goto_admin_page()
+        await admin_actions.peek_into_lobby(lobby_code)
+        await admin_actions.end_game()
+        await player2_page.wait_for_timeout(1000)
+        await player2_actions.leave_lobby()
+        await player2_session.screenshot("46_diana_left_after_game")
+
+        print("Leaving is refused mid-game and allowed after it ends")
 
     async def test_17_websocket_reconnection(
         self,
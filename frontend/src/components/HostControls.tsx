@@ -0,0 +1,106 @@
+import { useState } from 'react';
+import { api } from '@/services/api';
+import { Button, Card, Select } from '@/components';
+
+interface HostControlsProps {
+    sessionId: string;
+    hasTeams: boolean;
+    playerCount: number;
+    onError: (message: string) => void;
+}
+
+const DIFFICULTY_OPTIONS = [
+    { value: 'easy', label: 'Easy' },
+    { value: 'medium', label: 'Medium' },
+    { value: 'hard', label: 'Hard' },
+];
+
+// Shown to the lobby host, who can run a casual game without the admin panel
+export function HostControls({ sessionId, hasTeams, playerCount, onError }: HostControlsProps) {
+    const [numTeams, setNumTeams] = useState(2);
+    const [difficulty, setDifficulty] = useState('medium');
+    const [busyAction, setBusyAction] = useState<string | null>(null);
+
+    const run = async (action: string, call: () => Promise<unknown>) => {
+        setBusyAction(action);
+        try {
+            await call();
+            // The lobby reloads from the websocket events these broadcast
+        } catch (err) {
+            onError(err instanceof Error ? err.message : `Failed to ${action}`);
+        } finally {
+            setBusyAction(null);
+        }
+    };
+
+    const teamOptions = Array.from({ length: Math.max(1, Math.min(playerCount, 10) - 1) }, (_, i) => ({
+        value: i + 2,
+        label: `${i + 2} teams`,
+    }));
+
+    return (
+        <Card className='bg-secondary/70' data-testid='host-controls'>
+            <div className='text-tx-secondary mb-3 text-xs font-semibold tracking-wide uppercase'>You are the host</div>
+            <div className='flex flex-wrap items-end gap-3'>
+                <Select
+                    value={numTeams}
+                    onChange={value => setNumTeams(Number(value))}
+                    options={teamOptions}
+                    label='Teams'
+                    data-testid='host-num-teams'
+                />
+                <Button
+                    variant='secondary'
+                    loading={busyAction === 'create teams'}
+                    disabled={busyAction !== null}
+                    onClick={() => run('create teams', () => api.player.lobby.host.createTeams(numTeams, sessionId))}
+                    data-testid='host-create-teams'
+                >
+                    {hasTeams ? 'Recreate Teams' : 'Create Teams'}
+                </Button>
+                {hasTeams && (
+                    <Button
+                        variant='secondary'
+                        loading={busyAction === 'shuffle teams'}
+                        disabled={busyAction !== null}
+                        onClick={() => run('shuffle teams', () => api.player.lobby.host.shuffleTeams(sessionId))}
+                        data-testid='host-shuffle-teams'
+                    >
+                        Shuffle Teams
+                    </Button>
+                )}
+            </div>
+            {hasTeams && (
+                <div className='mt-4 flex flex-wrap items-end gap-3'>
+                    <Select
+                        value={difficulty}
+                        onChange={value => setDifficulty(String(value))}
+                        options={DIFFICULTY_OPTIONS}
+                        label='Difficulty'
+                        data-testid='host-difficulty'
+                    />
+                    <Button
+                        loading={busyAction === 'start the game'}
+                        disabled={busyAction !== null}
+                        onClick={() =>
+                            run('start the game', () =>
+                                api.player.lobby.host.startGame(
+                                    {
+                                        difficulty,
+                                        puzzle_mode: 'different',
+                                        word_count_mode: 'balanced',
+                                        force_start: false,
+                                    },
+                                    sessionId
+                                )
+                            )
+                        }
+                        data-testid='host-start-game'
+                    >
+                        Start Game
+                    </Button>
+                </div>
+            )}
+        </Card>
+    );
+}
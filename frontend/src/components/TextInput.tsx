@@ -1,6 +1,6 @@
 interface TextInputProps {
     id?: string;
-    type?: 'text' | 'password' | 'email' | 'number';
+    type?: 'text' | 'password' | 'email' | 'number' | 'datetime-local';
     value: string;
     onChange: (value: string) => void;
     placeholder?: string;
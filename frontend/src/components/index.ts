@@ -5,6 +5,7 @@ export { default as ConnectionBadge } from '@/components/ConnectionBadge';
 export { default as CopyableCode } from '@/components/CopyableCode';
 export { default as ErrorMessage } from '@/components/ErrorMessage';
 export { default as HintConfirmationModal } from '@/components/HintConfirmationModal';
+export { HostControls } from '@/components/HostControls';
 export { default as LoadingSpinner } from '@/components/LoadingSpinner';
 export { default as Modal } from '@/components/Modal';
 export { default as Select } from '@/components/Select';
@@ -0,0 +1,40 @@
+import { renderHook } from '@testing-library/react';
+import { describe, test, expect, beforeEach } from 'vitest';
+import { ACCESSIBILITY_CLASSES, useAccessibilityPreferences } from '@/hooks/useAccessibilityPreferences';
+import { PlayerPreferences } from '@/types';
+
+const noPreferences: PlayerPreferences = { reduced_motion: false, high_contrast: false, larger_text: false };
+
+describe('useAccessibilityPreferences Hook', () => {
+    beforeEach(() => {
+        document.documentElement.className = '';
+    });
+
+    test('adds a class for each enabled preference', () => {
+        renderHook(() => useAccessibilityPreferences({ ...noPreferences, high_contrast: true, larger_text: true }));
+
+        const classes = document.documentElement.classList;
+        expect(classes.contains(ACCESSIBILITY_CLASSES.high_contrast)).toBe(true);
+        expect(classes.contains(ACCESSIBILITY_CLASSES.larger_text)).toBe(true);
+        expect(classes.contains(ACCESSIBILITY_CLASSES.reduced_motion)).toBe(false);
+    });
+
+    test('removes classes when a preference is turned off', () => {
+        const { rerender } = renderHook(props => useAccessibilityPreferences(props), {
+            initialProps: { ...noPreferences, reduced_motion: true },
+        });
+        expect(document.documentElement.classList.contains(ACCESSIBILITY_CLASSES.reduced_motion)).toBe(true);
+
+        rerender(noPreferences);
+
+        expect(document.documentElement.classList.contains(ACCESSIBILITY_CLASSES.reduced_motion)).toBe(false);
+    });
+
+    test('leaves classes alone while the player is still loading', () => {
+        document.documentElement.classList.add(ACCESSIBILITY_CLASSES.larger_text);
+
+        renderHook(() => useAccessibilityPreferences(null));
+
+        expect(document.documentElement.classList.contains(ACCESSIBILITY_CLASSES.larger_text)).toBe(true);
+    });
+});
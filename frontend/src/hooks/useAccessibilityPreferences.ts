@@ -0,0 +1,28 @@
+import { useEffect } from 'react';
+import { PlayerPreferences } from '@/types';
+
+export const ACCESSIBILITY_CLASSES: Record<keyof PlayerPreferences, string> = {
+    reduced_motion: 'a11y-reduced-motion',
+    high_contrast: 'a11y-high-contrast',
+    larger_text: 'a11y-larger-text',
+};
+
+/**
+ * Apply a player's saved accessibility preferences as classes on the root element.
+ * Classes are left in place on unmount so moving between lobby and game pages doesn't flicker.
+ * @param preferences - The player's preferences, or null while they're still loading
+ */
+export function useAccessibilityPreferences(preferences: PlayerPreferences | null | undefined) {
+    const reducedMotion = preferences?.reduced_motion ?? false;
+    const highContrast = preferences?.high_contrast ?? false;
+    const largerText = preferences?.larger_text ?? false;
+    const loaded = preferences != null;
+
+    useEffect(() => {
+        if (!loaded) return;
+        const root = document.documentElement;
+        root.classList.toggle(ACCESSIBILITY_CLASSES.reduced_motion, reducedMotion);
+        root.classList.toggle(ACCESSIBILITY_CLASSES.high_contrast, highContrast);
+        root.classList.toggle(ACCESSIBILITY_CLASSES.larger_text, largerText);
+    }, [loaded, reducedMotion, highContrast, largerText]);
+}
@@ -1,8 +1,13 @@
 import { renderHook, act, waitFor } from '@testing-library/react';
 import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 import { useGameState } from './useGameState';
+import { api } from '@/services/api';
 import type { Puzzle } from '@/types/game';
 
+vi.mock('@/services/api', () => ({
+    api: { player: { lobby: { getTeamChat: vi.fn() }, game: { getPuzzle: vi.fn() } } },
+}));
+
 // Mock useWebSocket
 vi.mock('./useWebSocket', () => ({
     useWebSocket: vi.fn((url, options) => {
@@ -266,4 +271,37 @@ describe('useGameState', () => {
             expect(onGameWon).toHaveBeenCalledWith(gameWonEvent);
         });
     });
+
+    describe('Team chat', () => {
+        it('should load the team chat history', async () => {
+            vi.mocked(api.player.lobby.getTeamChat).mockResolvedValue({
+                messages: [
+                    {
+                        id: 1,
+                        team_id: 2,
+                        player_id: 3,
+                        player_name: 'Alice',
+                        text: 'try the bottom',
+                        sent_at: '2025-01-01T00:00:05Z',
+                    },
+                ],
+            });
+
+            const { result } = renderHook(() =>
+                useGameState({
+                    puzzle: mockPuzzle,
+                    initialState: mockInitialState,
+                    websocketUrl: 'ws://test',
+                    sessionId: 'session-1',
+                })
+            );
+
+            await waitFor(() => expect(result.current.chatMessages).toHaveLength(1));
+            expect(result.current.chatMessages[0]).toMatchObject({
+                type: 'team_chat',
+                player_name: 'Alice',
+                text: 'try the bottom',
+            });
+        });
+    });
 });
@@ -2,10 +2,24 @@
  * Simplified game state hook - fully authoritative server model.
  */
 
-import { useCallback, useState } from 'react';
+import { useCallback, useEffect, useRef, useState } from 'react';
 import { useWebSocket } from './useWebSocket';
+import { api } from '@/services/api';
 import type { Puzzle } from '@/types/game';
-import type { GuessSubmittedEvent, TeamPlacedEvent, WebSocketMessage } from '@/types';
+import { GameWebSocketEvents } from '@/types';
+import type {
+    GuessReviewedEvent,
+    GuessSubmittedEvent,
+    TeamPlacedEvent,
+    WebSocketMessage,
+    WordRolledBackEvent,
+    PaceHintEvent,
+    QueuedGuess,
+    TeamChatEvent,
+} from '@/types';
+
+// Matches the server's default TEAM_CHAT_HISTORY_SIZE
+const MAX_CHAT_MESSAGES = 50;
 
 interface GameState {
     revealed_steps: number[];
@@ -25,6 +39,7 @@ interface UseGameStateProps {
     onGameEnded?: () => void;
     onGameStarted?: () => void;
     onTimerStarted?: (event: { duration_seconds: number; started_at: string; expires_at: string }) => void;
+    onTimerTick?: (event: { remaining_seconds: number; expires_at: string }) => void;
     onTimerExpired?: () => void;
     sessionId?: string;
     maxRetries?: number;
@@ -44,6 +59,7 @@ export function useGameState({
     onGameEnded,
     onGameStarted,
     onTimerStarted,
+    onTimerTick,
     onTimerExpired,
     sessionId,
     maxRetries,
@@ -55,6 +71,35 @@ export function useGameState({
     const [direction, setDirection] = useState<'down' | 'up'>('down');
     const [error, setError] = useState<string | null>(null);
     const [lastGuessResult, setLastGuessResult] = useState<GuessSubmittedEvent | null>(null);
+    const [chatMessages, setChatMessages] = useState<TeamChatEvent[]>([]);
+    // Guesses made while the socket was down, sent in one batch when it's back
+    const queuedGuessesRef = useRef<QueuedGuess[]>([]);
+
+    // Fill the chat panel from the team's stored history, keeping live lines that arrived after it
+    const loadChatHistory = useCallback(() => {
+        if (!sessionId) return;
+        api.player.lobby
+            .getTeamChat(sessionId)
+            .then(({ messages }) => {
+                const stored: TeamChatEvent[] = messages.map(({ team_id, player_id, player_name, text, sent_at }) => ({
+                    type: GameWebSocketEvents.TEAM_CHAT,
+                    team_id,
+                    player_id,
+                    player_name,
+                    text,
+                    sent_at,
+                }));
+                const newest = stored.length ? Date.parse(stored[stored.length - 1].sent_at) : 0;
+                setChatMessages(prev =>
+                    [...stored, ...prev.filter(chat => Date.parse(chat.sent_at) > newest)].slice(-MAX_CHAT_MESSAGES)
+                );
+            })
+            .catch(err => console.error('[GameState] Failed to load team chat:', err));
+    }, [sessionId]);
+
+    useEffect(() => {
+        loadChatHistory();
+    }, [loadChatHistory]);
 
     const handleServerMessage = useCallback(
         (message: WebSocketMessage) => {
@@ -78,6 +123,42 @@ export function useGameState({
                     setLastGuessResult(message as GuessSubmittedEvent);
                     break;
 
+                case 'guess_pending_review':
+                    setError('Answer sent to the host for review');
+                    setTimeout(() => setError(null), 3000);
+                    break;
+
+                case 'guess_reviewed': {
+                    // Approved answers arrive as a state_update; only rejections need a notice
+                    const review = message as unknown as GuessReviewedEvent;
+                    if (!review.approved) {
+                        setError(`The host didn't accept "${review.guess}"`);
+                        setTimeout(() => setError(null), 3000);
+                    }
+                    break;
+                }
+
+                case 'word_rolled_back': {
+                    // The corrected state arrives as a state_update; this just explains why a word disappeared
+                    const rollback = message as unknown as WordRolledBackEvent;
+                    setError(`The host took back "${rollback.word}" - it needs solving again`);
+                    setTimeout(() => setError(null), 5000);
+                    break;
+                }
+
+                case 'team_chat': {
+                    const chat = message as unknown as TeamChatEvent;
+                    setChatMessages(prev => [...prev, chat].slice(-MAX_CHAT_MESSAGES));
+                    break;
+                }
+
+                case 'pace_hint': {
+                    const paceHint = message as unknown as PaceHintEvent;
+                    setError(`Hint for step ${paceHint.word_index + 1}: ${paceHint.hint}`);
+                    setTimeout(() => setError(null), 10000);
+                    break;
+                }
+
                 case 'team_completed':
                     console.log('[GameState] Team completed!');
                     onTeamCompleted?.();
@@ -113,6 +194,12 @@ export function useGameState({
                     }
                     break;
 
+                case 'teams_reassigned':
+                    if (sessionId && message.moves?.some(move => move.player_session_id === sessionId)) {
+                        onTeamChanged?.();
+                    }
+                    break;
+
                 case 'game_ended':
                     console.log('[GameState] Game ended by admin');
                     onGameEnded?.();
@@ -133,11 +220,30 @@ export function useGameState({
                     onTimerStarted?.(message as { duration_seconds: number; started_at: string; expires_at: string });
                     break;
 
+                case 'timer_tick':
+                    onTimerTick?.(message as { remaining_seconds: number; expires_at: string });
+                    break;
+
                 case 'timer_expired':
                     console.log('[GameState] Timer expired');
                     onTimerExpired?.();
                     break;
 
+                case 'resync_required':
+                    // Too much was missed while disconnected to replay; reload the authoritative state instead
+                    console.log('[GameState] Resync required after reconnect');
+                    if (sessionId) {
+                        api.player.game
+                            .getPuzzle(sessionId)
+                            .then(({ state }) => {
+                                setRevealedSteps(new Set(state.revealed_steps));
+                                setIsCompleted(state.is_completed);
+                            })
+                            .catch(err => console.error('[GameState] Failed to resync game state:', err));
+                    }
+                    loadChatHistory();
+                    break;
+
                 default:
                     break;
             }
@@ -151,14 +257,24 @@ export function useGameState({
             onGameEnded,
             onGameStarted,
             onTimerStarted,
+            onTimerTick,
             onTimerExpired,
             sessionId,
+            loadChatHistory,
         ]
     );
 
+    const connectToken = useCallback(
+        async () => (await api.player.lobby.getWebSocketToken(sessionId ?? '')).token,
+        [sessionId]
+    );
+
     const { isConnected, sendMessage, connectionStatus, retryCount, manualReconnect } = useWebSocket(websocketUrl, {
         onMessage: handleServerMessage,
         autoReconnect: true,
+        connectToken,
+        resumable: true,
+        diff: true,
         maxRetries,
         onMaxRetriesReached,
         onReconnecting,
@@ -205,13 +321,24 @@ export function useGameState({
 
     const submitGuess = useCallback(
         (guess: string) => {
-            if (!isConnected) {
-                setError('Not connected to server');
+            if (isCompleted) {
                 return false;
             }
 
-            if (isCompleted) {
-                return false;
+            if (!isConnected) {
+                if (!sessionId) {
+                    setError('Not connected to server');
+                    return false;
+                }
+                queuedGuessesRef.current.push({
+                    guess: guess.toUpperCase(),
+                    word_index: activeStepId,
+                    // Not crypto.randomUUID, which only exists on https and venue servers are often plain http
+                    client_guess_id: `${Date.now().toString(36)}-${Math.random().toString(36).slice(2, 10)}`,
+                    submitted_at: new Date().toISOString(),
+                });
+                setError('Offline - your guess will be sent when you reconnect');
+                return true;
             }
 
             const message = {
@@ -224,7 +351,49 @@ export function useGameState({
             sendMessage(message);
             return true;
         },
-        [isConnected, isCompleted, activeStepId, sendMessage]
+        [isConnected, isCompleted, activeStepId, sendMessage, sessionId]
+    );
+
+    // Send queued guesses once reconnected; the server skips any it already has, so a failed flush can be retried
+    useEffect(() => {
+        if (!isConnected || !sessionId || queuedGuessesRef.current.length === 0) {
+            return;
+        }
+        const queued = queuedGuessesRef.current;
+        queuedGuessesRef.current = [];
+        console.log('[GameState] Sending guesses queued while offline:', queued.length);
+        api.player.game
+            .submitQueuedGuesses(queued, sessionId)
+            .then(({ results }) => {
+                const judged = results.filter(result => result.result !== 'rejected');
+                const latest = judged[judged.length - 1];
+                if (latest) {
+                    setRevealedSteps(prev => new Set([...prev, ...latest.revealed_steps]));
+                    setIsCompleted(prev => prev || latest.is_completed);
+                }
+                const rejected = results.length - judged.length;
+                setError(
+                    rejected
+                        ? `${rejected} ${rejected === 1 ? 'guess' : 'guesses'} made while offline couldn't be counted`
+                        : null
+                );
+            })
+            .catch(err => {
+                console.error('[GameState] Failed to send queued guesses:', err);
+                queuedGuessesRef.current = [...queued, ...queuedGuessesRef.current];
+            });
+    }, [isConnected, sessionId]);
+
+    const sendChat = useCallback(
+        (text: string) => {
+            const trimmed = text.trim();
+            if (!isConnected || !trimmed) {
+                return false;
+            }
+            sendMessage({ action: 'team_chat', text: trimmed });
+            return true;
+        },
+        [isConnected, sendMessage]
     );
 
     const switchDirection = useCallback(() => {
@@ -250,5 +419,7 @@ export function useGameState({
         retryCount,
         manualReconnect,
         lastGuessResult,
+        chatMessages,
+        sendChat,
     };
 }
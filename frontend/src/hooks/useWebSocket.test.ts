@@ -1,6 +1,6 @@
 import { renderHook, act } from '@testing-library/react';
 import { describe, test, expect, vi, beforeEach, afterEach } from 'vitest';
-import { useWebSocket } from '@/hooks/useWebSocket';
+import { applyMergePatch, resolveStateDiff, useWebSocket } from '@/hooks/useWebSocket';
 
 // Mock WebSocket
 class MockWebSocket {
@@ -119,6 +119,22 @@ describe('useWebSocket Hook', () => {
             expect(onMessage).toHaveBeenCalledWith(testMessage);
         });
 
+        test('answers keepalive pings without forwarding them', async () => {
+            const onMessage = vi.fn();
+            renderHook(() => useWebSocket('ws://localhost:8000', { onMessage }));
+
+            await act(async () => {
+                await vi.runOnlyPendingTimersAsync();
+            });
+
+            act(() => {
+                mockWebSocket.simulateMessage(JSON.stringify({ type: 'ping' }));
+            });
+
+            expect(mockWebSocket.send).toHaveBeenCalledWith(JSON.stringify({ action: 'pong' }));
+            expect(onMessage).not.toHaveBeenCalled();
+        });
+
         test('handles invalid JSON messages gracefully', async () => {
             const onMessage = vi.fn();
             const consoleSpy = vi.spyOn(console, 'error').mockImplementation(() => {});
@@ -240,6 +256,106 @@ describe('useWebSocket Hook', () => {
             expect(global.WebSocket).toHaveBeenCalledTimes(2);
         });
 
+        test('resumable reconnects send the last seen seq', async () => {
+            renderHook(() =>
+                useWebSocket('ws://localhost:8000/ws/lobby/1/player/abc', { resumable: true, reconnectInterval: 1000 })
+            );
+
+            await act(async () => {
+                await vi.runOnlyPendingTimersAsync();
+            });
+
+            act(() => {
+                mockWebSocket.simulateMessage(JSON.stringify({ type: 'player_joined', seq: 7 }));
+                mockWebSocket.simulateMessage(JSON.stringify({ type: 'pong' }));
+                mockWebSocket.simulateClose();
+            });
+
+            await act(async () => {
+                vi.advanceTimersByTime(1000);
+                await vi.runOnlyPendingTimersAsync();
+            });
+
+            expect(global.WebSocket).toHaveBeenLastCalledWith('ws://localhost:8000/ws/lobby/1/player/abc?last_seq=7');
+        });
+
+        test('fetches a fresh connect token for every attempt', async () => {
+            const connectToken = vi.fn().mockResolvedValueOnce('first').mockResolvedValueOnce('second');
+            renderHook(() =>
+                useWebSocket('ws://localhost:8000/ws/lobby/1/player', { connectToken, reconnectInterval: 1000 })
+            );
+
+            await act(async () => {
+                await vi.runOnlyPendingTimersAsync();
+            });
+
+            expect(global.WebSocket).toHaveBeenLastCalledWith('ws://localhost:8000/ws/lobby/1/player?token=first');
+
+            act(() => {
+                mockWebSocket.simulateClose();
+            });
+
+            await act(async () => {
+                vi.advanceTimersByTime(1000);
+                await vi.runOnlyPendingTimersAsync();
+            });
+
+            expect(connectToken).toHaveBeenCalledTimes(2);
+            expect(global.WebSocket).toHaveBeenLastCalledWith('ws://localhost:8000/ws/lobby/1/player?token=second');
+        });
+
+        test('acknowledges critical events and skips resends already handled', async () => {
+            const onMessage = vi.fn();
+            renderHook(() =>
+                useWebSocket('ws://localhost:8000/ws/lobby/1/player/abc', {
+                    onMessage,
+                    resumable: true,
+                    acknowledge: true,
+                })
+            );
+
+            await act(async () => {
+                await vi.runOnlyPendingTimersAsync();
+            });
+
+            expect(global.WebSocket).toHaveBeenLastCalledWith('ws://localhost:8000/ws/lobby/1/player/abc?acks=true');
+
+            const gameStarted = JSON.stringify({ type: 'game_started', seq: 3, ack_required: true });
+            act(() => {
+                mockWebSocket.simulateMessage(gameStarted);
+                mockWebSocket.simulateMessage(gameStarted);
+            });
+
+            expect(mockWebSocket.send).toHaveBeenCalledTimes(2);
+            expect(mockWebSocket.send).toHaveBeenCalledWith(JSON.stringify({ action: 'ack', seq: 3 }));
+            expect(onMessage).toHaveBeenCalledTimes(1);
+        });
+
+        test('waits out a server shutdown before reconnecting', async () => {
+            renderHook(() => useWebSocket('ws://localhost:8000', { reconnectInterval: 1000 }));
+
+            await act(async () => {
+                await vi.runOnlyPendingTimersAsync();
+            });
+
+            act(() => {
+                mockWebSocket.simulateMessage(
+                    JSON.stringify({ type: 'server_shutdown', reason: 'restart', reconnect_after_seconds: 5 })
+                );
+                mockWebSocket.simulateClose();
+            });
+
+            await act(async () => {
+                await vi.advanceTimersByTimeAsync(1000);
+            });
+            expect(global.WebSocket).toHaveBeenCalledTimes(1);
+
+            await act(async () => {
+                await vi.advanceTimersByTimeAsync(4000);
+            });
+            expect(global.WebSocket).toHaveBeenCalledTimes(2);
+        });
+
         test('does not reconnect when autoReconnect is false', async () => {
             renderHook(() => useWebSocket('ws://localhost:8000', { autoReconnect: false }));
 
@@ -395,3 +511,46 @@ describe('useWebSocket Hook', () => {
         });
     });
 });
+
+describe('State diffs', () => {
+    test('merge patches replace, merge and remove fields', () => {
+        const patched = applyMergePatch(
+            { a: { b: 1, c: 2 }, list: [1], gone: true },
+            { a: { c: 3 }, list: [1, 2], gone: null }
+        );
+
+        expect(patched).toEqual({ a: { b: 1, c: 3 }, list: [1, 2] });
+    });
+
+    test('diffs are rebuilt on the last full copy of their state', () => {
+        const states = new Map();
+        const full = {
+            type: 'state_update',
+            team_id: 10,
+            revealed_steps: [0, 4],
+            state_key: 'state_update:10',
+            state_version: 1,
+        };
+
+        expect(resolveStateDiff(states, full)).toEqual({ type: 'state_update', team_id: 10, revealed_steps: [0, 4] });
+        const diff = {
+            type: 'state_diff',
+            of: 'state_update',
+            state_key: 'state_update:10',
+            base_version: 1,
+            version: 2,
+            patch: { revealed_steps: [0, 1, 4] },
+        };
+        expect(resolveStateDiff(states, diff)).toEqual({
+            type: 'state_update',
+            team_id: 10,
+            revealed_steps: [0, 1, 4],
+        });
+    });
+
+    test('a diff against a version the client missed is refused', () => {
+        const diff = { type: 'state_diff', state_key: 'state_update:10', base_version: 3, version: 4, patch: {} };
+
+        expect(resolveStateDiff(new Map(), diff)).toBeNull();
+    });
+});
@@ -11,6 +11,95 @@ interface UseWebSocketOptions {
     maxRetries?: number;
     onMaxRetriesReached?: () => void;
     onReconnecting?: (attemptNumber: number) => void;
+    // Reconnect with ?last_seq=N so the server replays events missed while disconnected
+    resumable?: boolean;
+    // Connect with ?acks=true and acknowledge critical events, which the server resends until they're acked
+    acknowledge?: boolean;
+    // Connect with ?diff=true to get state_update/team_progress as merge patches, rebuilt here before onMessage
+    diff?: boolean;
+    // Fetches a short-lived ?token= before every connection attempt, for sockets that don't take the session token in
+    // the URL (players: POST /api/lobby/ws-token, admins: POST /api/admin/ws-token)
+    connectToken?: () => Promise<string>;
+}
+
+// Sent by the server when a newer connection for the same session replaces this one
+export const REPLACED_CLOSE_CODE = 4000;
+
+type JsonObject = Record<string, unknown>;
+
+interface StateDiff {
+    of: string;
+    state_key: string;
+    base_version: number;
+    version: number;
+    patch: JsonObject;
+    seq?: number | null;
+}
+
+interface DiffState {
+    version: number;
+    payload: JsonObject;
+}
+
+export function withLastSeq(wsUrl: string, lastSeq: number | null): string {
+    if (lastSeq === null) return wsUrl;
+    return `${wsUrl}${wsUrl.includes('?') ? '&' : '?'}last_seq=${lastSeq}`;
+}
+
+export function withAcks(wsUrl: string, acknowledge: boolean): string {
+    if (!acknowledge) return wsUrl;
+    return `${wsUrl}${wsUrl.includes('?') ? '&' : '?'}acks=true`;
+}
+
+export function withToken(wsUrl: string, token: string | null): string {
+    if (token === null) return wsUrl;
+    return `${wsUrl}${wsUrl.includes('?') ? '&' : '?'}token=${encodeURIComponent(token)}`;
+}
+
+export function withDiff(wsUrl: string, diff: boolean): string {
+    if (!diff) return wsUrl;
+    return `${wsUrl}${wsUrl.includes('?') ? '&' : '?'}diff=true`;
+}
+
+function isObject(value: unknown): value is JsonObject {
+    return typeof value === 'object' && value !== null && !Array.isArray(value);
+}
+
+// RFC 7386: null removes a field, objects are merged recursively, anything else replaces
+export function applyMergePatch(target: JsonObject, patch: JsonObject): JsonObject {
+    const result: JsonObject = { ...target };
+    for (const [key, value] of Object.entries(patch)) {
+        if (value === null) {
+            delete result[key];
+        } else if (isObject(value) && isObject(result[key])) {
+            result[key] = applyMergePatch(result[key] as JsonObject, value);
+        } else {
+            result[key] = value;
+        }
+    }
+    return result;
+}
+
+// The full event a diff-mode message stands for, remembering it as the base for the next patch; null when a patch
+// doesn't apply to the copy held, and the server should be asked for full copies again
+export function resolveStateDiff(states: Map<string, DiffState>, message: JsonObject): JsonObject | null {
+    if (message.type === 'state_diff') {
+        const diff = message as unknown as StateDiff;
+        const base = states.get(diff.state_key);
+        if (!base || base.version !== diff.base_version) {
+            states.clear();
+            return null;
+        }
+        const payload = applyMergePatch(base.payload, diff.patch);
+        states.set(diff.state_key, { version: diff.version, payload });
+        return payload;
+    }
+    const { state_key: stateKey, state_version: stateVersion, ...payload } = message;
+    if (typeof stateKey === 'string' && typeof stateVersion === 'number') {
+        states.set(stateKey, { version: stateVersion, payload });
+        return payload;
+    }
+    return message;
 }
 
 export function useWebSocket(wsUrl: string, options: UseWebSocketOptions = {}) {
@@ -24,6 +113,10 @@ export function useWebSocket(wsUrl: string, options: UseWebSocketOptions = {}) {
         maxRetries = 10,
         onMaxRetriesReached,
         onReconnecting,
+        resumable = false,
+        acknowledge = false,
+        diff = false,
+        connectToken,
     } = options;
 
     const [isConnected, setIsConnected] = useState(false);
@@ -37,6 +130,10 @@ export function useWebSocket(wsUrl: string, options: UseWebSocketOptions = {}) {
     const shouldConnectRef = useRef(true);
     const retryCountRef = useRef(0);
     const hasEverConnectedRef = useRef(false);
+    const lastSeqRef = useRef<number | null>(null);
+    const shutdownDelayRef = useRef(0);
+    const diffStatesRef = useRef(new Map<string, DiffState>());
+    const attemptRef = useRef(0);
 
     // Store the latest callbacks in refs to avoid recreating connect function
     const onMessageRef = useRef(onMessage);
@@ -45,6 +142,7 @@ export function useWebSocket(wsUrl: string, options: UseWebSocketOptions = {}) {
     const onErrorRef = useRef(onError);
     const onMaxRetriesReachedRef = useRef(onMaxRetriesReached);
     const onReconnectingRef = useRef(onReconnecting);
+    const connectTokenRef = useRef(connectToken);
 
     // Update refs when callbacks change
     useEffect(() => {
@@ -54,79 +152,138 @@ export function useWebSocket(wsUrl: string, options: UseWebSocketOptions = {}) {
         onErrorRef.current = onError;
         onMaxRetriesReachedRef.current = onMaxRetriesReached;
         onReconnectingRef.current = onReconnecting;
-    }, [onMessage, onConnect, onDisconnect, onError, onMaxRetriesReached, onReconnecting]);
+        connectTokenRef.current = connectToken;
+    }, [onMessage, onConnect, onDisconnect, onError, onMaxRetriesReached, onReconnecting, connectToken]);
 
     const connect = useCallback(() => {
         if (!wsUrl || !shouldConnectRef.current) return;
+        // A token fetch still in flight for an earlier attempt mustn't open a second socket
+        const attempt = ++attemptRef.current;
 
-        try {
-            const ws = new WebSocket(wsUrl);
-            wsRef.current = ws;
-
-            ws.onopen = () => {
-                setConnectionStatus('connected');
-                setIsConnected(true);
-                setError(null);
-                setRetryCount(0);
-                setHasEverConnected(true);
-
-                retryCountRef.current = 0;
-                hasEverConnectedRef.current = true;
-
-                onConnectRef.current?.();
-            };
-
-            ws.onmessage = event => {
-                try {
-                    const message: WebSocketMessage = JSON.parse(event.data);
-                    onMessageRef.current?.(message);
-                } catch (err) {
-                    console.error('Failed to parse WebSocket message:', err);
+        const retry = () => {
+            if (shouldConnectRef.current && autoReconnect) {
+                // Distinguish: initial failure vs connection lost
+                if (!hasEverConnectedRef.current) {
+                    setConnectionStatus('failed');
+                    setError('Unable to establish initial connection');
+                } else {
+                    setConnectionStatus('reconnecting');
                 }
-            };
-
-            ws.onclose = () => {
-                setIsConnected(false);
-                wsRef.current = null;
-                onDisconnectRef.current?.();
-
-                if (shouldConnectRef.current && autoReconnect) {
-                    // Distinguish: initial failure vs connection lost
-                    if (!hasEverConnectedRef.current) {
-                        setConnectionStatus('failed');
-                        setError('Unable to establish initial connection');
-                    } else {
-                        setConnectionStatus('reconnecting');
-                    }
 
-                    retryCountRef.current += 1;
-                    setRetryCount(retryCountRef.current);
+                retryCountRef.current += 1;
+                setRetryCount(retryCountRef.current);
 
-                    onReconnectingRef.current?.(retryCountRef.current);
+                onReconnectingRef.current?.(retryCountRef.current);
 
-                    if (maxRetries && retryCountRef.current >= maxRetries) {
-                        setConnectionStatus('failed');
-                        setError(`Connection failed after ${maxRetries} attempts`);
-                        onMaxRetriesReachedRef.current?.();
-                    } else {
-                        reconnectTimeoutRef.current = setTimeout(connect, reconnectInterval);
-                    }
+                const delay = Math.max(reconnectInterval, shutdownDelayRef.current);
+                shutdownDelayRef.current = 0;
+                if (maxRetries && retryCountRef.current >= maxRetries) {
+                    setConnectionStatus('failed');
+                    setError(`Connection failed after ${maxRetries} attempts`);
+                    onMaxRetriesReachedRef.current?.();
                 } else {
-                    setConnectionStatus('disconnected');
+                    reconnectTimeoutRef.current = setTimeout(connect, delay);
                 }
-            };
-
-            ws.onerror = error => {
-                const errorMessage = hasEverConnectedRef.current ? 'Connection lost' : 'Failed to connect to server';
-                setError(errorMessage);
-                onErrorRef.current?.(error);
-            };
-        } catch (err) {
-            setConnectionStatus('failed');
-            setError('Failed to create WebSocket connection');
-            console.error('WebSocket connection error:', err);
+            } else {
+                setConnectionStatus('disconnected');
+            }
+        };
+
+        const open = (token: string | null) => {
+            if (!shouldConnectRef.current || attempt !== attemptRef.current) return;
+            try {
+                const url = withToken(resumable ? withLastSeq(wsUrl, lastSeqRef.current) : wsUrl, token);
+                const ws = new WebSocket(withDiff(withAcks(url, acknowledge), diff));
+                wsRef.current = ws;
+                // Diff state belongs to the connection, so a new one starts from full copies
+                diffStatesRef.current.clear();
+
+                ws.onopen = () => {
+                    setConnectionStatus('connected');
+                    setIsConnected(true);
+                    setError(null);
+                    setRetryCount(0);
+                    setHasEverConnected(true);
+
+                    retryCountRef.current = 0;
+                    hasEverConnectedRef.current = true;
+
+                    onConnectRef.current?.();
+                };
+
+                ws.onmessage = event => {
+                    try {
+                        let message: WebSocketMessage = JSON.parse(event.data);
+                        if (diff) {
+                            const resolved = resolveStateDiff(diffStatesRef.current, message as unknown as JsonObject);
+                            if (resolved === null) {
+                                ws.send(JSON.stringify({ action: 'diff_reset' }));
+                                return;
+                            }
+                            message = resolved as unknown as WebSocketMessage;
+                        }
+                        // Answer server keepalive pings so idle-timeout checks see this client as alive
+                        if (message.type === 'ping') {
+                            ws.send(JSON.stringify({ action: 'pong' }));
+                            return;
+                        }
+                        if (message.type === 'server_shutdown') {
+                            // The server is restarting: wait it out instead of spending retries on a closed port
+                            shutdownDelayRef.current = (message.reconnect_after_seconds ?? 0) * 1000;
+                        }
+                        if (acknowledge && message.ack_required && typeof message.seq === 'number') {
+                            ws.send(JSON.stringify({ action: 'ack', seq: message.seq }));
+                            // Resends of a critical event we already handled only need the ack
+                            if (message.seq <= (lastSeqRef.current ?? 0)) return;
+                        }
+                        if (typeof message.seq === 'number') {
+                            lastSeqRef.current = Math.max(lastSeqRef.current ?? 0, message.seq);
+                        }
+                        onMessageRef.current?.(message);
+                    } catch (err) {
+                        console.error('Failed to parse WebSocket message:', err);
+                    }
+                };
+
+                ws.onclose = event => {
+                    setIsConnected(false);
+                    wsRef.current = null;
+                    onDisconnectRef.current?.();
+                    if (event.code === REPLACED_CLOSE_CODE) {
+                        // Another tab or device took over the session; reconnecting would only take it back
+                        setConnectionStatus('disconnected');
+                        setError('Opened in another tab or device');
+                        return;
+                    }
+                    retry();
+                };
+
+                ws.onerror = error => {
+                    const errorMessage = hasEverConnectedRef.current
+                        ? 'Connection lost'
+                        : 'Failed to connect to server';
+                    setError(errorMessage);
+                    onErrorRef.current?.(error);
+                };
+            } catch (err) {
+                setConnectionStatus('failed');
+                setError('Failed to create WebSocket connection');
+                console.error('WebSocket connection error:', err);
+            }
+        };
+
+        const getToken = connectTokenRef.current;
+        if (!getToken) {
+            open(null);
+            return;
         }
-    }, [wsUrl, autoReconnect, reconnectInterval, maxRetries]);
+        getToken().then(open, err => {
+            if (attempt !== attemptRef.current) return;
+            console.error('Failed to get a WebSocket connect token:', err);
+            setError('Failed to connect to server');
+            retry();
+        });
+    }, [wsUrl, autoReconnect, reconnectInterval, maxRetries, resumable, acknowledge, diff]);
 
     const sendMessage = useCallback((message: object) => {
         if (wsRef.current && wsRef.current.readyState === WebSocket.OPEN) {
@@ -163,6 +320,8 @@ export function useWebSocket(wsUrl: string, options: UseWebSocketOptions = {}) {
 
     useEffect(() => {
         shouldConnectRef.current = true;
+        // Sequence numbers are per lobby, so a new URL starts fresh
+        lastSeqRef.current = null;
         connect();
 
         return () => {
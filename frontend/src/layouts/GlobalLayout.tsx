@@ -76,6 +76,12 @@ const GlobalLayout: React.FC = () => {
         setIsLoggingOut(true);
         try {
             if (location.pathname.startsWith('/admin')) {
+                if (adminApiToken) {
+                    // Revoke the web session server-side; clear local credentials even if that fails
+                    await api.admin.logout(adminApiToken).catch(err => {
+                        console.error('Admin logout error:', err);
+                    });
+                }
                 updateAdminApiToken(null);
                 updateAdminSessionId(null);
                 window.location.href = '/';
@@ -89,7 +95,7 @@ const GlobalLayout: React.FC = () => {
         } finally {
             setIsLoggingOut(false);
         }
-    }, [location.pathname, sessionId, updateAdminApiToken, updateAdminSessionId, updateSessionId]);
+    }, [location.pathname, sessionId, adminApiToken, updateAdminApiToken, updateAdminSessionId, updateSessionId]);
 
     const context: GlobalOutletContext = useMemo(
         () => ({
@@ -10,6 +10,7 @@ vi.mock('@/services/api', () => ({
     api: {
         admin: {
             checkCredentials: vi.fn(),
+            login: vi.fn(),
         },
     },
 }));
@@ -89,7 +90,7 @@ describe('AdminLoginPage Component', () => {
             });
 
             expect(screen.getByText('Admin Login')).toBeInTheDocument();
-            expect(screen.getByText('Enter your admin api key to access the admin dashboard')).toBeInTheDocument();
+            expect(screen.getByText('Enter the admin password to access the admin dashboard')).toBeInTheDocument();
             expect(screen.getByTestId('admin-login-form')).toBeInTheDocument();
             expect(screen.getByTestId('admin-token-input')).toBeInTheDocument();
             expect(screen.getByTestId('admin-login-submit')).toBeInTheDocument();
@@ -242,16 +243,16 @@ describe('AdminLoginPage Component', () => {
             const submitButton = screen.getByTestId('admin-login-submit');
             await user.click(submitButton);
 
-            // Should not call API if token is empty
-            expect(api.admin.checkCredentials).not.toHaveBeenCalled();
+            // Should not call API if password is empty
+            expect(api.admin.login).not.toHaveBeenCalled();
         });
     });
 
     describe('Form Submission', () => {
         test('successfully logs in with valid token', async () => {
             const user = userEvent.setup();
-            const mockResponse = { session_id: 'new-admin-session' };
-            vi.mocked(api.admin.checkCredentials).mockResolvedValue(mockResponse);
+            const mockResponse = { session_id: 'new-admin-session', token: 'issued-session-token' };
+            vi.mocked(api.admin.login).mockResolvedValue(mockResponse);
 
             render(
                 <TestWrapper>
@@ -270,16 +271,41 @@ describe('AdminLoginPage Component', () => {
             await user.click(submitButton);
 
             await waitFor(() => {
-                expect(api.admin.checkCredentials).toHaveBeenCalledWith('valid-admin-token');
-                expect(mockSetAdminApiToken).toHaveBeenCalledWith('valid-admin-token');
+                expect(api.admin.login).toHaveBeenCalledWith('valid-admin-token', undefined);
+                expect(mockSetAdminApiToken).toHaveBeenCalledWith('issued-session-token');
                 expect(mockSetAdminSessionId).toHaveBeenCalledWith('new-admin-session');
                 expect(mockNavigate).toHaveBeenCalledWith('/admin');
             });
         });
 
+        test('logs in to an organization when one is given', async () => {
+            const user = userEvent.setup();
+            const mockResponse = { session_id: 'org-session', token: 'org-session-token', organization_id: 3 };
+            vi.mocked(api.admin.login).mockResolvedValue(mockResponse);
+
+            render(
+                <TestWrapper>
+                    <AdminLoginPage />
+                </TestWrapper>
+            );
+
+            await waitFor(() => {
+                expect(screen.getByTestId('admin-organization-input')).toBeInTheDocument();
+            });
+
+            await user.type(screen.getByTestId('admin-organization-input'), ' quiz-club ');
+            await user.type(screen.getByTestId('admin-token-input'), 'club-password');
+            await user.click(screen.getByTestId('admin-login-submit'));
+
+            await waitFor(() => {
+                expect(api.admin.login).toHaveBeenCalledWith('club-password', 'quiz-club');
+                expect(mockSetAdminApiToken).toHaveBeenCalledWith('org-session-token');
+            });
+        });
+
         test('shows error message for invalid token', async () => {
             const user = userEvent.setup();
-            vi.mocked(api.admin.checkCredentials).mockRejectedValue(new Error('Invalid token'));
+            vi.mocked(api.admin.login).mockRejectedValue(new Error('Invalid token'));
 
             const consoleSpy = vi.spyOn(console, 'error').mockImplementation(() => {});
 
@@ -301,7 +327,7 @@ describe('AdminLoginPage Component', () => {
 
             await waitFor(() => {
                 expect(screen.getByTestId('input-error')).toBeInTheDocument();
-                expect(screen.getByText('Invalid admin token')).toBeInTheDocument();
+                expect(screen.getByText('Invalid admin password')).toBeInTheDocument();
                 expect(consoleSpy).toHaveBeenCalledWith('Auth error:', expect.any(Error));
             });
 
@@ -311,7 +337,7 @@ describe('AdminLoginPage Component', () => {
         test('trims whitespace from token input', async () => {
             const user = userEvent.setup();
             const mockResponse = { session_id: 'session-123' };
-            vi.mocked(api.admin.checkCredentials).mockResolvedValue(mockResponse);
+            vi.mocked(api.admin.login).mockResolvedValue(mockResponse);
 
             render(
                 <TestWrapper>
@@ -330,7 +356,7 @@ describe('AdminLoginPage Component', () => {
             await user.click(submitButton);
 
             await waitFor(() => {
-                expect(api.admin.checkCredentials).toHaveBeenCalledWith('  admin-token  ');
+                expect(api.admin.login).toHaveBeenCalledWith('  admin-token  ', undefined);
             });
         });
     });
@@ -340,7 +366,7 @@ describe('AdminLoginPage Component', () => {
             const user = userEvent.setup();
 
             // Make API call hang to test loading state
-            vi.mocked(api.admin.checkCredentials).mockImplementation(() => new Promise(() => {}));
+            vi.mocked(api.admin.login).mockImplementation(() => new Promise(() => {}));
 
             render(
                 <TestWrapper>
@@ -366,7 +392,7 @@ describe('AdminLoginPage Component', () => {
         test('resets loading state after successful login', async () => {
             const user = userEvent.setup();
             const mockResponse = { session_id: 'session-123' };
-            vi.mocked(api.admin.checkCredentials).mockResolvedValue(mockResponse);
+            vi.mocked(api.admin.login).mockResolvedValue(mockResponse);
 
             render(
                 <TestWrapper>
@@ -385,7 +411,7 @@ describe('AdminLoginPage Component', () => {
             await user.click(submitButton);
 
             await waitFor(() => {
-                expect(api.admin.checkCredentials).toHaveBeenCalled();
+                expect(api.admin.login).toHaveBeenCalled();
             });
 
             // Should not show loading state after completion
@@ -394,7 +420,7 @@ describe('AdminLoginPage Component', () => {
 
         test('resets loading state after login error', async () => {
             const user = userEvent.setup();
-            vi.mocked(api.admin.checkCredentials).mockRejectedValue(new Error('Network error'));
+            vi.mocked(api.admin.login).mockRejectedValue(new Error('Network error'));
 
             const consoleSpy = vi.spyOn(console, 'error').mockImplementation(() => {});
 
@@ -431,7 +457,7 @@ describe('AdminLoginPage Component', () => {
             const user = userEvent.setup();
 
             // First submission - error
-            vi.mocked(api.admin.checkCredentials).mockRejectedValueOnce(new Error('Invalid token'));
+            vi.mocked(api.admin.login).mockRejectedValueOnce(new Error('Invalid token'));
 
             const consoleSpy = vi.spyOn(console, 'error').mockImplementation(() => {});
 
@@ -457,7 +483,7 @@ describe('AdminLoginPage Component', () => {
 
             // Second submission - success
             const mockResponse = { session_id: 'session-123' };
-            vi.mocked(api.admin.checkCredentials).mockResolvedValue(mockResponse);
+            vi.mocked(api.admin.login).mockResolvedValue(mockResponse);
 
             await user.clear(tokenInput);
             await user.type(tokenInput, 'valid-token');
@@ -10,7 +10,8 @@ export default function AdminLoginPage() {
         useGlobalOutletContext();
     const navigate = useNavigate();
 
-    const [localAdminApiToken, setLocalAdminApiToken] = useState('');
+    const [password, setPassword] = useState('');
+    const [organization, setOrganization] = useState('');
     const [pageLoading, setPageLoading] = useState(true);
     const [loading, setLoading] = useState(false);
     const [error, setError] = useState('');
@@ -52,18 +53,18 @@ export default function AdminLoginPage() {
     const handleLogin = async (e: React.FormEvent) => {
         e.preventDefault();
 
-        if (!localAdminApiToken.trim()) return;
+        if (!password.trim()) return;
 
         setLoading(true);
         setError('');
 
         try {
-            const response = await api.admin.checkCredentials(localAdminApiToken);
-            setAdminApiToken(localAdminApiToken);
+            const response = await api.admin.login(password, organization.trim() || undefined);
+            setAdminApiToken(response.token);
             setAdminSessionId(response.session_id);
             navigate('/admin');
         } catch (err) {
-            setError('Invalid admin token');
+            setError('Invalid admin password');
             console.error('Auth error:', err);
         } finally {
             setLoading(false);
@@ -78,18 +79,27 @@ export default function AdminLoginPage() {
                 <h1 className='text-tx-primary mb-6 text-3xl font-bold' data-testid='admin-login-title'>
                     Admin Login
                 </h1>
-                <p className='text-tx-secondary'>Enter your admin api key to access the admin dashboard</p>
+                <p className='text-tx-secondary'>Enter the admin password to access the admin dashboard</p>
             </div>
 
             <form onSubmit={handleLogin} className='space-y-6' data-testid='admin-login-form'>
                 <div className='mx-auto max-w-md space-y-6'>
+                    <TextInput
+                        id='adminOrganization'
+                        label='Organization (optional)'
+                        value={organization}
+                        onChange={setOrganization}
+                        placeholder='Leave blank for the deployment admin password'
+                        disabled={loading}
+                        data-testid='admin-organization-input'
+                    />
                     <TextInput
                         id='adminToken'
                         type='password'
-                        label='Admin Token'
-                        value={localAdminApiToken}
-                        onChange={setLocalAdminApiToken}
-                        placeholder='Enter admin token'
+                        label='Admin Password'
+                        value={password}
+                        onChange={setPassword}
+                        placeholder='Enter admin password'
                         disabled={loading}
                         error={error}
                         data-testid='admin-token-input'
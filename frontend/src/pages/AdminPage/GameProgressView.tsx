@@ -4,9 +4,10 @@ import type { TeamGameProgress } from '@/types';
 
 interface GameProgressViewProps {
     teams: TeamGameProgress[];
+    onRollbackWord?: (team: TeamGameProgress) => void;
 }
 
-export default function GameProgressView({ teams }: GameProgressViewProps) {
+export default function GameProgressView({ teams, onRollbackWord }: GameProgressViewProps) {
     if (!teams || teams.length === 0) {
         return (
             <div className='text-tx-muted py-8 text-center'>
@@ -109,6 +110,7 @@ export default function GameProgressView({ teams }: GameProgressViewProps) {
                         solving={solving}
                         isCollapsed={isCollapsed}
                         onToggleCollapse={() => toggleTeamCollapse(team.team_id)}
+                        onRollbackWord={onRollbackWord && (() => onRollbackWord(team))}
                     />
                 ))}
             </div>
@@ -121,9 +123,10 @@ interface TeamProgressProps {
     solving: SolvingContext;
     isCollapsed: boolean;
     onToggleCollapse: () => void;
+    onRollbackWord?: () => void;
 }
 
-function TeamProgress({ team, solving, isCollapsed, onToggleCollapse }: TeamProgressProps) {
+function TeamProgress({ team, solving, isCollapsed, onToggleCollapse, onRollbackWord }: TeamProgressProps) {
     const {
         revealedSet,
         progressPercent,
@@ -143,6 +146,18 @@ function TeamProgress({ team, solving, isCollapsed, onToggleCollapse }: TeamProg
                 <div className='mb-2 flex items-center justify-between'>
                     <h3 className='text-tx-primary text-lg font-semibold'>{team.team_name}</h3>
                     <div className='flex items-center gap-2'>
+                        {/* The first and last words are given, so anything beyond two was solved by the team */}
+                        {onRollbackWord && revealedSet.size > 2 && (
+                            <Button
+                                variant='secondary'
+                                size='sm'
+                                className='text-xs'
+                                onClick={onRollbackWord}
+                                data-testid={`rollback-word-${team.team_id}`}
+                            >
+                                Undo last word
+                            </Button>
+                        )}
                         {team.is_completed && (
                             <span className='rounded-full bg-green-100 px-3 py-1 text-xs font-medium text-green-800'>
                                 ✓ Completed
@@ -1,11 +1,27 @@
-import { Lobby } from '@/types';
+import { AdminLobbyListEntry, AdminLobbyListSort, LobbyPhase } from '@/types';
 import { useState, useEffect, useCallback } from 'react';
 import { api } from '@/services/api';
 import { useGlobalOutletContext } from '@/hooks/useGlobalOutletContext';
 import { useDebounce } from '@/hooks/useDebounce';
-import { LoadingSpinner, CopyableCode, Button, ErrorMessage, Card } from '@/components';
+import { LoadingSpinner, CopyableCode, Button, ErrorMessage, Card, Select } from '@/components';
 import CreateLobbyForm from './CreateLobbyForm';
 
+const LOBBIES_PER_PAGE = 20;
+
+const SORT_OPTIONS: { value: AdminLobbyListSort; label: string }[] = [
+    { value: 'newest', label: 'Newest first' },
+    { value: 'oldest', label: 'Oldest first' },
+    { value: 'name', label: 'Name' },
+    { value: 'players', label: 'Most players' },
+];
+
+const PHASE_LABELS: Record<LobbyPhase, string> = {
+    waiting: 'Waiting',
+    in_progress: 'Round in progress',
+    finished: 'Between rounds',
+    intermission: 'Intermission',
+};
+
 interface LobbiesListProps {
     onViewDetails: (lobbyId: number) => void;
     refreshKey: number;
@@ -15,7 +31,11 @@ interface LobbiesListProps {
 export default function LobbiesList({ onViewDetails, refreshKey, onDebouncedRefresh }: LobbiesListProps) {
     const { adminApiToken } = useGlobalOutletContext();
 
-    const [lobbies, setLobbies] = useState<Lobby[]>([]);
+    const [lobbies, setLobbies] = useState<AdminLobbyListEntry[]>([]);
+    const [total, setTotal] = useState(0);
+    const [page, setPage] = useState(1);
+    const [sort, setSort] = useState<AdminLobbyListSort>('newest');
+    const [activeOnly, setActiveOnly] = useState(false);
     const [isInitialLoad, setIsInitialLoad] = useState(true);
     const [isRefreshing, setIsRefreshing] = useState(false);
     const [error, setError] = useState('');
@@ -31,8 +51,14 @@ export default function LobbiesList({ onViewDetails, refreshKey, onDebouncedRefr
         try {
             setIsRefreshing(true);
             setError('');
-            const fetchedLobbies = await api.admin.lobby.getAll(adminApiToken);
-            setLobbies(fetchedLobbies);
+            const result = await api.admin.lobby.getAll(adminApiToken, {
+                page,
+                perPage: LOBBIES_PER_PAGE,
+                sort,
+                activeOnly,
+            });
+            setLobbies(result.items);
+            setTotal(result.total);
         } catch (err) {
             setError('Failed to load lobbies');
             console.error('Error loading lobbies:', err);
@@ -40,7 +66,9 @@ export default function LobbiesList({ onViewDetails, refreshKey, onDebouncedRefr
             setIsInitialLoad(false);
             setIsRefreshing(false);
         }
-    }, [adminApiToken]);
+    }, [adminApiToken, page, sort, activeOnly]);
+
+    const pageCount = Math.max(1, Math.ceil(total / LOBBIES_PER_PAGE));
 
     const debouncedRefreshLobbies = useDebounce(refreshLobbies);
 
@@ -98,57 +126,131 @@ export default function LobbiesList({ onViewDetails, refreshKey, onDebouncedRefr
 
             <div className='mb-3 flex items-center justify-between'>
                 <div className='text-tx-secondary text-sm tracking-wide uppercase' data-testid='all-lobbies-heading'>
-                    All Lobbies
+                    All Lobbies ({total})
+                </div>
+                <div className='flex items-center gap-3'>
+                    <label className='text-tx-secondary flex cursor-pointer items-center gap-2 text-xs'>
+                        <input
+                            type='checkbox'
+                            checked={activeOnly}
+                            onChange={() => {
+                                setActiveOnly(!activeOnly);
+                                setPage(1);
+                            }}
+                            className='accent-accent h-4 w-4'
+                            data-testid='lobbies-active-only'
+                        />
+                        Active only
+                    </label>
+                    <Select
+                        value={sort}
+                        onChange={value => {
+                            setSort(value as AdminLobbyListSort);
+                            setPage(1);
+                        }}
+                        options={SORT_OPTIONS}
+                        data-testid='lobbies-sort-select'
+                    />
+                    <Button
+                        onClick={debouncedRefreshLobbies}
+                        disabled={isRefreshing}
+                        loading={isRefreshing}
+                        loadingIndicatorPlacement='left'
+                        variant='secondary'
+                        size='sm'
+                        data-testid='refresh-lobbies-button'
+                    >
+                        Refresh
+                    </Button>
                 </div>
-                <Button
-                    onClick={debouncedRefreshLobbies}
-                    disabled={isRefreshing}
-                    loading={isRefreshing}
-                    loadingIndicatorPlacement='left'
-                    variant='secondary'
-                    size='sm'
-                    data-testid='refresh-lobbies-button'
-                >
-                    Refresh
-                </Button>
             </div>
 
             {isInitialLoad ? (
                 <LoadingSpinner />
             ) : lobbies.length === 0 ? (
                 <div className='border-border bg-tertiary text-tx-muted rounded-md border pt-8 pb-8 text-center'>
-                    No lobbies created yet
+                    {activeOnly ? 'No lobbies with a round in progress' : 'No lobbies created yet'}
                 </div>
             ) : (
                 <div className='max-h-96 overflow-y-auto pr-2'>
                     <div className='grid grid-cols-1 gap-3 lg:grid-cols-2'>
-                        {lobbies.map(lobby => (
-                            <Card
-                                key={lobby.id}
-                                variant='clickable'
-                                onClick={() => onViewDetails(lobby.id)}
-                                data-testid={`lobby-item-${lobby.id}`}
-                            >
-                                <div className='flex flex-col gap-2'>
-                                    <div className='flex flex-row items-start justify-between'>
-                                        <h3 className='text-tx-primary font-semibold'>{lobby.name}</h3>
-                                        <div className='text-tx-muted ml-2 text-xs whitespace-nowrap opacity-75'>
-                                            Click to view →
+                        {lobbies.map(
+                            ({
+                                lobby,
+                                player_count,
+                                soft_player_cap,
+                                over_soft_cap,
+                                team_count,
+                                connected_count,
+                                phase,
+                                last_activity_at,
+                            }) => (
+                                <Card
+                                    key={lobby.id}
+                                    variant='clickable'
+                                    onClick={() => onViewDetails(lobby.id)}
+                                    data-testid={`lobby-item-${lobby.id}`}
+                                >
+                                    <div className='flex flex-col gap-2'>
+                                        <div className='flex flex-row items-start justify-between'>
+                                            <h3 className='text-tx-primary font-semibold'>{lobby.name}</h3>
+                                            <div className='text-tx-muted ml-2 text-xs whitespace-nowrap opacity-75'>
+                                                Click to view →
+                                            </div>
+                                        </div>
+                                        <div className='dark:text-tx-secondary flex flex-col gap-2 text-sm text-gray-600'>
+                                            <span className='flex items-center gap-2'>
+                                                Code:
+                                                <CopyableCode code={lobby.code} />
+                                            </span>
+                                            <span>Created: {new Date(lobby.created_at).toLocaleDateString()}</span>
+                                            <span
+                                                className={over_soft_cap ? 'text-orange-500' : undefined}
+                                                data-testid={`lobby-player-count-${lobby.id}`}
+                                            >
+                                                Players: {player_count}
+                                                {soft_player_cap !== null && ` / ${soft_player_cap}`}
+                                                {over_soft_cap && ' (over soft cap)'}
+                                            </span>
+                                            <span data-testid={`lobby-occupancy-${lobby.id}`}>
+                                                {connected_count} connected · {team_count} teams ·{' '}
+                                                {PHASE_LABELS[phase]}
+                                            </span>
+                                            <span>Last activity: {new Date(last_activity_at).toLocaleString()}</span>
                                         </div>
                                     </div>
-                                    <div className='dark:text-tx-secondary flex flex-col gap-2 text-sm text-gray-600'>
-                                        <span className='flex items-center gap-2'>
-                                            Code:
-                                            <CopyableCode code={lobby.code} />
-                                        </span>
-                                        <span>Created: {new Date(lobby.created_at).toLocaleDateString()}</span>
-                                    </div>
-                                </div>
-                            </Card>
-                        ))}
+                                </Card>
+                            )
+                        )}
                     </div>
                 </div>
             )}
+
+            {pageCount > 1 && (
+                <div className='mt-3 flex items-center justify-between' data-testid='lobbies-pagination'>
+                    <Button
+                        onClick={() => setPage(page - 1)}
+                        disabled={page <= 1 || isRefreshing}
+                        variant='secondary'
+                        size='sm'
+                        data-testid='lobbies-prev-page'
+                    >
+                        ← Previous
+                    </Button>
+                    <span className='text-tx-secondary text-sm'>
+                        Page {page} of {pageCount}
+                    </span>
+                    <Button
+                        onClick={() => setPage(page + 1)}
+                        disabled={page >= pageCount || isRefreshing}
+                        variant='secondary'
+                        size='sm'
+                        data-testid='lobbies-next-page'
+                    >
+                        Next →
+                    </Button>
+                </div>
+            )}
         </div>
     );
 }
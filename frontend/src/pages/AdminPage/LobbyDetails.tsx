@@ -1,5 +1,17 @@
 import { useState, useEffect, useCallback, useMemo } from 'react';
-import { LobbyInfo, GameStateResponse, GameWebSocketEvents, LobbyWebSocketEvents, WebSocketMessage } from '@/types';
+import {
+    DeliverySummary,
+    LobbyInfo,
+    LobbyStateEvent,
+    LobbySchedule,
+    LobbyScheduleUpdate,
+    ScheduledTime,
+    GameStateResponse,
+    TeamGameProgress,
+    GameWebSocketEvents,
+    LobbyWebSocketEvents,
+    WebSocketMessage,
+} from '@/types';
 import { Modal, CopyableCode, Button, TextInput, Select, ErrorMessage, Card, ConnectionBadge } from '@/components';
 import { TeamLeaderboard } from '@/components/TeamLeaderboard';
 import { api, ApiError } from '@/services/api';
@@ -11,6 +23,25 @@ import { RoundSummary } from './RoundSummary';
 
 const MIN_TEAMS = 2;
 const MAX_TEAMS = 10;
+const COMMENTARY_LINES_SHOWN = 8;
+
+// datetime-local inputs want "YYYY-MM-DDTHH:mm" on the lobby's clock, without an offset
+const toLocalInput = (time: ScheduledTime | null) => (time ? time.local.slice(0, 16) : '');
+
+const scheduleFormFrom = (schedule: LobbySchedule): LobbyScheduleUpdate => ({
+    timezone: schedule.timezone,
+    opens_at: toLocalInput(schedule.opens_at),
+    closes_at: toLocalInput(schedule.closes_at),
+    starts_at: toLocalInput(schedule.starts_at),
+});
+
+const formatScheduledTime = (time: ScheduledTime | null) => (time ? time.display : 'Not scheduled');
+
+// Empty when every connection got the broadcast
+const deliveryWarning = (action: string, delivery: DeliverySummary) =>
+    delivery.failed
+        ? `${action}, but ${delivery.failed}/${delivery.attempted} connections missed it; they'll catch up on reconnect`
+        : '';
 
 interface LobbyDetailsProps {
     lobbyId: number;
@@ -36,13 +67,21 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
     const [availableDates, setAvailableDates] = useState<string[]>([]);
     const [isStartingGame, setIsStartingGame] = useState(false);
     const [isEndingGame, setIsEndingGame] = useState(false);
+    const [isAdvancingRound, setIsAdvancingRound] = useState(false);
     const [isAddingTeam, setIsAddingTeam] = useState(false);
+    const [isRebalancing, setIsRebalancing] = useState(false);
+    const [isRerollingSeed, setIsRerollingSeed] = useState(false);
+    const [scheduleForm, setScheduleForm] = useState<LobbyScheduleUpdate>({ timezone: 'UTC' });
+    const [isSavingSchedule, setIsSavingSchedule] = useState(false);
     const [removingTeamId, setRemovingTeamId] = useState<number | null>(null);
     const [editingTeamId, setEditingTeamId] = useState<number | null>(null);
     const [editingTeamName, setEditingTeamName] = useState('');
+    // admin_id -> team_id for other admins editing teams in this lobby
+    const [otherAdminsEditing, setOtherAdminsEditing] = useState<Record<string, number>>({});
     const [viewingRoundGameId, setViewingRoundGameId] = useState<number | null>(null);
     const [allRounds, setAllRounds] = useState<{ round_number: number; game_id: number }[]>([]);
     const [leaderboardRefreshKey, setLeaderboardRefreshKey] = useState(0);
+    const [commentary, setCommentary] = useState<string[]>([]);
 
     // Game state
     const [gameState, setGameState] = useState<GameStateResponse | null>(null);
@@ -170,6 +209,14 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
         setNumTeams(prev => clampTeamCount(prev));
     }, [clampTeamCount]);
 
+    // Keyed on the saved values so lobby reloads from websocket events don't wipe an edit in progress
+    const savedScheduleJson = selectedLobby ? JSON.stringify(selectedLobby.schedule) : null;
+    useEffect(() => {
+        if (savedScheduleJson) {
+            setScheduleForm(scheduleFormFrom(JSON.parse(savedScheduleJson)));
+        }
+    }, [savedScheduleJson]);
+
     // Timer countdown effect
     useEffect(() => {
         if (!isTimerActive || !timerExpiresAt) {
@@ -252,11 +299,49 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                         first_place_team_name: message.first_place_team_name,
                     });
                     break;
+                case GameWebSocketEvents.PACE_HINT:
+                    console.log('[Admin] Pace keeper hinted team:', message.team_id);
+                    break;
+                case 'admin_presence':
+                    if (!message.admin_id) break;
+                    setOtherAdminsEditing(prev => {
+                        const next = { ...prev };
+                        if (message.team_id == null) {
+                            delete next[message.admin_id!];
+                        } else {
+                            next[message.admin_id!] = message.team_id;
+                        }
+                        return next;
+                    });
+                    break;
+                case 'lobby_state': {
+                    const snapshot = message as unknown as LobbyStateEvent;
+                    if (snapshot.lobby_id === lobbyId && snapshot.state) {
+                        setSelectedLobby(snapshot.state);
+                    }
+                    break;
+                }
+                case 'commentary':
+                    if (message.lobby_id === lobbyId && message.text) {
+                        setCommentary(prev => [...prev, message.text!].slice(-COMMENTARY_LINES_SHOWN));
+                    }
+                    break;
+                case 'occupancy_warning':
+                    console.warn(
+                        `[Admin] ${message.player_name} joined past the soft cap: ` +
+                            `${message.player_count}/${message.soft_player_cap} players`
+                    );
+                    scheduleReload();
+                    break;
                 case 'timer_started':
                     console.log('[Admin] Timer started:', message);
                     setIsTimerActive(true);
                     setTimerExpiresAt(message.expires_at);
                     break;
+                case 'timer_tick':
+                    setIsTimerActive(true);
+                    setTimerExpiresAt(message.expires_at);
+                    break;
                 case 'timer_expired':
                     console.log('[Admin] Timer expired - backend is auto-ending game');
                     setIsTimerActive(false);
@@ -269,8 +354,17 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                 case GameWebSocketEvents.GAME_WON:
                     loadGameState();
                     break;
+                case 'puzzle_replaced':
+                    console.warn(
+                        `[Admin] Puzzle "${message.deleted_title}" was deleted; ` +
+                            `playing "${message.replacement_title}" instead`
+                    );
+                    reloadAll();
+                    break;
                 case 'round_ended':
                 case 'new_round_started':
+                case GameWebSocketEvents.GAME_FINISHED:
+                case GameWebSocketEvents.SCORE_ADJUSTED:
                     // Reload everything when a round ends or starts
                     reloadAll();
                     break;
@@ -279,8 +373,13 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                 case LobbyWebSocketEvents.TEAM_ASSIGNED:
                 case LobbyWebSocketEvents.TEAM_CHANGED:
                 case LobbyWebSocketEvents.DISCONNECTED:
+                case LobbyWebSocketEvents.PLAYER_DISCONNECTED:
                 case LobbyWebSocketEvents.PLAYER_KICKED:
                 case LobbyWebSocketEvents.READY_STATUS_CHANGED:
+                case LobbyWebSocketEvents.LATE_JOIN:
+                case LobbyWebSocketEvents.HOST_CHANGED:
+                case LobbyWebSocketEvents.TEAM_UPDATED:
+                case LobbyWebSocketEvents.TEAMS_REASSIGNED:
                     // Keep lobby details in sync when players join/leave or teams change
                     scheduleReload();
                     break;
@@ -289,7 +388,7 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                     break;
             }
         },
-        [loadGameState, scheduleReload, reloadAll]
+        [loadGameState, scheduleReload, reloadAll, lobbyId]
     );
 
     const onError = useCallback((event: Event) => {
@@ -305,9 +404,13 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
     }, []);
 
     const wsUrl = useMemo(
-        () => (adminSessionId && adminApiToken ? `/ws/admin/${adminSessionId}?token=${adminApiToken}` : ''),
+        () => (adminSessionId && adminApiToken ? `/ws/admin/${adminSessionId}` : ''),
         [adminSessionId, adminApiToken]
     );
+    const connectToken = useCallback(
+        async () => (await api.admin.getWebSocketToken(adminApiToken ?? '')).token,
+        [adminApiToken]
+    );
 
     const {
         isConnected: isWsConnected,
@@ -319,18 +422,30 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
         onDisconnect,
         onError,
         autoReconnect: true,
+        connectToken,
     });
 
     useEffect(() => {
         if (!sendMessage || !isWsConnected) return;
 
         sendMessage({ action: 'subscribe_lobby', lobby_id: lobbyId });
+        // Catch up on anything that changed while the socket was down without another HTTP round-trip
+        sendMessage({ action: 'request_lobby_state', lobby_id: lobbyId });
 
         return () => {
             sendMessage({ action: 'unsubscribe_lobby', lobby_id: lobbyId });
+            setOtherAdminsEditing({});
         };
     }, [isWsConnected, lobbyId, sendMessage]);
 
+    // Let co-hosts know which team we're editing so they don't overwrite each other
+    useEffect(() => {
+        if (!sendMessage || !isWsConnected) return;
+        sendMessage({ action: 'presence', lobby_id: lobbyId, team_id: editingTeamId });
+    }, [isWsConnected, lobbyId, sendMessage, editingTeamId]);
+
+    const teamsEditedByOthers = useMemo(() => new Set(Object.values(otherAdminsEditing)), [otherAdminsEditing]);
+
     const handleCreateTeams = useCallback(async () => {
         if (!adminApiToken || !selectedLobby) {
             setError(adminApiToken ? 'Lobby not selected' : 'Admin API token is required to create teams');
@@ -381,7 +496,8 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
         if (confirm('Are you sure you want to kick this player?')) {
             try {
                 setError('');
-                await api.admin.lobby.player.kick(playerId, adminApiToken);
+                const result = await api.admin.lobby.player.kick(playerId, adminApiToken);
+                setError(deliveryWarning('Player kicked', result.delivery));
                 scheduleReload();
             } catch (err) {
                 setError('Failed to kick player');
@@ -419,7 +535,7 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
 
             try {
                 setError('');
-                await api.admin.lobby.team.updateName(teamId, editingTeamName.trim(), adminApiToken);
+                await api.admin.lobby.team.update(teamId, { name: editingTeamName.trim() }, adminApiToken);
                 setEditingTeamId(null);
                 setEditingTeamName('');
                 scheduleReload();
@@ -431,6 +547,22 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
         [adminApiToken, selectedLobby, editingTeamName, scheduleReload]
     );
 
+    const handleUpdateTeamColor = useCallback(
+        async (teamId: number, color: string) => {
+            if (!adminApiToken) return;
+
+            try {
+                setError('');
+                await api.admin.lobby.team.update(teamId, { color }, adminApiToken);
+                scheduleReload();
+            } catch (err) {
+                setError('Failed to update team color');
+                console.error('Error updating team color:', err);
+            }
+        },
+        [adminApiToken, scheduleReload]
+    );
+
     const handleStartTeamNameEdit = (teamId: number, currentName: string) => {
         setEditingTeamId(teamId);
         setEditingTeamName(currentName);
@@ -492,6 +624,74 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
         }
     }, [adminApiToken, selectedLobby, maxTeamsAllowed, hasActiveGame, reloadAll]);
 
+    const handleRebalanceTeams = useCallback(async () => {
+        if (!adminApiToken || !selectedLobby) return;
+        if (hasActiveGame) {
+            setError('Cannot modify teams while a game is active');
+            return;
+        }
+        // OK moves as few players as possible; Cancel deals everyone out again
+        const shuffle = !confirm(
+            'Even out the teams by moving as few players as possible? Cancel reshuffles everyone instead.'
+        );
+
+        setIsRebalancing(true);
+        try {
+            setError('');
+            await api.admin.lobby.team.rebalance(selectedLobby.lobby.id, shuffle, adminApiToken);
+            await reloadAll();
+        } catch (err) {
+            const message = err instanceof ApiError ? err.message : 'Failed to rebalance teams';
+            setError(message);
+            console.error('Error rebalancing teams:', err);
+        } finally {
+            setIsRebalancing(false);
+        }
+    }, [adminApiToken, selectedLobby, hasActiveGame, reloadAll]);
+
+    const handleRerollSeed = useCallback(async () => {
+        if (!adminApiToken || !selectedLobby) return;
+
+        setIsRerollingSeed(true);
+        try {
+            setError('');
+            await api.admin.lobby.setSeed(selectedLobby.lobby.id, null, adminApiToken);
+            await reloadAll();
+        } catch (err) {
+            const message = err instanceof ApiError ? err.message : 'Failed to reroll seed';
+            setError(message);
+            console.error('Error rerolling seed:', err);
+        } finally {
+            setIsRerollingSeed(false);
+        }
+    }, [adminApiToken, selectedLobby, reloadAll]);
+
+    const handleSaveSchedule = useCallback(async () => {
+        if (!adminApiToken || !selectedLobby) return;
+
+        setIsSavingSchedule(true);
+        try {
+            setError('');
+            await api.admin.lobby.setSchedule(
+                selectedLobby.lobby.id,
+                {
+                    timezone: scheduleForm.timezone.trim() || 'UTC',
+                    opens_at: scheduleForm.opens_at || null,
+                    closes_at: scheduleForm.closes_at || null,
+                    starts_at: scheduleForm.starts_at || null,
+                },
+                adminApiToken
+            );
+            await reloadAll();
+        } catch (err) {
+            const message = err instanceof ApiError ? err.message : 'Failed to save schedule';
+            setError(message);
+            console.error('Error saving schedule:', err);
+        } finally {
+            setIsSavingSchedule(false);
+        }
+    }, [adminApiToken, selectedLobby, scheduleForm, reloadAll]);
+
     const handleRemoveTeam = useCallback(
         async (teamId: number, teamName: string) => {
             if (!adminApiToken || !selectedLobby) {
@@ -510,16 +710,17 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                 return;
             }
 
-            if (
-                !confirm(`Remove ${teamName}? Players on this team will be unassigned so you can place them elsewhere.`)
-            ) {
+            if (!confirm(`Remove ${teamName}?`)) {
                 return;
             }
+            const reassign = confirm(
+                `Move ${teamName}'s players onto the smallest remaining teams? Cancel leaves them unassigned so you can place them yourself.`
+            );
 
             setRemovingTeamId(teamId);
             try {
                 setError('');
-                await api.admin.lobby.team.remove(teamId, adminApiToken);
+                await api.admin.lobby.team.remove(teamId, adminApiToken, reassign);
                 await reloadAll();
             } catch (err) {
                 const message = err instanceof ApiError ? err.message : 'Failed to remove team';
@@ -570,6 +771,7 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                 puzzleDate || undefined
             );
                 console.log('Game started:', result);
+                setError(deliveryWarning('Game started', result.delivery));
                 // Load game state to show progress
                 await loadGameState();
             } catch (err) {
@@ -603,6 +805,37 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
         [adminApiToken, selectedLobby, difficulty, puzzleMode, wordCountMode, loadGameState]
     );
 
+    // Starts the next round straight from intermission, with the options picked below
+    const handleNextRound = useCallback(async () => {
+        if (!adminApiToken || !selectedLobby) {
+            setError(adminApiToken ? 'Lobby not selected' : 'Admin API token is required to start the next round');
+            return;
+        }
+
+        setIsAdvancingRound(true);
+        try {
+            setError('');
+            const result = await api.admin.lobby.nextRound(
+                selectedLobby.lobby.id,
+                { difficulty, puzzle_mode: puzzleMode, word_count_mode: wordCountMode },
+                adminApiToken
+            );
+            setError(deliveryWarning('Next round started', result.delivery));
+            await loadGameState();
+        } catch (err) {
+            const message =
+                err instanceof ApiError
+                    ? err.message
+                    : err instanceof Error
+                      ? err.message
+                      : 'Failed to start the next round';
+            setError(message);
+            console.error('Error starting next round:', err);
+        } finally {
+            setIsAdvancingRound(false);
+        }
+    }, [adminApiToken, selectedLobby, difficulty, puzzleMode, wordCountMode, loadGameState]);
+
     const handleEndGame = useCallback(async () => {
         if (!adminApiToken || !selectedLobby) {
             setError(adminApiToken ? 'Lobby not selected' : 'Admin API token is required to end game');
@@ -620,6 +853,7 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                 setError('');
                 const result = await api.admin.lobby.endGame(selectedLobby.lobby.id, adminApiToken);
                 console.log('Game ended:', result);
+                setError(deliveryWarning('Game ended', result.delivery));
                 // Clear timer state when manually ending game
                 setIsTimerActive(false);
                 setTimerExpiresAt(null);
@@ -637,6 +871,31 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
         }
     }, [adminApiToken, selectedLobby, gameState, reloadAll]);
 
+    const handleRollbackWord = useCallback(
+        async (team: TeamGameProgress) => {
+            if (!adminApiToken) {
+                setError('Admin API token is required to undo a word');
+                return;
+            }
+
+            if (!confirm(`Undo ${team.team_name}'s most recently solved word? They will have to solve it again.`)) {
+                return;
+            }
+
+            try {
+                setError('');
+                await api.admin.lobby.team.rollbackWord(team.team_id, adminApiToken);
+                await loadGameState();
+            } catch (err) {
+                const message =
+                    err instanceof ApiError ? err.message : err instanceof Error ? err.message : 'Failed to undo word';
+                setError(message);
+                console.error('Error rolling back word:', err);
+            }
+        },
+        [adminApiToken, loadGameState]
+    );
+
     const handleStartTimer = useCallback(async () => {
         if (!adminApiToken || !selectedLobby) {
             setError(adminApiToken ? 'Lobby not selected' : 'Admin API token is required to start timer');
@@ -755,6 +1014,74 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                             <p>
                                 <strong>Total Players:</strong> {selectedLobby.players.length}
                             </p>
+                            <p className='flex items-center gap-2'>
+                                <strong>Seed:</strong>
+                                <CopyableCode code={String(selectedLobby.lobby.rng_seed)} title='Click to copy seed' />
+                                <Button
+                                    onClick={handleRerollSeed}
+                                    variant='secondary'
+                                    size='sm'
+                                    className='text-xs'
+                                    loading={isRerollingSeed}
+                                    data-testid='reroll-seed-button'
+                                >
+                                    Reroll
+                                </Button>
+                            </p>
+                        </div>
+                        <div className='border-border mt-4 space-y-3 border-t pt-4 text-sm'>
+                            <div className='text-tx-primary space-y-1' data-testid='lobby-schedule'>
+                                <p>
+                                    <strong>Opens:</strong> {formatScheduledTime(selectedLobby.schedule.opens_at)}
+                                </p>
+                                <p>
+                                    <strong>Closes:</strong> {formatScheduledTime(selectedLobby.schedule.closes_at)}
+                                </p>
+                                <p>
+                                    <strong>Starts:</strong> {formatScheduledTime(selectedLobby.schedule.starts_at)}
+                                </p>
+                            </div>
+                            <div className='grid grid-cols-1 gap-3 md:grid-cols-2'>
+                                <TextInput
+                                    id='schedule-timezone'
+                                    label='Timezone'
+                                    value={scheduleForm.timezone}
+                                    onChange={value => setScheduleForm(prev => ({ ...prev, timezone: value }))}
+                                    placeholder='America/New_York'
+                                    data-testid='schedule-timezone-input'
+                                />
+                                <TextInput
+                                    id='schedule-opens-at'
+                                    type='datetime-local'
+                                    label='Opens'
+                                    value={scheduleForm.opens_at ?? ''}
+                                    onChange={value => setScheduleForm(prev => ({ ...prev, opens_at: value }))}
+                                />
+                                <TextInput
+                                    id='schedule-closes-at'
+                                    type='datetime-local'
+                                    label='Closes'
+                                    value={scheduleForm.closes_at ?? ''}
+                                    onChange={value => setScheduleForm(prev => ({ ...prev, closes_at: value }))}
+                                />
+                                <TextInput
+                                    id='schedule-starts-at'
+                                    type='datetime-local'
+                                    label='Starts'
+                                    value={scheduleForm.starts_at ?? ''}
+                                    onChange={value => setScheduleForm(prev => ({ ...prev, starts_at: value }))}
+                                />
+                            </div>
+                            <p className='text-tx-muted text-xs'>Times are entered in the lobby's timezone.</p>
+                            <Button
+                                onClick={handleSaveSchedule}
+                                variant='secondary'
+                                size='sm'
+                                loading={isSavingSchedule}
+                                data-testid='save-schedule-button'
+                            >
+                                Save Schedule
+                            </Button>
                         </div>
                     </Card>
                 </div>
@@ -913,6 +1240,17 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                                 >
                                     Add Team
                                 </Button>
+                                <Button
+                                    onClick={handleRebalanceTeams}
+                                    disabled={isRebalancing || hasActiveGame}
+                                    variant='secondary'
+                                    size='sm'
+                                    loading={isRebalancing}
+                                    loadingIndicatorPlacement='left'
+                                    data-testid='rebalance-teams-button'
+                                >
+                                    Rebalance
+                                </Button>
                                 <div className='text-tx-secondary text-xs'>
                                     Max {maxTeamsAllowed} teams · min {MIN_TEAMS} teams
                                 </div>
@@ -922,6 +1260,7 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                             {selectedLobby.teams.map(team => {
                                 const teamPlayers = selectedLobby.players_by_team?.[team.id] || [];
                                 const isEditing = editingTeamId === team.id;
+                                const isEditedByOther = teamsEditedByOthers.has(team.id);
                                 return (
                                     <Card key={team.id}>
                                         <div className='mb-3 flex flex-wrap items-center justify-between gap-2'>
@@ -963,6 +1302,17 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                                                     </>
                                                 ) : (
                                                     <>
+                                                        <input
+                                                            type='color'
+                                                            value={team.color ?? '#888888'}
+                                                            onChange={e =>
+                                                                handleUpdateTeamColor(team.id, e.target.value)
+                                                            }
+                                                            disabled={isEditedByOther}
+                                                            className='h-5 w-5 cursor-pointer rounded border-none bg-transparent p-0'
+                                                            title='Team color'
+                                                            data-testid={`team-color-input-${team.id}`}
+                                                        />
                                                         <h4
                                                             className='text-tx-primary font-semibold'
                                                             data-testid={`team-name-${team.id}`}
@@ -971,6 +1321,7 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                                                         </h4>
                                                         <Button
                                                             onClick={() => handleStartTeamNameEdit(team.id, team.name)}
+                                                            disabled={isEditedByOther}
                                                             variant='secondary'
                                                             size='sm'
                                                             className='text-xs'
@@ -978,6 +1329,14 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                                                         >
                                                             Edit
                                                         </Button>
+                                                        {isEditedByOther && (
+                                                            <span
+                                                                className='text-tx-muted text-xs'
+                                                                data-testid={`team-edited-by-other-${team.id}`}
+                                                            >
+                                                                Another admin is editing
+                                                            </span>
+                                                        )}
                                                     </>
                                                 )}
                                             </div>
@@ -1114,11 +1473,26 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
 
                 {selectedLobby.teams && selectedLobby.teams.length >= 2 && !gameState?.is_game_active && (
                     <div className='mb-6'>
-                        <div
-                            className='text-tx-secondary mb-3 text-sm tracking-wide uppercase'
-                            data-testid='start-game-heading'
-                        >
-                            Start Game
+                        <div className='mb-3 flex items-center justify-between'>
+                            <div
+                                className='text-tx-secondary text-sm tracking-wide uppercase'
+                                data-testid='start-game-heading'
+                            >
+                                Start Game
+                            </div>
+                            {selectedLobby.lobby.intermission_started_at && (
+                                <Button
+                                    onClick={handleNextRound}
+                                    disabled={isAdvancingRound}
+                                    variant='primary'
+                                    size='md'
+                                    loading={isAdvancingRound}
+                                    loadingIndicatorPlacement='left'
+                                    data-testid='next-round-button'
+                                >
+                                    Next Round
+                                </Button>
+                            )}
                         </div>
                         <Card>
                             <div className='grid grid-cols-1 gap-4 md:grid-cols-4'>
@@ -1377,7 +1751,18 @@ export default function LobbyDetails({ lobbyId, onClose, onLobbyDeleted, refresh
                             </Card>
                         )}
 
-                        <GameProgressView teams={gameState.teams} />
+                        <GameProgressView teams={gameState.teams} onRollbackWord={handleRollbackWord} />
+
+                        {commentary.length > 0 && (
+                            <Card className='mt-4'>
+                                <div className='text-tx-secondary mb-2 text-sm font-semibold'>Commentary</div>
+                                <ol className='text-tx-primary space-y-1 text-sm' aria-live='polite'>
+                                    {commentary.map((line, index) => (
+                                        <li key={index}>{line}</li>
+                                    ))}
+                                </ol>
+                            </Card>
+                        )}
                     </div>
                 )}
             </div>
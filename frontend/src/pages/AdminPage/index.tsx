@@ -1,15 +1,17 @@
-import { useState, useEffect, useCallback, useMemo } from 'react';
+import { useState, useEffect, useCallback, useMemo, useRef } from 'react';
 import LobbiesList from './LobbiesList';
 import LobbyDetails from './LobbyDetails';
 import { useGlobalOutletContext } from '@/hooks/useGlobalOutletContext';
 import { useNavigate } from 'react-router-dom';
 import { useWebSocket } from '@/hooks/useWebSocket';
+import { api } from '@/services/api';
 import { WebSocketMessage, LobbyWebSocketEvents } from '@/types';
 import { StatusIndicator, Alert } from '@/components';
 
 export default function AdminPage() {
-    const { adminApiToken, adminSessionId } = useGlobalOutletContext();
+    const { adminApiToken, adminSessionId, setAdminSessionId } = useGlobalOutletContext();
     const navigate = useNavigate();
+    const sessionRefreshAttemptedRef = useRef(false);
     const [selectedLobbyId, setSelectedLobbyId] = useState<number | null>(null);
     const [lobbyRefreshKey, setLobbyRefreshKey] = useState(0);
     const [allLobbiesRefreshKey, setAllLobbiesRefreshKey] = useState(0);
@@ -35,10 +37,22 @@ export default function AdminPage() {
         console.log('Admin WebSocket disconnected');
     }, []);
 
-    const onError = useCallback((error: Event) => {
-        console.error('Admin WebSocket error:', error);
-        setWsError('WebSocket connection failed');
-    }, []);
+    const onError = useCallback(
+        (error: Event) => {
+            console.error('Admin WebSocket error:', error);
+            setWsError('WebSocket connection failed');
+
+            // Admin sessions expire and don't survive a server restart; ask for a fresh one once before giving up
+            if (adminApiToken && !sessionRefreshAttemptedRef.current) {
+                sessionRefreshAttemptedRef.current = true;
+                api.admin
+                    .checkCredentials(adminApiToken)
+                    .then(response => setAdminSessionId(response.session_id))
+                    .catch(() => navigate('/admin/login'));
+            }
+        },
+        [adminApiToken, navigate, setAdminSessionId]
+    );
 
     const onMessage = useCallback(
         (message: WebSocketMessage) => {
@@ -50,7 +64,9 @@ export default function AdminPage() {
                 case LobbyWebSocketEvents.TEAM_ASSIGNED:
                 case LobbyWebSocketEvents.TEAM_CHANGED:
                 case LobbyWebSocketEvents.DISCONNECTED:
+                case LobbyWebSocketEvents.PLAYER_DISCONNECTED:
                 case LobbyWebSocketEvents.PLAYER_KICKED:
+                case 'occupancy_warning':
                     // Refresh both the selected lobby details and the lobbies list
                     scheduleReload();
                     scheduleLobbiesReload();
@@ -65,15 +81,20 @@ export default function AdminPage() {
     );
 
     const wsUrl = useMemo(
-        () => (adminSessionId ? `/ws/admin/${adminSessionId}?token=${adminApiToken}` : ''),
+        () => (adminSessionId && adminApiToken ? `/ws/admin/${adminSessionId}` : ''),
         [adminSessionId, adminApiToken]
     );
+    const connectToken = useCallback(
+        async () => (await api.admin.getWebSocketToken(adminApiToken ?? '')).token,
+        [adminApiToken]
+    );
     const { isConnected, sendMessage } = useWebSocket(wsUrl, {
         onConnect,
         onDisconnect,
         onError,
         onMessage,
         autoReconnect: false,
+        connectToken,
     });
 
     useEffect(() => {
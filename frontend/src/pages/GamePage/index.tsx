@@ -10,6 +10,7 @@
 
 import { useCallback, useEffect, useMemo, useRef, useState } from 'react';
 import { useNavigate, useLocation } from 'react-router-dom';
+import { useAccessibilityPreferences } from '@/hooks/useAccessibilityPreferences';
 import { useGameState } from '@/hooks/useGameState';
 import { useGlobalOutletContext } from '@/hooks/useGlobalOutletContext';
 import { useToast } from '@/hooks/useToast';
@@ -47,6 +48,8 @@ export default function GamePage() {
     const [isTimerActive, setIsTimerActive] = useState(false);
     const [timerExpiresAt, setTimerExpiresAt] = useState<string | null>(null);
 
+    useAccessibilityPreferences(gameData?.player);
+
     useEffect(() => {
         async function loadGameData() {
             try {
@@ -190,7 +193,7 @@ function Game({
     const [timeRemaining, setTimeRemaining] = useState<number>(0); // seconds
 
     // WebSocket URL
-    const wsUrl = `/ws/lobby/${lobbyId}/player/${sessionId}`;
+    const wsUrl = `/ws/lobby/${lobbyId}/player`;
 
     const handlePlayerKicked = useCallback(() => {
         addToast('You have been kicked from the lobby by an admin.', 'error', 5000);
@@ -233,6 +236,12 @@ function Game({
         [addToast]
     );
 
+    // The server's periodic tick keeps the countdown honest, and starts it for players who joined mid-timer
+    const handleTimerTick = useCallback((event: { remaining_seconds: number; expires_at: string }) => {
+        setIsTimerActive(true);
+        setTimerExpiresAt(event.expires_at);
+    }, []);
+
     const handleTimerExpired = useCallback(() => {
         console.log('[GamePage] Timer expired');
         setIsTimerActive(false);
@@ -268,6 +277,7 @@ function Game({
         onGameEnded: handleGameEnded,
         onGameStarted: handleGameStarted,
         onTimerStarted: handleTimerStarted,
+        onTimerTick: handleTimerTick,
         onTimerExpired: handleTimerExpired,
         sessionId,
         maxRetries: 10,
@@ -293,6 +293,57 @@ describe('JoinForm Component', () => {
                 ).toBeInTheDocument();
             });
         });
+
+        test('shows the server hint under the error', async () => {
+            const user = userEvent.setup();
+            vi.mocked(api.player.lobby.join).mockRejectedValue(
+                new ApiError(409, 'This lobby is full', {
+                    detail: {
+                        code: 'LOBBY_FULL',
+                        message: 'This lobby is full',
+                        hint: 'Ask the host to raise the player cap.',
+                    },
+                })
+            );
+
+            render(
+                <TestWrapper>
+                    <JoinForm />
+                </TestWrapper>
+            );
+
+            await user.type(screen.getByTestId('name-input'), 'John Doe');
+            await user.type(screen.getByTestId('lobby-code-input'), 'ABC123');
+            await user.click(screen.getByTestId('join-lobby-button'));
+
+            await waitFor(() => {
+                expect(screen.getByTestId('join-form-error')).toHaveTextContent('This lobby is full');
+                expect(screen.getByTestId('join-form-hint')).toHaveTextContent('Ask the host to raise the player cap.');
+            });
+        });
+
+        test('explains when the lobby game has already started', async () => {
+            const user = userEvent.setup();
+            vi.mocked(api.player.lobby.join).mockRejectedValue(
+                new ApiError(409, 'Cannot join while a game is in progress')
+            );
+
+            render(
+                <TestWrapper>
+                    <JoinForm />
+                </TestWrapper>
+            );
+
+            await user.type(screen.getByTestId('name-input'), 'John Doe');
+            await user.type(screen.getByTestId('lobby-code-input'), 'ABC123');
+            await user.click(screen.getByTestId('join-lobby-button'));
+
+            await waitFor(() => {
+                expect(
+                    screen.getByText('A game is already in progress in this lobby. Ask the host to allow late joining.')
+                ).toBeInTheDocument();
+            });
+        });
     });
 
     describe('Loading States', () => {
@@ -9,6 +9,7 @@ export default function JoinForm() {
     const [name, setName] = useState('');
     const [lobbyCode, setLobbyCode] = useState('');
     const [error, setError] = useState('');
+    const [hint, setHint] = useState('');
     const [loading, setLoading] = useState(false);
     const navigate = useNavigate();
 
@@ -25,6 +26,7 @@ export default function JoinForm() {
 
         setLoading(true);
         setError('');
+        setHint('');
 
         try {
             const player = await api.player.lobby.join(lobbyCode.trim().toUpperCase(), name.trim());
@@ -32,13 +34,21 @@ export default function JoinForm() {
             navigate(`/lobby/${lobbyCode.trim().toUpperCase()}`);
         } catch (err) {
             if (err instanceof ApiError) {
-                if (err.status === 400) {
+                if (err.code === 'NAME_NOT_ALLOWED') {
+                    setError(err.message);
+                } else if (err.status === 400) {
                     setError('Someone is already using that name in this lobby. Please choose another one.');
                 } else if (err.status === 404) {
                     setError('Lobby not found. Please double-check the code and try again.');
+                } else if (err.status === 409 && (!err.code || err.code === 'GAME_IN_PROGRESS')) {
+                    setError('A game is already in progress in this lobby. Ask the host to allow late joining.');
                 } else {
                     setError(err.message || 'Failed to join lobby. Please try again.');
                 }
+                // The server's hint says what to try next, e.g. a last initial when the name is taken
+                if (err.code !== 'GAME_IN_PROGRESS') {
+                    setHint(err.hint ?? '');
+                }
             } else {
                 setError('Failed to join lobby. Please check the lobby code and try again.');
             }
@@ -77,6 +87,11 @@ export default function JoinForm() {
                     {error}
                 </div>
             )}
+            {error && hint && (
+                <div className='text-tx-secondary -mt-4 text-center text-sm' data-testid='join-form-hint'>
+                    {hint}
+                </div>
+            )}
 
             <Button
                 type='submit'
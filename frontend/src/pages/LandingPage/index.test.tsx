@@ -17,10 +17,12 @@ vi.mock('@/services/api', () => ({
 }));
 
 const mockNavigate = vi.fn();
+let mockSearchParams = new URLSearchParams();
 
 vi.mock('react-router-dom', () => ({
     BrowserRouter: ({ children }: { children: React.ReactNode }) => <div>{children}</div>,
     useNavigate: () => mockNavigate,
+    useSearchParams: () => [mockSearchParams],
     Link: ({ children, ...props }: any) => <a {...props}>{children}</a>,
 }));
 
@@ -50,6 +52,7 @@ const TestWrapper = ({ children }: { children: React.ReactNode }) => <BrowserRou
 describe('LandingPage Component', () => {
     beforeEach(() => {
         vi.clearAllMocks();
+        mockSearchParams = new URLSearchParams();
         mockGetSessionIdFromLocalStorage.mockReturnValue(null);
     });
 
@@ -112,6 +115,24 @@ describe('LandingPage Component', () => {
             });
         });
 
+        test('uses session from join link over stored session', async () => {
+            mockSearchParams = new URLSearchParams({ session: 'imported-session' });
+            mockGetSessionIdFromLocalStorage.mockReturnValue('old-session');
+            vi.mocked(api.player.lobby.getInfo).mockResolvedValue({ code: 'XYZ789', name: 'Round Two' });
+
+            render(
+                <TestWrapper>
+                    <LandingPage />
+                </TestWrapper>
+            );
+
+            await waitFor(() => {
+                expect(api.player.lobby.getInfo).toHaveBeenCalledWith('imported-session');
+                expect(mockSetSessionId).toHaveBeenCalledWith('imported-session');
+                expect(mockNavigate).toHaveBeenCalledWith('/lobby/XYZ789');
+            });
+        });
+
         test('clears invalid session and shows landing page', async () => {
             const mockSessionId = 'invalid-session';
 
@@ -1,17 +1,19 @@
 import { useEffect, useState, useCallback } from 'react';
 import JoinForm from './JoinForm';
-import { useNavigate } from 'react-router-dom';
+import { useNavigate, useSearchParams } from 'react-router-dom';
 import { api } from '@/services/api';
 import { LoadingSpinner } from '@/components';
 import { useGlobalOutletContext } from '@/hooks/useGlobalOutletContext';
 
 export default function LandingPage() {
     const navigate = useNavigate();
+    const [searchParams] = useSearchParams();
     const { setSessionId, getSessionIdFromLocalStorage } = useGlobalOutletContext();
     const [pageLoading, setPageLoading] = useState(true);
 
     const redirectToLobby = useCallback(async () => {
-        const sessionId = getSessionIdFromLocalStorage();
+        // Join links issued by an admin import carry the player's session
+        const sessionId = searchParams.get('session') ?? getSessionIdFromLocalStorage();
         if (sessionId) {
             try {
                 const lobbyData = await api.player.lobby.getInfo(sessionId);
@@ -22,7 +24,7 @@ export default function LandingPage() {
                 setSessionId(null);
             }
         }
-    }, [getSessionIdFromLocalStorage, navigate, setSessionId]);
+    }, [getSessionIdFromLocalStorage, navigate, searchParams, setSessionId]);
 
     useEffect(() => {
         redirectToLobby();
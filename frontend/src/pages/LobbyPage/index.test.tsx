@@ -405,8 +405,9 @@ describe.skip('LobbyPage Component', () => {
 
             await waitFor(() => {
                 expect(mockUseWebSocket).toHaveBeenCalledWith(
-                    'ws://localhost:8000/ws/lobby/123/player/test-session-123',
+                    'ws://localhost:8000/ws/lobby/123/player',
                     expect.objectContaining({
+                        connectToken: expect.any(Function),
                         autoReconnect: true,
                     })
                 );
@@ -5,9 +5,19 @@ import { useWebSocket } from '@/hooks/useWebSocket';
 import { useGlobalOutletContext } from '@/hooks/useGlobalOutletContext';
 import { useDebounce } from '@/hooks/useDebounce';
 import { useToast } from '@/hooks/useToast';
-import { WebSocketMessage, LobbyWebSocketEvents, GameWebSocketEvents, Player, LobbyInfo } from '@/types';
+import { useAccessibilityPreferences } from '@/hooks/useAccessibilityPreferences';
+import {
+    WebSocketMessage,
+    LobbyWebSocketEvents,
+    GameWebSocketEvents,
+    Player,
+    PlayerPreferences,
+    LobbyInfo,
+    PrizeAward,
+} from '@/types';
 import { LoadingSpinner, CopyableCode, Button, ErrorMessage, Alert, Card, ConnectionBadge } from '@/components';
 import { TeamLeaderboard } from '@/components/TeamLeaderboard';
+import { HostControls } from '@/components/HostControls';
 
 interface PlayerAward {
     key: string;
@@ -42,6 +52,12 @@ interface TeamGameStats {
 }
 
 // Seeded random selection - ensures same awards are shown for a player across all views
+const ACCESSIBILITY_OPTIONS: { key: keyof PlayerPreferences; label: string }[] = [
+    { key: 'reduced_motion', label: 'Reduce motion' },
+    { key: 'high_contrast', label: 'High contrast' },
+    { key: 'larger_text', label: 'Larger text' },
+];
+
 function selectRandomAwards(awards: PlayerAward[], playerId: number, maxCount: number = 3): PlayerAward[] {
     if (awards.length <= maxCount) {
         return awards;
@@ -62,6 +78,14 @@ function selectRandomAwards(awards: PlayerAward[], playerId: number, maxCount: n
     return shuffled.slice(0, maxCount);
 }
 
+function prizeToast(award: PrizeAward): string {
+    if (award.prize === 'fastest_rung') {
+        const solver = award.player_name ? `${award.player_name} (${award.team_name})` : award.team_name;
+        return `Fastest rung: ${solver} in ${award.value}s`;
+    }
+    return `Best streak: ${award.team_name} with ${award.value} correct in a row`;
+}
+
 export default function LobbyPage() {
     const navigate = useNavigate();
     const { sessionId, setSessionId } = useGlobalOutletContext();
@@ -74,6 +98,7 @@ export default function LobbyPage() {
     const [wsError, setWsError] = useState<string | null>(null);
     const [isTeamGameCompleted, setIsTeamGameCompleted] = useState<boolean | null>(null);
     const [isTogglingReady, setIsTogglingReady] = useState(false);
+    const [isSavingPreferences, setIsSavingPreferences] = useState(false);
     const [leaderboardRefreshKey, setLeaderboardRefreshKey] = useState(0);
     const [teamRoundStats, setTeamRoundStats] = useState<TeamGameStats | null>(null);
     const [teamRoundNumber, setTeamRoundNumber] = useState<number | null>(null);
@@ -88,6 +113,8 @@ export default function LobbyPage() {
     const [timerExpiresAt, setTimerExpiresAt] = useState<string | null>(null);
     const [timeRemaining, setTimeRemaining] = useState<number>(0); // seconds
 
+    useAccessibilityPreferences(player);
+
     useEffect(() => {
         if (!sessionId) {
             navigate('/');
@@ -281,10 +308,14 @@ export default function LobbyPage() {
                     break;
                 case LobbyWebSocketEvents.TEAM_ASSIGNED:
                 case LobbyWebSocketEvents.TEAM_CHANGED:
+                case LobbyWebSocketEvents.LATE_JOIN:
+                case LobbyWebSocketEvents.TEAM_UPDATED:
+                case LobbyWebSocketEvents.TEAMS_REASSIGNED:
                     console.log('Team assignment changed');
                     scheduleReload();
                     break;
                 case LobbyWebSocketEvents.DISCONNECTED:
+                case LobbyWebSocketEvents.PLAYER_DISCONNECTED:
                     console.log('Player disconnected');
                     scheduleReload();
                     break;
@@ -308,6 +339,26 @@ export default function LobbyPage() {
                     console.log('Player ready status changed');
                     scheduleReload();
                     break;
+                case LobbyWebSocketEvents.PREFERENCES_UPDATED:
+                    console.log('Accessibility preferences changed on another device');
+                    scheduleReload();
+                    break;
+                case LobbyWebSocketEvents.HOST_CHANGED:
+                    if (message.player_session_id === sessionId) {
+                        addToast('You are now the lobby host.', 'info', 5000);
+                    }
+                    scheduleReload();
+                    break;
+                case LobbyWebSocketEvents.LOBBY_PHASE_CHANGED:
+                    // Entering a round is handled by GAME_STARTED, which carries the puzzle details
+                    console.log(`Lobby phase changed: ${message.previous_phase} -> ${message.phase}`);
+                    scheduleReload();
+                    break;
+                case LobbyWebSocketEvents.LOBBY_MERGED:
+                    // Same session, new lobby: reloading picks up the new lobby id and reconnects there
+                    addToast(`This lobby was merged into "${message.target_lobby_name}".`, 'info', 5000);
+                    scheduleReload();
+                    break;
                 case LobbyWebSocketEvents.LOBBY_DELETED:
                     addToast('This lobby was deleted by an admin.', 'error', 5000);
                     setSessionId(null);
@@ -336,11 +387,53 @@ export default function LobbyPage() {
                     // No toast notification - just reload silently
                     scheduleReload();
                     break;
+                case 'game_finished':
+                    if (message.winning_team_name) {
+                        addToast(`${message.winning_team_name} won the round!`, 'success', 8000);
+                    }
+                    for (const award of message.prizes ?? []) {
+                        if (award.prize !== 'top_team') {
+                            addToast(prizeToast(award), 'info', 8000);
+                        }
+                    }
+                    scheduleReload();
+                    break;
+                case 'intermission_started': {
+                    const seconds = message.auto_advance_at
+                        ? Math.max(0, Math.round((Date.parse(message.auto_advance_at) - Date.now()) / 1000))
+                        : null;
+                    addToast(
+                        seconds !== null
+                            ? `Intermission! The next round starts in ${seconds} seconds.`
+                            : 'Intermission! The next round starts when the host is ready.',
+                        'info',
+                        8000
+                    );
+                    scheduleReload();
+                    break;
+                }
+                case 'score_adjusted': {
+                    const delta = message.delta ?? 0;
+                    const change = delta > 0 ? `+${delta}` : `${delta}`;
+                    addToast(`${message.team_name}: ${change} points (${message.reason})`, 'info', 6000);
+                    scheduleReload();
+                    break;
+                }
                 case 'game_ended':
                     console.log('Game ended by admin, refreshing lobby');
                     addToast('Game has been ended by admin.', 'info', 5000);
                     scheduleReload();
                     break;
+                case 'credits':
+                    for (const credit of message.puzzles ?? []) {
+                        const handle = credit.author_handle ? ` (${credit.author_handle})` : '';
+                        addToast(`"${credit.title}" was written by ${credit.author}${handle}`, 'info', 8000);
+                    }
+                    break;
+                case 'resync_required':
+                    console.log('Missed too much while disconnected to replay, refreshing lobby');
+                    scheduleReload();
+                    break;
                 case 'timer_started':
                     console.log('Timer started on lobby page:', message);
                     setIsTimerActive(true);
@@ -351,6 +444,10 @@ export default function LobbyPage() {
                         5000
                     );
                     break;
+                case 'timer_tick':
+                    setIsTimerActive(true);
+                    setTimerExpiresAt(message.expires_at);
+                    break;
                 case 'timer_expired':
                     console.log('Timer expired on lobby page');
                     setIsTimerActive(false);
@@ -385,6 +482,33 @@ export default function LobbyPage() {
         }
     }, [sessionId]);
 
+    const handleTogglePreference = useCallback(
+        async (key: keyof PlayerPreferences) => {
+            if (!sessionId || !player) return;
+
+            setIsSavingPreferences(true);
+            try {
+                setError(null);
+                const updated = await api.player.lobby.setPreferences(
+                    {
+                        reduced_motion: player.reduced_motion,
+                        high_contrast: player.high_contrast,
+                        larger_text: player.larger_text,
+                        [key]: !player[key],
+                    },
+                    sessionId
+                );
+                setPlayer(updated);
+            } catch (err) {
+                setError(err instanceof Error ? err.message : 'Failed to save preferences');
+                console.error('Error saving preferences:', err);
+            } finally {
+                setIsSavingPreferences(false);
+            }
+        },
+        [sessionId, player]
+    );
+
     const formatTime = (seconds: number | null): string => {
         if (seconds === null) return 'DNF';
         const mins = Math.floor(seconds / 60);
@@ -403,16 +527,23 @@ export default function LobbyPage() {
     };
 
     const wsUrl = useMemo(
-        () => (player?.lobby_id && sessionId ? `/ws/lobby/${player.lobby_id}/player/${sessionId}` : ''),
+        () => (player?.lobby_id && sessionId ? `/ws/lobby/${player.lobby_id}/player` : ''),
         [player?.lobby_id, sessionId]
     );
+    const connectToken = useCallback(
+        async () => (await api.player.lobby.getWebSocketToken(sessionId ?? '')).token,
+        [sessionId]
+    );
 
     const { connectionStatus, retryCount, manualReconnect } = useWebSocket(wsUrl, {
         onConnect,
         onDisconnect,
         onError,
         onMessage,
+        connectToken,
         autoReconnect: true,
+        resumable: true,
+        acknowledge: true,
         maxRetries: 10,
         onMaxRetriesReached: () => {
             setWsError('Unable to connect to lobby. Please refresh the page or check your internet connection.');
@@ -461,6 +592,18 @@ export default function LobbyPage() {
                             <span className='text-tx-muted text-xs'>
                                 {lobbyInfo.teams?.length || 0} {lobbyInfo.teams?.length === 1 ? 'team' : 'teams'}
                             </span>
+                            {lobbyInfo.schedule?.starts_at && (
+                                <>
+                                    <span className='text-tx-muted text-xs'>•</span>
+                                    <span
+                                        className='text-tx-muted text-xs'
+                                        title={`Your time: ${new Date(lobbyInfo.schedule.starts_at.utc).toLocaleString()}`}
+                                        data-testid='lobby-starts-at'
+                                    >
+                                        Starts {lobbyInfo.schedule.starts_at.display}
+                                    </span>
+                                </>
+                            )}
                         </div>
                     </div>
                     <div className='flex-shrink-0'>
@@ -694,6 +837,16 @@ export default function LobbyPage() {
                 </div>
             )}
 
+            {/* Host controls - only for the lobby host */}
+            {sessionId && lobbyInfo.lobby.host_player_id === player.id && (
+                <HostControls
+                    sessionId={sessionId}
+                    hasTeams={Boolean(hasTeams)}
+                    playerCount={lobbyInfo.players.length}
+                    onError={setError}
+                />
+            )}
+
             {/* Tournament Leaderboard */}
             {sessionId && (
                 <TeamLeaderboard
@@ -806,7 +959,16 @@ export default function LobbyPage() {
                                     >
                                         <div className='mb-3 flex items-center justify-between gap-2'>
                                             <div>
-                                                <h3 className='text-tx-primary text-lg font-semibold'>{team.name}</h3>
+                                                <h3 className='text-tx-primary flex items-center gap-2 text-lg font-semibold'>
+                                                    {team.color && (
+                                                        <span
+                                                            className='inline-block h-3 w-3 rounded-full'
+                                                            style={{ backgroundColor: team.color }}
+                                                            data-testid={`team-color-${team.id}`}
+                                                        />
+                                                    )}
+                                                    {team.name}
+                                                </h3>
                                                 <p className='text-tx-secondary text-xs'>
                                                     {teamPlayers.length}{' '}
                                                     {teamPlayers.length === 1 ? 'player' : 'players'}
@@ -889,6 +1051,28 @@ export default function LobbyPage() {
                     )}
                 </>
             )}
+
+            {/* Accessibility preferences - saved to the player so every device renders the same */}
+            <Card className='p-4'>
+                <div className='text-tx-secondary mb-3 text-xs font-semibold tracking-wide uppercase'>
+                    Accessibility
+                </div>
+                <div className='flex flex-col gap-2 sm:flex-row sm:gap-6'>
+                    {ACCESSIBILITY_OPTIONS.map(({ key, label }) => (
+                        <label key={key} className='text-tx-primary flex cursor-pointer items-center gap-2 text-sm'>
+                            <input
+                                type='checkbox'
+                                checked={player[key]}
+                                disabled={isSavingPreferences}
+                                onChange={() => handleTogglePreference(key)}
+                                className='accent-accent h-4 w-4'
+                                data-testid={`preference-${key}`}
+                            />
+                            {label}
+                        </label>
+                    ))}
+                </div>
+            </Card>
         </div>
     );
 }
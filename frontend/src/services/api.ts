@@ -1,43 +1,115 @@
 import {
+    AdminLobbyListQuery,
+    AdminLobbyListResponse,
     Player,
+    PlayerPreferences,
     Lobby,
     LobbyInfo,
+    LobbySchedule,
+    LobbyScheduleUpdate,
+    LobbySettingsUpdate,
+    MergeLobbiesResponse,
+    NextRoundRequest,
     ApiResponse,
+    BroadcastApiResponse,
+    CommentaryFeedResponse,
+    ErrorCode,
     GeneratedNameResponse,
+    GuessBatchResponse,
+    GuessSubmitResponse,
+    HostStartGameRequest,
     AdminAuthAdminAuthenticatedResponse,
     GameState,
     Guess,
+    GameResultsResponse,
     GameStateResponse,
+    ImportPlayersResponse,
+    LobbyGuessHistoryResponse,
+    PendingGuess,
+    PlayerStatsQuery,
+    PlayerStatsResponse,
+    PollResponse,
+    PrizeTiers,
+    ProgressVisibility,
+    QueuedGuess,
+    QuickPlayInfo,
+    RevealStatus,
+    RivalriesResponse,
+    RosterResponse,
+    ScoreAdjustment,
+    SpectatorSessionResponse,
     StartGameRequest,
     StartGameResponse,
+    StoredPuzzleDetail,
+    StoredPuzzleInput,
+    StoredPuzzleSummary,
+    StructuredErrorDetail,
+    Team,
+    TeamNameTheme,
+    TeamPatch,
+    TeamChatHistoryResponse,
+    TeamGuessHistoryResponse,
+    TeamProgressResponse,
+    WebSocketTokenResponse,
+    AdminWebSocketTokenResponse,
 } from '@/types';
 import type { Puzzle } from '@/types/game';
 
 export class ApiError extends Error {
     status: number;
     data: unknown;
+    code?: ErrorCode;
+    hint?: string; // What the player can try next, for the common failures (see ERROR_HINTS in backend/errors.py)
 
     constructor(status: number, message: string, data?: unknown) {
         super(message);
         this.status = status;
         this.data = data;
+        if (isStructuredErrorDetail(data)) {
+            this.code = data.detail.code;
+            this.hint = data.detail.hint;
+        }
     }
 }
 
 const API_BASE = '/api';
 
+const isStructuredErrorDetail = (data: unknown): data is { detail: StructuredErrorDetail } => {
+    if (typeof data !== 'object' || data === null || !('detail' in data)) return false;
+    const detail = (data as { detail?: unknown }).detail;
+    return typeof detail === 'object' && detail !== null && 'code' in detail && 'message' in detail;
+};
+
+// Set alongside the admin session cookie when the server runs with ADMIN_COOKIE_AUTH; echoed back as a header so
+// cookie-authenticated writes pass the server's CSRF check
+const CSRF_COOKIE = 'raddle_csrf';
+
+const csrfToken = (): string | undefined => {
+    if (typeof document === 'undefined') return undefined;
+    const cookie = document.cookie.split('; ').find(entry => entry.startsWith(`${CSRF_COOKIE}=`));
+    return cookie?.slice(CSRF_COOKIE.length + 1);
+};
+
 const request = async <T>(endpoint: string, options?: RequestInit, bearerToken?: string): Promise<T> => {
     const url = `${API_BASE}${endpoint}`;
     console.log(`API Request: ${options?.method || 'GET'} ${url}`);
+    const csrf = csrfToken();
     const response = await fetch(url, {
         headers: {
             'Content-Type': 'application/json',
             ...options?.headers,
             ...(bearerToken ? { Authorization: `Bearer ${bearerToken}` } : {}),
+            ...(csrf ? { 'X-CSRF-Token': csrf } : {}),
         },
         ...options,
     });
 
+    if (response.headers.get('Deprecation')) {
+        const sunset = response.headers.get('Sunset');
+        const suffix = sunset ? ` (sunset ${sunset})` : '';
+        console.warn(`Deprecated API endpoint: ${options?.method || 'GET'} ${url}${suffix}`);
+    }
+
     if (!response.ok) {
         let errorData: unknown = null;
         let errorMessage = `API error: ${response.status} ${response.statusText}`;
@@ -53,6 +125,9 @@ const request = async <T>(endpoint: string, options?: RequestInit, bearerToken?:
                     typeof (errorData as { detail?: unknown }).detail === 'string'
                 ) {
                     errorMessage = (errorData as { detail: string }).detail;
+                } else if (isStructuredErrorDetail(errorData)) {
+                    // Structured errors (e.g. GAME_IN_PROGRESS) carry a code and lobby state alongside the message
+                    errorMessage = errorData.detail.message;
                 }
             } else {
                 const text = await response.text();
@@ -77,14 +152,84 @@ export const api = {
             async getDates(bearerToken: string): Promise<string[]> {
                 return request<string[]>('/admin/puzzles/dates', {}, bearerToken);
             },
+            async list(bearerToken: string, difficulty?: string, author?: string): Promise<StoredPuzzleSummary[]> {
+                const params = new URLSearchParams();
+                if (difficulty) {
+                    params.set('difficulty', difficulty);
+                }
+                if (author) {
+                    params.set('author', author);
+                }
+                return request<StoredPuzzleSummary[]>(`/admin/puzzle?${params}`, {}, bearerToken);
+            },
+            async get(puzzleId: number, bearerToken: string): Promise<StoredPuzzleDetail> {
+                return request<StoredPuzzleDetail>(`/admin/puzzle/${puzzleId}`, {}, bearerToken);
+            },
+            async create(puzzle: StoredPuzzleInput, bearerToken: string): Promise<StoredPuzzleDetail> {
+                return request<StoredPuzzleDetail>(
+                    '/admin/puzzle',
+                    {
+                        method: 'POST',
+                        body: JSON.stringify(puzzle),
+                    },
+                    bearerToken
+                );
+            },
+            async update(
+                puzzleId: number,
+                puzzle: StoredPuzzleInput,
+                bearerToken: string
+            ): Promise<StoredPuzzleDetail> {
+                return request<StoredPuzzleDetail>(
+                    `/admin/puzzle/${puzzleId}`,
+                    {
+                        method: 'PUT',
+                        body: JSON.stringify(puzzle),
+                    },
+                    bearerToken
+                );
+            },
+            async delete(puzzleId: number, bearerToken: string): Promise<ApiResponse> {
+                return request<ApiResponse>(
+                    `/admin/puzzle/${puzzleId}`,
+                    {
+                        method: 'DELETE',
+                    },
+                    bearerToken
+                );
+            },
         },
         lobby: {
-            async create(name: string, bearerToken: string): Promise<Lobby> {
+            async create(name: string, bearerToken: string, rngSeed?: number): Promise<Lobby> {
                 return request<Lobby>(
                     '/admin/lobby',
                     {
                         method: 'POST',
-                        body: JSON.stringify({ name }),
+                        body: JSON.stringify({ name, rng_seed: rngSeed }),
+                    },
+                    bearerToken
+                );
+            },
+            async setSeed(lobbyId: number, seed: number | null, bearerToken: string): Promise<Lobby> {
+                return request<Lobby>(
+                    `/admin/lobby/${lobbyId}/seed`,
+                    {
+                        method: 'PUT',
+                        body: JSON.stringify({ seed }),
+                    },
+                    bearerToken
+                );
+            },
+            async setSchedule(
+                lobbyId: number,
+                schedule: LobbyScheduleUpdate,
+                bearerToken: string
+            ): Promise<LobbySchedule> {
+                return request<LobbySchedule>(
+                    `/admin/lobby/${lobbyId}/schedule`,
+                    {
+                        method: 'PUT',
+                        body: JSON.stringify(schedule),
                     },
                     bearerToken
                 );
@@ -92,8 +237,21 @@ export const api = {
             async getRandomName(bearerToken: string): Promise<GeneratedNameResponse> {
                 return request<GeneratedNameResponse>('/admin/lobby/random-name', {}, bearerToken);
             },
-            async getAll(bearerToken: string): Promise<Lobby[]> {
-                return request<Lobby[]>('/admin/lobby', {}, bearerToken);
+            async getAll(bearerToken: string, query: AdminLobbyListQuery = {}): Promise<AdminLobbyListResponse> {
+                const params = new URLSearchParams();
+                if (query.page !== undefined) {
+                    params.set('page', String(query.page));
+                }
+                if (query.perPage !== undefined) {
+                    params.set('per_page', String(query.perPage));
+                }
+                if (query.sort) {
+                    params.set('sort', query.sort);
+                }
+                if (query.activeOnly) {
+                    params.set('active_only', 'true');
+                }
+                return request<AdminLobbyListResponse>(`/admin/lobby?${params}`, {}, bearerToken);
             },
             async delete(lobbyId: number, bearerToken: string): Promise<ApiResponse> {
                 return request<ApiResponse>(
@@ -107,29 +265,184 @@ export const api = {
             async getInfo(lobbyId: number, bearerToken: string): Promise<LobbyInfo> {
                 return request<LobbyInfo>(`/admin/lobby/${lobbyId}`, {}, bearerToken);
             },
+            async setLateJoin(lobbyId: number, enabled: boolean, bearerToken: string): Promise<ApiResponse> {
+                return request<ApiResponse>(
+                    `/admin/lobby/${lobbyId}/late-join`,
+                    {
+                        method: 'PUT',
+                        body: JSON.stringify({ enabled }),
+                    },
+                    bearerToken
+                );
+            },
+            async setPaceKeeper(lobbyId: number, enabled: boolean, bearerToken: string): Promise<ApiResponse> {
+                return request<ApiResponse>(
+                    `/admin/lobby/${lobbyId}/pace-keeper`,
+                    {
+                        method: 'PUT',
+                        body: JSON.stringify({ enabled }),
+                    },
+                    bearerToken
+                );
+            },
+            async setIntermission(
+                lobbyId: number,
+                enabled: boolean,
+                seconds: number | null,
+                bearerToken: string
+            ): Promise<ApiResponse> {
+                return request<ApiResponse>(
+                    `/admin/lobby/${lobbyId}/intermission`,
+                    {
+                        method: 'PUT',
+                        body: JSON.stringify({ enabled, seconds }),
+                    },
+                    bearerToken
+                );
+            },
+            async setPrizeTiers(lobbyId: number, tiers: PrizeTiers, bearerToken: string): Promise<ApiResponse> {
+                return request<ApiResponse>(
+                    `/admin/lobby/${lobbyId}/prizes`,
+                    {
+                        method: 'PUT',
+                        body: JSON.stringify(tiers),
+                    },
+                    bearerToken
+                );
+            },
+            async updateSettings(
+                lobbyId: number,
+                lobbySettings: LobbySettingsUpdate,
+                bearerToken: string
+            ): Promise<Lobby> {
+                return request<Lobby>(
+                    `/admin/lobby/${lobbyId}`,
+                    {
+                        method: 'PATCH',
+                        body: JSON.stringify(lobbySettings),
+                    },
+                    bearerToken
+                );
+            },
+            async setSoftPlayerCap(
+                lobbyId: number,
+                softPlayerCap: number | null,
+                bearerToken: string
+            ): Promise<ApiResponse> {
+                return request<ApiResponse>(
+                    `/admin/lobby/${lobbyId}/soft-cap`,
+                    {
+                        method: 'PUT',
+                        body: JSON.stringify({ soft_player_cap: softPlayerCap }),
+                    },
+                    bearerToken
+                );
+            },
+            async setProgressVisibility(
+                lobbyId: number,
+                visibility: ProgressVisibility,
+                bearerToken: string
+            ): Promise<ApiResponse> {
+                return request<ApiResponse>(
+                    `/admin/lobby/${lobbyId}/progress-visibility`,
+                    {
+                        method: 'PUT',
+                        body: JSON.stringify({ visibility }),
+                    },
+                    bearerToken
+                );
+            },
+            async importPlayers(
+                lobbyId: number,
+                fromLobbyId: number,
+                preserveTeams: boolean,
+                bearerToken: string
+            ): Promise<ImportPlayersResponse> {
+                const params = new URLSearchParams({
+                    from_lobby: String(fromLobbyId),
+                    preserve_teams: String(preserveTeams),
+                });
+                return request<ImportPlayersResponse>(
+                    `/admin/lobby/${lobbyId}/import-players?${params}`,
+                    {
+                        method: 'POST',
+                    },
+                    bearerToken
+                );
+            },
+            async mergeInto(sourceId: number, targetId: number, bearerToken: string): Promise<MergeLobbiesResponse> {
+                return request<MergeLobbiesResponse>(
+                    `/admin/lobby/${sourceId}/merge-into/${targetId}`,
+                    {
+                        method: 'POST',
+                    },
+                    bearerToken
+                );
+            },
+            async addBots(
+                lobbyId: number,
+                count: number,
+                skill: number | null,
+                bearerToken: string
+            ): Promise<Player[]> {
+                const params = new URLSearchParams({ count: String(count) });
+                if (skill !== null) {
+                    params.set('skill', String(skill));
+                }
+                return request<Player[]>(
+                    `/admin/lobby/${lobbyId}/bots?${params}`,
+                    {
+                        method: 'POST',
+                    },
+                    bearerToken
+                );
+            },
             team: {
-                async create(lobbyId: number, numTeams: number, bearerToken: string): Promise<ApiResponse> {
+                async create(
+                    lobbyId: number,
+                    numTeams: number,
+                    bearerToken: string,
+                    theme?: TeamNameTheme
+                ): Promise<ApiResponse> {
                     return request<ApiResponse>(
                         `/admin/lobby/${lobbyId}/team`,
                         {
                             method: 'POST',
-                            body: JSON.stringify({ num_teams: numTeams }),
+                            body: JSON.stringify({ num_teams: numTeams, theme }),
+                        },
+                        bearerToken
+                    );
+                },
+                async shuffle(lobbyId: number, bearerToken: string): Promise<ApiResponse> {
+                    return request<ApiResponse>(
+                        `/admin/lobby/${lobbyId}/team/shuffle`,
+                        {
+                            method: 'POST',
+                        },
+                        bearerToken
+                    );
+                },
+                async rebalance(lobbyId: number, shuffle: boolean, bearerToken: string): Promise<ApiResponse> {
+                    return request<ApiResponse>(
+                        `/admin/lobby/${lobbyId}/team/rebalance${shuffle ? '?shuffle=true' : ''}`,
+                        {
+                            method: 'POST',
                         },
                         bearerToken
                     );
                 },
                 async addOne(lobbyId: number, bearerToken: string): Promise<ApiResponse> {
                     return request<ApiResponse>(
-                        `/admin/lobby/${lobbyId}/team/add-one`,
+                        `/admin/lobby/${lobbyId}/team/single`,
                         {
                             method: 'POST',
                         },
                         bearerToken
                     );
                 },
-                async remove(teamId: number, bearerToken: string): Promise<ApiResponse> {
+                async remove(teamId: number, bearerToken: string, reassign: boolean = false): Promise<ApiResponse> {
                     return request<ApiResponse>(
-                        `/admin/lobby/team/${teamId}`,
+                        `/admin/lobby/team/${teamId}${reassign ? '?reassign=true' : ''}`,
                         {
                             method: 'DELETE',
                         },
@@ -145,20 +458,62 @@ export const api = {
                         bearerToken
                     );
                 },
-                async updateName(teamId: number, name: string, bearerToken: string): Promise<ApiResponse> {
+                // playerId -> teamId (0 unassigns), applied together with one teams_reassigned event
+                async assign(
+                    lobbyId: number,
+                    assignments: Record<number, number>,
+                    bearerToken: string
+                ): Promise<ApiResponse> {
                     return request<ApiResponse>(
-                        `/admin/lobby/team/${teamId}/name`,
+                        `/admin/lobby/${lobbyId}/assignments`,
                         {
                             method: 'PUT',
-                            body: JSON.stringify({ name }),
+                            body: JSON.stringify({ assignments }),
                         },
                         bearerToken
                     );
                 },
+                async update(teamId: number, patch: TeamPatch, bearerToken: string): Promise<Team> {
+                    return request<Team>(
+                        `/admin/lobby/team/${teamId}`,
+                        {
+                            method: 'PATCH',
+                            body: JSON.stringify(patch),
+                        },
+                        bearerToken
+                    );
+                },
+                async rollbackWord(teamId: number, bearerToken: string): Promise<ApiResponse> {
+                    return request<ApiResponse>(
+                        `/admin/lobby/team/${teamId}/rollback-word`,
+                        {
+                            method: 'POST',
+                        },
+                        bearerToken
+                    );
+                },
+                async adjustScore(
+                    teamId: number,
+                    delta: number,
+                    reason: string,
+                    bearerToken: string
+                ): Promise<ScoreAdjustment> {
+                    return request<ScoreAdjustment>(
+                        `/admin/lobby/team/${teamId}/score-adjust`,
+                        {
+                            method: 'POST',
+                            body: JSON.stringify({ delta, reason }),
+                        },
+                        bearerToken
+                    );
+                },
+                async getScoreAdjustments(lobbyId: number, bearerToken: string): Promise<ScoreAdjustment[]> {
+                    return request<ScoreAdjustment[]>(`/admin/lobby/${lobbyId}/score-adjustments`, {}, bearerToken);
+                },
             },
             player: {
-                async kick(playerId: number, bearerToken: string): Promise<ApiResponse> {
-                    return request<ApiResponse>(
+                async kick(playerId: number, bearerToken: string): Promise<BroadcastApiResponse> {
+                    return request<BroadcastApiResponse>(
                         `/admin/lobby/player/${playerId}`,
                         {
                             method: 'DELETE',
@@ -166,6 +521,87 @@ export const api = {
                         bearerToken
                     );
                 },
+                async makeHost(lobbyId: number, playerId: number, bearerToken: string): Promise<Lobby> {
+                    return request<Lobby>(
+                        `/admin/lobby/${lobbyId}/host/${playerId}`,
+                        {
+                            method: 'PUT',
+                        },
+                        bearerToken
+                    );
+                },
+            },
+            guess: {
+                async list(
+                    lobbyId: number,
+                    bearerToken: string,
+                    filters: { teamId?: number; playerId?: number; gameId?: number; page?: number } = {}
+                ): Promise<LobbyGuessHistoryResponse> {
+                    const params = new URLSearchParams({ page: String(filters.page ?? 1) });
+                    if (filters.teamId !== undefined) params.set('team_id', String(filters.teamId));
+                    if (filters.playerId !== undefined) params.set('player_id', String(filters.playerId));
+                    if (filters.gameId !== undefined) params.set('game_id', String(filters.gameId));
+                    return request<LobbyGuessHistoryResponse>(
+                        `/admin/lobby/${lobbyId}/guesses?${params.toString()}`,
+                        {},
+                        bearerToken
+                    );
+                },
+                async getPending(lobbyId: number, bearerToken: string): Promise<PendingGuess[]> {
+                    return request<PendingGuess[]>(`/admin/lobby/${lobbyId}/pending-guesses`, {}, bearerToken);
+                },
+                async approve(guessId: number, bearerToken: string): Promise<ApiResponse> {
+                    return request<ApiResponse>(
+                        `/admin/lobby/guess/${guessId}/approve`,
+                        {
+                            method: 'POST',
+                        },
+                        bearerToken
+                    );
+                },
+                async reject(guessId: number, bearerToken: string): Promise<ApiResponse> {
+                    return request<ApiResponse>(
+                        `/admin/lobby/guess/${guessId}/reject`,
+                        {
+                            method: 'POST',
+                        },
+                        bearerToken
+                    );
+                },
+            },
+            reveal: {
+                async start(
+                    lobbyId: number,
+                    intervalSeconds: number | null,
+                    bearerToken: string
+                ): Promise<RevealStatus> {
+                    return request<RevealStatus>(
+                        `/admin/lobby/${lobbyId}/reveal`,
+                        {
+                            method: 'POST',
+                            body: JSON.stringify({ interval_seconds: intervalSeconds }),
+                        },
+                        bearerToken
+                    );
+                },
+                async next(lobbyId: number, bearerToken: string): Promise<RevealStatus> {
+                    return request<RevealStatus>(
+                        `/admin/lobby/${lobbyId}/reveal/next`,
+                        {
+                            method: 'POST',
+                        },
+                        bearerToken
+                    );
+                },
+                async stop(lobbyId: number, bearerToken: string): Promise<ApiResponse> {
+                    return request<ApiResponse>(
+                        `/admin/lobby/${lobbyId}/reveal`,
+                        {
+                            method: 'DELETE',
+                        },
+                        bearerToken
+                    );
+                },
             },
             async startGame(
                 lobbyId: number,
@@ -184,7 +620,7 @@ export const api = {
                     puzzle_date: puzzleDate,
                 };
                 return request<StartGameResponse>(
-                    `/admin/lobby/${lobbyId}/start`,
+                    `/admin/lobby/${lobbyId}/game/start`,
                     {
                         method: 'POST',
                         body: JSON.stringify(requestBody),
@@ -195,9 +631,23 @@ export const api = {
             async getGameState(lobbyId: number, bearerToken: string): Promise<GameStateResponse> {
                 return request<GameStateResponse>(`/admin/lobby/${lobbyId}/game-state`, {}, bearerToken);
             },
-            async endGame(lobbyId: number, bearerToken: string): Promise<StartGameResponse> {
+            async nextRound(
+                lobbyId: number,
+                nextRound: NextRoundRequest,
+                bearerToken: string
+            ): Promise<StartGameResponse> {
                 return request<StartGameResponse>(
-                    `/admin/lobby/${lobbyId}/end`,
+                    `/admin/lobby/${lobbyId}/next-round`,
+                    {
+                        method: 'POST',
+                        body: JSON.stringify(nextRound),
+                    },
+                    bearerToken
+                );
+            },
+            async endGame(lobbyId: number, bearerToken: string): Promise<BroadcastApiResponse> {
+                return request<BroadcastApiResponse>(
+                    `/admin/lobby/${lobbyId}/game/end`,
                     {
                         method: 'POST',
                     },
@@ -322,21 +772,69 @@ export const api = {
                 return request(`/lobby/${lobbyId}/leaderboard`, {}, bearerToken);
             },
         },
+        stats: {
+            async getPlayers(bearerToken: string, query: PlayerStatsQuery = {}): Promise<PlayerStatsResponse> {
+                const params = new URLSearchParams();
+                if (query.dateFrom) {
+                    params.set('date_from', query.dateFrom);
+                }
+                if (query.dateTo) {
+                    params.set('date_to', query.dateTo);
+                }
+                if (query.limit !== undefined) {
+                    params.set('limit', String(query.limit));
+                }
+                return request<PlayerStatsResponse>(`/admin/stats/players?${params}`, {}, bearerToken);
+            },
+            async getRivalries(lobbyId: number, bearerToken: string): Promise<RivalriesResponse> {
+                return request<RivalriesResponse>(`/admin/stats/rivalries?lobby_id=${lobbyId}`, {}, bearerToken);
+            },
+        },
+        async login(password: string, organization?: string): Promise<AdminAuthAdminAuthenticatedResponse> {
+            return request<AdminAuthAdminAuthenticatedResponse>('/admin/login', {
+                method: 'POST',
+                body: JSON.stringify({ password, organization: organization || null }),
+            });
+        },
         async checkCredentials(bearerToken: string): Promise<AdminAuthAdminAuthenticatedResponse> {
             return request<AdminAuthAdminAuthenticatedResponse>('/admin/check', {}, bearerToken);
         },
+        async getWebSocketToken(bearerToken: string): Promise<AdminWebSocketTokenResponse> {
+            return request<AdminWebSocketTokenResponse>('/admin/ws-token', { method: 'POST' }, bearerToken);
+        },
+        async logout(bearerToken: string): Promise<ApiResponse> {
+            return request<ApiResponse>('/admin/logout', { method: 'POST' }, bearerToken);
+        },
     },
     player: {
         lobby: {
+            async getQuickPlay(): Promise<QuickPlayInfo> {
+                return request<QuickPlayInfo>('/quick-play');
+            },
             async activeUser(sessionId: string): Promise<Player> {
                 return request<Player>(`/lobby/active`, {}, sessionId);
             },
-            async join(lobbyCode: string, name: string, sessionId?: string): Promise<Player> {
+            async join(
+                lobbyCode: string,
+                name: string,
+                sessionId?: string,
+                preferences?: PlayerPreferences
+            ): Promise<Player> {
                 return request<Player>(
                     `/lobby/${lobbyCode}`,
                     {
                         method: 'POST',
-                        body: JSON.stringify({ name }),
+                        body: JSON.stringify({ name, preferences }),
+                    },
+                    sessionId
+                );
+            },
+            async setPreferences(preferences: PlayerPreferences, sessionId: string): Promise<Player> {
+                return request<Player>(
+                    `/lobby/player/preferences`,
+                    {
+                        method: 'PUT',
+                        body: JSON.stringify(preferences),
                     },
                     sessionId
                 );
@@ -347,6 +845,49 @@ export const api = {
             async getLobbyInfo(lobbyId: number, sessionId: string): Promise<LobbyInfo> {
                 return request<LobbyInfo>(`/lobby/${lobbyId}`, {}, sessionId);
             },
+            async poll(lobbyId: number, afterSeq: number | null, sessionId: string): Promise<PollResponse> {
+                const query = afterSeq === null ? '' : `?after_seq=${afterSeq}`;
+                return request<PollResponse>(`/lobby/${lobbyId}/poll${query}`, {}, sessionId);
+            },
+            async getRoster(lobbyId: number, sinceVersion: number | null, sessionId: string): Promise<RosterResponse> {
+                const query = sinceVersion === null ? '' : `?since_version=${sinceVersion}`;
+                return request<RosterResponse>(`/lobby/${lobbyId}/roster${query}`, {}, sessionId);
+            },
+            async getResults(lobbyId: number, roundNumber?: number): Promise<GameResultsResponse> {
+                const query = roundNumber === undefined ? '' : `?round_number=${roundNumber}`;
+                return request<GameResultsResponse>(`/lobby/${lobbyId}/results${query}`);
+            },
+            host: {
+                async createTeams(numTeams: number, sessionId: string, theme?: TeamNameTheme): Promise<ApiResponse> {
+                    return request<ApiResponse>(
+                        `/lobby/host/teams`,
+                        {
+                            method: 'POST',
+                            body: JSON.stringify({ num_teams: numTeams, theme }),
+                        },
+                        sessionId
+                    );
+                },
+                async shuffleTeams(sessionId: string): Promise<ApiResponse> {
+                    return request<ApiResponse>(
+                        `/lobby/host/teams/shuffle`,
+                        {
+                            method: 'POST',
+                        },
+                        sessionId
+                    );
+                },
+                async startGame(requestBody: HostStartGameRequest, sessionId: string): Promise<StartGameResponse> {
+                    return request<StartGameResponse>(
+                        `/lobby/host/game/start`,
+                        {
+                            method: 'POST',
+                            body: JSON.stringify(requestBody),
+                        },
+                        sessionId
+                    );
+                },
+            },
             async leave(sessionId: string): Promise<ApiResponse> {
                 return request<ApiResponse>(
                     `/lobby`,
@@ -356,6 +897,21 @@ export const api = {
                     sessionId
                 );
             },
+            async getTeamGuesses(sessionId: string, page: number = 1): Promise<TeamGuessHistoryResponse> {
+                return request<TeamGuessHistoryResponse>(`/lobby/team/guesses?page=${page}`, {}, sessionId);
+            },
+            async getTeamChat(sessionId: string): Promise<TeamChatHistoryResponse> {
+                return request<TeamChatHistoryResponse>(`/lobby/team/chat`, {}, sessionId);
+            },
+            async getWebSocketToken(sessionId: string): Promise<WebSocketTokenResponse> {
+                return request<WebSocketTokenResponse>(`/lobby/ws-token`, { method: 'POST' }, sessionId);
+            },
+            async spectate(lobbyCode: string): Promise<SpectatorSessionResponse> {
+                return request<SpectatorSessionResponse>(`/lobby/spectate/${lobbyCode}`, { method: 'POST' });
+            },
+            async getCommentary(lobbyId: number): Promise<CommentaryFeedResponse> {
+                return request<CommentaryFeedResponse>(`/lobby/${lobbyId}/commentary`);
+            },
             async toggleReady(sessionId: string): Promise<ApiResponse> {
                 return request<ApiResponse>(
                     `/lobby/ready`,
@@ -408,6 +964,26 @@ export const api = {
                     guesses: Guess[];
                 }>(`/game/puzzle?player_session_id=${sessionId}`, {}, sessionId);
             },
+            async submitGuess(guess: string, wordIndex: number, sessionId: string): Promise<GuessSubmitResponse> {
+                return request<GuessSubmitResponse>(
+                    '/lobby/guess',
+                    {
+                        method: 'POST',
+                        body: JSON.stringify({ guess, word_index: wordIndex }),
+                    },
+                    sessionId
+                );
+            },
+            async submitQueuedGuesses(guesses: QueuedGuess[], sessionId: string): Promise<GuessBatchResponse> {
+                return request<GuessBatchResponse>(
+                    '/lobby/guess/batch',
+                    {
+                        method: 'POST',
+                        body: JSON.stringify({ guesses }),
+                    },
+                    sessionId
+                );
+            },
             async getGameStats(
                 gameId: number,
                 sessionId: string
@@ -459,6 +1035,9 @@ export const api = {
                     expires_at: string | null;
                 }>(`/game/timer-state?player_session_id=${sessionId}`, {}, sessionId);
             },
+            async getTeamProgress(sessionId: string): Promise<TeamProgressResponse> {
+                return request<TeamProgressResponse>('/game/progress', {}, sessionId);
+            },
         },
     },
 };
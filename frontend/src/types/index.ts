@@ -2,51 +2,278 @@
 // ? API RESPONSES
 // #########################################################################
 
-export interface Player {
+export interface PlayerPreferences {
+    reduced_motion: boolean;
+    high_contrast: boolean;
+    larger_text: boolean;
+}
+
+export interface Player extends PlayerPreferences {
     id: number;
     name: string;
     session_id: string;
     lobby_id: number;
     team_id?: number;
     is_ready: boolean;
+    is_bot?: boolean;
+    bot_skill?: number | null;
+    join_order: number; // Rosters come sorted by it
     created_at: string;
 }
 
 export interface Team {
     id: number;
     name: string;
+    color: string | null; // "#RRGGBB", assigned from the server's palette
     game_id: number;
     lobby_id: number;
     current_word_index: number;
     created_at: string;
 }
 
+export interface TeamPatch {
+    name?: string;
+    color?: string; // "#RRGGBB"
+}
+
+export type TeamNameTheme = 'animals' | 'colors' | 'cities';
+
+export type ProgressVisibility = 'none' | 'coarse' | 'exact';
+
 export interface Lobby {
     id: number;
     code: string;
     name: string;
+    team_name_theme?: TeamNameTheme | null;
+    progress_visibility: ProgressVisibility;
+    late_join_enabled: boolean;
+    soft_player_cap: number | null; // null uses the server default, 0 disables the cap
+    max_players: number | null; // Hard cap on joins, null for none
+    locked: boolean;
+    is_private: boolean; // Private lobbies can't hand out public share links
+    pace_keeper_enabled: boolean;
+    rng_seed: number;
+    timezone: string;
+    opens_at: string | null;
+    closes_at: string | null;
+    starts_at: string | null;
+    roster_version: number;
+    round_time_limit_seconds: number | null; // Arms the round timer on every game start, null for untimed rounds
+    host_player_id: number | null; // Player who may create teams and start games; null for quick play
+    prize_tiers: string | null; // JSON PrizeTiers while contest mode is on
+    intermission_enabled: boolean; // Hold the lobby between rounds until the admin starts the next one
+    intermission_seconds: number | null; // Auto-advance after this long; null waits for the admin
+    intermission_started_at: string | null; // Set while the lobby is in intermission
     created_at: string;
 }
 
+export interface LobbySettingsUpdate {
+    max_players?: number | null;
+    locked?: boolean;
+    is_private?: boolean;
+    round_time_limit_seconds?: number | null;
+}
+
+export interface AdminLobbyListEntry {
+    lobby: Lobby;
+    player_count: number;
+    soft_player_cap: number | null; // The cap in effect, after the server default
+    over_soft_cap: boolean;
+    team_count: number;
+    connected_count: number; // Players with an open websocket right now
+    phase: LobbyPhase;
+    last_activity_at: string; // Latest join, round start or guess
+}
+
+export type AdminLobbyListSort = 'newest' | 'oldest' | 'name' | 'players';
+
+export interface AdminLobbyListQuery {
+    page?: number;
+    perPage?: number;
+    sort?: AdminLobbyListSort;
+    activeOnly?: boolean;
+}
+
+export interface AdminLobbyListResponse {
+    items: AdminLobbyListEntry[];
+    total: number; // Lobbies matching the filter, across all pages
+    page: number;
+    per_page: number;
+    has_more: boolean;
+}
+
+export interface ScheduledTime {
+    utc: string;
+    local: string; // ISO 8601 with the lobby timezone's offset
+    display: string; // e.g. "Fri, Oct 16, 7:00 PM EDT"
+}
+
+export interface LobbySchedule {
+    timezone: string;
+    opens_at: ScheduledTime | null;
+    closes_at: ScheduledTime | null;
+    starts_at: ScheduledTime | null;
+}
+
+export interface LobbyScheduleUpdate {
+    timezone: string;
+    opens_at?: string | null;
+    closes_at?: string | null;
+    starts_at?: string | null;
+}
+
+export interface PollResponse {
+    events: WebSocketMessage[];
+    last_seq: number; // Pass as afterSeq on the next poll
+    resync_required: boolean; // Reload lobby state, then poll from last_seq
+}
+
+// Head-to-head record between two recurring team line-ups (matched by member names across lobbies)
+export interface Rivalry {
+    members_a: string[];
+    members_b: string[];
+    team_names_a: string[];
+    team_names_b: string[];
+    meetings: number;
+    wins_a: number;
+    wins_b: number;
+    ties: number;
+    avg_point_margin: number; // Positive when line-up A usually finishes ahead
+    last_met_at: string | null;
+}
+
+export interface RivalriesResponse {
+    rivalries: Rivalry[];
+}
+
+// A guess made while offline, sent once the connection is back
+export interface QueuedGuess {
+    guess: string;
+    word_index: number;
+    client_guess_id: string; // Resending it returns the first outcome instead of guessing again
+    submitted_at: string; // When it was typed
+}
+
+export interface GuessSubmitResponse {
+    result: 'correct' | 'wrong' | 'pending' | 'already_solved' | 'rejected'; // 'rejected' only from a batch
+    word_index: number;
+    revealed_steps: number[]; // The team's revealed rungs after this guess (empty when rejected)
+    is_completed: boolean;
+    client_guess_id: string | null;
+    duplicate: boolean; // Sent before; the result is from the first time
+    detail: string | null; // Why a rejected guess wasn't judged
+}
+
+export interface GuessBatchResponse {
+    results: GuessSubmitResponse[]; // One per queued guess, in the order sent
+}
+
+export interface QuickPlayInfo {
+    enabled: boolean;
+    code: string | null; // Join code for the server-run quick play lobby
+    player_count: number;
+    next_round_at: string | null;
+}
+
+export interface StoredPuzzleStep {
+    word: string;
+    clue: string | null;
+    transform: string | null;
+    open_ended?: boolean;
+    alternates?: string[]; // Other accepted answers; solving with one reveals word
+}
+
+export interface StoredPuzzleInput {
+    title: string;
+    difficulty: string;
+    ladder: StoredPuzzleStep[];
+    author_name?: string | null;
+    author_handle?: string | null;
+    author_notes?: string | null; // Only shown to admins
+}
+
+export interface StoredPuzzleSummary {
+    id: number;
+    title: string;
+    difficulty: string;
+    word_count: number;
+    author_name: string | null;
+    author_handle: string | null;
+    author_notes: string | null;
+    created_at: string;
+    updated_at: string | null;
+}
+
+export interface StoredPuzzleDetail extends StoredPuzzleSummary {
+    ladder: StoredPuzzleStep[];
+}
+
 export interface LobbyInfo {
     lobby: Lobby;
     players: Player[];
     players_by_team: Record<number, Player[]> | null;
     teams: Team[] | null;
+    schedule: LobbySchedule;
+    phase: LobbyPhase;
     game: null;
 }
 
+export type LobbyPhase = 'waiting' | 'in_progress' | 'finished' | 'intermission';
+
 export interface ApiResponse {
     status: boolean;
     message: string;
 }
 
+// How a critical broadcast went; connections are "player:<session_id>" or "admin:<web_session_id>"
+export interface DeliverySummary {
+    attempted: number;
+    succeeded: number;
+    failed: number;
+    failures: { connection: string; reason: string }[];
+}
+
+export interface BroadcastApiResponse extends ApiResponse {
+    delivery: DeliverySummary;
+}
+
 export interface GeneratedNameResponse {
     name: string;
 }
 
 export interface AdminAuthAdminAuthenticatedResponse {
     session_id: string;
+    token: string; // Sent as the bearer instead of the password, and traded for the admin websocket's ?token=
+    is_owner: boolean;
+    organization_id: number | null; // Set when the admin only sees one organization's lobbies and puzzles
+    expires_at: string;
+}
+
+export interface ImportedPlayer {
+    name: string;
+    session_id: string;
+    join_url: string;
+    team_name: string | null;
+}
+
+export interface ImportPlayersResponse {
+    imported: ImportedPlayer[];
+    skipped_names: string[];
+}
+
+export interface MergeLobbiesResponse {
+    target_lobby_id: number;
+    moved_players: number;
+    moved_teams: number;
+    renamed_players: Record<string, string>; // Old name to new, for names the target lobby already had
+    renamed_teams: Record<string, string>;
+}
+
+export interface HostStartGameRequest {
+    difficulty: string;
+    puzzle_mode: string;
+    word_count_mode: string;
+    force_start: boolean;
 }
 
 export interface StartGameRequest {
@@ -55,12 +282,14 @@ export interface StartGameRequest {
     word_count_mode: string;
     force_start: boolean;
     puzzle_date?: string;
+    puzzle_id?: number; // Stored puzzle every team plays
 }
 
 export interface StartGameResponse {
     success: boolean;
     game_id: number;
     message: string;
+    delivery: DeliverySummary;
 }
 
 // #########################################################################
@@ -76,10 +305,18 @@ export enum LobbyWebSocketEvents {
     PLAYER_KICKED = 'player_kicked',
     READY_STATUS_CHANGED = 'ready_status_changed',
     LOBBY_DELETED = 'lobby_deleted',
+    LATE_JOIN = 'late_join',
+    PREFERENCES_UPDATED = 'preferences_updated',
+    PLAYER_DISCONNECTED = 'player_disconnected',
+    LOBBY_PHASE_CHANGED = 'lobby_phase_changed',
+    LOBBY_MERGED = 'lobby_merged',
+    HOST_CHANGED = 'host_changed',
+    TEAM_UPDATED = 'team_updated',
+    TEAMS_REASSIGNED = 'teams_reassigned',
 }
 
 export interface WebSocketMessage {
-    type: LobbyWebSocketEvents | GameWebSocketEvents | string;
+    type: ServerEventType | string;
     data?: Record<string, unknown>;
     player_session_id?: string;
     message?: Record<string, unknown>;
@@ -93,9 +330,214 @@ export interface WebSocketMessage {
     last_updated_at?: string;
     old_team_id?: number;
     new_team_id?: number;
+    new_team_color?: string | null;
+    team_color?: string | null;
     placement?: number;
     points_earned?: number;
     first_place_team_name?: string;
+    admin_id?: string;
+    phase?: LobbyPhase;
+    previous_phase?: LobbyPhase;
+    player_name?: string;
+    player_count?: number;
+    soft_player_cap?: number;
+    puzzles?: PuzzleCredit[];
+    step_number?: number;
+    total_steps?: number;
+    word?: string;
+    explanation?: string | null;
+    completed?: boolean;
+    seq?: number; // Per-lobby sequence number on player events, sent back as last_seq when reconnecting
+    roster_version?: number; // On roster changes; refetch the roster when it differs from the one rendered
+    target_lobby_name?: string;
+    winning_team_name?: string | null;
+    prizes?: PrizeAward[]; // Contest-mode awards on game_finished
+    moves?: PlayerTeamMove[]; // On teams_reassigned
+    delta?: number;
+    reason?: string;
+    ack_required?: boolean; // Critical event; clients connected with ?acks=true answer { action: 'ack', seq }
+    reconnect_after_seconds?: number;
+    text?: string; // On commentary
+    auto_advance_at?: string | null; // On intermission_started
+    deleted_title?: string; // On puzzle_replaced
+    replacement_title?: string;
+}
+
+export interface LateJoinEvent {
+    type: LobbyWebSocketEvents.LATE_JOIN;
+    lobby_id: number;
+    player_session_id: string;
+    player_id: number;
+    player_name: string;
+    team_id: number;
+    team_name: string;
+    team_color: string | null;
+}
+
+export interface LobbyMergedEvent {
+    type: LobbyWebSocketEvents.LOBBY_MERGED;
+    lobby_id: number;
+    player_session_id: string;
+    target_lobby_id: number;
+    target_lobby_code: string;
+    target_lobby_name: string;
+}
+
+export interface TeamUpdatedEvent {
+    type: LobbyWebSocketEvents.TEAM_UPDATED;
+    lobby_id: number;
+    team_id: number;
+    team_name: string;
+    team_color: string | null;
+    roster_version: number;
+}
+
+export interface PlayerTeamMove {
+    player_session_id: string;
+    old_team_id: number; // 0 when the player had no team
+    new_team_id: number; // 0 when the player was unassigned
+    new_team_color: string | null;
+}
+
+// Several players moved at once by the admin's bulk assignment
+export interface TeamsReassignedEvent {
+    type: LobbyWebSocketEvents.TEAMS_REASSIGNED;
+    lobby_id: number;
+    moves: PlayerTeamMove[];
+    roster_version: number;
+}
+
+export interface HostChangedEvent {
+    type: LobbyWebSocketEvents.HOST_CHANGED;
+    lobby_id: number;
+    player_session_id: string; // The new host's
+    host_player_id: number;
+    host_name: string;
+}
+
+export interface LobbyPhaseChangedEvent {
+    type: LobbyWebSocketEvents.LOBBY_PHASE_CHANGED;
+    lobby_id: number;
+    phase: LobbyPhase;
+    previous_phase: LobbyPhase;
+}
+
+// ErrorCode in backend/errors.py
+export type ErrorCode =
+    | 'GAME_IN_PROGRESS'
+    | 'LOBBY_LOCKED'
+    | 'LOBBY_FULL'
+    | 'NAME_TAKEN'
+    | 'NAME_NOT_ALLOWED'
+    | 'PUZZLE_IN_USE'
+    | 'NO_REVEAL'
+    | 'SHUTTING_DOWN'
+    | 'CSRF_FAILED'
+    | 'RATE_LIMITED';
+
+// The {"detail": ...} body of errors that carry a code
+export interface StructuredErrorDetail {
+    code: ErrorCode;
+    message: string;
+    hint?: string; // Guidance for players, e.g. on NAME_TAKEN and LOBBY_FULL
+}
+
+export interface GameInProgressErrorDetail {
+    code: 'GAME_IN_PROGRESS';
+    message: string;
+    hint?: string;
+    lobby_state: {
+        lobby_id: number;
+        code: string;
+        name: string;
+        phase: Extract<LobbyPhase, 'in_progress'>;
+        round_number: number;
+        late_join_enabled: boolean;
+    };
+}
+
+export interface OccupancyWarningEvent {
+    type: 'occupancy_warning';
+    lobby_id: number;
+    player_name: string;
+    player_count: number;
+    soft_player_cap: number;
+}
+
+export interface AdminPresenceEvent {
+    type: 'admin_presence';
+    lobby_id: number;
+    admin_id: string;
+    team_id: number | null;
+}
+
+export interface PuzzleCredit {
+    title: string;
+    author: string;
+    author_handle: string | null;
+}
+
+export interface CreditsEvent {
+    type: 'credits';
+    lobby_id: number;
+    puzzles: PuzzleCredit[];
+}
+
+export interface ServerShutdownEvent {
+    type: 'server_shutdown';
+    reason: string;
+    reconnect_after_seconds: number;
+}
+
+export interface RevealStepEvent {
+    type: 'reveal_step';
+    lobby_id: number;
+    step_number: number;
+    total_steps: number;
+    puzzle_title: string;
+    puzzle_number: number;
+    puzzle_count: number;
+    word_index: number;
+    word: string;
+    explanation: string | null;
+    next_step_in_seconds: number | null;
+}
+
+export interface RevealEndedEvent {
+    type: 'reveal_ended';
+    lobby_id: number;
+    completed: boolean;
+}
+
+export interface RevealStatus {
+    lobby_id: number;
+    step_number: number;
+    total_steps: number;
+    finished: boolean;
+    active: boolean;
+    interval_seconds: number | null;
+}
+
+export interface HealthStatusEvent {
+    type: 'health_status';
+    degraded: boolean;
+    db_latency_ms: number | null;
+    error_rate: number | null;
+    disabled_features: string[];
+}
+
+export interface HealthStatus {
+    degraded: boolean;
+    db_latency_ms: number | null;
+    error_rate: number | null;
+    request_count: number;
+    disabled_features: string[];
+}
+
+export interface LobbyStateEvent {
+    type: 'lobby_state';
+    lobby_id: number;
+    state: LobbyInfo | null; // null when the lobby doesn't exist
 }
 
 export type ConnectionStatus =
@@ -117,10 +559,45 @@ export enum GameWebSocketEvents {
     TEAM_COMPLETED = 'team_completed',
     TEAM_PLACED = 'team_placed',
     GAME_WON = 'game_won',
+    GAME_FINISHED = 'game_finished',
+    SCORE_ADJUSTED = 'score_adjusted',
     STATE_UPDATE = 'state_update',
     ALREADY_SOLVED = 'already_solved',
+    GUESS_PENDING_REVIEW = 'guess_pending_review',
+    GUESS_REVIEWED = 'guess_reviewed',
+    TEAM_PROGRESS = 'team_progress',
+    WORD_ROLLED_BACK = 'word_rolled_back',
+    PACE_HINT = 'pace_hint',
+    TEAM_CHAT = 'team_chat',
+    TIMER_STARTED = 'timer_started',
+    TIMER_TICK = 'timer_tick',
+    TIMER_EXPIRED = 'timer_expired',
 }
 
+// Every event type the server sends (ServerEvent in backend/websocket/events.py); a backend test fails when
+// an event is added there without being listed here
+export type ServerEventType =
+    | LobbyWebSocketEvents
+    | GameWebSocketEvents
+    | 'round_ended'
+    | 'new_round_started'
+    | 'game_ended'
+    | 'credits'
+    | 'reveal_step'
+    | 'reveal_ended'
+    | 'ping'
+    | 'pong'
+    | 'resync_required'
+    | 'state_diff'
+    | 'occupancy_warning'
+    | 'lobby_state'
+    | 'admin_presence'
+    | 'health_status'
+    | 'server_shutdown'
+    | 'commentary'
+    | 'intermission_started'
+    | 'puzzle_replaced';
+
 export type Direction = 'down' | 'up';
 
 export type GamePhase = 'DOWNWARD' | 'UPWARD' | 'DIRECTION_LOCKED' | 'COMPLETED';
@@ -172,6 +649,11 @@ export interface WordSolvedEvent {
     word_index: number;
     word: string;
     direction: Direction;
+    revealed_count: number;
+    rank: number | null;
+    rank_delta: number;
+    projected_points: number;
+    points_earned: number;
 }
 
 export interface DirectionChangedEvent {
@@ -198,6 +680,7 @@ export interface TeamCompletedEvent {
     type: GameWebSocketEvents.TEAM_COMPLETED;
     team_id: number;
     team_name: string;
+    team_color: string | null;
     completed_at: string;
 }
 
@@ -208,16 +691,297 @@ export interface GameWonEvent {
     winning_team_name: string;
 }
 
+export interface FinalStanding {
+    team_id: number;
+    team_name: string;
+    team_color: string | null;
+    placement: number;
+    points_earned: number;
+    total_points: number; // Tournament total including this round
+    completion_percentage: number;
+    time_to_complete: number | null; // Seconds, null when the team didn't finish
+    completed: boolean;
+}
+
+export interface PrizeTiers {
+    top_teams: number; // How many places get a prize; 0 for none
+    fastest_rung: boolean;
+    best_streak: boolean;
+}
+
+export type PrizeKind = 'top_team' | 'fastest_rung' | 'best_streak';
+
+export interface PrizeAward {
+    prize: PrizeKind;
+    place: number; // 1..N for top_team
+    team_id: number;
+    team_name: string;
+    player_name: string | null; // Who solved the fastest rung
+    value: number; // Total points, seconds for the rung, or correct guesses in a row
+}
+
+export interface GameFinishedEvent {
+    type: GameWebSocketEvents.GAME_FINISHED;
+    lobby_id: number;
+    round_number: number;
+    winning_team_id: number | null;
+    winning_team_name: string | null;
+    standings: FinalStanding[];
+    prizes: PrizeAward[]; // Only in contest mode
+}
+
+// The lobby is holding between rounds, showing the standings so far
+export interface IntermissionStartedEvent {
+    type: 'intermission_started';
+    lobby_id: number;
+    round_number: number; // The round just played
+    standings: FinalStanding[];
+    auto_advance_at: string | null; // When the next round starts on its own; null waits for the admin
+}
+
+// A stored puzzle the lobby was using was force-deleted and a bundled one swapped in (sent to admins)
+export interface PuzzleReplacedEvent {
+    type: 'puzzle_replaced';
+    lobby_id: number;
+    deleted_title: string;
+    replacement_title: string;
+    restarted_team_ids: number[]; // Teams that were mid-puzzle and start over on the replacement
+}
+
+export interface NextRoundRequest {
+    difficulty?: string; // Defaults to the last round's
+    puzzle_mode?: 'same' | 'different';
+    word_count_mode?: 'exact' | 'balanced';
+}
+
+export interface GameResultsResponse {
+    round_number: number; // 0 until a round has been scored
+    winning_team_id: number | null;
+    winning_team_name: string | null;
+    standings: FinalStanding[];
+    prizes: PrizeAward[]; // Contest-mode awards so far
+}
+
+export interface PlayerStatsQuery {
+    dateFrom?: string; // YYYY-MM-DD, inclusive
+    dateTo?: string; // YYYY-MM-DD, inclusive
+    limit?: number;
+}
+
+export interface PlayerAggregateStats {
+    name: string; // Most recent spelling; players are matched by name across lobbies
+    games_played: number;
+    guesses: number;
+    correct_guesses: number;
+    avg_solve_seconds: number | null; // Over the games their team finished
+}
+
+export interface PlayerStatsResponse {
+    date_from: string | null;
+    date_to: string | null;
+    players: PlayerAggregateStats[];
+}
+
+export interface ScoreAdjustment {
+    id: number;
+    lobby_id: number;
+    team_id: number;
+    team_name: string; // As it was when adjusted
+    delta: number; // Negative when points were taken away
+    reason: string;
+    total_points: number; // The team's total after the adjustment
+    admin_session_id: string | null;
+    created_at: string;
+}
+
+export interface ScoreAdjustedEvent {
+    type: GameWebSocketEvents.SCORE_ADJUSTED;
+    lobby_id: number;
+    team_id: number;
+    team_name: string;
+    delta: number;
+    reason: string;
+    total_points: number;
+}
+
 export interface AlreadySolvedEvent {
     type: GameWebSocketEvents.ALREADY_SOLVED;
     team_id: number;
     word_index: number;
 }
 
+export interface GuessPendingReviewEvent {
+    type: GameWebSocketEvents.GUESS_PENDING_REVIEW;
+    team_id: number;
+    guess_id: number;
+    player_id: number;
+    player_name: string;
+    word_index: number;
+    guess: string;
+}
+
+export interface GuessReviewedEvent {
+    type: GameWebSocketEvents.GUESS_REVIEWED;
+    team_id: number;
+    guess_id: number;
+    word_index: number;
+    guess: string;
+    approved: boolean;
+}
+
+export interface WordRolledBackEvent {
+    type: GameWebSocketEvents.WORD_ROLLED_BACK;
+    team_id: number;
+    word_index: number;
+    word: string;
+    revealed_count: number;
+}
+
+export interface PaceHintEvent {
+    type: GameWebSocketEvents.PACE_HINT;
+    team_id: number;
+    word_index: number;
+    level: number; // Escalates while the team stays stuck on the rung
+    hint: string; // First letters of the word, e.g. "FL___"
+}
+
+export interface TeamChatEvent {
+    type: GameWebSocketEvents.TEAM_CHAT;
+    team_id: number;
+    player_id: number;
+    player_name: string;
+    text: string;
+    sent_at: string;
+}
+
+export interface TeamProgressEvent {
+    type: GameWebSocketEvents.TEAM_PROGRESS;
+    team_id: number;
+    team_name: string;
+    team_color: string | null;
+    progress: number;
+    solved_count: number | null;
+    total_count: number | null;
+    is_completed: boolean;
+}
+
+export interface TeamProgressSnapshot {
+    team_id: number;
+    team_name: string;
+    team_color: string | null;
+    is_own_team: boolean;
+    progress: number;
+    solved_count: number | null;
+    total_count: number | null;
+    is_completed: boolean;
+}
+
+export interface TeamProgressResponse {
+    visibility: ProgressVisibility;
+    teams: TeamProgressSnapshot[];
+}
+
+export interface TeamGuessEntry {
+    id: number;
+    word_index: number;
+    direction: 'down' | 'up';
+    guess: string;
+    result: 'correct' | 'wrong' | 'pending';
+    player_id: number;
+    player_name: string;
+    created_at: string;
+}
+
+export interface TeamGuessHistoryResponse {
+    game_id: number | null;
+    page: number;
+    page_size: number;
+    total: number;
+    has_more: boolean;
+    guesses: TeamGuessEntry[];
+}
+
+export interface TeamChatEntry {
+    id: number;
+    team_id: number;
+    player_id: number;
+    player_name: string;
+    text: string;
+    sent_at: string;
+}
+
+export interface TeamChatHistoryResponse {
+    messages: TeamChatEntry[];
+}
+
+export interface RosterResponse {
+    roster_version: number;
+    changed: boolean; // False when since_version was already current; players is then empty
+    players: Player[];
+}
+
+export interface WebSocketTokenResponse {
+    token: string; // Goes in /ws/lobby/{lobby_id}/player?token=...
+    lobby_id: number;
+    expires_in_seconds: number;
+}
+
+export interface AdminWebSocketTokenResponse {
+    token: string; // Goes in /ws/admin/{session_id}?token=...
+    session_id: string;
+    expires_in_seconds: number;
+}
+
+export interface SpectatorSessionResponse {
+    spectator_session_id: string;
+    lobby_id: number;
+    lobby_code: string;
+    lobby_name: string;
+}
+
+// Plain-text game narration sent to spectators and admins, e.g. "Red Foxes solved rung 3 after 4 guesses!"
+export interface CommentaryEvent {
+    type: 'commentary';
+    lobby_id: number;
+    text: string;
+    created_at: string;
+}
+
+export interface CommentaryFeedResponse {
+    lines: CommentaryEvent[]; // Oldest first
+}
+
+export interface LobbyGuessEntry extends TeamGuessEntry {
+    team_id: number;
+    team_name: string;
+    game_id: number;
+}
+
+export interface LobbyGuessHistoryResponse {
+    page: number;
+    page_size: number;
+    total: number;
+    has_more: boolean;
+    guesses: LobbyGuessEntry[];
+}
+
+export interface PendingGuess {
+    guess_id: number;
+    team_id: number;
+    team_name: string;
+    player_name: string;
+    word_index: number;
+    clue: string | null;
+    example_answer: string;
+    guess: string;
+    created_at: string;
+}
+
 export interface TeamPlacedEvent {
     type: GameWebSocketEvents.TEAM_PLACED;
     team_id: number;
     team_name: string;
+    team_color: string | null;
     placement: number;
     points_earned: number;
     completed_at: string;
@@ -232,7 +996,12 @@ export type GameEvent =
     | StateUpdateEvent
     | TeamCompletedEvent
     | TeamPlacedEvent
+    | GuessPendingReviewEvent
+    | GuessReviewedEvent
+    | TeamProgressEvent
     | GameWonEvent
+    | GameFinishedEvent
+    | ScoreAdjustedEvent
     | AlreadySolvedEvent;
 
 // #########################################################################
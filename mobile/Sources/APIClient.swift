@@ -83,9 +83,21 @@ struct GeneratedNameResponse: Codable {
 
 struct AdminAuthenticatedResponse: Codable {
     let sessionId: String
+    let token: String
 
     enum CodingKeys: String, CodingKey {
         case sessionId = "session_id"
+        case token
+    }
+}
+
+struct AdminWebSocketTokenResponse: Codable {
+    let token: String
+    let expiresInSeconds: Int
+
+    enum CodingKeys: String, CodingKey {
+        case token
+        case expiresInSeconds = "expires_in_seconds"
     }
 }
 
@@ -250,19 +262,28 @@ final class APIClient: @unchecked Sendable {
         decoder = JSONDecoder()
     }
 
-    func adminWebSocketURL(sessionId: String, token: String) throws -> URL {
+    /// `connectToken` comes from `getWebSocketToken`, so the session token never goes in the URL.
+    func adminWebSocketURL(sessionId: String, connectToken: String) throws -> URL {
         var components = URLComponents(url: baseURL, resolvingAgainstBaseURL: false)
         components?.scheme = baseURL.scheme == "https" ? "wss" : "ws"
         components?.path = "/ws/admin/\(sessionId)"
-        components?.queryItems = [URLQueryItem(name: "token", value: token)]
+        components?.queryItems = [URLQueryItem(name: "token", value: connectToken)]
         guard let url = components?.url else { throw APIError.invalidURL }
         return url
     }
 
+    func login(password: String) async throws -> AdminAuthenticatedResponse {
+        try await request("/api/admin/login", method: "POST", body: ["password": password])
+    }
+
     func checkCredentials(token: String) async throws -> AdminAuthenticatedResponse {
         try await request("/api/admin/check", token: token)
     }
 
+    func getWebSocketToken(token: String) async throws -> AdminWebSocketTokenResponse {
+        try await request("/api/admin/ws-token", method: "POST", token: token)
+    }
+
     func getPuzzleDates(token: String) async throws -> [String] {
         try await request("/api/admin/puzzles/dates", token: token)
     }
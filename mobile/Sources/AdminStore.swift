@@ -46,7 +46,8 @@ final class AdminStore: ObservableObject {
     func login() async {
         guard !token.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty else { return }
         await run {
-            let response = try await api.checkCredentials(token: token)
+            let response = try await api.login(password: token)
+            token = response.token
             sessionId = response.sessionId
             UserDefaults.standard.set(token, forKey: "raddle_admin_token")
             UserDefaults.standard.set(response.sessionId, forKey: "raddle_admin_session_id")
@@ -236,18 +237,21 @@ final class AdminStore: ObservableObject {
         socketTask?.cancel(with: .goingAway, reason: nil)
         guard let sessionId, let activeToken else { return }
 
-        do {
-            let url = try api.adminWebSocketURL(sessionId: sessionId, token: activeToken)
-            let task = URLSession.shared.webSocketTask(with: url)
-            socketTask = task
-            task.resume()
-            socketConnected = true
-            receiveSocketMessage()
-            if let selectedLobbyId {
-                sendSocketMessage(["action": "subscribe_lobby", "lobby_id": selectedLobbyId])
+        Task {
+            do {
+                let connectToken = try await api.getWebSocketToken(token: activeToken)
+                let url = try api.adminWebSocketURL(sessionId: sessionId, connectToken: connectToken.token)
+                let task = URLSession.shared.webSocketTask(with: url)
+                socketTask = task
+                task.resume()
+                socketConnected = true
+                receiveSocketMessage()
+                if let selectedLobbyId {
+                    sendSocketMessage(["action": "subscribe_lobby", "lobby_id": selectedLobbyId])
+                }
+            } catch {
+                errorMessage = error.localizedDescription
             }
-        } catch {
-            errorMessage = error.localizedDescription
         }
     }
 